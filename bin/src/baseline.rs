@@ -0,0 +1,128 @@
+//! Baselines of lint findings, to adopt stricter lints without fixing every existing finding first
+
+use std::{collections::BTreeMap, path::Path, sync::Arc};
+
+use hemtt_workspace::reporting::{Code, WorkspaceFiles};
+use serde::{Deserialize, Serialize};
+
+use crate::{report::Report, Error};
+
+/// The baseline used by `hemtt check` when it exists
+pub const DEFAULT_PATH: &str = ".hemtt/lint-baseline.toml";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default, rename = "finding")]
+    findings: Vec<Finding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+/// A lint finding, identified without its position so it survives unrelated edits
+struct Finding {
+    code: String,
+    #[serde(default)]
+    file: String,
+    message: String,
+    #[serde(default = "one")]
+    count: usize,
+}
+
+const fn one() -> usize {
+    1
+}
+
+impl Baseline {
+    /// Record the lint findings of a report
+    #[must_use]
+    pub fn from_report(report: &Report) -> Self {
+        let workspace_files = WorkspaceFiles::new();
+        let mut counts: BTreeMap<(String, String, String), usize> = BTreeMap::new();
+        for code in report.codes() {
+            if let Some(key) = key(code, &workspace_files) {
+                *counts.entry(key).or_default() += 1;
+            }
+        }
+        Self {
+            findings: counts
+                .into_iter()
+                .map(|((code, file, message), count)| Finding {
+                    code,
+                    file,
+                    message,
+                    count,
+                })
+                .collect(),
+        }
+    }
+
+    /// Read a baseline file
+    ///
+    /// # Errors
+    /// [`Error::Io`] if the file cannot be read
+    /// [`Error::TomlDe`] if the file is not a valid baseline
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        Ok(toml::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Write the baseline to a file
+    ///
+    /// # Errors
+    /// [`Error::Io`] if the file cannot be written
+    /// [`Error::TomlSer`] if the baseline cannot be serialized
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// The number of findings in the baseline
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.findings.iter().map(|f| f.count).sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove the findings in the baseline from the report, returning how many were removed
+    ///
+    /// When a finding occurs more often than recorded, the extra occurrences are kept
+    pub fn apply(&self, report: &mut Report) -> usize {
+        let workspace_files = WorkspaceFiles::new();
+        let mut remaining: BTreeMap<(String, String, String), usize> = self
+            .findings
+            .iter()
+            .map(|f| ((f.code.clone(), f.file.clone(), f.message.clone()), f.count))
+            .collect();
+        let mut removed = 0;
+        report.retain(|code| {
+            let Some(count) = key(code, &workspace_files).and_then(|k| remaining.get_mut(&k))
+            else {
+                return true;
+            };
+            if *count == 0 {
+                return true;
+            }
+            *count -= 1;
+            removed += 1;
+            false
+        });
+        removed
+    }
+}
+
+/// The identity of a lint finding, only lints can be part of a baseline
+fn key(code: &Arc<dyn Code>, workspace_files: &WorkspaceFiles) -> Option<(String, String, String)> {
+    if !code.ident().starts_with("L-") {
+        return None;
+    }
+    let file = code
+        .diagnostic()
+        .and_then(|diag| diag.to_json(workspace_files).file)
+        .unwrap_or_default();
+    Some((code.ident().to_string(), file, code.message()))
+}
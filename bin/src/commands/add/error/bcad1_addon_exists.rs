@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct AddonExists {
+    name: String,
+}
+
+impl Code for AddonExists {
+    fn ident(&self) -> &'static str {
+        "BCAD1"
+    }
+
+    fn message(&self) -> String {
+        format!("Addon `{}` already exists.", self.name)
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl AddonExists {
+    pub fn code(name: String) -> Arc<dyn Code> {
+        Arc::new(Self { name })
+    }
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct InvalidName {
+    name: String,
+}
+
+impl Code for InvalidName {
+    fn ident(&self) -> &'static str {
+        "BCAD2"
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` is not a valid addon name.", self.name)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("use only lowercase letters, numbers, and underscores".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl InvalidName {
+    pub fn code(name: String) -> Arc<dyn Code> {
+        Arc::new(Self { name })
+    }
+}
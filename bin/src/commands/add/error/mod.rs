@@ -0,0 +1,2 @@
+pub mod bcad1_addon_exists;
+pub mod bcad2_invalid_name;
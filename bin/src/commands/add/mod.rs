@@ -0,0 +1,107 @@
+use crate::{
+    context::Context,
+    modules::{write_custom_addon, Template},
+    report::Report,
+    Error,
+};
+
+use self::error::{bcad1_addon_exists::AddonExists, bcad2_invalid_name::InvalidName};
+
+mod error;
+
+#[derive(clap::Parser)]
+#[command(verbatim_doc_comment)]
+/// Add a new addon to the project
+///
+/// Creates `addons/<name>` with a `$PBOPREFIX$`, and a `config.cpp` with a
+/// `CfgPatches` class that requires the `main` addon.
+///
+/// If the `main` addon uses the CBA macros, with an `addons/main/script_mod.hpp`,
+/// the new addon includes them in its `script_component.hpp`, and has extended event handlers.
+///
+/// ## Templates
+///
+/// The files in `.hemtt/templates/addon` are used instead, if the folder exists.
+/// The following are replaced in each file:
+///
+/// - `{addon}` - The name of the addon, `my_addon`
+/// - `{addon_beautified}` - The name of the addon in title case, `My Addon`
+/// - `{name}` - The name of the project
+/// - `{prefix}` - The prefix of the project
+/// - `{mainprefix}` - The main prefix of the project
+///
+/// A `$PBOPREFIX$` is added if the template does not have one.
+pub struct Command {
+    /// The name of the addon, using only lowercase letters, numbers, and underscores
+    name: String,
+
+    #[clap(flatten)]
+    global: crate::GlobalArgs,
+}
+
+/// Execute the add command
+///
+/// # Errors
+/// [`Error`] if the project can not be loaded, or a file can not be written
+pub fn execute(cmd: &Command) -> Result<Report, Error> {
+    let mut report = Report::new();
+    let ctx = Context::new(None, crate::context::PreservePrevious::Keep, None, false)?;
+
+    if cmd.name.is_empty()
+        || !cmd
+            .name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        report.push(InvalidName::code(cmd.name.clone()));
+        return Ok(report);
+    }
+    if ctx.project_folder().join("addons").join(&cmd.name).exists() {
+        report.push(AddonExists::code(cmd.name.clone()));
+        return Ok(report);
+    }
+
+    let beautified = beautify(&cmd.name);
+    let values = [
+        ("addon", cmd.name.as_str()),
+        ("addon_beautified", beautified.as_str()),
+        ("name", ctx.config().name().as_str()),
+        ("prefix", ctx.config().prefix().as_str()),
+        (
+            "mainprefix",
+            ctx.config().mainprefix().map_or("", String::as_str),
+        ),
+    ];
+
+    let custom = ctx
+        .project_folder()
+        .join(".hemtt")
+        .join("templates")
+        .join("addon");
+    if custom.is_dir() {
+        debug!("Using the addon template in `.hemtt/templates/addon`");
+        write_custom_addon(&custom, ctx.project_folder(), &cmd.name, &values)?;
+    } else {
+        Template::detect(ctx.project_folder()).write_addon(
+            ctx.project_folder(),
+            &cmd.name,
+            &values,
+        )?;
+    }
+    info!("Created `addons/{}`", cmd.name);
+    Ok(report)
+}
+
+/// `my_addon` to `My Addon`
+fn beautify(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
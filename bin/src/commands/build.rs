@@ -1,8 +1,15 @@
+use std::path::Path;
+
+use hemtt_common::config::{WorkspaceConfig, WORKSPACE_FILE};
+
 use crate::{
     context::{self, Context},
     error::Error,
     executor::Executor,
-    modules::{pbo::Collapse, Binarize, Files, Rapifier},
+    modules::{
+        pbo::Collapse, AudioConverter, Binarize, Dependencies, Files, PaaConverter, Rapifier,
+        SQFCompiler, Stringtables,
+    },
     report::Report,
 };
 
@@ -30,6 +37,59 @@ use super::global_modules;
 /// ### `optional_mod_folders`
 ///
 /// By default, `hemtt build` will create separate mods for each optional mod folder.
+///
+/// ### `compression`
+///
+/// Files inside the PBOs can be compressed with LZSS, this is disabled by default.
+/// Compression is also used by [`hemtt release`](./release.md).
+///
+/// ```toml
+/// [hemtt.build.compression]
+/// enabled = true # Default: false
+/// min_size = 2048 # Default: 1024
+/// include = ["sqf", "hpp"] # Default: all extensions that are not excluded
+/// exclude = ["ogg", "paa"] # Default: bin, jpg, lip, ogg, p3d, paa, pac, png, rtm, wav, wrp, wss
+/// ```
+///
+/// Files smaller than `min_size` bytes are never compressed,
+/// and files are stored uncompressed if compression does not make them smaller.
+///
+/// ### `sqfc_version`
+///
+/// SQF is compiled to the latest revision of the `.sqfc` format by default.
+/// Older game branches may only load an earlier revision.
+///
+/// ```toml
+/// [hemtt.build]
+/// sqfc_version = 1 # Default: 2
+/// ```
+///
+/// | Version | Changes |
+/// | ------- | ------- |
+/// | 1 | Constants are not compressed, nular commands are always called |
+/// | 2 | Constants are compressed, nular commands with a constant result are stored as constants |
+///
+/// Any other version is an error. Scripts optimized with `sqf_optimize` can be compiled to either version,
+/// arrays that can't be stored as constants are built when they are used.
+///
+/// ## Workspaces
+///
+/// Projects that are developed together, such as a mod and its companion maps,
+/// can be listed in a `hemtt-workspace.toml` in a folder above them,
+/// and built with `hemtt build --workspace` from that folder.
+///
+/// ```toml
+/// [projects.main]
+/// path = "mod"
+///
+/// [projects.maps]
+/// path = "maps"
+/// depends = ["main"] # Default: none
+/// ```
+///
+/// Each project is built into its own `.hemttout/build` after the projects it depends on,
+/// and the build stops at the first project that fails.
+/// All projects share the cache in the `.hemttout/cache` of the workspace.
 pub struct Command {
     #[clap(flatten)]
     build: BuildArgs,
@@ -37,6 +97,15 @@ pub struct Command {
     #[clap(flatten)]
     just: super::JustArgs,
 
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    /// Build every project in `hemtt-workspace.toml`
+    ///
+    /// Each project is built after the projects it depends on.
+    workspace: bool,
+
+    #[clap(flatten)]
+    pub(crate) report: crate::ReportArgs,
+
     #[clap(flatten)]
     global: crate::GlobalArgs,
 }
@@ -56,6 +125,12 @@ pub struct BuildArgs {
     /// They will be copied directly into the PBO.
     /// This can be configured per addon in [`addon.toml`](../configuration/addon#rapify).
     no_rap: bool,
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    /// Write byte-identical PBOs for the same sources
+    ///
+    /// Files and properties are sorted, and timestamps are removed,
+    /// so the output of two builds can be compared.
+    deterministic: bool,
 }
 
 /// Execute the build command, build a new executor
@@ -63,30 +138,62 @@ pub struct BuildArgs {
 /// # Errors
 /// [`Error`] depending on the modules
 pub fn execute(cmd: &Command) -> Result<Report, Error> {
-    let just = cmd
-        .just
-        .just
-        .iter()
-        .map(|s| s.to_lowercase())
-        .collect::<Vec<_>>();
+    if cmd.workspace {
+        return execute_workspace(cmd);
+    }
+    build(cmd, None)
+}
+
+/// Build each project of a workspace, stopping at the first that fails
+fn execute_workspace(cmd: &Command) -> Result<Report, Error> {
+    let root = std::env::current_dir()?;
+    let path = root.join(WORKSPACE_FILE);
+    if !path.exists() {
+        return Err(Error::WorkspaceConfigNotFound);
+    }
+    let config = WorkspaceConfig::from_file(&path)?;
+    let hemttout = root.join(".hemttout");
+    std::fs::create_dir_all(&hemttout)?;
+    std::fs::File::create(hemttout.join("ci_annotations.txt"))?;
+    let cache = hemttout.join("cache");
+    let mut report = Report::new();
+    for (name, project) in config.build_order()? {
+        info!("Building workspace project `{name}`");
+        std::env::set_current_dir(root.join(project.path()))?;
+        let result = build(cmd, Some(&cache));
+        std::env::set_current_dir(&root)?;
+        let result = result?;
+        let failed = result.failed_policy();
+        report.merge(result);
+        if failed {
+            error!("Failed to build `{name}`, the remaining projects were not built");
+            break;
+        }
+    }
+    Ok(report)
+}
+
+fn build(cmd: &Command, shared_cache: Option<&Path>) -> Result<Report, Error> {
     let mut ctx = Context::new(
         Some("build"),
-        if just.is_empty() {
-            context::PreservePrevious::Remove
-        } else {
+        cmd.global.preserve_previous(if cmd.just.is_partial() {
             warn!("keeping previous build artifacts");
             context::PreservePrevious::Keep
-        },
+        } else {
+            context::PreservePrevious::Remove
+        }),
+        cmd.global.out(),
         true,
-    )?;
-    if !just.is_empty() {
-        ctx = ctx.filter(|a, _| just.contains(&a.name().to_lowercase()));
+    )?
+    .with_cache(cmd.global.cache());
+    if let Some(folder) = shared_cache {
+        ctx = ctx.with_shared_cache(folder);
     }
+    cmd.just.validate(&ctx)?;
+    let ctx = ctx.filter(|a, _| cmd.just.includes(a));
+    let fail_on = ctx.config().hemtt().check().fail_on();
     let mut executor = executor(ctx, &cmd.build);
-
-    if !just.is_empty() {
-        warn!("Use of `--just` is not recommended, only use it if you know what you're doing");
-    }
+    executor.fail_on(fail_on);
 
     info!("Creating `build` version");
 
@@ -95,7 +202,7 @@ pub fn execute(cmd: &Command) -> Result<Report, Error> {
 
 #[must_use]
 pub fn executor(ctx: Context, args: &BuildArgs) -> Executor {
-    let mut executor = Executor::new(ctx);
+    let mut executor = Executor::new(ctx.with_deterministic(args.deterministic));
     global_modules(&mut executor);
 
     executor.collapse(Collapse::No);
@@ -103,6 +210,11 @@ pub fn executor(ctx: Context, args: &BuildArgs) -> Executor {
     if !args.no_rap {
         executor.add_module(Box::<Rapifier>::default());
     }
+    executor.add_module(Box::<Dependencies>::default());
+    executor.add_module(Box::<SQFCompiler>::default());
+    executor.add_module(Box::<Stringtables>::default());
+    executor.add_module(Box::<PaaConverter>::default());
+    executor.add_module(Box::<AudioConverter>::default());
     if !args.no_bin {
         executor.add_module(Box::<Binarize>::default());
     }
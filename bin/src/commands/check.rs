@@ -1,9 +1,13 @@
+use std::{collections::HashSet, io::Write, path::Path};
+
 use crate::{
+    baseline::{Baseline, DEFAULT_PATH as DEFAULT_BASELINE},
     commands::global_modules,
     context::Context,
     error::Error,
     executor::Executor,
-    modules::{pbo::Collapse, Binarize, Rapifier},
+    fix,
+    modules::{pbo::Collapse, Binarize, Dependencies, Rapifier, SQFCompiler, Stringtables},
     report::Report,
 };
 
@@ -14,6 +18,46 @@ use crate::{
 /// All the same checks are run as [`hemtt dev`](./dev.md), but it will not
 /// write files to disk, saving time and resources.
 pub struct Command {
+    #[arg(long, value_name = "PATH", verbatim_doc_comment)]
+    /// Write the warnings and errors to a SARIF file
+    ///
+    /// The file can be uploaded to GitHub code scanning to annotate pull requests.
+    sarif: Option<String>,
+
+    #[arg(long, action = clap::ArgAction::SetTrue, verbatim_doc_comment)]
+    /// Apply safe fixes to the source files
+    ///
+    /// Only fixes that do not change the meaning of the code are applied,
+    /// such as quoting invalid config values and correcting the case of SQF commands.
+    fix: bool,
+
+    #[arg(long, value_name = "PATH", verbatim_doc_comment)]
+    /// Record the current lint findings to a baseline file
+    ///
+    /// Findings in the baseline are not reported by later runs,
+    /// so stricter lints can be adopted without fixing every existing finding first.
+    /// `.hemtt/lint-baseline.toml` is used by default when it exists.
+    write_baseline: Option<String>,
+
+    #[arg(long, value_name = "PATH", conflicts_with = "write_baseline")]
+    /// Only report lint findings that are not in this baseline file
+    baseline: Option<String>,
+
+    #[arg(long, action = clap::ArgAction::SetTrue, conflicts_with = "fix", verbatim_doc_comment)]
+    /// Only check the changes staged in git
+    ///
+    /// The staged version of each file is checked, changes that are not staged are ignored.
+    /// Addons with staged files are checked, along with the addons that include them.
+    /// Staged changes to `include` or `.hemtt` check every addon.
+    /// Useful for a pre-commit hook.
+    staged: bool,
+
+    #[clap(flatten)]
+    pub(crate) just: super::JustArgs,
+
+    #[clap(flatten)]
+    pub(crate) report: crate::ReportArgs,
+
     #[clap(flatten)]
     global: crate::GlobalArgs,
 }
@@ -22,19 +66,42 @@ pub struct Command {
 ///
 /// # Errors
 /// [`Error`] depending on the modules
-pub fn execute(_: &Command) -> Result<Report, Error> {
+pub fn execute(cmd: &Command) -> Result<Report, Error> {
     let ctx = Context::new(
         Some("check"),
-        crate::context::PreservePrevious::Remove,
+        cmd.global
+            .preserve_previous(crate::context::PreservePrevious::Remove),
+        cmd.global.out(),
         true,
-    )?;
+    )?
+    .with_cache(cmd.global.cache());
+    cmd.just.validate(&ctx)?;
+    let staged = if cmd.staged {
+        staged_addons(&ctx)?
+    } else {
+        None
+    };
+    if staged.as_ref().is_some_and(Vec::is_empty) {
+        return Ok(Report::new());
+    }
+    let ctx = ctx.filter(|a, _| {
+        cmd.just.includes(a)
+            && staged
+                .as_ref()
+                .is_none_or(|staged| staged.contains(&a.folder()))
+    });
 
+    let fail_on = ctx.config().hemtt().check().fail_on();
+    let project = ctx.project_folder().clone();
     let mut executor = Executor::new(ctx);
     global_modules(&mut executor);
 
     executor.collapse(Collapse::Yes);
 
     executor.add_module(Box::<Rapifier>::default());
+    executor.add_module(Box::<Dependencies>::default());
+    executor.add_module(Box::<SQFCompiler>::default());
+    executor.add_module(Box::<Stringtables>::default());
     executor.add_module(Box::<Binarize>::new(Binarize::new(true)));
 
     info!("Running checks");
@@ -43,5 +110,195 @@ pub fn execute(_: &Command) -> Result<Report, Error> {
     executor.check();
     executor.build(false);
 
-    executor.run()
+    let mut report = executor.run()?.with_fail_on(fail_on);
+    if cmd.fix {
+        let fixed = fix::apply(&project, &mut report)?;
+        info!("Applied {fixed} fixes");
+    }
+    let baseline = if let Some(path) = &cmd.write_baseline {
+        let baseline = Baseline::from_report(&report);
+        baseline.write(Path::new(path))?;
+        info!("Wrote {} findings to the baseline `{path}`", baseline.len());
+        Some(baseline)
+    } else if let Some(path) = &cmd.baseline {
+        Some(Baseline::read(Path::new(path))?)
+    } else if Path::new(DEFAULT_BASELINE).exists() {
+        Some(Baseline::read(Path::new(DEFAULT_BASELINE))?)
+    } else {
+        None
+    };
+    if let Some(baseline) = baseline {
+        let suppressed = baseline.apply(&mut report);
+        if suppressed > 0 {
+            info!("{suppressed} findings are suppressed by the baseline");
+        }
+    }
+    if let Some(sarif) = &cmd.sarif {
+        report.write_sarif(Path::new(sarif))?;
+        info!("Wrote SARIF report to `{sarif}`");
+    }
+    Ok(report)
+}
+
+/// Files that can `#include` other files
+const INCLUDERS: [&str; 10] = [
+    "cpp", "hpp", "h", "inc", "ext", "sqf", "sqm", "rvmat", "cfg", "fsm",
+];
+
+/// The folders of the addons with staged changes, or that include a staged file
+///
+/// Files with changes that are not staged are replaced in the workspace by their staged version,
+/// so only what would be committed is checked
+///
+/// [`None`] if every addon should be checked
+///
+/// # Errors
+/// [`Error::Git`] if the status of the repository can not be read
+/// [`Error::Workspace`] if the files of an addon can not be read
+/// [`Error::Io`] if a staged file can not be written to the workspace
+pub fn staged_addons(ctx: &Context) -> Result<Option<Vec<String>>, Error> {
+    let Ok(repo) = git2::Repository::discover(ctx.project_folder()) else {
+        warn!("`--staged` requires a git repository, checking all addons");
+        return Ok(None);
+    };
+    let Some(workdir) = repo.workdir() else {
+        warn!("`--staged` requires a git repository, checking all addons");
+        return Ok(None);
+    };
+    let index = repo.index()?;
+    let mut options = git2::StatusOptions::new();
+    options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .include_ignored(false);
+    let statuses = repo.statuses(Some(&mut options))?;
+    let mut changed = HashSet::new();
+    let mut everything = false;
+    for entry in statuses.iter() {
+        let Some(git_path) = entry.path() else {
+            continue;
+        };
+        let path = workdir.join(git_path);
+        let Ok(path) = path.strip_prefix(ctx.project_folder()) else {
+            continue;
+        };
+        let path = path.to_string_lossy().replace('\\', "/");
+        if entry.status().intersects(
+            git2::Status::WT_NEW
+                | git2::Status::WT_MODIFIED
+                | git2::Status::WT_DELETED
+                | git2::Status::WT_TYPECHANGE,
+        ) {
+            debug!("`{path}` has changes that are not staged");
+            use_staged(ctx, &repo, &index, git_path, &path)?;
+        }
+        if !entry.status().intersects(
+            git2::Status::INDEX_NEW
+                | git2::Status::INDEX_MODIFIED
+                | git2::Status::INDEX_DELETED
+                | git2::Status::INDEX_RENAMED
+                | git2::Status::INDEX_TYPECHANGE,
+        ) {
+            continue;
+        }
+        if path.starts_with("include/") || path.starts_with(".hemtt/") {
+            info!("`{path}` is staged, checking all addons");
+            everything = true;
+        }
+        debug!("`{path}` is staged");
+        changed.insert(format!("/{}", path.to_lowercase()));
+    }
+    if everything {
+        return Ok(None);
+    }
+    if changed.is_empty() {
+        info!("No changes are staged");
+        return Ok(Some(Vec::new()));
+    }
+    // Files that include a changed file, directly or through other files, have changed too
+    let graph = includes(ctx)?;
+    loop {
+        let before = changed.len();
+        for (file, paths) in &graph {
+            if paths.iter().any(|path| changed.contains(path)) {
+                changed.insert(file.clone());
+            }
+        }
+        if changed.len() == before {
+            break;
+        }
+    }
+    let staged = ctx
+        .all_addons()
+        .iter()
+        .map(hemtt_workspace::addons::Addon::folder)
+        .filter(|folder| {
+            let folder = format!("/{}/", folder.to_lowercase());
+            changed.iter().any(|path| path.starts_with(&folder))
+        })
+        .collect::<Vec<_>>();
+    if staged.is_empty() {
+        info!("No addons have staged changes");
+    }
+    Ok(Some(staged))
+}
+
+/// Replace a file in the workspace with its staged version, or hide it if it is not staged
+fn use_staged(
+    ctx: &Context,
+    repo: &git2::Repository,
+    index: &git2::Index,
+    git_path: &str,
+    path: &str,
+) -> Result<(), Error> {
+    let file = ctx.workspace_path().join(path)?;
+    if let Some(staged) = index.get_path(Path::new(git_path), 0) {
+        file.vfs().parent().create_dir_all()?;
+        file.create_file()?
+            .write_all(repo.find_blob(staged.id)?.content())?;
+    } else if file.exists()? {
+        file.vfs().remove_file()?;
+    }
+    Ok(())
+}
+
+/// The files of every addon, with the files they `#include`
+fn includes(ctx: &Context) -> Result<Vec<(String, Vec<String>)>, Error> {
+    let mut files = Vec::new();
+    for addon in ctx.all_addons() {
+        for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
+            if !entry.is_file()?
+                || !entry
+                    .extension()
+                    .is_some_and(|ext| INCLUDERS.contains(&ext.to_lowercase().as_str()))
+            {
+                continue;
+            }
+            let Ok(content) = entry.read_to_string() else {
+                continue;
+            };
+            let included = content
+                .lines()
+                .filter_map(include_path)
+                .filter_map(|include| entry.locate(include).ok().flatten())
+                .map(|found| found.path.as_str().to_lowercase())
+                .collect::<Vec<_>>();
+            if !included.is_empty() {
+                files.push((entry.as_str().to_lowercase(), included));
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// The path of an `#include` directive
+fn include_path(line: &str) -> Option<&str> {
+    let rest = line.trim_start().strip_prefix("#include")?.trim_start();
+    let close = match rest.chars().next()? {
+        '"' => '"',
+        '<' => '>',
+        _ => return None,
+    };
+    let rest = &rest[1..];
+    rest.find(close).map(|end| &rest[..end])
 }
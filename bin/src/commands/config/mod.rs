@@ -0,0 +1,29 @@
+use crate::{report::Report, Error};
+
+pub mod show;
+
+#[derive(clap::Parser)]
+#[command(arg_required_else_help = true)]
+/// Inspect the project configuration
+pub struct Command {
+    #[command(subcommand)]
+    commands: Subcommands,
+
+    #[clap(flatten)]
+    global: crate::GlobalArgs,
+}
+
+#[derive(clap::Subcommand)]
+enum Subcommands {
+    Show(show::Command),
+}
+
+/// Execute the config command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(cmd: &Command) -> Result<Report, Error> {
+    match &cmd.commands {
+        Subcommands::Show(cmd) => show::show(cmd),
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{context::Context, report::Report, Error};
+
+#[derive(clap::Parser)]
+#[allow(clippy::module_name_repetitions)]
+/// Print the effective project configuration
+///
+/// HEMTT will load the project the same way as any other
+/// command, and print the fully resolved configuration.
+/// Defaults are filled in and launch profiles have their
+/// `extends` applied, so the output shows exactly what
+/// HEMTT will use.
+///
+/// Keys are always printed in the same order, so the
+/// output can be compared between runs.
+pub struct Command {
+    #[arg(long, default_value = "toml")]
+    /// Output format
+    format: ConfigFormat,
+}
+
+#[derive(clap::ValueEnum, Clone, Default, Debug)]
+pub enum ConfigFormat {
+    /// toml, matching `.hemtt/project.toml`
+    #[default]
+    Toml,
+    /// compact json, ideal for machines
+    Json,
+    /// pretty json, ideal for humans
+    PrettyJson,
+}
+
+/// Print the effective project configuration
+///
+/// # Errors
+/// [`Error`] if the project config can not be loaded or serialized
+pub fn show(cmd: &Command) -> Result<Report, Error> {
+    let ctx = Context::new(None, crate::context::PreservePrevious::Keep, None, false)?;
+    let output = match cmd.format {
+        ConfigFormat::Toml => toml::to_string(ctx.config())?,
+        ConfigFormat::Json => serde_json::to_string(ctx.config())?,
+        ConfigFormat::PrettyJson => serde_json::to_string_pretty(ctx.config())?,
+    };
+    println!("{}", output.trim_end());
+    Ok(Report::new())
+}
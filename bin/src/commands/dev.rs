@@ -5,7 +5,10 @@ use crate::{
     context::Context,
     error::Error,
     executor::Executor,
-    modules::{pbo::Collapse, Binarize, FilePatching, Files, Rapifier},
+    modules::{
+        changes, pbo::Collapse, AudioConverter, Binarize, Changes, Dependencies, DevLinks,
+        FilePatching, Files, PaaConverter, Rapifier, SQFCompiler, Stringtables,
+    },
     report::Report,
 };
 
@@ -27,12 +30,34 @@ use super::JustArgs;
 /// ```toml
 /// [hemtt.dev]
 /// exclude = ["addons/unused"]
+/// link_to = ["C:/Program Files (x86)/Steam/steamapps/common/Arma 3/@my_mod"]
+/// defines = ["DEBUG_MODE_FULL"]
 /// ```
 ///
 /// ### exclude
 ///
 /// A list of addons to exclude from the development build.
 /// Includes from excluded addons can be used, but they will not be built or linked.
+///
+/// ### `link_to`
+///
+/// A list of folders that will be linked to `.hemttout/dev` after each build.
+/// Missing links are created, and links pointing somewhere else are replaced.
+/// Existing folders that are not links are left untouched.
+/// Relative paths are resolved from the project root.
+///
+/// ### defines
+///
+/// Macros defined for every file preprocessed by `hemtt dev` and `hemtt launch`,
+/// as `NAME`, defined as `1`, or `NAME=VALUE`.
+/// `hemtt release` and `hemtt build` use the `defines` of `[hemtt.release]` instead,
+/// so debug code can be left out of releases with `#ifdef`.
+///
+/// ```cpp
+/// #ifdef DEBUG_MODE_FULL
+///     diag_log format ["Spawned %1", _unit];
+/// #endif
+/// ```
 pub struct Command {
     #[clap(flatten)]
     pub(crate) dev: DevArgs,
@@ -45,7 +70,7 @@ pub struct Command {
 }
 
 #[derive(clap::Args)]
-#[allow(clippy::module_name_repetitions)]
+#[allow(clippy::module_name_repetitions, clippy::struct_excessive_bools)]
 pub struct DevArgs {
     #[arg(long, short, action = clap::ArgAction::SetTrue, verbatim_doc_comment)]
     /// Use BI's binarize on supported files
@@ -70,6 +95,14 @@ pub struct DevArgs {
     ///
     /// They will be copied directly into the PBO, not .bin version is created.
     pub(crate) no_rap: bool,
+    #[arg(long, action = clap::ArgAction::SetTrue, verbatim_doc_comment)]
+    /// Only rebuild addons with changes
+    ///
+    /// Addons with files that were added, removed, or modified since they were last built by `hemtt dev` are rebuilt,
+    /// the PBOs of the other addons are kept from the previous build.
+    /// Changes to `.hemtt` or the include folders rebuild every addon,
+    /// the include folders are `include`, `hemtt.preprocessor.include_paths`, and `hemtt.preprocessor.mounts`.
+    pub(crate) changed: bool,
 }
 
 /// Execute the dev command
@@ -77,7 +110,14 @@ pub struct DevArgs {
 /// # Errors
 /// [`Error`] depending on the modules
 pub fn execute(cmd: &Command, launch_optionals: &[String]) -> Result<(Report, Context), Error> {
-    let mut executor = context(&cmd.dev, &cmd.just, launch_optionals, false, true)?;
+    let mut executor = context(
+        &cmd.dev,
+        &cmd.just,
+        &cmd.global,
+        launch_optionals,
+        false,
+        true,
+    )?;
     executor.run().map(|r| (r, executor.into_ctx()))
 }
 
@@ -88,6 +128,7 @@ pub fn execute(cmd: &Command, launch_optionals: &[String]) -> Result<(Report, Co
 pub fn context(
     dev: &DevArgs,
     just: &JustArgs,
+    global: &crate::GlobalArgs,
     launch_optionals: &[String],
     force_binarize: bool,
     rapify: bool,
@@ -99,24 +140,33 @@ pub fn context(
         .map(std::string::String::as_str)
         .collect::<Vec<_>>();
 
-    let just = just
-        .just
-        .iter()
-        .map(|s| s.to_lowercase())
-        .collect::<Vec<_>>();
-
     let ctx = Context::new(
         Some("dev"),
-        if just.is_empty() {
-            crate::context::PreservePrevious::Remove
-        } else {
+        global.preserve_previous(if just.is_partial() || dev.changed {
             warn!("keeping previous build artifacts");
             crate::context::PreservePrevious::Keep
-        },
+        } else {
+            crate::context::PreservePrevious::Remove
+        }),
+        global.out(),
         true,
     )?
-    .filter(|a, config| {
-        if !just.is_empty() && !just.contains(&a.name().to_lowercase()) {
+    .with_cache(global.cache());
+    just.validate(&ctx)?;
+    let changed = if dev.changed {
+        changed_addons(&ctx)?
+    } else {
+        None
+    };
+    let ctx = ctx.filter(|a, config| {
+        if !just.includes(a) {
+            return false;
+        }
+        if changed
+            .as_ref()
+            .is_some_and(|changed| !changed.contains(&a.folder()))
+        {
+            debug!("skipping unchanged {}", a.name());
             return false;
         }
         if launch_optionals.iter().any(|o| o == a.name()) {
@@ -148,11 +198,18 @@ pub fn context(
 
     executor.collapse(Collapse::Yes);
 
+    executor.add_module(Box::<Changes>::default());
     if rapify && !dev.no_rap {
         executor.add_module(Box::<Rapifier>::default());
     }
+    executor.add_module(Box::<Dependencies>::default());
+    executor.add_module(Box::<SQFCompiler>::default());
+    executor.add_module(Box::<Stringtables>::default());
+    executor.add_module(Box::<PaaConverter>::default());
+    executor.add_module(Box::<AudioConverter>::default());
     executor.add_module(Box::<Files>::default());
     executor.add_module(Box::<FilePatching>::default());
+    executor.add_module(Box::<DevLinks>::default());
     if force_binarize || dev.binarize {
         executor.add_module(Box::<Binarize>::default());
     }
@@ -163,9 +220,36 @@ pub fn context(
     executor.check();
     executor.build(true);
 
-    if !just.is_empty() {
-        warn!("Use of `--just` is not recommended, only use it if you know what you're doing");
-    }
-
     Ok(executor)
 }
+
+/// The folders of the addons that changed since they were last built, or that have not been built yet
+///
+/// [`None`] if every addon should be built
+fn changed_addons(ctx: &Context) -> Result<Option<Vec<String>>, Error> {
+    let Some(previous) = changes::previous(ctx) else {
+        info!("No previous `hemtt dev` build was recorded, building all addons");
+        return Ok(None);
+    };
+    let current = changes::fingerprints(ctx, ctx.addons())?;
+    let build_folder = ctx.build_folder().expect("dev has a build folder");
+    let mut changed = Vec::new();
+    for addon in ctx.addons() {
+        let folder = addon.folder();
+        let built = build_folder
+            .join("addons")
+            .join(format!("{}.pbo", addon.pbo_name(ctx.config().prefix())))
+            .exists();
+        if !built {
+            debug!("{} has not been built", addon.name());
+            changed.push(folder);
+        } else if previous.get(&folder) != current.get(&folder) {
+            debug!("{} has changed", addon.name());
+            changed.push(folder);
+        }
+    }
+    if changed.is_empty() {
+        info!("No addons have changed");
+    }
+    Ok(Some(changed))
+}
@@ -0,0 +1,106 @@
+use std::path::Path;
+
+use crate::{context::Context, report::Report, Error};
+
+use super::error::{
+    bcfm1_not_formatted::NotFormatted, bcfm2_unsupported_syntax::UnsupportedSyntax,
+};
+
+#[derive(clap::Parser)]
+#[allow(clippy::module_name_repetitions)]
+#[command(verbatim_doc_comment)]
+/// Format config files
+///
+/// Formats every `.cpp` and `.hpp` file in `addons` and `optionals`,
+/// or only the given files and folders.
+///
+/// HEMTT will:
+///
+/// 1. Indent classes and properties by 4 spaces.
+/// 2. Write `class Name: Parent {` with the brace on the same line.
+/// 3. Put a single space around `=` and `+=`.
+/// 4. Reduce runs of blank lines to one, and remove them at the start and end of a class.
+/// 5. Write arrays on one line if they fit in 100 characters, otherwise with one element per line.
+///
+/// Comments, macros, and preprocessor directives are kept as they are written.
+/// Files that use a directive or comment inside the definition of a class or property are skipped.
+pub struct Command {
+    /// Files or folders to format, relative to the project
+    paths: Vec<String>,
+    #[arg(long)]
+    /// Only report files that are not formatted, without changing them
+    ///
+    /// Fails if any file is not formatted, for use in CI
+    check: bool,
+}
+
+/// Format the config files
+///
+/// # Errors
+/// [`Error`] if a file can not be read or written
+pub fn config(cmd: &Command) -> Result<Report, Error> {
+    let ctx = Context::new(None, crate::context::PreservePrevious::Keep, None, false)?;
+
+    let roots = if cmd.paths.is_empty() {
+        vec!["addons".to_string(), "optionals".to_string()]
+    } else {
+        cmd.paths.clone()
+    };
+    let mut files = Vec::new();
+    for root in roots {
+        let root = ctx.project_folder().join(root);
+        if root.is_file() {
+            files.push(root);
+            continue;
+        }
+        if !root.exists() {
+            continue;
+        }
+        for entry in walkdir::WalkDir::new(&root) {
+            let entry = entry?;
+            if entry.file_type().is_file() && is_config(entry.path()) {
+                files.push(entry.path().to_path_buf());
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+
+    let mut report = Report::new();
+    let mut changed = 0;
+    for path in &files {
+        let name = path
+            .strip_prefix(ctx.project_folder())
+            .unwrap_or(path)
+            .display()
+            .to_string()
+            .replace('\\', "/");
+        let source = std::fs::read_to_string(path)?;
+        let formatted = match hemtt_config::format::format(&source) {
+            Ok(formatted) => formatted,
+            Err(e) => {
+                report.push(UnsupportedSyntax::code(name, e));
+                continue;
+            }
+        };
+        if formatted == source {
+            continue;
+        }
+        changed += 1;
+        if cmd.check {
+            report.push(NotFormatted::code(name));
+        } else {
+            debug!("Formatted `{name}`");
+            std::fs::write(path, formatted)?;
+        }
+    }
+    if !cmd.check {
+        info!("Formatted {changed} of {} files", files.len());
+    }
+    Ok(report)
+}
+
+fn is_config(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("cpp") || ext.eq_ignore_ascii_case("hpp"))
+}
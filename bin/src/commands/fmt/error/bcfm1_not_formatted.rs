@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct NotFormatted {
+    file: String,
+}
+
+impl Code for NotFormatted {
+    fn ident(&self) -> &'static str {
+        "BCFM1"
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` is not formatted", self.file)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("run `hemtt fmt config` to format it".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl NotFormatted {
+    pub fn code(file: String) -> Arc<dyn Code> {
+        Arc::new(Self { file })
+    }
+}
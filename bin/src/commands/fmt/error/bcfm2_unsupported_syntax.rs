@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic, Severity};
+
+pub struct UnsupportedSyntax {
+    file: String,
+    error: hemtt_config::format::Error,
+}
+
+impl Code for UnsupportedSyntax {
+    fn ident(&self) -> &'static str {
+        "BCFM2"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` was not formatted, {} at {}:{}",
+            self.file,
+            self.error.message(),
+            self.error.line(),
+            self.error.column()
+        )
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("the file is left as it is".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl UnsupportedSyntax {
+    pub fn code(file: String, error: hemtt_config::format::Error) -> Arc<dyn Code> {
+        Arc::new(Self { file, error })
+    }
+}
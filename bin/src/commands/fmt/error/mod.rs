@@ -0,0 +1,2 @@
+pub mod bcfm1_not_formatted;
+pub mod bcfm2_unsupported_syntax;
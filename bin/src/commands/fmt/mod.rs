@@ -0,0 +1,30 @@
+use crate::{report::Report, Error};
+
+pub mod config;
+mod error;
+
+#[derive(clap::Parser)]
+#[command(arg_required_else_help = true)]
+/// Format project files
+pub struct Command {
+    #[command(subcommand)]
+    commands: Subcommands,
+
+    #[clap(flatten)]
+    global: crate::GlobalArgs,
+}
+
+#[derive(clap::Subcommand)]
+enum Subcommands {
+    Config(config::Command),
+}
+
+/// Execute the fmt command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(cmd: &Command) -> Result<Report, Error> {
+    match &cmd.commands {
+        Subcommands::Config(cmd) => config::config(cmd),
+    }
+}
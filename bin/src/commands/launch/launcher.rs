@@ -1,4 +1,5 @@
 use std::{
+    fmt::Write,
     path::{Path, PathBuf},
     process::Child,
 };
@@ -11,6 +12,7 @@ use crate::{
         error::{bcle1_preset_not_found::PresetNotFound, bcle4_arma_not_found::ArmaNotFound},
         preset,
     },
+    link::create_link,
     report::Report,
     Error,
 };
@@ -24,6 +26,9 @@ use super::{
     LaunchArgs,
 };
 
+/// The port the dedicated server listens on, and the clients connect to
+const SERVER_PORT: u16 = 2302;
+
 pub struct Launcher {
     executable: String,
     dlc: Vec<DLC>,
@@ -32,6 +37,9 @@ pub struct Launcher {
     arma3: PathBuf,
     mission: Option<String>,
     instances: u8,
+    profiles: Vec<String>,
+    server: bool,
+    headless: u8,
     file_patching: bool,
 }
 
@@ -52,6 +60,9 @@ impl Launcher {
         debug!("Arma 3 found at: {}", arma3.display());
         let mut launcher = Self {
             instances: launch.instances.unwrap_or_else(|| options.instances()),
+            profiles: options.profiles().to_vec(),
+            server: options.server() || launch.server,
+            headless: options.headless(),
             file_patching: options.file_patching() && !launch.no_filepatching,
             executable: options.executable(),
             dlc: options.dlc().to_vec(),
@@ -193,14 +204,14 @@ impl Launcher {
         }
         args.extend(self.options.clone());
 
-        if let Some(mission) = &self.mission {
-            let mut path = PathBuf::from(mission);
+        let mission = if let Some(name) = &self.mission {
+            let mut path = PathBuf::from(name);
 
             if path.is_absolute() {
-                report.push(MissionAbsolutePath::code(mission.to_string()));
+                report.push(MissionAbsolutePath::code(name.clone()));
                 return Ok(None);
             }
-            path = std::env::current_dir()?.join(mission);
+            path = std::env::current_dir()?.join(name);
 
             if !path.ends_with("mission.sqm") {
                 path.push("mission.sqm");
@@ -210,28 +221,64 @@ impl Launcher {
                 path = std::env::current_dir()?
                     .join(".hemtt")
                     .join("missions")
-                    .join(mission)
+                    .join(name)
                     .join("mission.sqm");
             }
 
-            if path.is_file() {
-                args.push(format!("\"{}\"", path.display()));
-            } else {
+            if !path.is_file() {
                 report.push(MissionNotFound::code(
-                    mission.to_string(),
+                    name.clone(),
                     &std::env::current_dir()?,
                 ));
                 return Ok(None);
             }
-        }
+            Some(path)
+        } else {
+            None
+        };
 
         let mut instances = Vec::new();
-        for _ in 0..self.instances {
+        if self.server {
+            let mut server = args.clone();
+            server.push("-server".to_string());
+            server.push(format!("-port={SERVER_PORT}"));
+            server.push(format!(
+                "-config=\"{}\"",
+                self.server_config(mission.as_deref())?.display()
+            ));
+            if mission.is_some() {
+                server.push("-autoInit".to_string());
+            }
+            if self.file_patching {
+                server.push("-filePatching".to_string());
+            }
+            instances.push(server);
+        } else if let Some(mission) = &mission {
+            args.push(format!("\"{}\"", mission.display()));
+        }
+
+        for instance in 0..self.instances {
             let mut args = args.clone();
-            // if with_server {
-            if false {
+            if let Some(profile) = self.profiles.get(instance as usize) {
+                args.push(format!("-name={profile}"));
+            }
+            if self.server {
                 args.push("-connect=127.0.0.1".to_string());
-            } else if self.file_patching {
+                args.push(format!("-port={SERVER_PORT}"));
+            }
+            if self.file_patching {
+                args.push("-filePatching".to_string());
+            }
+            instances.push(args);
+        }
+
+        for headless in 0..self.headless {
+            let mut args = args.clone();
+            args.push("-client".to_string());
+            args.push(format!("-name=hc_{headless}"));
+            args.push("-connect=127.0.0.1".to_string());
+            args.push(format!("-port={SERVER_PORT}"));
+            if self.file_patching {
                 args.push("-filePatching".to_string());
             }
             instances.push(args);
@@ -258,6 +305,47 @@ impl Launcher {
         }
     }
 
+    /// Writes the config for the dedicated server, linking the mission
+    /// into the `MPMissions` folder so the server can load it
+    ///
+    /// # Errors
+    /// [`Error::Io`] if the config could not be written or the mission could not be linked
+    fn server_config(&self, mission: Option<&Path>) -> Result<PathBuf, Error> {
+        let mut config = String::from("hostname = \"HEMTT\";\npersistent = 1;\n");
+        if self.file_patching {
+            config.push_str("allowedFilePatching = 2;\n");
+        }
+        if self.headless > 0 {
+            config.push_str("headlessClients[] = {\"127.0.0.1\"};\n");
+            config.push_str("localClient[] = {\"127.0.0.1\"};\n");
+        }
+        if let Some(folder) = mission.and_then(Path::parent) {
+            let template = folder
+                .file_name()
+                .expect("mission folder has a name")
+                .to_string_lossy()
+                .to_string();
+            let missions = self.arma3.join("MPMissions");
+            std::fs::create_dir_all(&missions)?;
+            let link = missions.join(&template);
+            if !link.exists() {
+                create_link(&link, &folder.to_path_buf())?;
+            }
+            writeln!(
+                config,
+                "class Missions {{\n    class hemtt {{\n        template = \"{template}\";\n        difficulty = \"Custom\";\n    }};\n}};"
+            )
+            .expect("can write to a string");
+        }
+        let path = std::env::current_dir()?
+            .join(".hemttout")
+            .join("launch")
+            .join("server.cfg");
+        std::fs::create_dir_all(path.parent().expect("server config has a parent"))?;
+        std::fs::write(&path, config)?;
+        Ok(path)
+    }
+
     #[must_use]
     pub fn arma3dir(&self) -> &Path {
         &self.arma3
@@ -76,6 +76,17 @@ pub mod preset;
 /// binarize = true # Default: false
 /// rapify = false # Default: true
 ///
+/// # Launched with `hemtt launch test`
+/// [hemtt.launch.test]
+/// extends = "default"
+/// server = true # Default: false
+/// instances = 2 # Default: 1
+/// headless = 1 # Default: 0
+/// profiles = [
+///     "alpha",
+///     "bravo",
+/// ]
+///
 /// # Launched with `hemtt launch vn`
 /// [hemtt.launch.vn]
 /// extends = "default"
@@ -149,6 +160,9 @@ pub mod preset;
 /// or the relative (to the project root) path to a `mission.sqm`
 /// file or a folder containing it.
 ///
+/// When launching with a dedicated server, the mission is linked into the
+/// `MPMissions` folder of Arma 3 and loaded by the server instead.
+///
 /// ### parameters
 ///
 /// A list of [Startup Parameters](https://community.bistudio.com/wiki/Arma_3:_Startup_Parameters) to pass to the Arma 3 executable.
@@ -171,6 +185,24 @@ pub mod preset;
 /// ### rapify
 ///
 /// Provides the ability to disable rapify for the launch command. Equivalent to `--no-rap`.
+///
+/// ### instances
+///
+/// The number of clients to launch. Equivalent to `--instances`.
+///
+/// ### profiles
+///
+/// The Arma 3 profiles to use, in the order of the instances.
+/// Instances without a profile use the last profile used in Arma 3.
+///
+/// ### server
+///
+/// Whether to launch a dedicated server, with all the instances connecting to it. Equivalent to `--server`.
+///
+/// ### headless
+///
+/// The number of headless clients to connect to the dedicated server.
+/// Launching any headless clients implies `server`.
 pub struct Command {
     #[clap(flatten)]
     launch: LaunchArgs,
@@ -222,6 +254,11 @@ pub struct LaunchArgs {
     ///
     /// If unspecified, it will default to 1.
     instances: Option<u8>,
+    #[arg(long)]
+    /// Launches a dedicated server, and connects the instances to it
+    ///
+    /// Overrides the `server` option in the configuration file.
+    server: bool,
     #[arg(long = "quick", short = 'Q')]
     /// Skips the build step, launching the last built version
     ///
@@ -269,10 +306,12 @@ pub fn execute(cmd: &Command) -> Result<Report, Error> {
 
     if cmd.launch.no_build {
         warn!("Using Quick Launch! HEMTT will not rebuild the project");
-        if !std::env::current_dir()?.join(".hemttout/dev").exists() {
-            report.push(CanNotQuickLaunch::code(
-                "no dev build found in .hemttout/dev".to_string(),
-            ));
+        let dev_folder = Path::new(cmd.global.out().unwrap_or(".hemttout")).join("dev");
+        if !std::env::current_dir()?.join(&dev_folder).exists() {
+            report.push(CanNotQuickLaunch::code(format!(
+                "no dev build found in {}",
+                dev_folder.display()
+            )));
             return Ok(report);
         }
 
@@ -288,6 +327,7 @@ pub fn execute(cmd: &Command) -> Result<Report, Error> {
         let mut executor = super::dev::context(
             &cmd.dev,
             &cmd.just,
+            &cmd.global,
             launch.optionals(),
             launch.binarize(),
             launch.rapify(),
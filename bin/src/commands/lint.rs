@@ -0,0 +1,226 @@
+use std::{collections::HashSet, path::PathBuf, sync::Arc};
+
+use hemtt_preprocessor::Processor;
+use hemtt_sqf::{
+    analyze::analyze,
+    parser::{database::Database, ParserError},
+};
+use hemtt_workspace::{addons::Addon, WorkspacePath};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use vfs::VfsFileType;
+
+use crate::{
+    context::Context,
+    error::Error,
+    modules::{can_rapify, check_config},
+    report::Report,
+};
+
+#[derive(clap::Parser)]
+#[command(verbatim_doc_comment)]
+/// Checks specific files for errors
+///
+/// `hemtt lint` runs the same preprocessor, config, and SQF checks as
+/// [`hemtt check`](./check.md), but only on the files it is given,
+/// without running the other modules of a build.
+/// It is quick enough to run from a pre-commit hook or an editor.
+///
+/// ```bash
+/// hemtt lint addons/main/config.cpp "addons/main/functions/*.sqf"
+/// ```
+///
+/// Headers are checked through the configs that include them,
+/// so the diagnostics of a header are reported where it is used.
+/// Nothing is written to `.hemttout`.
+///
+/// Checks that need the whole project, such as unused functions
+/// and the `requiredVersion` of commands, are only run by `hemtt check`.
+pub struct Command {
+    #[arg(required = true, verbatim_doc_comment)]
+    /// Files to check, or glob patterns that match them
+    ///
+    /// Files that are not in an addon are skipped.
+    files: Vec<String>,
+
+    #[clap(flatten)]
+    pub(crate) report: crate::ReportArgs,
+
+    #[clap(flatten)]
+    global: crate::GlobalArgs,
+}
+
+/// Execute the lint command
+///
+/// # Errors
+/// [`Error`] if a pattern is invalid, or a file can not be read
+pub fn execute(cmd: &Command) -> Result<Report, Error> {
+    let ctx = Context::read_only()?;
+    let root = std::fs::canonicalize(ctx.project_folder())?;
+    let database = Arc::new(Database::a3_with_workspace(ctx.workspace_path(), false)?);
+    let mut report = Report::new();
+    let mut count = 0;
+    let mut checked = HashSet::new();
+    let mut headers = Vec::new();
+    for file in expand(&cmd.files)? {
+        let absolute = std::fs::canonicalize(&file)?;
+        let Ok(relative) = absolute.strip_prefix(&root) else {
+            warn!("`{}` is not in the project, skipping", file.display());
+            continue;
+        };
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        let Some(addon) = ctx
+            .addons()
+            .iter()
+            .find(|addon| relative.starts_with(&format!("{}/", addon.folder())))
+        else {
+            warn!("`{relative}` is not in an addon, skipping");
+            continue;
+        };
+        let path = ctx.workspace_path().join(&relative)?;
+        if path.extension().as_deref() == Some("sqf") {
+            report.merge(sqf(&ctx, addon, &path, database.clone())?);
+        } else if let Some(config) = check_config(addon, &path, &ctx)? {
+            report.merge(config);
+        } else {
+            headers.push(path);
+            continue;
+        }
+        checked.insert(relative);
+        count += 1;
+    }
+    for (addon, config) in including(&ctx, &headers)? {
+        if checked.insert(config.as_str().trim_start_matches('/').to_string()) {
+            debug!("checking `{config}`, it includes a changed file");
+            if let Some(config) = check_config(addon, &config, &ctx)? {
+                report.merge(config);
+                count += 1;
+            }
+        }
+    }
+    info!("Checked {count} files");
+    Ok(report.with_fail_on(ctx.config().hemtt().check().fail_on()))
+}
+
+/// The files given to the command, with glob patterns expanded
+fn expand(patterns: &[String]) -> Result<Vec<PathBuf>, Error> {
+    let mut files = Vec::new();
+    for pattern in patterns {
+        let path = PathBuf::from(pattern);
+        if path.is_file() {
+            files.push(path);
+            continue;
+        }
+        let before = files.len();
+        for entry in glob::glob(pattern)? {
+            let entry = entry?;
+            if entry.is_file() {
+                files.push(entry);
+            }
+        }
+        if files.len() == before {
+            warn!("`{pattern}` does not match any files");
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// The configs that include any of the headers, so the headers are checked where they are used
+///
+/// Configs that can not be preprocessed are included, the error may be in one of the headers
+fn including<'a>(
+    ctx: &'a Context,
+    headers: &[WorkspacePath],
+) -> Result<Vec<(&'a Addon, WorkspacePath)>, Error> {
+    if headers.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut configs = Vec::new();
+    for addon in ctx.addons() {
+        for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
+            if entry.metadata()?.file_type == VfsFileType::File && can_rapify(entry.as_str()) {
+                configs.push((addon, entry));
+            }
+        }
+    }
+    let configs = configs
+        .into_par_iter()
+        .filter_map(|(addon, config)| {
+            let included = Processor::run(&config).map_or_else(
+                |_| headers.iter().collect(),
+                |processed| {
+                    headers
+                        .iter()
+                        .filter(|header| {
+                            processed
+                                .includes()
+                                .iter()
+                                .any(|include| include.file() == *header)
+                        })
+                        .collect::<Vec<_>>()
+                },
+            );
+            (!included.is_empty()).then_some((addon, config, included))
+        })
+        .collect::<Vec<_>>();
+    for header in headers {
+        if !configs
+            .iter()
+            .any(|(_, _, included)| included.contains(&header))
+        {
+            warn!("`{header}` is not included by any config, skipping");
+        }
+    }
+    Ok(configs
+        .into_iter()
+        .map(|(addon, config, _)| (addon, config))
+        .collect())
+}
+
+/// Preprocess, parse, and analyze an SQF file
+fn sqf(
+    ctx: &Context,
+    addon: &Addon,
+    path: &WorkspacePath,
+    database: Arc<Database>,
+) -> Result<Report, Error> {
+    let mut report = Report::new();
+    let processed = match Processor::run(path).map_err(|(_, e)| e) {
+        Ok(processed) => processed,
+        Err(hemtt_preprocessor::Error::Code(code)) => {
+            report.push(code);
+            return Ok(report);
+        }
+        Err(e) => return Err(e.into()),
+    };
+    for warning in processed.warnings() {
+        report.push(warning.clone());
+    }
+    match hemtt_sqf::parser::run(&database, &processed) {
+        Ok(sqf) => {
+            report.extend(analyze(
+                &sqf,
+                Some(ctx.addon_config(addon)),
+                &processed,
+                Arc::new(addon.clone()),
+                database,
+            ));
+        }
+        Err(ParserError::ParsingError(e)) => {
+            if processed.as_str().starts_with("force ") || processed.as_str().contains("\nforce ") {
+                warn!("skipping apparent CBA settings file: {}", path);
+            } else {
+                for error in e {
+                    report.push(error);
+                }
+            }
+        }
+        Err(ParserError::LexingError(e)) => {
+            for error in e {
+                report.push(error);
+            }
+        }
+    }
+    Ok(report)
+}
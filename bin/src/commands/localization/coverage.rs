@@ -6,6 +6,8 @@ use tabled::{settings::Style, Table, Tabled};
 
 use crate::{context::Context, report::Report, Error, TableFormat};
 
+use super::error::bclo1_below_threshold::BelowThreshold;
+
 #[derive(clap::Parser)]
 #[allow(clippy::module_name_repetitions)]
 /// Generate a coverage report
@@ -14,10 +16,32 @@ use crate::{context::Context, report::Report, Error, TableFormat};
 /// language localization in the project. Showing the
 /// percentage, total strings, and how many
 /// addons have gaps in their localization.
+///
+/// With `--threshold`, the command fails if any language
+/// that has been started is below the given percentage,
+/// to catch missing translations in CI.
 pub struct Command {
     #[arg(long, default_value = "ascii")]
     /// Output format
     format: TableFormat,
+    #[arg(long, conflicts_with = "format")]
+    /// Output compact json, same as `--format json`
+    json: bool,
+    #[arg(long, value_parser = threshold)]
+    /// Fail if any language is below this percentage of coverage
+    threshold: Option<f64>,
+}
+
+fn threshold(value: &str) -> Result<f64, String> {
+    let threshold = value
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .map_err(|e| e.to_string())?;
+    if (0.0..=100.0).contains(&threshold) {
+        Ok(threshold)
+    } else {
+        Err("must be between 0 and 100".to_string())
+    }
 }
 
 macro_rules! missing {
@@ -37,6 +61,7 @@ macro_rules! row {
                 $table.push(Entry {
                     language: first_capital(stringify!($lang)),
                     percent: Percentage(f64::from($global.$lang()) / f64::from($global.total()) * 100.0),
+                    strings: Count { translated: $global.$lang(), total: $global.total() },
                     missing: MissingAddons($missing.get(stringify!($lang)).cloned().unwrap_or_default())
                 });
             }
@@ -75,12 +100,26 @@ impl std::fmt::Display for Percentage {
     }
 }
 
+#[derive(Serialize)]
+pub struct Count {
+    translated: u32,
+    total: u32,
+}
+
+impl std::fmt::Display for Count {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} / {}", self.translated, self.total)
+    }
+}
+
 #[derive(Tabled, Serialize)]
 struct Entry {
     #[tabled(rename = "Language")]
     language: String,
     #[tabled(rename = "Coverage %")]
     percent: Percentage,
+    #[tabled(rename = "Strings")]
+    strings: Count,
     #[tabled(rename = "Addons")]
     missing: MissingAddons,
 }
@@ -92,8 +131,9 @@ struct Entry {
 ///
 /// # Panics
 /// If json serialization fails
+#[allow(clippy::too_many_lines)]
 pub fn coverage(cmd: &Command) -> Result<Report, Error> {
-    let ctx = Context::new(None, crate::context::PreservePrevious::Remove, true)?;
+    let ctx = Context::new(None, crate::context::PreservePrevious::Remove, None, true)?;
 
     let mut global = Totals::default();
     let mut missing = HashMap::new();
@@ -171,7 +211,12 @@ pub fn coverage(cmd: &Command) -> Result<Report, Error> {
     row!(table, global, missing, finnish);
     row!(table, global, missing, dutch);
 
-    match cmd.format {
+    let format = if cmd.json {
+        TableFormat::Json
+    } else {
+        cmd.format.clone()
+    };
+    match format {
         TableFormat::Ascii => {
             println!("{}", Table::new(&table).with(Style::modern()));
         }
@@ -192,5 +237,17 @@ pub fn coverage(cmd: &Command) -> Result<Report, Error> {
         }
     }
 
-    Ok(Report::new())
+    let mut report = Report::new();
+    if let Some(threshold) = cmd.threshold {
+        for entry in &table {
+            if entry.percent.0 < threshold {
+                report.push(BelowThreshold::code(
+                    entry.language.clone(),
+                    entry.percent.0,
+                    threshold,
+                ));
+            }
+        }
+    }
+    Ok(report)
 }
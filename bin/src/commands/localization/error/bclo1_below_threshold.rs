@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct BelowThreshold {
+    language: String,
+    percent: f64,
+    threshold: f64,
+}
+
+impl Code for BelowThreshold {
+    fn ident(&self) -> &'static str {
+        "BCLO1"
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "{} coverage is {:.2}%, below the threshold of {:.2}%",
+            self.language, self.percent, self.threshold
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "add the missing `{}` entries, or lower `--threshold`",
+            self.language
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl BelowThreshold {
+    pub fn code(language: String, percent: f64, threshold: f64) -> Arc<dyn Code> {
+        Arc::new(Self {
+            language,
+            percent,
+            threshold,
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+pub struct InvalidTranslations {
+    file: String,
+    reason: String,
+}
+
+impl Code for InvalidTranslations {
+    fn ident(&self) -> &'static str {
+        "BCLO2"
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` is not a valid translations file", self.file)
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(self.reason.clone())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("create the file with `hemtt localization export`".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl InvalidTranslations {
+    pub fn code(file: String, reason: String) -> Arc<dyn Code> {
+        Arc::new(Self { file, reason })
+    }
+}
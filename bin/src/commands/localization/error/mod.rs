@@ -0,0 +1,2 @@
+pub mod bclo1_below_threshold;
+pub mod bclo2_invalid_translations;
@@ -0,0 +1,100 @@
+use std::{io::BufReader, path::PathBuf};
+
+use hemtt_stringtable::Project;
+
+use crate::{context::Context, report::Report, Error};
+
+use super::translations::{keys, languages, stringtables, Format, Row};
+
+#[derive(clap::Parser)]
+#[allow(clippy::module_name_repetitions)]
+#[command(verbatim_doc_comment)]
+/// Export the stringtables for translators
+///
+/// Writes every key of every stringtable to a single CSV or JSON file,
+/// with one column for each language that is used in the project.
+///
+/// Texts are exported as they are written in the XML, entities
+/// such as `&lt;` are kept as they are.
+///
+/// The file can be edited and merged back with [`hemtt localization import`](./import.md).
+pub struct Command {
+    /// File to write, such as `translations.csv`
+    output: String,
+    #[arg(long)]
+    /// Format of the file, taken from the extension if not set
+    format: Option<Format>,
+    #[arg(long, action = clap::ArgAction::Append)]
+    /// Only export these languages, `Original` is always exported
+    lang: Vec<String>,
+}
+
+/// Export the stringtables
+///
+/// # Errors
+/// [`Error`] if a stringtable can not be read, or the file can not be written
+pub fn export(cmd: &Command) -> Result<Report, Error> {
+    let ctx = Context::new(None, crate::context::PreservePrevious::Remove, None, true)?;
+
+    let mut rows = Vec::new();
+    for (file, path) in stringtables(ctx.project_folder())? {
+        let project = match Project::from_reader(BufReader::new(std::fs::File::open(&path)?)) {
+            Ok(project) => project,
+            Err(e) => {
+                error!("Failed to read stringtable for {}", path.display());
+                error!("{:?}", e);
+                return Ok(Report::new());
+            }
+        };
+        for package in project.packages() {
+            for key in keys(package) {
+                rows.push(Row {
+                    file: file.clone(),
+                    key: key.id().to_string(),
+                    entries: languages()
+                        .filter(|language| {
+                            *language == "Original"
+                                || cmd.lang.is_empty()
+                                || cmd.lang.iter().any(|l| l.eq_ignore_ascii_case(language))
+                        })
+                        .filter_map(|language| {
+                            key.get(language)
+                                .map(|text| (language.to_string(), text.to_string()))
+                        })
+                        .collect(),
+                });
+            }
+        }
+    }
+
+    let output = PathBuf::from(&cmd.output);
+    match Format::resolve(cmd.format, &output) {
+        Format::Csv => {
+            let columns = languages()
+                .filter(|language| {
+                    rows.iter()
+                        .any(|row| row.entries.iter().any(|(l, _)| l == language))
+                        || cmd.lang.iter().any(|l| l.eq_ignore_ascii_case(language))
+                })
+                .collect::<Vec<_>>();
+            let mut writer = csv::Writer::from_path(&output)?;
+            writer.write_record(["file", "key"].iter().chain(columns.iter()))?;
+            for row in &rows {
+                writer.write_record([row.file.as_str(), row.key.as_str()].into_iter().chain(
+                    columns.iter().map(|language| {
+                        row.entries
+                            .iter()
+                            .find(|(l, _)| l == language)
+                            .map_or("", |(_, text)| text.as_str())
+                    }),
+                ))?;
+            }
+            writer.flush()?;
+        }
+        Format::Json => {
+            std::fs::write(&output, serde_json::to_string_pretty(&rows)?)?;
+        }
+    }
+    info!("Exported {} keys to `{}`", rows.len(), output.display());
+    Ok(Report::new())
+}
@@ -0,0 +1,181 @@
+use std::{collections::HashMap, io::BufReader, path::PathBuf};
+
+use hemtt_stringtable::{Package, Project};
+
+use crate::{context::Context, report::Report, Error};
+
+use super::{
+    error::bclo2_invalid_translations::InvalidTranslations,
+    translations::{languages, stringtables, Format, Row},
+};
+
+#[derive(clap::Parser)]
+#[allow(clippy::module_name_repetitions)]
+#[command(verbatim_doc_comment)]
+/// Import translations into the stringtables
+///
+/// Reads a CSV or JSON file created by [`hemtt localization export`](./export.md),
+/// and updates the keys in the stringtables with the texts from the file.
+///
+/// Only the texts of existing keys are changed, the order of the keys
+/// and the comments in the stringtables are kept.
+/// Empty texts are ignored, they do not remove the existing text.
+pub struct Command {
+    /// File to read, such as `translations.csv`
+    input: String,
+    #[arg(long)]
+    /// Format of the file, taken from the extension if not set
+    format: Option<Format>,
+}
+
+/// Import translations into the stringtables
+///
+/// # Errors
+/// [`Error`] if a stringtable or the file can not be read, or a stringtable can not be written
+pub fn import(cmd: &Command) -> Result<Report, Error> {
+    let ctx = Context::new(None, crate::context::PreservePrevious::Remove, None, true)?;
+
+    let mut report = Report::new();
+    let input = PathBuf::from(&cmd.input);
+    let rows = match Format::resolve(cmd.format, &input) {
+        Format::Csv => read_csv(&input)?,
+        Format::Json => read_json(&input)?,
+    };
+    let rows = match rows {
+        Ok(rows) => rows,
+        Err(reason) => {
+            report.push(InvalidTranslations::code(cmd.input.clone(), reason));
+            return Ok(report);
+        }
+    };
+
+    let mut files: HashMap<String, HashMap<String, Vec<(String, String)>>> = HashMap::new();
+    for row in rows {
+        let mut entries = Vec::new();
+        for (language, text) in row.entries {
+            if text.is_empty() {
+                continue;
+            }
+            let Some(language) = languages().find(|l| l.eq_ignore_ascii_case(&language)) else {
+                warn!("Ignoring unknown language `{language}` for `{}`", row.key);
+                continue;
+            };
+            entries.push((language.to_string(), text));
+        }
+        files
+            .entry(row.file)
+            .or_default()
+            .insert(row.key.to_lowercase(), entries);
+    }
+
+    let mut updated = 0;
+    for (file, path) in stringtables(ctx.project_folder())? {
+        let Some(mut keys) = files.remove(&file) else {
+            continue;
+        };
+        let mut project = match Project::from_reader(BufReader::new(std::fs::File::open(&path)?)) {
+            Ok(project) => project,
+            Err(e) => {
+                error!("Failed to read stringtable for {}", path.display());
+                error!("{:?}", e);
+                return Ok(report);
+            }
+        };
+        let mut changed = 0;
+        for package in project.packages_mut() {
+            changed += apply(package, &mut keys);
+        }
+        for key in keys.keys() {
+            warn!("Key `{key}` was not found in `{file}`");
+        }
+        if changed == 0 {
+            continue;
+        }
+        let mut writer = String::new();
+        if let Err(e) = project.to_writer(&mut writer) {
+            error!("Failed to write stringtable for {}", path.display());
+            error!("{:?}", e);
+            return Ok(report);
+        }
+        std::fs::write(&path, writer)?;
+        info!("Updated {changed} texts in `{file}`");
+        updated += changed;
+    }
+    for file in files.keys() {
+        warn!("Stringtable `{file}` was not found");
+    }
+    info!("Updated {updated} texts");
+    Ok(report)
+}
+
+/// Update the keys of a package and its containers,
+/// removing the keys that were found
+fn apply(package: &mut Package, keys: &mut HashMap<String, Vec<(String, String)>>) -> usize {
+    let mut changed = 0;
+    for key in package.keys_mut() {
+        let Some(entries) = keys.remove(&key.id().to_lowercase()) else {
+            continue;
+        };
+        for (language, text) in entries {
+            if key.get(&language) != Some(text.as_str()) {
+                key.set(&language, Some(text));
+                changed += 1;
+            }
+        }
+    }
+    for container in package.containers_mut() {
+        changed += apply(container, keys);
+    }
+    changed
+}
+
+fn read_csv(path: &PathBuf) -> Result<Result<Vec<Row>, String>, Error> {
+    let mut reader = csv::Reader::from_path(path)?;
+    let headers = reader.headers()?.clone();
+    let (Some(file), Some(key)) = (
+        headers.iter().position(|h| h == "file"),
+        headers.iter().position(|h| h == "key"),
+    ) else {
+        return Ok(Err(
+            "the first row must name the `file` and `key` columns".to_string()
+        ));
+    };
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        rows.push(Row {
+            file: record.get(file).unwrap_or_default().to_string(),
+            key: record.get(key).unwrap_or_default().to_string(),
+            entries: headers
+                .iter()
+                .zip(record.iter())
+                .enumerate()
+                .filter(|(i, _)| *i != file && *i != key)
+                .map(|(_, (language, text))| (language.to_string(), text.to_string()))
+                .collect(),
+        });
+    }
+    Ok(Ok(rows))
+}
+
+fn read_json(path: &PathBuf) -> Result<Result<Vec<Row>, String>, Error> {
+    let objects: Vec<serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_reader(BufReader::new(std::fs::File::open(path)?))?;
+    let mut rows = Vec::new();
+    for mut object in objects {
+        let (Some(serde_json::Value::String(file)), Some(serde_json::Value::String(key))) =
+            (object.remove("file"), object.remove("key"))
+        else {
+            return Ok(Err("every object must have a `file` and `key`".to_string()));
+        };
+        let mut entries = Vec::new();
+        for (language, text) in object {
+            let serde_json::Value::String(text) = text else {
+                return Ok(Err(format!("`{language}` of `{key}` is not a string")));
+            };
+            entries.push((language, text));
+        }
+        rows.push(Row { file, key, entries });
+    }
+    Ok(Ok(rows))
+}
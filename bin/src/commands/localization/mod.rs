@@ -1,7 +1,11 @@
 use crate::{report::Report, Error};
 
 pub mod coverage;
+mod error;
+pub mod export;
+pub mod import;
 pub mod sort;
+mod translations;
 
 #[derive(clap::Parser)]
 #[command(arg_required_else_help = true)]
@@ -16,7 +20,10 @@ pub struct Command {
 
 #[derive(clap::Subcommand)]
 enum Subcommands {
+    #[command(visible_alias = "report")]
     Coverage(coverage::Command),
+    Export(export::Command),
+    Import(import::Command),
     Sort(sort::Command),
 }
 
@@ -27,6 +34,8 @@ enum Subcommands {
 pub fn execute(cmd: &Command) -> Result<Report, Error> {
     match &cmd.commands {
         Subcommands::Coverage(cmd) => coverage::coverage(cmd),
+        Subcommands::Export(cmd) => export::export(cmd),
+        Subcommands::Import(cmd) => import::import(cmd),
         Subcommands::Sort(cmd) => sort::sort(cmd),
     }
 }
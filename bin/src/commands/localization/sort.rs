@@ -26,7 +26,7 @@ pub struct Command {
 /// # Errors
 /// [`Error`] depending on the modules
 pub fn sort(cmd: &Command) -> Result<Report, Error> {
-    let ctx = Context::new(None, crate::context::PreservePrevious::Remove, true)?;
+    let ctx = Context::new(None, crate::context::PreservePrevious::Remove, None, true)?;
 
     for root in ["addons", "optionals"] {
         if !ctx.project_folder().join(root).exists() {
@@ -0,0 +1,91 @@
+use std::path::{Path, PathBuf};
+
+use hemtt_stringtable::{Key, Package, ALL_LANGUAGES};
+use serde::ser::{SerializeMap, Serializer};
+
+use crate::Error;
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+/// File format for translators
+pub enum Format {
+    /// One row per key, one column per language
+    Csv,
+    /// An array with one object per key
+    Json,
+}
+
+impl Format {
+    /// The format from `--format`, or the extension of the file
+    pub fn resolve(format: Option<Self>, path: &Path) -> Self {
+        format.unwrap_or_else(|| {
+            if path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+            {
+                Self::Json
+            } else {
+                Self::Csv
+            }
+        })
+    }
+}
+
+/// A key, and the stringtable it is in
+pub struct Row {
+    /// The stringtable, relative to the project
+    pub file: String,
+    pub key: String,
+    /// The language and the text, `Original` first
+    pub entries: Vec<(String, String)>,
+}
+
+impl serde::Serialize for Row {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.entries.len() + 2))?;
+        map.serialize_entry("file", &self.file)?;
+        map.serialize_entry("key", &self.key)?;
+        for (language, text) in &self.entries {
+            map.serialize_entry(language, text)?;
+        }
+        map.end()
+    }
+}
+
+/// `Original` and every supported language, in the order of the wiki
+pub fn languages() -> impl Iterator<Item = &'static str> {
+    std::iter::once("Original").chain(ALL_LANGUAGES.iter().copied())
+}
+
+/// Every `stringtable.xml` in `addons` and `optionals`,
+/// with its path relative to the project
+pub fn stringtables(project: &Path) -> Result<Vec<(String, PathBuf)>, Error> {
+    let mut stringtables = Vec::new();
+    for root in ["addons", "optionals"] {
+        if !project.join(root).exists() {
+            continue;
+        }
+        for entry in walkdir::WalkDir::new(project.join(root)) {
+            let entry = entry?;
+            if entry.file_name() == "stringtable.xml" {
+                let relative = entry
+                    .path()
+                    .strip_prefix(project)
+                    .expect("stringtable is in the project")
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                stringtables.push((relative, entry.path().to_path_buf()));
+            }
+        }
+    }
+    stringtables.sort();
+    Ok(stringtables)
+}
+
+/// Every key in a package and its containers, in the order they are written
+pub fn keys(package: &Package) -> Vec<&Key> {
+    let mut keys = package.keys().iter().collect::<Vec<_>>();
+    for container in package.containers() {
+        keys.extend(self::keys(container));
+    }
+    keys
+}
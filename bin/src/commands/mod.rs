@@ -1,14 +1,24 @@
+use hemtt_workspace::addons::Addon;
+
+use crate::{context::Context, error::Error};
+
+pub mod add;
 pub mod book;
 pub mod build;
 pub mod check;
+pub mod config;
 pub mod dev;
+pub mod fmt;
 pub mod launch;
+pub mod lint;
 pub mod localization;
 pub mod new;
+pub mod publish;
 pub mod release;
 pub mod script;
 pub mod utils;
 pub mod value;
+pub mod watch;
 pub mod wiki;
 
 #[cfg(windows)]
@@ -19,16 +29,66 @@ pub mod photoshoot;
 /// - hemtt dev
 /// - hemtt build
 /// - hemtt release
+///
+/// The [`crate::modules::SQFCompiler`] is added by each command after the
+/// [`crate::modules::Rapifier`], its lints need the `requiredVersion` of each addon.
+/// The [`crate::modules::Stringtables`] are added after both, they check the keys used by configs and scripts
 pub fn global_modules(executor: &mut crate::executor::Executor) {
     executor.add_module(Box::<crate::modules::bom::BOMCheck>::default());
     executor.add_module(Box::<crate::modules::Hooks>::default());
-    executor.add_module(Box::<crate::modules::Stringtables>::default());
-    executor.add_module(Box::<crate::modules::SQFCompiler>::default());
+    for module in crate::modules::MODULES {
+        executor.add_module(module());
+    }
 }
 
 #[derive(clap::Args)]
 pub struct JustArgs {
-    #[arg(long, action = clap::ArgAction::Append)]
+    #[arg(long, visible_alias = "only", action = clap::ArgAction::Append)]
     /// Only build the given addon
     pub(crate) just: Vec<String>,
+    #[arg(long, action = clap::ArgAction::Append)]
+    /// Do not build the given addon
+    pub(crate) exclude: Vec<String>,
+}
+
+impl JustArgs {
+    /// Are only some of the addons built
+    pub(crate) const fn is_partial(&self) -> bool {
+        !self.just.is_empty() || !self.exclude.is_empty()
+    }
+
+    /// Should the addon be built
+    pub(crate) fn includes(&self, addon: &Addon) -> bool {
+        (self.just.is_empty()
+            || self
+                .just
+                .iter()
+                .any(|j| j.eq_ignore_ascii_case(addon.name())))
+            && !self
+                .exclude
+                .iter()
+                .any(|e| e.eq_ignore_ascii_case(addon.name()))
+    }
+
+    /// Check that every addon given exists in the project
+    ///
+    /// # Errors
+    /// [`Error::Addon`] if an addon is not found
+    pub(crate) fn validate(&self, ctx: &Context) -> Result<(), Error> {
+        for name in self.just.iter().chain(&self.exclude) {
+            if !ctx
+                .all_addons()
+                .iter()
+                .any(|a| a.name().eq_ignore_ascii_case(name))
+            {
+                return Err(Error::Addon(hemtt_workspace::addons::Error::NotFound(
+                    name.clone(),
+                )));
+            }
+        }
+        if self.is_partial() {
+            warn!("Building only some addons is not recommended, only do it if you know what you're doing");
+        }
+        Ok(())
+    }
 }
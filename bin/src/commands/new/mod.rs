@@ -11,7 +11,7 @@ use crate::{
         bcne1_not_terminal::TerminalNotInput, bcne2_folder_exists::FolderExists,
     },
     error::Error,
-    modules::Licenses,
+    modules::{Licenses, Template},
     report::Report,
 };
 
@@ -30,6 +30,11 @@ mod error;
 /// - The prefix of your mod  
 /// - The main prefix of your mod  
 /// - A license for your mod  
+/// - A template for the `main` addon, using only Arma 3, CBA, or ACE  
+///
+/// The `main` addon is created with a `$PBOPREFIX$`, `config.cpp`,
+/// `script_component.hpp`, and `script_version.hpp` for the project version.
+/// The CBA and ACE templates also add extended event handlers.
 pub struct Command {
     #[clap(name = "name", verbatim_doc_comment)]
     /// The name of the new project
@@ -100,8 +105,15 @@ pub fn execute(cmd: &Command, in_test: bool) -> Result<Report, Error> {
         Licenses::select(&author)
     };
 
+    let template = if test_mode {
+        Template::Vanilla
+    } else {
+        Template::select()
+    };
+
     create_dir(path)?;
     create_dir(path.join("addons"))?;
+    template.write(path, &full_name, &author, &prefix, &mainprefix)?;
 
     git2::Repository::init(path)?;
 
@@ -124,5 +136,12 @@ pub fn execute(cmd: &Command, in_test: bool) -> Result<Report, Error> {
         file.write_all(license.as_bytes())?;
     }
 
+    if let Some(dependency) = template.dependency() {
+        println!(
+            "The {dependency} macros are required to build, add them to `include` or use a P Drive"
+        );
+        println!("See https://hemtt.dev/configuration/p-drive.html");
+    }
+
     Ok(report)
 }
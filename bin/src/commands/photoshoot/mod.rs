@@ -101,15 +101,24 @@ pub fn execute(cmd: &Command) -> Result<Report, Error> {
                 all_optionals: true,
                 binarize: false,
                 no_rap: false,
+                changed: false,
+            },
+            just: JustArgs {
+                just: Vec::new(),
+                exclude: Vec::new(),
             },
-            just: JustArgs { just: Vec::new() },
         },
         launch.optionals(),
     )?;
     if report.failed() {
         return Ok(report);
     }
-    let ctx = Context::new(Some("photoshoot"), PreservePrevious::Remove, false)?;
+    let ctx = Context::new(
+        Some("photoshoot"),
+        PreservePrevious::Remove,
+        cmd.global.out(),
+        false,
+    )?;
 
     let mut ps = Photoshoot::new(command, ctx.profile().join("Users/hemtt/Screenshots"));
 
@@ -0,0 +1,29 @@
+use crate::{report::Report, Error};
+
+pub mod workshop;
+
+#[derive(clap::Parser)]
+#[command(arg_required_else_help = true)]
+/// Publish the release of the project
+pub struct Command {
+    #[command(subcommand)]
+    commands: Subcommands,
+
+    #[clap(flatten)]
+    global: crate::GlobalArgs,
+}
+
+#[derive(clap::Subcommand)]
+enum Subcommands {
+    Workshop(workshop::Command),
+}
+
+/// Execute the publish command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(cmd: &Command) -> Result<Report, Error> {
+    match &cmd.commands {
+        Subcommands::Workshop(workshop) => workshop::workshop(workshop, &cmd.global),
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    io::Write,
+    path::Path,
+    process::{ExitStatus, Stdio},
+};
+
+use crate::{context::Context, modules::github::changelog_section, report::Report, Error};
+
+/// The app id of Arma 3 on Steam
+const ARMA3_APP_ID: u32 = 107_410;
+
+#[derive(clap::Parser)]
+#[command(verbatim_doc_comment)]
+/// Upload the release to the Steam Workshop
+///
+/// `hemtt publish workshop` uploads `.hemttout/release` to the workshop item
+/// set in `hemtt.release.publishedid`, using [SteamCMD](https://developer.valvesoftware.com/wiki/SteamCMD).
+/// Run [`hemtt release`](../release.md) first.
+///
+/// The item must already exist on the workshop, it can be created with the Arma 3 Publisher.
+///
+/// ## Configuration
+///
+/// ```toml
+/// [hemtt.release]
+/// publishedid = 463939057
+///
+/// [hemtt.publish]
+/// steamcmd = "C:/steamcmd/steamcmd.exe" # Default: "steamcmd"
+/// username = "banana" # Default: `STEAM_USERNAME`
+/// changenote = "{name} {version}\n\n{changelog}" # Default: "{changelog}"
+/// ```
+///
+/// ### username
+///
+/// The Steam account to upload with. It can also be set with the `STEAM_USERNAME` environment variable.
+/// The password is read from `STEAM_PASSWORD` and given to `SteamCMD` when it asks for it, it is never passed as an argument.
+/// If it is not set `SteamCMD` will use its saved login or ask for it.
+///
+/// ### changenote
+///
+/// The change notes of the update. `{name}` and `{version}` are replaced with the values of the project,
+/// and `{changelog}` with the section for the version in `hemtt.release.changelog`, the same notes as
+/// [GitHub releases](../release.md#github-releases).
+pub struct Command {
+    #[arg(long)]
+    /// Workshop item to upload to, instead of `hemtt.release.publishedid`
+    id: Option<u64>,
+
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    /// Write the `SteamCMD` script without uploading
+    dry_run: bool,
+}
+
+/// Execute the workshop command
+///
+/// # Errors
+/// [`Error::Workshop`] if the upload can not be started or fails
+/// [`Error::Io`] if the script can not be written
+///
+/// # Panics
+/// If the script path has no parent, which it always has
+pub fn workshop(cmd: &Command, global: &crate::GlobalArgs) -> Result<Report, Error> {
+    let ctx = Context::new(
+        None,
+        crate::context::PreservePrevious::Keep,
+        global.out(),
+        true,
+    )?;
+    let Some(id) = cmd
+        .id
+        .or_else(|| ctx.config().hemtt().release().publishedid())
+    else {
+        return Err(Error::Workshop(
+            "no workshop item, set `hemtt.release.publishedid` or use `--id`".to_string(),
+        ));
+    };
+    let release = ctx.out_folder().join("release");
+    if !release.exists() {
+        return Err(Error::Workshop(format!(
+            "`{}` does not exist, run `hemtt release` first",
+            release.display()
+        )));
+    }
+
+    let version = ctx.config().version().get(ctx.workspace_path().vfs())?;
+    let changelog = ctx
+        .project_folder()
+        .join(ctx.config().hemtt().release().changelog());
+    let notes = if changelog.exists() {
+        let content = std::fs::read_to_string(&changelog)?;
+        changelog_section(&content, &version.to_string())
+            .or_else(|| {
+                changelog_section(
+                    &content,
+                    &format!(
+                        "{}.{}.{}",
+                        version.major(),
+                        version.minor(),
+                        version.patch()
+                    ),
+                )
+            })
+            .unwrap_or_default()
+    } else {
+        String::new()
+    };
+    let changenote = ctx.config().hemtt().publish().changenote(
+        ctx.config().name(),
+        &version.to_string(),
+        &notes,
+    );
+
+    let script = ctx.out_folder().join("publish").join("workshop.vdf");
+    std::fs::create_dir_all(script.parent().expect("script has a parent"))?;
+    std::fs::write(
+        &script,
+        workshop_vdf(id, &std::path::absolute(&release)?, &changenote),
+    )?;
+    if cmd.dry_run {
+        info!("Wrote `{}`", script.display());
+        return Ok(Report::new());
+    }
+
+    let Some(username) = std::env::var("STEAM_USERNAME")
+        .ok()
+        .filter(|username| !username.is_empty())
+        .or_else(|| {
+            ctx.config()
+                .hemtt()
+                .publish()
+                .username()
+                .map(ToString::to_string)
+        })
+    else {
+        return Err(Error::Workshop(
+            "no Steam account, set `hemtt.publish.username` or `STEAM_USERNAME`".to_string(),
+        ));
+    };
+    info!("Uploading `{}` to workshop item {id}", release.display());
+    let status = steamcmd(
+        ctx.config().hemtt().publish().steamcmd(),
+        &username,
+        &std::path::absolute(&script)?,
+    )?;
+    if !status.success() {
+        return Err(Error::Workshop(format!("SteamCMD exited with {status}")));
+    }
+    info!("Updated https://steamcommunity.com/sharedfiles/filedetails/?id={id}");
+    Ok(Report::new())
+}
+
+/// Run the `SteamCMD` script, giving it the password from `STEAM_PASSWORD` when it asks for it
+fn steamcmd(steamcmd: &str, username: &str, script: &Path) -> Result<ExitStatus, Error> {
+    let password = std::env::var("STEAM_PASSWORD")
+        .ok()
+        .filter(|password| !password.is_empty());
+    let mut command = std::process::Command::new(steamcmd);
+    command
+        .arg("+login")
+        .arg(username)
+        .arg("+workshop_build_item")
+        .arg(script)
+        .arg("+quit");
+    if password.is_some() {
+        command.stdin(Stdio::piped());
+    }
+    let mut child = command
+        .spawn()
+        .map_err(|e| Error::Workshop(format!("failed to run `{steamcmd}`: {e}")))?;
+    if let Some(password) = password {
+        // arguments can be seen by other users, so the password is only written to stdin
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        if let Err(e) = writeln!(stdin, "{password}") {
+            debug!("SteamCMD did not read the password: {e}");
+        }
+    }
+    Ok(child.wait()?)
+}
+
+#[must_use]
+/// The `SteamCMD` `workshop_build_item` script to update an item
+pub fn workshop_vdf(id: u64, content: &Path, changenote: &str) -> String {
+    let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!(
+        "\"workshopitem\"\n{{\n\t\"appid\"\t\"{ARMA3_APP_ID}\"\n\t\"publishedfileid\"\t\"{id}\"\n\t\"contentfolder\"\t\"{}\"\n\t\"changenote\"\t\"{}\"\n}}\n",
+        escape(&content.display().to_string()),
+        escape(changenote),
+    )
+}
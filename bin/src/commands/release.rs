@@ -1,4 +1,9 @@
-use crate::{context::Context, error::Error, modules::Sign, report::Report};
+use crate::{
+    context::Context,
+    error::Error,
+    modules::{GitHubRelease, Meta, Sign},
+    report::Report,
+};
 
 use super::build;
 
@@ -11,7 +16,7 @@ use super::build;
 ///
 /// It is intended to be used for releasing your mod.
 ///
-/// It will create two zip archives in the `releases` folder: - `{name}-latest.zip` - `{name}-{version}.zip`
+/// It will create two zip archives in the `releases` folder: - `{prefix}-latest.zip` - `{prefix}-{version}.zip`
 ///
 /// ## Configuration
 ///
@@ -21,6 +26,15 @@ use super::build;
 /// [hemtt.release]
 /// sign = false # Default: true
 /// archive = false # Default: true
+/// archive_name = "{name}-{version}" # Default: "{prefix}-{version}"
+/// archive_tar = true # Default: false
+/// publishedid = 463939057 # Default: none
+/// minify = true # Default: false
+/// exclude = ["*.hpp"] # Default: none
+/// defines = ["RELEASE", "LOG_LEVEL=1"] # Default: none
+///
+/// [hemtt.release.rename]
+/// "functions/fnc_internal.sqf" = "functions/a.sqf"
 /// ```
 ///
 /// ### sign
@@ -36,6 +50,78 @@ use super::build;
 /// ### archive
 ///
 /// If `archive` is set to `false`, a zip archive will not be created. The output will be in `.hemttout/release`.
+///
+/// ### `archive_name`
+///
+/// The name of the versioned archive, without an extension.
+/// `{name}`, `{prefix}`, and `{version}` are replaced with the values of the project.
+/// The `{prefix}-latest` archive is always created with the same contents.
+///
+/// ### `archive_tar`
+///
+/// If `archive_tar` is set to `true`, a `.tar.gz` archive is created next to each zip archive.
+///
+/// ### `publishedid`
+///
+/// The id of the mod on the Steam Workshop.
+/// A `meta.cpp` is created in the release if the project does not have one,
+/// and the `publishedid` of an existing `meta.cpp` is kept up to date.
+///
+/// ### `minify`
+///
+/// If `minify` is set to `true`, comments and the whitespace around each line are removed
+/// from `sqf`, `hpp`, `h`, `inc`, `ext`, and `cpp` files that are not rapified.
+/// Line breaks are kept, so line numbers in errors still match the source.
+///
+/// ### `exclude`
+///
+/// Files to leave out of the released PBOs, as globs relative to each addon.
+/// They are still packed by `hemtt dev` and `hemtt build`.
+///
+/// ```admonish warning
+/// Only exclude headers that are not included at runtime, such as by a `config.cpp` that is not rapified
+/// or a script compiled with `#include`.
+/// ```
+///
+/// ### `rename`
+///
+/// Files to rename in the released PBOs, relative to each addon.
+/// Anything that refers to the file by its path must use the new name.
+///
+/// ### `defines`
+///
+/// Macros defined for every file preprocessed by `hemtt release` and `hemtt build`,
+/// as `NAME`, defined as `1`, or `NAME=VALUE`.
+/// `hemtt dev` and `hemtt launch` use the `defines` of [`[hemtt.dev]`](dev.md) instead.
+///
+/// ### `mod.cpp` and `meta.cpp`
+///
+/// `{name}`, `{prefix}`, `{version}`, and `{publishedid}` in the `mod.cpp` and `meta.cpp`
+/// of the project are replaced with the values of the project in the release.
+/// Quotes in the values are escaped, and lines with `{publishedid}` are left out
+/// when no `publishedid` is set.
+///
+/// ```cpp
+/// name = "{name}";
+/// tooltip = "{name} {version}";
+/// ```
+///
+/// ## GitHub Releases
+///
+/// With `--draft`, a draft GitHub release is created after the archives, with the versioned zip archive attached.
+/// The release is tagged `v{major}.{minor}.{patch}`, with `.{build}` if the version has a build number,
+/// and is only published once it is reviewed on GitHub.
+///
+/// The token is read from the `GITHUB_TOKEN` or `GH_TOKEN` environment variable, and needs permission to create releases.
+///
+/// ```toml
+/// [hemtt.release]
+/// github = "BrettMayson/HEMTT" # Default: the `origin` remote
+/// changelog = "docs/CHANGELOG.md" # Default: "CHANGELOG.md"
+/// ```
+///
+/// The notes of the release are the section of the changelog under the heading with the version,
+/// such as `## v1.2.3` or `## [1.2.3] - 2024-01-01`, up to the next heading of the same level.
 pub struct Command {
     #[clap(flatten)]
     build: build::BuildArgs,
@@ -43,6 +129,9 @@ pub struct Command {
     #[clap(flatten)]
     release: ReleaseArgs,
 
+    #[clap(flatten)]
+    pub(crate) report: crate::ReportArgs,
+
     #[clap(flatten)]
     global: crate::GlobalArgs,
 }
@@ -64,6 +153,11 @@ pub struct ReleaseArgs {
     ///
     /// The output will be in `.hemttout/release`.
     no_archive: bool,
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    /// Create a draft GitHub release with the zip archive.
+    ///
+    /// The token is read from `GITHUB_TOKEN` or `GH_TOKEN`.
+    draft: bool,
 }
 
 /// Execute the release command
@@ -73,10 +167,13 @@ pub struct ReleaseArgs {
 pub fn execute(cmd: &Command) -> Result<Report, Error> {
     let ctx = Context::new(
         Some("release"),
-        crate::context::PreservePrevious::Remove,
+        cmd.global
+            .preserve_previous(crate::context::PreservePrevious::Remove),
+        cmd.global.out(),
         true,
     )?;
     let mut executor = build::executor(ctx, &cmd.build);
+    executor.add_module(Box::<Meta>::default());
 
     if !cmd.release.no_sign && executor.ctx().config().hemtt().release().sign() {
         executor.add_module(Box::new(Sign::new()));
@@ -88,7 +185,18 @@ pub fn execute(cmd: &Command) -> Result<Report, Error> {
         executor.ctx().config().hemtt().release().archive()
     };
 
+    if cmd.release.draft {
+        if !archive {
+            return Err(Error::GitHub(
+                "`--draft` needs the archive, which is disabled".to_string(),
+            ));
+        }
+        executor.add_module(Box::<GitHubRelease>::default());
+    }
+
     executor.release(archive);
 
+    let fail_on = executor.ctx().config().hemtt().check().fail_on();
+    executor.fail_on(fail_on);
     executor.run()
 }
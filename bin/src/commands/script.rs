@@ -30,7 +30,9 @@ pub struct Command {
 pub fn execute(cmd: &Command) -> Result<Report, Error> {
     let ctx = Context::new(
         Some("script"),
-        crate::context::PreservePrevious::Remove,
+        cmd.global
+            .preserve_previous(crate::context::PreservePrevious::Remove),
+        cmd.global.out(),
         true,
     )?;
     Hooks::run_file(&ctx, &cmd.name).map(|(report, _)| report)
@@ -15,10 +15,16 @@ pub struct Command {
 enum Subcommands {
     Inspect(utils::inspect::Command),
     Config(utils::config::Command),
+    Deps(utils::deps::Command),
+    Includes(utils::includes::Command),
     Paa(utils::paa::Command),
     Pbo(utils::pbo::Command),
+    Preprocess(utils::preprocess::Command),
+    Sign(utils::sign::Command),
     Sqf(utils::sqf::Command),
+    Sqm(utils::sqm::Command),
     Verify(utils::verify::Command),
+    VerifySigns(utils::verify_signs::Command),
 }
 
 /// Execute the utils command
@@ -33,18 +39,36 @@ pub fn execute(cmd: &Command) -> Result<Report, Error> {
         Subcommands::Config(cmd) => {
             utils::config::execute(cmd)?;
         }
+        Subcommands::Deps(cmd) => {
+            utils::deps::execute(cmd)?;
+        }
+        Subcommands::Includes(cmd) => {
+            utils::includes::execute(cmd)?;
+        }
         Subcommands::Paa(cmd) => {
             utils::paa::execute(cmd)?;
         }
         Subcommands::Pbo(cmd) => {
             utils::pbo::execute(cmd)?;
         }
+        Subcommands::Preprocess(cmd) => {
+            utils::preprocess::execute(cmd)?;
+        }
+        Subcommands::Sign(cmd) => {
+            utils::sign::execute(cmd)?;
+        }
         Subcommands::Sqf(cmd) => {
             utils::sqf::execute(cmd)?;
         }
+        Subcommands::Sqm(cmd) => {
+            utils::sqm::execute(cmd)?;
+        }
         Subcommands::Verify(cmd) => {
             utils::verify::execute(cmd)?;
         }
+        Subcommands::VerifySigns(cmd) => {
+            return utils::verify_signs::execute(cmd);
+        }
     }
     Ok(Report::new())
 }
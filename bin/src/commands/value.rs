@@ -19,7 +19,7 @@ pub struct Command {
 /// [`Error`] depending on the modules
 pub fn execute(cmd: &Command) -> Result<Report, Error> {
     let default = String::new();
-    let ctx = Context::new(None, crate::context::PreservePrevious::Remove, false)?;
+    let ctx = Context::new(None, crate::context::PreservePrevious::Remove, None, false)?;
     match cmd.name.as_str() {
         "project.name" => {
             println!("{}", ctx.config().name());
@@ -0,0 +1,135 @@
+use std::{collections::HashSet, path::Path, sync::mpsc, time::Duration, time::Instant};
+
+use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode};
+
+use crate::{context::Context, error::Error, report::Report};
+
+use super::{
+    dev::{self, DevArgs},
+    JustArgs,
+};
+
+#[derive(clap::Parser)]
+#[command(verbatim_doc_comment)]
+/// Build the project for development, and rebuild it when files change
+///
+/// `hemtt watch` creates the same `dev` version of your mod as
+/// [`hemtt dev`](dev.md), then keeps running and rebuilds
+/// only the addons whose files have changed.
+///
+/// Changes to the include folders rebuild every addon, the include folders are
+/// `include`, `hemtt.preprocessor.include_paths`, and `hemtt.preprocessor.mounts`.
+/// Changes to `.hemtt`, and new or removed addons, require
+/// `hemtt watch` to be restarted.
+pub struct Command {
+    #[clap(flatten)]
+    pub(crate) dev: DevArgs,
+
+    #[clap(flatten)]
+    pub(crate) global: crate::GlobalArgs,
+}
+
+/// Execute the watch command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+/// [`Error::Notify`] if the project can not be watched
+pub fn execute(cmd: &Command) -> Result<Report, Error> {
+    let mut executor = dev::context(
+        &cmd.dev,
+        &JustArgs {
+            just: Vec::new(),
+            exclude: Vec::new(),
+        },
+        &cmd.global,
+        &[],
+        false,
+        true,
+    )?;
+    let report = executor.run()?;
+    if report.failed() {
+        return Ok(report);
+    }
+    report.write_to_stdout();
+    let ctx = executor.ctx().clone();
+
+    let (tx, rx) = mpsc::channel();
+    let mut debouncer = new_debouncer(Duration::from_millis(250), tx)?;
+    for path in ["addons", "optionals"]
+        .into_iter()
+        .map(|folder| ctx.project_folder().join(folder))
+        .chain(ctx.include_folders())
+    {
+        if path.is_dir() {
+            debouncer.watcher().watch(&path, RecursiveMode::Recursive)?;
+        }
+    }
+    info!("Watching for changes, press Ctrl+C to stop");
+
+    for events in rx {
+        let events = match events {
+            Ok(events) => events,
+            Err(e) => {
+                error!("File watcher error: {e}");
+                continue;
+            }
+        };
+        let changed = changed_addons(&ctx, events.iter().map(|event| event.path.as_path()));
+        if changed.is_empty() {
+            continue;
+        }
+        info!(
+            "Rebuilding {}",
+            changed.iter().cloned().collect::<Vec<_>>().join(", ")
+        );
+        let start = Instant::now();
+        let rebuild = match ctx.reset() {
+            Ok(rebuild) => rebuild.filter(|addon, _| changed.contains(addon.name())),
+            Err(e) => {
+                error!("Rebuild failed: {e}");
+                continue;
+            }
+        };
+        match executor.rerun(rebuild) {
+            Ok(report) => {
+                report.write_to_stdout();
+                if !report.failed() {
+                    info!("Rebuilt in {:.2?}", start.elapsed());
+                }
+            }
+            Err(e) => error!("Rebuild failed: {e}"),
+        }
+    }
+    Ok(Report::new())
+}
+
+#[must_use]
+/// The names of the addons affected by the changed paths,
+/// every addon if a file in an include folder changed
+pub fn changed_addons<'a>(ctx: &Context, paths: impl Iterator<Item = &'a Path>) -> HashSet<String> {
+    // include paths and mounts can point outside of the project, such as `../common`
+    let includes = ctx
+        .include_folders()
+        .into_iter()
+        .flat_map(|folder| [folder.canonicalize().ok(), Some(folder)])
+        .flatten()
+        .collect::<Vec<_>>();
+    let mut changed = HashSet::new();
+    for path in paths {
+        if includes.iter().any(|include| path.starts_with(include)) {
+            return ctx
+                .addons()
+                .iter()
+                .map(|addon| addon.name().to_string())
+                .collect();
+        }
+        if let Some(addon) = ctx
+            .addons()
+            .iter()
+            .find(|addon| path.starts_with(ctx.project_folder().join(addon.folder_pathbuf())))
+        {
+            changed.insert(addon.name().to_string());
+        }
+    }
+    changed
+}
@@ -1,12 +1,13 @@
 use std::{
+    collections::HashMap,
     env::temp_dir,
     fs::{create_dir_all, remove_dir_all},
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::Arc,
 };
 
 use hemtt_common::config::ProjectConfig;
-use hemtt_workspace::{addons::Addon, LayerType, Workspace, WorkspacePath};
+use hemtt_workspace::{addons::Addon, LayerType, Workspace, WorkspaceBuilder, WorkspacePath};
 
 use crate::error::Error;
 
@@ -24,6 +25,8 @@ pub type State = state::TypeMap![Send + Sync];
 #[derive(Debug, Clone)]
 pub struct Context {
     config: ProjectConfig,
+    /// The config of each addon with an `addon.toml`, keyed by the addon folder
+    addon_configs: Arc<HashMap<String, ProjectConfig>>,
     folder: Option<String>,
     addons: Vec<Addon>,
     all_addons: Vec<Addon>,
@@ -32,6 +35,8 @@ pub struct Context {
     hemtt_folder: PathBuf,
     out_folder: PathBuf,
     build_folder: Option<PathBuf>,
+    cache_folder: Option<PathBuf>,
+    deterministic: bool,
     tmp: PathBuf,
     profile: PathBuf,
     state: Arc<State>,
@@ -51,33 +56,12 @@ impl Context {
     pub fn new(
         folder: Option<&str>,
         preserve_previous: PreservePrevious,
+        out: Option<&str>,
         print_info: bool,
     ) -> Result<Self, Error> {
         let root = std::env::current_dir()?;
-        let config = {
-            let path = root.join(".hemtt").join("project.toml");
-            if !path.exists() {
-                return Err(Error::ConfigNotFound);
-            }
-            ProjectConfig::from_file(&path)?
-        };
-        let tmp = {
-            let mut tmp = temp_dir().join("hemtt");
-            // on linux add the user to the path for multiple users
-            if !cfg!(target_os = "windows") {
-                tmp = tmp.join(whoami::username());
-            }
-            tmp
-        };
-        let profile = tmp.join("profile");
-        let tmp = tmp.join(
-            root.components()
-                .skip(2)
-                .collect::<PathBuf>()
-                .to_str()
-                .expect("valid utf-8")
-                .replace(['\\', '/'], "_"),
-        );
+        let config = project_config(&root)?;
+        let (tmp, profile) = tmp_folders(&root);
         if tmp.exists() {
             remove_dir_all(&tmp)?;
         }
@@ -87,10 +71,12 @@ impl Context {
         if !hemtt_folder.exists() {
             return Err(Error::ConfigNotFound);
         }
-        let out_folder = root.join(".hemttout");
+        let hemttout = root.join(".hemttout");
+        create_dir_all(&hemttout)?;
+        std::fs::File::create(hemttout.join("ci_annotations.txt"))?;
+        let out_folder = out.map_or(hemttout, |out| root.join(out));
         trace!("using out folder: {:?}", out_folder.display());
         create_dir_all(&out_folder)?;
-        std::fs::File::create(out_folder.join("ci_annotations.txt"))?;
         let mut builder = Workspace::builder().physical(&root, LayerType::Source);
         let mut maybe_build_folder = None;
         if let Some(folder) = folder {
@@ -101,10 +87,12 @@ impl Context {
             }
             create_dir_all(&build_folder)?;
             builder = builder.physical(&tmp.join("hemtt_binarize_output"), LayerType::Build);
-            let include = root.join("include");
-            if include.is_dir() {
-                builder = builder.physical(&include, LayerType::Include);
-            }
+            builder = include_layers(builder, &root, &config);
+            builder = match folder {
+                "dev" => builder.defines(config.hemtt().dev().defines()),
+                "build" | "release" => builder.defines(config.hemtt().release().defines()),
+                _ => builder,
+            };
             maybe_build_folder = Some(build_folder);
         };
         let workspace = builder.memory().finish(
@@ -119,6 +107,7 @@ impl Context {
         version_check(&config, &workspace, print_info)?;
         let addons = Addon::scan(&root)?;
         Ok(Self {
+            addon_configs: addon_configs(&config, &addons),
             config,
             folder: folder.map(std::borrow::ToOwned::to_owned),
             workspace,
@@ -128,12 +117,71 @@ impl Context {
             hemtt_folder,
             out_folder,
             build_folder: maybe_build_folder,
+            cache_folder: None,
+            deterministic: false,
             tmp,
             profile,
             state: Arc::new(State::default()),
         })
     }
 
+    /// Create a context that only reads the project, for checking files without a build
+    ///
+    /// Nothing is created or removed in `.hemttout` or the temporary folder,
+    /// and the version of the project is not checked
+    ///
+    /// # Errors
+    /// [`Error::ConfigNotFound`] if the project.toml is not found
+    /// [`Error::Workspace`] if the workspace can not be created
+    ///
+    /// # Panics
+    /// If the project folder is not a valid [`OsStr`] (UTF-8)
+    pub fn read_only() -> Result<Self, Error> {
+        let root = std::env::current_dir()?;
+        let config = project_config(&root)?;
+        let (tmp, profile) = tmp_folders(&root);
+        let hemtt_folder = root.join(".hemtt");
+        let builder = include_layers(
+            Workspace::builder().physical(&root, LayerType::Source),
+            &root,
+            &config,
+        );
+        let workspace =
+            builder
+                .memory()
+                .finish(Some(config.clone()), true, config.hemtt().check().pdrive())?;
+        let addons = Addon::scan(&root)?;
+        Ok(Self {
+            addon_configs: addon_configs(&config, &addons),
+            config,
+            folder: Some(String::from("check")),
+            workspace,
+            all_addons: addons.clone(),
+            addons,
+            out_folder: root.join(".hemttout"),
+            project_folder: root,
+            hemtt_folder,
+            build_folder: None,
+            cache_folder: None,
+            deterministic: false,
+            tmp,
+            profile,
+            state: Arc::new(State::default()),
+        })
+    }
+
+    /// A context for rebuilding the project, without the files or state of the last build
+    ///
+    /// # Errors
+    /// [`Error::Workspace`] if the files of the last build can not be removed
+    pub fn reset(&self) -> Result<Self, Error> {
+        self.workspace().clear_memory()?;
+        Ok(Self {
+            state: Arc::new(State::default()),
+            ..self.clone()
+        })
+    }
+
     #[must_use]
     pub fn filter<F>(self, mut filter: F) -> Self
     where
@@ -150,11 +198,46 @@ impl Context {
         }
     }
 
+    #[must_use]
+    /// Enable the cache in `.hemttout/cache`
+    pub fn with_cache(self, enabled: bool) -> Self {
+        Self {
+            cache_folder: enabled.then(|| self.project_folder.join(".hemttout").join("cache")),
+            ..self
+        }
+    }
+
+    #[must_use]
+    /// Use a cache shared with other projects, if the cache is enabled
+    pub fn with_shared_cache(self, folder: &Path) -> Self {
+        Self {
+            cache_folder: self.cache_folder.map(|_| folder.to_path_buf()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    /// Write PBOs that only depend on their contents
+    pub fn with_deterministic(self, enabled: bool) -> Self {
+        Self {
+            deterministic: enabled,
+            ..self
+        }
+    }
+
     #[must_use]
     pub const fn config(&self) -> &ProjectConfig {
         &self.config
     }
 
+    #[must_use]
+    /// The config used for an addon, with the lint overrides of its `addon.toml`
+    pub fn addon_config(&self, addon: &Addon) -> &ProjectConfig {
+        self.addon_configs
+            .get(&addon.folder())
+            .unwrap_or(&self.config)
+    }
+
     #[must_use]
     pub const fn folder(&self) -> Option<&String> {
         self.folder.as_ref()
@@ -170,6 +253,17 @@ impl Context {
         &self.all_addons
     }
 
+    #[must_use]
+    /// The folders searched for includes outside of the addons,
+    /// `include`, `hemtt.preprocessor.include_paths`, and `hemtt.preprocessor.mounts`
+    pub fn include_folders(&self) -> Vec<PathBuf> {
+        include_folders(&self.project_folder, &self.config)
+            .into_iter()
+            .map(|(_, _, folder)| folder)
+            .filter(|folder| folder.is_dir())
+            .collect()
+    }
+
     #[must_use]
     pub fn addon(&self, name: &str) -> Option<&Addon> {
         self.addons.iter().find(|a| a.name() == name)
@@ -198,7 +292,7 @@ impl Context {
     }
 
     #[must_use]
-    /// The .hemttout folder
+    /// The .hemttout folder, or the folder passed with `--out`
     pub const fn out_folder(&self) -> &PathBuf {
         &self.out_folder
     }
@@ -209,6 +303,18 @@ impl Context {
         self.build_folder.as_ref()
     }
 
+    #[must_use]
+    /// The .hemttout/cache folder, [`None`] if the cache is disabled
+    pub const fn cache_folder(&self) -> Option<&PathBuf> {
+        self.cache_folder.as_ref()
+    }
+
+    #[must_use]
+    /// Are PBOs written so the same sources always produce the same bytes
+    pub const fn deterministic(&self) -> bool {
+        self.deterministic
+    }
+
     #[must_use]
     /// %temp%/hemtt/project
     pub const fn tmp(&self) -> &PathBuf {
@@ -228,6 +334,99 @@ impl Context {
     }
 }
 
+/// The config of the project, from `.hemtt/project.toml`
+fn project_config(root: &Path) -> Result<ProjectConfig, Error> {
+    let path = root.join(".hemtt").join("project.toml");
+    if !path.exists() {
+        return Err(Error::ConfigNotFound);
+    }
+    Ok(ProjectConfig::from_file(&path)?)
+}
+
+/// The config of each addon with an `addon.toml`, created once and shared by every file of the addon
+fn addon_configs(config: &ProjectConfig, addons: &[Addon]) -> Arc<HashMap<String, ProjectConfig>> {
+    Arc::new(
+        addons
+            .iter()
+            .filter_map(|addon| {
+                addon
+                    .config()
+                    .map(|addon_config| (addon.folder(), config.with_addon(addon_config)))
+            })
+            .collect(),
+    )
+}
+
+/// The temporary folder of the project, and the folder of the launch profile
+fn tmp_folders(root: &Path) -> (PathBuf, PathBuf) {
+    let mut tmp = temp_dir().join("hemtt");
+    // on linux add the user to the path for multiple users
+    if !cfg!(target_os = "windows") {
+        tmp = tmp.join(whoami::username());
+    }
+    let profile = tmp.join("profile");
+    let tmp = tmp.join(
+        root.components()
+            .skip(2)
+            .collect::<PathBuf>()
+            .to_str()
+            .expect("valid utf-8")
+            .replace(['\\', '/'], "_"),
+    );
+    (tmp, profile)
+}
+
+fn include_layers(
+    mut builder: WorkspaceBuilder,
+    root: &Path,
+    config: &ProjectConfig,
+) -> WorkspaceBuilder {
+    // the first folder is `include`, which is optional
+    for (index, (prefix, path, folder)) in include_folders(root, config).into_iter().enumerate() {
+        match (prefix, folder.is_dir()) {
+            (None, true) => builder = builder.physical(&folder, LayerType::Include),
+            (Some(prefix), true) => builder = builder.mount(prefix, &folder),
+            (None, false) if index > 0 => warn!(
+                "`{path}` from `hemtt.preprocessor.include_paths` is not a folder, it will not be searched"
+            ),
+            (Some(prefix), false) => warn!(
+                "`{path}` from `hemtt.preprocessor.mounts` is not a folder, it will not be mounted at `{prefix}`"
+            ),
+            (None, false) => {}
+        }
+    }
+    builder
+}
+
+/// The folders searched for includes outside of the addons, with the prefix of each mount
+///
+/// `include`, then `hemtt.preprocessor.include_paths`, then `hemtt.preprocessor.mounts`,
+/// as the path from the project config and the folder it points to
+fn include_folders<'a>(
+    root: &Path,
+    config: &'a ProjectConfig,
+) -> Vec<(Option<&'a str>, &'a str, PathBuf)> {
+    std::iter::once((None, "include"))
+        .chain(
+            config
+                .hemtt()
+                .preprocessor()
+                .include_paths()
+                .iter()
+                .map(|path| (None, path.as_str())),
+        )
+        .chain(
+            config
+                .hemtt()
+                .preprocessor()
+                .mounts()
+                .iter()
+                .map(|(prefix, path)| (Some(prefix.as_str()), path.as_str())),
+        )
+        .map(|(prefix, path)| (prefix, path, root.join(path)))
+        .collect()
+}
+
 fn version_check(
     config: &ProjectConfig,
     workspace: &WorkspacePath,
@@ -243,13 +442,15 @@ fn version_check(
         match e {
             hemtt_common::version::Error::UnknownVersion => {
                 error!("HEMTT was not able to determine the version of your project.");
-                println!("\nThere are two ways to define the version of your project");
+                println!("\nThere are three ways to define the version of your project");
                 println!(
                     "\n1. Macros inside `addons/main/script_version.hpp`, or a specified file"
                 );
                 println!("[version]\npath = \"addons/not_main/script_version.hpp\"");
                 println!("\n2. A `version` table in your project.toml");
                 println!("[version]\nmajor = 1\nminor = 0\npatch = 0\nbuild = 0\n");
+                println!("\n3. Git tags, such as `v1.0.0`, falling back to 1. or 2. without a tag");
+                println!("[version]\ngit = \"tag\"\n");
                 if supports_hyperlinks::on(supports_hyperlinks::Stream::Stdout) {
                     let link = terminal_link::Link::new(
                         "The HEMTT Book",
@@ -276,6 +477,10 @@ fn version_check(
                 error!("HEMTT is not able to determine the version of your project.");
                 println!("Encountered an invalid version component: {s}");
             }
+            hemtt_common::version::Error::InvalidTag(tag) => {
+                error!("HEMTT is not able to determine the version of your project.");
+                println!("The git tag `{tag}` is not a version, such as `v1.0.0`");
+            }
             hemtt_common::version::Error::VersionPathConflict => {
                 // The version path is defined, and a version table is defined
                 error!("HEMTT is not able to determine the source of the version.");
@@ -0,0 +1,19 @@
+//! Graphs in the DOT format, used by `graphviz`
+
+#[must_use]
+/// Quote a name as a DOT id, escaping characters that would end or change it
+pub fn id(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 2);
+    out.push('"');
+    for c in name.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -2,6 +2,8 @@
 pub enum Error {
     #[error("`.hemtt/project.toml` not found")]
     ConfigNotFound,
+    #[error("`hemtt-workspace.toml` not found")]
+    WorkspaceConfigNotFound,
 
     #[error("Unable to create link: {0}")]
     #[allow(dead_code)] // Unused on Linux and Mac
@@ -26,9 +28,17 @@ pub enum Error {
     #[error("Addon Error: {0}")]
     Addon(#[from] hemtt_workspace::addons::Error),
 
+    #[error("Config value error: {0}")]
+    ConfigValue(String),
     #[error("Update error: {0}")]
     Update(String),
+    #[error("GitHub error: {0}")]
+    GitHub(String),
+    #[error("Workshop error: {0}")]
+    Workshop(String),
 
+    #[error("CSV Error: {0}")]
+    Csv(#[from] csv::Error),
     #[error("Dialoguer Error: {0}")]
     Dialoguer(#[from] dialoguer::Error),
     #[error("Git Error: {0}")]
@@ -39,10 +49,16 @@ pub enum Error {
     GlobPattern(#[from] glob::PatternError),
     #[error("Image Error: {0}")]
     Image(#[from] image::ImageError),
+    #[error("File Watcher Error: {0}")]
+    Notify(#[from] notify_debouncer_mini::notify::Error),
     #[error("IO Error: {0}")]
     Io(#[from] std::io::Error),
     #[error("serde_json Error: {0}")]
     SerdeJson(#[from] serde_json::Error),
+    #[error("TOML Error: {0}")]
+    TomlDe(#[from] toml::de::Error),
+    #[error("TOML Error: {0}")]
+    TomlSer(#[from] toml::ser::Error),
     #[error("Vfs Error {0}")]
     Vfs(Box<vfs::VfsError>),
     #[error("Walkdir Error: {0}")]
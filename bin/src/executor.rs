@@ -1,9 +1,12 @@
+use hemtt_common::config::FailOn;
+
 use crate::error::Error;
 
 use crate::report::Report;
 use crate::{
     context::Context,
     modules::{self, pbo::Collapse, Module},
+    timings,
 };
 
 pub struct Executor {
@@ -11,6 +14,7 @@ pub struct Executor {
     modules: Vec<Box<dyn Module>>,
     collapse: Collapse,
     stages: Vec<&'static str>,
+    fail_on: FailOn,
 }
 
 impl Executor {
@@ -21,6 +25,7 @@ impl Executor {
             modules: Vec::new(),
             collapse: Collapse::Yes,
             stages: Vec::new(),
+            fail_on: FailOn::Errors,
         }
     }
 
@@ -38,6 +43,12 @@ impl Executor {
         self.collapse = collpase;
     }
 
+    /// Stop after the `check` and `pre_build` phases when their codes fail the policy,
+    /// before anything is written, archived, signed, or published
+    pub const fn fail_on(&mut self, fail_on: FailOn) {
+        self.fail_on = fail_on;
+    }
+
     pub fn add_module(&mut self, module: Box<dyn Module>) {
         self.modules.push(module);
     }
@@ -75,13 +86,14 @@ impl Executor {
     /// # Errors
     /// [`Error`] depending on the modules
     pub fn run(&mut self) -> Result<Report, Error> {
-        let mut report = Report::new();
+        let mut report = Report::new().with_fail_on(self.fail_on);
         for stage in self.stages.clone() {
             report.merge(match stage {
                 "init" => self.run_modules("init")?,
                 "check" => self.run_modules("check")?,
                 "pre_build" => self.run_modules("pre_build")?,
                 "build" => {
+                    let _span = timings::span("build", "PBO");
                     trace!("phase: build (start)");
                     let report = modules::pbo::build(&self.ctx, self.collapse)?;
                     trace!("phase: build (done)");
@@ -90,6 +102,7 @@ impl Executor {
                 "post_build" => self.run_modules("post_build")?,
                 "pre_release" => self.run_modules("pre_release")?,
                 "archive" => {
+                    let _span = timings::span("archive", "Archive");
                     trace!("phase: release (start)");
                     let report = modules::archive::release(&self.ctx)?;
                     trace!("phase: release (done)");
@@ -101,14 +114,33 @@ impl Executor {
             if report.failed() {
                 break;
             }
+            if matches!(stage, "check" | "pre_build") && report.failed_policy() {
+                warn!("stopping after `{stage}`, `fail_on` is set to warnings");
+                break;
+            }
         }
+        timings::write(&self.ctx.out_folder().join("timings.json"))?;
         Ok(report)
     }
 
+    /// Run every phase except `init` again, with a new context
+    ///
+    /// Modules keep anything they set up during `init`,
+    /// which makes rebuilding a few addons much faster than starting over
+    ///
+    /// # Errors
+    /// [`Error`] depending on the modules
+    pub fn rerun(&mut self, ctx: Context) -> Result<Report, Error> {
+        self.ctx = ctx;
+        self.stages.retain(|stage| *stage != "init");
+        self.run()
+    }
+
     fn run_modules(&mut self, phase: &str) -> Result<Report, Error> {
         let mut report = Report::new();
         for module in &mut self.modules {
             trace!("phase: {} ({}) (start)", phase, module.name());
+            let _span = timings::span("module", &format!("{phase} {}", module.name()));
             report.merge(match phase {
                 "init" => module.init(&self.ctx)?,
                 "check" => module.check(&self.ctx)?,
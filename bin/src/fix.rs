@@ -0,0 +1,73 @@
+//! Applying the machine-applicable fixes of lints to the source files
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::Path,
+};
+
+use hemtt_workspace::reporting::Fix;
+
+use crate::{report::Report, Error};
+
+/// Apply the fixes of the codes in the report to the files in the project
+///
+/// Fixed codes are removed from the report. Files from includes are never changed,
+/// and a fix is skipped if it overlaps another fix, or the file no longer matches.
+///
+/// Returns the number of fixes applied
+///
+/// # Errors
+/// [`Error::Io`] if a file cannot be read or written
+pub fn apply(project: &Path, report: &mut Report) -> Result<usize, Error> {
+    let mut files: BTreeMap<String, Vec<(usize, Fix)>> = BTreeMap::new();
+    for (index, code) in report.codes().iter().enumerate() {
+        let Some(fix) = code.fix() else {
+            continue;
+        };
+        if fix.file().is_include() {
+            continue;
+        }
+        files
+            .entry(fix.file().as_str().to_string())
+            .or_default()
+            .push((index, fix));
+    }
+    let mut fixed = HashSet::new();
+    let mut count = 0;
+    for (file, mut fixes) in files {
+        let path = project.join(file.trim_start_matches('/'));
+        if !path.is_file() {
+            continue;
+        }
+        let mut content = std::fs::read_to_string(&path)?;
+        // Apply from the end, so earlier spans are not moved
+        fixes.sort_by_key(|(_, fix)| std::cmp::Reverse(fix.span().start));
+        let mut applied: Vec<Fix> = Vec::new();
+        for (index, fix) in fixes {
+            if applied.contains(&fix) {
+                fixed.insert(index);
+                continue;
+            }
+            let span = fix.span().clone();
+            if applied.iter().any(|other| span.end > other.span().start)
+                || content.get(span.clone()) != Some(fix.original())
+            {
+                continue;
+            }
+            content.replace_range(span, fix.replacement());
+            fixed.insert(index);
+            applied.push(fix);
+        }
+        if !applied.is_empty() {
+            std::fs::write(&path, content)?;
+            debug!("applied {} fixes to {}", applied.len(), file);
+            count += applied.len();
+        }
+    }
+    let mut index = 0;
+    report.retain(|_| {
+        index += 1;
+        !fixed.contains(&(index - 1))
+    });
+    Ok(count)
+}
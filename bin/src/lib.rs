@@ -1,19 +1,24 @@
 use clap::CommandFactory;
 pub use error::Error;
+pub use linkme;
 
 #[macro_use]
 extern crate tracing;
 
+pub mod baseline;
 pub mod commands;
 pub mod context;
 pub mod controller;
+pub mod dot;
 pub mod error;
 pub mod executor;
+pub mod fix;
 pub mod link;
 pub mod logging;
 pub mod modules;
 mod progress;
 pub mod report;
+pub mod timings;
 pub mod update;
 pub mod utils;
 
@@ -30,6 +35,7 @@ pub struct Cli {
 }
 
 #[derive(Clone, clap::Args)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct GlobalArgs {
     #[arg(global = true, long, short)]
     /// Number of threads, defaults to # of CPUs
@@ -37,6 +43,25 @@ pub struct GlobalArgs {
     #[arg(global = true, short, action = clap::ArgAction::Count)]
     /// Verbosity level
     verbosity: u8,
+    #[arg(global = true, long, value_name = "DIR", verbatim_doc_comment)]
+    /// Folder to write output to, defaults to `.hemttout`
+    ///
+    /// Relative paths are resolved from the project root.
+    out: Option<String>,
+    #[arg(global = true, long, action = clap::ArgAction::SetTrue, overrides_with = "no_clean")]
+    /// Remove the previous output before building
+    clean: bool,
+    #[arg(global = true, long, action = clap::ArgAction::SetTrue, overrides_with = "clean")]
+    /// Keep the previous output, files that are built again are overwritten
+    no_clean: bool,
+    #[arg(global = true, long, action = clap::ArgAction::SetTrue)]
+    /// Do not use or update the cache in `.hemttout/cache`, `hemtt release` never uses the cache
+    no_cache: bool,
+    #[arg(global = true, long, action = clap::ArgAction::SetTrue, verbatim_doc_comment)]
+    /// Record the time spent in each phase, module, addon, and file
+    ///
+    /// Written to `timings.json` in the output folder, as a Chrome trace.
+    timings: bool,
     #[cfg(debug_assertions)]
     #[arg(global = true, long)]
     /// Directory to run in
@@ -47,20 +72,83 @@ pub struct GlobalArgs {
     in_test: bool,
 }
 
+impl GlobalArgs {
+    #[must_use]
+    /// The output folder passed with `--out`
+    pub fn out(&self) -> Option<&str> {
+        self.out.as_deref()
+    }
+
+    #[must_use]
+    /// Should unchanged files be loaded from the cache
+    pub const fn cache(&self) -> bool {
+        !self.no_cache
+    }
+
+    #[must_use]
+    /// Resolve `--clean` and `--no-clean` against the default of a command
+    pub const fn preserve_previous(
+        &self,
+        default: context::PreservePrevious,
+    ) -> context::PreservePrevious {
+        if self.clean {
+            context::PreservePrevious::Remove
+        } else if self.no_clean {
+            context::PreservePrevious::Keep
+        } else {
+            default
+        }
+    }
+}
+
+#[derive(Clone, clap::Args)]
+/// Arguments for commands that report warnings and errors in the project
+pub struct ReportArgs {
+    #[arg(long, default_value = "human", verbatim_doc_comment)]
+    /// Format of the warnings and errors
+    ///
+    /// With `json`, each one is printed to stdout as a json object on its own line,
+    /// and the log is printed to stderr.
+    format: ReportFormat,
+}
+
+impl ReportArgs {
+    #[must_use]
+    /// The format passed with `--format`
+    pub const fn format(&self) -> ReportFormat {
+        self.format
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// text for the terminal
+    #[default]
+    Human,
+    /// one json object per line, ideal for editors and CI
+    Json,
+}
+
 #[derive(clap::Subcommand)]
 enum Commands {
     Book(commands::book::Command),
     New(commands::new::Command),
+    Add(commands::add::Command),
     Check(commands::check::Command),
+    Config(commands::config::Command),
     Dev(commands::dev::Command),
+    Fmt(commands::fmt::Command),
     Launch(commands::launch::Command),
+    Lint(commands::lint::Command),
     Build(commands::build::Command),
     Release(commands::release::Command),
+    Publish(commands::publish::Command),
     #[clap(alias = "ln")]
     Localization(commands::localization::Command),
     Script(commands::script::Command),
     Utils(commands::utils::Command),
     Value(commands::value::Command),
+    Watch(commands::watch::Command),
     Wiki(commands::wiki::Command),
     #[cfg(windows)]
     Photoshoot(commands::photoshoot::Command),
@@ -91,9 +179,18 @@ pub fn execute(cli: &Cli) -> Result<(), Error> {
     #[cfg(not(debug_assertions))]
     let in_test = false;
 
-    if !in_test && !matches!(cli.command, Some(Commands::Value(_))) {
+    let format = match cli.command {
+        Some(Commands::Check(ref cmd)) => cmd.report.format(),
+        Some(Commands::Lint(ref cmd)) => cmd.report.format(),
+        Some(Commands::Build(ref cmd)) => cmd.report.format(),
+        Some(Commands::Release(ref cmd)) => cmd.report.format(),
+        _ => ReportFormat::Human,
+    };
+
+    if !in_test && !matches!(cli.command, Some(Commands::Config(_) | Commands::Value(_))) {
         logging::init(
             cli.global.verbosity,
+            format == ReportFormat::Json,
             !matches!(
                 cli.command,
                 Some(Commands::Utils(_) | Commands::Wiki(_) | Commands::New(_) | Commands::Book(_))
@@ -108,6 +205,10 @@ pub fn execute(cli: &Cli) -> Result<(), Error> {
 
     check_for_update();
 
+    if cli.global.timings {
+        timings::enable();
+    }
+
     trace!("version: {}", env!("HEMTT_VERSION"));
     trace!("platform: {}", std::env::consts::OS);
 
@@ -123,33 +224,32 @@ pub fn execute(cli: &Cli) -> Result<(), Error> {
         }
     }
 
-    let report = match cli.command.as_ref().expect("Handled above") {
-        Commands::Book(ref cmd) => commands::book::execute(cmd),
-        Commands::New(ref cmd) => commands::new::execute(cmd, in_test),
-        Commands::Check(ref cmd) => commands::check::execute(cmd),
-        Commands::Dev(ref cmd) => commands::dev::execute(cmd, &[]).map(|(r, _)| r),
-        Commands::Launch(ref cmd) => commands::launch::execute(cmd),
-        Commands::Build(ref cmd) => commands::build::execute(cmd),
-        Commands::Release(ref cmd) => commands::release::execute(cmd),
-        Commands::Localization(ref cmd) => commands::localization::execute(cmd),
-        Commands::Script(ref cmd) => commands::script::execute(cmd),
-        Commands::Utils(ref cmd) => commands::utils::execute(cmd),
-        Commands::Value(ref cmd) => commands::value::execute(cmd),
-        Commands::Wiki(ref cmd) => commands::wiki::execute(cmd),
-        #[cfg(windows)]
-        Commands::Photoshoot(ref cmd) => commands::photoshoot::execute(cmd),
-    };
+    let report = run(cli.command.as_ref().expect("Handled above"), in_test);
 
     match report {
         Ok(report) => {
-            report.write_to_stdout();
+            match format {
+                ReportFormat::Human => report.write_to_stdout(),
+                ReportFormat::Json => report.write_json()?,
+            }
             if !matches!(
                 cli.command,
-                Some(Commands::New(_) | Commands::Utils(_) | Commands::Wiki(_))
+                Some(Commands::New(_) | Commands::Lint(_) | Commands::Utils(_) | Commands::Wiki(_))
             ) {
                 report.write_ci_annotations()?;
             }
-            if report.failed() {
+            if matches!(
+                cli.command,
+                Some(
+                    Commands::Check(_)
+                        | Commands::Lint(_)
+                        | Commands::Build(_)
+                        | Commands::Release(_)
+                )
+            ) {
+                report.write_summary();
+            }
+            if report.failed_policy() {
                 std::process::exit(1);
             }
         }
@@ -161,6 +261,32 @@ pub fn execute(cli: &Cli) -> Result<(), Error> {
     Ok(())
 }
 
+/// Run a command, returning the report of its findings
+fn run(command: &Commands, in_test: bool) -> Result<report::Report, Error> {
+    match command {
+        Commands::Book(ref cmd) => commands::book::execute(cmd),
+        Commands::New(ref cmd) => commands::new::execute(cmd, in_test),
+        Commands::Add(ref cmd) => commands::add::execute(cmd),
+        Commands::Check(ref cmd) => commands::check::execute(cmd),
+        Commands::Config(ref cmd) => commands::config::execute(cmd),
+        Commands::Dev(ref cmd) => commands::dev::execute(cmd, &[]).map(|(r, _)| r),
+        Commands::Fmt(ref cmd) => commands::fmt::execute(cmd),
+        Commands::Launch(ref cmd) => commands::launch::execute(cmd),
+        Commands::Lint(ref cmd) => commands::lint::execute(cmd),
+        Commands::Build(ref cmd) => commands::build::execute(cmd),
+        Commands::Release(ref cmd) => commands::release::execute(cmd),
+        Commands::Publish(ref cmd) => commands::publish::execute(cmd),
+        Commands::Localization(ref cmd) => commands::localization::execute(cmd),
+        Commands::Script(ref cmd) => commands::script::execute(cmd),
+        Commands::Utils(ref cmd) => commands::utils::execute(cmd),
+        Commands::Value(ref cmd) => commands::value::execute(cmd),
+        Commands::Watch(ref cmd) => commands::watch::execute(cmd),
+        Commands::Wiki(ref cmd) => commands::wiki::execute(cmd),
+        #[cfg(windows)]
+        Commands::Photoshoot(ref cmd) => commands::photoshoot::execute(cmd),
+    }
+}
+
 #[must_use]
 pub fn is_ci() -> bool {
     // TODO: replace with crate if a decent one comes along
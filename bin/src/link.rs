@@ -100,6 +100,9 @@ pub fn create_link(link: &PathBuf, target: &PathBuf) -> Result<(), Error> {
 /// - [`std::io::Error`] if the link could not be created
 pub fn create_link(link: &PathBuf, target: &PathBuf) -> Result<(), Error> {
     if link.exists() {
+        if link.read_link().is_ok_and(|existing| &existing == target) {
+            return Ok(());
+        }
         warn!(
             "link {:?} already exists, intended to point to {:?}",
             link, target
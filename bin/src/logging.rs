@@ -5,25 +5,32 @@ use std::{
 
 use tracing::metadata::LevelFilter;
 use tracing_subscriber::{
-    prelude::__tracing_subscriber_SubscriberExt, util::SubscriberInitExt, Layer,
+    fmt::writer::BoxMakeWriter, prelude::__tracing_subscriber_SubscriberExt,
+    util::SubscriberInitExt, Layer,
 };
 
 use crate::Error;
 
-/// Initialize the logger
+/// Initialize the logger, printing to stderr instead of stdout if `stderr` is true
 ///
 /// # Errors
 /// If `hemttout` is true, but no `.hemtt` folder is found
 ///
 /// # Panics
 /// If the log file could not be created
-pub fn init(verbosity: u8, hemttout: bool) -> Result<(), Error> {
+pub fn init(verbosity: u8, stderr: bool, hemttout: bool) -> Result<(), Error> {
     let format = tracing_subscriber::fmt::format()
         .without_time()
         .with_target(false)
         .compact();
 
-    let stdout = tracing_subscriber::fmt::layer().event_format(format);
+    let stdout = tracing_subscriber::fmt::layer()
+        .event_format(format)
+        .with_writer(if stderr {
+            BoxMakeWriter::new(std::io::stderr)
+        } else {
+            BoxMakeWriter::new(std::io::stdout)
+        });
 
     let filter = if crate::is_ci() {
         LevelFilter::TRACE
@@ -36,7 +43,10 @@ pub fn init(verbosity: u8, hemttout: bool) -> Result<(), Error> {
     };
 
     if hemttout {
-        if !std::path::Path::new(".hemtt").exists() {
+        // the root of a workspace has no project, but is still logged to
+        if !std::path::Path::new(".hemtt").exists()
+            && !std::path::Path::new(hemtt_common::config::WORKSPACE_FILE).exists()
+        {
             tracing_subscriber::registry()
                 .with(stdout.with_filter(filter))
                 .init();
@@ -1,8 +1,9 @@
 use std::{
     fs::{create_dir_all, File},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
+use flate2::{write::GzEncoder, Compression};
 use walkdir::WalkDir;
 use zip::{write::SimpleFileOptions, ZipWriter};
 
@@ -13,12 +14,13 @@ enum Entry {
     Directory(String),
 }
 
-/// Creates the release zips
+/// Creates the release archives
 ///
 /// # Errors
 /// [`Error`] depending on the modules
 /// [`Error::Zip`] if the zip fails to create
 /// [`Error::Io`] if the zip fails to write
+/// [`Error::Pbo`] if a file is shorter than its metadata
 /// [`Error::Version`] if the version is invalid
 ///
 /// # Panics
@@ -72,11 +74,13 @@ pub fn release(ctx: &Context) -> Result<Report, Error> {
     }
     let progress = progress_bar(to_write.len() as u64).with_message("Creating release");
     let mut zip = ZipWriter::new(File::create(&output)?);
-    for entry in to_write {
+    for entry in &to_write {
         match entry {
             Entry::File(file, path) => {
                 zip.start_file(file, options)?;
-                std::io::copy(&mut File::open(path)?, &mut zip)?;
+                let source = File::open(path)?;
+                let size = source.metadata()?.len();
+                hemtt_pbo::stream(source, &mut zip, size)?;
             }
             Entry::Directory(dir) => {
                 zip.add_directory(dir, options)?;
@@ -87,15 +91,45 @@ pub fn release(ctx: &Context) -> Result<Report, Error> {
     progress.finish_and_clear();
     zip.finish()?;
     info!("Created release: {}", output.display());
-    std::fs::copy(&output, {
-        let mut output = output.clone();
-        output.set_file_name(format!(
-            "{}-{}.zip",
-            ctx.config().prefix(),
-            ctx.config().version().get(ctx.workspace_path().vfs())?
-        ));
-        info!("Created release: {}", output.display());
-        output
-    })?;
+
+    let name = ctx.config().hemtt().release().archive_name(
+        ctx.config().name(),
+        ctx.config().prefix(),
+        &ctx.config()
+            .version()
+            .get(ctx.workspace_path().vfs())?
+            .to_string(),
+    );
+    copy_named(&output, &name, "zip")?;
+
+    if ctx.config().hemtt().release().archive_tar() {
+        let latest = output.with_extension("tar.gz");
+        let mut tar =
+            tar::Builder::new(GzEncoder::new(File::create(&latest)?, Compression::best()));
+        for entry in &to_write {
+            match entry {
+                Entry::File(file, path) => {
+                    tar.append_path_with_name(path, file)?;
+                }
+                Entry::Directory(dir) => {
+                    tar.append_dir(dir, ctx.build_folder().expect("build folder exists"))?;
+                }
+            }
+        }
+        tar.into_inner()?.finish()?;
+        info!("Created release: {}", latest.display());
+        copy_named(&latest, &name, "tar.gz")?;
+    }
     Ok(Report::new())
 }
+
+/// Copy the latest archive to the versioned name, unless the names are the same
+fn copy_named(latest: &Path, name: &str, extension: &str) -> Result<(), Error> {
+    let named = latest.with_file_name(format!("{name}.{extension}"));
+    if named == latest {
+        return Ok(());
+    }
+    std::fs::copy(latest, &named)?;
+    info!("Created release: {}", named.display());
+    Ok(())
+}
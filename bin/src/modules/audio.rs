@@ -0,0 +1,195 @@
+use std::{
+    io::Write,
+    sync::{
+        atomic::{AtomicU16, Ordering},
+        Arc,
+    },
+};
+
+use hemtt_audio::Audio;
+use hemtt_workspace::{
+    reporting::{Code, Diagnostic},
+    WorkspacePath,
+};
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use vfs::VfsFileType;
+
+use crate::{context::Context, error::Error, progress::progress_bar, report::Report};
+
+use super::Module;
+
+#[derive(Default)]
+/// Converts `.wav` files to `.ogg` or `.wss` in addons with `[audio] enabled = true`
+pub struct AudioConverter;
+
+impl Module for AudioConverter {
+    fn name(&self) -> &'static str {
+        "AudioConverter"
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        let counter = AtomicU16::new(0);
+        let glob_options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+        let mut entries = Vec::new();
+        for addon in ctx.addons() {
+            let Some(config) = addon.config() else {
+                continue;
+            };
+            let config = config.audio();
+            if !config.enabled() {
+                continue;
+            }
+            let patterns = |globs: &[String]| {
+                globs
+                    .iter()
+                    .map(|file| glob::Pattern::new(file))
+                    .collect::<Result<Vec<_>, _>>()
+            };
+            let exclude = patterns(config.exclude())?;
+            let wss = patterns(config.wss())?;
+            for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
+                if entry.metadata()?.file_type != VfsFileType::File
+                    || !entry
+                        .extension()
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case("wav"))
+                {
+                    continue;
+                }
+                let relative = entry
+                    .as_str()
+                    .trim_start_matches(&format!("/{}/", addon.folder()))
+                    .to_string();
+                if exclude
+                    .iter()
+                    .any(|pat| pat.matches_with(&relative, glob_options))
+                {
+                    debug!("skipping {}", entry.as_str());
+                    continue;
+                }
+                let target = if wss
+                    .iter()
+                    .any(|pat| pat.matches_with(&relative, glob_options))
+                {
+                    Target::Wss
+                } else {
+                    Target::Ogg(config.bitrate())
+                };
+                if entry.with_extension(target.extension())?.exists()? {
+                    debug!(
+                        "skipping {}, a {} already exists",
+                        entry.as_str(),
+                        target.extension()
+                    );
+                    continue;
+                }
+                entries.push((entry, target));
+            }
+        }
+
+        let progress = progress_bar(entries.len() as u64).with_message("Converting Audio");
+        let codes = entries
+            .par_iter()
+            .map(|(entry, target)| {
+                let code = convert(entry, *target)?;
+                if code.is_none() {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+                progress.inc(1);
+                Ok(code)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        report.extend(codes.into_iter().flatten().collect());
+
+        progress.finish_and_clear();
+        info!("Converted {} sounds", counter.load(Ordering::Relaxed));
+        Ok(report)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Target {
+    /// Ogg Vorbis with a bitrate in kbps
+    Ogg(u32),
+    Wss,
+}
+
+impl Target {
+    const fn extension(self) -> &'static str {
+        match self {
+            Self::Ogg(_) => "ogg",
+            Self::Wss => "wss",
+        }
+    }
+}
+
+/// Convert a `.wav` to the target next to it, and remove the `.wav` from the build
+fn convert(entry: &WorkspacePath, target: Target) -> Result<Option<Arc<dyn Code>>, Error> {
+    let mut output = Vec::new();
+    let result = Audio::read_wav(entry.open_file()?).and_then(|audio| match target {
+        Target::Ogg(bitrate) => audio.write_ogg(bitrate, &mut output),
+        Target::Wss => audio.write_wss(&mut output),
+    });
+    if let Err(e) = result {
+        return Ok(Some(Arc::new(CodeAudioConvertFailed::new(
+            entry.clone(),
+            e.to_string(),
+        ))));
+    }
+    entry
+        .with_extension(target.extension())?
+        .create_file()?
+        .write_all(&output)?;
+    entry.vfs().remove_file()?;
+    trace!("converted {}", entry.as_str());
+    Ok(None)
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeAudioConvertFailed {
+    path: WorkspacePath,
+    reason: String,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeAudioConvertFailed {
+    fn ident(&self) -> &'static str {
+        "AUDIO-CONVERT-FAILED"
+    }
+
+    fn message(&self) -> String {
+        format!("Sound at `{}` could not be converted", self.path)
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(self.reason.clone())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("add the file to `audio.exclude` in the addon.toml to leave it as it is".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeAudioConvertFailed {
+    #[must_use]
+    pub fn new(path: WorkspacePath, reason: String) -> Self {
+        Self {
+            path,
+            reason,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
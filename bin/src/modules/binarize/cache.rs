@@ -0,0 +1,163 @@
+//! Cache of binarized files in `.hemttout/cache/binarize`
+//!
+//! Each file has an entry with the hash of the file, the textures and materials
+//! it references, and every `model.cfg` that binarize would read for it.
+//! Terrains and animations read files that are not known without parsing them,
+//! so their entries include every file of the project's addons.
+//! The entry is only used when that hash, the project config, and the HEMTT
+//! version match, otherwise the file is binarized again.
+
+use std::{io::Read, path::PathBuf, sync::OnceLock};
+
+use hemtt_workspace::WorkspacePath;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use crate::{context::Context, modules::rapifier::cache::write};
+
+pub struct Cache {
+    folder: PathBuf,
+    /// The project prefix, projects in a workspace share the cache folder
+    prefix: String,
+    /// Hash of everything that is not a source file
+    base: Vec<u8>,
+    /// The folders of the project's addons
+    addons: Vec<WorkspacePath>,
+    /// Hash of every file in [`Self::addons`], only computed for terrains and animations
+    project: OnceLock<Option<Vec<u8>>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    hash: String,
+}
+
+impl Cache {
+    /// Open the cache, [`None`] if it is disabled
+    pub fn new(ctx: &Context) -> Option<Self> {
+        let folder = ctx.cache_folder()?.join("binarize");
+        if let Err(e) = std::fs::create_dir_all(&folder) {
+            warn!("unable to create binarize cache: {e}");
+            return None;
+        }
+        let config = match serde_json::to_vec(ctx.config()) {
+            Ok(config) => config,
+            Err(e) => {
+                warn!("unable to hash project config for the binarize cache: {e}");
+                return None;
+            }
+        };
+        let mut hasher = Sha1::new();
+        hasher.update(env!("HEMTT_VERSION"));
+        hasher.update(config);
+        Some(Self {
+            folder,
+            prefix: ctx.config().prefix().clone(),
+            base: hasher.finalize().to_vec(),
+            addons: ctx
+                .addons()
+                .iter()
+                .map(|addon| ctx.workspace_path().join(addon.folder()))
+                .collect::<Result<_, _>>()
+                .ok()?,
+            project: OnceLock::new(),
+        })
+    }
+
+    /// Hash a file and the files binarize reads for it, [`None`] if any can not be read
+    ///
+    /// `references` are the textures and materials of a P3D, missing ones are
+    /// hashed by name, so adding them later binarizes the file again
+    pub fn hash(&self, entry: &WorkspacePath, references: &[String]) -> Option<String> {
+        let mut hasher = Sha1::new();
+        hasher.update(&self.base);
+        hash_file(&mut hasher, entry)?;
+        let mut folder = entry.parent();
+        loop {
+            let model_cfg = folder.join("model.cfg").ok()?;
+            if model_cfg.is_file().ok()? {
+                hash_file(&mut hasher, &model_cfg)?;
+            }
+            if folder.as_str().is_empty() || folder.as_str() == "/" {
+                break;
+            }
+            folder = folder.parent();
+        }
+        if matches!(entry.extension().as_deref(), Some("wrp" | "rtm")) {
+            let project = self.project.get_or_init(|| self.hash_project()).as_ref()?;
+            hasher.update(project);
+        }
+        for reference in references {
+            hasher.update(reference.len().to_le_bytes());
+            hasher.update(reference);
+            match entry.locate(reference).ok()? {
+                Some(located) => hash_file(&mut hasher, &located.path)?,
+                None => hasher.update([0]),
+            }
+        }
+        Some(format!("{:x}", hasher.finalize()))
+    }
+
+    /// Get the cached output of a file, if its hash matches
+    pub fn get(&self, entry: &str, hash: &str) -> Option<Vec<u8>> {
+        let (entry_path, output_path) = self.paths(entry);
+        let cached: Entry = serde_json::from_slice(&std::fs::read(entry_path).ok()?).ok()?;
+        if cached.hash != hash {
+            return None;
+        }
+        std::fs::read(output_path).ok()
+    }
+
+    /// Store the output of a file
+    pub fn insert(&self, entry: &str, hash: &str, output: &[u8]) {
+        let (entry_path, output_path) = self.paths(entry);
+        let result = (|| -> std::io::Result<()> {
+            write(&output_path, output)?;
+            write(
+                &entry_path,
+                &serde_json::to_vec(&Entry {
+                    hash: hash.to_string(),
+                })?,
+            )
+        })();
+        if let Err(e) = result {
+            debug!("unable to cache {entry}: {e}");
+        }
+    }
+
+    /// Hash every file of the project's addons, [`None`] if any can not be read
+    fn hash_project(&self) -> Option<Vec<u8>> {
+        let mut files = Vec::new();
+        for addon in &self.addons {
+            for entry in addon.walk_dir().ok()? {
+                if entry.is_file().ok()? {
+                    files.push(entry);
+                }
+            }
+        }
+        files.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let mut hasher = Sha1::new();
+        for file in &files {
+            hash_file(&mut hasher, file)?;
+        }
+        Some(hasher.finalize().to_vec())
+    }
+
+    fn paths(&self, entry: &str) -> (PathBuf, PathBuf) {
+        let name = format!("{:x}", Sha1::digest(format!("{}{entry}", self.prefix)));
+        (
+            self.folder.join(format!("{name}.json")),
+            self.folder.join(format!("{name}.bin")),
+        )
+    }
+}
+
+fn hash_file(hasher: &mut Sha1, path: &WorkspacePath) -> Option<()> {
+    let mut content = Vec::new();
+    path.open_file().ok()?.read_to_end(&mut content).ok()?;
+    hasher.update(path.as_str().len().to_le_bytes());
+    hasher.update(path.as_str());
+    hasher.update(content.len().to_le_bytes());
+    hasher.update(content);
+    Some(())
+}
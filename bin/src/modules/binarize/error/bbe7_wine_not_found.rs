@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use hemtt_workspace::reporting::{Code, Diagnostic, Severity};
 
-pub struct WineNotFound;
+pub struct WineNotFound {
+    command: String,
+}
 
 impl Code for WineNotFound {
     fn ident(&self) -> &'static str {
@@ -14,12 +16,12 @@ impl Code for WineNotFound {
     }
 
     fn message(&self) -> String {
-        String::from("`wine64` not found in PATH.")
+        format!("`{}` not found in PATH.", self.command)
     }
 
     fn note(&self) -> Option<String> {
         Some(String::from(
-            "When specifying tools on Linux, make sure `wine64` is in your PATH.",
+            "`hemtt.build.binarize_wrapper` must be `proton` or a command in your PATH, such as `wine`.",
         ))
     }
 
@@ -30,7 +32,7 @@ impl Code for WineNotFound {
 
 impl WineNotFound {
     #[allow(dead_code)] // only used on non-windows platforms
-    pub fn code() -> Arc<dyn Code> {
-        Arc::new(Self)
+    pub fn code(command: String) -> Arc<dyn Code> {
+        Arc::new(Self { command })
     }
 }
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic, Severity};
+
+pub struct LogMissingTextures {
+    p3d: String,
+    textures: Vec<String>,
+}
+impl Code for LogMissingTextures {
+    fn ident(&self) -> &'static str {
+        "BBW3"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "binarize could not load {} texture{} for {}:\n  {}",
+            self.textures.len(),
+            if self.textures.len() == 1 { "" } else { "s" },
+            self.p3d,
+            self.textures.join("\n  ")
+        )
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(String::from(
+            "missing textures are shown as white or pink in-game",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl LogMissingTextures {
+    pub fn code(p3d: String, textures: Vec<String>) -> Arc<dyn Code> {
+        Arc::new(Self {
+            p3d,
+            textures: textures.into_iter().map(|t| t.replace('\\', "/")).collect(),
+        })
+    }
+}
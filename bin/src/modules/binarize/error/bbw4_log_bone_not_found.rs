@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic, Severity};
+
+pub struct LogBoneNotFound {
+    p3d: String,
+    bone: String,
+    skeleton: Option<String>,
+}
+impl Code for LogBoneNotFound {
+    fn ident(&self) -> &'static str {
+        "BBW4"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        self.skeleton.as_ref().map_or_else(
+            || format!("bone `{}` not found for {}", self.bone, self.p3d),
+            |skeleton| {
+                format!(
+                    "bone `{}` not found in skeleton `{skeleton}` for {}",
+                    self.bone, self.p3d
+                )
+            },
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(String::from(
+            "add the bone to `skeletonBones[]` in the `model.cfg`, or check the selection names of the model",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl LogBoneNotFound {
+    pub fn code(p3d: String, bone: String, skeleton: Option<String>) -> Arc<dyn Code> {
+        Arc::new(Self {
+            p3d,
+            bone,
+            skeleton,
+        })
+    }
+}
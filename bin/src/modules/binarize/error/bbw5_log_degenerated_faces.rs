@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::{Code, Diagnostic, Severity};
+
+pub struct LogDegeneratedFaces {
+    p3d: String,
+    lod: Option<String>,
+    count: Option<usize>,
+}
+impl Code for LogDegeneratedFaces {
+    fn ident(&self) -> &'static str {
+        "BBW5"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        let faces = match self.count {
+            Some(1) => String::from("1 degenerated face"),
+            Some(count) => format!("{count} degenerated faces"),
+            None => String::from("degenerated faces"),
+        };
+        self.lod.as_ref().map_or_else(
+            || format!("{} has {faces}", self.p3d),
+            |lod| format!("{} has {faces} in LOD {lod}", self.p3d),
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(String::from(
+            "remove faces without an area, such as faces with duplicate vertices, in Object Builder",
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl LogDegeneratedFaces {
+    pub fn code(p3d: String, lod: Option<String>, count: Option<usize>) -> Arc<dyn Code> {
+        Arc::new(Self { p3d, lod, count })
+    }
+}
@@ -8,3 +8,6 @@ pub mod bbe7_wine_not_found;
 
 pub mod bbw1_tools_not_found;
 pub mod bbw2_platform_not_supported;
+pub mod bbw3_log_missing_textures;
+pub mod bbw4_log_bone_not_found;
+pub mod bbw5_log_degenerated_faces;
@@ -0,0 +1,83 @@
+//! Diagnostics from the output of `binarize_x64.exe`
+//!
+//! Binarize writes its warnings as plain lines, the known ones are reported as codes, and the rest are only traced.
+
+use std::sync::{Arc, LazyLock};
+
+use hemtt_workspace::reporting::Code;
+use regex::Regex;
+
+use super::error::{
+    bbw3_log_missing_textures::LogMissingTextures, bbw4_log_bone_not_found::LogBoneNotFound,
+    bbw5_log_degenerated_faces::LogDegeneratedFaces,
+};
+
+static MISSING_TEXTURE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?i)(?:cannot (?:load|open|find)|missing)\s+(?:texture|file)\s+['"]?(?P<texture>[^\s'"]+?\.(?:paa|pac|tga|png))"#,
+    )
+    .expect("missing texture regex compiles")
+});
+
+static BONE_NOT_FOUND: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?i)bone\s+['"]?(?P<bone>[^\s'"]+?)['"]?\s+not found(?:\s+in\s+skeleton\s+['"]?(?P<skeleton>[^\s'"]+?)['"]?)?(?:[\s.,]|$)"#,
+    )
+    .expect("bone not found regex compiles")
+});
+
+static DEGENERATED_FACES: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(?:(?:^|\s)(?P<before>\d+)\s+)?degenerated\s+faces?(?:\s*[:=(]\s*(?P<after>\d+))?",
+    )
+    .expect("degenerated faces regex compiles")
+});
+
+/// The resolution of a LOD, written by binarize after the model, such as `model.p3d:1.000`
+static LOD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\.p3d:\s*(?P<lod>\d+(?:\.\d+)?(?:e[+-]?\d+)?)").expect("lod regex compiles")
+});
+
+/// Convert the output of binarizing a file into codes
+///
+/// Missing textures are grouped into a single code, as binarize reports each use of a texture
+pub fn codes(file: &str, output: &str) -> Vec<Arc<dyn Code>> {
+    let mut textures: Vec<String> = Vec::new();
+    let mut codes = Vec::new();
+    for line in output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+    {
+        if let Some(captures) = MISSING_TEXTURE.captures(line) {
+            let texture = captures["texture"].to_string();
+            if !textures
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(&texture))
+            {
+                textures.push(texture);
+            }
+        } else if let Some(captures) = BONE_NOT_FOUND.captures(line) {
+            codes.push(LogBoneNotFound::code(
+                file.to_string(),
+                captures["bone"].to_string(),
+                captures.name("skeleton").map(|s| s.as_str().to_string()),
+            ));
+        } else if let Some(captures) = DEGENERATED_FACES.captures(line) {
+            codes.push(LogDegeneratedFaces::code(
+                file.to_string(),
+                LOD.captures(line).map(|lod| lod["lod"].to_string()),
+                captures
+                    .name("before")
+                    .or_else(|| captures.name("after"))
+                    .and_then(|count| count.as_str().parse().ok()),
+            ));
+        } else {
+            trace!("binarize {file}: {line}");
+        }
+    }
+    if !textures.is_empty() {
+        codes.insert(0, LogMissingTextures::code(file.to_string(), textures));
+    }
+    codes
+}
@@ -10,30 +10,35 @@ use std::{
 };
 
 use hemtt_common::config::PDriveOption;
-use hemtt_p3d::SearchCache;
+use hemtt_p3d::{SearchCache, P3D};
 use hemtt_workspace::reporting::Severity;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 use vfs::VfsFileType;
 
 #[allow(unused_imports)] // some are Linux only
 use self::error::{
-    bbe3_binarize_failed::BinarizeFailed, bbw1_tools_not_found::ToolsNotFound,
-    bbw2_platform_not_supported::PlatformNotSupported,
+    bbe3_binarize_failed::BinarizeFailed, bbe7_wine_not_found::WineNotFound,
+    bbw1_tools_not_found::ToolsNotFound, bbw2_platform_not_supported::PlatformNotSupported,
+};
+use self::{
+    cache::Cache,
+    error::{bbe4_missing_textures::MissingTextures, bbe6_missing_pdrive::MissingPDrive},
 };
-use self::error::{bbe4_missing_textures::MissingTextures, bbe6_missing_pdrive::MissingPDrive};
 use super::Module;
 use crate::{
     context::Context, error::Error, link::create_link,
-    modules::binarize::error::bbe5_missing_material::MissingMaterials, report::Report,
+    modules::binarize::error::bbe5_missing_material::MissingMaterials, report::Report, timings,
 };
 
+mod cache;
 mod error;
+pub mod log;
 
 #[derive(Default)]
 pub struct Binarize {
     check_only: bool,
     command: Option<String>,
-    proton: bool,
+    wrapper: Wrapper,
     prechecked: RwLock<Vec<BinarizeTarget>>,
 }
 
@@ -43,7 +48,7 @@ impl Binarize {
         Self {
             check_only,
             command: None,
-            proton: false,
+            wrapper: Wrapper::None,
             prechecked: RwLock::new(Vec::new()),
         }
     }
@@ -113,25 +118,51 @@ impl Module for Binarize {
             }
         };
         let path = tools_path.join("Binarize").join("binarize_x64.exe");
-        if path.exists() {
-            self.command = Some(path.display().to_string());
-            let mut cmd = Command::new("wine64");
-            cmd.arg("--version");
-            if cmd.output().is_err() {
-                if home_dir()
-                    .expect("home directory exists")
-                    .join(".local/share/Steam/steamapps/common/SteamLinuxRuntime_sniper/run")
-                    .exists()
-                {
-                    self.proton = true;
+        if !path.exists() {
+            report.push(ToolsNotFound::code(Severity::Warning));
+            setup_tmp(ctx)?;
+            return Ok(report);
+        }
+        let proton_available = home_dir()
+            .expect("home directory exists")
+            .join(".local/share/Steam/steamapps/common/SteamLinuxRuntime_sniper/run")
+            .exists();
+        self.wrapper = match ctx.config().hemtt().build().binarize_wrapper() {
+            Some("proton") => {
+                if proton_available {
+                    Wrapper::Proton
                 } else {
-                    debug!("tools found, but not wine64 or proton");
+                    debug!("proton was requested, but not found");
                     report.push(ToolsNotFound::code(Severity::Warning));
-                    self.command = None;
+                    Wrapper::None
                 }
             }
-        } else {
-            report.push(ToolsNotFound::code(Severity::Warning));
+            Some(wrapper) => {
+                if command_exists(wrapper) {
+                    Wrapper::Wine(wrapper.to_string())
+                } else {
+                    report.push(WineNotFound::code(wrapper.to_string()));
+                    Wrapper::None
+                }
+            }
+            None => ["wine64", "wine"]
+                .into_iter()
+                .find(|wine| command_exists(wine))
+                .map_or_else(
+                    || {
+                        if proton_available {
+                            Wrapper::Proton
+                        } else {
+                            debug!("tools found, but not wine or proton");
+                            report.push(ToolsNotFound::code(Severity::Warning));
+                            Wrapper::None
+                        }
+                    },
+                    |wine| Wrapper::Wine(wine.to_string()),
+                ),
+        };
+        if self.wrapper != Wrapper::None {
+            self.command = Some(path.display().to_string());
         }
         setup_tmp(ctx)?;
         Ok(report)
@@ -146,8 +177,18 @@ impl Module for Binarize {
         };
 
         let mut report = Report::new();
+        self.prechecked.write().expect("can write in check").clear();
         let tmp_out = ctx.tmp().join("hemtt_binarize_output");
         let search_cache = SearchCache::new();
+        let glob_options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+        let cache = if self.check_only || self.command.is_none() {
+            None
+        } else {
+            Cache::new(ctx)
+        };
         if let Some(pdrive) = ctx.workspace().pdrive() {
             info!("P Drive at {}", pdrive.link().display());
         } else if pdrive_option == &PDriveOption::Require {
@@ -184,10 +225,11 @@ impl Module for Binarize {
                             .collect::<Result<Vec<_>, glob::PatternError>>()?
                             .iter()
                             .any(|pat| {
-                                pat.matches(
+                                pat.matches_with(
                                     entry
                                         .as_str()
                                         .trim_start_matches(&format!("/{}/", addon.folder())),
+                                    glob_options,
                                 )
                             })
                         {
@@ -212,7 +254,7 @@ impl Module for Binarize {
                     }
 
                     // check mlod for textures
-                    if buf == [0x4D, 0x4C, 0x4F, 0x44] {
+                    let references = if buf == [0x4D, 0x4C, 0x4F, 0x44] {
                         trace!("checking textures & materials for {}", entry.as_str());
                         let p3d = hemtt_p3d::P3D::read(
                             &mut entry.open_file().expect("file should exist from walk_dir"),
@@ -236,7 +278,10 @@ impl Module for Binarize {
                             );
                             report.push(diag);
                         }
-                    }
+                        self::references(&p3d)
+                    } else {
+                        Vec::new()
+                    };
 
                     let tmp_sourced = ctx.tmp().join(addon.prefix().as_pathbuf()).join(
                         entry
@@ -252,6 +297,7 @@ impl Module for Binarize {
                         .write()
                         .expect("can write in check")
                         .push(BinarizeTarget {
+                            path: entry.as_str().to_string(),
                             source: tmp_sourced
                                 .to_str()
                                 .expect("tmp source path should be valid utf-8")
@@ -261,6 +307,11 @@ impl Module for Binarize {
                                 .expect("tmp output path should be valid utf-8")
                                 .to_owned(),
                             entry: entry.filename().trim_start_matches('/').to_owned(),
+                            cache: cache.as_ref().and_then(|cache| {
+                                cache
+                                    .hash(&entry, &references)
+                                    .map(|hash| (entry.as_str().to_string(), hash))
+                            }),
                         });
                 }
             }
@@ -282,46 +333,64 @@ impl Module for Binarize {
         }
         let mut report = Report::new();
         let counter = AtomicU16::new(0);
+        let cached = AtomicU16::new(0);
+        let cache = Cache::new(ctx);
         self.prechecked
             .read()
             .expect("can read in pre_build")
             .par_iter()
             .map(|target| {
-                debug!("binarizing {}", target.entry);
                 create_dir_all(&target.output)
                     .expect("should be able to create output dir for target");
+                let cache = cache.as_ref().zip(target.cache.as_ref());
+                if let Some((cache, (key, hash))) = cache {
+                    if let Some(output) = cache.get(key, hash) {
+                        debug!("using cached {}", target.entry);
+                        if std::fs::write(PathBuf::from(&target.output).join(&target.entry), output)
+                            .is_ok()
+                        {
+                            cached.fetch_add(1, Ordering::Relaxed);
+                            return Vec::new();
+                        }
+                    }
+                }
+                debug!("binarizing {}", target.entry);
                 let exe = self
                     .command
                     .as_ref()
                     .expect("command should be set if we attempted to binarize");
-                let mut cmd = if cfg!(windows) {
-                    Command::new(exe)
-                } else if self.proton {
-                    let mut home = dirs::home_dir().expect("home directory exists");
-                    if exe.contains("/.var/") {
-                        home = home.join(".var/app/com.valvesoftware.Steam");
+                let mut cmd = match &self.wrapper {
+                    Wrapper::None => Command::new(exe),
+                    Wrapper::Proton => {
+                        let mut home = dirs::home_dir().expect("home directory exists");
+                        if exe.contains("/.var/") {
+                            home = home.join(".var/app/com.valvesoftware.Steam");
+                        }
+                        let mut cmd = Command::new({
+                            home.join(".local/share/Steam/steamapps/common/SteamLinuxRuntime_sniper/run")
+                        });
+                        cmd.env("STEAM_COMPAT_CLIENT_INSTALL_PATH", 
+                            home.join(".local/share/Steam")
+                        ).env(
+                            "STEAM_COMPAT_DATA_PATH",
+                            home.join(".local/share/Steam/steamapps/compatdata/233800")
+                        ).env("STEAM_COMPAT_INSTALL_PATH", "/tmp/hemtt-scip").arg("--").arg(
+                            home.join(".local/share/Steam/steamapps/common/Proton - Experimental/proton")
+                        ).arg("run").arg(
+                            home.join(".local/share/Steam/steamapps/common/Arma 3 Tools/Binarize/binarize_x64.exe")
+                        );
+                        cmd
+                    }
+                    Wrapper::Wine(wine) => {
+                        let mut cmd = Command::new(wine);
+                        cmd.arg(exe);
+                        if std::env::var_os("WINEPREFIX").is_none() {
+                            cmd.env("WINEPREFIX", "/tmp/hemtt-wine");
+                            std::fs::create_dir_all("/tmp/hemtt-wine")
+                                .expect("should be able to create wine prefix");
+                        }
+                        cmd
                     }
-                    let mut cmd = Command::new({
-                        home.join(".local/share/Steam/steamapps/common/SteamLinuxRuntime_sniper/run")
-                    });
-                    cmd.env("STEAM_COMPAT_CLIENT_INSTALL_PATH", 
-                        home.join(".local/share/Steam")
-                    ).env(
-                        "STEAM_COMPAT_DATA_PATH",
-                        home.join(".local/share/Steam/steamapps/compatdata/233800")
-                    ).env("STEAM_COMPAT_INSTALL_PATH", "/tmp/hemtt-scip").arg("--").arg(
-                        home.join(".local/share/Steam/steamapps/common/Proton - Experimental/proton")
-                    ).arg("run").arg(
-                        home.join(".local/share/Steam/steamapps/common/Arma 3 Tools/Binarize/binarize_x64.exe")
-                    );
-                    cmd
-                } else {
-                    let mut cmd = Command::new("wine64");
-                    cmd.arg(exe);
-                    cmd.env("WINEPREFIX", "/tmp/hemtt-wine");
-                    std::fs::create_dir_all("/tmp/hemtt-wine")
-                        .expect("should be able to create wine prefix");
-                    cmd
                 };
                 cmd.args([
                     "-norecurse",
@@ -344,18 +413,36 @@ impl Module for Binarize {
                 ])
                 .current_dir(ctx.tmp());
                 trace!("{:?}", cmd);
+                let span = timings::span("binarize", &target.entry);
                 let output = cmd.output().expect("should be able to run binarize");
+                drop(span);
                 assert!(
                     output.status.success(),
                     "binarize failed with code {:?}",
                     output.status.code().unwrap_or(-1)
                 );
-                if PathBuf::from(&target.output).join(&target.entry).exists() {
+                let mut codes = log::codes(
+                    &target.path,
+                    &format!(
+                        "{}\n{}",
+                        String::from_utf8_lossy(&output.stdout),
+                        String::from_utf8_lossy(&output.stderr)
+                    ),
+                );
+                let output = PathBuf::from(&target.output).join(&target.entry);
+                if output.exists() {
                     counter.fetch_add(1, Ordering::Relaxed);
-                    None
+                    // Files with warnings are binarized again, to report them on the next build
+                    if let Some((cache, (key, hash))) = cache.filter(|_| codes.is_empty()) {
+                        match std::fs::read(&output) {
+                            Ok(output) => cache.insert(key, hash, &output),
+                            Err(e) => debug!("unable to cache {}: {e}", target.entry),
+                        }
+                    }
                 } else {
-                    Some(BinarizeFailed::code(target.entry.clone()))
+                    codes.push(BinarizeFailed::code(target.entry.clone()));
                 }
+                codes
             })
             .collect::<Vec<_>>()
             .into_iter()
@@ -365,14 +452,62 @@ impl Module for Binarize {
             });
 
         info!("Binarized {} files", counter.load(Ordering::Relaxed));
+        let cached = cached.load(Ordering::Relaxed);
+        if cached > 0 {
+            info!("Used {cached} cached binarized files");
+        }
         Ok(report)
     }
 }
 
+/// How `binarize_x64.exe` is run
+#[derive(Default, PartialEq, Eq)]
+#[cfg_attr(windows, allow(dead_code))] // wrappers are only used on non-windows platforms
+enum Wrapper {
+    /// Run directly, on Windows
+    #[default]
+    None,
+    /// Run with Wine, using the given command
+    Wine(String),
+    /// Run with Proton through the Steam Linux Runtime
+    Proton,
+}
+
+#[cfg(not(windows))]
+/// Check if a command can be started
+fn command_exists(command: &str) -> bool {
+    Command::new(command).arg("--version").output().is_ok()
+}
+
 struct BinarizeTarget {
+    /// The path in the workspace, used for reporting
+    path: String,
     source: String,
     output: String,
     entry: String,
+    /// The workspace path and hash used for the cache
+    cache: Option<(String, String)>,
+}
+
+/// The textures and materials of a P3D, in the form binarize looks them up
+fn references(p3d: &P3D) -> Vec<String> {
+    let mut references = p3d
+        .lods
+        .iter()
+        .flat_map(|lod| &lod.faces)
+        .flat_map(|face| [&face.texture, &face.material])
+        .filter(|path| !path.is_empty() && !path.starts_with('#'))
+        .map(|path| {
+            if path.starts_with('\\') {
+                path.clone()
+            } else {
+                format!("\\{path}")
+            }
+        })
+        .collect::<Vec<_>>();
+    references.sort();
+    references.dedup();
+    references
 }
 
 /// Check if the file signature indicates that it is already binarized
@@ -0,0 +1,122 @@
+//! The addons built by `hemtt dev`, so `hemtt dev --changed` only rebuilds the addons that changed since
+//!
+//! Each addon is recorded in `.hemttout/changed.json` with a fingerprint of the size and modification time
+//! of its files, and of the files in `.hemtt` and the include folders, which are used by every addon
+
+use std::{collections::BTreeMap, path::Path, sync::Mutex, time::UNIX_EPOCH};
+
+use hemtt_workspace::addons::Addon;
+use sha1::{Digest, Sha1};
+
+use crate::{context::Context, error::Error, report::Report};
+
+use super::{rapifier::cache::hex, Module};
+
+/// The fingerprint of each addon folder
+pub type Fingerprints = BTreeMap<String, String>;
+
+#[derive(Default)]
+/// Records the fingerprint of each addon built by `hemtt dev`
+pub struct Changes {
+    /// Taken before anything is built, so files edited during the build are rebuilt next time
+    fingerprints: Mutex<Fingerprints>,
+}
+
+impl Module for Changes {
+    fn name(&self) -> &'static str {
+        "Changes"
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        *self
+            .fingerprints
+            .lock()
+            .expect("fingerprints lock poisoned") = fingerprints(ctx, ctx.addons())?;
+        Ok(Report::new())
+    }
+
+    fn post_build(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut built = previous(ctx).unwrap_or_default();
+        built.extend(std::mem::take(
+            &mut *self
+                .fingerprints
+                .lock()
+                .expect("fingerprints lock poisoned"),
+        ));
+        std::fs::write(path(ctx), serde_json::to_vec_pretty(&built)?)?;
+        Ok(Report::new())
+    }
+}
+
+#[must_use]
+/// The fingerprints recorded by the last `hemtt dev`, [`None`] if there are none
+pub fn previous(ctx: &Context) -> Option<Fingerprints> {
+    let content = std::fs::read(path(ctx)).ok()?;
+    serde_json::from_slice(&content).ok()
+}
+
+/// The fingerprint of each addon, from its files, the files in `.hemtt`, and the include folders
+///
+/// # Errors
+/// [`Error::Walkdir`] if a folder can not be read
+/// [`Error::Io`] if the metadata of a file can not be read
+pub fn fingerprints<'a>(
+    ctx: &Context,
+    addons: impl IntoIterator<Item = &'a Addon>,
+) -> Result<Fingerprints, Error> {
+    let mut shared = Sha1::new();
+    let mut folders = ctx.include_folders();
+    folders.push(ctx.hemtt_folder().clone());
+    // mounts are not ordered
+    folders.sort();
+    folders.dedup();
+    for folder in &folders {
+        hash_folder(&mut shared, ctx.project_folder(), folder)?;
+    }
+    addons
+        .into_iter()
+        .map(|addon| {
+            let mut hasher = shared.clone();
+            hash_folder(
+                &mut hasher,
+                ctx.project_folder(),
+                &ctx.project_folder().join(addon.folder()),
+            )?;
+            Ok((addon.folder(), hex(&hasher.finalize())))
+        })
+        .collect()
+}
+
+fn path(ctx: &Context) -> std::path::PathBuf {
+    ctx.project_folder().join(".hemttout").join("changed.json")
+}
+
+/// Hash the path, size, and modification time of every file in the folder
+fn hash_folder(hasher: &mut Sha1, root: &Path, folder: &Path) -> Result<(), Error> {
+    if !folder.exists() {
+        return Ok(());
+    }
+    for entry in walkdir::WalkDir::new(folder).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        let path = entry
+            .path()
+            .strip_prefix(root)
+            .unwrap_or_else(|_| entry.path())
+            .to_string_lossy()
+            .replace('\\', "/");
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |duration| duration.as_nanos());
+        hasher.update(path.len().to_le_bytes());
+        hasher.update(path);
+        hasher.update(metadata.len().to_le_bytes());
+        hasher.update(modified.to_le_bytes());
+    }
+    Ok(())
+}
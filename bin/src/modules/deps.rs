@@ -0,0 +1,496 @@
+//! Dependencies between the addons of the project, from `requiredAddons[]` in `CfgPatches`
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Write,
+    sync::Arc,
+};
+
+use hemtt_common::config::DependenciesConfig;
+use hemtt_workspace::{
+    addons::{Addon, Patch},
+    reporting::{Code, Diagnostic, Severity},
+};
+
+use crate::{context::Context, dot, error::Error, report::Report};
+
+use super::Module;
+
+#[derive(Default)]
+/// Checks the `requiredAddons[]` of each addon against the other addons of the project
+pub struct Dependencies;
+
+impl Module for Dependencies {
+    fn name(&self) -> &'static str {
+        "Dependencies"
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        // the patches of addons that are not built are unknown
+        if ctx.addons().len() < ctx.all_addons().len() {
+            debug!("skipping dependency checks, not all addons are built");
+            return Ok(report);
+        }
+        let graph = Graph::from_addons(ctx.addons());
+        for cycle in graph.cycles() {
+            report.push(CyclicDependency::code(cycle));
+        }
+        for (addon, patch, required) in
+            graph.missing(ctx.config().prefix(), ctx.config().dependencies())
+        {
+            report.push(MissingDependency::code(
+                addon.to_string(),
+                patch.to_string(),
+                required.to_string(),
+            ));
+        }
+        let dependencies = ctx.config().dependencies();
+        if !dependencies.is_empty() {
+            for (addon, patch, required, similar) in
+                graph.undeclared(ctx.config().prefix(), dependencies)
+            {
+                report.push(UndeclaredDependency::code(
+                    addon.to_string(),
+                    patch.to_string(),
+                    required.to_string(),
+                    similar,
+                ));
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// The addons of a project, and the classes in their `CfgPatches`
+pub struct Graph {
+    addons: BTreeMap<String, Vec<Patch>>,
+    /// The addon that declares each patch, by the lowercase name of the patch
+    owners: HashMap<String, String>,
+}
+
+impl Graph {
+    #[must_use]
+    pub fn new(addons: BTreeMap<String, Vec<Patch>>) -> Self {
+        let mut owners = HashMap::new();
+        for (addon, patches) in &addons {
+            for (name, _) in patches {
+                owners
+                    .entry(name.to_lowercase())
+                    .or_insert_with(|| addon.clone());
+            }
+        }
+        Self { addons, owners }
+    }
+
+    #[must_use]
+    /// The graph of addons that have been rapified
+    pub fn from_addons(addons: &[Addon]) -> Self {
+        Self::new(
+            addons
+                .iter()
+                .map(|addon| (addon.name().to_string(), addon.build_data().patches()))
+                .collect(),
+        )
+    }
+
+    /// The addon that declares a patch
+    fn owner(&self, patch: &str) -> Option<&str> {
+        self.owners.get(&patch.to_lowercase()).map(String::as_str)
+    }
+
+    #[must_use]
+    /// The other addons each addon requires
+    pub fn edges(&self) -> BTreeMap<&str, BTreeSet<&str>> {
+        self.addons
+            .iter()
+            .map(|(addon, patches)| {
+                (
+                    addon.as_str(),
+                    patches
+                        .iter()
+                        .flat_map(|(_, required)| required)
+                        .filter_map(|required| self.owner(required))
+                        .filter(|owner| owner != addon)
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[must_use]
+    /// The patches required by each addon that no addon of the project declares
+    pub fn external(&self) -> BTreeMap<&str, BTreeSet<&str>> {
+        self.addons
+            .iter()
+            .map(|(addon, patches)| {
+                (
+                    addon.as_str(),
+                    patches
+                        .iter()
+                        .flat_map(|(_, required)| required)
+                        .filter(|required| self.owner(required).is_none())
+                        .map(String::as_str)
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[must_use]
+    /// Required patches that start with the project prefix, but are not declared by any addon
+    ///
+    /// Patches from `[dependencies]` are other mods that share the prefix.
+    /// Nothing is returned if the patches of an addon are unknown, such as addons that are not rapified,
+    /// since the required patch could be one of them.
+    /// Returns the addon, the patch that requires it, and the required patch
+    pub fn missing(
+        &self,
+        prefix: &str,
+        dependencies: &DependenciesConfig,
+    ) -> Vec<(&str, &str, &str)> {
+        if let Some((addon, _)) = self.addons.iter().find(|(_, patches)| patches.is_empty()) {
+            debug!("skipping missing dependencies, the patches of `{addon}` are unknown");
+            return Vec::new();
+        }
+        let prefix = format!("{}_", prefix.to_lowercase());
+        let mut missing = Vec::new();
+        for (addon, patches) in &self.addons {
+            for (patch, required) in patches {
+                for required in required {
+                    if required.to_lowercase().starts_with(&prefix)
+                        && self.owner(required).is_none()
+                        && dependencies.declared_by(required).is_none()
+                    {
+                        missing.push((addon.as_str(), patch.as_str(), required.as_str()));
+                    }
+                }
+            }
+        }
+        missing
+    }
+
+    #[must_use]
+    /// Required patches outside of the project that are not in `[dependencies]`
+    ///
+    /// Patches of the base game, starting with `A3_`, are always allowed.
+    /// Returns the addon, the patch that requires it, the required patch, and similar known patches
+    pub fn undeclared<'a>(
+        &'a self,
+        prefix: &str,
+        dependencies: &'a DependenciesConfig,
+    ) -> Vec<(&'a str, &'a str, &'a str, Vec<String>)> {
+        let prefix = format!("{}_", prefix.to_lowercase());
+        let known = self
+            .addons
+            .values()
+            .flatten()
+            .map(|(name, _)| name.as_str())
+            .chain(
+                dependencies
+                    .mods()
+                    .values()
+                    .flatten()
+                    .filter(|patch| !patch.ends_with('*'))
+                    .map(String::as_str),
+            )
+            .collect::<Vec<_>>();
+        let mut undeclared = Vec::new();
+        for (addon, patches) in &self.addons {
+            for (patch, required) in patches {
+                for required in required {
+                    let lower = required.to_lowercase();
+                    if lower.starts_with(&prefix)
+                        || lower.starts_with("a3_")
+                        || self.owner(required).is_some()
+                        || dependencies.declared_by(required).is_some()
+                    {
+                        continue;
+                    }
+                    undeclared.push((
+                        addon.as_str(),
+                        patch.as_str(),
+                        required.as_str(),
+                        hemtt_common::similar_values(required, &known)
+                            .into_iter()
+                            .map(ToString::to_string)
+                            .collect(),
+                    ));
+                }
+            }
+        }
+        undeclared
+    }
+
+    #[must_use]
+    /// The addons ordered so each comes after the addons it requires
+    ///
+    /// Addons that require each other are ordered by name
+    pub fn load_order(&self) -> Vec<&str> {
+        fn visit<'a>(
+            edges: &BTreeMap<&'a str, BTreeSet<&'a str>>,
+            node: &'a str,
+            visited: &mut BTreeSet<&'a str>,
+            order: &mut Vec<&'a str>,
+        ) {
+            if !visited.insert(node) {
+                return;
+            }
+            for other in edges.get(node).into_iter().flatten() {
+                visit(edges, other, visited, order);
+            }
+            order.push(node);
+        }
+
+        let edges = self.edges();
+        let mut visited = BTreeSet::new();
+        let mut order = Vec::new();
+        for addon in self.addons.keys() {
+            visit(&edges, addon, &mut visited, &mut order);
+        }
+        order
+    }
+
+    #[must_use]
+    /// Groups of addons that require each other, found with Tarjan's algorithm
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        struct State<'a> {
+            edges: BTreeMap<&'a str, BTreeSet<&'a str>>,
+            index: BTreeMap<&'a str, usize>,
+            low: BTreeMap<&'a str, usize>,
+            stack: Vec<&'a str>,
+            cycles: Vec<Vec<String>>,
+        }
+
+        fn visit<'a>(state: &mut State<'a>, node: &'a str) {
+            let index = state.index.len();
+            state.index.insert(node, index);
+            state.low.insert(node, index);
+            state.stack.push(node);
+            let next = state.edges.get(node).cloned().unwrap_or_default();
+            for other in next {
+                if !state.index.contains_key(other) {
+                    visit(state, other);
+                    let low = state.low[node].min(state.low[other]);
+                    state.low.insert(node, low);
+                } else if state.stack.contains(&other) {
+                    let low = state.low[node].min(state.index[other]);
+                    state.low.insert(node, low);
+                }
+            }
+            if state.low[node] == state.index[node] {
+                let position = state
+                    .stack
+                    .iter()
+                    .position(|other| *other == node)
+                    .expect("node is on the stack");
+                let group = state.stack.split_off(position);
+                if group.len() > 1 {
+                    let mut group = group
+                        .into_iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>();
+                    group.sort();
+                    state.cycles.push(group);
+                }
+            }
+        }
+
+        let mut state = State {
+            edges: self.edges(),
+            index: BTreeMap::new(),
+            low: BTreeMap::new(),
+            stack: Vec::new(),
+            cycles: Vec::new(),
+        };
+        for addon in self.addons.keys() {
+            if !state.index.contains_key(addon.as_str()) {
+                visit(&mut state, addon);
+            }
+        }
+        state.cycles.sort();
+        state.cycles
+    }
+
+    #[must_use]
+    /// A Graphviz digraph of the addons, with the patches outside of the project if `external` is set
+    pub fn dot(&self, external: bool) -> String {
+        let mut out = String::from("digraph dependencies {\n");
+        for addon in self.addons.keys() {
+            writeln!(out, "    {};", dot::id(addon)).expect("writing to a string");
+        }
+        for (addon, required) in self.edges() {
+            for other in required {
+                writeln!(out, "    {} -> {};", dot::id(addon), dot::id(other))
+                    .expect("writing to a string");
+            }
+        }
+        if external {
+            let external = self.external();
+            let patches = external
+                .values()
+                .flatten()
+                .copied()
+                .collect::<BTreeSet<_>>();
+            for patch in &patches {
+                writeln!(out, "    {} [style=dashed];", dot::id(patch))
+                    .expect("writing to a string");
+            }
+            for (addon, required) in external {
+                for patch in required {
+                    writeln!(
+                        out,
+                        "    {} -> {} [style=dashed];",
+                        dot::id(addon),
+                        dot::id(patch)
+                    )
+                    .expect("writing to a string");
+                }
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+pub struct CyclicDependency {
+    addons: Vec<String>,
+}
+impl Code for CyclicDependency {
+    fn ident(&self) -> &'static str {
+        "BDE1"
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Addons {} require each other",
+            self.addons
+                .iter()
+                .map(|addon| format!("`{addon}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("the game can not find an order to load the addons in".to_string())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("remove one of the requirements from `requiredAddons[]` in `CfgPatches`".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl CyclicDependency {
+    #[must_use]
+    pub fn code(addons: Vec<String>) -> Arc<dyn Code> {
+        Arc::new(Self { addons })
+    }
+}
+
+pub struct MissingDependency {
+    addon: String,
+    patch: String,
+    required: String,
+}
+impl Code for MissingDependency {
+    fn ident(&self) -> &'static str {
+        "BDW1"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` in addon `{}` requires `{}`, which is not in the project",
+            self.patch, self.addon, self.required
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("check the name in `requiredAddons[]`, or remove it".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl MissingDependency {
+    #[must_use]
+    pub fn code(addon: String, patch: String, required: String) -> Arc<dyn Code> {
+        Arc::new(Self {
+            addon,
+            patch,
+            required,
+        })
+    }
+}
+
+pub struct UndeclaredDependency {
+    addon: String,
+    patch: String,
+    required: String,
+    similar: Vec<String>,
+}
+impl Code for UndeclaredDependency {
+    fn ident(&self) -> &'static str {
+        "BDW2"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` in addon `{}` requires `{}`, which is not declared in `[dependencies]`",
+            self.patch, self.addon, self.required
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        if self.similar.is_empty() {
+            Some(format!(
+                "add `{}` to the `patches` of a mod in `[dependencies]` of `.hemtt/project.toml`",
+                self.required
+            ))
+        } else {
+            Some(format!(
+                "did you mean {}?",
+                self.similar
+                    .iter()
+                    .map(|patch| format!("`{patch}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        }
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl UndeclaredDependency {
+    #[must_use]
+    pub fn code(
+        addon: String,
+        patch: String,
+        required: String,
+        similar: Vec<String>,
+    ) -> Arc<dyn Code> {
+        Arc::new(Self {
+            addon,
+            patch,
+            required,
+            similar,
+        })
+    }
+}
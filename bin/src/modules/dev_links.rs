@@ -0,0 +1,58 @@
+use std::{fs::create_dir_all, path::Path};
+
+use crate::{context::Context, error::Error, link::create_link, report::Report};
+
+use super::Module;
+
+#[derive(Default)]
+/// Links the folders in `hemtt.dev.link_to` to the dev build
+pub struct DevLinks;
+
+impl Module for DevLinks {
+    fn name(&self) -> &'static str {
+        "DevLinks"
+    }
+
+    fn post_build(&self, ctx: &Context) -> Result<Report, Error> {
+        let target = ctx.build_folder().expect("build folder exists");
+        for link in ctx.config().hemtt().dev().link_to() {
+            if link.trim().is_empty() {
+                continue;
+            }
+            let link = ctx.project_folder().join(link.trim());
+            if link.is_symlink() {
+                if link.read_link().is_ok_and(|existing| &existing == target) {
+                    continue;
+                }
+                debug!("replacing link {:?}", link);
+                remove_link(&link)?;
+            } else if link.exists() {
+                warn!(
+                    "`{}` from `hemtt.dev.link_to` already exists and is not a link, it will not be linked to the dev build",
+                    link.display()
+                );
+                continue;
+            }
+            if let Some(parent) = link.parent() {
+                create_dir_all(parent)?;
+            }
+            create_link(&link, target)?;
+            info!("Linked `{}` to the dev build", link.display());
+        }
+        Ok(Report::new())
+    }
+}
+
+#[cfg(windows)]
+/// Remove a junction or directory symbolic link, without touching its target
+fn remove_link(link: &Path) -> Result<(), Error> {
+    std::fs::remove_dir(link)?;
+    Ok(())
+}
+
+#[cfg(not(windows))]
+/// Remove a symbolic link, without touching its target
+fn remove_link(link: &Path) -> Result<(), Error> {
+    std::fs::remove_file(link)?;
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use reqwest::blocking::Client;
+use serde::Deserialize;
+
+use crate::{context::Context, error::Error, report::Report};
+
+use super::Module;
+
+#[derive(Default)]
+/// Creates a draft GitHub release with the versioned zip archive
+///
+/// The token is read from `GITHUB_TOKEN` or `GH_TOKEN`, and the notes from the
+/// section of the changelog for the version
+pub struct GitHubRelease {
+    token: String,
+    repository: String,
+}
+
+#[derive(Deserialize)]
+struct Release {
+    upload_url: String,
+    html_url: String,
+}
+
+impl Module for GitHubRelease {
+    fn name(&self) -> &'static str {
+        "GitHubRelease"
+    }
+
+    fn init(&mut self, ctx: &Context) -> Result<Report, Error> {
+        let Some(token) = ["GITHUB_TOKEN", "GH_TOKEN"]
+            .into_iter()
+            .find_map(|key| std::env::var(key).ok().filter(|token| !token.is_empty()))
+        else {
+            return Err(Error::GitHub(
+                "`GITHUB_TOKEN` or `GH_TOKEN` must be set to create a release".to_string(),
+            ));
+        };
+        self.token = token;
+        self.repository = if let Some(repository) = ctx.config().hemtt().release().github() {
+            repository.to_string()
+        } else {
+            let repo = git2::Repository::discover(ctx.project_folder())?;
+            let remote = repo.find_remote("origin")?;
+            remote.url().and_then(repository_from_url).ok_or_else(|| {
+                Error::GitHub(
+                    "the `origin` remote is not on GitHub, set `hemtt.release.github`".to_string(),
+                )
+            })?
+        };
+        Ok(Report::new())
+    }
+
+    fn post_release(&self, ctx: &Context) -> Result<Report, Error> {
+        let version = ctx.config().version().get(ctx.workspace_path().vfs())?;
+        let short = format!(
+            "{}.{}.{}",
+            version.major(),
+            version.minor(),
+            version.patch()
+        );
+        let tag = version
+            .build()
+            .map_or_else(|| format!("v{short}"), |build| format!("v{short}.{build}"));
+        let name = ctx.config().hemtt().release().archive_name(
+            ctx.config().name(),
+            ctx.config().prefix(),
+            &version.to_string(),
+        );
+        let archive = ctx
+            .project_folder()
+            .join("releases")
+            .join(format!("{name}.zip"));
+        if !archive.exists() {
+            return Err(Error::GitHub(format!(
+                "`{}` was not created, a release can not be drafted without an archive",
+                archive.display()
+            )));
+        }
+
+        let changelog = ctx
+            .project_folder()
+            .join(ctx.config().hemtt().release().changelog());
+        let body = if changelog.exists() {
+            let content = std::fs::read_to_string(&changelog)?;
+            let notes = changelog_section(&content, &tag[1..])
+                .or_else(|| changelog_section(&content, &short));
+            if notes.is_none() {
+                warn!(
+                    "No section for {short} in `{}`, the release notes are empty",
+                    changelog.display()
+                );
+            }
+            notes.unwrap_or_default()
+        } else {
+            String::new()
+        };
+        let commit = git2::Repository::discover(ctx.project_folder())
+            .and_then(|repo| {
+                repo.revparse_single("HEAD")
+                    .map(|head| head.id().to_string())
+            })
+            .ok();
+
+        let client = Client::builder()
+            .user_agent("HEMTT")
+            .build()
+            .map_err(|e| Error::GitHub(e.to_string()))?;
+        let response = client
+            .post(format!(
+                "https://api.github.com/repos/{}/releases",
+                self.repository
+            ))
+            .bearer_auth(&self.token)
+            .header("Accept", "application/vnd.github+json")
+            .json(&serde_json::json!({
+                "tag_name": tag,
+                "target_commitish": commit,
+                "name": format!("{} {tag}", ctx.config().name()),
+                "body": body,
+                "draft": true,
+            }))
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .map_err(|e| Error::GitHub(format!("failed to create the release: {e}")))?;
+        let release: Release = response
+            .json()
+            .map_err(|e| Error::GitHub(format!("failed to read the release: {e}")))?;
+
+        let upload = release
+            .upload_url
+            .split_once('{')
+            .map_or(release.upload_url.as_str(), |(url, _)| url);
+        client
+            .post(upload)
+            .query(&[(
+                "name",
+                archive
+                    .file_name()
+                    .expect("archive has a name")
+                    .to_string_lossy(),
+            )])
+            .bearer_auth(&self.token)
+            .header("Accept", "application/vnd.github+json")
+            .header("Content-Type", "application/zip")
+            .body(std::fs::read(&archive)?)
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .map_err(|e| Error::GitHub(format!("failed to upload `{name}.zip`: {e}")))?;
+        info!("Created draft release: {}", release.html_url);
+        Ok(Report::new())
+    }
+}
+
+#[must_use]
+/// The `owner/repo` of a GitHub remote url
+///
+/// - `https://github.com/owner/repo.git`
+/// - `git@github.com:owner/repo.git`
+pub fn repository_from_url(url: &str) -> Option<String> {
+    let (_, path) = url
+        .split_once("github.com/")
+        .or_else(|| url.split_once("github.com:"))?;
+    let path = path.trim_end_matches('/').trim_end_matches(".git");
+    let (owner, repo) = path.split_once('/')?;
+    if owner.is_empty() || repo.is_empty() || repo.contains('/') {
+        return None;
+    }
+    Some(format!("{owner}/{repo}"))
+}
+
+#[must_use]
+/// The notes under the heading of a version in a changelog, without the heading
+///
+/// The heading is any level of markdown heading that contains the version, such as `## v1.2.3 - 2024-01-01`,
+/// and the section ends at the next heading of the same or a higher level
+pub fn changelog_section(changelog: &str, version: &str) -> Option<String> {
+    let level = |line: &str| {
+        let hashes = line.chars().take_while(|c| *c == '#').count();
+        (hashes > 0 && line[hashes..].starts_with(' ')).then_some(hashes)
+    };
+    let mut lines = changelog.lines();
+    let heading = lines.by_ref().find_map(|line| {
+        let level = level(line)?;
+        line.split(|c: char| !(c.is_ascii_alphanumeric() || c == '.'))
+            .any(|word| word.trim_start_matches(['v', 'V']) == version)
+            .then_some(level)
+    })?;
+    let section = lines
+        .take_while(|line| level(line).is_none_or(|level| level > heading))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Some(section.trim().to_string())
+}
@@ -0,0 +1,59 @@
+use hemtt_workspace::addons::Addon;
+use rhai::plugin::{
+    export_module, Dynamic, FnNamespace, FuncRegistration, Module, NativeCallContext, PluginFunc,
+    RhaiResult, TypeId,
+};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct RhaiAddon {
+    name: String,
+    folder: String,
+    location: String,
+    prefix: String,
+    pbo_name: String,
+}
+
+impl RhaiAddon {
+    pub fn new(addon: &Addon, project_prefix: &str) -> Self {
+        Self {
+            name: addon.name().to_string(),
+            folder: addon.folder(),
+            location: addon.location().to_string(),
+            prefix: addon.prefix().to_string(),
+            pbo_name: addon.pbo_name(project_prefix),
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_ref_mut)]
+#[allow(clippy::unwrap_used)] // coming from rhai codegen
+#[export_module]
+pub mod addon_functions {
+    use crate::modules::hook::libraries::addon::RhaiAddon;
+
+    #[rhai_fn(global, pure)]
+    pub fn name(addon: &mut RhaiAddon) -> String {
+        addon.name.clone()
+    }
+
+    #[rhai_fn(global, pure)]
+    pub fn folder(addon: &mut RhaiAddon) -> String {
+        addon.folder.clone()
+    }
+
+    #[rhai_fn(global, pure)]
+    pub fn location(addon: &mut RhaiAddon) -> String {
+        addon.location.clone()
+    }
+
+    #[rhai_fn(global, pure)]
+    pub fn prefix(addon: &mut RhaiAddon) -> String {
+        addon.prefix.clone()
+    }
+
+    #[rhai_fn(global, pure)]
+    pub fn pbo_name(addon: &mut RhaiAddon) -> String {
+        addon.pbo_name.clone()
+    }
+}
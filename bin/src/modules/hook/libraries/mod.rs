@@ -1,5 +1,6 @@
 use rhai::{combine_with_exported_module, def_package};
 
+mod addon;
 pub mod hemtt;
 mod project;
 mod rfs;
@@ -11,6 +12,7 @@ def_package! {
         combine_with_exported_module!(lib, "hemtt", hemtt::project_functions);
         combine_with_exported_module!(lib, "hemtt_version", version::version_functions);
         combine_with_exported_module!(lib, "hemtt_project", project::project_functions);
+        combine_with_exported_module!(lib, "hemtt_addon", addon::addon_functions);
     }
 }
 
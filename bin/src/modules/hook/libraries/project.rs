@@ -6,6 +6,8 @@ use rhai::plugin::{
 
 use crate::context::Context;
 
+use super::addon::RhaiAddon;
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone)]
 pub struct RhaiProject {
@@ -13,7 +15,7 @@ pub struct RhaiProject {
     prefix: String,
     mainprefix: String,
     version: Version,
-    // addons: Vec<Addon>,
+    addons: Vec<RhaiAddon>,
 }
 
 impl RhaiProject {
@@ -30,7 +32,11 @@ impl RhaiProject {
                 .version()
                 .get(ctx.workspace_path().vfs())
                 .expect("version config is valid to get to rhai module"),
-            // addons: ctx.addons().to_vec(),
+            addons: ctx
+                .addons()
+                .iter()
+                .map(|addon| RhaiAddon::new(addon, ctx.config().prefix()))
+                .collect(),
         }
     }
 }
@@ -61,9 +67,8 @@ pub mod project_functions {
         project.version.clone()
     }
 
-    // TODO: Add functions to addons
-    // #[rhai_fn(global, pure)]
-    // pub fn addons(project: &mut RhaiProject) -> Vec<Addon> {
-    //     project.addons.clone()
-    // }
+    #[rhai_fn(global, pure)]
+    pub fn addons(project: &mut RhaiProject) -> rhai::Array {
+        project.addons.iter().cloned().map(Dynamic::from).collect()
+    }
 }
@@ -0,0 +1,108 @@
+use regex::Regex;
+
+use crate::{context::Context, error::Error, report::Report};
+
+use super::Module;
+
+#[derive(Default)]
+/// Keeps `mod.cpp` and `meta.cpp` in the release in sync with the project
+///
+/// `{name}`, `{prefix}`, `{version}`, and `{publishedid}` are replaced in both files,
+/// lines with `{publishedid}` are left out when there is no id.
+/// With `hemtt.release.publishedid` set, `meta.cpp` is created if it is missing,
+/// and its `publishedid` is updated if it is different
+pub struct Meta;
+
+impl Module for Meta {
+    fn name(&self) -> &'static str {
+        "Meta"
+    }
+
+    fn pre_release(&self, ctx: &Context) -> Result<Report, Error> {
+        let build = ctx.build_folder().expect("build folder exists");
+        let publishedid = ctx.config().hemtt().release().publishedid();
+        let version = ctx
+            .config()
+            .version()
+            .get(ctx.workspace_path().vfs())?
+            .to_string();
+        let template = |content: &str| {
+            template(
+                content,
+                ctx.config().name(),
+                ctx.config().prefix(),
+                &version,
+                publishedid,
+            )
+        };
+
+        let modcpp = build.join("mod.cpp");
+        if modcpp.exists() {
+            let existing = std::fs::read_to_string(&modcpp)?;
+            let content = template(&existing);
+            if content != existing {
+                debug!("updated mod.cpp");
+                std::fs::write(&modcpp, content)?;
+            }
+        }
+
+        let metacpp = build.join("meta.cpp");
+        if metacpp.exists() {
+            let existing = std::fs::read_to_string(&metacpp)?;
+            let mut content = template(&existing);
+            if let Some(id) = publishedid {
+                let regex =
+                    Regex::new(r"publishedid\s*=\s*\d+\s*;").expect("publishedid regex compiles");
+                content = regex
+                    .replace(&content, format!("publishedid = {id};"))
+                    .to_string();
+            }
+            if content != existing {
+                debug!("updated meta.cpp");
+                std::fs::write(&metacpp, content)?;
+            }
+        } else if let Some(id) = publishedid {
+            info!("Created meta.cpp for Workshop item {id}");
+            std::fs::write(
+                &metacpp,
+                format!(
+                    "protocol = 1;\npublishedid = {id};\nname = \"{}\";\n",
+                    escape(ctx.config().name())
+                ),
+            )?;
+        }
+        Ok(Report::new())
+    }
+}
+
+#[must_use]
+#[allow(clippy::literal_string_with_formatting_args)]
+/// Replace the placeholders of `mod.cpp` or `meta.cpp`, escaped for config strings
+///
+/// Lines with `{publishedid}` are left out without an id, instead of leaving an empty value
+pub fn template(
+    content: &str,
+    name: &str,
+    prefix: &str,
+    version: &str,
+    publishedid: Option<u64>,
+) -> String {
+    content
+        .split_inclusive('\n')
+        .filter(|line| publishedid.is_some() || !line.contains("{publishedid}"))
+        .map(|line| {
+            line.replace("{name}", &escape(name))
+                .replace("{prefix}", &escape(prefix))
+                .replace("{version}", &escape(version))
+                .replace(
+                    "{publishedid}",
+                    &publishedid.map_or_else(String::new, |id| id.to_string()),
+                )
+        })
+        .collect()
+}
+
+/// Escape a value for a config string, quotes are doubled
+fn escape(value: &str) -> String {
+    value.replace('"', "\"\"")
+}
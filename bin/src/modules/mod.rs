@@ -1,29 +1,84 @@
 use crate::{context::Context, error::Error, report::Report};
 
-mod binarize;
+mod audio;
+mod dev_links;
 mod file_patching;
 mod files;
+pub mod meta;
 mod new;
+mod paa;
 mod rapifier;
 mod sqf;
 mod stringtables;
 
 pub mod archive;
+pub mod binarize;
 pub mod bom;
+pub mod changes;
+pub mod deps;
+pub mod github;
 pub mod hook;
 pub mod pbo;
 pub(crate) mod sign;
 
+pub use audio::AudioConverter;
 pub use binarize::Binarize;
+pub use changes::Changes;
+pub use deps::Dependencies;
+pub use dev_links::DevLinks;
 pub use file_patching::FilePatching;
 pub use files::Files;
+pub use github::GitHubRelease;
 pub use hook::Hooks;
-pub use new::Licenses;
-pub use rapifier::{AddonConfigs, Rapifier};
+pub use meta::Meta;
+pub use new::{write_custom_addon, Licenses, Template};
+pub use paa::PaaConverter;
+pub use rapifier::{can_rapify, check_config, AddonConfigs, Rapifier};
 pub use sign::Sign;
 pub use sqf::SQFCompiler;
 pub use stringtables::Stringtables;
 
+#[linkme::distributed_slice]
+/// Modules registered by other crates with [`crate::module!`]
+///
+/// They are added to `hemtt check`, `hemtt dev`, `hemtt build`, and `hemtt release`
+/// with the global modules, before the modules of each command
+pub static MODULES: [fn() -> Box<dyn Module>];
+
+#[macro_export]
+/// Register a module from another crate, to run it in every build
+///
+/// The module must implement [`Default`] and [`Module`],
+/// the crate is then built into its own binary that calls [`crate::execute`]
+///
+/// ```ignore
+/// #[derive(Default)]
+/// struct Terrain;
+///
+/// impl hemtt::modules::Module for Terrain {
+///     fn name(&self) -> &'static str {
+///         "Terrain"
+///     }
+/// }
+///
+/// hemtt::module!(Terrain);
+/// ```
+macro_rules! module {
+    ($module:ty) => {
+        const _: () = {
+            #[$crate::linkme::distributed_slice($crate::modules::MODULES)]
+            #[linkme(crate = $crate::linkme)]
+            static MODULE: fn() -> Box<dyn $crate::modules::Module> = || Box::<$module>::default();
+        };
+    };
+}
+
+/// A step of the build, each phase is run for every module before the next phase
+///
+/// Modules have access to the project through the [`Context`], and the files of the project
+/// through [`Context::workspace_path`]. Warnings and errors are returned in a [`Report`],
+/// the build stops after the phase if any module reports an error.
+/// Data can be shared between modules with [`Context::state`].
 pub trait Module {
     fn name(&self) -> &'static str;
     /// Executes the module's `init` phase
@@ -1,2 +1,4 @@
 mod license;
+mod template;
 pub use license::Licenses;
+pub use template::{write_custom_addon, Template};
@@ -0,0 +1,282 @@
+use std::path::Path;
+
+/// The starting files of the `main` addon
+pub enum Template {
+    /// Only Arma 3
+    Vanilla,
+    /// Uses the CBA macros and extended event handlers
+    Cba,
+    /// Uses the ACE macros, which include the CBA macros
+    Ace,
+}
+
+impl Template {
+    #[must_use]
+    /// Has the user select a template
+    ///
+    /// # Panics
+    /// If there is a problem with dialoguer
+    pub fn select() -> Self {
+        let templates = vec!["None (Arma 3 only)", "CBA", "ACE"];
+
+        let selection = dialoguer::Select::new()
+            .with_prompt("Select a template")
+            .items(&templates)
+            .default(1)
+            .interact()
+            .expect("Failed to get template selection");
+
+        match selection {
+            0 => Self::Vanilla,
+            1 => Self::Cba,
+            2 => Self::Ace,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The files of the template, relative to the addon folder
+    fn files(&self) -> Vec<(&'static str, &'static str)> {
+        let mut files = vec![(
+            "script_version.hpp",
+            include_str!("templates/script_version.hpp"),
+        )];
+        match self {
+            Self::Vanilla => files.extend([
+                ("config.cpp", include_str!("templates/vanilla/config.cpp")),
+                (
+                    "script_component.hpp",
+                    include_str!("templates/vanilla/script_component.hpp"),
+                ),
+            ]),
+            Self::Cba | Self::Ace => files.extend([
+                ("config.cpp", include_str!("templates/cba/config.cpp")),
+                (
+                    "CfgEventHandlers.hpp",
+                    include_str!("templates/cba/CfgEventHandlers.hpp"),
+                ),
+                (
+                    "script_component.hpp",
+                    include_str!("templates/cba/script_component.hpp"),
+                ),
+                (
+                    "script_mod.hpp",
+                    include_str!("templates/cba/script_mod.hpp"),
+                ),
+                (
+                    "script_macros.hpp",
+                    if matches!(self, Self::Ace) {
+                        include_str!("templates/ace/script_macros.hpp")
+                    } else {
+                        include_str!("templates/cba/script_macros.hpp")
+                    },
+                ),
+                ("XEH_PREP.hpp", include_str!("templates/cba/XEH_PREP.hpp")),
+                (
+                    "XEH_preInit.sqf",
+                    include_str!("templates/cba/XEH_preInit.sqf"),
+                ),
+                (
+                    "XEH_preStart.sqf",
+                    include_str!("templates/cba/XEH_preStart.sqf"),
+                ),
+            ]),
+        }
+        files
+    }
+
+    /// The addons that `CfgPatches` requires
+    const fn requires(&self) -> &'static str {
+        match self {
+            Self::Vanilla => "\"A3_Data_F_Decade_Loadorder\"",
+            Self::Cba => "\"cba_main\"",
+            Self::Ace => "\"ace_common\"",
+        }
+    }
+
+    /// Write the `main` addon to `addons/main`
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if a file can not be written
+    pub fn write(
+        &self,
+        project: &Path,
+        name: &str,
+        author: &str,
+        prefix: &str,
+        mainprefix: &str,
+    ) -> Result<(), std::io::Error> {
+        write_addon(
+            project,
+            "main",
+            &self.files(),
+            &[
+                ("requires", self.requires()),
+                ("name", name),
+                ("author", author),
+                ("prefix", prefix),
+                ("mainprefix", mainprefix),
+            ],
+        )
+    }
+
+    #[must_use]
+    /// The template used by the `main` addon of an existing project
+    pub fn detect(project: &Path) -> Self {
+        if project
+            .join("addons")
+            .join("main")
+            .join("script_mod.hpp")
+            .exists()
+        {
+            Self::Cba
+        } else {
+            Self::Vanilla
+        }
+    }
+
+    /// The files of a new addon, relative to the addon folder
+    fn addon_files(&self) -> Vec<(&'static str, &'static str)> {
+        match self {
+            Self::Vanilla => vec![
+                (
+                    "config.cpp",
+                    include_str!("templates/addon/vanilla/config.cpp"),
+                ),
+                (
+                    "script_component.hpp",
+                    include_str!("templates/addon/vanilla/script_component.hpp"),
+                ),
+            ],
+            Self::Cba | Self::Ace => vec![
+                ("config.cpp", include_str!("templates/addon/cba/config.cpp")),
+                (
+                    "script_component.hpp",
+                    include_str!("templates/addon/cba/script_component.hpp"),
+                ),
+                (
+                    "CfgEventHandlers.hpp",
+                    include_str!("templates/cba/CfgEventHandlers.hpp"),
+                ),
+                ("XEH_PREP.hpp", include_str!("templates/cba/XEH_PREP.hpp")),
+                (
+                    "XEH_preInit.sqf",
+                    include_str!("templates/cba/XEH_preInit.sqf"),
+                ),
+                (
+                    "XEH_preStart.sqf",
+                    include_str!("templates/cba/XEH_preStart.sqf"),
+                ),
+            ],
+        }
+    }
+
+    /// Write a new addon to `addons/{addon}`
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if a file can not be written
+    pub fn write_addon(
+        &self,
+        project: &Path,
+        addon: &str,
+        values: &[(&str, &str)],
+    ) -> Result<(), std::io::Error> {
+        write_addon(project, addon, &self.addon_files(), values)
+    }
+
+    /// Where the macros of the template come from, if they are not part of the project
+    #[must_use]
+    pub const fn dependency(&self) -> Option<&'static str> {
+        match self {
+            Self::Vanilla => None,
+            Self::Cba => Some("CBA_A3"),
+            Self::Ace => Some("ACE3"),
+        }
+    }
+}
+
+/// Write the files and `$PBOPREFIX$` of an addon
+///
+/// `values` replace `{key}` in the files, and should contain `prefix` and `mainprefix`
+fn write_addon(
+    project: &Path,
+    addon: &str,
+    files: &[(&str, &str)],
+    values: &[(&str, &str)],
+) -> Result<(), std::io::Error> {
+    let folder = project.join("addons").join(addon);
+    std::fs::create_dir_all(&folder)?;
+    write_prefix(&folder, addon, values)?;
+    for (file, content) in files {
+        std::fs::write(folder.join(file), fill(content, values))?;
+    }
+    Ok(())
+}
+
+/// Write a new addon to `addons/{addon}` from the files in a template folder
+///
+/// Files that are valid UTF-8 have each `{key}` replaced with its value,
+/// a `$PBOPREFIX$` is added if the template does not have one
+///
+/// # Errors
+/// [`std::io::Error`] if a file can not be read or written
+///
+/// # Panics
+/// If walkdir returns a path outside of the template
+pub fn write_custom_addon(
+    template: &Path,
+    project: &Path,
+    addon: &str,
+    values: &[(&str, &str)],
+) -> Result<(), std::io::Error> {
+    let folder = project.join("addons").join(addon);
+    for entry in walkdir::WalkDir::new(template) {
+        let entry = entry?;
+        let target = folder.join(
+            entry
+                .path()
+                .strip_prefix(template)
+                .expect("walkdir entries are in the template"),
+        );
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&target)?;
+            continue;
+        }
+        let content = std::fs::read(entry.path())?;
+        match String::from_utf8(content) {
+            Ok(content) => std::fs::write(&target, fill(&content, values))?,
+            Err(e) => std::fs::write(&target, e.into_bytes())?,
+        }
+    }
+    if !folder.join("$PBOPREFIX$").exists() {
+        write_prefix(&folder, addon, values)?;
+    }
+    Ok(())
+}
+
+fn write_prefix(folder: &Path, addon: &str, values: &[(&str, &str)]) -> Result<(), std::io::Error> {
+    let value = |key: &str| {
+        values
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map_or("", |(_, value)| value)
+    };
+    let prefix = if value("mainprefix").is_empty() {
+        format!("{}\\addons\\{addon}\n", value("prefix"))
+    } else {
+        format!(
+            "{}\\{}\\addons\\{addon}\n",
+            value("mainprefix"),
+            value("prefix")
+        )
+    };
+    std::fs::write(folder.join("$PBOPREFIX$"), prefix)
+}
+
+/// Replace each `{key}` with its value
+fn fill(content: &str, values: &[(&str, &str)]) -> String {
+    values
+        .iter()
+        .fold(content.to_string(), |content, (key, value)| {
+            content.replace(&format!("{{{key}}}"), value)
+        })
+}
@@ -0,0 +1 @@
+#include "\z\ace\addons\main\script_macros.hpp"
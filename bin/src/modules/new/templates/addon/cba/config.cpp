@@ -0,0 +1,14 @@
+#include "script_component.hpp"
+
+class CfgPatches {
+    class ADDON {
+        name = COMPONENT_NAME;
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = REQUIRED_VERSION;
+        requiredAddons[] = {"{prefix}_main"};
+        VERSION_CONFIG;
+    };
+};
+
+#include "CfgEventHandlers.hpp"
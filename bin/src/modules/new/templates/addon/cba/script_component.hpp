@@ -0,0 +1,8 @@
+#define COMPONENT {addon}
+#define COMPONENT_BEAUTIFIED {addon_beautified}
+#include "\{mainprefix}\{prefix}\addons\main\script_mod.hpp"
+
+// #define DEBUG_MODE_FULL
+// #define DISABLE_COMPILE_CACHE
+
+#include "\{mainprefix}\{prefix}\addons\main\script_macros.hpp"
@@ -0,0 +1,11 @@
+#include "script_component.hpp"
+
+class CfgPatches {
+    class ADDON {
+        name = COMPONENT_NAME;
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = 2.18;
+        requiredAddons[] = {"{prefix}_main"};
+    };
+};
@@ -0,0 +1 @@
+// PREP(myFunction);
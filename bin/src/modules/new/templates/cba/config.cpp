@@ -0,0 +1,15 @@
+#include "script_component.hpp"
+
+class CfgPatches {
+    class ADDON {
+        name = COMPONENT_NAME;
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = REQUIRED_VERSION;
+        requiredAddons[] = {{requires}};
+        author = "{author}";
+        VERSION_CONFIG;
+    };
+};
+
+#include "CfgEventHandlers.hpp"
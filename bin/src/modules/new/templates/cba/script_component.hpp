@@ -0,0 +1,8 @@
+#define COMPONENT main
+#define COMPONENT_BEAUTIFIED Main
+#include "\{mainprefix}\{prefix}\addons\main\script_mod.hpp"
+
+// #define DEBUG_MODE_FULL
+// #define DISABLE_COMPILE_CACHE
+
+#include "\{mainprefix}\{prefix}\addons\main\script_macros.hpp"
@@ -0,0 +1,16 @@
+#define MAINPREFIX {mainprefix}
+#define PREFIX {prefix}
+
+#include "\{mainprefix}\{prefix}\addons\main\script_version.hpp"
+
+#define VERSION MAJOR.MINOR
+#define VERSION_STR MAJOR.MINOR.PATCH
+#define VERSION_AR MAJOR,MINOR,PATCH
+
+#define REQUIRED_VERSION 2.18
+
+#ifdef COMPONENT_BEAUTIFIED
+#define COMPONENT_NAME QUOTE({name} - COMPONENT_BEAUTIFIED)
+#else
+#define COMPONENT_NAME QUOTE({name} - COMPONENT)
+#endif
@@ -0,0 +1,12 @@
+#include "script_component.hpp"
+
+class CfgPatches {
+    class ADDON {
+        name = COMPONENT_NAME;
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = 2.18;
+        requiredAddons[] = {"A3_Data_F_Decade_Loadorder"};
+        author = "{author}";
+    };
+};
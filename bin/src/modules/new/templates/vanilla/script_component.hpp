@@ -0,0 +1,6 @@
+#define MAINPREFIX {mainprefix}
+#define PREFIX {prefix}
+#define COMPONENT main
+
+#define ADDON PREFIX##_##COMPONENT
+#define COMPONENT_NAME "{name} - Main"
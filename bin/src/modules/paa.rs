@@ -0,0 +1,173 @@
+use std::{
+    io::{Read, Write},
+    sync::{
+        atomic::{AtomicU16, Ordering},
+        Arc,
+    },
+};
+
+use hemtt_workspace::{
+    reporting::{Code, Diagnostic},
+    WorkspacePath,
+};
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use vfs::VfsFileType;
+
+use crate::{context::Context, error::Error, progress::progress_bar, report::Report};
+
+use super::Module;
+
+/// Extensions of images that are converted
+const EXTENSIONS: [&str; 2] = ["png", "tga"];
+
+#[derive(Default)]
+/// Converts `.png` and `.tga` files to `.paa` in addons with `[paa] enabled = true`
+pub struct PaaConverter;
+
+impl Module for PaaConverter {
+    fn name(&self) -> &'static str {
+        "PaaConverter"
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        let counter = AtomicU16::new(0);
+        let glob_options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+        let mut entries = Vec::new();
+        for addon in ctx.addons() {
+            let Some(config) = addon.config() else {
+                continue;
+            };
+            if !config.paa().enabled() {
+                continue;
+            }
+            let globs = config
+                .paa()
+                .exclude()
+                .iter()
+                .map(|file| glob::Pattern::new(file))
+                .collect::<Result<Vec<_>, _>>()?;
+            for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
+                if entry.metadata()?.file_type != VfsFileType::File
+                    || !entry
+                        .extension()
+                        .is_some_and(|ext| EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+                {
+                    continue;
+                }
+                let relative = entry
+                    .as_str()
+                    .trim_start_matches(&format!("/{}/", addon.folder()))
+                    .to_string();
+                if globs
+                    .iter()
+                    .any(|pat| pat.matches_with(&relative, glob_options))
+                {
+                    debug!("skipping {}", entry.as_str());
+                    continue;
+                }
+                if entry.with_extension("paa")?.exists()? {
+                    debug!("skipping {}, a paa already exists", entry.as_str());
+                    continue;
+                }
+                entries.push(entry);
+            }
+        }
+
+        let progress = progress_bar(entries.len() as u64).with_message("Converting Textures");
+        let codes = entries
+            .par_iter()
+            .map(|entry| {
+                let code = convert(entry)?;
+                if code.is_none() {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+                progress.inc(1);
+                Ok(code)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        report.extend(codes.into_iter().flatten().collect());
+
+        progress.finish_and_clear();
+        info!("Converted {} textures", counter.load(Ordering::Relaxed));
+        Ok(report)
+    }
+}
+
+/// Convert an image to a `.paa` next to it, and remove the image from the build
+fn convert(entry: &WorkspacePath) -> Result<Option<Arc<dyn Code>>, Error> {
+    let mut buffer = Vec::new();
+    entry.open_file()?.read_to_end(&mut buffer)?;
+    let image = match image::load_from_memory(&buffer) {
+        Ok(image) => image.to_rgba8(),
+        Err(e) => {
+            return Ok(Some(Arc::new(CodePaaConvertFailed::new(
+                entry.clone(),
+                e.to_string(),
+            ))))
+        }
+    };
+    let mut paa = Vec::new();
+    if let Err(e) = hemtt_paa::Paa::write(&image, &mut paa) {
+        return Ok(Some(Arc::new(CodePaaConvertFailed::new(
+            entry.clone(),
+            e.to_string(),
+        ))));
+    }
+    entry
+        .with_extension("paa")?
+        .create_file()?
+        .write_all(&paa)?;
+    entry.vfs().remove_file()?;
+    trace!("converted {}", entry.as_str());
+    Ok(None)
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodePaaConvertFailed {
+    path: WorkspacePath,
+    reason: String,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodePaaConvertFailed {
+    fn ident(&self) -> &'static str {
+        "PAA-CONVERT-FAILED"
+    }
+
+    fn message(&self) -> String {
+        format!("Texture at `{}` could not be converted to paa", self.path)
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(self.reason.clone())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("add the file to `paa.exclude` in the addon.toml to leave it as it is".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodePaaConvertFailed {
+    #[must_use]
+    pub fn new(path: WorkspacePath, reason: String) -> Self {
+        Self {
+            path,
+            reason,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self));
+        self
+    }
+}
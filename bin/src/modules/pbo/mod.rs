@@ -0,0 +1,401 @@
+use std::{
+    collections::HashMap,
+    fs::{create_dir_all, File},
+    io::{Seek, SeekFrom},
+    sync::{
+        atomic::{AtomicU16, Ordering},
+        Arc,
+    },
+};
+
+use git2::Repository;
+use hemtt_common::{
+    prefix::{self, Prefix},
+    version::Version,
+};
+use hemtt_pbo::WritablePbo;
+use hemtt_workspace::{
+    addons::{Addon, Location},
+    reporting::{Code, Diagnostic, Severity},
+};
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use vfs::VfsFileType;
+
+use crate::{context::Context, error::Error, progress::progress_bar, report::Report, timings};
+
+use self::transform::Transform;
+
+mod transform;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Should the optional and compat PBOs be collapsed into the addons folder
+pub enum Collapse {
+    /// Yes, used for development
+    Yes,
+    /// No, used for build and release
+    No,
+}
+
+/// Builds the PBOs
+///
+/// # Errors
+/// [`Error`] depending on the modules
+/// [`Error::Io`] if the PBO fails to write
+/// [`Error::Version`] if the version is invalid
+/// [`Error::Git`] if the git hash is invalid
+/// [`Error::Pbo`] if the PBO fails to write
+pub fn build(ctx: &Context, collapse: Collapse) -> Result<Report, Error> {
+    let version = ctx.config().version().get(ctx.workspace_path().vfs())?;
+    let git_hash = {
+        Repository::discover(".").map_or(None, |repo| {
+            repo.revparse_single("HEAD").map_or(None, |rev| {
+                let id = rev.id().to_string();
+                Some(id)
+            })
+        })
+    };
+    let transform = Transform::new(ctx)?;
+    let counter = AtomicU16::new(0);
+    let progress = progress_bar(ctx.addons().len() as u64).with_message("Building PBOs");
+    let results = ctx
+        .addons()
+        .par_iter()
+        .map(|addon| {
+            let span = timings::span("pbo", addon.name());
+            let result = internal_build(
+                ctx,
+                addon,
+                collapse,
+                &version,
+                git_hash.as_ref(),
+                transform.as_ref(),
+            );
+            drop(span);
+            progress.inc(1);
+            if result.is_ok() {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+            (addon, result)
+        })
+        .collect::<Vec<_>>();
+    progress.finish_and_clear();
+    info!("Built {} PBOs", counter.load(Ordering::Relaxed));
+    let mut report = Report::new();
+    let mut first_error = None;
+    // every failed addon is logged, so one failure does not hide the others
+    for (addon, result) in results {
+        match result {
+            Ok(new_report) => report.merge(new_report),
+            Err(e) => {
+                error!("Failed to build `{}`: {e}", addon.name());
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    if let Some(e) = first_error {
+        return Err(e);
+    }
+    Ok(report)
+}
+
+#[allow(clippy::too_many_lines)]
+fn internal_build(
+    ctx: &Context,
+    addon: &Addon,
+    collapse: Collapse,
+    version: &Version,
+    git_hash: Option<&String>,
+    transform: Option<&Transform>,
+) -> Result<Report, Error> {
+    let mut report = Report::new();
+    let mut pbo = WritablePbo::new();
+    let target = ctx.build_folder().expect("build folder exists");
+
+    let pbo_name = addon.pbo_name(ctx.config().prefix());
+
+    let target_pbo = {
+        let mut path = match collapse {
+            Collapse::No => match addon.location() {
+                Location::Addons => target.join("addons").join(pbo_name),
+                Location::Optionals => {
+                    if ctx.config().hemtt().build().optional_mod_folders() {
+                        target
+                            .join("optionals")
+                            .join(format!(
+                                "@{}",
+                                addon.pbo_name(ctx.config().hemtt().release().folder())
+                            ))
+                            .join("addons")
+                            .join(pbo_name)
+                    } else {
+                        target.join(addon.location().to_string()).join(pbo_name)
+                    }
+                }
+            },
+            Collapse::Yes => target.join("addons").join(pbo_name),
+        };
+        path.set_extension("pbo");
+        path
+    };
+    let Some(parent) = target_pbo.parent() else {
+        return Err(Error::Io(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "target_pbo is at root",
+        )));
+    };
+    if parent.exists() {
+        debug!("{:?} already exists", parent);
+    } else {
+        debug!("creating {:?}", parent);
+        create_dir_all(parent)?;
+    }
+    debug!(
+        "building {:?} => {:?}",
+        addon.folder(),
+        target_pbo.display()
+    );
+
+    pbo.add_property("hemtt", env!("HEMTT_VERSION"));
+    pbo.add_property("version", version.to_string());
+
+    // paths inside a PBO are case-insensitive, keyed by the lowercase path
+    let mut added: HashMap<String, String> = HashMap::new();
+    let mut prefixes = Vec::new();
+    // compression is only used for `hemtt build` and `hemtt release`
+    let compression = if matches!(ctx.folder().map(String::as_str), Some("build" | "release")) {
+        Some(ctx.config().hemtt().build().compression())
+    } else {
+        None
+    };
+    let keep_sqf = ctx.config().hemtt().build().sqf_keep_original();
+    let glob_options = glob::MatchOptions {
+        require_literal_separator: true,
+        ..Default::default()
+    };
+    let mut entries = ctx.workspace_path().join(addon.folder())?.walk_dir()?;
+    if ctx.deterministic() {
+        // the first of two duplicate files is packed, so the order must not depend on the file system
+        entries.sort_by_key(|entry| entry.as_str().to_string());
+    }
+    'entries: for entry in entries {
+        if entry.metadata()?.file_type == VfsFileType::File {
+            if entry.filename() == "config.cpp" && entry.parent().join("config.bin")?.exists()? {
+                continue;
+            }
+
+            if entry.filename() == "addon.toml" {
+                continue;
+            }
+
+            // the source stays in the workspace, so it can still be included and rebuilt by `hemtt watch`
+            if !keep_sqf
+                && entry.extension().as_deref() == Some("sqf")
+                && entry
+                    .parent()
+                    .join(format!("{}c", entry.filename()))?
+                    .exists()?
+            {
+                trace!("excluding compiled {:?}", entry.as_str());
+                continue;
+            }
+
+            for exclude in ctx.config().files().exclude() {
+                if glob::Pattern::new(exclude)?
+                    .matches_with(entry.as_str().trim_start_matches('/'), glob_options)
+                {
+                    continue 'entries;
+                }
+            }
+            if let Some(config) = addon.config() {
+                for exclude in config.files().exclude() {
+                    if glob::Pattern::new(exclude)?.matches_with(
+                        entry
+                            .as_str()
+                            .trim_start_matches(&format!("/{}/", addon.folder())),
+                        glob_options,
+                    ) {
+                        continue 'entries;
+                    }
+                }
+            }
+
+            if transform.is_some_and(|t| {
+                t.excluded(
+                    entry
+                        .as_str()
+                        .trim_start_matches(&format!("/{}/", addon.folder())),
+                )
+            }) {
+                trace!("excluding {:?} from the release", entry.as_str());
+                continue;
+            }
+
+            if let Some(precedence) = prefix::precedence(&entry.filename()) {
+                prefixes.push((precedence, entry.filename(), entry.read_to_string()?));
+                continue;
+            }
+
+            let file = entry
+                .as_str()
+                .trim_start_matches(&format!("/{}/", addon.folder()))
+                .replace('/', "\\");
+            let file = match transform {
+                Some(transform) => transform.rename(file),
+                None => file,
+            };
+            if let Some(existing) = added.get(&file.to_lowercase()) {
+                report.push(DuplicateFile::code(
+                    file,
+                    existing.clone(),
+                    entry.as_str().to_string(),
+                ));
+                continue;
+            }
+            added.insert(file.to_lowercase(), entry.as_str().to_string());
+            let mut content = match transform {
+                Some(transform) => transform.content(&entry)?,
+                None => entry.open_file()?,
+            };
+            // the size of what is packed, which is smaller than the source when it is minified
+            let size = content.seek(SeekFrom::End(0))?;
+            content.rewind()?;
+            if compression.is_some_and(|c| c.should_compress(&file, size)) {
+                trace!("adding compressed file {:?}", file);
+                pbo.add_file_compressed(file, content)?;
+            } else {
+                trace!("adding file {:?}", file);
+                pbo.add_file(file, content)?;
+            }
+        }
+    }
+    prefixes.sort();
+    if let Some((_, file, content)) = prefixes.first() {
+        let prefix = Prefix::new(content)?;
+        let ignored = prefixes
+            .iter()
+            .skip(1)
+            .filter(|(_, _, other)| Prefix::new(other).as_ref() != Ok(&prefix))
+            .map(|(_, other, _)| other.clone())
+            .collect::<Vec<_>>();
+        if !ignored.is_empty() {
+            report.push(ConflictingPrefix::code(
+                addon.folder(),
+                file.clone(),
+                ignored,
+            ));
+        }
+        pbo.add_property("prefix", prefix.to_string());
+        if let Some(hash) = git_hash {
+            pbo.add_property("git", hash);
+        }
+    }
+    for header in ctx.config().properties() {
+        pbo.add_property(header.0, header.1.clone());
+    }
+    if let Some(config) = addon.config() {
+        for header in config.properties() {
+            pbo.add_property(header.0, header.1.clone());
+        }
+    }
+    if ctx.deterministic() {
+        pbo.normalize();
+    }
+    pbo.write(&mut File::create(target_pbo)?, true)?;
+    Ok(report)
+}
+
+pub struct DuplicateFile {
+    file: String,
+    first: String,
+    second: String,
+}
+impl Code for DuplicateFile {
+    fn ident(&self) -> &'static str {
+        "BPE1"
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` and `{}` are both packed as `{}`",
+            self.first, self.second, self.file
+        )
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(
+            "paths inside a PBO are case-insensitive, only one of the files was packed".to_string(),
+        )
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("rename or remove one of the files".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl DuplicateFile {
+    #[must_use]
+    pub fn code(file: String, first: String, second: String) -> Arc<dyn Code> {
+        Arc::new(Self {
+            file,
+            first,
+            second,
+        })
+    }
+}
+
+pub struct ConflictingPrefix {
+    addon: String,
+    used: String,
+    ignored: Vec<String>,
+}
+impl Code for ConflictingPrefix {
+    fn ident(&self) -> &'static str {
+        "BPW1"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn message(&self) -> String {
+        format!("Addon `{}` has conflicting prefix files", self.addon)
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(format!(
+            "using `{}`, ignoring {}",
+            self.used,
+            self.ignored
+                .iter()
+                .map(|f| format!("`{f}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "remove the unused prefix files, the order of precedence is {}",
+            prefix::FILES.join(", ")
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl ConflictingPrefix {
+    #[must_use]
+    pub fn code(addon: String, used: String, ignored: Vec<String>) -> Arc<dyn Code> {
+        Arc::new(Self {
+            addon,
+            used,
+            ignored,
+        })
+    }
+}
@@ -0,0 +1,150 @@
+//! Changes made to files as they are packed for `hemtt release`
+//!
+//! Set in `[hemtt.release]`, files can be left out, renamed, or minified.
+//! Minified files keep their line breaks, so line numbers in errors still match the source.
+
+use std::{collections::HashMap, io::Cursor};
+
+use hemtt_workspace::WorkspacePath;
+use vfs::SeekAndRead;
+
+use crate::{context::Context, error::Error};
+
+/// Extensions of text files that are minified
+const MINIFY: [&str; 6] = ["sqf", "hpp", "h", "inc", "ext", "cpp"];
+
+pub struct Transform {
+    minify: bool,
+    exclude: Vec<glob::Pattern>,
+    /// Renames keyed by the lowercase path inside the PBO
+    rename: HashMap<String, String>,
+}
+
+impl Transform {
+    /// The transform for a build, `None` unless the build is a release
+    ///
+    /// # Errors
+    /// [`Error::GlobPattern`] if an exclude is not a valid glob
+    pub fn new(ctx: &Context) -> Result<Option<Self>, Error> {
+        if ctx.folder().map(String::as_str) != Some("release") {
+            return Ok(None);
+        }
+        let options = ctx.config().hemtt().release();
+        Ok(Some(Self {
+            minify: options.minify(),
+            exclude: options
+                .exclude()
+                .iter()
+                .map(|file| glob::Pattern::new(file))
+                .collect::<Result<Vec<_>, _>>()?,
+            rename: options
+                .rename()
+                .iter()
+                .map(|(from, to)| (normalize(from).to_lowercase(), normalize(to)))
+                .collect(),
+        }))
+    }
+
+    /// Is the file, relative to the addon, left out of the PBO
+    pub fn excluded(&self, file: &str) -> bool {
+        let options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+        self.exclude
+            .iter()
+            .any(|pat| pat.matches_with(file, options))
+    }
+
+    /// The name of the file inside the PBO
+    pub fn rename(&self, file: String) -> String {
+        let Some(to) = self.rename.get(&file.to_lowercase()) else {
+            return file;
+        };
+        trace!("renaming {:?} to {:?}", file, to);
+        to.clone()
+    }
+
+    /// The content of the file to pack
+    ///
+    /// # Errors
+    /// [`Error::Workspace`] if the file can not be read
+    pub fn content(&self, entry: &WorkspacePath) -> Result<Box<dyn SeekAndRead + Send>, Error> {
+        if self.minify
+            && entry
+                .extension()
+                .is_some_and(|ext| MINIFY.contains(&ext.to_lowercase().as_str()))
+        {
+            // files that are not text are packed as they are
+            if let Ok(source) = entry.read_to_string() {
+                trace!("minifying {:?}", entry.as_str());
+                return Ok(Box::new(Cursor::new(minify(&source).into_bytes())));
+            }
+        }
+        Ok(entry.open_file()?)
+    }
+}
+
+fn normalize(path: &str) -> String {
+    path.replace('/', "\\").trim_start_matches('\\').to_string()
+}
+
+/// Remove comments and the whitespace around each line, keeping the line breaks
+///
+/// Strings are left as they are, including any that span multiple lines
+pub fn minify(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut line = String::new();
+    // the current line started inside a string, so its indentation is part of it
+    let mut continued = false;
+    let mut quote = None;
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quote, chars.peek()) {
+            ('\n', _, _) => {
+                end_line(&mut out, &mut line, continued, quote.is_some());
+                continued = quote.is_some();
+            }
+            (_, Some(q), _) => {
+                line.push(c);
+                if c == q {
+                    quote = None;
+                }
+            }
+            ('"' | '\'', None, _) => {
+                quote = Some(c);
+                line.push(c);
+            }
+            ('/', None, Some('/')) => while chars.next_if(|c| *c != '\n').is_some() {},
+            ('/', None, Some('*')) => {
+                chars.next();
+                let mut last = None;
+                while let Some(c) = chars.next_if(|c| !(last == Some('*') && *c == '/')) {
+                    // line breaks in comments are kept, so the line numbers do not change
+                    if c == '\n' {
+                        end_line(&mut out, &mut line, continued, false);
+                        continued = false;
+                    }
+                    last = Some(c);
+                }
+                chars.next();
+            }
+            _ => line.push(c),
+        }
+    }
+    let start = if continued { &line } else { line.trim_start() };
+    out.push_str(start.trim_end());
+    out
+}
+
+/// Add a line to the output, trimmed where it is not inside a string
+fn end_line(out: &mut String, line: &mut String, continued: bool, in_string: bool) {
+    let start = if continued {
+        line.as_str()
+    } else {
+        line.trim_start()
+    };
+    out.push_str(if in_string { start } else { start.trim_end() });
+    out.push('\n');
+    line.clear();
+}
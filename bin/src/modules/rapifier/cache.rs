@@ -0,0 +1,376 @@
+//! Cache of rapified files in `.hemttout/cache/rapify`
+//!
+//! Each file has an entry that lists the file, everything it includes, and the files it looked for.
+//! The entry is only used when the hash of those files, the project config and schemas,
+//! the defines of the command and the project version, and the HEMTT version matches, otherwise the file is processed again.
+//! Files that produced any diagnostics are never cached, so they are always reported,
+//! except for unused includes, which are stored with the entry and reported again when it is used.
+//! Files with `CfgFunctions` are never cached, as their functions are checked against the files on disk.
+
+use std::{
+    fmt::Write,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use hemtt_common::version::Version;
+use hemtt_stringtable::analyze::keys::KeyLocation;
+use hemtt_workspace::{
+    addons::{DeclaredFunction, Patch, SoundShaderReference},
+    position::{LineCol, Position},
+    reporting::Processed,
+    WorkspacePath,
+};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use crate::context::Context;
+
+pub struct Cache {
+    folder: PathBuf,
+    /// The project prefix, projects in a workspace share the cache folder
+    prefix: String,
+    root: WorkspacePath,
+    /// Hash of everything that is not a source file
+    base: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    hash: String,
+    /// The file and every file it includes
+    sources: Vec<Source>,
+    /// Files that were looked for, with the file they were located from and if they were found
+    probes: Vec<(Source, String, bool)>,
+    /// The version required by `CfgPatches`, only set for `config.cpp`
+    required_version: Option<(Version, Source, Range<usize>)>,
+    /// The functions declared in `CfgFunctions` and used in strings, only set for `config.cpp`
+    functions: Option<CachedFunctions>,
+    /// The classes in `CfgPatches`, empty for files other than `config.cpp`
+    patches: Vec<Patch>,
+    /// The sound shaders declared and used by sound sets, only set for `config.cpp`
+    sound_shaders: Option<CachedSoundShaders>,
+    /// The preprocessor asked for the file to not be rapified
+    no_rapify: bool,
+    /// Includes of the file that are not used, reported as `PW5`
+    unused_includes: Vec<(CachedPosition, Source)>,
+    /// The stringtable keys used by the file
+    keys: Vec<(String, Source, Range<usize>)>,
+}
+
+type CachedFunctions = (
+    Vec<(String, Option<String>, Source, Range<usize>)>,
+    Vec<String>,
+);
+
+type CachedSoundShaders = (Vec<String>, Vec<(String, String, Source, Range<usize>)>);
+
+/// The layer of a file and its path, files in different layers can share a path
+type Source = (String, String);
+
+/// The file, and the offset, line, and column of the start and end
+type CachedPosition = (Source, (usize, usize, usize), (usize, usize, usize));
+
+#[derive(Default)]
+/// What is restored from the cache for a file
+pub struct Cached {
+    pub required_version: Option<(Version, WorkspacePath, Range<usize>)>,
+    pub functions: Option<(Vec<DeclaredFunction>, Vec<String>)>,
+    pub patches: Vec<Patch>,
+    pub sound_shaders: Option<(Vec<String>, Vec<SoundShaderReference>)>,
+    /// The rapified output, [`None`] if the file is not rapified
+    pub output: Option<Vec<u8>>,
+    /// The position of each unused include, and the file it includes
+    pub unused_includes: Vec<(Position, WorkspacePath)>,
+    /// The stringtable keys used by the file
+    pub keys: Vec<KeyLocation>,
+}
+
+impl Cache {
+    /// Open the cache, [`None`] if it is disabled
+    pub fn new(ctx: &Context) -> Option<Self> {
+        let folder = ctx.cache_folder()?.join("rapify");
+        if let Err(e) = std::fs::create_dir_all(&folder) {
+            warn!("unable to create rapify cache: {e}");
+            return None;
+        }
+        let config = match serde_json::to_vec(ctx.config()) {
+            Ok(config) => config,
+            Err(e) => {
+                warn!("unable to hash project config for the rapify cache: {e}");
+                return None;
+            }
+        };
+        let mut hasher = Sha1::new();
+        hasher.update(env!("HEMTT_VERSION"));
+        hasher.update(config);
+        // the defines differ between commands that share the cache
+        for define in ctx.workspace_path().workspace().defines() {
+            hasher.update(define);
+        }
+        // the `VERSION_*` defines change with the version, which is not part of the project config
+        if ctx.config().version().defines() {
+            if let Ok(version) = ctx.config().version().get(ctx.workspace_path().vfs()) {
+                hasher.update(version.to_string());
+            }
+        }
+        // schemas are read from `.hemtt/schemas`, and are not serialized with the project config
+        for schema in ctx.config().schemas() {
+            hasher.update(schema.name());
+            hasher.update(schema.source().len().to_le_bytes());
+            hasher.update(schema.source());
+        }
+        // lints can be overridden per addon
+        for addon in ctx.addons() {
+            if let Some(config) = addon.config() {
+                hasher.update(addon.folder());
+                hasher.update(serde_json::to_vec(config.lints()).ok()?);
+            }
+        }
+        Some(Self {
+            folder,
+            prefix: ctx.config().prefix().clone(),
+            root: ctx.workspace_path().clone(),
+            base: hasher.finalize().to_vec(),
+        })
+    }
+
+    /// Get the cached output of a file, if none of its sources have changed
+    pub fn get(&self, path: &WorkspacePath) -> Option<Cached> {
+        let (entry_path, output_path) = self.paths(path);
+        let entry: Entry = serde_json::from_slice(&std::fs::read(entry_path).ok()?).ok()?;
+        let sources = entry
+            .sources
+            .iter()
+            .map(|source| self.resolve(source))
+            .collect::<Option<Vec<_>>>()?;
+        if self.hash(&sources)? != entry.hash {
+            return None;
+        }
+        for (from, probe, found) in &entry.probes {
+            if self.resolve(from)?.locate(probe).ok()?.is_some() != *found {
+                return None;
+            }
+        }
+        let output = if entry.no_rapify {
+            None
+        } else {
+            Some(std::fs::read(output_path).ok()?)
+        };
+        let required_version = match entry.required_version {
+            Some((version, file, span)) => Some((version, self.resolve(&file)?, span)),
+            None => None,
+        };
+        let functions = match entry.functions {
+            Some((declared, references)) => Some((
+                declared
+                    .into_iter()
+                    .map(|(name, source, file, span)| {
+                        Some(DeclaredFunction::new(
+                            name,
+                            source,
+                            self.resolve(&file)?,
+                            span,
+                        ))
+                    })
+                    .collect::<Option<Vec<_>>>()?,
+                references,
+            )),
+            None => None,
+        };
+        let sound_shaders = match entry.sound_shaders {
+            Some((shaders, references)) => Some((
+                shaders,
+                references
+                    .into_iter()
+                    .map(|(shader, sound_set, file, span)| {
+                        Some(SoundShaderReference::new(
+                            shader,
+                            sound_set,
+                            self.resolve(&file)?,
+                            span,
+                        ))
+                    })
+                    .collect::<Option<Vec<_>>>()?,
+            )),
+            None => None,
+        };
+        let unused_includes = entry
+            .unused_includes
+            .iter()
+            .map(|(position, file)| Some((self.position(position)?, self.resolve(file)?)))
+            .collect::<Option<Vec<_>>>()?;
+        let keys = entry
+            .keys
+            .into_iter()
+            .map(|(key, file, span)| Some(KeyLocation::new(key, self.resolve(&file)?, span)))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Cached {
+            required_version,
+            functions,
+            patches: entry.patches,
+            sound_shaders,
+            output,
+            unused_includes,
+            keys,
+        })
+    }
+
+    /// Store the output of a file
+    pub fn insert(&self, path: &WorkspacePath, processed: &Processed, cached: &Cached) {
+        let (entry_path, output_path) = self.paths(path);
+        let mut sources = vec![path.to_owned()];
+        for include in processed.included_files() {
+            if !sources.contains(include) {
+                sources.push(include.clone());
+            }
+        }
+        let Some(hash) = self.hash(&sources) else {
+            return;
+        };
+        let entry = Entry {
+            hash,
+            sources: sources.iter().map(source).collect(),
+            probes: processed
+                .probes()
+                .iter()
+                .map(|probe| {
+                    (
+                        source(probe.from()),
+                        probe.path().to_string(),
+                        probe.found(),
+                    )
+                })
+                .collect(),
+            required_version: cached
+                .required_version
+                .as_ref()
+                .map(|(version, file, span)| (version.clone(), source(file), span.clone())),
+            functions: cached.functions.as_ref().map(|(declared, references)| {
+                (
+                    declared
+                        .iter()
+                        .map(|function| {
+                            (
+                                function.name().to_string(),
+                                function.source().map(ToString::to_string),
+                                source(function.file()),
+                                function.span().clone(),
+                            )
+                        })
+                        .collect(),
+                    references.clone(),
+                )
+            }),
+            patches: cached.patches.clone(),
+            sound_shaders: cached.sound_shaders.as_ref().map(|(shaders, references)| {
+                (
+                    shaders.clone(),
+                    references
+                        .iter()
+                        .map(|reference| {
+                            (
+                                reference.shader().to_string(),
+                                reference.sound_set().to_string(),
+                                source(reference.file()),
+                                reference.span().clone(),
+                            )
+                        })
+                        .collect(),
+                )
+            }),
+            no_rapify: cached.output.is_none(),
+            unused_includes: cached
+                .unused_includes
+                .iter()
+                .map(|(position, file)| {
+                    let linecol =
+                        |linecol: &LineCol| (linecol.offset(), linecol.line(), linecol.column());
+                    (
+                        (
+                            source(position.path()),
+                            linecol(position.start()),
+                            linecol(position.end()),
+                        ),
+                        source(file),
+                    )
+                })
+                .collect(),
+            keys: cached
+                .keys
+                .iter()
+                .map(|key| (key.key().to_string(), source(key.file()), key.span()))
+                .collect(),
+        };
+        let result = (|| -> std::io::Result<()> {
+            if let Some(output) = &cached.output {
+                write(&output_path, output)?;
+            }
+            write(&entry_path, &serde_json::to_vec(&entry)?)
+        })();
+        if let Err(e) = result {
+            debug!("unable to cache {}: {e}", path.as_str());
+        }
+    }
+
+    fn paths(&self, path: &WorkspacePath) -> (PathBuf, PathBuf) {
+        let name = hex(&Sha1::digest(format!(
+            "{}{}{}",
+            self.prefix,
+            path.layer(),
+            path.as_str()
+        )));
+        (
+            self.folder.join(format!("{name}.json")),
+            self.folder.join(format!("{name}.bin")),
+        )
+    }
+
+    /// Hash the sources, [`None`] if any of them can not be read
+    fn hash(&self, sources: &[WorkspacePath]) -> Option<String> {
+        let mut hasher = Sha1::new();
+        hasher.update(&self.base);
+        for source in sources {
+            let content = source.read_to_string().ok()?;
+            let layer = source.layer();
+            hasher.update(layer.len().to_le_bytes());
+            hasher.update(layer);
+            hasher.update(source.as_str().len().to_le_bytes());
+            hasher.update(source.as_str());
+            hasher.update(content.len().to_le_bytes());
+            hasher.update(content);
+        }
+        Some(hex(&hasher.finalize()))
+    }
+
+    fn resolve(&self, (layer, path): &Source) -> Option<WorkspacePath> {
+        self.root.join_layer(layer, path).ok().flatten()
+    }
+
+    fn position(&self, (file, start, end): &CachedPosition) -> Option<Position> {
+        Some(Position::new(
+            LineCol(start.0, (start.1, start.2)),
+            LineCol(end.0, (end.1, end.2)),
+            self.resolve(file)?,
+        ))
+    }
+}
+
+fn source(path: &WorkspacePath) -> Source {
+    (path.layer(), path.as_str().to_string())
+}
+
+/// Write through a temporary file, so other HEMTT processes never read a partial file
+pub fn write(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(tmp, path)
+}
+
+/// Lowercase hex of a hash, used for the names of cache entries and fingerprints
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut out, byte| {
+        let _ = write!(out, "{byte:02x}");
+        out
+    })
+}
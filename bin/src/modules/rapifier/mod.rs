@@ -0,0 +1,531 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::{Cursor, Read, Write},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU16, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use hemtt_config::{
+    analyze::{
+        analyze_sound_shaders, custom_check, lint_check, schema_check,
+        sound_shaders::{sound_shader_references, SoundShaderUsage},
+    },
+    parse,
+    rapify::{is_rapified, Derapify, Rapify},
+    sqm, Config, ConfigReport,
+};
+use hemtt_preprocessor::{codes::pw5_include_unused::IncludeUnused, Processor};
+use hemtt_stringtable::analyze::keys::references;
+use hemtt_workspace::{
+    addons::{Addon, DeclaredFunction, Location},
+    reporting::{Code, Diagnostic, Processed, Severity, Token},
+    WorkspacePath,
+};
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use vfs::VfsFileType;
+
+use crate::{context::Context, error::Error, progress::progress_bar, report::Report, timings};
+
+use super::{stringtables::ConfigKeys, Module};
+
+pub mod cache;
+
+use cache::{Cache, Cached};
+
+#[derive(Default)]
+pub struct AddonConfigs(RwLock<HashMap<(String, Location), Config>>);
+
+impl std::ops::Deref for AddonConfigs {
+    type Target = RwLock<HashMap<(String, Location), Config>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Default)]
+pub struct Rapifier;
+
+impl Module for Rapifier {
+    fn name(&self) -> &'static str {
+        "Rapifier"
+    }
+
+    fn check(&self, ctx: &Context) -> Result<Report, Error> {
+        let mut report = Report::new();
+        report.extend(lint_check(ctx.config().lints().config().clone()));
+        report.extend(custom_check(ctx.config().lints()));
+        report.extend(schema_check(ctx.config()));
+        for config in ctx.addons().iter().filter_map(|addon| addon.config()) {
+            report.extend(lint_check(config.lints().config().clone()));
+            report.extend(custom_check(config.lints()));
+        }
+        Ok(report)
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        ctx.state().set(AddonConfigs::default());
+        ctx.state().set(ConfigKeys::default());
+        let mut report = Report::new();
+        let counter = AtomicU16::new(0);
+        let glob_options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+        let mut entries = Vec::new();
+        ctx.addons()
+            .iter()
+            .map(|addon| {
+                let mut globs = Vec::new();
+                if let Some(config) = addon.config() {
+                    if !config.rapify().enabled() {
+                        debug!("rapify disabled for {}", addon.name());
+                        return Ok(());
+                    }
+                    for file in config.rapify().exclude() {
+                        globs.push(glob::Pattern::new(file)?);
+                    }
+                }
+                for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
+                    if entry.metadata()?.file_type == VfsFileType::File
+                        && (can_rapify(entry.as_str()) || is_model_cfg(&entry))
+                    {
+                        if globs
+                            .iter()
+                            .any(|pat| pat.matches_with(entry.as_str(), glob_options))
+                        {
+                            debug!("skipping {}", entry.as_str());
+                            continue;
+                        }
+                        entries.push((addon, entry));
+                    }
+                }
+                Ok(())
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let cache = Cache::new(ctx);
+        let progress = progress_bar(entries.len() as u64).with_message("Rapifying Configs");
+        let reports = entries
+            .par_iter()
+            .map(|(addon, entry)| {
+                let _span = timings::span("rapify", entry.as_str());
+                let report = if is_model_cfg(entry) {
+                    check_model_cfg(addon, entry, ctx)?
+                } else {
+                    let report = rapify(addon, entry, ctx, cache.as_ref())?;
+                    counter.fetch_add(1, Ordering::Relaxed);
+                    report
+                };
+                progress.inc(1);
+                Ok(report)
+            })
+            .collect::<Result<Vec<Report>, Error>>()?;
+
+        for new_report in reports {
+            report.merge(new_report);
+        }
+
+        // Sound sets can use the shaders of any addon, so they are checked once every config is rapified
+        let declared = Arc::new(
+            ctx.addons()
+                .iter()
+                .flat_map(|addon| addon.build_data().sound_shaders())
+                .map(|shader| shader.to_lowercase())
+                .collect::<HashSet<_>>(),
+        );
+        for addon in ctx.addons() {
+            report.extend(analyze_sound_shaders(
+                &SoundShaderUsage::new(
+                    declared.clone(),
+                    addon.build_data().sound_shader_references(),
+                ),
+                Some(ctx.addon_config(addon)),
+            ));
+        }
+
+        progress.finish_and_clear();
+        info!("Rapified {} addon configs", counter.load(Ordering::Relaxed));
+        Ok(report)
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+/// Rapify a file, using the cached output if none of its sources have changed
+fn rapify(
+    addon: &Addon,
+    path: &WorkspacePath,
+    ctx: &Context,
+    cache: Option<&Cache>,
+) -> Result<Report, Error> {
+    let is_cpp = std::path::Path::new(&path.filename())
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("cpp"));
+    let is_sqm = std::path::Path::new(&path.filename())
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("sqm"));
+    if is_sqm {
+        let mut magic = [0; 4];
+        let read = path.open_file()?.read(&mut magic)?;
+        if is_rapified(&magic[..read]) {
+            debug!("{} is already binarized", path.as_str());
+            return Ok(Report::new());
+        }
+    }
+    if let Some(hit) = cache.and_then(|cache| cache.get(path)) {
+        // the parsed config is used by other modules, so it is read back from the cached output
+        let config = if path.filename() == "config.cpp" {
+            hit.output.as_ref().and_then(|output| {
+                Config::derapify(&mut Cursor::new(output))
+                    .inspect_err(|e| debug!("unable to read cached {}: {e}", path.as_str()))
+                    .ok()
+            })
+        } else {
+            None
+        };
+        if path.filename() != "config.cpp" || config.is_some() {
+            debug!("using cached {}", path.as_str());
+            return restore(addon, path, ctx, hit, config, is_cpp);
+        }
+    }
+    let mut report = Report::new();
+    let Some((processed, configreport)) = process(addon, path, ctx, &mut report)? else {
+        return Ok(report);
+    };
+    let keys = references(&processed);
+    ctx.state()
+        .get::<ConfigKeys>()
+        .lock()
+        .expect("config keys lock poisoned")
+        .extend(keys.clone());
+    let mut cached = Cached {
+        keys,
+        unused_includes: processed
+            .includes()
+            .iter()
+            .filter(|include| include.unused_in(path))
+            .map(|include| (include.position().clone(), include.file().clone()))
+            .collect(),
+        ..Default::default()
+    };
+    let out = if is_cpp {
+        if path.filename() == "config.cpp" {
+            let (version, cfgpatch) = configreport.required_version();
+            let mut file = path;
+            let mut span = 0..0;
+            if let Some(cfgpatch) = cfgpatch {
+                let map = processed
+                    .mapping(cfgpatch.name().span.start)
+                    .expect("mapping should exist");
+                file = map.original().path();
+                span = map.original().start().0..map.original().end().0;
+            }
+            cached.required_version = Some((version.clone(), file.to_owned(), span.clone()));
+            addon
+                .build_data()
+                .set_required_version(version, file.to_owned(), span);
+            let declared = config_functions(configreport.config(), &processed);
+            let references = configreport.config().get_references();
+            addon
+                .build_data()
+                .set_config_functions(declared.clone(), references.clone());
+            cached.functions = Some((declared, references));
+            let cfgpatches = configreport
+                .config()
+                .get_patches()
+                .into_iter()
+                .map(|patch| {
+                    (
+                        patch.name().as_str().to_string(),
+                        patch.required_addons().to_vec(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            addon.build_data().set_patches(cfgpatches.clone());
+            cached.patches = cfgpatches;
+            let shaders = configreport.config().get_sound_shaders();
+            let references = sound_shader_references(configreport.config(), &processed);
+            addon
+                .build_data()
+                .set_sound_shaders(shaders.clone(), references.clone());
+            cached.sound_shaders = Some((shaders, references));
+            ctx.state()
+                .get::<AddonConfigs>()
+                .write()
+                .expect("state is poisoned")
+                .insert(
+                    (addon.name().to_owned(), *addon.location()),
+                    configreport.config().clone(),
+                );
+        }
+        path.with_extension("bin")?
+    } else {
+        path.to_owned()
+    };
+    // `CfgFunctions` is checked against files that are not sources of the config
+    // unused includes are stored with the entry, and reported again from it
+    let cacheable = report
+        .codes()
+        .iter()
+        .all(|code| code.ident() == IncludeUnused::IDENT)
+        && configreport.config().get_functions().is_empty();
+    let skip = if processed.no_rapify() {
+        debug!(
+            "skipping rapify for {}, as instructed by preprocessor",
+            out.as_str()
+        );
+        true
+    } else if is_sqm && !sqm::binarization_wanted(configreport.config()) {
+        debug!(
+            "skipping rapify for {}, binarization is disabled in the mission",
+            out.as_str()
+        );
+        true
+    } else {
+        false
+    };
+    if skip {
+        // without the output, a cached `config.cpp` could not be added to `AddonConfigs`
+        if let Some(cache) = cache.filter(|_| cacheable && path.filename() != "config.cpp") {
+            cache.insert(path, &processed, &cached);
+        }
+        return Ok(report);
+    }
+    let mut buffer = Vec::new();
+    if let Err(e) = configreport.config().rapify(&mut buffer, 0) {
+        return Err(e.into());
+    }
+    let mut output = match out.create_file() {
+        Ok(output) => output,
+        Err(e) => {
+            return Err(e.into());
+        }
+    };
+    output.write_all(&buffer)?;
+    if let Some(cache) = cache.filter(|_| cacheable) {
+        cached.output = Some(buffer);
+        cache.insert(path, &processed, &cached);
+    }
+    Ok(report)
+}
+
+/// Restore a file from the cache
+///
+/// `config` is the cached `config.cpp`, read back to be added to [`AddonConfigs`]
+fn restore(
+    addon: &Addon,
+    path: &WorkspacePath,
+    ctx: &Context,
+    hit: Cached,
+    config: Option<Config>,
+    is_cpp: bool,
+) -> Result<Report, Error> {
+    if let Some((version, file, span)) = hit.required_version {
+        addon.build_data().set_required_version(version, file, span);
+    }
+    if let Some((declared, references)) = hit.functions {
+        addon
+            .build_data()
+            .set_config_functions(declared, references);
+    }
+    if !hit.patches.is_empty() {
+        addon.build_data().set_patches(hit.patches);
+    }
+    if let Some((shaders, references)) = hit.sound_shaders {
+        addon.build_data().set_sound_shaders(shaders, references);
+    }
+    ctx.state()
+        .get::<ConfigKeys>()
+        .lock()
+        .expect("config keys lock poisoned")
+        .extend(hit.keys);
+    if let Some(config) = config {
+        ctx.state()
+            .get::<AddonConfigs>()
+            .write()
+            .expect("state is poisoned")
+            .insert((addon.name().to_owned(), *addon.location()), config);
+    }
+    if let Some(output) = hit.output {
+        let out = if is_cpp {
+            path.with_extension("bin")?
+        } else {
+            path.to_owned()
+        };
+        out.create_file()?.write_all(&output)?;
+    }
+    let mut report = Report::new();
+    for (position, file) in hit.unused_includes {
+        report.push(Arc::new(IncludeUnused::new(position, file)));
+    }
+    Ok(report)
+}
+
+/// Preprocess and parse a config, adding its warnings and errors to the report
+///
+/// [`None`] if the config could not be parsed, or has errors
+fn process(
+    addon: &Addon,
+    path: &WorkspacePath,
+    ctx: &Context,
+    report: &mut Report,
+) -> Result<Option<(Processed, ConfigReport)>, Error> {
+    let span = timings::span("preprocess", path.as_str());
+    let processed = Processor::run(path);
+    drop(span);
+    let processed = match processed {
+        Ok(processed) => processed,
+        Err((_, hemtt_preprocessor::Error::Code(e))) => {
+            report.push(e);
+            return Ok(None);
+        }
+        Err((_, e)) => {
+            return Err(e.into());
+        }
+    };
+    for warning in processed.warnings() {
+        report.push(warning.clone());
+    }
+    let configreport = match parse(Some(ctx.addon_config(addon)), &processed) {
+        Ok(configreport) => configreport,
+        Err(errors) => {
+            for e in &errors {
+                report.push(e.clone());
+            }
+            return Ok(None);
+        }
+    };
+    configreport.warnings().into_iter().for_each(|e| {
+        report.push(e.clone());
+    });
+    configreport.errors().into_iter().for_each(|e| {
+        report.push(e.clone());
+    });
+    if !configreport.errors().is_empty() {
+        return Ok(None);
+    }
+    Ok(Some((processed, configreport)))
+}
+
+/// Check a `model.cfg`, it is not rapified as binarize reads the text
+///
+/// Binarize has its own parser, so errors from HEMTT are only reported as warnings
+fn check_model_cfg(addon: &Addon, path: &WorkspacePath, ctx: &Context) -> Result<Report, Error> {
+    let mut checked = Report::new();
+    process(addon, path, ctx, &mut checked)?;
+    let mut report = Report::new();
+    for code in checked.codes() {
+        if code.severity() == Severity::Error {
+            report.push(Arc::new(ModelCfgError(code.clone())));
+        } else {
+            report.push(code.clone());
+        }
+    }
+    Ok(report)
+}
+
+/// An error in a `model.cfg`, reported as a warning
+struct ModelCfgError(Arc<dyn Code>);
+
+impl Code for ModelCfgError {
+    fn token(&self) -> Option<&Token> {
+        self.0.token()
+    }
+
+    fn ident(&self) -> &'static str {
+        self.0.ident()
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.0.link()
+    }
+
+    fn message(&self) -> String {
+        self.0.message()
+    }
+
+    fn label_message(&self) -> String {
+        self.0.label_message()
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn help(&self) -> Option<String> {
+        self.0.help()
+    }
+
+    fn note(&self) -> Option<String> {
+        self.0.note()
+    }
+
+    fn suggestion(&self) -> Option<String> {
+        self.0.suggestion()
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.0
+            .diagnostic()
+            .map(|diag| diag.set_severity(Severity::Warning))
+    }
+}
+
+/// Check a config without rapifying it, [`None`] if the file is not a config
+///
+/// # Errors
+/// [`Error`] if the file can not be read
+pub fn check_config(
+    addon: &Addon,
+    path: &WorkspacePath,
+    ctx: &Context,
+) -> Result<Option<Report>, Error> {
+    if is_model_cfg(path) {
+        return check_model_cfg(addon, path, ctx).map(Some);
+    }
+    if !can_rapify(path.as_str()) {
+        return Ok(None);
+    }
+    let mut report = Report::new();
+    process(addon, path, ctx, &mut report)?;
+    Ok(Some(report))
+}
+
+fn is_model_cfg(path: &WorkspacePath) -> bool {
+    path.filename().eq_ignore_ascii_case("model.cfg")
+}
+
+/// The functions declared in `CfgFunctions`, located in their original files
+fn config_functions(config: &Config, processed: &Processed) -> Vec<DeclaredFunction> {
+    config
+        .get_functions()
+        .into_iter()
+        .filter_map(|function| {
+            let map = processed.mapping(function.class().span.start)?;
+            Some(DeclaredFunction::new(
+                function.name().to_string(),
+                function.file().map(ToString::to_string),
+                map.original().path().to_owned(),
+                map.original().start().0..map.original().end().0,
+            ))
+        })
+        .collect()
+}
+
+pub fn can_rapify(path: &str) -> bool {
+    let pathbuf = PathBuf::from(path);
+    let ext = pathbuf
+        .extension()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap_or_default();
+    if ext == "cpp" && pathbuf.file_name() != Some(std::ffi::OsStr::new("config.cpp")) {
+        warn!(
+            "{} - cpp files other than config.cpp are usually not intentional. use hpp for includes",
+            path.trim_start_matches('/')
+        );
+    }
+    ["cpp", "rvmat", "ext", "sqm"].contains(&ext)
+}
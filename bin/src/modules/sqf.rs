@@ -1,30 +1,40 @@
 use std::sync::{
     atomic::{AtomicU16, Ordering},
-    Arc,
+    Arc, Mutex,
 };
 
 use hemtt_common::version::Version;
 use hemtt_preprocessor::Processor;
 use hemtt_sqf::{
-    analyze::{analyze, lint_check},
+    analyze::{
+        analyze, analyze_functions,
+        functions::{scan, FunctionUsage},
+        lint_check,
+    },
+    compiler::serializer::Revision,
     parser::{database::Database, ParserError},
 };
+use hemtt_stringtable::analyze::keys::references;
 use hemtt_workspace::reporting::{Code, CodesExt, Diagnostic, Severity};
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 
-use crate::{context::Context, error::Error, progress::progress_bar, report::Report};
+use crate::{context::Context, error::Error, progress::progress_bar, report::Report, timings};
 
-use super::Module;
+use super::{stringtables::ScriptKeys, Module};
 
 #[derive(Default)]
 pub struct SQFCompiler {
     pub database: Option<Arc<Database>>,
+    revision: Revision,
 }
 
 impl SQFCompiler {
     #[must_use]
     pub const fn new() -> Self {
-        Self { database: None }
+        Self {
+            database: None,
+            revision: Revision::Latest,
+        }
     }
 }
 
@@ -38,29 +48,62 @@ impl Module for SQFCompiler {
             ctx.workspace_path(),
             false,
         )?));
+        if let Some(version) = ctx.config().hemtt().build().sqfc_version() {
+            self.revision = Revision::from_number(version).ok_or_else(|| {
+                hemtt_common::error::Error::ConfigInvalid(format!(
+                    "`hemtt.build.sqfc_version` must be between {} and {}, found {version}",
+                    Revision::Initial.number(),
+                    Revision::Latest.number(),
+                ))
+            })?;
+        }
         Ok(Report::new())
     }
 
     fn check(&self, ctx: &Context) -> Result<Report, Error> {
         let mut report = Report::new();
         report.extend(lint_check(ctx.config().lints().sqf().clone()));
+        for config in ctx.addons().iter().filter_map(|addon| addon.config()) {
+            report.extend(lint_check(config.lints().sqf().clone()));
+        }
         Ok(report)
     }
 
     #[allow(clippy::too_many_lines)]
     fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        ctx.state().set(ScriptKeys::default());
         let mut report = Report::new();
         let sqf_ext = Some(String::from("sqf"));
         let counter = AtomicU16::new(0);
+        let glob_options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
         let mut entries = Vec::new();
         for addon in ctx.addons() {
             let addon = Arc::new(addon.clone());
+            let mut enabled = true;
+            let mut globs = Vec::new();
+            if let Some(config) = addon.config() {
+                if !config.sqf().enabled() {
+                    debug!("sqf compilation disabled for {}", addon.name());
+                    enabled = false;
+                }
+                for file in config.sqf().exclude() {
+                    globs.push(glob::Pattern::new(file)?);
+                }
+            }
             for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
                 if entry.is_file()? {
                     if entry.extension() != sqf_ext || entry.filename().ends_with(".inc.sqf") {
                         continue;
                     }
-                    entries.push((addon.clone(), entry));
+                    // Files that are not compiled are still checked
+                    let compile = enabled
+                        && !globs
+                            .iter()
+                            .any(|pat| pat.matches_with(entry.as_str(), glob_options));
+                    entries.push((addon.clone(), entry, compile));
                 }
             }
         }
@@ -69,13 +112,17 @@ impl Module for SQFCompiler {
             .as_ref()
             .expect("database not initialized")
             .clone();
+        let usage = Mutex::new(FunctionUsage::new());
         let progress = progress_bar(entries.len() as u64).with_message("Compiling SQF");
         let reports = entries
             .par_iter()
-            .map(|(addon, entry)| {
+            .map(|(addon, entry, compile)| {
                 trace!("sqf compiling {}", entry);
                 let mut report = Report::new();
-                let processed = match Processor::run(entry).map_err(|(_, e)| e) {
+                let span = timings::span("preprocess", entry.as_str());
+                let processed = Processor::run(entry);
+                drop(span);
+                let processed = match processed.map_err(|(_, e)| e) {
                     Ok(p) => p,
                     Err(e) => {
                         if let hemtt_preprocessor::Error::Code(code) = e {
@@ -88,21 +135,57 @@ impl Module for SQFCompiler {
                 for warning in processed.warnings() {
                     report.push(warning.clone());
                 }
-                match hemtt_sqf::parser::run(&database, &processed) {
+                ctx.state()
+                    .get::<ScriptKeys>()
+                    .lock()
+                    .expect("script keys lock poisoned")
+                    .extend(references(&processed));
+                let span = timings::span("parse", entry.as_str());
+                let parsed = hemtt_sqf::parser::run(&database, &processed);
+                drop(span);
+                match parsed {
                     Ok(sqf) => {
+                        let (declared, references) = scan(&sqf, &processed);
+                        {
+                            let mut usage = usage.lock().expect("usage lock poisoned");
+                            for function in declared {
+                                usage.declare(addon.name(), function);
+                            }
+                            for name in references {
+                                usage.reference(&name, entry.as_str());
+                            }
+                            if processed.is_entry() {
+                                usage.entry(entry.as_str());
+                            }
+                        }
                         let codes = analyze(
                             &sqf,
-                            Some(ctx.config()),
+                            Some(ctx.addon_config(addon)),
                             &processed,
                             addon.clone(),
                             database.clone(),
                         );
-                        if !codes.failed() {
+                        if !codes.failed() && *compile {
+                            let _span = timings::span("compile", entry.as_str());
                             let mut out = entry.with_extension("sqfc")?.create_file()?;
-                            sqf.optimize().compile_to_writer(&processed, &mut out)?;
+                            let sqf = if ctx.config().hemtt().build().sqf_optimize() {
+                                sqf.optimize()
+                            } else {
+                                sqf
+                            };
+                            let compiled = sqf
+                                .compile(&processed, self.revision)
+                                .map_err(hemtt_sqf::Error::from)?;
+                            compiled
+                                .serialize(&mut out, self.revision)
+                                .map_err(hemtt_sqf::Error::from)?;
+                            if ctx.config().hemtt().build().sqf_debug_info() {
+                                let map = entry.with_extension("sqfc.map")?.create_file()?;
+                                serde_json::to_writer(map, &compiled.debug_map(&processed))?;
+                            }
                             counter.fetch_add(1, Ordering::Relaxed);
-                            progress.inc(1);
                         }
+                        progress.inc(1);
                         for code in codes {
                             report.push(code);
                         }
@@ -134,6 +217,30 @@ impl Module for SQFCompiler {
         }
         progress.finish_and_clear();
         info!("Compiled {} sqf files", counter.load(Ordering::Relaxed));
+
+        // Functions can be used by any addon, so they are checked once every file is scanned
+        if ctx.addons().len() < ctx.all_addons().len() {
+            debug!("skipping unused function checks, not all addons are built");
+            return Ok(report);
+        }
+        let mut usage = usage.into_inner().expect("usage lock poisoned");
+        for addon in ctx.addons() {
+            let config = format!("{}/config.cpp", addon.folder());
+            for function in addon.build_data().config_functions() {
+                usage.declare(addon.name(), function);
+            }
+            for name in addon.build_data().config_references() {
+                usage.reference(&name, &config);
+            }
+        }
+        for addon in ctx.addons() {
+            report.extend(analyze_functions(
+                &usage,
+                Some(ctx.addon_config(addon)),
+                Arc::new(addon.clone()),
+                database.clone(),
+            ));
+        }
         Ok(report)
     }
 
@@ -1,7 +1,14 @@
-use std::{io::BufReader, sync::Arc};
+use std::{
+    io::BufReader,
+    sync::{Arc, Mutex},
+};
 
 use hemtt_stringtable::{
-    analyze::{lint_all, lint_check, lint_one},
+    analyze::{
+        keys::{KeyLocation, KeyUsage},
+        lint_all, lint_check, lint_one, lint_usage,
+        lints::l01_sorted::StringtableData,
+    },
     rapify::convert_stringtable,
     Project,
 };
@@ -14,7 +21,34 @@ use crate::{context::Context, report::Report, Error};
 
 use super::Module;
 
+#[derive(Default)]
+/// The stringtable keys used by configs, collected by the [`Rapifier`](super::Rapifier)
+pub struct ConfigKeys(Mutex<Vec<KeyLocation>>);
+
+impl std::ops::Deref for ConfigKeys {
+    type Target = Mutex<Vec<KeyLocation>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Default)]
+/// The stringtable keys used by scripts, collected by the [`SQFCompiler`](super::SQFCompiler)
+pub struct ScriptKeys(Mutex<Vec<KeyLocation>>);
+
+impl std::ops::Deref for ScriptKeys {
+    type Target = Mutex<Vec<KeyLocation>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 #[derive(Debug, Default)]
+/// Checks and converts the stringtables
+///
+/// Added after the [`SQFCompiler`](super::SQFCompiler), the keys used by configs and scripts are collected while they are processed
 pub struct Stringtables;
 impl Stringtables {
     #[must_use]
@@ -30,7 +64,10 @@ impl Module for Stringtables {
 
     fn check(&self, ctx: &crate::context::Context) -> Result<crate::report::Report, crate::Error> {
         let mut report = Report::new();
-        report.extend(lint_check(ctx.config().lints().sqf().clone()));
+        report.extend(lint_check(ctx.config().lints().stringtables().clone()));
+        for config in ctx.addons().iter().filter_map(|addon| addon.config()) {
+            report.extend(lint_check(config.lints().stringtables().clone()));
+        }
         Ok(report)
     }
 
@@ -66,9 +103,19 @@ impl Module for Stringtables {
         }
 
         report.extend(lint_all(&stringtables, Some(ctx.config())));
+        if let Some(usage) = usage(ctx, &stringtables) {
+            report.extend(lint_usage(&usage, Some(ctx.config())));
+        }
 
         for stringtable in stringtables {
-            let codes = lint_one(&stringtable, Some(ctx.config()));
+            let addon = ctx.addons().iter().find(|addon| {
+                stringtable
+                    .1
+                    .as_str()
+                    .starts_with(&format!("/{}/", addon.folder()))
+            });
+            let config = addon.map_or_else(|| ctx.config(), |addon| ctx.addon_config(addon));
+            let codes = lint_one(&stringtable, Some(config));
             if !codes.iter().any(|c| c.severity() == Severity::Error) {
                 convert_stringtable(&stringtable.0, &stringtable.1);
             }
@@ -78,6 +125,31 @@ impl Module for Stringtables {
     }
 }
 
+/// Collect the keys defined by the stringtables, and the keys used by every config and script
+///
+/// [`None`] if the configs or scripts were not processed
+fn usage(ctx: &Context, stringtables: &[StringtableData]) -> Option<KeyUsage> {
+    let state = ctx.state();
+    let (Some(configs), Some(scripts)) =
+        (state.try_get::<ConfigKeys>(), state.try_get::<ScriptKeys>())
+    else {
+        debug!("configs or scripts were not processed, skipping stringtable key usage");
+        return None;
+    };
+    let mut usage = KeyUsage::new();
+    usage.define(stringtables);
+    usage.extend(std::mem::take(
+        &mut *configs.lock().expect("config keys lock poisoned"),
+    ));
+    usage.extend(std::mem::take(
+        &mut *scripts.lock().expect("script keys lock poisoned"),
+    ));
+    if ctx.addons().len() != ctx.all_addons().len() {
+        usage.set_partial();
+    }
+    Some(usage)
+}
+
 #[allow(clippy::module_name_repetitions)]
 pub struct CodeStringtableInvalid {
     path: WorkspacePath,
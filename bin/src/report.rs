@@ -1,9 +1,14 @@
 use std::{
+    collections::BTreeMap,
     fs::OpenOptions,
     io::{BufWriter, Write},
+    path::Path,
     sync::Arc,
 };
 
+use serde_json::json;
+
+use hemtt_common::config::FailOn;
 use hemtt_workspace::reporting::{Code, Codes, Severity, WorkspaceFiles};
 
 use crate::Error;
@@ -11,12 +16,23 @@ use crate::Error;
 #[derive(Debug, Default)]
 pub struct Report {
     codes: Codes,
+    fail_on: FailOn,
 }
 
 impl Report {
     #[must_use]
     pub fn new() -> Self {
-        Self { codes: Vec::new() }
+        Self {
+            codes: Vec::new(),
+            fail_on: FailOn::Errors,
+        }
+    }
+
+    #[must_use]
+    /// Set the severity that fails the command, from `[hemtt.check] fail_on`
+    pub const fn with_fail_on(mut self, fail_on: FailOn) -> Self {
+        self.fail_on = fail_on;
+        self
     }
 
     /// Write the report to the `ci_annotations.txt` file for GitHub Actions
@@ -52,12 +68,7 @@ impl Report {
     }
 
     pub fn write_to_stdout(&self) {
-        let with_includes = if std::env::var("HEMTT_REPORT_WITH_INCLUDES") == Ok("true".to_string())
-        {
-            WithIncludes::Yes
-        } else {
-            WithIncludes::No
-        };
+        let with_includes = WithIncludes::from_env();
         let workspace_files = WorkspaceFiles::new();
         for code in self
             .helps(with_includes)
@@ -71,7 +82,166 @@ impl Report {
         }
     }
 
+    /// Write the report to stdout as json, one diagnostic per line
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if stdout cannot be written to
+    pub fn write_json(&self) -> Result<(), Error> {
+        let with_includes = WithIncludes::from_env();
+        let workspace_files = WorkspaceFiles::new();
+        let mut stdout = std::io::stdout().lock();
+        for code in self
+            .helps(with_includes)
+            .iter()
+            .chain(self.warnings(with_includes).iter())
+            .chain(self.errors().iter())
+        {
+            if let Some(diag) = code.diagnostic() {
+                serde_json::to_writer(&mut stdout, &diag.to_json(&workspace_files))?;
+                stdout.write_all(b"\n")?;
+            }
+        }
+        stdout.flush()?;
+        Ok(())
+    }
+
+    /// Write the report to a SARIF 2.1 file, for GitHub code scanning
+    ///
+    /// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the file cannot be written
+    pub fn write_sarif(&self, path: &Path) -> Result<(), Error> {
+        let with_includes = WithIncludes::from_env();
+        let workspace_files = WorkspaceFiles::new();
+        let mut rules = BTreeMap::new();
+        let mut results = Vec::new();
+        for code in self
+            .helps(with_includes)
+            .iter()
+            .chain(self.warnings(with_includes).iter())
+            .chain(self.errors().iter())
+        {
+            let Some(diag) = code.diagnostic() else {
+                continue;
+            };
+            let diag = diag.to_json(&workspace_files);
+            rules.entry(diag.ident.clone()).or_insert_with(|| {
+                let mut rule = json!({
+                    "id": diag.ident,
+                    "shortDescription": { "text": diag.message },
+                });
+                if let Some(link) = &diag.link {
+                    rule["helpUri"] = json!(link);
+                }
+                rule
+            });
+            let mut text = diag.message.clone();
+            if let Some(label) = diag
+                .label
+                .as_ref()
+                .filter(|l| !l.is_empty() && **l != diag.message)
+            {
+                text = format!("{text}: {label}");
+            }
+            for help in &diag.help {
+                text = format!("{text}\nhelp: {help}");
+            }
+            let mut result = json!({
+                "ruleId": diag.ident,
+                "level": match diag.severity {
+                    "error" => "error",
+                    "warning" => "warning",
+                    _ => "note",
+                },
+                "message": { "text": text },
+            });
+            if let Some(file) = &diag.file {
+                let mut location = json!({
+                    "artifactLocation": { "uri": file.trim_start_matches('/') },
+                });
+                if let Some(span) = &diag.span {
+                    location["region"] = json!({
+                        "startLine": span.start.line,
+                        "startColumn": span.start.column,
+                        "endLine": span.end.line,
+                        "endColumn": span.end.column,
+                    });
+                }
+                result["locations"] = json!([{ "physicalLocation": location }]);
+            }
+            results.push(result);
+        }
+        let sarif = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "HEMTT",
+                        "version": env!("HEMTT_VERSION"),
+                        "informationUri": "https://hemtt.dev",
+                        "rules": rules.into_values().collect::<Vec<_>>(),
+                    },
+                },
+                "results": results,
+            }],
+        });
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(&sarif)?)?;
+        Ok(())
+    }
+
+    /// Write a summary of the codes per severity and ident to stderr
+    pub fn write_summary(&self) {
+        let with_includes = WithIncludes::from_env();
+        let mut counts: BTreeMap<(u8, &'static str), usize> = BTreeMap::new();
+        let codes = self
+            .errors()
+            .into_iter()
+            .map(|code| (0, code))
+            .chain(
+                self.warnings(with_includes)
+                    .into_iter()
+                    .map(|code| (1, code)),
+            )
+            .chain(self.helps(with_includes).into_iter().map(|code| (2, code)))
+            .collect::<Vec<_>>();
+        if codes.is_empty() {
+            return;
+        }
+        for (order, code) in &codes {
+            *counts.entry((*order, code.ident())).or_default() += 1;
+        }
+        let width = counts
+            .keys()
+            .map(|(_, ident)| ident.len())
+            .max()
+            .unwrap_or_default();
+        eprintln!("Summary");
+        for ((order, ident), count) in &counts {
+            let severity = match order {
+                0 => "error",
+                1 => "warning",
+                _ => "help",
+            };
+            eprintln!("  {severity:<8} {ident:<width$} {count:>5}");
+        }
+        let errors = codes.iter().filter(|(order, _)| *order == 0).count();
+        let warnings = codes.iter().filter(|(order, _)| *order == 1).count();
+        eprintln!(
+            "  {errors} error{}, {warnings} warning{}",
+            if errors == 1 { "" } else { "s" },
+            if warnings == 1 { "" } else { "s" },
+        );
+    }
+
     pub fn merge(&mut self, other: Self) {
+        if other.fail_on == FailOn::Warnings {
+            self.fail_on = FailOn::Warnings;
+        }
         self.codes.extend(other.codes);
     }
 
@@ -83,6 +253,17 @@ impl Report {
         self.codes.extend(codes);
     }
 
+    /// Only keep the codes matching the predicate
+    pub fn retain(&mut self, f: impl FnMut(&Arc<dyn Code>) -> bool) {
+        self.codes.retain(f);
+    }
+
+    #[must_use]
+    /// All codes, of every severity
+    pub fn codes(&self) -> &Codes {
+        &self.codes
+    }
+
     #[must_use]
     pub fn errors(&self) -> Vec<Arc<dyn Code>> {
         filter_codes(&self.codes, Severity::Error, WithIncludes::Yes)
@@ -100,11 +281,27 @@ impl Report {
         help
     }
 
+    #[must_use]
+    /// Returns `true` if there are no codes of any severity
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
     #[must_use]
     /// Returns `true` if there are any errors
     pub fn failed(&self) -> bool {
         !self.errors().is_empty()
     }
+
+    #[must_use]
+    /// Returns `true` if the command should exit with a failure
+    ///
+    /// With `fail_on = "warnings"`, any warning that would be shown also fails the command
+    pub fn failed_policy(&self) -> bool {
+        self.failed()
+            || (self.fail_on == FailOn::Warnings
+                && !self.warnings(WithIncludes::from_env()).is_empty())
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -113,6 +310,17 @@ pub enum WithIncludes {
     No,
 }
 
+impl WithIncludes {
+    /// Codes from includes are only shown if `HEMTT_REPORT_WITH_INCLUDES` is `true`
+    fn from_env() -> Self {
+        if std::env::var("HEMTT_REPORT_WITH_INCLUDES") == Ok("true".to_string()) {
+            Self::Yes
+        } else {
+            Self::No
+        }
+    }
+}
+
 fn filter_codes(
     codes: &[Arc<dyn Code>],
     severity: Severity,
@@ -0,0 +1,121 @@
+//! Wall time of the build phases, recorded with `--timings`
+//!
+//! The spans are written as a Chrome trace, which can be opened in `chrome://tracing`,
+//! [Perfetto](https://ui.perfetto.dev), or [speedscope](https://www.speedscope.app)
+
+use std::{
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, OnceLock,
+    },
+    time::Instant,
+};
+
+use serde::Serialize;
+
+use crate::error::Error;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static START: OnceLock<Instant> = OnceLock::new();
+static EVENTS: Mutex<Vec<Event>> = Mutex::new(Vec::new());
+
+#[derive(Serialize)]
+/// A complete event of the Chrome trace format
+struct Event {
+    name: String,
+    cat: &'static str,
+    ph: &'static str,
+    /// Start, in microseconds since timings were enabled
+    ts: u128,
+    /// Duration, in microseconds
+    dur: u128,
+    pid: u32,
+    tid: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Trace<'a> {
+    trace_events: &'a [Event],
+    display_time_unit: &'static str,
+}
+
+/// Start recording spans
+pub fn enable() {
+    START.get_or_init(Instant::now);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+#[must_use]
+/// Are spans being recorded
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+#[must_use]
+/// Record the time until the returned span is dropped, [`None`] if timings are not enabled
+///
+/// The category is the kind of work, such as `preprocess` or `rapify`,
+/// and the name is what it was done to, such as a file or an addon
+pub fn span(category: &'static str, name: &str) -> Option<Span> {
+    enabled().then(|| Span {
+        category,
+        name: name.to_string(),
+        start: Instant::now(),
+    })
+}
+
+/// A span of work, recorded when it is dropped
+pub struct Span {
+    category: &'static str,
+    name: String,
+    start: Instant,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some(origin) = START.get() else {
+            return;
+        };
+        let event = Event {
+            name: std::mem::take(&mut self.name),
+            cat: self.category,
+            ph: "X",
+            ts: self.start.duration_since(*origin).as_micros(),
+            dur: self.start.elapsed().as_micros(),
+            pid: std::process::id(),
+            // 0 is the main thread, rayon threads start at 1
+            tid: rayon::current_thread_index().map_or(0, |index| index + 1),
+        };
+        EVENTS.lock().expect("timings lock poisoned").push(event);
+    }
+}
+
+/// Write the recorded spans to a file, does nothing if timings are not enabled
+///
+/// The spans are cleared, so each project of a workspace only writes its own
+///
+/// # Errors
+/// [`Error::Io`] if the file can not be written
+/// [`Error::SerdeJson`] if the spans can not be serialized
+///
+/// # Panics
+/// If a thread panicked while recording a span
+pub fn write(path: &Path) -> Result<(), Error> {
+    if !enabled() {
+        return Ok(());
+    }
+    let mut events = std::mem::take(&mut *EVENTS.lock().expect("timings lock poisoned"));
+    events.sort_by_key(|event| event.ts);
+    let file = std::fs::File::create(path)?;
+    serde_json::to_writer(
+        std::io::BufWriter::new(file),
+        &Trace {
+            trace_events: &events,
+            display_time_unit: "ms",
+        },
+    )?;
+    info!("Timings written to {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use crate::Error;
+
+use super::Format;
+
+#[derive(clap::Args)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ConvertArgs {
+    /// Config to read, either a `config.cpp` or a rapified `config.bin`
+    pub(crate) config: String,
+    /// File to write, such as `config.json`, printed if not set
+    pub(crate) output: Option<String>,
+    #[arg(long)]
+    /// Format of the output, taken from the extension if not set, JSON by default
+    pub(crate) format: Option<Format>,
+}
+
+/// Execute the convert command
+///
+/// # Errors
+/// [`Error`] if the config can not be read, or the file can not be written
+pub fn execute(args: &ConvertArgs) -> Result<(), Error> {
+    let Some(config) = super::inspect::read(&PathBuf::from(&args.config))? else {
+        return Ok(());
+    };
+    let output = args.output.as_ref().map(PathBuf::from);
+    let converted = Format::resolve(args.format, output.as_deref(), Format::Json).write(&config)?;
+    if let Some(output) = output {
+        if output.exists() {
+            error!("Output file already exists");
+            return Ok(());
+        }
+        std::fs::write(&output, converted)?;
+        info!("Wrote the config to `{}`", output.display());
+    } else {
+        print!("{converted}");
+    }
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use std::{
+    fs::File,
+    io::{BufReader, Write},
+    path::PathBuf,
+};
+
+use hemtt_config::{rapify::Derapify, Config};
+
+use crate::Error;
+
+#[derive(clap::Args)]
+#[allow(clippy::module_name_repetitions)]
+pub struct DerapifyArgs {
+    /// Rapified config to read, usually `config.bin`
+    pub(crate) config: String,
+    /// Where to save the config, printed if not set
+    pub(crate) output: Option<String>,
+}
+
+/// Execute the derapify command
+///
+/// # Errors
+/// [`Error::Io`] if the file is not a rapified config, or the output can not be written
+pub fn execute(args: &DerapifyArgs) -> Result<(), Error> {
+    let config = Config::derapify(&mut BufReader::new(File::open(PathBuf::from(
+        &args.config,
+    ))?))?;
+    let output = args.output.as_ref().map(PathBuf::from);
+    if let Some(output) = output {
+        if output.exists() {
+            error!("Output file already exists");
+            return Ok(());
+        }
+        File::create(output)?.write_all(config.to_string().as_bytes())?;
+    } else {
+        print!("{config}");
+    }
+    Ok(())
+}
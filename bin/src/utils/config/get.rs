@@ -0,0 +1,123 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::PathBuf,
+};
+
+use hemtt_common::config::ProjectConfig;
+use hemtt_config::{rapify::Derapify, Class, Config, Property, Value};
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::reporting::WorkspaceFiles;
+
+use crate::Error;
+
+#[derive(clap::Args)]
+#[allow(clippy::module_name_repetitions)]
+pub struct GetArgs {
+    /// Folder of an addon, such as `addons/main`, or a config file
+    pub(crate) config: String,
+    /// Path of the value, such as `CfgPatches.abe_main.requiredVersion`
+    pub(crate) path: String,
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    /// Print the value as JSON
+    pub(crate) json: bool,
+}
+
+/// Execute the get command
+///
+/// # Errors
+/// [`Error`] if the config can not be read or parsed, or the path is not defined
+pub fn execute(args: &GetArgs) -> Result<(), Error> {
+    let mut file = PathBuf::from(&args.config);
+    if file.is_dir() {
+        file = file.join("config.cpp");
+    }
+    let config = read(&file)?;
+    let segments = args
+        .path
+        .split('.')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+    let not_found = || Error::ConfigValue(format!("`{}` is not defined", args.path));
+    let Some((name, classes)) = segments.split_last() else {
+        return Err(not_found());
+    };
+    let properties = if classes.is_empty() {
+        config.0.clone()
+    } else {
+        config
+            .resolve(&classes.join("/"))
+            .ok_or_else(not_found)?
+            .properties()
+            .to_vec()
+    };
+    let property = properties
+        .iter()
+        .find(|property| {
+            matches!(
+                property,
+                Property::Entry { .. } | Property::Class(Class::Local { .. })
+            ) && property.name().as_str().eq_ignore_ascii_case(name)
+        })
+        .ok_or_else(not_found)?;
+    if let Property::Entry { value, .. } = property {
+        print_value(value, args)?;
+    } else {
+        // Classes are printed with the properties they inherit
+        let class = config.resolve(&segments.join("/")).ok_or_else(not_found)?;
+        if args.json {
+            println!("{}", Config(class.properties().to_vec()).to_json()?);
+        } else {
+            print!("{}", class.to_config());
+        }
+    }
+    Ok(())
+}
+
+/// Print a value, strings without quotes and arrays as JSON, so they can be used in scripts
+fn print_value(value: &Value, args: &GetArgs) -> Result<(), Error> {
+    match value {
+        Value::Invalid(_) => {
+            return Err(Error::ConfigValue(format!(
+                "`{}` is not a valid value",
+                args.path
+            )));
+        }
+        _ if args.json => println!("{}", serde_json::to_string(value)?),
+        Value::Str(value) => println!("{}", value.value()),
+        Value::Number(value) => println!("{value}"),
+        Value::Expression(value) => println!("__EVAL({})", value.value()),
+        Value::Array(_) | Value::UnexpectedArray(_) => {
+            println!("{}", serde_json::to_string(value)?);
+        }
+    }
+    Ok(())
+}
+
+/// Read a config, either rapified or source, using the includes of the project when inside one
+fn read(file: &PathBuf) -> Result<Config, Error> {
+    // Files shorter than the magic are source
+    let mut magic = Vec::with_capacity(4);
+    File::open(file)?.take(4).read_to_end(&mut magic)?;
+    if magic == b"\0raP" {
+        return Ok(Config::derapify(&mut BufReader::new(File::open(file)?))?);
+    }
+    let source = crate::utils::preprocess::source(file)?;
+    let processed = Processor::run(&source).map_err(|e| e.1)?;
+    match hemtt_config::parse(Some(&ProjectConfig::test_project()), &processed) {
+        Ok(report) => Ok(report.into_config()),
+        Err(errors) => {
+            let workspacefiles = WorkspaceFiles::new();
+            for error in errors {
+                if let Some(diag) = error.diagnostic() {
+                    eprintln!("{}", diag.to_string(&workspacefiles));
+                }
+            }
+            Err(Error::ConfigValue(format!(
+                "`{}` could not be parsed",
+                file.display()
+            )))
+        }
+    }
+}
@@ -1,7 +1,13 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, Read},
+    path::PathBuf,
+    sync::Arc,
+};
 
 use hemtt_common::config::ProjectConfig;
-use hemtt_config::ConfigReport;
+use hemtt_config::{rapify::Derapify, Config, ConfigReport};
 use hemtt_preprocessor::Processor;
 use hemtt_workspace::{
     reporting::{Code, WorkspaceFiles},
@@ -11,11 +17,63 @@ use hemtt_workspace::{
 #[derive(clap::Args)]
 #[allow(clippy::module_name_repetitions)]
 pub struct InspectArgs {
-    /// Config to inspect
+    /// Config to inspect, or the path of a class in the project such as `CfgVehicles/MyTank`
     pub(crate) config: String,
+    #[arg(long, action = clap::ArgAction::Append)]
+    /// Config to load before the project when resolving a class, such as an extracted `config.bin` of a dependency
+    pub(crate) with: Vec<String>,
 }
 
-use crate::Error;
+use crate::{context::Context, modules::deps::Graph, Error};
+
+/// Execute the inspect command
+///
+/// # Errors
+/// [`Error`] if the config can not be read, or the project can not be loaded
+pub fn execute(args: &InspectArgs) -> Result<(), Error> {
+    let file = PathBuf::from(&args.config);
+    if file.is_file() && args.with.is_empty() {
+        inspect(&file)
+    } else {
+        class(args)
+    }
+}
+
+/// Prints the properties of a class in the project, including the ones it inherits
+///
+/// # Errors
+/// [`Error`] if a config can not be read, or the project can not be loaded
+pub fn class(args: &InspectArgs) -> Result<(), Error> {
+    let Some(config) = project(&args.with)? else {
+        return Ok(());
+    };
+    let Some(class) = config.resolve(&args.config) else {
+        error!("Class `{}` was not found", args.config);
+        return Ok(());
+    };
+    if let Some(missing) = class.missing() {
+        warn!(
+            "`{}` was not found, the properties it would inherit are missing",
+            missing.as_str()
+        );
+    }
+    if class.parents().is_empty() {
+        println!("// {}", args.config);
+    } else {
+        println!(
+            "// {}: {}",
+            args.config,
+            class
+                .parents()
+                .iter()
+                .map(hemtt_config::Ident::as_str)
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        );
+    }
+    print!("{}", class.to_config());
+    Ok(())
+}
 
 /// Prints information about a config to stdout
 ///
@@ -57,6 +115,100 @@ pub fn inspect(file: &PathBuf) -> Result<(), Error> {
     }
 }
 
+/// The configs of the addons in the project merged in load order, after the configs in `with`
+///
+/// [`None`] if one of the configs in `with` can not be parsed
+///
+/// # Errors
+/// [`Error`] if a config can not be read, or the project can not be loaded
+pub fn project(with: &[String]) -> Result<Option<Config>, Error> {
+    let mut configs = Vec::new();
+    for file in with {
+        let Some(config) = read(&PathBuf::from(file))? else {
+            return Ok(None);
+        };
+        configs.push(config);
+    }
+
+    let ctx = Context::new(None, crate::context::PreservePrevious::Keep, None, false)?;
+    let mut patches = BTreeMap::new();
+    let mut addons = BTreeMap::new();
+    for addon in ctx.addons() {
+        let entry = ctx
+            .workspace_path()
+            .join(addon.folder())?
+            .join("config.cpp")?;
+        if !entry.exists()? {
+            continue;
+        }
+        let processed = match Processor::run(&entry) {
+            Ok(processed) => processed,
+            Err((_, e)) => {
+                warn!("Failed to preprocess `{entry}`: {e}");
+                continue;
+            }
+        };
+        let Ok(report) = hemtt_config::parse(Some(ctx.addon_config(addon)), &processed) else {
+            warn!("Failed to parse `{entry}`, run `hemtt check` for details");
+            continue;
+        };
+        let config = report.into_config();
+        patches.insert(
+            addon.name().to_string(),
+            config
+                .get_patches()
+                .into_iter()
+                .map(|patch| {
+                    (
+                        patch.name().as_str().to_string(),
+                        patch.required_addons().to_vec(),
+                    )
+                })
+                .collect(),
+        );
+        addons.insert(addon.name().to_string(), config);
+    }
+    let graph = Graph::new(patches);
+    for addon in graph.load_order() {
+        if let Some(config) = addons.remove(addon) {
+            configs.push(config);
+        }
+    }
+
+    Ok(Some(Config::merge(configs)))
+}
+
+/// Read a config, either rapified or source
+///
+/// [`None`] if the source can not be parsed
+///
+/// # Errors
+/// [`Error`] if the file can not be read
+pub fn read(file: &PathBuf) -> Result<Option<Config>, Error> {
+    let mut magic = [0; 4];
+    File::open(file)?.read_exact(&mut magic)?;
+    if &magic == b"\0raP" {
+        return Ok(Some(Config::derapify(&mut BufReader::new(File::open(
+            file,
+        )?))?));
+    }
+    let Ok(report) = get_report(file)? else {
+        error!(
+            "Failed to parse `{}`, run `hemtt utils config inspect` on it for details",
+            file.display()
+        );
+        return Ok(None);
+    };
+    Ok(Some(report.into_config()))
+}
+
+/// Preprocess and parse a config on its own, outside of a project
+///
+/// # Errors
+/// [`Error`] if the file can not be preprocessed
+///
+/// # Panics
+/// If the path is not a file
 pub fn get_report(file: &PathBuf) -> Result<Result<ConfigReport, Vec<Arc<dyn Code>>>, Error> {
     assert!(file.is_file());
     let folder = PathBuf::from(&file)
@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use crate::Error;
+
+use super::Format;
+
+#[derive(clap::Args)]
+#[allow(clippy::module_name_repetitions)]
+pub struct MergeArgs {
+    /// File to write, such as `config.cpp`, printed if not set
+    output: Option<String>,
+    #[arg(long)]
+    /// Format of the output, taken from the extension if not set
+    format: Option<Format>,
+    #[arg(long, action = clap::ArgAction::Append)]
+    /// Config to load before the project, such as an extracted `config.bin` of a dependency
+    with: Vec<String>,
+}
+
+/// Execute the merge command
+///
+/// # Errors
+/// [`Error`] if a config can not be read, the project can not be loaded, or the file can not be written
+pub fn execute(args: &MergeArgs) -> Result<(), Error> {
+    let Some(config) = super::inspect::project(&args.with)? else {
+        return Ok(());
+    };
+    let output = args.output.as_ref().map(PathBuf::from);
+    let merged = Format::resolve(args.format, output.as_deref(), Format::Cpp).write(&config)?;
+    if let Some(output) = output {
+        std::fs::write(&output, merged)?;
+        info!("Wrote the merged config to `{}`", output.display());
+    } else {
+        print!("{merged}");
+    }
+    Ok(())
+}
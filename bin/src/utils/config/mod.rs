@@ -1,10 +1,16 @@
-use std::path::PathBuf;
+use std::path::Path;
+
+use hemtt_config::Config;
 
 use crate::Error;
 
+mod convert;
+mod derapify;
+mod get;
 mod inspect;
+mod merge;
 
-pub use inspect::inspect;
+pub use inspect::{get_report, inspect};
 
 #[derive(clap::Parser)]
 #[command(arg_required_else_help = true)]
@@ -16,8 +22,16 @@ pub struct Command {
 
 #[derive(clap::Subcommand)]
 enum Subcommands {
-    /// Inspect a config file
+    /// Convert a config to JSON or YAML
+    Convert(convert::ConvertArgs),
+    /// Convert a rapified config back to source
+    Derapify(derapify::DerapifyArgs),
+    /// Print a value of a config
+    Get(get::GetArgs),
+    /// Inspect a config file, or a class of the project
     Inspect(inspect::InspectArgs),
+    /// Merge the configs of the project into one
+    Merge(merge::MergeArgs),
 }
 
 /// Execute the config command
@@ -29,6 +43,48 @@ enum Subcommands {
 /// If the args are not present from clap
 pub fn execute(cmd: &Command) -> Result<(), Error> {
     match &cmd.commands {
-        Subcommands::Inspect(args) => inspect::inspect(&PathBuf::from(&args.config)),
+        Subcommands::Convert(args) => convert::execute(args),
+        Subcommands::Derapify(args) => derapify::execute(args),
+        Subcommands::Get(args) => get::execute(args),
+        Subcommands::Inspect(args) => inspect::execute(args),
+        Subcommands::Merge(args) => merge::execute(args),
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+/// Format of a config that is written
+pub enum Format {
+    /// Config source, like `CfgConvert` creates
+    Cpp,
+    /// An object of properties, where classes are objects with `parent` and `properties`
+    Json,
+    /// The same shape as JSON
+    Yaml,
+}
+
+impl Format {
+    /// The format from `--format`, or the extension of the file
+    fn resolve(format: Option<Self>, path: Option<&Path>, default: Self) -> Self {
+        format.unwrap_or_else(|| {
+            match path
+                .and_then(Path::extension)
+                .map(|ext| ext.to_string_lossy().to_lowercase())
+                .as_deref()
+            {
+                Some("json") => Self::Json,
+                Some("yaml" | "yml") => Self::Yaml,
+                Some("cpp" | "hpp") => Self::Cpp,
+                _ => default,
+            }
+        })
+    }
+
+    /// Write the config in the format
+    fn write(self, config: &Config) -> Result<String, Error> {
+        Ok(match self {
+            Self::Cpp => config.to_string(),
+            Self::Json => config.to_json()? + "\n",
+            Self::Yaml => config.to_yaml()?,
+        })
     }
 }
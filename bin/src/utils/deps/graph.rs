@@ -0,0 +1,73 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use hemtt_preprocessor::Processor;
+
+use crate::{context::Context, modules::deps::Graph, Error};
+
+#[derive(clap::Args)]
+#[allow(clippy::module_name_repetitions)]
+pub struct DepsGraphArgs {
+    /// File to write, such as `deps.dot`, printed if not set
+    output: Option<String>,
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    /// Include the required addons that are not in the project
+    external: bool,
+}
+
+/// Execute the graph command
+///
+/// # Errors
+/// [`Error`] if the project can not be loaded, or the file can not be written
+pub fn execute(args: &DepsGraphArgs) -> Result<(), Error> {
+    let ctx = Context::new(None, crate::context::PreservePrevious::Keep, None, false)?;
+
+    let mut addons = BTreeMap::new();
+    for addon in ctx.addons() {
+        let entry = ctx
+            .workspace_path()
+            .join(addon.folder())?
+            .join("config.cpp")?;
+        if !entry.exists()? {
+            continue;
+        }
+        let processed = match Processor::run(&entry) {
+            Ok(processed) => processed,
+            Err((_, e)) => {
+                warn!("Failed to preprocess `{entry}`: {e}");
+                continue;
+            }
+        };
+        let Ok(report) = hemtt_config::parse(Some(ctx.addon_config(addon)), &processed) else {
+            warn!("Failed to parse `{entry}`, run `hemtt check` for details");
+            continue;
+        };
+        addons.insert(
+            addon.name().to_string(),
+            report
+                .config()
+                .get_patches()
+                .into_iter()
+                .map(|patch| {
+                    (
+                        patch.name().as_str().to_string(),
+                        patch.required_addons().to_vec(),
+                    )
+                })
+                .collect(),
+        );
+    }
+
+    let graph = Graph::new(addons);
+    for cycle in graph.cycles() {
+        warn!("Addons {} require each other", cycle.join(", "));
+    }
+    let dot = graph.dot(args.external);
+    if let Some(output) = &args.output {
+        let output = PathBuf::from(output);
+        std::fs::write(&output, dot)?;
+        info!("Wrote the dependency graph to `{}`", output.display());
+    } else {
+        print!("{dot}");
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+mod graph;
+
+use crate::Error;
+
+#[derive(clap::Parser)]
+#[command(arg_required_else_help = true)]
+/// Commands for the dependencies between addons
+pub struct Command {
+    #[command(subcommand)]
+    commands: Subcommands,
+}
+
+#[derive(clap::Subcommand)]
+enum Subcommands {
+    /// Export the dependency graph of the addons
+    Graph(graph::DepsGraphArgs),
+}
+
+/// Execute the deps command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(cmd: &Command) -> Result<(), Error> {
+    match &cmd.commands {
+        Subcommands::Graph(args) => graph::execute(args),
+    }
+}
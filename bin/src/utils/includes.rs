@@ -0,0 +1,85 @@
+use std::{collections::BTreeSet, fmt::Write, path::Path};
+
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::reporting::Include;
+
+use crate::{dot, utils::preprocess::source, Error};
+
+#[derive(clap::Parser)]
+#[command(verbatim_doc_comment)]
+/// Print the files included by a file
+///
+/// Includes that are never used are marked, as the file would still
+/// be rebuilt every time they change.
+pub struct Command {
+    /// File to list the includes of
+    file: String,
+
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    /// Print the includes as a graph in the DOT format
+    graph: bool,
+}
+
+/// Execute the includes command
+///
+/// # Errors
+/// [`Error`] if the file can not be found or preprocessed
+pub fn execute(cmd: &Command) -> Result<(), Error> {
+    let path = source(Path::new(&cmd.file))?;
+    let processed = Processor::run(&path).map_err(|e| e.1)?;
+    if cmd.graph {
+        print!("{}", graph(path.as_str(), processed.includes()));
+    } else {
+        print!("{}", tree(path.as_str(), processed.includes()));
+    }
+    Ok(())
+}
+
+#[must_use]
+/// The includes as a tree, in the order they were included
+pub fn tree(root: &str, includes: &[Include]) -> String {
+    let mut out = format!("{root}\n");
+    // Includes are processed depth first, so the stack is the chain of files to the current include
+    let mut stack = vec![root.to_string()];
+    for include in includes {
+        while stack.len() > 1 && stack.last().map(String::as_str) != Some(include.parent().as_str())
+        {
+            stack.pop();
+        }
+        out.push_str(&"  ".repeat(stack.len() - 1));
+        out.push_str("- ");
+        out.push_str(include.file().as_str());
+        if !include.used() {
+            out.push_str(" (unused)");
+        }
+        out.push('\n');
+        stack.push(include.file().as_str().to_string());
+    }
+    out
+}
+
+#[must_use]
+/// The includes as a graph in the DOT format, unused includes are dashed
+pub fn graph(root: &str, includes: &[Include]) -> String {
+    let mut out = String::from("digraph includes {\n    rankdir=LR;\n");
+    writeln!(out, "    {} [shape=box];", dot::id(root)).expect("writing to a string");
+    let edges = includes
+        .iter()
+        .map(|include| {
+            (
+                include.parent().as_str(),
+                include.file().as_str(),
+                include.used(),
+            )
+        })
+        .collect::<BTreeSet<_>>();
+    for (parent, file, used) in edges {
+        write!(out, "    {} -> {}", dot::id(parent), dot::id(file)).expect("writing to a string");
+        if !used {
+            out.push_str(" [style=dashed]");
+        }
+        out.push_str(";\n");
+    }
+    out.push_str("}\n");
+    out
+}
@@ -35,7 +35,7 @@ pub fn execute(cmd: &Command) -> Result<(), Error> {
             super::paa::inspect(File::open(&path)?, &crate::TableFormat::Ascii)?;
         }
         "pbo" => {
-            super::pbo::inspect(File::open(&path)?, &crate::TableFormat::Ascii)?;
+            super::pbo::inspect(&File::open(&path)?, &crate::TableFormat::Ascii)?;
         }
         "bikey" => {
             bikey(File::open(&path)?, &path)?;
@@ -46,6 +46,9 @@ pub fn execute(cmd: &Command) -> Result<(), Error> {
         "cpp" | "hpp" => {
             super::config::inspect(&path)?;
         }
+        "sqm" => {
+            super::sqm::validate(&path)?;
+        }
         _ => {
             let mut file = File::open(&path)?;
             let buf = &mut [0u8; 6];
@@ -54,7 +57,7 @@ pub fn execute(cmd: &Command) -> Result<(), Error> {
             // PBO
             if buf == b"\x00sreV\x00" {
                 warn!("The file appears to be a PBO but does not have the .pbo extension.");
-                super::pbo::inspect(file, &crate::TableFormat::Ascii)?;
+                super::pbo::inspect(&file, &crate::TableFormat::Ascii)?;
                 return Ok(());
             }
             // PAA (skip first two bytes)
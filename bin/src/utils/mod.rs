@@ -1,7 +1,13 @@
 pub mod config;
+pub mod deps;
+pub mod includes;
 pub mod inspect;
 pub mod paa;
 pub mod pbo;
 pub mod photoshoot;
+pub mod preprocess;
+pub mod sign;
 pub mod sqf;
+pub mod sqm;
 pub mod verify;
+pub mod verify_signs;
@@ -1,13 +1,36 @@
 use std::path::PathBuf;
 
+use hemtt_paa::{PaXType, Paa};
+
 use crate::Error;
 
 #[derive(clap::Args)]
 pub struct PaaConvertArgs {
-    /// PAA to convert
-    paa: String,
-    /// Where to save the file
+    /// PAA or image to convert
+    file: String,
+    /// Where to save the file, a PAA if it ends in `.paa`
     output: String,
+    #[arg(long)]
+    /// Format of the PAA, DXT1 for opaque images and DXT5 otherwise if not set
+    format: Option<PaaFormat>,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+/// Format of a written PAA
+pub enum PaaFormat {
+    /// No or 1-bit alpha
+    Dxt1,
+    /// Interpolated alpha
+    Dxt5,
+}
+
+impl From<PaaFormat> for PaXType {
+    fn from(format: PaaFormat) -> Self {
+        match format {
+            PaaFormat::Dxt1 => Self::DXT1,
+            PaaFormat::Dxt5 => Self::DXT5,
+        }
+    }
 }
 
 /// Execute the convert command
@@ -15,15 +38,38 @@ pub struct PaaConvertArgs {
 /// # Errors
 /// [`Error`] depending on the modules
 pub fn execute(args: &PaaConvertArgs) -> Result<(), Error> {
-    let paa = PathBuf::from(&args.paa);
+    let file = PathBuf::from(&args.file);
     let output = PathBuf::from(&args.output);
     if output.exists() {
         error!("Output file already exists");
         return Ok(());
     }
-    let paa = hemtt_paa::Paa::read(std::fs::File::open(paa)?)?;
+    if output
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("paa"))
+    {
+        let image = match image::open(&file) {
+            Ok(image) => image.to_rgba8(),
+            Err(e) => {
+                error!("Failed to read image: {}", e);
+                return Ok(());
+            }
+        };
+        let format = args
+            .format
+            .map_or_else(|| Paa::format_for(&image), Into::into);
+        let mut buffer = Vec::new();
+        if let Err(e) = Paa::write_as(&image, &format, &mut buffer) {
+            error!("Failed to convert to PAA: {}", e);
+            return Ok(());
+        }
+        std::fs::write(output, buffer)?;
+        info!("Image converted to {} PAA", format);
+        return Ok(());
+    }
+    let paa = Paa::read(std::fs::File::open(file)?)?;
     if let Err(e) = paa.maps()[0].get_image().save(output) {
-        error!("Failed to save PNG: {}", e);
+        error!("Failed to save image: {}", e);
     } else {
         info!("PAA converted");
     }
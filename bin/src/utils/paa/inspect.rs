@@ -39,6 +39,23 @@ pub fn inspect(mut file: File, format: &TableFormat) -> Result<(), Error> {
     let paa = hemtt_paa::Paa::read(&mut file)?;
     println!("PAA");
     println!("  - Format: {}", paa.format());
+    for (tagg, name) in [("CGVA", "Average Color"), ("CXAM", "Max Color")] {
+        if let Some(color) = paa.taggs().get(tagg).filter(|color| color.len() == 4) {
+            // Stored as BGRA
+            println!(
+                "  - {name}: #{:02X}{:02X}{:02X}{:02X}",
+                color[2], color[1], color[0], color[3]
+            );
+        }
+    }
+    println!(
+        "  - Alpha: {}",
+        match paa.taggs().get("GALF").and_then(|flag| flag.first()) {
+            Some(1) => "interpolated",
+            Some(2) => "transparent",
+            _ => "none",
+        }
+    );
     let maps = paa.maps();
     println!("Maps: {}", maps.len());
     let data = maps
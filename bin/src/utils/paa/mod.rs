@@ -18,6 +18,7 @@ pub struct Command {
 #[derive(clap::Subcommand)]
 enum Subcommands {
     Convert(convert::PaaConvertArgs),
+    #[command(visible_alias = "info")]
     Inspect(inspect::PaaInspectArgs),
 }
 
@@ -0,0 +1,147 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io::Cursor,
+    path::PathBuf,
+};
+
+use hemtt_config::{rapify::Derapify, Config};
+use hemtt_pbo::{Checksum, Mime, Mmap, ReadablePbo};
+use sha1::{Digest, Sha1};
+use similar::TextDiff;
+
+use crate::Error;
+
+#[derive(clap::Args)]
+#[allow(clippy::module_name_repetitions)]
+pub struct PboDiffArgs {
+    /// The old PBO
+    old: String,
+    /// The new PBO
+    new: String,
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    /// Only list the changed files, without the changes inside configs
+    summary: bool,
+}
+
+/// Execute the diff command
+///
+/// # Errors
+/// [`Error::Io`] if a PBO can not be read
+/// [`Error::Pbo`] if a file is not a valid PBO
+pub fn execute(args: &PboDiffArgs) -> Result<(), Error> {
+    let old = ReadablePbo::mmap(&File::open(PathBuf::from(&args.old))?)?;
+    let new = ReadablePbo::mmap(&File::open(PathBuf::from(&args.new))?)?;
+
+    println!("Properties");
+    let keys = old
+        .properties()
+        .keys()
+        .chain(new.properties().keys())
+        .collect::<BTreeSet<_>>();
+    let mut changed_properties = 0;
+    for key in keys {
+        match (old.properties().get(key), new.properties().get(key)) {
+            (Some(before), Some(after)) if before != after => {
+                println!("  ~ {key}: {before} -> {after}");
+            }
+            (Some(before), None) => println!("  - {key}: {before}"),
+            (None, Some(after)) => println!("  + {key}: {after}"),
+            _ => continue,
+        }
+        changed_properties += 1;
+    }
+    if changed_properties == 0 {
+        println!("  No changes");
+    }
+
+    let old_files = contents(&old)?;
+    let new_files = contents(&new)?;
+    let (mut added, mut removed, mut changed, mut unchanged) = (0, 0, 0, 0);
+    println!("Files");
+    let names = old_files
+        .keys()
+        .chain(new_files.keys())
+        .collect::<BTreeSet<_>>();
+    for name in names {
+        match (old_files.get(name), new_files.get(name)) {
+            (Some((before_name, before)), Some((_, after))) => {
+                if before == after {
+                    unchanged += 1;
+                    continue;
+                }
+                changed += 1;
+                println!(
+                    "  ~ {before_name} ({} -> {}, {} -> {} bytes)",
+                    hash(before),
+                    hash(after),
+                    before.len(),
+                    after.len()
+                );
+                if !args.summary {
+                    if let Some(diff) = config_diff(before, after) {
+                        for line in diff.lines() {
+                            println!("      {line}");
+                        }
+                    }
+                }
+            }
+            (Some((before_name, before)), None) => {
+                removed += 1;
+                println!("  - {before_name} ({} bytes)", before.len());
+            }
+            (None, Some((after_name, after))) => {
+                added += 1;
+                println!("  + {after_name} ({} bytes)", after.len());
+            }
+            (None, None) => unreachable!("the name is from one of the PBOs"),
+        }
+    }
+    println!("  {added} added, {removed} removed, {changed} changed, {unchanged} unchanged");
+    Ok(())
+}
+
+/// The name and contents of each file, keyed by the lowercase name
+type Contents<'a> = BTreeMap<String, (String, Cow<'a, [u8]>)>;
+
+/// The contents of each file, keyed by the lowercase name, decompressed if needed
+///
+/// Files that are not compressed are borrowed from the memory map
+fn contents(pbo: &ReadablePbo<Cursor<Mmap>>) -> Result<Contents<'_>, Error> {
+    let mut files = BTreeMap::new();
+    for (header, data) in pbo.files_bytes()? {
+        let data = if header.mime() == &Mime::Cprs {
+            Cow::Owned(hemtt_pbo::lzss::decompress(
+                data,
+                header.original() as usize,
+            )?)
+        } else {
+            Cow::Borrowed(data)
+        };
+        files.insert(
+            header.filename().to_lowercase(),
+            (header.filename().to_string(), data),
+        );
+    }
+    Ok(files)
+}
+
+/// The start of the SHA1 hash of a file
+fn hash(data: &[u8]) -> String {
+    let mut hex = Checksum::from_bytes(Sha1::digest(data).into()).hex();
+    hex.truncate(8);
+    hex
+}
+
+/// The changed lines between two rapified configs, `None` if either is not a rapified config
+fn config_diff(before: &[u8], after: &[u8]) -> Option<String> {
+    let before = Config::derapify(&mut Cursor::new(before)).ok()?.to_string();
+    let after = Config::derapify(&mut Cursor::new(after)).ok()?.to_string();
+    Some(
+        TextDiff::from_lines(&before, &after)
+            .unified_diff()
+            .context_radius(2)
+            .to_string(),
+    )
+}
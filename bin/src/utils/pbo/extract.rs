@@ -1,4 +1,4 @@
-use std::{fs::File, path::PathBuf};
+use std::{fs::File, io::Write, path::PathBuf};
 
 use hemtt_pbo::ReadablePbo;
 
@@ -21,8 +21,8 @@ pub struct PboExtractArgs {
 /// [`Error`] depending on the modules
 pub fn execute(args: &PboExtractArgs) -> Result<(), Error> {
     let path = PathBuf::from(&args.pbo);
-    let mut pbo = ReadablePbo::from(File::open(path)?)?;
-    let Some(mut file) = pbo.file(&args.file)? else {
+    let pbo = ReadablePbo::mmap(&File::open(path)?)?;
+    let Some(file) = pbo.file_bytes(&args.file)? else {
         error!("File `{}` not found in PBO", args.file);
         return Ok(());
     };
@@ -32,9 +32,9 @@ pub fn execute(args: &PboExtractArgs) -> Result<(), Error> {
             error!("Output file already exists");
             return Ok(());
         }
-        std::io::copy(&mut file, &mut File::create(output)?)?;
+        std::fs::write(output, file)?;
     } else {
-        std::io::copy(&mut file, &mut std::io::stdout())?;
+        std::io::stdout().write_all(file)?;
     }
     Ok(())
 }
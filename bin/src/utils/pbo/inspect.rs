@@ -1,11 +1,12 @@
 use std::fs::File;
 
-use hemtt_pbo::ReadablePbo;
+use hemtt_pbo::{Mime, ReadablePbo};
 use serde::Serialize;
 use tabled::{
     settings::{object::Columns, Alignment, Style},
     Table, Tabled,
 };
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 
 use crate::{Error, TableFormat};
 
@@ -22,9 +23,11 @@ pub struct PboInspectArgs {
 pub struct FileInfo {
     filename: String,
     mime: String,
+    compressed: bool,
     size: u32,
     original: u32,
     timestamp: u32,
+    modified: String,
 }
 
 /// Prints information about a [`ReadablePbo`] to stdout
@@ -34,8 +37,15 @@ pub struct FileInfo {
 ///
 /// # Panics
 /// If the file is not a valid [`ReadablePbo`]
-pub fn inspect(file: File, format: &TableFormat) -> Result<(), Error> {
-    let mut pbo = ReadablePbo::from(file)?;
+pub fn inspect(file: &File, format: &TableFormat) -> Result<(), Error> {
+    let mut pbo = ReadablePbo::mmap(file)?;
+    println!("Prefix");
+    println!(
+        "  - {}",
+        pbo.properties()
+            .get("prefix")
+            .map_or("None", String::as_str)
+    );
     println!("Properties");
     for (key, value) in pbo.properties() {
         println!("  - {key}: {value}");
@@ -45,6 +55,11 @@ pub fn inspect(file: File, format: &TableFormat) -> Result<(), Error> {
     println!("  - Stored:  {}", stored.hex());
     let actual = pbo.gen_checksum()?;
     println!("  - Actual:  {}", actual.hex());
+    if stored == actual {
+        println!("  - Valid: true");
+    } else {
+        println!("  - Valid: false !!!");
+    }
 
     let files = pbo.files();
     println!("Files");
@@ -54,14 +69,25 @@ pub fn inspect(file: File, format: &TableFormat) -> Result<(), Error> {
         println!("  - Sorted: false !!!");
     }
     println!("  - Count: {}", files.len());
+    let compressed = files
+        .iter()
+        .filter(|file| file.mime() == &Mime::Cprs)
+        .count();
+    println!("  - Compressed: {compressed}");
+    println!(
+        "  - Size: {}",
+        files.iter().map(|file| u64::from(file.size())).sum::<u64>()
+    );
     let data = files
         .iter()
         .map(|file| FileInfo {
             filename: file.filename().to_string(),
             mime: file.mime().to_string(),
+            compressed: file.mime() == &Mime::Cprs,
             size: file.size(),
             original: file.original(),
             timestamp: file.timestamp(),
+            modified: modified(file.timestamp()),
         })
         .collect::<Vec<_>>();
 
@@ -81,6 +107,17 @@ pub fn inspect(file: File, format: &TableFormat) -> Result<(), Error> {
     Ok(())
 }
 
+/// Formats a header timestamp, HEMTT and most other tools write 0
+fn modified(timestamp: u32) -> String {
+    if timestamp == 0 {
+        return String::new();
+    }
+    OffsetDateTime::from_unix_timestamp(i64::from(timestamp))
+        .ok()
+        .and_then(|date| date.format(&Rfc3339).ok())
+        .unwrap_or_default()
+}
+
 fn modify(table: &mut Table) -> &mut Table {
     table.modify(Columns::new(1..), Alignment::right())
 }
@@ -2,6 +2,7 @@ use std::{fs::File, path::PathBuf};
 
 use crate::Error;
 
+mod diff;
 mod extract;
 mod inspect;
 mod unpack;
@@ -18,6 +19,8 @@ pub struct Command {
 
 #[derive(clap::Subcommand)]
 enum Subcommands {
+    /// Compare two PBO files
+    Diff(diff::PboDiffArgs),
     /// Extract a file from a PBO
     Extract(extract::PboExtractArgs),
     /// Inspect a PBO file
@@ -35,9 +38,10 @@ enum Subcommands {
 /// If the args are not present from clap
 pub fn execute(cmd: &Command) -> Result<(), Error> {
     match &cmd.commands {
+        Subcommands::Diff(args) => diff::execute(args),
         Subcommands::Extract(args) => extract::execute(args),
         Subcommands::Inspect(args) => {
-            inspect::inspect(File::open(PathBuf::from(&args.pbo))?, &args.format)
+            inspect::inspect(&File::open(PathBuf::from(&args.pbo))?, &args.format)
         }
         Subcommands::Unpack(args) => unpack::execute(args),
     }
@@ -22,7 +22,7 @@ pub struct PboUnpackArgs {
 /// [`Error`] depending on the modules
 pub fn execute(args: &PboUnpackArgs) -> Result<(), Error> {
     let path = PathBuf::from(&args.pbo);
-    let mut pbo = ReadablePbo::from(File::open(path)?)?;
+    let pbo = ReadablePbo::mmap(&File::open(path)?)?;
     let output = PathBuf::from(&args.output);
     if output.exists() {
         error!("Output directory already exists");
@@ -42,14 +42,10 @@ pub fn execute(args: &PboUnpackArgs) -> Result<(), Error> {
             file.write_all(format!("{key}={value}\n").as_bytes())?;
         }
     }
-    for header in pbo.files() {
+    for (header, data) in pbo.files_bytes()? {
         let path = output.join(header.filename().replace('\\', "/"));
         std::fs::create_dir_all(path.parent().expect("must have parent, just joined"))?;
-        let mut out = File::create(path)?;
-        let mut file = pbo
-            .file(header.filename())?
-            .expect("file must exist if header exists");
-        std::io::copy(&mut file, &mut out)?;
+        std::fs::write(path, data)?;
     }
     Ok(())
 }
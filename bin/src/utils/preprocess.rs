@@ -0,0 +1,180 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::{
+    reporting::{Mapping, Processed},
+    LayerType, WorkspacePath,
+};
+
+use crate::{context::Context, Error};
+
+#[derive(clap::Parser)]
+#[command(verbatim_doc_comment)]
+/// Print the preprocessed output of a file
+///
+/// Inside a project, the project's include folders are used to resolve `#include`s.
+/// Outside of a project, `#include`s are only resolved from the folder of the file.
+pub struct Command {
+    /// File to preprocess
+    file: String,
+
+    #[arg(long, value_name = "MACRO")]
+    /// Show each expansion of a macro, and the macros it expands to, instead of the output
+    trace: Option<String>,
+}
+
+/// Execute the preprocess command
+///
+/// # Errors
+/// [`Error`] if the file can not be found or preprocessed
+pub fn execute(cmd: &Command) -> Result<(), Error> {
+    let processed = Processor::run(&source(Path::new(&cmd.file))?).map_err(|e| e.1)?;
+    if let Some(name) = &cmd.trace {
+        print!("{}", trace(&processed, name));
+    } else {
+        println!("{}", processed.as_str());
+    }
+    Ok(())
+}
+
+/// Find the file in the project, or in its own folder outside of a project
+///
+/// # Errors
+/// [`Error`] if the file does not exist, or the project can not be loaded
+///
+/// # Panics
+/// If the path of an existing file has no file name
+pub fn source(file: &Path) -> Result<WorkspacePath, Error> {
+    if !file.is_file() {
+        return Err(Error::Io(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("`{}` not found", file.display()),
+        )));
+    }
+    if PathBuf::from(".hemtt/project.toml").exists() {
+        let ctx = Context::read_only()?;
+        let absolute = std::fs::canonicalize(file)?;
+        let root = std::fs::canonicalize(ctx.project_folder())?;
+        if let Ok(relative) = absolute.strip_prefix(&root) {
+            return Ok(ctx
+                .workspace_path()
+                .join(relative.to_string_lossy().replace('\\', "/"))?);
+        }
+    }
+    let folder = file
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    info!(
+        "`{}` is not in a project, `#include`s are only resolved from `{}`",
+        file.display(),
+        folder.display()
+    );
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&folder.to_path_buf(), LayerType::Source)
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)?;
+    Ok(workspace.join(
+        file.file_name()
+            .expect("is a file")
+            .to_string_lossy()
+            .as_ref(),
+    )?)
+}
+
+#[must_use]
+/// Each expansion of a macro as a tree of the macros it expanded to
+pub fn trace(processed: &Processed, name: &str) -> String {
+    let mut out = String::new();
+    // Expansions are mapped after the macros they contain
+    let mut macros = processed
+        .raw_mappings()
+        .iter()
+        .enumerate()
+        .filter(|(_, mapping)| mapping.was_macro())
+        .collect::<Vec<_>>();
+    macros.sort_by_key(|(order, mapping)| {
+        (
+            mapping.processed_start().offset(),
+            std::cmp::Reverse(mapping.processed_end().offset()),
+            std::cmp::Reverse(*order),
+        )
+    });
+    let macros = macros
+        .into_iter()
+        .map(|(_, mapping)| mapping)
+        .collect::<Vec<_>>();
+    if let Some(definitions) = processed.macros().get(name) {
+        for definition in definitions {
+            writeln!(
+                out,
+                "{name} defined at {}:{}",
+                definition.path(),
+                definition.start().line()
+            )
+            .expect("writing to a string");
+        }
+    }
+    let mut covered = 0;
+    let mut count = 0;
+    for (index, mapping) in macros.iter().enumerate() {
+        if mapping.token().to_source() != name || mapping.processed_start().offset() < covered {
+            continue;
+        }
+        covered = mapping.processed_end().offset();
+        count += 1;
+        out.push('\n');
+        write_expansion(&mut out, processed, mapping, 0);
+        // Macros are sorted so the ones containing another come first
+        let nested = macros[index + 1..]
+            .iter()
+            .take_while(|nested| nested.processed_start().offset() < covered)
+            .collect::<Vec<_>>();
+        for (i, inner) in nested.iter().enumerate() {
+            let depth = 1 + nested[..i]
+                .iter()
+                .filter(|outer| contains(outer, inner))
+                .count();
+            write_expansion(&mut out, processed, inner, depth);
+        }
+    }
+    if count == 0 {
+        warn!("`{name}` is not expanded in the file");
+    }
+    out
+}
+
+/// Does the expansion of `outer` contain the expansion of `inner`
+const fn contains(outer: &Mapping, inner: &Mapping) -> bool {
+    outer.processed_start().offset() <= inner.processed_start().offset()
+        && outer.processed_end().offset() >= inner.processed_end().offset()
+}
+
+fn write_expansion(out: &mut String, processed: &Processed, mapping: &Mapping, depth: usize) {
+    let position = mapping.original();
+    let indent = "  ".repeat(depth);
+    let range = mapping.processed_start().offset()..mapping.processed_end().offset();
+    let output = if range.is_empty() {
+        String::new()
+    } else {
+        processed.extract(range).to_string()
+    };
+    let name = mapping.token().to_source();
+    // Arguments of a function macro are not defines of the file
+    let kind = if processed.macros().contains_key(&name) {
+        ""
+    } else {
+        "argument "
+    };
+    writeln!(
+        out,
+        "{indent}{kind}{name} at {}:{}:{}",
+        position.path(),
+        position.start().line(),
+        position.start().column() + 1,
+    )
+    .expect("writing to a string");
+    writeln!(out, "{indent}  -> {}", output.trim()).expect("writing to a string");
+}
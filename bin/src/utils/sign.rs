@@ -0,0 +1,97 @@
+use std::{fs::File, path::PathBuf};
+
+use hemtt_common::BISignVersion;
+use hemtt_pbo::ReadablePbo;
+use hemtt_signing::BIPrivateKey;
+
+use crate::Error;
+
+#[derive(clap::Parser)]
+#[command(arg_required_else_help = true)]
+/// Sign PBOs
+///
+/// Signs PBOs with a new or existing private key,
+/// the .bisign is written next to each PBO
+pub struct Command {
+    #[arg(required = true)]
+    /// PBOs to sign, or folders containing PBOs
+    pbos: Vec<String>,
+    #[arg(long, conflicts_with = "authority")]
+    /// Existing `.biprivatekey` to sign with
+    key: Option<String>,
+    #[arg(long, required_unless_present = "key")]
+    /// Authority of a new key
+    authority: Option<String>,
+    #[arg(long)]
+    /// Folder to write the new `.bikey` to, defaults to the current folder
+    keys: Option<String>,
+    #[arg(long, action = clap::ArgAction::SetTrue, requires = "authority")]
+    /// Also write the new `.biprivatekey`, to sign more PBOs with the same key later
+    keep_private: bool,
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(2..=3))]
+    /// Version of the signatures, 2 or 3
+    signature_version: u32,
+}
+
+/// Execute the sign command
+///
+/// # Errors
+/// [`Error`] if a PBO or key can not be read, or a file can not be written
+///
+/// # Panics
+/// If neither a key nor an authority is set, which clap prevents
+pub fn execute(cmd: &Command) -> Result<(), Error> {
+    let version = if cmd.signature_version == 2 {
+        BISignVersion::V2
+    } else {
+        BISignVersion::V3
+    };
+
+    let key = if let Some(key) = &cmd.key {
+        debug!("Reading private key: {key}");
+        BIPrivateKey::read(&mut File::open(key)?)?
+    } else {
+        let authority = cmd.authority.as_ref().expect("clap requires authority");
+        let key = BIPrivateKey::generate(1024, authority)?;
+        let keys = cmd.keys.as_ref().map_or_else(PathBuf::new, PathBuf::from);
+        std::fs::create_dir_all(&keys)?;
+        let bikey = keys.join(format!("{authority}.bikey"));
+        key.to_public_key().write(&mut File::create(&bikey)?)?;
+        info!("Created `{}`", bikey.display());
+        if cmd.keep_private {
+            let private = keys.join(format!("{authority}.biprivatekey"));
+            key.write_danger(&mut File::create(&private)?)?;
+            warn!(
+                "Created `{}`, keep it private, anyone with it can sign PBOs as `{authority}`",
+                private.display()
+            );
+        }
+        key
+    };
+    let authority = key.to_public_key().authority().to_string();
+
+    let mut pbos = Vec::new();
+    for path in &cmd.pbos {
+        let path = PathBuf::from(path);
+        if path.is_dir() {
+            for entry in std::fs::read_dir(&path)? {
+                let entry = entry?.path();
+                if entry.extension().is_some_and(|ext| ext == "pbo") {
+                    pbos.push(entry);
+                }
+            }
+        } else {
+            pbos.push(path);
+        }
+    }
+    pbos.sort();
+
+    for path in pbos {
+        let mut pbo = ReadablePbo::from(File::open(&path)?)?;
+        let signature = path.with_extension(format!("pbo.{authority}.bisign"));
+        key.sign(&mut pbo, version)?
+            .write(&mut File::create(&signature)?)?;
+        info!("Signed `{}`", path.display());
+    }
+    Ok(())
+}
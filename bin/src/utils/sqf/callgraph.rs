@@ -0,0 +1,253 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Write,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use hemtt_preprocessor::Processor;
+use hemtt_sqf::{parser::database::Database, BinaryCommand, Expression, UnaryCommand};
+use hemtt_workspace::WorkspacePath;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde::Serialize;
+
+use crate::{context::Context, dot, Error};
+
+#[derive(clap::Args)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SqfCallgraphArgs {
+    /// File to write, such as `callgraph.dot`
+    output: String,
+    #[arg(long)]
+    /// Format of the file, taken from the extension if not set
+    format: Option<Format>,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+/// Format of the call graph
+pub enum Format {
+    /// A Graphviz digraph
+    Dot,
+    /// An object with `nodes` and `edges`
+    Json,
+}
+
+impl Format {
+    /// The format from `--format`, or the extension of the file
+    fn resolve(format: Option<Self>, path: &Path) -> Self {
+        format.unwrap_or_else(|| {
+            if path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+            {
+                Self::Json
+            } else {
+                Self::Dot
+            }
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct Graph {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+#[derive(Serialize)]
+struct Node {
+    /// The function name, or the path of scripts that are not functions
+    id: String,
+    /// The file of the node, `None` for functions outside of the project
+    file: Option<String>,
+}
+
+#[derive(Serialize, PartialEq, Eq, PartialOrd, Ord)]
+struct Edge {
+    from: String,
+    to: String,
+    /// `call`, `spawn`, or `execVM`
+    kind: &'static str,
+}
+
+/// A call found in a file, before it is resolved to a node
+enum Target {
+    Function(String),
+    Script(String),
+}
+
+/// Execute the callgraph command
+///
+/// # Errors
+/// [`Error`] if the project can not be loaded, or the file can not be written
+pub fn execute(args: &SqfCallgraphArgs) -> Result<(), Error> {
+    let ctx = Context::new(None, crate::context::PreservePrevious::Keep, None, false)?;
+    let database = Arc::new(Database::a3_with_workspace(ctx.workspace_path(), false)?);
+    let prefix = ctx.config().prefix().to_lowercase();
+
+    let mut entries = Vec::new();
+    for addon in ctx.addons() {
+        for entry in ctx.workspace_path().join(addon.folder())?.walk_dir()? {
+            if entry.is_file()?
+                && entry.extension().as_deref() == Some("sqf")
+                && !entry.filename().ends_with(".inc.sqf")
+            {
+                entries.push((addon.name().to_lowercase(), entry));
+            }
+        }
+    }
+
+    let files = entries
+        .par_iter()
+        .map(|(addon, entry)| {
+            let id = function_name(&prefix, addon, entry)
+                .unwrap_or_else(|| entry.as_str().trim_start_matches('/').to_string());
+            (
+                id,
+                entry.as_str().trim_start_matches('/').to_string(),
+                calls(&database, entry),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let functions = files
+        .iter()
+        .map(|(id, _, _)| (id.to_lowercase(), id.clone()))
+        .collect::<HashMap<_, _>>();
+    let mut nodes = files
+        .iter()
+        .map(|(id, file, _)| (id.clone(), Some(file.clone())))
+        .collect::<BTreeMap<_, _>>();
+    let mut edges = BTreeSet::new();
+    for (from, _, calls) in &files {
+        for (kind, target) in calls {
+            let to = match target {
+                Target::Function(name) => functions
+                    .get(&name.to_lowercase())
+                    .cloned()
+                    .unwrap_or_else(|| name.clone()),
+                Target::Script(path) => {
+                    let path = path.replace('\\', "/").to_lowercase();
+                    files
+                        .iter()
+                        .find(|(_, file, _)| {
+                            let file = file.to_lowercase();
+                            path.ends_with(&file) || file.ends_with(path.trim_start_matches('/'))
+                        })
+                        .map_or_else(|| path.clone(), |(id, _, _)| id.clone())
+                }
+            };
+            nodes.entry(to.clone()).or_insert(None);
+            edges.insert(Edge {
+                from: from.clone(),
+                to,
+                kind,
+            });
+        }
+    }
+    let graph = Graph {
+        nodes: nodes
+            .into_iter()
+            .map(|(id, file)| Node { id, file })
+            .collect(),
+        edges: edges.into_iter().collect(),
+    };
+
+    let output = PathBuf::from(&args.output);
+    match Format::resolve(args.format, &output) {
+        Format::Dot => std::fs::write(&output, dot(&graph))?,
+        Format::Json => std::fs::write(&output, serde_json::to_string_pretty(&graph)?)?,
+    }
+    info!(
+        "Wrote {} nodes and {} calls to `{}`",
+        graph.nodes.len(),
+        graph.edges.len(),
+        output.display()
+    );
+    Ok(())
+}
+
+/// The name of a CBA style function, `addons/<addon>/functions/fnc_<name>.sqf`
+fn function_name(prefix: &str, addon: &str, entry: &WorkspacePath) -> Option<String> {
+    let name = entry
+        .filename()
+        .strip_suffix(".sqf")?
+        .strip_prefix("fnc_")?
+        .to_string();
+    if !entry.parent().filename().eq_ignore_ascii_case("functions") {
+        return None;
+    }
+    Some(format!("{prefix}_{addon}_fnc_{name}"))
+}
+
+/// The functions and scripts a file calls
+fn calls(database: &Database, entry: &WorkspacePath) -> Vec<(&'static str, Target)> {
+    let processed = match Processor::run(entry) {
+        Ok(processed) => processed,
+        Err((_, e)) => {
+            warn!("Failed to preprocess `{entry}`: {e}");
+            return Vec::new();
+        }
+    };
+    let Ok(sqf) = hemtt_sqf::parser::run(database, &processed) else {
+        warn!("Failed to parse `{entry}`, run `hemtt check` for details");
+        return Vec::new();
+    };
+    let mut calls = Vec::new();
+    for expression in sqf
+        .content()
+        .iter()
+        .flat_map(hemtt_sqf::Statement::walk_expressions)
+    {
+        let (Expression::UnaryCommand(UnaryCommand::Named(command), target, _)
+        | Expression::BinaryCommand(BinaryCommand::Named(command), _, target, _)) = expression
+        else {
+            continue;
+        };
+        let kind = match command.to_lowercase().as_str() {
+            "call" => "call",
+            "spawn" => "spawn",
+            "execvm" => "execVM",
+            _ => continue,
+        };
+        match (kind, &**target) {
+            ("call" | "spawn", Expression::Variable(name, _)) if !name.starts_with('_') => {
+                calls.push((kind, Target::Function(name.clone())));
+            }
+            ("execVM", Expression::String(path, _, _)) => {
+                calls.push((kind, Target::Script(path.to_string())));
+            }
+            _ => {}
+        }
+    }
+    calls
+}
+
+fn dot(graph: &Graph) -> String {
+    let mut out = String::from("digraph callgraph {\n");
+    for node in &graph.nodes {
+        if node.file.is_some() {
+            writeln!(out, "    {};", dot::id(&node.id)).expect("writing to a string");
+        } else {
+            writeln!(out, "    {} [style=dashed];", dot::id(&node.id))
+                .expect("writing to a string");
+        }
+    }
+    for edge in &graph.edges {
+        if edge.kind == "call" {
+            writeln!(out, "    {} -> {};", dot::id(&edge.from), dot::id(&edge.to))
+                .expect("writing to a string");
+        } else {
+            writeln!(
+                out,
+                "    {} -> {} [label={}];",
+                dot::id(&edge.from),
+                dot::id(&edge.to),
+                dot::id(edge.kind)
+            )
+            .expect("writing to a string");
+        }
+    }
+    out.push_str("}\n");
+    out
+}
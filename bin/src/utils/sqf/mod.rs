@@ -1,3 +1,4 @@
+mod callgraph;
 mod case;
 
 use crate::Error;
@@ -14,6 +15,8 @@ pub struct Command {
 enum Subcommands {
     /// Convert case
     Case(case::SqfCaseArgs),
+    /// Export the call graph of the project
+    Callgraph(callgraph::SqfCallgraphArgs),
 }
 
 /// Execute the paa command
@@ -26,5 +29,6 @@ enum Subcommands {
 pub fn execute(cmd: &Command) -> Result<(), Error> {
     match &cmd.commands {
         Subcommands::Case(args) => case::execute(args),
+        Subcommands::Callgraph(args) => callgraph::execute(args),
     }
 }
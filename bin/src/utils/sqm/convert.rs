@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use hemtt_config::rapify::Rapify;
+use hemtt_workspace::reporting::WorkspaceFiles;
+
+use crate::Error;
+
+use super::Mission;
+
+#[derive(clap::Args)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ConvertArgs {
+    /// Mission to read, usually `mission.sqm`
+    pub(crate) mission: String,
+    /// Where to save the mission, printed if not set and the mission is binarized
+    pub(crate) output: Option<String>,
+}
+
+/// Execute the convert command
+///
+/// Binarized missions are written as text, and text missions are binarized
+///
+/// # Errors
+/// [`Error::Io`] if the mission can not be read, or the output can not be written
+pub fn execute(args: &ConvertArgs) -> Result<(), Error> {
+    let output = args.output.as_ref().map(PathBuf::from);
+    if output.as_ref().is_some_and(|output| output.exists()) {
+        error!("Output file already exists");
+        return Ok(());
+    }
+    match super::read(&PathBuf::from(&args.mission))? {
+        Mission::Binarized(config) => {
+            if let Some(output) = output {
+                std::fs::write(&output, config.to_string())?;
+                info!("Wrote the text mission to `{}`", output.display());
+            } else {
+                print!("{config}");
+            }
+        }
+        Mission::Text(config) => {
+            let Some(output) = output else {
+                error!("An output is required to binarize a mission");
+                return Ok(());
+            };
+            let mut buffer = Vec::new();
+            config.rapify(&mut buffer, 0)?;
+            std::fs::write(&output, buffer)?;
+            info!("Wrote the binarized mission to `{}`", output.display());
+        }
+        Mission::Invalid(errors) => {
+            let workspacefiles = WorkspaceFiles::new();
+            for error in errors {
+                if let Some(diag) = error.diagnostic() {
+                    eprintln!("{}", diag.to_string(&workspacefiles));
+                }
+            }
+            error!("The mission could not be read");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use hemtt_config::{rapify::Derapify, Config};
+use hemtt_workspace::reporting::Code;
+
+use crate::Error;
+
+mod convert;
+mod validate;
+
+pub use validate::validate;
+
+#[derive(clap::Parser)]
+#[command(arg_required_else_help = true)]
+/// Commands for mission files
+pub struct Command {
+    #[command(subcommand)]
+    commands: Subcommands,
+}
+
+#[derive(clap::Subcommand)]
+enum Subcommands {
+    /// Convert a mission between text and binarized
+    Convert(convert::ConvertArgs),
+    /// Check that a mission can be read by the game
+    Validate(validate::ValidateArgs),
+}
+
+/// Execute the sqm command
+///
+/// # Errors
+/// [`Error`] depending on the modules
+pub fn execute(cmd: &Command) -> Result<(), Error> {
+    match &cmd.commands {
+        Subcommands::Convert(args) => convert::execute(args),
+        Subcommands::Validate(args) => validate(&PathBuf::from(&args.mission)),
+    }
+}
+
+/// A mission read from a file
+enum Mission {
+    /// A binarized mission
+    Binarized(Config),
+    /// A text mission
+    Text(Config),
+    /// A text mission that could not be parsed
+    Invalid(Vec<Arc<dyn Code>>),
+}
+
+/// Read a mission, either binarized or text
+fn read(file: &PathBuf) -> Result<Mission, Error> {
+    let mut magic = [0; 4];
+    let read = File::open(file)?.read(&mut magic)?;
+    if hemtt_config::rapify::is_rapified(&magic[..read]) {
+        return Ok(Mission::Binarized(Config::derapify(&mut BufReader::new(
+            File::open(file)?,
+        ))?));
+    }
+    Ok(match super::config::get_report(file)? {
+        Ok(report) => {
+            let invalid = report.errors().into_iter().cloned().collect::<Vec<_>>();
+            if invalid.is_empty() {
+                Mission::Text(report.into_config())
+            } else {
+                Mission::Invalid(invalid)
+            }
+        }
+        Err(errors) => Mission::Invalid(errors),
+    })
+}
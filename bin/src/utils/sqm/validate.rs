@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use hemtt_config::sqm;
+use hemtt_workspace::reporting::WorkspaceFiles;
+
+use crate::Error;
+
+use super::Mission;
+
+#[derive(clap::Args)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ValidateArgs {
+    /// Mission to check, usually `mission.sqm`
+    pub(crate) mission: String,
+}
+
+/// Prints information about a mission to stdout
+///
+/// # Errors
+/// [`Error::Io`] if the mission can not be read
+pub fn validate(file: &PathBuf) -> Result<(), Error> {
+    let (config, binarized) = match super::read(file)? {
+        Mission::Binarized(config) => (config, true),
+        Mission::Text(config) => (config, false),
+        Mission::Invalid(errors) => {
+            let workspacefiles = WorkspaceFiles::new();
+            for error in errors {
+                if let Some(diag) = error.diagnostic() {
+                    eprintln!("{}", diag.to_string(&workspacefiles));
+                }
+            }
+            error!("The mission could not be read");
+            return Ok(());
+        }
+    };
+    let Some(version) = sqm::version(&config) else {
+        error!("`version` must be the first property of the mission, the game will not load it");
+        return Ok(());
+    };
+    println!("Mission is valid!");
+    println!(" - Version {version}");
+    if binarized {
+        println!(" - Binarized");
+    } else if sqm::binarization_wanted(&config) {
+        println!(" - Text, binarized when built");
+    } else {
+        println!(" - Text, `binarizationWanted = 0` keeps it as text when built");
+    }
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use hemtt_pbo::ReadablePbo;
+use hemtt_signing::{generate_hashes, BIPublicKey, BISign};
+use hemtt_workspace::reporting::{Code, Diagnostic};
+
+use crate::{report::Report, Error};
+
+#[derive(clap::Parser)]
+#[command(arg_required_else_help = true)]
+/// Verify the signatures of every PBO in a folder
+///
+/// Checks each .bisign against its PBO and a public key,
+/// and reports which part of the signature does not match
+pub struct Command {
+    /// Folder containing the PBOs, such as `@my_mod/addons`
+    addons: String,
+    /// `BIKey` to verify against
+    bikey: String,
+}
+
+/// Execute the verify-signs command
+///
+/// # Errors
+/// [`Error`] if the folder or key can not be read
+pub fn execute(cmd: &Command) -> Result<Report, Error> {
+    let bikey_path = PathBuf::from(&cmd.bikey);
+    debug!("Reading BIKey: {:?}", &bikey_path);
+    let key = BIPublicKey::read(&mut File::open(&bikey_path)?)?;
+
+    let mut pbos = std::fs::read_dir(&cmd.addons)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    pbos.retain(|path| path.extension().is_some_and(|ext| ext == "pbo"));
+    pbos.sort();
+
+    let mut report = Report::new();
+    let mut verified = 0;
+    for path in &pbos {
+        match verify(path, &key) {
+            Ok(()) => {
+                debug!("Verified `{}`", path.display());
+                verified += 1;
+            }
+            Err(reason) => report.push(SignatureInvalid::code(path, reason)),
+        }
+    }
+    info!("Verified {verified} of {} PBOs", pbos.len());
+    Ok(report)
+}
+
+fn verify(path: &Path, key: &BIPublicKey) -> Result<(), Reason> {
+    let signature_path = path.with_extension(format!("pbo.{}.bisign", key.authority()));
+    let Ok(mut signature) = File::open(&signature_path) else {
+        return Err(Reason::MissingSignature(
+            signature_path
+                .file_name()
+                .expect("signature has a file name")
+                .to_string_lossy()
+                .to_string(),
+        ));
+    };
+    let signature = BISign::read(&mut signature).map_err(|e| Reason::Unreadable(e.to_string()))?;
+    if signature.authority() != key.authority() {
+        return Err(Reason::AuthorityMismatch(signature.authority().to_string()));
+    }
+    if signature.modulus() != key.modulus() {
+        return Err(Reason::DifferentKey);
+    }
+
+    let mut pbo =
+        ReadablePbo::from(File::open(path).map_err(|e| Reason::Unreadable(e.to_string()))?)
+            .map_err(|e| Reason::Unreadable(e.to_string()))?;
+    if pbo.is_sorted().is_err() {
+        return Err(Reason::InvalidFileSorting);
+    }
+    let real = generate_hashes(&mut pbo, signature.version(), key.length())
+        .map_err(|e| Reason::Unreadable(e.to_string()))?;
+    let signed = signature.signatures_modpow();
+    let mismatched = [
+        (1, &signed.0, &real.0),
+        (2, &signed.1, &real.1),
+        (3, &signed.2, &real.2),
+    ]
+    .into_iter()
+    .filter(|(_, signed, real)| signed != real)
+    .map(|(hash, signed, real)| {
+        (
+            hash,
+            short_hash(&signed.to_str_radix(16)),
+            short_hash(&real.to_str_radix(16)),
+        )
+    })
+    .collect::<Vec<_>>();
+    if mismatched.is_empty() {
+        Ok(())
+    } else {
+        Err(Reason::HashMismatch(mismatched))
+    }
+}
+
+/// The SHA1 hash at the end of a padded hash
+fn short_hash(hex: &str) -> String {
+    hex[hex.len().saturating_sub(40)..].to_string()
+}
+
+enum Reason {
+    MissingSignature(String),
+    Unreadable(String),
+    AuthorityMismatch(String),
+    DifferentKey,
+    InvalidFileSorting,
+    /// The hash number, the signed hash, and the actual hash
+    HashMismatch(Vec<(u8, String, String)>),
+}
+
+pub struct SignatureInvalid {
+    pbo: String,
+    reason: Reason,
+}
+
+impl Code for SignatureInvalid {
+    fn ident(&self) -> &'static str {
+        "BUSE1"
+    }
+
+    fn message(&self) -> String {
+        match &self.reason {
+            Reason::MissingSignature(signature) => {
+                format!("`{}` has no signature, expected `{signature}`", self.pbo)
+            }
+            _ => format!("`{}` failed verification", self.pbo),
+        }
+    }
+
+    fn note(&self) -> Option<String> {
+        match &self.reason {
+            Reason::MissingSignature(_) => None,
+            Reason::Unreadable(e) => Some(format!("unable to read the PBO or signature: {e}")),
+            Reason::AuthorityMismatch(authority) => {
+                Some(format!("the signature was made by `{authority}`"))
+            }
+            Reason::DifferentKey => Some(
+                "the signature has the same authority, but was made with a different key"
+                    .to_string(),
+            ),
+            Reason::InvalidFileSorting => {
+                Some("the files in the PBO are not sorted, the game will reject it".to_string())
+            }
+            Reason::HashMismatch(hashes) => Some(
+                hashes
+                    .iter()
+                    .map(|(hash, signed, real)| {
+                        let part = match hash {
+                            1 => "the checksum of the whole PBO",
+                            2 => "hash 1, the file names, and the prefix",
+                            _ => "the contents of the signed files, the file names, and the prefix",
+                        };
+                        format!("hash {hash} covers {part}\n  signed `{signed}`\n  actual `{real}`")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        let Reason::HashMismatch(hashes) = &self.reason else {
+            return None;
+        };
+        if hashes.iter().any(|(hash, _, _)| *hash == 3) {
+            Some("files in the PBO were changed, added, removed, or renamed after it was signed, sign it again or restore the original".to_string())
+        } else {
+            Some("the signed files are unchanged, only files not covered by this signature version, or the properties of the PBO, were changed".to_string())
+        }
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
+
+impl SignatureInvalid {
+    fn code(pbo: &Path, reason: Reason) -> Arc<dyn Code> {
+        Arc::new(Self {
+            pbo: pbo
+                .file_name()
+                .expect("pbo has a file name")
+                .to_string_lossy()
+                .to_string(),
+            reason,
+        })
+    }
+}
@@ -0,0 +1,74 @@
+use hemtt::modules::binarize::log;
+
+#[test]
+fn log_missing_textures() {
+    let codes = log::codes(
+        "addons/main/data/box.p3d",
+        "Warning: Cannot load texture a3\\data_f\\box_co.paa\n\
+         Warning: cannot load texture A3\\DATA_F\\BOX_CO.PAA\n\
+         missing file 'z\\test\\addons\\main\\data\\box_nohq.paa'\n",
+    );
+    assert_eq!(codes.len(), 1);
+    assert_eq!(codes[0].ident(), "BBW3");
+    assert_eq!(
+        codes[0].message(),
+        "binarize could not load 2 textures for addons/main/data/box.p3d:\n  a3/data_f/box_co.paa\n  z/test/addons/main/data/box_nohq.paa"
+    );
+}
+
+#[test]
+fn log_bone_not_found() {
+    let codes = log::codes(
+        "addons/main/data/door.p3d",
+        "Bone door_1 not found in skeleton 'Vehicle_Skeleton'.\nbone \"door_2\" not found\n",
+    );
+    assert_eq!(codes.len(), 2);
+    assert!(codes.iter().all(|code| code.ident() == "BBW4"));
+    assert_eq!(
+        codes[0].message(),
+        "bone `door_1` not found in skeleton `Vehicle_Skeleton` for addons/main/data/door.p3d"
+    );
+    assert_eq!(
+        codes[1].message(),
+        "bone `door_2` not found for addons/main/data/door.p3d"
+    );
+}
+
+#[test]
+fn log_degenerated_faces() {
+    let codes = log::codes(
+        "addons/main/data/box.p3d",
+        "Warning: box.p3d:1.000 - 12 degenerated faces\n\
+         box.p3d:1e+15 degenerated faces: 1\n\
+         degenerated faces\n",
+    );
+    assert_eq!(codes.len(), 3);
+    assert!(codes.iter().all(|code| code.ident() == "BBW5"));
+    assert_eq!(
+        codes[0].message(),
+        "addons/main/data/box.p3d has 12 degenerated faces in LOD 1.000"
+    );
+    assert_eq!(
+        codes[1].message(),
+        "addons/main/data/box.p3d has 1 degenerated face in LOD 1e+15"
+    );
+    assert_eq!(
+        codes[2].message(),
+        "addons/main/data/box.p3d has degenerated faces"
+    );
+}
+
+#[test]
+fn log_order_and_unknown() {
+    let codes = log::codes(
+        "addons/main/data/box.p3d",
+        "Binarizing addons/main/data/box.p3d\n\
+         \n\
+         Bone door not found\n\
+         Warning: Cannot open texture box_co.paa\n\
+         Done\n",
+    );
+    let idents = codes.iter().map(|code| code.ident()).collect::<Vec<_>>();
+    assert_eq!(idents, vec!["BBW3", "BBW4"]);
+    assert!(log::codes("addons/main/data/box.p3d", "Done\n").is_empty());
+}
@@ -1,9 +1,20 @@
 #![allow(clippy::unwrap_used)]
 
+use std::io::Read;
+
 use clap::Parser;
+use hemtt_config::{rapify::Rapify, Class, Config, Ident, Property};
 use sealed_test::prelude::*;
 
-use hemtt::Cli;
+use hemtt::{
+    commands::check,
+    context::{Context, PreservePrevious},
+    modules::{
+        pbo::Collapse, AddonConfigs, AudioConverter, Dependencies, Module, PaaConverter, Rapifier,
+        SQFCompiler, Stringtables,
+    },
+    Cli,
+};
 
 #[sealed_test]
 fn build_alpha() {
@@ -18,3 +29,1841 @@ fn build_bravo() {
     hemtt::execute(&Cli::parse_from(vec!["hemtt", "script", "test"])).unwrap();
     hemtt::execute(&Cli::parse_from(vec!["hemtt", "release", "--in-test"])).unwrap();
 }
+
+#[sealed_test]
+fn check_sarif() {
+    hemtt::execute(&Cli::parse_from(vec!["hemtt", "new", "test", "--in-test"])).unwrap();
+    // the new repository has no commits
+    let mut project = std::fs::read_to_string("test/.hemtt/project.toml").unwrap();
+    project.push_str("\n[version]\ngit_hash = 0\n");
+    std::fs::write("test/.hemtt/project.toml", project).unwrap();
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "check",
+        "--dir",
+        "test",
+        "--in-test",
+        "--sarif",
+        ".hemttout/check.sarif",
+    ]))
+    .unwrap();
+    let sarif: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(".hemttout/check.sarif").unwrap()).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(sarif["runs"][0]["tool"]["driver"]["name"], "HEMTT");
+    assert!(sarif["runs"][0]["results"].is_array());
+}
+
+#[sealed_test]
+fn check_sarif_results() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Sarif\"\nprefix = \"sa\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\sa\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "#define A 1\n#define A 2\nclass CfgPatches {\n    class sa_main {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {};\n        value = A;\n    };\n};\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/other").unwrap();
+    std::fs::write("addons/other/$PBOPREFIX$", "z\\sa\\addons\\other").unwrap();
+    std::fs::write(
+        "addons/other/config.cpp",
+        "class MyMod {\n    value = 1;\n};\n",
+    )
+    .unwrap();
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "check",
+        "--in-test",
+        "--sarif",
+        ".hemttout/check.sarif",
+    ]))
+    .unwrap();
+    let sarif: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(".hemttout/check.sarif").unwrap()).unwrap();
+    let run = &sarif["runs"][0];
+    let result = |rule: &str| {
+        let results = run["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|result| result["ruleId"] == rule)
+            .collect::<Vec<_>>();
+        assert_eq!(results.len(), 1, "one result for {rule}");
+        results[0].clone()
+    };
+    let rule = |id: &str| {
+        run["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|rule| rule["id"] == id)
+            .unwrap()
+            .clone()
+    };
+
+    let redefine = result("PW1");
+    assert_eq!(redefine["level"], "warning");
+    assert_eq!(
+        redefine["message"]["text"],
+        "redefining macro\nhelp: `#undef` macros before redefining them"
+    );
+    let location = &redefine["locations"][0]["physicalLocation"];
+    assert_eq!(
+        location["artifactLocation"]["uri"],
+        "addons/main/config.cpp"
+    );
+    assert_eq!(
+        location["region"],
+        serde_json::json!({
+            "startLine": 2,
+            "startColumn": 9,
+            "endLine": 2,
+            "endColumn": 10,
+        })
+    );
+    assert_eq!(rule("PW1")["shortDescription"]["text"], "redefining macro");
+
+    let patches = result("L-C12MC");
+    assert_eq!(
+        patches["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "addons/other/config.cpp"
+    );
+    assert!(patches["locations"][0]["physicalLocation"]["region"]["startLine"].is_u64());
+    assert!(rule("L-C12MC")["helpUri"].is_string());
+}
+
+#[sealed_test]
+fn build_alpha_out() {
+    std::env::set_current_dir(format!("{}/tests/alpha", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "build",
+        "--in-test",
+        "--out",
+        ".hemttout/redirected",
+    ]))
+    .unwrap();
+    assert!(std::path::Path::new(".hemttout/redirected/build/addons/test_main.pbo").exists());
+    assert!(std::path::Path::new(".hemttout/redirected/build/addons/test_secondary.pbo").exists());
+}
+
+#[sealed_test]
+fn build_alpha_no_clean() {
+    std::env::set_current_dir(format!("{}/tests/alpha", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    std::fs::create_dir_all(".hemttout/no_clean/build").unwrap();
+    std::fs::write(".hemttout/no_clean/build/unrelated.txt", "keep me").unwrap();
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "build",
+        "--in-test",
+        "--out",
+        ".hemttout/no_clean",
+        "--no-clean",
+    ]))
+    .unwrap();
+    assert!(std::path::Path::new(".hemttout/no_clean/build/addons/test_main.pbo").exists());
+    assert_eq!(
+        std::fs::read_to_string(".hemttout/no_clean/build/unrelated.txt").unwrap(),
+        "keep me"
+    );
+}
+
+#[sealed_test]
+fn build_exclude() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Exclude\"\nprefix = \"ex\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    for addon in ["main", "terrain"] {
+        std::fs::create_dir_all(format!("addons/{addon}")).unwrap();
+        std::fs::write(
+            format!("addons/{addon}/$PBOPREFIX$"),
+            format!("z\\ex\\addons\\{addon}"),
+        )
+        .unwrap();
+        std::fs::write(
+            format!("addons/{addon}/config.cpp"),
+            format!("class CfgPatches {{\n    class ex_{addon} {{\n        units[] = {{}};\n        weapons[] = {{}};\n        requiredVersion = 2.14;\n        requiredAddons[] = {{}};\n    }};\n}};\n"),
+        )
+        .unwrap();
+    }
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "build",
+        "--in-test",
+        "--exclude",
+        "Terrain",
+    ]))
+    .unwrap();
+    assert!(std::path::Path::new(".hemttout/build/addons/ex_main.pbo").exists());
+    assert!(!std::path::Path::new(".hemttout/build/addons/ex_terrain.pbo").exists());
+
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "dev",
+        "--in-test",
+        "--only",
+        "terrain",
+    ]))
+    .unwrap();
+    assert!(!std::path::Path::new(".hemttout/dev/addons/ex_main.pbo").exists());
+    assert!(std::path::Path::new(".hemttout/dev/addons/ex_terrain.pbo").exists());
+}
+
+#[sealed_test]
+fn build_only_unused_function() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Only\"\nprefix = \"on\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[lints.sqf.unused_function]\nenabled = true\n",
+    )
+    .unwrap();
+    for addon in ["main", "other"] {
+        std::fs::create_dir_all(format!("addons/{addon}/functions")).unwrap();
+        std::fs::write(
+            format!("addons/{addon}/$PBOPREFIX$"),
+            format!("z\\on\\addons\\{addon}"),
+        )
+        .unwrap();
+    }
+    std::fs::write(
+        "addons/main/XEH_PREP.sqf",
+        "[\"\\z\\on\\addons\\main\\functions\\fnc_helper.sqf\", \"on_main_fnc_helper\"] call CBA_fnc_compileFunction;\n",
+    )
+    .unwrap();
+    std::fs::write("addons/main/functions/fnc_helper.sqf", "true\n").unwrap();
+    // only used by an addon that is not built
+    std::fs::write("addons/other/script.sqf", "[] call on_main_fnc_helper;\n").unwrap();
+
+    let unused = |args: &[&str]| {
+        let (report, _) = hemtt::commands::dev::execute(
+            &hemtt::commands::dev::Command::parse_from(
+                ["dev", "--in-test"].iter().chain(args.iter()),
+            ),
+            &[],
+        )
+        .unwrap();
+        report
+            .codes()
+            .iter()
+            .filter(|code| code.ident() == "L-S28")
+            .count()
+    };
+    assert_eq!(unused(&[]), 0);
+    assert_eq!(unused(&["--only", "main"]), 0);
+    // the function is unused once the other addon no longer calls it
+    std::fs::write("addons/other/script.sqf", "true\n").unwrap();
+    assert_eq!(unused(&[]), 1);
+}
+
+#[sealed_test]
+fn build_files_exclude() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Exclude\"\nprefix = \"ex\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[files]\nexclude = [\"**/*.psd\", \"addons/main/README.md\"]\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main/data/source").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\ex\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/addon.toml",
+        "[files]\nexclude = [\"data/*.txt\"]\n",
+    )
+    .unwrap();
+    for file in [
+        "README.md",
+        "data/texture.psd",
+        "data/notes.txt",
+        "data/source/notes.txt",
+    ] {
+        std::fs::write(format!("addons/main/{file}"), "data").unwrap();
+    }
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    let report = hemtt::modules::pbo::build(&ctx, Collapse::No).unwrap();
+    assert!(!report.failed());
+    let pbo = hemtt_pbo::ReadablePbo::from(
+        std::fs::File::open(".hemttout/build/addons/ex_main.pbo").unwrap(),
+    )
+    .unwrap();
+    let mut files = pbo
+        .files()
+        .iter()
+        .map(|header| header.filename().to_string())
+        .collect::<Vec<_>>();
+    files.sort();
+    // `*` does not match across folders
+    assert_eq!(files, vec!["data\\source\\notes.txt"]);
+}
+
+#[sealed_test]
+fn addon_config_shared() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Addon\"\nprefix = \"ad\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[lints.config.invalid_value]\nseverity = \"Error\"\n",
+    )
+    .unwrap();
+    for addon in ["main", "other"] {
+        std::fs::create_dir_all(format!("addons/{addon}")).unwrap();
+        std::fs::write(
+            format!("addons/{addon}/$PBOPREFIX$"),
+            format!("z\\ad\\addons\\{addon}"),
+        )
+        .unwrap();
+    }
+    std::fs::write(
+        "addons/main/addon.toml",
+        "[lints.config]\ninvalid_value = \"Warning\"\n",
+    )
+    .unwrap();
+    let ctx = Context::new(Some("check"), PreservePrevious::Remove, None, false).unwrap();
+    let main = ctx.addon("main").unwrap();
+    let other = ctx.addon("other").unwrap();
+    let severity = |config: &hemtt_common::config::ProjectConfig| {
+        config
+            .lints()
+            .config()
+            .get("invalid_value")
+            .unwrap()
+            .severity()
+    };
+    assert_eq!(
+        severity(ctx.addon_config(main)),
+        Some(hemtt_workspace::reporting::Severity::Warning)
+    );
+    assert_eq!(
+        severity(ctx.addon_config(other)),
+        Some(hemtt_workspace::reporting::Severity::Error)
+    );
+    // the config of an addon is created once, not for each file
+    assert!(std::ptr::eq(ctx.addon_config(main), ctx.addon_config(main)));
+    assert!(std::ptr::eq(ctx.addon_config(other), ctx.config()));
+    let filtered = ctx.clone().filter(|addon, _| addon.name() == "main");
+    assert!(std::ptr::eq(
+        filtered.addon_config(main),
+        ctx.addon_config(main)
+    ));
+}
+
+#[sealed_test]
+fn dev_changed() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Changed\"\nprefix = \"ch\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::write(".gitignore", ".hemttout\n").unwrap();
+    for addon in ["main", "other"] {
+        std::fs::create_dir_all(format!("addons/{addon}")).unwrap();
+        std::fs::write(
+            format!("addons/{addon}/$PBOPREFIX$"),
+            format!("z\\ch\\addons\\{addon}"),
+        )
+        .unwrap();
+        std::fs::write(
+            format!("addons/{addon}/config.cpp"),
+            format!("class CfgPatches {{\n    class ch_{addon} {{\n        units[] = {{}};\n        weapons[] = {{}};\n        requiredVersion = 2.14;\n        requiredAddons[] = {{}};\n    }};\n}};\n"),
+        )
+        .unwrap();
+    }
+    let repo = git2::Repository::init(".").unwrap();
+    let signature = git2::Signature::now("HEMTT", "hemtt@example.com").unwrap();
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    repo.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+        .unwrap();
+
+    hemtt::execute(&Cli::parse_from(vec!["hemtt", "dev", "--in-test"])).unwrap();
+    std::fs::write(".hemttout/dev/addons/ch_main.pbo", "unchanged").unwrap();
+    std::fs::write(".hemttout/dev/addons/ch_other.pbo", "unchanged").unwrap();
+    std::fs::write("addons/other/notes.txt", "changed").unwrap();
+
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "dev",
+        "--in-test",
+        "--changed",
+    ]))
+    .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(".hemttout/dev/addons/ch_main.pbo").unwrap(),
+        "unchanged"
+    );
+    assert_ne!(
+        std::fs::read(".hemttout/dev/addons/ch_other.pbo").unwrap(),
+        b"unchanged"
+    );
+
+    // committed changes are compared with the last build, not with the last commit
+    std::fs::write(".hemttout/dev/addons/ch_other.pbo", "unchanged").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "class CfgPatches {\n    class ch_main {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.16;\n        requiredAddons[] = {};\n    };\n};\n",
+    )
+    .unwrap();
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let parent = repo.head().unwrap().peel_to_commit().unwrap();
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        "main",
+        &tree,
+        &[&parent],
+    )
+    .unwrap();
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "dev",
+        "--in-test",
+        "--changed",
+    ]))
+    .unwrap();
+    assert_ne!(
+        std::fs::read(".hemttout/dev/addons/ch_main.pbo").unwrap(),
+        b"unchanged"
+    );
+    assert_eq!(
+        std::fs::read_to_string(".hemttout/dev/addons/ch_other.pbo").unwrap(),
+        "unchanged"
+    );
+}
+
+#[sealed_test]
+fn dev_changed_include_folders() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Changed\"\nprefix = \"ch\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[hemtt.preprocessor]\ninclude_paths = [\"common\"]\n\n[hemtt.preprocessor.mounts]\n'\\x\\cba' = \"cba\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\ch\\addons\\main").unwrap();
+    std::fs::create_dir_all("common").unwrap();
+    std::fs::write("common/script_macros.hpp", "#define VALUE 1\n").unwrap();
+    std::fs::create_dir_all("cba").unwrap();
+    std::fs::write("cba/script_macros.hpp", "#define VALUE 1\n").unwrap();
+
+    let fingerprint = || {
+        let ctx = Context::new(Some("dev"), PreservePrevious::Remove, None, false).unwrap();
+        hemtt::modules::changes::fingerprints(&ctx, ctx.addons()).unwrap()["addons/main"].clone()
+    };
+    let first = fingerprint();
+    assert_eq!(fingerprint(), first);
+    // headers outside of the project are used by every addon
+    std::fs::write("common/script_macros.hpp", "#define VALUE 22\n").unwrap();
+    let second = fingerprint();
+    assert_ne!(second, first);
+    std::fs::write("cba/script_macros.hpp", "#define VALUE 22\n").unwrap();
+    assert_ne!(fingerprint(), second);
+}
+
+#[sealed_test]
+fn check_staged() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Staged\"\nprefix = \"st\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::write(".gitignore", ".hemttout\n").unwrap();
+    for addon in ["main", "other", "third"] {
+        std::fs::create_dir_all(format!("addons/{addon}")).unwrap();
+        std::fs::write(
+            format!("addons/{addon}/$PBOPREFIX$"),
+            format!("z\\st\\addons\\{addon}"),
+        )
+        .unwrap();
+        std::fs::write(
+            format!("addons/{addon}/config.cpp"),
+            format!("#include \"script_component.hpp\"\nclass CfgPatches {{\n    class st_{addon} {{\n        units[] = {{}};\n        weapons[] = {{}};\n        requiredVersion = 2.14;\n        requiredAddons[] = {{}};\n    }};\n}};\n"),
+        )
+        .unwrap();
+    }
+    std::fs::write("addons/main/script_component.hpp", "#define VALUE 1\n").unwrap();
+    std::fs::write(
+        "addons/other/script_component.hpp",
+        "#include \"\\z\\st\\addons\\main\\script_component.hpp\"\n",
+    )
+    .unwrap();
+    std::fs::write("addons/third/script_component.hpp", "#define VALUE 3\n").unwrap();
+    let repo = git2::Repository::init(".").unwrap();
+    let signature = git2::Signature::now("HEMTT", "hemtt@example.com").unwrap();
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    repo.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+        .unwrap();
+
+    let staged = || {
+        let ctx = Context::new(Some("check"), PreservePrevious::Remove, None, false).unwrap();
+        let mut staged = check::staged_addons(&ctx).unwrap().unwrap();
+        staged.sort();
+        staged
+    };
+    assert!(staged().is_empty());
+
+    // changes that are not staged are not checked
+    std::fs::write("addons/third/script_component.hpp", "#define VALUE 4\n").unwrap();
+    std::fs::write("addons/main/script_component.hpp", "#define VALUE 2\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index
+        .add_path(std::path::Path::new("addons/main/script_component.hpp"))
+        .unwrap();
+    index.write().unwrap();
+    // other includes the header of main
+    assert_eq!(staged(), vec!["addons/main", "addons/other"]);
+
+    // the staged version of a file is checked, and files that are not tracked are hidden
+    std::fs::write("addons/main/script_component.hpp", "class {\n").unwrap();
+    std::fs::write("addons/main/broken.hpp", "class {\n").unwrap();
+    let ctx = Context::new(Some("check"), PreservePrevious::Remove, None, false).unwrap();
+    check::staged_addons(&ctx).unwrap();
+    assert_eq!(
+        ctx.workspace_path()
+            .join("addons/main/script_component.hpp")
+            .unwrap()
+            .read_to_string()
+            .unwrap(),
+        "#define VALUE 2\n"
+    );
+    assert!(!ctx
+        .workspace_path()
+        .join("addons/main/broken.hpp")
+        .unwrap()
+        .exists()
+        .unwrap());
+
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "check",
+        "--in-test",
+        "--staged",
+    ]))
+    .unwrap();
+}
+
+#[sealed_test]
+fn check_baseline() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Baseline\"\nprefix = \"bl\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    let addon = |name: &str| {
+        std::fs::create_dir_all(format!("addons/{name}")).unwrap();
+        std::fs::write(
+            format!("addons/{name}/$PBOPREFIX$"),
+            format!("z\\bl\\addons\\{name}"),
+        )
+        .unwrap();
+        // no CfgPatches, reported by L-C12MC
+        std::fs::write(
+            format!("addons/{name}/config.cpp"),
+            "class MyMod {\n    value = 1;\n};\n",
+        )
+        .unwrap();
+    };
+    let lints = |args: &[&str]| {
+        let report = check::execute(&check::Command::parse_from(
+            std::iter::once("check").chain(args.iter().copied()),
+        ))
+        .unwrap();
+        let mut lints = report
+            .codes()
+            .iter()
+            .filter(|code| code.ident().starts_with("L-"))
+            .map(|code| {
+                (
+                    code.ident().to_string(),
+                    code.diagnostic().unwrap().labels[0]
+                        .file()
+                        .as_str()
+                        .to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        lints.sort();
+        lints
+    };
+    addon("main");
+    assert_eq!(
+        lints(&[]),
+        vec![(
+            String::from("L-C12MC"),
+            String::from("/addons/main/config.cpp")
+        )]
+    );
+
+    // the findings are recorded, and not reported
+    assert!(lints(&["--write-baseline", ".hemtt/lint-baseline.toml"]).is_empty());
+    let baseline = std::fs::read_to_string(".hemtt/lint-baseline.toml").unwrap();
+    assert!(baseline.contains("[[finding]]"));
+    assert!(baseline.contains("code = \"L-C12MC\""));
+    assert!(baseline.contains("count = 1"));
+
+    // the default baseline is used when it exists
+    assert!(lints(&[]).is_empty());
+
+    // new findings are still reported
+    addon("other");
+    assert_eq!(
+        lints(&[]),
+        vec![(
+            String::from("L-C12MC"),
+            String::from("/addons/other/config.cpp")
+        )]
+    );
+
+    // an explicit baseline replaces the default one
+    std::fs::write(".hemtt/empty.toml", "").unwrap();
+    assert_eq!(lints(&["--baseline", ".hemtt/empty.toml"]).len(), 2);
+    std::fs::write(".hemtt/invalid.toml", "finding = 1").unwrap();
+    assert!(check::execute(&check::Command::parse_from(vec![
+        "check",
+        "--baseline",
+        ".hemtt/invalid.toml"
+    ]))
+    .is_err());
+}
+
+#[sealed_test]
+fn build_missions() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Missions\"\nprefix = \"mis\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\mis\\addons\\main").unwrap();
+    for (mission, wanted) in [("binarized.VR", 1), ("text.VR", 0)] {
+        std::fs::create_dir_all(format!("addons/main/missions/{mission}")).unwrap();
+        std::fs::write(
+            format!("addons/main/missions/{mission}/mission.sqm"),
+            format!("version=54;\nbinarizationWanted={wanted};\nclass Mission\n{{\n\tclass Intel\n\t{{\n\t\tbriefingName=\"{mission}\";\n\t}};\n}};\n"),
+        )
+        .unwrap();
+    }
+
+    hemtt::execute(&Cli::parse_from(vec!["hemtt", "build", "--in-test"])).unwrap();
+    let mut pbo = hemtt_pbo::ReadablePbo::from(
+        std::fs::File::open(".hemttout/build/addons/mis_main.pbo").unwrap(),
+    )
+    .unwrap();
+    let mut read = |name: &str| {
+        let mut data = Vec::new();
+        pbo.file(name)
+            .unwrap()
+            .unwrap()
+            .read_to_end(&mut data)
+            .unwrap();
+        data
+    };
+    assert!(hemtt_config::rapify::is_rapified(&read(
+        "missions\\binarized.VR\\mission.sqm"
+    )));
+    assert!(read("missions\\text.VR\\mission.sqm").starts_with(b"version=54;"));
+}
+
+#[cfg(target_os = "linux")]
+#[sealed_test]
+fn build_duplicate_file() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Duplicate\"\nprefix = \"dup\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\dup\\addons\\main").unwrap();
+    std::fs::write("addons/main/readme.txt", "lower").unwrap();
+    std::fs::write("addons/main/README.txt", "upper").unwrap();
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    let report = hemtt::modules::pbo::build(&ctx, Collapse::No).unwrap();
+    let errors = report.errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].ident(), "BPE1");
+}
+
+#[sealed_test]
+fn build_conflicting_prefix() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Prefix\"\nprefix = \"pre\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/pboprefix.txt", "z\\other\\addons\\main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\pre\\addons\\main").unwrap();
+    std::fs::write("addons/main/readme.txt", "hello").unwrap();
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    assert_eq!(
+        ctx.addon("main").unwrap().prefix().to_string(),
+        "z\\pre\\addons\\main"
+    );
+    let report = hemtt::modules::pbo::build(&ctx, Collapse::No).unwrap();
+    let warnings = report.warnings(hemtt::report::WithIncludes::Yes);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].ident(), "BPW1");
+    let pbo = hemtt_pbo::ReadablePbo::from(
+        std::fs::File::open(".hemttout/build/addons/pre_main.pbo").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        pbo.properties().get("prefix").unwrap(),
+        "z\\pre\\addons\\main"
+    );
+    assert!(pbo.header("pboprefix.txt").is_none());
+}
+
+#[sealed_test]
+fn build_model_cfg_invalid() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Model\"\nprefix = \"model\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\model\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/model.cfg",
+        "class CfgSkeletons {\n    class Skeleton {\n",
+    )
+    .unwrap();
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    let report = Rapifier.pre_build(&ctx).unwrap();
+    assert!(report.errors().is_empty());
+    assert!(!report.warnings(hemtt::report::WithIncludes::Yes).is_empty());
+}
+
+#[sealed_test]
+fn build_compression() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Compress\"\nprefix = \"cmp\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[hemtt.build.compression]\nenabled = true\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\cmp\\addons\\main").unwrap();
+    let text = "params [\"_unit\"];\n_unit setDamage 0;\n".repeat(100);
+    std::fs::write("addons/main/fnc_heal.sqf", &text).unwrap();
+    std::fs::write("addons/main/texture_co.paa", &text).unwrap();
+    std::fs::write("addons/main/small.sqf", "true").unwrap();
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    let report = hemtt::modules::pbo::build(&ctx, Collapse::No).unwrap();
+    assert!(!report.failed());
+    let mut pbo = hemtt_pbo::ReadablePbo::from(
+        std::fs::File::open(".hemttout/build/addons/cmp_main.pbo").unwrap(),
+    )
+    .unwrap();
+    let sqf = pbo.header("fnc_heal.sqf").unwrap().clone();
+    assert_eq!(sqf.mime(), &hemtt_pbo::Mime::Cprs);
+    assert_eq!(sqf.original() as usize, text.len());
+    assert!((sqf.size() as usize) < text.len());
+    let mut data = Vec::new();
+    std::io::Read::read_to_end(&mut pbo.file("fnc_heal.sqf").unwrap().unwrap(), &mut data).unwrap();
+    assert_eq!(
+        hemtt_pbo::lzss::decompress(&data, sqf.original() as usize).unwrap(),
+        text.as_bytes()
+    );
+    let paa = pbo.header("texture_co.paa").unwrap();
+    assert_eq!(paa.mime(), &hemtt_pbo::Mime::Blank);
+    assert_eq!(paa.size() as usize, text.len());
+    let small = pbo.header("small.sqf").unwrap();
+    assert_eq!(small.mime(), &hemtt_pbo::Mime::Blank);
+}
+
+#[sealed_test]
+fn build_release_transform() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Release\"\nprefix = \"rel\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[hemtt.release]\nminify = true\nexclude = [\"*.hpp\"]\n\n[hemtt.release.rename]\n\"functions/fnc_secret.sqf\" = \"functions/a.sqf\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main/functions").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\rel\\addons\\main").unwrap();
+    std::fs::write("addons/main/script_component.hpp", "#define DEBUG").unwrap();
+    std::fs::write(
+        "addons/main/functions/fnc_heal.sqf",
+        "// heal the unit\nparams [\"_unit\"];\n\n/* full\n   health */\n    _unit setDamage 0; // done\nhint \"http://a  b\";\n",
+    )
+    .unwrap();
+    std::fs::write("addons/main/functions/fnc_secret.sqf", "true").unwrap();
+    // the exclude does not match files in subfolders
+    std::fs::write(
+        "addons/main/functions/script_component.hpp",
+        "#define DEBUG",
+    )
+    .unwrap();
+
+    let read = |pbo: &mut hemtt_pbo::ReadablePbo<std::fs::File>, name: &str| {
+        let mut data = String::new();
+        pbo.file(name)
+            .unwrap()
+            .unwrap()
+            .read_to_string(&mut data)
+            .unwrap();
+        data
+    };
+
+    let ctx = Context::new(Some("release"), PreservePrevious::Remove, None, false).unwrap();
+    let report = hemtt::modules::pbo::build(&ctx, Collapse::No).unwrap();
+    assert!(!report.failed());
+    let mut pbo = hemtt_pbo::ReadablePbo::from(
+        std::fs::File::open(".hemttout/release/addons/rel_main.pbo").unwrap(),
+    )
+    .unwrap();
+    assert!(pbo.header("script_component.hpp").is_none());
+    assert!(pbo.header("functions\\script_component.hpp").is_some());
+    assert!(pbo.header("functions\\fnc_secret.sqf").is_none());
+    assert!(pbo.header("functions\\a.sqf").is_some());
+    assert_eq!(
+        read(&mut pbo, "functions\\fnc_heal.sqf"),
+        "\nparams [\"_unit\"];\n\n\n\n_unit setDamage 0;\nhint \"http://a  b\";\n"
+    );
+
+    // the options only apply to releases
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    hemtt::modules::pbo::build(&ctx, Collapse::No).unwrap();
+    let mut pbo = hemtt_pbo::ReadablePbo::from(
+        std::fs::File::open(".hemttout/build/addons/rel_main.pbo").unwrap(),
+    )
+    .unwrap();
+    assert!(pbo.header("script_component.hpp").is_some());
+    assert!(pbo.header("functions\\fnc_secret.sqf").is_some());
+    assert!(read(&mut pbo, "functions\\fnc_heal.sqf").starts_with("// heal the unit"));
+}
+
+#[sealed_test]
+fn build_compression_minified() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Compress\"\nprefix = \"cmp\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[hemtt.build.compression]\nenabled = true\nmin_size = 512\n\n[hemtt.release]\nminify = true\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\cmp\\addons\\main").unwrap();
+    // larger than the minimum size, until the comments are removed
+    let code = "_unit setDamage 0;\n".repeat(10);
+    std::fs::write(
+        "addons/main/fnc_heal.sqf",
+        format!("/* {} */\n{code}", "comment ".repeat(100)),
+    )
+    .unwrap();
+    std::fs::write("addons/main/fnc_full.sqf", code.repeat(10)).unwrap();
+    let ctx = Context::new(Some("release"), PreservePrevious::Remove, None, false).unwrap();
+    let report = hemtt::modules::pbo::build(&ctx, Collapse::No).unwrap();
+    assert!(!report.failed());
+    let pbo = hemtt_pbo::ReadablePbo::from(
+        std::fs::File::open(".hemttout/release/addons/cmp_main.pbo").unwrap(),
+    )
+    .unwrap();
+    let heal = pbo.header("fnc_heal.sqf").unwrap();
+    assert_eq!(heal.mime(), &hemtt_pbo::Mime::Blank);
+    assert!((heal.size() as usize) < 512);
+    assert_eq!(
+        pbo.header("fnc_full.sqf").unwrap().mime(),
+        &hemtt_pbo::Mime::Cprs
+    );
+}
+
+#[sealed_test]
+fn build_deterministic() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Same\"\nprefix = \"same\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[properties]\nauthor = \"me\"\nurl = \"https://example.com\"\nbranch = \"main\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main/functions").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\same\\addons\\main").unwrap();
+    for name in ["fnc_b", "fnc_a", "fnc_c"] {
+        std::fs::write(format!("addons/main/functions/{name}.sqf"), name).unwrap();
+    }
+
+    let build = || {
+        let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false)
+            .unwrap()
+            .with_deterministic(true);
+        hemtt::modules::pbo::build(&ctx, Collapse::No).unwrap();
+        std::fs::read(".hemttout/build/addons/same_main.pbo").unwrap()
+    };
+    let first = build();
+    assert_eq!(first, build());
+    let pbo = hemtt_pbo::ReadablePbo::from(std::io::Cursor::new(first)).unwrap();
+    assert_eq!(
+        pbo.properties().keys().collect::<Vec<_>>(),
+        ["prefix", "author", "branch", "hemtt", "url", "version"]
+    );
+    assert!(pbo.files().iter().all(|header| header.timestamp() == 0));
+}
+
+#[sealed_test]
+fn build_workspace() {
+    workspace("", "requiredVersion = 2.10; ");
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "build",
+        "--workspace",
+        "--in-test",
+    ]))
+    .unwrap();
+    assert!(std::path::Path::new("mod/.hemttout/build/addons/mod_main.pbo").exists());
+    assert!(std::path::Path::new("maps/.hemttout/build/addons/maps_map.pbo").exists());
+    // both projects use the cache of the workspace
+    assert_eq!(
+        std::fs::read_dir(".hemttout/cache/rapify")
+            .unwrap()
+            .filter(|entry| entry
+                .as_ref()
+                .unwrap()
+                .path()
+                .extension()
+                .is_some_and(|ext| ext == "bin"))
+            .count(),
+        2
+    );
+    assert!(!std::path::Path::new("mod/.hemttout/cache").exists());
+}
+
+#[sealed_test]
+fn build_fail_on_warnings() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Strict\"\nprefix = \"strict\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[hemtt.check]\nfail_on = \"warnings\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\strict\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "class CfgPatches { class strict_main { units[] = {}; weapons[] = {}; requiredAddons[] = {}; }; };\n",
+    )
+    .unwrap();
+    let report =
+        hemtt::commands::build::execute(&hemtt::commands::build::Command::parse_from(vec![
+            "build",
+            "--in-test",
+        ]))
+        .unwrap();
+    assert!(!report.failed());
+    assert!(report.failed_policy());
+    // the build stops before the PBOs are written
+    assert!(!std::path::Path::new(".hemttout/build/addons/strict_main.pbo").exists());
+}
+
+#[sealed_test]
+fn build_workspace_fail_on_warnings() {
+    // `requiredVersion` is missing, which is a warning
+    workspace("\n[hemtt.check]\nfail_on = \"warnings\"\n", "");
+    let report =
+        hemtt::commands::build::execute(&hemtt::commands::build::Command::parse_from(vec![
+            "build",
+            "--workspace",
+            "--in-test",
+        ]))
+        .unwrap();
+    assert!(report.failed_policy());
+    // the project depending on the failed one is not built
+    assert!(!std::path::Path::new("maps/.hemttout/build").exists());
+}
+
+#[sealed_test]
+fn build_workspace_timings() {
+    workspace("", "requiredVersion = 2.10; ");
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "build",
+        "--workspace",
+        "--in-test",
+        "--timings",
+    ]))
+    .unwrap();
+    // each project only writes the spans recorded while it was built
+    for (folder, own, other) in [("mod", "main", "map"), ("maps", "map", "main")] {
+        let trace: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(format!("{folder}/.hemttout/timings.json")).unwrap(),
+        )
+        .unwrap();
+        let pbos = trace["traceEvents"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|event| event["cat"] == "pbo")
+            .map(|event| event["name"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(pbos, [own], "`{other}` was built by the other project");
+    }
+}
+
+/// A workspace of two projects, `maps` depends on `mod`
+fn workspace(project: &str, patches: &str) {
+    std::fs::write(
+        "hemtt-workspace.toml",
+        "[projects.maps]\npath = \"maps\"\ndepends = [\"main\"]\n\n[projects.main]\npath = \"mod\"\n",
+    )
+    .unwrap();
+    for (folder, prefix, addon) in [("mod", "mod", "main"), ("maps", "maps", "map")] {
+        std::fs::create_dir_all(format!("{folder}/.hemtt")).unwrap();
+        std::fs::write(
+            format!("{folder}/.hemtt/project.toml"),
+            format!("name = \"{prefix}\"\nprefix = \"{prefix}\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n{project}"),
+        )
+        .unwrap();
+        std::fs::create_dir_all(format!("{folder}/addons/{addon}")).unwrap();
+        std::fs::write(
+            format!("{folder}/addons/{addon}/$PBOPREFIX$"),
+            format!("z\\{prefix}\\addons\\{addon}"),
+        )
+        .unwrap();
+        std::fs::write(
+            format!("{folder}/addons/{addon}/config.cpp"),
+            format!("class CfgPatches {{ class {prefix}_{addon} {{ units[] = {{}}; weapons[] = {{}}; {patches}requiredAddons[] = {{}}; }}; }};\n"),
+        )
+        .unwrap();
+    }
+}
+
+#[sealed_test]
+fn build_dependencies() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Deps\"\nprefix = \"deps\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    for (addon, required) in [
+        ("main", "\"deps_other\""),
+        ("other", "\"deps_main\", \"deps_missing\", \"cba_main\""),
+        ("extra", "\"deps_main\""),
+    ] {
+        std::fs::create_dir_all(format!("addons/{addon}")).unwrap();
+        std::fs::write(
+            format!("addons/{addon}/$PBOPREFIX$"),
+            format!("z\\deps\\addons\\{addon}"),
+        )
+        .unwrap();
+        std::fs::write(
+            format!("addons/{addon}/config.cpp"),
+            format!("class CfgPatches {{\n    class deps_{addon} {{\n        units[] = {{}};\n        weapons[] = {{}};\n        requiredVersion = 2.14;\n        requiredAddons[] = {{{required}}};\n    }};\n}};\n"),
+        )
+        .unwrap();
+    }
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    Rapifier.pre_build(&ctx).unwrap();
+    let report = Dependencies.pre_build(&ctx).unwrap();
+    let errors = report.errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].ident(), "BDE1");
+    assert_eq!(
+        errors[0].message(),
+        "Addons `main`, `other` require each other"
+    );
+    let warnings = report.warnings(hemtt::report::WithIncludes::Yes);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].ident(), "BDW1");
+    assert!(warnings[0].message().contains("`deps_missing`"));
+
+    let graph = hemtt::modules::deps::Graph::from_addons(ctx.addons());
+    assert_eq!(
+        graph.dot(false),
+        "digraph dependencies {\n    \"extra\";\n    \"main\";\n    \"other\";\n    \"extra\" -> \"main\";\n    \"main\" -> \"other\";\n    \"other\" -> \"main\";\n}\n"
+    );
+    assert!(graph
+        .dot(true)
+        .contains("    \"other\" -> \"cba_main\" [style=dashed];\n"));
+}
+
+#[sealed_test]
+fn build_dependencies_declared() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Deps\"\nprefix = \"deps\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[dependencies.cba]\npatches = [\"cba_main\", \"cba_xeh\"]\n\n[dependencies.ace]\npatches = [\"ace_*\"]\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\deps\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "class CfgPatches {\n    class deps_main {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {\"A3_Data_F\", \"CBA_Main\", \"ace_common\", \"cba_mian\"};\n    };\n};\n",
+    )
+    .unwrap();
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    Rapifier.pre_build(&ctx).unwrap();
+    let report = Dependencies.pre_build(&ctx).unwrap();
+    assert!(report.errors().is_empty());
+    let warnings = report.warnings(hemtt::report::WithIncludes::Yes);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].ident(), "BDW2");
+    assert!(warnings[0].message().contains("`cba_mian`"));
+    assert_eq!(warnings[0].help().unwrap(), "did you mean `cba_main`?");
+}
+
+#[sealed_test]
+fn build_dependencies_unknown() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Deps\"\nprefix = \"deps\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[dependencies.extras]\npatches = [\"deps_extras_*\"]\n",
+    )
+    .unwrap();
+    for (addon, required) in [
+        ("main", "\"deps_extras_main\""),
+        ("other", "\"deps_main\", \"deps_unrapified\""),
+        ("unrapified", ""),
+    ] {
+        std::fs::create_dir_all(format!("addons/{addon}")).unwrap();
+        std::fs::write(
+            format!("addons/{addon}/$PBOPREFIX$"),
+            format!("z\\deps\\addons\\{addon}"),
+        )
+        .unwrap();
+        std::fs::write(
+            format!("addons/{addon}/config.cpp"),
+            format!("class CfgPatches {{\n    class deps_{addon} {{\n        units[] = {{}};\n        weapons[] = {{}};\n        requiredVersion = 2.14;\n        requiredAddons[] = {{{required}}};\n    }};\n}};\n"),
+        )
+        .unwrap();
+    }
+    // the patches of an addon that is not rapified are unknown
+    std::fs::write(
+        "addons/unrapified/addon.toml",
+        "[rapify]\nenabled = false\n",
+    )
+    .unwrap();
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    Rapifier.pre_build(&ctx).unwrap();
+    let report = Dependencies.pre_build(&ctx).unwrap();
+    assert!(report.errors().is_empty());
+    assert!(report.warnings(hemtt::report::WithIncludes::Yes).is_empty());
+
+    // with every addon known, only the patch from `[dependencies]` is allowed
+    let graph = hemtt::modules::deps::Graph::new(
+        [
+            (
+                "main".to_string(),
+                vec![(
+                    "deps_main".to_string(),
+                    vec![
+                        "deps_extras_main".to_string(),
+                        "DEPS_OTHER".to_string(),
+                        "deps_gone".to_string(),
+                    ],
+                )],
+            ),
+            (
+                "other".to_string(),
+                vec![("deps_other".to_string(), vec![])],
+            ),
+        ]
+        .into_iter()
+        .collect(),
+    );
+    assert_eq!(
+        graph.missing("deps", ctx.config().dependencies()),
+        vec![("main", "deps_main", "deps_gone")]
+    );
+}
+
+#[test]
+fn dependencies_dot_escaped() {
+    let graph = hemtt::modules::deps::Graph::new(
+        [
+            (
+                "main".to_string(),
+                vec![(
+                    "abe_main".to_string(),
+                    vec!["abe_\"quoted\"".to_string(), "cba\\main".to_string()],
+                )],
+            ),
+            (
+                "\"quoted\"".to_string(),
+                vec![("abe_\"quoted\"".to_string(), vec![])],
+            ),
+        ]
+        .into_iter()
+        .collect(),
+    );
+    assert_eq!(
+        graph.dot(true),
+        "digraph dependencies {\n    \"\\\"quoted\\\"\";\n    \"main\";\n    \"main\" -> \"\\\"quoted\\\"\";\n    \"cba\\\\main\" [style=dashed];\n    \"main\" -> \"cba\\\\main\" [style=dashed];\n}\n"
+    );
+}
+
+#[sealed_test]
+fn build_version_defines() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Version\"\nprefix = \"ver\"\n\n[version]\nmajor = 1\nminor = 2\npatch = 3\nbuild = 4\ngit_hash = 0\ndefines = true\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\ver\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "class CfgVersion {\n    version[] = {VERSION_MAJOR, VERSION_MINOR, VERSION_PATCH, VERSION_BUILD};\n    text = VERSION_STR;\n};\n",
+    )
+    .unwrap();
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    let processed = hemtt_preprocessor::Processor::run(
+        &ctx.workspace_path().join("addons/main/config.cpp").unwrap(),
+    )
+    .unwrap();
+    assert!(processed.as_str().contains("version[] = {1, 2, 3, 4};"));
+    assert!(processed.as_str().contains("text = 1.2.3.4;"));
+}
+
+#[sealed_test]
+fn build_version_git() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Version\"\nprefix = \"ver\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\ngit = \"describe\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\ver\\addons\\main").unwrap();
+
+    let version = || {
+        let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+        let version = ctx
+            .config()
+            .version()
+            .get(ctx.workspace_path().vfs())
+            .unwrap();
+        ctx.config().version().invalidate();
+        version.to_string()
+    };
+
+    // falls back to the version in the config outside of a git repository
+    assert_eq!(version(), "1.0.0");
+
+    let repo = git2::Repository::init(".").unwrap();
+    let signature = git2::Signature::now("HEMTT", "hemtt@example.com").unwrap();
+    let commit = |message: &str| {
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let parents = repo
+            .head()
+            .ok()
+            .map(|head| head.peel_to_commit().unwrap())
+            .into_iter()
+            .collect::<Vec<_>>();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            &parents.iter().collect::<Vec<_>>(),
+        )
+        .unwrap()
+    };
+
+    // falls back to the version in the config without a tag
+    let first = commit("first");
+    assert_eq!(version(), "1.0.0");
+
+    repo.tag_lightweight("v2.3.4", &repo.find_object(first, None).unwrap(), false)
+        .unwrap();
+    assert_eq!(version(), "2.3.4.0");
+
+    std::fs::write("addons/main/readme.txt", "hello").unwrap();
+    let second = commit("second");
+    assert_eq!(version(), "2.3.4.1");
+
+    // falls back to the version in the config when the nearest tag is not a version
+    repo.tag_lightweight("nightly", &repo.find_object(second, None).unwrap(), false)
+        .unwrap();
+    assert_eq!(version(), "1.0.0");
+}
+
+#[sealed_test]
+fn build_rapify_cache() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Cache\"\nprefix = \"cache\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\cache\\addons\\main").unwrap();
+    std::fs::write("addons/main/script_macros.hpp", "#define VALUE 1\n").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "#include \"script_macros.hpp\"\nclass CfgPatches {\n    class cache_main {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {};\n    };\n};\nclass CfgCache {\n    value = VALUE;\n};\n",
+    )
+    .unwrap();
+
+    let first = rapify_with_cache();
+    let cached = cached_outputs();
+    assert_eq!(cached.len(), 1);
+    assert_eq!(std::fs::read(&cached[0]).unwrap(), first);
+
+    // unchanged sources use the cached output
+    std::fs::write(&cached[0], cached_marker()).unwrap();
+    assert_eq!(rapify_with_cache(), cached_marker());
+
+    // the cached config is read back for other modules
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false)
+        .unwrap()
+        .with_cache(true);
+    Rapifier.pre_build(&ctx).unwrap();
+    let state = ctx.state();
+    let configs = state.get::<AddonConfigs>();
+    let names = configs
+        .read()
+        .unwrap()
+        .values()
+        .map(|config| config.0[0].name().as_str().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Cached"]);
+
+    // changing an include invalidates the entry
+    std::fs::write("addons/main/script_macros.hpp", "#define VALUE 2\n").unwrap();
+    let changed = rapify_with_cache();
+    assert_ne!(changed, cached_marker());
+    assert_ne!(changed, first);
+    assert_eq!(std::fs::read(&cached[0]).unwrap(), changed);
+}
+
+#[sealed_test]
+fn build_paa() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Paa\"\nprefix = \"paa\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main/data").unwrap();
+    std::fs::create_dir_all("addons/main/source").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\paa\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/addon.toml",
+        "[paa]\nenabled = true\nexclude = [\"source/*\"]\n",
+    )
+    .unwrap();
+    let image = image::RgbaImage::from_pixel(16, 16, image::Rgba([0, 128, 0, 255]));
+    image.save("addons/main/data/logo_co.png").unwrap();
+    image.save("addons/main/source/logo.png").unwrap();
+    image::RgbaImage::new(12, 16)
+        .save("addons/main/data/odd_co.png")
+        .unwrap();
+
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    let report = PaaConverter.pre_build(&ctx).unwrap();
+    let errors = report.errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].ident(), "PAA-CONVERT-FAILED");
+
+    let exists = |path: &str| ctx.workspace_path().join(path).unwrap().exists().unwrap();
+    assert!(exists("addons/main/data/logo_co.paa"));
+    assert!(!exists("addons/main/data/logo_co.png"));
+    assert!(exists("addons/main/source/logo.png"));
+    assert!(!exists("addons/main/source/logo.paa"));
+    assert!(exists("addons/main/data/odd_co.png"));
+    let mut paa = Vec::new();
+    ctx.workspace_path()
+        .join("addons/main/data/logo_co.paa")
+        .unwrap()
+        .open_file()
+        .unwrap()
+        .read_to_end(&mut paa)
+        .unwrap();
+    let paa = hemtt_paa::Paa::read(std::io::Cursor::new(paa)).unwrap();
+    assert_eq!(paa.format(), &hemtt_paa::PaXType::DXT1);
+    assert_eq!(paa.maps().len(), 3);
+    assert!(std::path::Path::new("addons/main/data/logo_co.png").exists());
+}
+
+#[sealed_test]
+fn build_audio() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Audio\"\nprefix = \"audio\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main/sounds/radio").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\audio\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/addon.toml",
+        "[audio]\nenabled = true\nbitrate = 64\nwss = [\"sounds/radio/*\"]\n",
+    )
+    .unwrap();
+    for path in [
+        "addons/main/sounds/shot.wav",
+        "addons/main/sounds/radio/beep.wav",
+    ] {
+        let mut writer = hound::WavWriter::create(
+            path,
+            hound::WavSpec {
+                channels: 1,
+                sample_rate: 22050,
+                bits_per_sample: 16,
+                sample_format: hound::SampleFormat::Int,
+            },
+        )
+        .unwrap();
+        for i in 0..22050i16 {
+            writer.write_sample((i % 100) * 100).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+    std::fs::write("addons/main/sounds/broken.wav", "not a wav").unwrap();
+
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    let report = AudioConverter.pre_build(&ctx).unwrap();
+    let errors = report.errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].ident(), "AUDIO-CONVERT-FAILED");
+
+    let exists = |path: &str| ctx.workspace_path().join(path).unwrap().exists().unwrap();
+    assert!(exists("addons/main/sounds/shot.ogg"));
+    assert!(!exists("addons/main/sounds/shot.wav"));
+    assert!(exists("addons/main/sounds/radio/beep.wss"));
+    assert!(!exists("addons/main/sounds/radio/beep.ogg"));
+    assert!(exists("addons/main/sounds/broken.wav"));
+    let mut wss = Vec::new();
+    ctx.workspace_path()
+        .join("addons/main/sounds/radio/beep.wss")
+        .unwrap()
+        .open_file()
+        .unwrap()
+        .read_to_end(&mut wss)
+        .unwrap();
+    let wss = hemtt_audio::Audio::read_wss(std::io::Cursor::new(wss)).unwrap();
+    assert_eq!(wss.sample_rate(), 22050);
+    assert_eq!(wss.samples().len(), 22050);
+}
+
+#[sealed_test]
+fn build_rapify_cache_mount() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Cache\"\nprefix = \"cache\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[hemtt.preprocessor.mounts]\n'\\x\\ext' = \"ext\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\cache\\addons\\main").unwrap();
+    // shares its path in the project with the mounted file
+    std::fs::write("addons/main/script_macros.hpp", "#define OTHER 1\n").unwrap();
+    std::fs::create_dir_all("ext/addons/main").unwrap();
+    std::fs::write("ext/addons/main/script_macros.hpp", "#define VALUE 1\n").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "#include \"\\x\\ext\\addons\\main\\script_macros.hpp\"\nclass CfgPatches {\n    class cache_main {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {};\n    };\n};\nclass CfgCache {\n    value = VALUE;\n};\n",
+    )
+    .unwrap();
+
+    rapify_with_cache();
+    let cached = cached_outputs();
+    assert_eq!(cached.len(), 1);
+    std::fs::write(&cached[0], cached_marker()).unwrap();
+    assert_eq!(rapify_with_cache(), cached_marker());
+
+    // changing the mounted file invalidates the entry
+    std::fs::write("ext/addons/main/script_macros.hpp", "#define VALUE 2\n").unwrap();
+    assert_ne!(rapify_with_cache(), cached_marker());
+}
+
+#[sealed_test]
+fn build_rapify_cache_has_include() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Cache\"\nprefix = \"cache\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\cache\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "class CfgPatches {\n    class cache_main {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {};\n    };\n};\nclass CfgCache {\n#if __has_include(\"optional.hpp\")\n    value = 2;\n#else\n    value = 1;\n#endif\n};\n",
+    )
+    .unwrap();
+
+    rapify_with_cache();
+    let cached = cached_outputs();
+    assert_eq!(cached.len(), 1);
+    std::fs::write(&cached[0], cached_marker()).unwrap();
+    assert_eq!(rapify_with_cache(), cached_marker());
+
+    // adding a file that was looked for invalidates the entry
+    std::fs::write("addons/main/optional.hpp", "").unwrap();
+    assert_ne!(rapify_with_cache(), cached_marker());
+}
+
+#[sealed_test]
+fn build_rapify_cache_unused_include() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Cache\"\nprefix = \"cache\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\cache\\addons\\main").unwrap();
+    std::fs::write("addons/main/unused.hpp", "#define UNUSED_VALUE 1\n").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "#include \"unused.hpp\"\nclass CfgPatches {\n    class cache_main {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {};\n    };\n};\n",
+    )
+    .unwrap();
+
+    // the unused include does not stop the file from being cached, and is reported from the entry
+    for _ in 0..2 {
+        let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false)
+            .unwrap()
+            .with_cache(true);
+        let report = Rapifier.pre_build(&ctx).unwrap();
+        let helps = report.helps(hemtt::report::WithIncludes::Yes);
+        assert_eq!(helps.len(), 1);
+        assert_eq!(helps[0].ident(), "PW5");
+        assert_eq!(
+            helps[0].diagnostic().unwrap().labels[0].file().as_str(),
+            "/addons/main/config.cpp"
+        );
+    }
+    assert_eq!(cached_outputs().len(), 1);
+}
+
+#[sealed_test]
+fn build_sound_shader_sibling() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Cache\"\nprefix = \"cache\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    for addon in ["main", "sounds"] {
+        std::fs::create_dir_all(format!("addons/{addon}")).unwrap();
+        std::fs::write(
+            format!("addons/{addon}/$PBOPREFIX$"),
+            format!("z\\cache\\addons\\{addon}"),
+        )
+        .unwrap();
+    }
+    let patches = |addon: &str| {
+        format!("class CfgPatches {{\n    class cache_{addon} {{\n        units[] = {{}};\n        weapons[] = {{}};\n        requiredVersion = 2.14;\n        requiredAddons[] = {{}};\n    }};\n}};\n")
+    };
+    std::fs::write(
+        "addons/main/config.cpp",
+        format!(
+            "{}class CfgSoundSets {{\n    class cache_shot_SoundSet {{\n        soundShaders[] = {{\"cache_shot_SoundShader\"}};\n    }};\n}};\n",
+            patches("main")
+        ),
+    )
+    .unwrap();
+    let shader = "class CfgSoundShaders {\n    class cache_shot_SoundShader {\n        samples[] = {};\n        volume = 1;\n        range = 100;\n    };\n};\n";
+    std::fs::write(
+        "addons/sounds/config.cpp",
+        format!("{}{shader}", patches("sounds")),
+    )
+    .unwrap();
+    let sound_shader_missing = || {
+        let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false)
+            .unwrap()
+            .with_cache(true);
+        let report = Rapifier.pre_build(&ctx).unwrap();
+        report
+            .warnings(hemtt::report::WithIncludes::Yes)
+            .iter()
+            .filter(|code| code.ident() == "L-C20")
+            .count()
+    };
+
+    // the shader of another addon is found
+    assert_eq!(sound_shader_missing(), 0);
+    assert_eq!(sound_shader_missing(), 0);
+    assert_eq!(cached_outputs().len(), 2);
+
+    // removing it is reported, even though the sound set is cached
+    std::fs::write("addons/sounds/config.cpp", patches("sounds")).unwrap();
+    assert_eq!(sound_shader_missing(), 1);
+}
+
+#[sealed_test]
+fn build_stringtable_keys() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Cache\"\nprefix = \"cache\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\cache\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/stringtable.xml",
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<Project name=\"Cache\">\n    <Package name=\"Main\">\n        <Key ID=\"STR_cache_main_config\">\n            <Original>Config</Original>\n        </Key>\n        <Key ID=\"STR_cache_main_script\">\n            <Original>Script</Original>\n        </Key>\n        <Key ID=\"STR_cache_main_unused\">\n            <Original>Unused</Original>\n        </Key>\n    </Package>\n</Project>\n",
+    )
+    .unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "class CfgPatches {\n    class cache_main {\n        name = \"$STR_CACHE_MAIN_CONFIG\";\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {};\n    };\n};\nclass CfgCache {\n    displayName = \"$STR_cache_main_missing\";\n};\n",
+    )
+    .unwrap();
+    std::fs::write(
+        "addons/main/script.sqf",
+        "hint localize \"STR_cache_main_script\";\n",
+    )
+    .unwrap();
+    let keys = || {
+        let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false)
+            .unwrap()
+            .with_cache(true);
+        let mut sqf = SQFCompiler::new();
+        sqf.init(&ctx).unwrap();
+        Rapifier.pre_build(&ctx).unwrap();
+        sqf.pre_build(&ctx).unwrap();
+        let report = Stringtables.pre_build(&ctx).unwrap();
+        let mut codes = report
+            .warnings(hemtt::report::WithIncludes::Yes)
+            .into_iter()
+            .chain(report.errors())
+            .filter(|code| ["L-L05", "L-L06"].contains(&code.ident()))
+            .map(|code| (code.ident(), code.message()))
+            .collect::<Vec<_>>();
+        codes.sort();
+        codes
+    };
+
+    // keys are matched case insensitively, from configs and scripts
+    let first = keys();
+    assert_eq!(first.len(), 2);
+    assert_eq!(first[0].0, "L-L05");
+    assert!(first[0].1.contains("STR_cache_main_missing"));
+    assert_eq!(first[1].0, "L-L06");
+    assert!(first[1].1.contains("STR_cache_main_unused"));
+    assert_eq!(cached_outputs().len(), 1);
+
+    // the keys of cached configs are still used
+    assert_eq!(keys(), first);
+}
+
+#[sealed_test]
+fn build_sqf_drop_original() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Compiled\"\nprefix = \"compiled\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[hemtt.build]\nsqf_keep_original = false\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\compiled\\addons\\main").unwrap();
+    std::fs::write("addons/main/script.sqf", "systemChat str time;\n").unwrap();
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false).unwrap();
+    let mut sqf = SQFCompiler::new();
+    sqf.init(&ctx).unwrap();
+    sqf.pre_build(&ctx).unwrap();
+    // the source is not removed, so it can be compiled again by `hemtt watch`
+    assert!(ctx
+        .workspace_path()
+        .join("addons/main/script.sqf")
+        .unwrap()
+        .exists()
+        .unwrap());
+    sqf.pre_build(&ctx).unwrap();
+    hemtt::modules::pbo::build(&ctx, Collapse::No).unwrap();
+    let pbo = hemtt_pbo::ReadablePbo::from(
+        std::fs::File::open(".hemttout/build/addons/compiled_main.pbo").unwrap(),
+    )
+    .unwrap();
+    let files = pbo
+        .files()
+        .iter()
+        .map(|header| header.filename().to_string())
+        .collect::<Vec<_>>();
+    assert!(files.contains(&"script.sqfc".to_string()));
+    assert!(!files.contains(&"script.sqf".to_string()));
+}
+
+#[sealed_test]
+fn build_rapify_cache_file_missing() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Cache\"\nprefix = \"cache\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main/data").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\cache\\addons\\main").unwrap();
+    std::fs::write("addons/main/data/icon_ca.paa", "").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "class CfgPatches {\n    class cache_main {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {};\n    };\n};\nclass CfgCache {\n    picture = \"\\z\\cache\\addons\\main\\data\\icon_ca.paa\";\n};\n",
+    )
+    .unwrap();
+    rapify_with_cache();
+    assert_eq!(cached_outputs().len(), 1);
+
+    // removing a referenced file invalidates the entry, so it is reported
+    std::fs::remove_file("addons/main/data/icon_ca.paa").unwrap();
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false)
+        .unwrap()
+        .with_cache(true);
+    let report = Rapifier.pre_build(&ctx).unwrap();
+    let warnings = report.warnings(hemtt::report::WithIncludes::Yes);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].ident(), "L-C15");
+}
+
+#[sealed_test]
+fn build_rapify_cache_rvmat_texture_missing() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Cache\"\nprefix = \"cache\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main/data").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\cache\\addons\\main").unwrap();
+    std::fs::write("addons/main/data/body_nohq.paa", "").unwrap();
+    std::fs::write(
+        "addons/main/data/body.rvmat",
+        "PixelShaderID = \"NormalMap\";\nVertexShaderID = \"NormalMap\";\nclass Stage1 {\n    texture = \"\\z\\cache\\addons\\main\\data\\body_nohq.paa\";\n    uvSource = \"tex\";\n};\n",
+    )
+    .unwrap();
+    let rapify = || {
+        let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false)
+            .unwrap()
+            .with_cache(true);
+        Rapifier.pre_build(&ctx).unwrap()
+    };
+    assert!(rapify().is_empty());
+    assert_eq!(cached_outputs().len(), 1);
+
+    // removing the texture invalidates the entry, so it is reported
+    std::fs::remove_file("addons/main/data/body_nohq.paa").unwrap();
+    let report = rapify();
+    let warnings = report.warnings(hemtt::report::WithIncludes::Yes);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].ident(), "L-C16");
+}
+
+#[sealed_test]
+fn build_rapify_cache_schema() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Cache\"\nprefix = \"cache\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\cache\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "class CfgPatches {\n    class cache_main {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {};\n    };\n};\nclass CfgCache {\n    value = 1;\n};\n",
+    )
+    .unwrap();
+    rapify_with_cache();
+
+    // a new schema invalidates the cached entry, so the config is checked against it
+    std::fs::create_dir_all(".hemtt/schemas").unwrap();
+    std::fs::write(
+        ".hemtt/schemas/cache.toml",
+        "class = \"^CfgCache$\"\nseverity = \"Error\"\n\n[properties.value]\ntype = \"string\"\n",
+    )
+    .unwrap();
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false)
+        .unwrap()
+        .with_cache(true);
+    let report = Rapifier.pre_build(&ctx).unwrap();
+    assert_eq!(report.errors().len(), 1);
+}
+
+fn rapify_with_cache() -> Vec<u8> {
+    let ctx = Context::new(Some("build"), PreservePrevious::Remove, None, false)
+        .unwrap()
+        .with_cache(true);
+    let report = Rapifier.pre_build(&ctx).unwrap();
+    assert!(report.is_empty());
+    let mut output = Vec::new();
+    ctx.workspace_path()
+        .join("addons/main/config.bin")
+        .unwrap()
+        .open_file()
+        .unwrap()
+        .read_to_end(&mut output)
+        .unwrap();
+    output
+}
+
+/// A rapified config that is written over the cached output, to tell if it was used
+fn cached_marker() -> Vec<u8> {
+    let mut output = Vec::new();
+    Config(vec![Property::Class(Class::External {
+        name: Ident {
+            value: "Cached".to_string(),
+            span: 0..0,
+        },
+    })])
+    .rapify(&mut output, 0)
+    .unwrap();
+    output
+}
+
+fn cached_outputs() -> Vec<std::path::PathBuf> {
+    std::fs::read_dir(".hemttout/cache/rapify")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "bin"))
+        .collect()
+}
+
+#[sealed_test]
+fn build_alpha_timings() {
+    std::env::set_current_dir(format!("{}/tests/alpha", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "build",
+        "--in-test",
+        "--timings",
+    ]))
+    .unwrap();
+    let trace: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(".hemttout/timings.json").unwrap()).unwrap();
+    let events = trace["traceEvents"].as_array().unwrap();
+    for category in ["module", "preprocess", "rapify", "pbo"] {
+        assert!(
+            events.iter().any(|event| event["cat"] == category),
+            "no `{category}` spans"
+        );
+    }
+    assert!(events.iter().all(|event| event["ph"] == "X"));
+}
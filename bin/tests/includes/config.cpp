@@ -0,0 +1,8 @@
+#include "script_component.hpp"
+#include "unused.hpp"
+
+class CfgPatches {
+    class PREFIX {
+        units[] = {};
+    };
+};
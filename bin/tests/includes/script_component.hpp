@@ -0,0 +1 @@
+#include "script_macros.hpp"
@@ -0,0 +1 @@
+#define PREFIX main
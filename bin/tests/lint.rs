@@ -0,0 +1,67 @@
+#![allow(clippy::unwrap_used)]
+
+use clap::Parser;
+use sealed_test::prelude::*;
+
+use hemtt::{
+    commands::lint::{execute, Command},
+    report::WithIncludes,
+};
+
+#[sealed_test]
+fn lint_alpha() {
+    std::env::set_current_dir(format!("{}/tests/alpha", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    let report = execute(&Command::parse_from(vec![
+        "lint",
+        "addons/main/config.cpp",
+        "addons/*/*.sqf",
+    ]))
+    .unwrap();
+    assert!(report.errors().is_empty());
+    let warnings = report.warnings(WithIncludes::Yes);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].ident(), "L-C12MC");
+    let diagnostic = warnings[0].diagnostic().unwrap();
+    assert_eq!(
+        diagnostic.labels[0].file().as_str(),
+        "/addons/main/config.cpp"
+    );
+    assert!(!std::path::Path::new(".hemttout/check").exists());
+}
+
+#[sealed_test]
+fn lint_header() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Lint\"\nprefix = \"lint\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::create_dir_all("addons/other").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\lint\\addons\\main").unwrap();
+    std::fs::write("addons/main/macros.hpp", "#define A 1\n#define A 2\n").unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "#include \"macros.hpp\"\nclass CfgPatches {\n    class lint_main {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {};\n        value = A;\n    };\n};\n",
+    )
+    .unwrap();
+    std::fs::write("addons/other/$PBOPREFIX$", "z\\lint\\addons\\other").unwrap();
+    std::fs::write(
+        "addons/other/config.cpp",
+        "class CfgPatches {\n    class lint_other {\n        units[] = {};\n        weapons[] = {};\n        requiredVersion = 2.14;\n        requiredAddons[] = {};\n        value = 1; value = 2;\n    };\n};\n",
+    )
+    .unwrap();
+    let report = execute(&Command::parse_from(vec!["lint", "addons/main/macros.hpp"])).unwrap();
+    // only the config that includes the header is checked
+    assert!(report.errors().is_empty());
+    let warnings = report.warnings(WithIncludes::Yes);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].ident(), "PW1");
+    let diagnostic = warnings[0].diagnostic().unwrap();
+    assert_eq!(
+        diagnostic.labels[0].file().as_str(),
+        "/addons/main/macros.hpp"
+    );
+    assert!(!std::path::Path::new(".hemttout").exists());
+}
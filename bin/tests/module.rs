@@ -0,0 +1,39 @@
+#![allow(clippy::unwrap_used)]
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use clap::Parser;
+use sealed_test::prelude::*;
+
+use hemtt::{context::Context, error::Error, modules::Module, report::Report, Cli};
+
+static PHASES: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Default)]
+struct Counter;
+
+impl Module for Counter {
+    fn name(&self) -> &'static str {
+        "Counter"
+    }
+
+    fn pre_build(&self, ctx: &Context) -> Result<Report, Error> {
+        assert!(ctx.workspace_path().join("addons")?.exists()?);
+        PHASES.fetch_add(1, Ordering::Relaxed);
+        Ok(Report::new())
+    }
+
+    fn post_build(&self, _ctx: &Context) -> Result<Report, Error> {
+        PHASES.fetch_add(1, Ordering::Relaxed);
+        Ok(Report::new())
+    }
+}
+
+hemtt::module!(Counter);
+
+#[sealed_test]
+fn registered_module() {
+    std::env::set_current_dir(format!("{}/tests/alpha", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    hemtt::execute(&Cli::parse_from(vec!["hemtt", "build", "--in-test"])).unwrap();
+    assert_eq!(PHASES.load(Ordering::Relaxed), 2);
+}
@@ -8,4 +8,42 @@ use sealed_test::prelude::*;
 #[sealed_test]
 fn new() {
     hemtt::execute(&Cli::parse_from(vec!["hemtt", "new", "test", "--in-test"])).unwrap();
+    for file in [
+        ".hemtt/project.toml",
+        "addons/main/$PBOPREFIX$",
+        "addons/main/config.cpp",
+        "addons/main/script_component.hpp",
+        "addons/main/script_version.hpp",
+    ] {
+        assert!(std::path::Path::new("test").join(file).exists(), "{file}");
+    }
+    assert_eq!(
+        std::fs::read_to_string("test/addons/main/$PBOPREFIX$").unwrap(),
+        "z\\abe\\addons\\main\n"
+    );
+}
+
+#[sealed_test]
+fn add_addon() {
+    hemtt::execute(&Cli::parse_from(vec!["hemtt", "new", "test", "--in-test"])).unwrap();
+    // the new repository has no commits
+    let mut project = std::fs::read_to_string("test/.hemtt/project.toml").unwrap();
+    project.push_str("\n[version]\ngit_hash = 0\n");
+    std::fs::write("test/.hemtt/project.toml", project).unwrap();
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "add",
+        "second",
+        "--dir",
+        "test",
+        "--in-test",
+    ]))
+    .unwrap();
+    assert_eq!(
+        std::fs::read_to_string("addons/second/$PBOPREFIX$").unwrap(),
+        "z\\abe\\addons\\second\n"
+    );
+    assert!(std::fs::read_to_string("addons/second/config.cpp")
+        .unwrap()
+        .contains("requiredAddons[] = {\"abe_main\"};"));
 }
@@ -0,0 +1,229 @@
+#![allow(clippy::unwrap_used)]
+
+use clap::Parser;
+use sealed_test::prelude::*;
+
+use hemtt::{
+    commands::publish::workshop::workshop_vdf,
+    context::{Context, PreservePrevious},
+    modules::{
+        github::{changelog_section, repository_from_url},
+        meta::template,
+        Meta, Module,
+    },
+    Cli,
+};
+
+#[test]
+fn github_repository() {
+    assert_eq!(
+        repository_from_url("https://github.com/BrettMayson/HEMTT.git").as_deref(),
+        Some("BrettMayson/HEMTT")
+    );
+    assert_eq!(
+        repository_from_url("git@github.com:BrettMayson/HEMTT.git").as_deref(),
+        Some("BrettMayson/HEMTT")
+    );
+    assert_eq!(
+        repository_from_url("https://github.com/BrettMayson/HEMTT/").as_deref(),
+        Some("BrettMayson/HEMTT")
+    );
+    assert_eq!(
+        repository_from_url("https://gitlab.com/owner/repo.git"),
+        None
+    );
+    assert_eq!(repository_from_url("https://github.com/owner"), None);
+}
+
+#[test]
+fn github_changelog() {
+    let changelog = "# Changelog
+
+## [1.3.0] - 2024-02-01
+
+### Added
+
+- Bananas
+
+## v1.2.3
+
+- Fixed apples
+
+## 1.2.0
+";
+    assert_eq!(
+        changelog_section(changelog, "1.3.0").as_deref(),
+        Some("### Added\n\n- Bananas")
+    );
+    assert_eq!(
+        changelog_section(changelog, "1.2.3").as_deref(),
+        Some("- Fixed apples")
+    );
+    assert_eq!(changelog_section(changelog, "1.2.0").as_deref(), Some(""));
+    assert_eq!(changelog_section(changelog, "1.2"), None);
+}
+
+#[test]
+fn workshop_script() {
+    assert_eq!(
+        workshop_vdf(
+            463_939_057,
+            std::path::Path::new("C:\\mods\\@abe"),
+            "Fixed \"bananas\""
+        ),
+        "\"workshopitem\"\n{\n\t\"appid\"\t\"107410\"\n\t\"publishedfileid\"\t\"463939057\"\n\t\"contentfolder\"\t\"C:\\\\mods\\\\@abe\"\n\t\"changenote\"\t\"Fixed \\\"bananas\\\"\"\n}\n"
+    );
+}
+
+#[sealed_test]
+fn workshop_dry_run() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Publish\"\nprefix = \"pub\"\n\n[version]\nmajor = 1\nminor = 2\npatch = 3\ngit_hash = 0\n\n[hemtt.release]\npublishedid = 463939057\n\n[hemtt.publish]\nchangenote = \"{name} {version}: {changelog}\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        "CHANGELOG.md",
+        "## 1.2.3\n\n- Bananas\n\n## 1.2.2\n\n- Apples\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all(".hemttout/release/addons").unwrap();
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "publish",
+        "workshop",
+        "--dry-run",
+        "--in-test",
+    ]))
+    .unwrap();
+    let script = std::fs::read_to_string(".hemttout/publish/workshop.vdf").unwrap();
+    assert!(script.contains("\t\"publishedfileid\"\t\"463939057\"\n"));
+    assert!(script.contains("\t\"changenote\"\t\"Publish 1.2.3: - Bananas\"\n"));
+}
+
+#[cfg(unix)]
+#[sealed_test(env = [("STEAM_USERNAME", "banana"), ("STEAM_PASSWORD", "secret")])]
+fn workshop_password() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Publish\"\nprefix = \"pub\"\n\n[version]\nmajor = 1\nminor = 2\npatch = 3\ngit_hash = 0\n\n[hemtt.release]\npublishedid = 463939057\n\n[hemtt.publish]\nsteamcmd = \"./steamcmd.sh\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all(".hemttout/release/addons").unwrap();
+    std::fs::write(
+        "steamcmd.sh",
+        "#!/bin/sh\necho \"$@\" > args.txt\nread -r password\necho \"$password\" > stdin.txt\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(
+        "steamcmd.sh",
+        <std::fs::Permissions as std::os::unix::fs::PermissionsExt>::from_mode(0o755),
+    )
+    .unwrap();
+    hemtt::execute(&Cli::parse_from(vec![
+        "hemtt",
+        "publish",
+        "workshop",
+        "--in-test",
+    ]))
+    .unwrap();
+    let args = std::fs::read_to_string("args.txt").unwrap();
+    assert!(args.starts_with("+login banana +workshop_build_item "));
+    assert!(!args.contains("secret"));
+    assert_eq!(std::fs::read_to_string("stdin.txt").unwrap(), "secret\n");
+}
+
+#[test]
+fn meta_template() {
+    let content =
+        "name = \"{name} {version}\";\nprefix = \"{prefix}\";\npublishedid = {publishedid};\n";
+    assert_eq!(
+        template(
+            content,
+            "The \"Best\" Mod",
+            "abe",
+            "1.2.3",
+            Some(463_939_057)
+        ),
+        "name = \"The \"\"Best\"\" Mod 1.2.3\";\nprefix = \"abe\";\npublishedid = 463939057;\n"
+    );
+    assert_eq!(
+        template(content, "Mod", "abe", "1.2.3", None),
+        "name = \"Mod 1.2.3\";\nprefix = \"abe\";\n"
+    );
+}
+
+#[sealed_test]
+fn meta_release() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"The \\\"Best\\\" Mod\"\nprefix = \"abe\"\n\n[version]\nmajor = 1\nminor = 2\npatch = 3\ngit_hash = 0\n\n[hemtt.release]\npublishedid = 463939057\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all(".hemttout/release").unwrap();
+    std::fs::write(
+        ".hemttout/release/mod.cpp",
+        "name = \"{name}\";\ntooltip = \"{name} {version}\";\n",
+    )
+    .unwrap();
+    let ctx = Context::new(Some("release"), PreservePrevious::Keep, None, false).unwrap();
+    Meta.pre_release(&ctx).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(".hemttout/release/mod.cpp").unwrap(),
+        "name = \"The \"\"Best\"\" Mod\";\ntooltip = \"The \"\"Best\"\" Mod 1.2.3\";\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(".hemttout/release/meta.cpp").unwrap(),
+        "protocol = 1;\npublishedid = 463939057;\nname = \"The \"\"Best\"\" Mod\";\n"
+    );
+}
+
+#[sealed_test]
+fn archive_latest_name() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    let project = |archive_name: &str| {
+        std::fs::write(
+            ".hemtt/project.toml",
+            format!("name = \"Archive\"\nprefix = \"ar\"\n\n[version]\nmajor = 1\nminor = 2\npatch = 3\ngit_hash = 0\n\n[hemtt.release]\narchive_name = \"{archive_name}\"\narchive_tar = true\n"),
+        )
+        .unwrap();
+        let ctx = Context::new(Some("release"), PreservePrevious::Remove, None, false).unwrap();
+        std::fs::create_dir_all(ctx.build_folder().unwrap().join("addons")).unwrap();
+        std::fs::write(
+            ctx.build_folder()
+                .unwrap()
+                .join("addons")
+                .join("ar_main.pbo"),
+            "pbo",
+        )
+        .unwrap();
+        hemtt::modules::archive::release(&ctx).unwrap();
+    };
+    let files = |path: &str| {
+        let mut zip = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        let mut file = zip.by_name("@ar/addons/ar_main.pbo").unwrap();
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut file, &mut content).unwrap();
+        content
+    };
+
+    project("{prefix}-{version}");
+    assert_eq!(files("releases/ar-latest.zip"), "pbo");
+    assert_eq!(files("releases/ar-1.2.3.zip"), "pbo");
+    assert!(std::path::Path::new("releases/ar-1.2.3.tar.gz").exists());
+    std::fs::remove_dir_all("releases").unwrap();
+
+    // the latest archive is not copied onto itself
+    project("{prefix}-latest");
+    assert_eq!(files("releases/ar-latest.zip"), "pbo");
+    assert!(
+        std::fs::metadata("releases/ar-latest.tar.gz")
+            .unwrap()
+            .len()
+            > 0
+    );
+    assert_eq!(std::fs::read_dir("releases").unwrap().count(), 2);
+}
@@ -0,0 +1,274 @@
+#![allow(clippy::unwrap_used)]
+
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use hemtt_common::config::PDriveOption;
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::{reporting::Include, LayerType, Workspace};
+use sealed_test::prelude::*;
+
+use hemtt::Cli;
+
+#[sealed_test]
+fn config_get() {
+    std::env::set_current_dir(format!("{}/tests/alpha", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    for path in ["MyMod.value", "MyMod"] {
+        hemtt::execute(&Cli::parse_from(vec![
+            "hemtt",
+            "utils",
+            "config",
+            "get",
+            "addons/main",
+            path,
+            "--in-test",
+        ]))
+        .unwrap();
+    }
+}
+
+#[sealed_test]
+fn config_get_short_file() {
+    // shorter than the magic of a rapified config
+    std::fs::write("short.hpp", "\n").unwrap();
+    let error = hemtt::utils::config::execute(&hemtt::utils::config::Command::parse_from(vec![
+        "config",
+        "get",
+        "short.hpp",
+        "value",
+    ]))
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Config value error: `value` is not defined"
+    );
+}
+
+fn includes() -> Vec<Include> {
+    let workspace = Workspace::builder()
+        .physical(
+            &PathBuf::from(format!("{}/tests/includes", env!("CARGO_MANIFEST_DIR"))),
+            LayerType::Source,
+        )
+        .finish(None, false, &PDriveOption::Disallow)
+        .unwrap();
+    let processed = Processor::run(&workspace.join("config.cpp").unwrap()).unwrap();
+    processed.includes().to_vec()
+}
+
+#[test]
+fn includes_tree() {
+    assert_eq!(
+        hemtt::utils::includes::tree("/config.cpp", &includes()),
+        "/config.cpp
+- /script_component.hpp
+  - /script_macros.hpp
+- /unused.hpp (unused)
+"
+    );
+}
+
+#[test]
+fn includes_graph() {
+    assert_eq!(
+        hemtt::utils::includes::graph("/config.cpp", &includes()),
+        r#"digraph includes {
+    rankdir=LR;
+    "/config.cpp" [shape=box];
+    "/config.cpp" -> "/script_component.hpp";
+    "/config.cpp" -> "/unused.hpp" [style=dashed];
+    "/script_component.hpp" -> "/script_macros.hpp";
+}
+"#
+    );
+}
+
+#[test]
+fn dot_id() {
+    assert_eq!(hemtt::dot::id("main"), r#""main""#);
+    assert_eq!(
+        hemtt::dot::id(r#"\x\my "mod"\addons"#),
+        r#""\\x\\my \"mod\"\\addons""#
+    );
+    assert_eq!(hemtt::dot::id("a\nb"), r#""a\nb""#);
+}
+
+#[sealed_test]
+fn sqf_callgraph() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Calls\"\nprefix = \"ca\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all("addons/main/functions").unwrap();
+    std::fs::create_dir_all("addons/main/scripts").unwrap();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\ca\\addons\\main").unwrap();
+    std::fs::write(
+        "addons/main/functions/fnc_a.sqf",
+        "[] call ca_main_fnc_b;\n[] spawn CA_MAIN_FNC_B;\n[] execVM \"\\z\\ca\\addons\\main\\scripts\\run.sqf\";\ncall ext_fnc_x;\n[] call _local;\n",
+    )
+    .unwrap();
+    std::fs::write(
+        "addons/main/functions/fnc_b.sqf",
+        "[] execVM \"bad\"\"name.sqf\";\n",
+    )
+    .unwrap();
+    std::fs::write("addons/main/scripts/run.sqf", "call ca_main_fnc_a;\n").unwrap();
+
+    for output in ["callgraph.dot", "callgraph.json"] {
+        hemtt::execute(&Cli::parse_from(vec![
+            "hemtt",
+            "utils",
+            "sqf",
+            "callgraph",
+            output,
+            "--in-test",
+        ]))
+        .unwrap();
+    }
+
+    let graph: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string("callgraph.json").unwrap()).unwrap();
+    assert_eq!(
+        graph["nodes"],
+        serde_json::json!([
+            { "id": "addons/main/scripts/run.sqf", "file": "addons/main/scripts/run.sqf" },
+            { "id": "bad\"name.sqf", "file": null },
+            { "id": "ca_main_fnc_a", "file": "addons/main/functions/fnc_a.sqf" },
+            { "id": "ca_main_fnc_b", "file": "addons/main/functions/fnc_b.sqf" },
+            { "id": "ext_fnc_x", "file": null },
+        ])
+    );
+    assert_eq!(
+        graph["edges"],
+        serde_json::json!([
+            { "from": "addons/main/scripts/run.sqf", "to": "ca_main_fnc_a", "kind": "call" },
+            { "from": "ca_main_fnc_a", "to": "addons/main/scripts/run.sqf", "kind": "execVM" },
+            { "from": "ca_main_fnc_a", "to": "ca_main_fnc_b", "kind": "call" },
+            { "from": "ca_main_fnc_a", "to": "ca_main_fnc_b", "kind": "spawn" },
+            { "from": "ca_main_fnc_a", "to": "ext_fnc_x", "kind": "call" },
+            { "from": "ca_main_fnc_b", "to": "bad\"name.sqf", "kind": "execVM" },
+        ])
+    );
+
+    assert_eq!(
+        std::fs::read_to_string("callgraph.dot").unwrap(),
+        r#"digraph callgraph {
+    "addons/main/scripts/run.sqf";
+    "bad\"name.sqf" [style=dashed];
+    "ca_main_fnc_a";
+    "ca_main_fnc_b";
+    "ext_fnc_x" [style=dashed];
+    "addons/main/scripts/run.sqf" -> "ca_main_fnc_a";
+    "ca_main_fnc_a" -> "addons/main/scripts/run.sqf" [label="execVM"];
+    "ca_main_fnc_a" -> "ca_main_fnc_b";
+    "ca_main_fnc_a" -> "ca_main_fnc_b" [label="spawn"];
+    "ca_main_fnc_a" -> "ext_fnc_x";
+    "ca_main_fnc_b" -> "bad\"name.sqf" [label="execVM"];
+}
+"#
+    );
+}
+
+fn preprocess_files() {
+    std::fs::create_dir_all("addons/main").unwrap();
+    std::fs::write(
+        "addons/main/script_macros.hpp",
+        "#define PREFIX abe\n#define COMPONENT main\n#define DOUBLES(a,b) a##_##b\n#define ADDON DOUBLES(PREFIX,COMPONENT)\n#define QUOTE(a) #a\n#define GVAR(a) DOUBLES(ADDON,a)\n#define QGVAR(a) QUOTE(GVAR(a))\n",
+    )
+    .unwrap();
+    std::fs::write(
+        "addons/main/config.cpp",
+        "#include \"script_macros.hpp\"\nclass Test {\n    file = __FILE__;\n    value = GVAR(peel);\n};\n",
+    )
+    .unwrap();
+}
+
+#[sealed_test]
+fn preprocess_source() {
+    preprocess_files();
+    // outside of a project, only the folder of the file is used
+    let processed = Processor::run(
+        &hemtt::utils::preprocess::source(Path::new("addons/main/config.cpp")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        processed.as_str(),
+        "class Test {\n    file = \"\\config.cpp\";\n    value = abe_main_peel;\n};\n"
+    );
+    assert!(hemtt::utils::preprocess::source(Path::new("addons/main/missing.cpp")).is_err());
+    assert!(hemtt::utils::preprocess::source(Path::new("addons/main")).is_err());
+    assert!(!Path::new(".hemttout").exists());
+}
+
+#[sealed_test]
+fn preprocess_project() {
+    std::fs::create_dir_all(".hemtt").unwrap();
+    std::fs::write(
+        ".hemtt/project.toml",
+        "name = \"Preprocess\"\nprefix = \"pp\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n",
+    )
+    .unwrap();
+    preprocess_files();
+    std::fs::write("addons/main/$PBOPREFIX$", "z\\pp\\addons\\main").unwrap();
+    std::fs::create_dir_all("addons/other").unwrap();
+    std::fs::write("addons/other/$PBOPREFIX$", "z\\pp\\addons\\other").unwrap();
+    // includes from other addons are resolved by their prefix
+    std::fs::write(
+        "addons/other/config.cpp",
+        "#include \"\\z\\pp\\addons\\main\\script_macros.hpp\"\nvalue = QGVAR(other);\n",
+    )
+    .unwrap();
+    let processed = Processor::run(
+        &hemtt::utils::preprocess::source(Path::new("addons/other/config.cpp")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(processed.as_str(), "value = \"abe_main_other\";\n");
+    let processed = Processor::run(
+        &hemtt::utils::preprocess::source(Path::new("addons/main/config.cpp")).unwrap(),
+    )
+    .unwrap();
+    assert!(processed
+        .as_str()
+        .contains("file = \"pp\\addons\\main\\config.cpp\";"));
+    for args in [vec![], vec!["--trace", "GVAR"]] {
+        hemtt::execute(&Cli::parse_from(
+            [
+                "hemtt",
+                "utils",
+                "preprocess",
+                "addons/main/config.cpp",
+                "--in-test",
+            ]
+            .into_iter()
+            .chain(args),
+        ))
+        .unwrap();
+    }
+    assert!(!Path::new(".hemttout").exists());
+}
+
+#[sealed_test]
+fn preprocess_trace() {
+    preprocess_files();
+    let processed = Processor::run(
+        &hemtt::utils::preprocess::source(Path::new("addons/main/config.cpp")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        hemtt::utils::preprocess::trace(&processed, "GVAR"),
+        "GVAR defined at /script_macros.hpp:6
+
+GVAR at /config.cpp:4:13
+  -> abe_main_peel
+  DOUBLES at /script_macros.hpp:6:17
+    -> abe_main_peel
+    argument a at /script_macros.hpp:3:22
+      -> abe_main
+    argument b at /script_macros.hpp:3:28
+      -> peel
+"
+    );
+    assert_eq!(hemtt::utils::preprocess::trace(&processed, "UNUSED"), "");
+}
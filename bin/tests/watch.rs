@@ -0,0 +1,89 @@
+#![allow(clippy::unwrap_used)]
+
+use sealed_test::prelude::*;
+
+use hemtt::{
+    commands::watch::changed_addons,
+    context::{Context, PreservePrevious},
+    modules::{AddonConfigs, Module, Rapifier},
+};
+
+#[sealed_test]
+fn watch_reset() {
+    std::env::set_current_dir(format!("{}/tests/alpha", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    let ctx = Context::new(Some("dev"), PreservePrevious::Remove, None, false).unwrap();
+    Rapifier.pre_build(&ctx).unwrap();
+    let rapified = ctx
+        .workspace_path()
+        .join("addons/secondary/config.bin")
+        .unwrap();
+    assert!(rapified.exists().unwrap());
+    assert_eq!(ctx.state().get::<AddonConfigs>().read().unwrap().len(), 2);
+
+    // only `main` changed, nothing from the last build is left for `secondary`
+    let rebuild = ctx
+        .reset()
+        .unwrap()
+        .filter(|addon, _| addon.name() == "main");
+    assert!(!rapified.exists().unwrap());
+    assert!(rebuild.state().try_get::<AddonConfigs>().is_none());
+    Rapifier.pre_build(&rebuild).unwrap();
+    let names = rebuild
+        .state()
+        .get::<AddonConfigs>()
+        .read()
+        .unwrap()
+        .keys()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["main".to_string()]);
+    assert!(!rapified.exists().unwrap());
+}
+
+#[sealed_test]
+fn watch_include_folders() {
+    std::fs::create_dir_all("project/.hemtt").unwrap();
+    std::fs::write(
+        "project/.hemtt/project.toml",
+        "name = \"Watch\"\nprefix = \"wa\"\n\n[version]\nmajor = 1\nminor = 0\npatch = 0\ngit_hash = 0\n\n[hemtt.preprocessor]\ninclude_paths = [\"../common\"]\n\n[hemtt.preprocessor.mounts]\n'\\x\\cba' = \"cba\"\n",
+    )
+    .unwrap();
+    for addon in ["main", "other"] {
+        std::fs::create_dir_all(format!("project/addons/{addon}")).unwrap();
+        std::fs::write(
+            format!("project/addons/{addon}/$PBOPREFIX$"),
+            format!("z\\wa\\addons\\{addon}"),
+        )
+        .unwrap();
+    }
+    std::fs::create_dir_all("common").unwrap();
+    std::fs::create_dir_all("project/cba").unwrap();
+    std::env::set_current_dir("project").unwrap();
+    let ctx = Context::new(Some("dev"), PreservePrevious::Remove, None, false).unwrap();
+    let changed = |path: std::path::PathBuf| {
+        let mut changed = changed_addons(&ctx, std::iter::once(path.as_path()))
+            .into_iter()
+            .collect::<Vec<_>>();
+        changed.sort();
+        changed
+    };
+
+    assert_eq!(
+        changed(ctx.project_folder().join("addons/main/config.cpp")),
+        vec!["main"]
+    );
+    // headers outside of the addons are used by every addon
+    let common = ctx
+        .project_folder()
+        .join("../common")
+        .canonicalize()
+        .unwrap();
+    assert_eq!(
+        changed(common.join("script_macros.hpp")),
+        vec!["main", "other"]
+    );
+    assert_eq!(
+        changed(ctx.project_folder().join("cba/script_macros.hpp")),
+        vec!["main", "other"]
+    );
+}
@@ -5,6 +5,7 @@ pub fn run(chapter: &mut Chapter) {
     let commands = vec![
         ("new", hemtt::commands::new::Command::command()),
         ("check", hemtt::commands::check::Command::command()),
+        ("lint", hemtt::commands::lint::Command::command()),
         ("dev", hemtt::commands::dev::Command::command()),
         ("launch", hemtt::commands::launch::Command::command()),
         ("build", hemtt::commands::build::Command::command()),
@@ -12,19 +13,32 @@ pub fn run(chapter: &mut Chapter) {
         ("script", hemtt::commands::script::Command::command()),
     ];
 
-    let nested = [(
-        "localization",
-        vec![
-            (
-                "coverage",
-                hemtt::commands::localization::coverage::Command::command(),
-            ),
-            (
-                "sort",
-                hemtt::commands::localization::sort::Command::command(),
-            ),
-        ],
-    )];
+    let nested = [
+        (
+            "config",
+            vec![("show", hemtt::commands::config::show::Command::command())],
+        ),
+        (
+            "localization",
+            vec![
+                (
+                    "coverage",
+                    hemtt::commands::localization::coverage::Command::command(),
+                ),
+                (
+                    "sort",
+                    hemtt::commands::localization::sort::Command::command(),
+                ),
+            ],
+        ),
+        (
+            "publish",
+            vec![(
+                "workshop",
+                hemtt::commands::publish::workshop::Command::command(),
+            )],
+        ),
+    ];
 
     for item in &mut chapter.sub_items {
         if let mdbook::BookItem::Chapter(ref mut chapter) = item {
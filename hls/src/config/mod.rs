@@ -4,7 +4,7 @@ use std::{
 };
 
 use hemtt_preprocessor::Processor;
-use hemtt_workspace::{reporting::WorkspaceFiles, WorkspacePath};
+use hemtt_workspace::{position::Position, reporting::WorkspaceFiles, WorkspacePath};
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 use url::Url;
@@ -16,6 +16,7 @@ use crate::{
 
 pub struct CacheBundle {
     pub sources: Vec<WorkspacePath>,
+    pub macros: HashMap<String, Vec<Position>>,
 }
 
 #[derive(Clone)]
@@ -30,6 +31,16 @@ impl ConfigCache {
         });
         (*SINGLETON).clone()
     }
+
+    /// The macros of the first config that uses a file
+    pub async fn macros(&self, source: &WorkspacePath) -> Option<HashMap<String, Vec<Position>>> {
+        self.files
+            .read()
+            .await
+            .values()
+            .find(|bundle| bundle.sources.contains(source))
+            .map(|bundle| bundle.macros.clone())
+    }
 }
 
 async fn check_addons(workspace: EditorWorkspace) {
@@ -49,8 +60,10 @@ async fn check_addon(source: WorkspacePath, workspace: EditorWorkspace) {
     };
     manager.clear_current(&format!("config:{}", source.as_str()));
     let mut lsp_diags = HashMap::new();
+    let mut macros = HashMap::new();
     let sources = match Processor::run(&source) {
         Ok(processed) => {
+            macros = processed.macros().clone();
             let workspace_files = WorkspaceFiles::new();
             match hemtt_config::parse(None, &processed) {
                 Ok(report) => {
@@ -121,7 +134,7 @@ async fn check_addon(source: WorkspacePath, workspace: EditorWorkspace) {
             .files
             .write()
             .await
-            .insert(source.clone(), CacheBundle { sources });
+            .insert(source.clone(), CacheBundle { sources, macros });
     } else {
         cache.files.write().await.remove(&source);
     }
@@ -13,6 +13,7 @@ use crate::workspace::EditorWorkspaces;
 mod config;
 mod diag_manager;
 mod positions;
+mod preprocessor;
 pub mod sqf;
 mod sqf_project;
 mod workspace;
@@ -34,6 +35,7 @@ impl LanguageServer for Backend {
                     TextDocumentSyncKind::INCREMENTAL,
                 )),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
+                definition_provider: Some(OneOf::Left(true)),
                 workspace: Some(WorkspaceServerCapabilities {
                     workspace_folders: Some(WorkspaceFoldersServerCapabilities {
                         supported: Some(true),
@@ -123,7 +125,7 @@ impl LanguageServer for Backend {
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
         debug!("did_save: {:?}", params.text_document.uri);
-        SqfCache::cache(params.text_document.uri.clone()).await;
+        SqfCache::did_save(params.text_document.uri.clone()).await;
         config::did_save(params.text_document.uri.clone()).await;
         if let Some(text) = params.text {
             SqfAnalyzer::get()
@@ -141,12 +143,26 @@ impl LanguageServer for Backend {
     }
 
     async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
-        Ok(SqfAnalyzer::get()
-            .hover(
-                params.text_document_position_params.text_document.uri,
-                params.text_document_position_params.position,
-            )
-            .await)
+        let url = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+        if let Some(hover) = preprocessor::hover(&url, position).await {
+            return Ok(Some(hover));
+        }
+        if !url.path().ends_with(".sqf") {
+            return Ok(None);
+        }
+        Ok(SqfAnalyzer::get().hover(url, position).await)
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        Ok(preprocessor::definition(
+            &params.text_document_position_params.text_document.uri,
+            params.text_document_position_params.position,
+        )
+        .await)
     }
 
     async fn semantic_tokens_full(
@@ -183,12 +199,21 @@ async fn main() {
     tracing_subscriber::fmt()
         .with_ansi(false)
         .with_max_level(Level::DEBUG)
+        .with_writer(std::io::stderr)
         .init();
 
-    // first argument is the port
-    let port = std::env::args().nth(1).unwrap_or("9632".to_string());
+    let (service, socket) = LspService::new(|client| Backend { client });
+
+    // first argument is the port, or `--stdio` for editors that start the server themselves
+    let arg = std::env::args().nth(1).unwrap_or("9632".to_string());
+    if arg == "--stdio" {
+        Server::new(tokio::io::stdin(), tokio::io::stdout(), socket)
+            .serve(service)
+            .await;
+        return;
+    }
 
-    let stream = TcpStream::connect(format!("127.0.0.1:{}", port))
+    let stream = TcpStream::connect(format!("127.0.0.1:{}", arg))
         .await
         .unwrap();
 
@@ -196,6 +221,5 @@ async fn main() {
 
     let (read, write) = tokio::io::split(stream);
 
-    let (service, socket) = LspService::new(|client| Backend { client });
     Server::new(read, write, socket).serve(service).await;
 }
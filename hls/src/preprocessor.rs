@@ -0,0 +1,146 @@
+//! Hover and go to definition for macros and includes
+
+use std::collections::HashMap;
+
+use hemtt_workspace::{position::Position as SourcePosition, WorkspacePath};
+use tower_lsp::lsp_types::{
+    GotoDefinitionResponse, Hover, HoverContents, Location, MarkupContent, MarkupKind, Position,
+    Range,
+};
+use tracing::warn;
+use url::Url;
+
+use crate::{
+    config::ConfigCache,
+    sqf_project::SqfCache,
+    workspace::{EditorWorkspace, EditorWorkspaces},
+};
+
+pub async fn hover(url: &Url, position: Position) -> Option<Hover> {
+    let (workspace, source) = resolve(url).await?;
+    let line = line(&source, position.line)?;
+    let (word, range) = word_at(&line, position)?;
+    let definitions = macros(url, &source).await?.remove(word)?;
+    let definition = definitions.last()?;
+    let text = definition_text(definition)?;
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!(
+                "```cpp\n{text}\n```\n\nDefined in `{}`",
+                workspace
+                    .to_url(definition.path())
+                    .path()
+                    .strip_prefix(workspace.url().path())
+                    .unwrap_or_else(|| definition.path().as_str())
+                    .trim_start_matches('/'),
+            ),
+        }),
+        range: Some(range),
+    })
+}
+
+pub async fn definition(url: &Url, position: Position) -> Option<GotoDefinitionResponse> {
+    let (workspace, source) = resolve(url).await?;
+    let line = line(&source, position.line)?;
+    if let Some(include) = include(&line) {
+        let located = match source.locate(include) {
+            Ok(Some(located)) => located.path,
+            Ok(None) => return None,
+            Err(e) => {
+                warn!("failed to locate {include}: {e}");
+                return None;
+            }
+        };
+        return Some(GotoDefinitionResponse::Scalar(Location {
+            uri: workspace.to_url(&located),
+            range: Range::default(),
+        }));
+    }
+    let (word, _) = word_at(&line, position)?;
+    let definitions = macros(url, &source).await?.remove(word)?;
+    Some(GotoDefinitionResponse::Array(
+        definitions
+            .iter()
+            .map(|definition| Location {
+                uri: workspace.to_url(definition.path()),
+                range: definition.to_lsp(),
+            })
+            .collect(),
+    ))
+}
+
+async fn resolve(url: &Url) -> Option<(EditorWorkspace, WorkspacePath)> {
+    let Some(workspace) = EditorWorkspaces::get().guess_workspace_retry(url).await else {
+        warn!("Failed to find workspace for {:?}", url);
+        return None;
+    };
+    let source = workspace.join_url(url).ok()?;
+    Some((workspace, source))
+}
+
+/// The macros known in a file, from the last time it or a config using it was processed
+async fn macros(url: &Url, source: &WorkspacePath) -> Option<HashMap<String, Vec<SourcePosition>>> {
+    if let Some(macros) = SqfCache::get().macros(url) {
+        return Some(macros);
+    }
+    ConfigCache::get().macros(source).await
+}
+
+fn line(source: &WorkspacePath, line: u32) -> Option<String> {
+    source
+        .read_to_string()
+        .ok()?
+        .lines()
+        .nth(line as usize)
+        .map(str::to_string)
+}
+
+/// The path of an `#include` directive
+fn include(line: &str) -> Option<&str> {
+    let rest = line.trim_start().strip_prefix("#include")?.trim();
+    let (open, close) = match rest.chars().next()? {
+        '"' => ('"', '"'),
+        '<' => ('<', '>'),
+        _ => return None,
+    };
+    let rest = rest.strip_prefix(open)?;
+    Some(&rest[..rest.find(close)?])
+}
+
+/// The word under the cursor, and its range
+fn word_at(line: &str, position: Position) -> Option<(&str, Range)> {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let cursor = line
+        .char_indices()
+        .nth(position.character as usize)
+        .map_or(line.len(), |(i, _)| i);
+    let start = line[..cursor]
+        .rfind(|c: char| !is_word(c))
+        .map_or(0, |i| i + 1);
+    let end = line[cursor..]
+        .find(|c: char| !is_word(c))
+        .map_or(line.len(), |i| cursor + i);
+    if start >= end {
+        return None;
+    }
+    let range = Range::new(
+        Position::new(position.line, line[..start].chars().count() as u32),
+        Position::new(position.line, line[..end].chars().count() as u32),
+    );
+    Some((&line[start..end], range))
+}
+
+/// The `#define` of a macro, including continued lines
+fn definition_text(definition: &SourcePosition) -> Option<String> {
+    let content = definition.path().read_to_string().ok()?;
+    let mut lines = Vec::new();
+    for line in content.lines().skip(definition.start().line() - 1) {
+        let line = line.trim_end();
+        lines.push(line);
+        if !line.ends_with('\\') {
+            break;
+        }
+    }
+    Some(lines.join("\n"))
+}
@@ -22,7 +22,7 @@ impl Locate for (&Processed, &Statements) {
                     .line_offset(&cache_bundle.source, position.line as usize)
                     .unwrap_or_default()
                     + position.character as usize;
-                for statement in cache_bundle.statements.content().iter() {
+                for statement in cache_bundle.statements.iter().flat_map(|s| s.content()) {
                     match statement {
                         Statement::AssignGlobal(_, expression, _)
                         | Statement::AssignLocal(_, expression, _)
@@ -4,19 +4,32 @@ use std::{
 };
 
 use hemtt_preprocessor::Processor;
-use hemtt_sqf::{parser::database::Database, Statements};
-use hemtt_workspace::{reporting::Processed, WorkspacePath};
+use hemtt_sqf::{
+    analyze::analyze,
+    parser::{database::Database, ParserError},
+    Statements,
+};
+use hemtt_workspace::{
+    addons::Addon,
+    position::Position,
+    reporting::{Codes, Processed, WorkspaceFiles},
+    WorkspacePath,
+};
 use tracing::{debug, error, warn};
 use url::Url;
 
-use crate::workspace::EditorWorkspaces;
+use crate::{
+    diag_manager::DiagManager,
+    workspace::{EditorWorkspace, EditorWorkspaces},
+};
 
 #[allow(dead_code)]
 pub struct CacheBundle {
     pub processed: Processed,
     pub source: WorkspacePath,
-    pub statements: Statements,
-    pub database: Database,
+    /// `None` if the file could not be parsed
+    pub statements: Option<Statements>,
+    pub database: Arc<Database>,
 }
 
 #[derive(Clone)]
@@ -45,39 +58,134 @@ impl SqfCache {
             return;
         };
         debug!("Caching {:?}", source);
+        let scope = format!("sqf:{}", source.as_str());
+        let Some(manager) = DiagManager::get() else {
+            warn!("failed to get diag manager");
+            return;
+        };
+        manager.clear_current(&scope);
+        let workspace_files = WorkspaceFiles::new();
+        let mut codes: Codes = Vec::new();
         let processed = match Processor::run(&source) {
             Ok(processed) => processed,
-            Err(e) => {
-                warn!(
-                    "Failed to process {} {:?}",
-                    url,
-                    e.1.get_code().unwrap().diagnostic()
-                );
+            Err((_, e)) => {
+                warn!("Failed to process {}: {:?}", url, e);
+                if let hemtt_preprocessor::Error::Code(code) = e {
+                    codes.push(code);
+                }
+                publish(&manager, &scope, &workspace, &workspace_files, &codes);
+                Self::get().files.write().unwrap().remove(&url);
                 return;
             }
         };
+        codes.extend(processed.warnings().iter().cloned());
         let database = match Database::a3_with_workspace(workspace.root(), false) {
-            Ok(database) => database,
+            Ok(database) => Arc::new(database),
             Err(e) => {
                 error!("Failed to create database {:?}", e);
                 return;
             }
         };
-        match hemtt_sqf::parser::run(&database, &processed) {
+        let statements = match hemtt_sqf::parser::run(&database, &processed) {
             Ok(sqf) => {
-                Self::get().files.write().unwrap().insert(
-                    url,
-                    CacheBundle {
-                        processed,
-                        source,
-                        statements: sqf,
-                        database,
-                    },
-                );
+                if let Some(addon) = addon(&workspace, &source) {
+                    codes.extend(analyze(
+                        &sqf,
+                        None,
+                        &processed,
+                        Arc::new(addon),
+                        database.clone(),
+                    ));
+                }
+                Some(sqf)
             }
-            Err(e) => {
-                warn!("Failed to parse {}: {e:?}", url);
+            Err(ParserError::LexingError(e) | ParserError::ParsingError(e)) => {
+                warn!("Failed to parse {}", url);
+                codes.extend(e);
+                None
             }
+        };
+        publish(&manager, &scope, &workspace, &workspace_files, &codes);
+        Self::get().files.write().unwrap().insert(
+            url,
+            CacheBundle {
+                processed,
+                source,
+                statements,
+                database,
+            },
+        );
+    }
+
+    /// Reprocess the cached files that use the saved file, including itself
+    pub async fn did_save(url: Url) {
+        let Some(workspace) = EditorWorkspaces::get().guess_workspace_retry(&url).await else {
+            warn!("Failed to find workspace for {:?}", url);
+            return;
+        };
+        let Ok(saved) = workspace.join_url(&url) else {
+            return;
+        };
+        let mut stale = Self::get()
+            .files
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, bundle)| {
+                bundle
+                    .processed
+                    .sources()
+                    .iter()
+                    .any(|(path, _)| path == &saved)
+            })
+            .map(|(url, _)| url.clone())
+            .collect::<Vec<_>>();
+        if !stale.contains(&url) {
+            stale.push(url);
         }
+        for url in stale {
+            debug!("reprocessing {:?}", url);
+            tokio::spawn(Self::cache(url));
+        }
+    }
+
+    /// The macros available in a cached file
+    pub fn macros(&self, url: &Url) -> Option<HashMap<String, Vec<Position>>> {
+        self.files
+            .read()
+            .unwrap()
+            .get(url)
+            .map(|bundle| bundle.processed.macros().clone())
+    }
+}
+
+/// The addon a file belongs to, from its location in the project
+fn addon(workspace: &EditorWorkspace, source: &WorkspacePath) -> Option<Addon> {
+    let root = workspace.url().to_file_path().ok()?;
+    let mut parts = source.as_str().trim_start_matches('/').split('/');
+    let location = parts.next()?.parse().ok()?;
+    let name = parts.next()?;
+    Addon::new(&root, name.to_string(), location).ok()
+}
+
+fn publish(
+    manager: &DiagManager,
+    scope: &str,
+    workspace: &EditorWorkspace,
+    workspace_files: &WorkspaceFiles,
+    codes: &Codes,
+) {
+    let mut lsp_diags = HashMap::new();
+    for code in codes {
+        let Some(diag) = code.diagnostic() else {
+            continue;
+        };
+        for (file, diag) in diag.to_lsp(workspace_files) {
+            lsp_diags.entry(file).or_insert_with(Vec::new).push(diag);
+        }
+    }
+    for (file, diags) in lsp_diags {
+        manager.set_current(scope, &workspace.to_url(&file), diags);
     }
+    manager.sync();
 }
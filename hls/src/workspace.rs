@@ -139,8 +139,14 @@ impl EditorWorkspace {
 
     pub fn to_url(&self, path: &WorkspacePath) -> Url {
         // trim the workspace path
-        let path = path.as_str().strip_prefix(self.workspace.as_str()).unwrap();
-        let path = path.replace('\\', "/");
+        let trimmed = path.as_str().strip_prefix(self.workspace.as_str()).unwrap();
+        let trimmed = trimmed.replace('\\', "/");
+        // files from the include layer are in the `include` folder
+        let path = if path.is_include() {
+            format!("/include{trimmed}")
+        } else {
+            trimmed
+        };
         // url encode the path
         let path = urlencoding::encode(&path);
         let path = path.replace("%2F", "/");
@@ -153,7 +159,6 @@ impl EditorWorkspace {
         &self.workspace
     }
 
-    #[allow(dead_code)]
     pub fn url(&self) -> &Url {
         &self.url
     }
@@ -0,0 +1,16 @@
+#[derive(thiserror::Error, Debug)]
+/// Error type for reading and writing audio
+pub enum Error {
+    #[error("IO Error: {0}")]
+    /// [`std::io::Error`]
+    Io(#[from] std::io::Error),
+    #[error("WAV Error: {0}")]
+    /// [`hound::Error`]
+    Wav(#[from] hound::Error),
+    #[error("Vorbis Error: {0}")]
+    /// [`vorbis_rs::VorbisError`]
+    Vorbis(#[from] vorbis_rs::VorbisError),
+    #[error("Unsupported audio: {0}")]
+    /// The audio can not be read or written
+    Unsupported(String),
+}
@@ -0,0 +1,177 @@
+//! Audio for Arma 3, reading `.wav` sources and writing `.ogg` and `.wss`
+
+#![allow(clippy::cast_possible_truncation)]
+
+use std::{
+    io::{Read, Write},
+    num::{NonZeroU32, NonZeroU8},
+};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use hound::SampleFormat;
+use vorbis_rs::{VorbisBitrateManagementStrategy, VorbisEncoderBuilder};
+
+mod error;
+
+pub use error::Error;
+
+/// Serial of written Ogg streams, fixed so the same source always gives the same file
+const OGG_SERIAL: i32 = 0x4845_4D54;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Audio samples, as 16 bit PCM
+pub struct Audio {
+    channels: u16,
+    sample_rate: u32,
+    /// Interleaved samples
+    samples: Vec<i16>,
+}
+
+impl Audio {
+    #[must_use]
+    /// Create audio from interleaved samples
+    pub const fn new(channels: u16, sample_rate: u32, samples: Vec<i16>) -> Self {
+        Self {
+            channels,
+            sample_rate,
+            samples,
+        }
+    }
+
+    #[must_use]
+    /// Number of channels
+    pub const fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    #[must_use]
+    /// Samples per second
+    pub const fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    #[must_use]
+    /// Interleaved samples
+    pub fn samples(&self) -> &[i16] {
+        &self.samples
+    }
+
+    /// Read a `.wav` file, samples of any bit depth are converted to 16 bit
+    ///
+    /// # Errors
+    /// [`Error::Wav`] if the input is not a valid `.wav` file
+    pub fn read_wav<R: Read>(input: R) -> Result<Self, Error> {
+        let reader = hound::WavReader::new(input)?;
+        let spec = reader.spec();
+        let samples = match spec.sample_format {
+            SampleFormat::Float => reader
+                .into_samples::<f32>()
+                .map(|s| s.map(|s| (s.clamp(-1.0, 1.0) * f32::from(i16::MAX)) as i16))
+                .collect::<Result<Vec<_>, _>>()?,
+            SampleFormat::Int if spec.bits_per_sample <= 16 => {
+                let shift = 16 - spec.bits_per_sample;
+                reader
+                    .into_samples::<i16>()
+                    .map(|s| s.map(|s| s << shift))
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+            SampleFormat::Int => {
+                let shift = spec.bits_per_sample - 16;
+                reader
+                    .into_samples::<i32>()
+                    .map(|s| s.map(|s| (s >> shift) as i16))
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+        };
+        Ok(Self::new(spec.channels, spec.sample_rate, samples))
+    }
+
+    /// Read a `.wss` file, only uncompressed 16 bit PCM is supported
+    ///
+    /// # Errors
+    /// [`Error::Io`] if the input is not readable
+    /// [`Error::Unsupported`] if the file is not uncompressed 16 bit PCM
+    pub fn read_wss<R: Read>(mut input: R) -> Result<Self, Error> {
+        let mut magic = [0; 4];
+        input.read_exact(&mut magic)?;
+        if &magic != b"WSS0" {
+            return Err(Error::Unsupported("not a WSS file".to_string()));
+        }
+        let compression = input.read_u32::<LittleEndian>()?;
+        let format = input.read_u16::<LittleEndian>()?;
+        let channels = input.read_u16::<LittleEndian>()?;
+        let sample_rate = input.read_u32::<LittleEndian>()?;
+        let _bytes_per_second = input.read_u32::<LittleEndian>()?;
+        let _block_align = input.read_u16::<LittleEndian>()?;
+        let bits_per_sample = input.read_u16::<LittleEndian>()?;
+        let _extra = input.read_u16::<LittleEndian>()?;
+        if compression != 0 || format != 1 || bits_per_sample != 16 {
+            return Err(Error::Unsupported(format!(
+                "compression {compression}, format {format}, {bits_per_sample} bits per sample"
+            )));
+        }
+        let mut data = Vec::new();
+        input.read_to_end(&mut data)?;
+        let samples = data
+            .chunks_exact(2)
+            .map(|s| i16::from_le_bytes([s[0], s[1]]))
+            .collect();
+        Ok(Self::new(channels, sample_rate, samples))
+    }
+
+    /// Write as an uncompressed `.wss` file
+    ///
+    /// # Errors
+    /// [`Error::Io`] if the output is not writable
+    pub fn write_wss<W: Write>(&self, output: &mut W) -> Result<(), Error> {
+        let block_align = self.channels * 2;
+        output.write_all(b"WSS0")?;
+        output.write_u32::<LittleEndian>(0)?; // no compression
+        output.write_u16::<LittleEndian>(1)?; // PCM
+        output.write_u16::<LittleEndian>(self.channels)?;
+        output.write_u32::<LittleEndian>(self.sample_rate)?;
+        output.write_u32::<LittleEndian>(self.sample_rate * u32::from(block_align))?;
+        output.write_u16::<LittleEndian>(block_align)?;
+        output.write_u16::<LittleEndian>(16)?;
+        output.write_u16::<LittleEndian>(0)?;
+        for sample in &self.samples {
+            output.write_i16::<LittleEndian>(*sample)?;
+        }
+        Ok(())
+    }
+
+    /// Write as an Ogg Vorbis file, with a target bitrate in kbps
+    ///
+    /// # Errors
+    /// [`Error::Unsupported`] if the audio has no channels, more than 255 channels, or no sample rate
+    /// [`Error::Vorbis`] if the audio could not be encoded
+    pub fn write_ogg<W: Write>(&self, bitrate: u32, output: W) -> Result<(), Error> {
+        let channels = u8::try_from(self.channels)
+            .ok()
+            .and_then(NonZeroU8::new)
+            .ok_or_else(|| Error::Unsupported(format!("{} channels", self.channels)))?;
+        let sample_rate = NonZeroU32::new(self.sample_rate)
+            .ok_or_else(|| Error::Unsupported("sample rate of 0".to_string()))?;
+        let target_bitrate = NonZeroU32::new(bitrate.saturating_mul(1000))
+            .ok_or_else(|| Error::Unsupported("bitrate of 0".to_string()))?;
+        let mut encoder =
+            VorbisEncoderBuilder::new_with_serial(sample_rate, channels, output, OGG_SERIAL)
+                .bitrate_management_strategy(VorbisBitrateManagementStrategy::Vbr {
+                    target_bitrate,
+                })
+                .build()?;
+        // Encode in blocks of a second, the encoder takes one slice per channel
+        let block = self.sample_rate as usize * self.channels as usize;
+        for chunk in self.samples.chunks(block) {
+            let mut planar = vec![Vec::with_capacity(chunk.len()); self.channels as usize];
+            for frame in chunk.chunks_exact(self.channels as usize) {
+                for (channel, sample) in planar.iter_mut().zip(frame) {
+                    channel.push(f32::from(*sample) / f32::from(i16::MAX));
+                }
+            }
+            encoder.encode_audio_block(&planar)?;
+        }
+        encoder.finish()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+#![allow(clippy::unwrap_used, clippy::cast_possible_truncation)]
+
+use hemtt_audio::Audio;
+
+/// A second of a 440hz sine wave
+fn sine(channels: u16) -> Audio {
+    let samples = (0..22050i16)
+        .flat_map(|i| {
+            let value = (f32::from(i16::MAX)
+                * 0.5
+                * (f32::from(i) * 440.0 * std::f32::consts::TAU / 22050.0).sin())
+                as i16;
+            std::iter::repeat_n(value, channels as usize)
+        })
+        .collect();
+    Audio::new(channels, 22050, samples)
+}
+
+#[test]
+fn read_wav() {
+    let mut wav = Vec::new();
+    {
+        let mut writer = hound::WavWriter::new(
+            std::io::Cursor::new(&mut wav),
+            hound::WavSpec {
+                channels: 1,
+                sample_rate: 44100,
+                bits_per_sample: 8,
+                sample_format: hound::SampleFormat::Int,
+            },
+        )
+        .unwrap();
+        for sample in [0i8, 64, -64, 127] {
+            writer.write_sample(sample).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+    let audio = Audio::read_wav(std::io::Cursor::new(wav)).unwrap();
+    assert_eq!(audio.channels(), 1);
+    assert_eq!(audio.sample_rate(), 44100);
+    assert_eq!(audio.samples(), &[0, 16384, -16384, 32512]);
+}
+
+#[test]
+fn wss_roundtrip() {
+    let audio = sine(2);
+    let mut wss = Vec::new();
+    audio.write_wss(&mut wss).unwrap();
+    assert_eq!(&wss[..4], b"WSS0");
+    assert_eq!(wss.len(), 4 + 4 + 18 + 22050 * 2 * 2);
+    assert_eq!(Audio::read_wss(std::io::Cursor::new(wss)).unwrap(), audio);
+}
+
+#[test]
+fn write_ogg() {
+    let audio = sine(1);
+    let mut ogg = Vec::new();
+    audio.write_ogg(64, &mut ogg).unwrap();
+    assert_eq!(&ogg[..4], b"OggS");
+    assert!(ogg.len() < audio.samples().len() * 2);
+    let mut again = Vec::new();
+    audio.write_ogg(64, &mut again).unwrap();
+    assert_eq!(ogg, again);
+}
+
+#[test]
+fn write_ogg_no_bitrate() {
+    assert!(sine(1).write_ogg(0, Vec::new()).is_err());
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+/// Bitrate of `.ogg` files when none is set, in kbps
+const DEFAULT_BITRATE: u32 = 96;
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+/// Audio conversion config
+pub struct AudioConfig {
+    /// Is conversion of `.wav` files enabled
+    enabled: bool,
+    /// Target bitrate of `.ogg` files, in kbps
+    bitrate: u32,
+    /// Files to write as `.wss` instead of `.ogg`
+    wss: Vec<String>,
+    /// Files to exclude from conversion
+    exclude: Vec<String>,
+}
+
+impl AudioConfig {
+    #[must_use]
+    /// Is conversion of `.wav` files enabled
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[must_use]
+    /// Target bitrate of `.ogg` files, in kbps
+    pub const fn bitrate(&self) -> u32 {
+        self.bitrate
+    }
+
+    #[must_use]
+    /// Files to write as `.wss` instead of `.ogg`
+    pub const fn wss(&self) -> &Vec<String> {
+        &self.wss
+    }
+
+    #[must_use]
+    /// Files to exclude from conversion
+    pub const fn exclude(&self) -> &Vec<String> {
+        &self.exclude
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// Section of the addon.toml file for audio conversion
+pub struct AudioSectionFile {
+    #[serde(default)]
+    /// Is conversion of `.wav` files enabled
+    pub enabled: Option<bool>,
+    #[serde(default)]
+    /// Target bitrate of `.ogg` files, in kbps
+    pub bitrate: Option<u32>,
+    #[serde(default)]
+    /// Files to write as `.wss` instead of `.ogg`
+    pub wss: Vec<String>,
+    #[serde(default)]
+    /// Files to exclude from conversion
+    pub exclude: Vec<String>,
+}
+
+impl From<AudioSectionFile> for AudioConfig {
+    fn from(file: AudioSectionFile) -> Self {
+        Self {
+            enabled: file.enabled.unwrap_or(false),
+            bitrate: file.bitrate.unwrap_or(DEFAULT_BITRATE),
+            wss: file.wss,
+            exclude: file.exclude,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_defined() {
+        let toml = r#"
+enabled = true
+bitrate = 160
+wss = ["sounds/radio/*"]
+exclude = ["sounds/source/*"]
+"#;
+        let file: AudioSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = AudioConfig::from(file);
+        assert!(config.enabled());
+        assert_eq!(config.bitrate(), 160);
+        assert_eq!(config.wss(), &["sounds/radio/*"]);
+        assert_eq!(config.exclude(), &["sounds/source/*"]);
+    }
+
+    #[test]
+    fn empty() {
+        let toml = "";
+        let file: AudioSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = AudioConfig::from(file);
+        assert!(!config.enabled());
+        assert_eq!(config.bitrate(), DEFAULT_BITRATE);
+        assert!(config.wss().is_empty());
+        assert!(config.exclude().is_empty());
+    }
+}
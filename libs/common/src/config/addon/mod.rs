@@ -1,6 +1,9 @@
+mod audio;
 mod binarize;
 mod files;
+mod paa;
 mod rapify;
+mod sqf;
 
 use std::{collections::HashMap, sync::Once};
 
@@ -8,7 +11,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::Error;
 
-use super::deprecated;
+use super::{
+    deprecated,
+    project::lint::{LintGroupConfig, LintSectionFile},
+};
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone)]
@@ -24,6 +30,18 @@ pub struct AddonConfig {
 
     /// Files to exclude from the pbo
     files: files::FilesConfig,
+
+    /// PAA conversion config
+    paa: paa::PaaConfig,
+
+    /// Audio conversion config
+    audio: audio::AudioConfig,
+
+    /// SQF compilation config
+    sqf: sqf::SqfConfig,
+
+    /// Lint overrides for the addon
+    lints: LintGroupConfig,
 }
 
 impl AddonConfig {
@@ -51,6 +69,30 @@ impl AddonConfig {
         &self.files
     }
 
+    #[must_use]
+    /// PAA conversion config
+    pub const fn paa(&self) -> &paa::PaaConfig {
+        &self.paa
+    }
+
+    #[must_use]
+    /// Audio conversion config
+    pub const fn audio(&self) -> &audio::AudioConfig {
+        &self.audio
+    }
+
+    #[must_use]
+    /// SQF compilation config
+    pub const fn sqf(&self) -> &sqf::SqfConfig {
+        &self.sqf
+    }
+
+    #[must_use]
+    /// Lint overrides for the addon, applied on top of the project's
+    pub const fn lints(&self) -> &LintGroupConfig {
+        &self.lints
+    }
+
     /// Load a configuration from a file.
     ///
     /// # Errors
@@ -83,6 +125,18 @@ pub struct AddonFile {
 
     #[serde(default)]
     files: files::FilesSectionFile,
+
+    #[serde(default)]
+    paa: paa::PaaSectionFile,
+
+    #[serde(default)]
+    audio: audio::AudioSectionFile,
+
+    #[serde(default)]
+    sqf: sqf::SqfSectionFile,
+
+    #[serde(default)]
+    lints: LintSectionFile,
 }
 
 static DEPRECATION: Once = Once::new();
@@ -134,6 +188,10 @@ impl From<AddonFile> for AddonConfig {
                 files.exclude_mut().extend(file.exclude);
                 files
             },
+            paa: file.paa.into(),
+            audio: file.audio.into(),
+            sqf: file.sqf.into(),
+            lints: file.lints.into(),
         }
     }
 }
@@ -157,6 +215,17 @@ test = "test"
 [files]
 exclude = ["test"]
 
+[paa]
+enabled = true
+
+[audio]
+enabled = true
+
+[sqf]
+enabled = false
+
+[lints.config]
+invalid_value = "Warning"
 "#;
         let file: AddonFile = toml::from_str(toml).expect("failed to deserialize");
         let config = AddonConfig::from(file);
@@ -164,6 +233,10 @@ exclude = ["test"]
         assert!(config.binarize().enabled());
         assert_eq!(config.properties().get("test"), Some(&"test".to_string()));
         assert_eq!(config.files().exclude(), &["test"]);
+        assert!(config.paa().enabled());
+        assert!(config.audio().enabled());
+        assert!(!config.sqf().enabled());
+        assert!(config.lints().config().contains_key("invalid_value"));
     }
 
     #[test]
@@ -175,6 +248,10 @@ exclude = ["test"]
         assert!(config.binarize().enabled());
         assert!(config.properties().is_empty());
         assert!(config.files().exclude().is_empty());
+        assert!(!config.paa().enabled());
+        assert!(!config.audio().enabled());
+        assert!(config.sqf().enabled());
+        assert!(config.lints().is_empty());
     }
 
     #[test]
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+/// PAA conversion config
+pub struct PaaConfig {
+    /// Is conversion of `.png` and `.tga` files enabled
+    enabled: bool,
+    /// Files to exclude from conversion
+    exclude: Vec<String>,
+}
+
+impl PaaConfig {
+    #[must_use]
+    /// Is conversion of `.png` and `.tga` files enabled
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[must_use]
+    /// Files to exclude from conversion
+    pub const fn exclude(&self) -> &Vec<String> {
+        &self.exclude
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// Section of the addon.toml file for PAA conversion
+pub struct PaaSectionFile {
+    #[serde(default)]
+    /// Is conversion of `.png` and `.tga` files enabled
+    pub enabled: Option<bool>,
+    #[serde(default)]
+    /// Files to exclude from conversion
+    pub exclude: Vec<String>,
+}
+
+impl From<PaaSectionFile> for PaaConfig {
+    fn from(file: PaaSectionFile) -> Self {
+        Self {
+            enabled: file.enabled.unwrap_or(false),
+            exclude: file.exclude,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_defined() {
+        let toml = r#"
+enabled = true
+exclude = ["data/source/*"]
+"#;
+        let file: PaaSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = PaaConfig::from(file);
+        assert!(config.enabled());
+        assert_eq!(config.exclude(), &["data/source/*"]);
+    }
+
+    #[test]
+    fn empty() {
+        let toml = "";
+        let file: PaaSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = PaaConfig::from(file);
+        assert!(!config.enabled());
+        assert!(config.exclude().is_empty());
+    }
+}
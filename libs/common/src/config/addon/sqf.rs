@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+/// SQF compilation config
+pub struct SqfConfig {
+    enabled: bool,
+    exclude: Vec<String>,
+}
+
+impl SqfConfig {
+    #[must_use]
+    /// Is compiling SQF to `.sqfc` enabled
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[must_use]
+    /// Files to exclude from compilation
+    pub const fn exclude(&self) -> &Vec<String> {
+        &self.exclude
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// Section of the addon.toml file for SQF compilation
+pub struct SqfSectionFile {
+    #[serde(default)]
+    enabled: Option<bool>,
+    #[serde(default)]
+    exclude: Vec<String>,
+}
+
+impl From<SqfSectionFile> for SqfConfig {
+    fn from(file: SqfSectionFile) -> Self {
+        Self {
+            enabled: file.enabled.unwrap_or(true),
+            exclude: file.exclude,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_defined() {
+        let toml = r#"
+enabled = false
+exclude = ["functions/fnc_patched.sqf"]
+"#;
+        let file: SqfSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = SqfConfig::from(file);
+        assert!(!config.enabled());
+        assert_eq!(config.exclude(), &["functions/fnc_patched.sqf"]);
+    }
+
+    #[test]
+    fn default() {
+        let toml = "";
+        let file: SqfSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = SqfConfig::from(file);
+        assert!(config.enabled());
+        assert!(config.exclude().is_empty());
+    }
+}
@@ -2,19 +2,26 @@
 
 //! Read the project configuration into a [`ProjectConfig`] struct
 
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Serialize, Serializer};
 use tracing::warn;
 
 mod addon;
 mod pdrive;
 mod project;
+mod workspace;
 
 pub use addon::AddonConfig;
 pub use pdrive::PDriveOption;
 pub use project::{
-    hemtt::launch::LaunchOptions,
-    lint::{LintConfig, LintConfigOverride},
+    dependencies::DependenciesConfig,
+    hemtt::{check::FailOn, launch::LaunchOptions},
+    lint::{CustomLintConfig, LintConfig, LintConfigOverride, LintGroupConfig},
+    schema::SchemaSource,
     ProjectConfig,
 };
+pub use workspace::{WorkspaceConfig, WorkspaceProject, WORKSPACE_FILE};
 
 fn deprecated(file: &str, key: &str, replacement: &str, info: Option<&str>) {
     warn!(
@@ -25,3 +32,12 @@ fn deprecated(file: &str, key: &str, replacement: &str, info: Option<&str>) {
         info.map(|i| format!("\n  {i}")).unwrap_or_default()
     );
 }
+
+/// Serialize a map with sorted keys, so the output is stable between runs
+fn sorted<S, V>(map: &HashMap<String, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: Serialize,
+{
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
@@ -33,7 +33,7 @@ impl Serialize for PDriveOption {
         match self {
             Self::Disallow => serializer.serialize_str("disallow"),
             Self::Ignore => serializer.serialize_str("ignore"),
-            Self::Require => serializer.serialize_str("required"),
+            Self::Require => serializer.serialize_str("require"),
         }
     }
 }
@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+/// External mods the project depends on
+pub struct DependenciesConfig {
+    /// The `CfgPatches` classes of each mod, by the name of the mod
+    mods: BTreeMap<String, Vec<String>>,
+}
+
+impl DependenciesConfig {
+    #[must_use]
+    /// Are any mods declared
+    pub fn is_empty(&self) -> bool {
+        self.mods.is_empty()
+    }
+
+    #[must_use]
+    /// The `CfgPatches` classes of each mod, by the name of the mod
+    pub const fn mods(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.mods
+    }
+
+    #[must_use]
+    /// The mod that declares a `CfgPatches` class
+    ///
+    /// A patch ending with `*` matches any class starting with the rest of it
+    pub fn declared_by(&self, patch: &str) -> Option<&str> {
+        let patch = patch.to_lowercase();
+        self.mods.iter().find_map(|(name, patches)| {
+            patches
+                .iter()
+                .any(|declared| {
+                    let declared = declared.to_lowercase();
+                    declared
+                        .strip_suffix('*')
+                        .map_or(declared == patch, |start| patch.starts_with(start))
+                })
+                .then_some(name.as_str())
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(PartialEq, Eq, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DependencySectionFile {
+    #[serde(default)]
+    /// The `CfgPatches` classes of the mod
+    patches: Vec<String>,
+}
+
+impl From<BTreeMap<String, DependencySectionFile>> for DependenciesConfig {
+    fn from(file: BTreeMap<String, DependencySectionFile>) -> Self {
+        Self {
+            mods: file
+                .into_iter()
+                .map(|(name, section)| (name, section.patches))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_defined() {
+        let toml = r#"
+[cba]
+patches = ["cba_main", "cba_xeh"]
+
+[ace]
+patches = ["ace_*"]
+"#;
+        let file: BTreeMap<String, DependencySectionFile> =
+            toml::from_str(toml).expect("failed to deserialize");
+        let config = DependenciesConfig::from(file);
+        assert!(!config.is_empty());
+        assert_eq!(config.mods()["cba"], ["cba_main", "cba_xeh"]);
+        assert_eq!(config.declared_by("CBA_Main"), Some("cba"));
+        assert_eq!(config.declared_by("ace_medical"), Some("ace"));
+        assert_eq!(config.declared_by("cba_mian"), None);
+        assert_eq!(config.declared_by("acex_main"), None);
+    }
+
+    #[test]
+    fn default() {
+        let toml = "";
+        let file: BTreeMap<String, DependencySectionFile> =
+            toml::from_str(toml).expect("failed to deserialize");
+        let config = DependenciesConfig::from(file);
+        assert!(config.is_empty());
+        assert_eq!(config.declared_by("cba_main"), None);
+    }
+}
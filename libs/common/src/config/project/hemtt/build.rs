@@ -1,13 +1,25 @@
 use serde::{Deserialize, Serialize};
 
-use crate::config::pdrive::PDriveOption;
+use crate::{config::pdrive::PDriveOption, error::Error};
 
-#[allow(clippy::module_name_repetitions)]
-#[derive(PartialEq, Eq, Debug, Clone)]
+/// Extensions that are never compressed unless `exclude` is set,
+/// they are already compressed or are read directly by the game
+pub const DEFAULT_COMPRESSION_EXCLUDE: [&str; 12] = [
+    "bin", "jpg", "lip", "ogg", "p3d", "paa", "pac", "png", "rtm", "wav", "wrp", "wss",
+];
+
+#[allow(clippy::module_name_repetitions, clippy::struct_excessive_bools)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
 /// Configuration for `hemtt build`
 pub struct BuildOptions {
     optional_mod_folders: bool,
     pdrive: PDriveOption,
+    compression: CompressionOptions,
+    sqf_optimize: bool,
+    sqf_debug_info: bool,
+    sqf_keep_original: bool,
+    sqfc_version: Option<u32>,
+    binarize_wrapper: Option<String>,
 }
 
 impl BuildOptions {
@@ -20,6 +32,41 @@ impl BuildOptions {
     pub const fn pdrive(&self) -> &PDriveOption {
         &self.pdrive
     }
+
+    /// How files are compressed inside PBOs
+    pub const fn compression(&self) -> &CompressionOptions {
+        &self.compression
+    }
+
+    /// Should SQF be optimized before it is compiled?
+    /// Defaults to true
+    pub const fn sqf_optimize(&self) -> bool {
+        self.sqf_optimize
+    }
+
+    /// Should a `.sqfc.map` be written next to each `.sqfc`?
+    /// Defaults to false
+    pub const fn sqf_debug_info(&self) -> bool {
+        self.sqf_debug_info
+    }
+
+    /// Should the original `.sqf` be packed next to its `.sqfc`?
+    /// Defaults to true
+    pub const fn sqf_keep_original(&self) -> bool {
+        self.sqf_keep_original
+    }
+
+    /// Revision of the `.sqfc` format to compile to, for older game branches
+    /// Defaults to the latest revision if `None`
+    pub const fn sqfc_version(&self) -> Option<u32> {
+        self.sqfc_version
+    }
+
+    /// Command used to run the Arma 3 Tools on Linux, `proton` to use Proton
+    /// Detected automatically if `None`
+    pub fn binarize_wrapper(&self) -> Option<&str> {
+        self.binarize_wrapper.as_deref()
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -30,14 +77,146 @@ pub struct BuildOptionsFile {
     optional_mod_folders: Option<bool>,
     #[serde(default)]
     pdrive: Option<PDriveOption>,
+    #[serde(default)]
+    compression: CompressionOptionsFile,
+    #[serde(default)]
+    sqf_optimize: Option<bool>,
+    #[serde(default)]
+    sqf_debug_info: Option<bool>,
+    #[serde(default)]
+    sqf_keep_original: Option<bool>,
+    #[serde(default)]
+    sqfc_version: Option<u32>,
+    #[serde(default)]
+    binarize_wrapper: Option<String>,
 }
 
-impl From<BuildOptionsFile> for BuildOptions {
-    fn from(file: BuildOptionsFile) -> Self {
-        Self {
+impl TryFrom<BuildOptionsFile> for BuildOptions {
+    type Error = Error;
+
+    fn try_from(file: BuildOptionsFile) -> Result<Self, Self::Error> {
+        Ok(Self {
             optional_mod_folders: file.optional_mod_folders.unwrap_or(true),
             pdrive: file.pdrive.unwrap_or_default(),
+            compression: file.compression.try_into()?,
+            sqf_optimize: file.sqf_optimize.unwrap_or(true),
+            sqf_debug_info: file.sqf_debug_info.unwrap_or(false),
+            sqf_keep_original: file.sqf_keep_original.unwrap_or(true),
+            sqfc_version: file.sqfc_version,
+            binarize_wrapper: file
+                .binarize_wrapper
+                .map(|wrapper| wrapper.trim().to_string())
+                .filter(|wrapper| !wrapper.is_empty()),
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+/// Configuration for compressing files inside PBOs
+pub struct CompressionOptions {
+    enabled: bool,
+    min_size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include: Option<Vec<String>>,
+    exclude: Vec<String>,
+}
+
+impl CompressionOptions {
+    /// Is compression enabled?
+    /// Defaults to false
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Files smaller than this many bytes are not compressed
+    /// Defaults to 1024
+    pub const fn min_size(&self) -> u64 {
+        self.min_size
+    }
+
+    /// Extensions to compress, all extensions not excluded if `None`
+    pub const fn include(&self) -> Option<&Vec<String>> {
+        self.include.as_ref()
+    }
+
+    /// Extensions that are never compressed
+    /// Defaults to [`DEFAULT_COMPRESSION_EXCLUDE`]
+    pub fn exclude(&self) -> &[String] {
+        &self.exclude
+    }
+
+    #[must_use]
+    /// Should a file be compressed
+    pub fn should_compress(&self, filename: &str, size: u64) -> bool {
+        if !self.enabled || size < self.min_size {
+            return false;
+        }
+        let filename = filename.rsplit(['\\', '/']).next().unwrap_or(filename);
+        let Some((_, extension)) = filename.rsplit_once('.') else {
+            return self.include.is_none();
+        };
+        let extension = extension.to_lowercase();
+        if self.exclude.contains(&extension) {
+            return false;
+        }
+        self.include
+            .as_ref()
+            .is_none_or(|include| include.contains(&extension))
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Default, Clone, Serialize, Deserialize)]
+/// Compression specific configuration
+pub struct CompressionOptionsFile {
+    #[serde(default)]
+    enabled: Option<bool>,
+    #[serde(default)]
+    min_size: Option<u64>,
+    #[serde(default)]
+    include: Option<Vec<String>>,
+    #[serde(default)]
+    exclude: Option<Vec<String>>,
+}
+
+impl TryFrom<CompressionOptionsFile> for CompressionOptions {
+    type Error = Error;
+
+    fn try_from(file: CompressionOptionsFile) -> Result<Self, Self::Error> {
+        fn normalize(extensions: Vec<String>) -> Result<Vec<String>, Error> {
+            extensions
+                .into_iter()
+                .map(|ext| {
+                    let ext = ext.trim().trim_start_matches('.').to_lowercase();
+                    if ext.is_empty() || ext.contains(['/', '\\', '*']) {
+                        Err(Error::ConfigInvalid(format!(
+                            "`hemtt.build.compression` contains an invalid extension `{ext}`, extensions should be written like `sqf`"
+                        )))
+                    } else {
+                        Ok(ext)
+                    }
+                })
+                .collect()
         }
+        let include = file.include.map(normalize).transpose()?;
+        let exclude = file.exclude.map(normalize).transpose()?.unwrap_or_else(|| {
+            DEFAULT_COMPRESSION_EXCLUDE
+                .iter()
+                .map(std::string::ToString::to_string)
+                .collect()
+        });
+        if let Some(include) = &include {
+            if let Some(ext) = include.iter().find(|ext| exclude.contains(ext)) {
+                return Err(Error::ConfigInvalid(format!(
+                    "`hemtt.build.compression` has `{ext}` in both `include` and `exclude`, `exclude` defaults to {DEFAULT_COMPRESSION_EXCLUDE:?}"
+                )));
+            }
+        }
+        Ok(Self {
+            enabled: file.enabled.unwrap_or(false),
+            min_size: file.min_size.unwrap_or(1024),
+            include,
+            exclude,
+        })
     }
 }
 
@@ -50,19 +229,90 @@ mod tests {
         let toml = r#"
 optional_mod_folders = false
 pdrive = "disallow"
+sqf_optimize = false
+sqf_debug_info = true
+sqf_keep_original = false
+sqfc_version = 1
+binarize_wrapper = "wine"
+
+[compression]
+enabled = true
+min_size = 16
+include = [".SQF", "hpp"]
+exclude = ["ogg"]
 "#;
         let file: BuildOptionsFile = toml::from_str(toml).expect("failed to deserialize");
-        let config = BuildOptions::from(file);
+        let config = BuildOptions::try_from(file).expect("valid config");
         assert!(!config.optional_mod_folders());
         assert_eq!(config.pdrive(), &PDriveOption::Disallow);
+        assert!(!config.sqf_optimize());
+        assert!(config.sqf_debug_info());
+        assert!(!config.sqf_keep_original());
+        assert_eq!(config.sqfc_version(), Some(1));
+        assert_eq!(config.binarize_wrapper(), Some("wine"));
+        let compression = config.compression();
+        assert!(compression.enabled());
+        assert_eq!(compression.min_size(), 16);
+        assert_eq!(
+            compression.include(),
+            Some(&vec!["sqf".to_string(), "hpp".to_string()])
+        );
+        assert_eq!(compression.exclude(), &["ogg".to_string()]);
+        assert!(compression.should_compress("fnc_test.sqf", 16));
+        assert!(!compression.should_compress("fnc_test.sqf", 15));
+        assert!(!compression.should_compress("config.bin", 2048));
     }
 
     #[test]
     fn default() {
         let toml = "";
         let file: BuildOptionsFile = toml::from_str(toml).expect("failed to deserialize");
-        let config = BuildOptions::from(file);
+        let config = BuildOptions::try_from(file).expect("valid config");
         assert!(config.optional_mod_folders());
         assert_eq!(config.pdrive(), &PDriveOption::Ignore);
+        assert!(config.sqf_optimize());
+        assert!(!config.sqf_debug_info());
+        assert!(config.sqf_keep_original());
+        assert_eq!(config.sqfc_version(), None);
+        assert_eq!(config.binarize_wrapper(), None);
+        assert!(!config.compression().enabled());
+        assert!(!config.compression().should_compress("fnc_test.sqf", 4096));
+    }
+
+    #[test]
+    fn compression_default_exclude() {
+        let toml = r"
+[compression]
+enabled = true
+";
+        let file: BuildOptionsFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = BuildOptions::try_from(file).expect("valid config");
+        let compression = config.compression();
+        assert!(compression.should_compress("fnc_test.sqf", 4096));
+        assert!(compression.should_compress("data\\readme", 4096));
+        assert!(!compression.should_compress("data\\texture_co.PAA", 4096));
+        assert!(!compression.should_compress("sounds\\music.ogg", 4096));
+        assert!(!compression.should_compress("fnc_test.sqf", 100));
+    }
+
+    #[test]
+    fn compression_conflict() {
+        let toml = r#"
+[compression]
+enabled = true
+include = ["paa"]
+"#;
+        let file: BuildOptionsFile = toml::from_str(toml).expect("failed to deserialize");
+        assert!(BuildOptions::try_from(file).is_err());
+    }
+
+    #[test]
+    fn compression_invalid_extension() {
+        let toml = r#"
+[compression]
+exclude = ["*.ogg"]
+"#;
+        let file: BuildOptionsFile = toml::from_str(toml).expect("failed to deserialize");
+        assert!(BuildOptions::try_from(file).is_err());
     }
 }
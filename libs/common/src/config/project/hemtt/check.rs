@@ -3,10 +3,11 @@ use serde::{Deserialize, Serialize};
 use crate::config::pdrive::PDriveOption;
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
 /// Configuration for `hemtt check`
 pub struct CheckOptions {
     pdrive: PDriveOption,
+    fail_on: FailOn,
 }
 
 impl CheckOptions {
@@ -14,6 +15,11 @@ impl CheckOptions {
     pub const fn pdrive(&self) -> &PDriveOption {
         &self.pdrive
     }
+
+    /// Which severity makes `check`, `build`, and `release` exit with a failure
+    pub const fn fail_on(&self) -> FailOn {
+        self.fail_on
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -22,16 +28,30 @@ impl CheckOptions {
 pub struct CheckOptionsFile {
     #[serde(default)]
     pdrive: Option<PDriveOption>,
+    #[serde(default)]
+    fail_on: Option<FailOn>,
 }
 
 impl From<CheckOptionsFile> for CheckOptions {
     fn from(file: CheckOptionsFile) -> Self {
         Self {
             pdrive: file.pdrive.unwrap_or_default(),
+            fail_on: file.fail_on.unwrap_or_default(),
         }
     }
 }
 
+#[derive(Default, PartialEq, Eq, Debug, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// The lowest severity that fails a command
+pub enum FailOn {
+    /// Fail on any warning or error
+    Warnings,
+    /// Only fail on errors
+    #[default]
+    Errors,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,10 +60,12 @@ mod tests {
     fn fully_defined() {
         let toml = r#"
 pdrive = "disallow"
+fail_on = "warnings"
 "#;
         let file: CheckOptionsFile = toml::from_str(toml).expect("failed to deserialize");
         let config = CheckOptions::from(file);
         assert_eq!(config.pdrive(), &PDriveOption::Disallow);
+        assert_eq!(config.fail_on(), FailOn::Warnings);
     }
 
     #[test]
@@ -52,5 +74,14 @@ pdrive = "disallow"
         let file: CheckOptionsFile = toml::from_str(toml).expect("failed to deserialize");
         let config = CheckOptions::from(file);
         assert_eq!(config.pdrive(), &PDriveOption::Ignore);
+        assert_eq!(config.fail_on(), FailOn::Errors);
+    }
+
+    #[test]
+    fn invalid_fail_on() {
+        let toml = r#"
+fail_on = "helps"
+"#;
+        assert!(toml::from_str::<CheckOptionsFile>(toml).is_err());
     }
 }
@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 /// Configuration for `hemtt dev`
 pub struct DevOptions {
     exclude: Vec<String>,
+    link_to: Vec<String>,
+    defines: Vec<String>,
 }
 
 impl DevOptions {
@@ -12,6 +14,17 @@ impl DevOptions {
     pub fn exclude(&self) -> &[String] {
         &self.exclude
     }
+
+    /// Folders to link to the dev build, such as a folder in the Arma 3 directory
+    pub fn link_to(&self) -> &[String] {
+        &self.link_to
+    }
+
+    /// Macros defined for every file preprocessed by `hemtt dev` and `hemtt launch`,
+    /// as `NAME` or `NAME=VALUE`
+    pub fn defines(&self) -> &[String] {
+        &self.defines
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -20,12 +33,18 @@ impl DevOptions {
 pub struct DevOptionsFile {
     #[serde(default)]
     exclude: Vec<String>,
+    #[serde(default)]
+    link_to: Vec<String>,
+    #[serde(default)]
+    defines: Vec<String>,
 }
 
 impl From<DevOptionsFile> for DevOptions {
     fn from(file: DevOptionsFile) -> Self {
         Self {
             exclude: file.exclude,
+            link_to: file.link_to,
+            defines: super::defines(file.defines),
         }
     }
 }
@@ -38,10 +57,14 @@ mod tests {
     fn fully_defined() {
         let toml = r#"
 exclude = ["test"]
+link_to = ["C:/Arma 3/@test"]
+defines = ["DEBUG", " DEBUG_SYNCHRONOUS "]
 "#;
         let file: DevOptionsFile = toml::from_str(toml).expect("failed to deserialize");
         let config = DevOptions::from(file);
         assert_eq!(config.exclude(), &["test"]);
+        assert_eq!(config.link_to(), &["C:/Arma 3/@test"]);
+        assert_eq!(config.defines(), &["DEBUG", "DEBUG_SYNCHRONOUS"]);
     }
 
     #[test]
@@ -50,5 +73,7 @@ exclude = ["test"]
         let file: DevOptionsFile = toml::from_str(toml).expect("failed to deserialize");
         let config = DevOptions::from(file);
         assert!(config.exclude().is_empty());
+        assert!(config.link_to().is_empty());
+        assert!(config.defines().is_empty());
     }
 }
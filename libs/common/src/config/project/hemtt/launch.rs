@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
 
 use crate::arma::dlc::DLC;
 
@@ -38,6 +38,15 @@ pub struct LaunchOptions {
 
     // Should HEMTT rapify
     rapify: Option<bool>,
+
+    /// Arma 3 profiles to use, one for each client instance
+    profiles: Vec<String>,
+
+    // Should HEMTT launch a dedicated server for the clients to connect to
+    server: Option<bool>,
+
+    /// Number of headless clients to connect to the dedicated server
+    headless: Option<u8>,
 }
 
 impl LaunchOptions {
@@ -123,6 +132,26 @@ impl LaunchOptions {
         self.rapify.unwrap_or(true)
     }
 
+    #[must_use]
+    /// Arma 3 profiles to use, one for each client instance
+    pub fn profiles(&self) -> &[String] {
+        &self.profiles
+    }
+
+    #[must_use]
+    /// Should HEMTT launch a dedicated server for the clients to connect to
+    /// Defaults to `false`
+    pub fn server(&self) -> bool {
+        self.server.unwrap_or(false) || self.headless() > 0
+    }
+
+    #[must_use]
+    /// Number of headless clients to connect to the dedicated server
+    /// Defaults to 0, launching any implies `server`
+    pub fn headless(&self) -> u8 {
+        self.headless.unwrap_or(0)
+    }
+
     #[must_use]
     /// Overlay two launch options  
     /// Other will take precedence
@@ -133,6 +162,7 @@ impl LaunchOptions {
         base.presets.extend(other.presets);
         base.optionals.extend(other.optionals);
         base.parameters.extend(other.parameters);
+        base.profiles.extend(other.profiles);
         if let Some(executable) = other.executable {
             base.executable = Some(executable);
         }
@@ -151,10 +181,45 @@ impl LaunchOptions {
         if let Some(rapify) = other.rapify {
             base.rapify = Some(rapify);
         }
+        if let Some(server) = other.server {
+            base.server = Some(server);
+        }
+        if let Some(headless) = other.headless {
+            base.headless = Some(headless);
+        }
         base
     }
 }
 
+impl Serialize for LaunchOptions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // Defaults are resolved, so the output shows what `hemtt launch` will use
+        let mut state = serializer.serialize_struct("LaunchOptions", 14)?;
+        state.serialize_field("workshop", &self.workshop)?;
+        state.serialize_field("dlc", &self.dlc)?;
+        state.serialize_field("presets", &self.presets)?;
+        state.serialize_field("optionals", &self.optionals)?;
+        if let Some(mission) = &self.mission {
+            state.serialize_field("mission", mission)?;
+        } else {
+            state.skip_field("mission")?;
+        }
+        state.serialize_field("parameters", &self.parameters)?;
+        state.serialize_field("executable", &self.executable())?;
+        state.serialize_field("binarize", &self.binarize())?;
+        state.serialize_field("file_patching", &self.file_patching())?;
+        state.serialize_field("instances", &self.instances())?;
+        state.serialize_field("rapify", &self.rapify())?;
+        state.serialize_field("profiles", &self.profiles)?;
+        state.serialize_field("server", &self.server())?;
+        state.serialize_field("headless", &self.headless())?;
+        state.end()
+    }
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(PartialEq, Eq, Debug, Default, Clone, Serialize, Deserialize)]
 /// Launch specific configuration
@@ -194,6 +259,15 @@ pub struct LaunchOptionsFile {
 
     #[serde(default)]
     rapify: Option<bool>,
+
+    #[serde(default)]
+    profiles: Vec<String>,
+
+    #[serde(default)]
+    server: Option<bool>,
+
+    #[serde(default)]
+    headless: Option<u8>,
 }
 
 impl LaunchOptionsFile {
@@ -204,6 +278,7 @@ impl LaunchOptionsFile {
         other.presets.extend(self.presets);
         other.optionals.extend(self.optionals);
         other.parameters.extend(self.parameters);
+        other.profiles.extend(self.profiles);
         if let Some(executable) = self.executable {
             other.executable = Some(executable);
         }
@@ -222,6 +297,12 @@ impl LaunchOptionsFile {
         if let Some(rapify) = self.rapify {
             other.rapify = Some(rapify);
         }
+        if let Some(server) = self.server {
+            other.server = Some(server);
+        }
+        if let Some(headless) = self.headless {
+            other.headless = Some(headless);
+        }
         other
     }
 
@@ -253,6 +334,9 @@ impl From<LaunchOptionsFile> for LaunchOptions {
             file_patching: file.file_patching,
             instances: file.instances,
             rapify: file.rapify,
+            profiles: file.profiles,
+            server: file.server,
+            headless: file.headless,
         }
     }
 }
@@ -275,6 +359,9 @@ binarize = true
 file_patching = false
 instances = 2
 rapify = false
+profiles = ["alpha", "bravo"]
+server = true
+headless = 2
 "#;
         let file: LaunchOptionsFile = toml::from_str(toml).expect("failed to deserialize");
         let config = LaunchOptions::from(file);
@@ -289,6 +376,9 @@ rapify = false
         assert!(!config.file_patching());
         assert_eq!(config.instances(), 2);
         assert!(!config.rapify());
+        assert_eq!(config.profiles(), &["alpha", "bravo"]);
+        assert!(config.server());
+        assert_eq!(config.headless(), 2);
     }
 
     #[test]
@@ -307,5 +397,8 @@ rapify = false
         assert!(config.file_patching());
         assert_eq!(config.instances(), 1);
         assert!(config.rapify());
+        assert!(config.profiles().is_empty());
+        assert!(!config.server());
+        assert_eq!(config.headless(), 0);
     }
 }
@@ -2,6 +2,8 @@ pub mod build;
 pub mod check;
 pub mod dev;
 pub mod launch;
+pub mod preprocessor;
+pub mod publish;
 pub mod release;
 
 use std::{collections::HashMap, path::Path};
@@ -12,18 +14,23 @@ use serde::{Deserialize, Serialize};
 use crate::Error;
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 /// Configure HEMTT commands
 pub struct HemttConfig {
     check: check::CheckOptions,
 
     dev: dev::DevOptions,
 
+    #[serde(serialize_with = "crate::config::sorted")]
     launch: HashMap<String, launch::LaunchOptions>,
 
     build: build::BuildOptions,
 
+    preprocessor: preprocessor::PreprocessorOptions,
+
     release: release::ReleaseOptions,
+
+    publish: publish::PublishOptions,
 }
 
 impl HemttConfig {
@@ -47,10 +54,20 @@ impl HemttConfig {
         &self.build
     }
 
+    /// Get the preprocessor options
+    pub const fn preprocessor(&self) -> &preprocessor::PreprocessorOptions {
+        &self.preprocessor
+    }
+
     /// Get the release options
     pub const fn release(&self) -> &release::ReleaseOptions {
         &self.release
     }
+
+    /// Get the publish options
+    pub const fn publish(&self) -> &publish::PublishOptions {
+        &self.publish
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -69,8 +86,14 @@ pub struct HemttSectionFile {
     #[serde(default)]
     build: build::BuildOptionsFile,
 
+    #[serde(default)]
+    preprocessor: preprocessor::PreprocessorOptionsFile,
+
     #[serde(default)]
     release: release::ReleaseOptionsFile,
+
+    #[serde(default)]
+    publish: publish::PublishOptionsFile,
 }
 
 impl HemttSectionFile {
@@ -120,12 +143,23 @@ impl HemttSectionFile {
                     })
                     .collect::<Result<_, _>>()?
             },
-            build: self.build.into(),
+            build: self.build.try_into()?,
+            preprocessor: self.preprocessor.into(),
             release: self.release.into_config(prefix),
+            publish: self.publish.into(),
         })
     }
 }
 
+/// Trim the macros defined by a command, ignoring empty entries
+fn defines(defines: Vec<String>) -> Vec<String> {
+    defines
+        .into_iter()
+        .map(|define| define.trim().to_string())
+        .filter(|define| !define.is_empty())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::arma::dlc::DLC;
@@ -200,4 +234,43 @@ mission = "test"
         let config = file.into_config(Path::new("."), "test");
         assert!(config.is_err());
     }
+
+    #[test]
+    fn serialize_resolved() {
+        let toml = r#"
+[launch.base]
+workshop = ["123456"]
+
+[launch.test]
+extends = "base"
+mission = "test"
+
+[launch.alpha]
+instances = 2
+"#;
+        let file: HemttSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = file
+            .into_config(Path::new("."), "test")
+            .expect("failed to convert");
+        let value = toml::Value::try_from(&config).expect("failed to serialize");
+        let test = &value["launch"]["test"];
+        assert_eq!(
+            test["workshop"].as_array().expect("workshop is an array"),
+            &[toml::Value::from("123456")]
+        );
+        assert_eq!(test["mission"].as_str(), Some("test"));
+        assert_eq!(test["executable"].as_str(), Some("arma3_x64.exe"));
+        assert_eq!(test["file_patching"].as_bool(), Some(true));
+        assert_eq!(value["release"]["folder"].as_str(), Some("test"));
+        let output = toml::to_string(&config).expect("failed to serialize");
+        let launch = output
+            .lines()
+            .filter(|line| line.starts_with("[launch."))
+            .collect::<Vec<_>>();
+        assert_eq!(launch, ["[launch.alpha]", "[launch.base]", "[launch.test]"]);
+        assert_eq!(
+            output,
+            toml::to_string(&config).expect("failed to serialize")
+        );
+    }
 }
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Configuration for the preprocessor
+pub struct PreprocessorOptions {
+    include_paths: Vec<String>,
+    #[serde(serialize_with = "crate::config::sorted")]
+    mounts: HashMap<String, String>,
+}
+
+impl PreprocessorOptions {
+    /// Folders searched by `#include` when a file is not found relative to the current file,
+    /// relative to the project root
+    pub fn include_paths(&self) -> &[String] {
+        &self.include_paths
+    }
+
+    /// Folders outside the project mounted at a prefix, such as `\x\cba`,
+    /// relative to the project root
+    pub const fn mounts(&self) -> &HashMap<String, String> {
+        &self.mounts
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(PartialEq, Eq, Debug, Default, Clone, Serialize, Deserialize)]
+/// Preprocessor specific configuration
+pub struct PreprocessorOptionsFile {
+    #[serde(default)]
+    include_paths: Vec<String>,
+    #[serde(default)]
+    mounts: HashMap<String, String>,
+}
+
+impl From<PreprocessorOptionsFile> for PreprocessorOptions {
+    fn from(file: PreprocessorOptionsFile) -> Self {
+        Self {
+            include_paths: file
+                .include_paths
+                .into_iter()
+                .map(|path| path.trim().to_string())
+                .filter(|path| !path.is_empty())
+                .collect(),
+            mounts: file.mounts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_defined() {
+        let toml = r#"
+include_paths = ["include", "../common", " "]
+
+[mounts]
+'\x\cba' = "../CBA_A3"
+'\' = "P:/"
+"#;
+        let file: PreprocessorOptionsFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = PreprocessorOptions::from(file);
+        assert_eq!(config.include_paths(), &["include", "../common"]);
+        assert_eq!(
+            config.mounts().get("\\x\\cba").map(String::as_str),
+            Some("../CBA_A3")
+        );
+        assert_eq!(config.mounts().get("\\").map(String::as_str), Some("P:/"));
+    }
+
+    #[test]
+    fn default() {
+        let toml = "";
+        let file: PreprocessorOptionsFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = PreprocessorOptions::from(file);
+        assert!(config.include_paths().is_empty());
+        assert!(config.mounts().is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Configuration for `hemtt publish`
+pub struct PublishOptions {
+    steamcmd: String,
+    username: Option<String>,
+    changenote: String,
+}
+
+impl PublishOptions {
+    /// Path to `SteamCMD`
+    /// Defaults to `steamcmd`, found on the `PATH`
+    pub fn steamcmd(&self) -> &str {
+        &self.steamcmd
+    }
+
+    /// Steam account to upload with, can also be set with `STEAM_USERNAME`
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    /// Change notes of a workshop update
+    /// `{name}`, `{version}`, and `{changelog}` are replaced with the project's values
+    /// Defaults to `{changelog}`
+    #[allow(clippy::literal_string_with_formatting_args)]
+    pub fn changenote(&self, name: &str, version: &str, changelog: &str) -> String {
+        self.changenote
+            .replace("{name}", name)
+            .replace("{version}", version)
+            .replace("{changelog}", changelog)
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(PartialEq, Eq, Debug, Default, Clone, Serialize, Deserialize)]
+/// Publish specific configuration
+pub struct PublishOptionsFile {
+    #[serde(default)]
+    steamcmd: Option<String>,
+
+    #[serde(default)]
+    username: Option<String>,
+
+    #[serde(default)]
+    changenote: Option<String>,
+}
+
+#[allow(clippy::literal_string_with_formatting_args)] // `{changelog}` is replaced by `changenote`
+impl From<PublishOptionsFile> for PublishOptions {
+    fn from(file: PublishOptionsFile) -> Self {
+        Self {
+            steamcmd: file.steamcmd.unwrap_or_else(|| "steamcmd".to_string()),
+            username: file.username,
+            changenote: file.changenote.unwrap_or_else(|| "{changelog}".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::literal_string_with_formatting_args)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fully_defined() {
+        let toml = r#"
+steamcmd = "C:/steamcmd/steamcmd.exe"
+username = "banana"
+changenote = "{name} {version}\n\n{changelog}"
+"#;
+        let file: PublishOptionsFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = PublishOptions::from(file);
+        assert_eq!(config.steamcmd(), "C:/steamcmd/steamcmd.exe");
+        assert_eq!(config.username(), Some("banana"));
+        assert_eq!(
+            config.changenote("Advanced Banana Environment", "1.3.0", "- Bananas"),
+            "Advanced Banana Environment 1.3.0\n\n- Bananas"
+        );
+    }
+
+    #[test]
+    fn default() {
+        let toml = "";
+        let file: PublishOptionsFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = PublishOptions::from(file);
+        assert_eq!(config.steamcmd(), "steamcmd");
+        assert_eq!(config.username(), None);
+        assert_eq!(
+            config.changenote("Advanced Banana Environment", "1.3.0", "- Bananas"),
+            "- Bananas"
+        );
+    }
+}
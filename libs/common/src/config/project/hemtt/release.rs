@@ -1,12 +1,23 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-#[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions, clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 /// Configuration for `hemtt release`
 pub struct ReleaseOptions {
     folder: String,
     sign: bool,
     archive: bool,
+    archive_name: String,
+    archive_tar: bool,
+    publishedid: Option<u64>,
+    minify: bool,
+    exclude: Vec<String>,
+    rename: BTreeMap<String, String>,
+    github: Option<String>,
+    changelog: String,
+    defines: Vec<String>,
 }
 
 impl ReleaseOptions {
@@ -27,6 +38,62 @@ impl ReleaseOptions {
     pub const fn archive(&self) -> bool {
         self.archive
     }
+
+    /// Name of the versioned archive, without an extension
+    /// `{name}`, `{prefix}`, and `{version}` are replaced with the project's values
+    /// Defaults to `{prefix}-{version}`
+    #[allow(clippy::literal_string_with_formatting_args)]
+    pub fn archive_name(&self, name: &str, prefix: &str, version: &str) -> String {
+        self.archive_name
+            .replace("{name}", name)
+            .replace("{prefix}", prefix)
+            .replace("{version}", version)
+    }
+
+    /// Also create a `.tar.gz` archive of the release
+    /// Defaults to false
+    pub const fn archive_tar(&self) -> bool {
+        self.archive_tar
+    }
+
+    /// Steam Workshop id of the mod, written to `meta.cpp`
+    pub const fn publishedid(&self) -> Option<u64> {
+        self.publishedid
+    }
+
+    /// Strip comments and indentation from text files that are not rapified
+    /// Defaults to false
+    pub const fn minify(&self) -> bool {
+        self.minify
+    }
+
+    /// Files to leave out of the released PBOs, relative to the addon
+    pub const fn exclude(&self) -> &Vec<String> {
+        &self.exclude
+    }
+
+    /// Files to rename in the released PBOs, relative to the addon
+    pub const fn rename(&self) -> &BTreeMap<String, String> {
+        &self.rename
+    }
+
+    /// GitHub repository for `hemtt release --draft`, as `owner/repo`
+    /// Defaults to the `origin` remote
+    pub fn github(&self) -> Option<&str> {
+        self.github.as_deref()
+    }
+
+    /// Changelog to read the notes of a GitHub release from
+    /// Defaults to `CHANGELOG.md`
+    pub fn changelog(&self) -> &str {
+        &self.changelog
+    }
+
+    /// Macros defined for every file preprocessed by `hemtt release` and `hemtt build`,
+    /// as `NAME` or `NAME=VALUE`
+    pub fn defines(&self) -> &[String] {
+        &self.defines
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -41,19 +108,61 @@ pub struct ReleaseOptionsFile {
 
     #[serde(default)]
     archive: Option<bool>,
+
+    #[serde(default)]
+    archive_name: Option<String>,
+
+    #[serde(default)]
+    archive_tar: Option<bool>,
+
+    #[serde(default)]
+    publishedid: Option<u64>,
+
+    #[serde(default)]
+    minify: Option<bool>,
+
+    #[serde(default)]
+    exclude: Vec<String>,
+
+    #[serde(default)]
+    rename: BTreeMap<String, String>,
+
+    #[serde(default)]
+    github: Option<String>,
+
+    #[serde(default)]
+    changelog: Option<String>,
+
+    #[serde(default)]
+    defines: Vec<String>,
 }
 
+#[allow(clippy::literal_string_with_formatting_args)] // `{prefix}` is replaced by `archive_name`
 impl ReleaseOptionsFile {
     pub fn into_config(self, prefix: &str) -> ReleaseOptions {
         ReleaseOptions {
             folder: self.folder.unwrap_or_else(|| prefix.to_string()),
             sign: self.sign.unwrap_or(true),
             archive: self.archive.unwrap_or(true),
+            archive_name: self
+                .archive_name
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| "{prefix}-{version}".to_string()),
+            archive_tar: self.archive_tar.unwrap_or_default(),
+            publishedid: self.publishedid,
+            minify: self.minify.unwrap_or_default(),
+            exclude: self.exclude,
+            rename: self.rename,
+            github: self.github,
+            changelog: self.changelog.unwrap_or_else(|| "CHANGELOG.md".to_string()),
+            defines: super::defines(self.defines),
         }
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::literal_string_with_formatting_args)]
 mod tests {
     use super::*;
 
@@ -63,12 +172,38 @@ mod tests {
 folder = "test"
 sign = false
 archive = false
+archive_name = "{name}_{version}"
+archive_tar = true
+publishedid = 463939057
+minify = true
+exclude = ["*.hpp"]
+github = "BrettMayson/HEMTT"
+changelog = "docs/changes.md"
+defines = ["RELEASE", "LOG_LEVEL=2", " "]
+
+[rename]
+"functions/fnc_secret.sqf" = "functions/a.sqf"
 "#;
         let file: ReleaseOptionsFile = toml::from_str(toml).expect("failed to deserialize");
         let config = file.into_config("test");
         assert_eq!(config.folder(), "test");
         assert!(!config.sign());
         assert!(!config.archive());
+        assert_eq!(
+            config.archive_name("Advanced Banana Environment", "abe", "1.3.0.1052"),
+            "Advanced Banana Environment_1.3.0.1052"
+        );
+        assert!(config.archive_tar());
+        assert_eq!(config.publishedid(), Some(463_939_057));
+        assert!(config.minify());
+        assert_eq!(config.exclude(), &["*.hpp"]);
+        assert_eq!(
+            config.rename().get("functions/fnc_secret.sqf"),
+            Some(&"functions/a.sqf".to_string())
+        );
+        assert_eq!(config.github(), Some("BrettMayson/HEMTT"));
+        assert_eq!(config.changelog(), "docs/changes.md");
+        assert_eq!(config.defines(), &["RELEASE", "LOG_LEVEL=2"]);
     }
 
     #[test]
@@ -79,5 +214,17 @@ archive = false
         assert_eq!(config.folder(), "test");
         assert!(config.sign());
         assert!(config.archive());
+        assert_eq!(
+            config.archive_name("Advanced Banana Environment", "abe", "1.3.0.1052"),
+            "abe-1.3.0.1052"
+        );
+        assert!(!config.archive_tar());
+        assert_eq!(config.publishedid(), None);
+        assert!(!config.minify());
+        assert!(config.exclude().is_empty());
+        assert!(config.rename().is_empty());
+        assert_eq!(config.github(), None);
+        assert_eq!(config.changelog(), "CHANGELOG.md");
+        assert!(config.defines().is_empty());
     }
 }
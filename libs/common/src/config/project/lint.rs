@@ -4,12 +4,17 @@ use codespan_reporting::diagnostic::Severity;
 use serde::{Deserialize, Serialize};
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 /// Lint group config
 pub struct LintGroupConfig {
+    #[serde(serialize_with = "crate::config::sorted")]
     config: HashMap<String, LintConfigOverride>,
+    #[serde(serialize_with = "crate::config::sorted")]
     sqf: HashMap<String, LintConfigOverride>,
+    #[serde(serialize_with = "crate::config::sorted")]
     stringtables: HashMap<String, LintConfigOverride>,
+    #[serde(serialize_with = "crate::config::sorted")]
+    custom: HashMap<String, CustomLintConfig>,
 }
 
 impl LintGroupConfig {
@@ -31,8 +36,96 @@ impl LintGroupConfig {
         &self.stringtables
     }
 
+    #[must_use]
+    /// Get the user defined config lints
+    pub const fn custom(&self) -> &HashMap<String, CustomLintConfig> {
+        &self.custom
+    }
+
+    #[must_use]
+    /// Are there no lint overrides
     pub fn is_empty(&self) -> bool {
-        self.config.is_empty() && self.sqf.is_empty()
+        self.config.is_empty()
+            && self.sqf.is_empty()
+            && self.stringtables.is_empty()
+            && self.custom.is_empty()
+    }
+
+    #[must_use]
+    /// Apply the overrides of an addon on top of the project's
+    pub fn with_overrides(&self, overrides: &Self) -> Self {
+        fn merge(
+            base: &HashMap<String, LintConfigOverride>,
+            overrides: &HashMap<String, LintConfigOverride>,
+        ) -> HashMap<String, LintConfigOverride> {
+            let mut merged = base.clone();
+            for (lint, config) in overrides {
+                merged
+                    .entry(lint.clone())
+                    .and_modify(|base| *base = base.with_override(config))
+                    .or_insert_with(|| config.clone());
+            }
+            merged
+        }
+        Self {
+            config: merge(&self.config, &overrides.config),
+            sqf: merge(&self.sqf, &overrides.sqf),
+            stringtables: merge(&self.stringtables, &overrides.stringtables),
+            custom: {
+                let mut custom = self.custom.clone();
+                custom.extend(overrides.custom.clone());
+                custom
+            },
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// A user defined lint, checking the properties of config classes
+pub struct CustomLintConfig {
+    #[serde(default)]
+    class: Option<String>,
+    #[serde(default)]
+    forbid: Option<String>,
+    #[serde(default)]
+    require: Vec<String>,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    severity: Option<Severity>,
+}
+
+impl CustomLintConfig {
+    #[must_use]
+    /// Regex of the class paths the lint applies to, all classes if not set
+    pub fn class(&self) -> Option<&str> {
+        self.class.as_deref()
+    }
+
+    #[must_use]
+    /// Regex of property names that must not exist
+    pub fn forbid(&self) -> Option<&str> {
+        self.forbid.as_deref()
+    }
+
+    #[must_use]
+    /// Properties that must exist
+    pub fn require(&self) -> &[String] {
+        &self.require
+    }
+
+    #[must_use]
+    /// Message to report instead of the default
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    #[must_use]
+    /// Severity of the lint, defaults to warning
+    pub fn severity(&self) -> Severity {
+        self.severity.unwrap_or(Severity::Warning)
     }
 }
 
@@ -113,7 +206,7 @@ impl LintConfig {
 pub struct LintConfigOverride {
     enabled: Option<bool>,
     severity: Option<Severity>,
-    #[serde(default)]
+    #[serde(default, serialize_with = "crate::config::sorted")]
     options: HashMap<String, toml::Value>,
 }
 impl Eq for LintConfigOverride {}
@@ -134,6 +227,18 @@ impl LintConfigOverride {
         self.options.get(key)
     }
 
+    #[must_use]
+    /// Combine with a more specific override, its values take precedence
+    pub fn with_override(&self, other: &Self) -> Self {
+        let mut options = self.options.clone();
+        options.extend(other.options.clone());
+        Self {
+            enabled: other.enabled.or(self.enabled),
+            severity: other.severity.or(self.severity),
+            options,
+        }
+    }
+
     #[must_use]
     pub fn apply(&self, config: LintConfig) -> LintConfig {
         let mut new = config;
@@ -154,6 +259,7 @@ pub struct LintSectionFile {
     pub config: Option<HashMap<String, LintConfigFile>>,
     pub sqf: Option<HashMap<String, LintConfigFile>>,
     pub stringtables: Option<HashMap<String, LintConfigFile>>,
+    pub custom: Option<HashMap<String, CustomLintConfig>>,
 }
 
 impl From<LintSectionFile> for LintGroupConfig {
@@ -177,6 +283,7 @@ impl From<LintSectionFile> for LintGroupConfig {
                 .into_iter()
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
+            custom: file.custom.unwrap_or_default(),
         }
     }
 }
@@ -269,6 +376,88 @@ options.test = true
         assert_eq!(example.option("test"), Some(&toml::Value::Boolean(true)));
     }
 
+    #[test]
+    fn with_overrides() {
+        let project: LintSectionFile = toml::from_str(
+            r#"
+[config.invalid_value]
+severity = "Error"
+options.test = 1
+
+[sqf]
+example = false
+"#,
+        )
+        .expect("failed to deserialize");
+        let addon: LintSectionFile = toml::from_str(
+            r#"
+[config]
+invalid_value = "Warning"
+
+[stringtables]
+example = false
+"#,
+        )
+        .expect("failed to deserialize");
+        let config = LintGroupConfig::from(project).with_overrides(&addon.into());
+        let invalid_value = config
+            .config()
+            .get("invalid_value")
+            .expect("invalid_value exists");
+        assert_eq!(invalid_value.severity(), Some(Severity::Warning));
+        assert_eq!(invalid_value.option("test"), Some(&toml::Value::Integer(1)));
+        assert_eq!(
+            config
+                .sqf()
+                .get("example")
+                .expect("example exists")
+                .enabled(),
+            Some(false)
+        );
+        assert_eq!(
+            config
+                .stringtables()
+                .get("example")
+                .expect("example exists")
+                .enabled(),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn custom() {
+        let project: LintSectionFile = toml::from_str(
+            r#"
+[custom.no_debug]
+forbid = "^debug_"
+
+[custom.weapon_author]
+class = "^CfgWeapons/"
+require = ["author"]
+"#,
+        )
+        .expect("failed to deserialize");
+        let addon: LintSectionFile = toml::from_str(
+            r#"
+[custom.no_debug]
+forbid = "^debug_"
+severity = "Error"
+"#,
+        )
+        .expect("failed to deserialize");
+        let config = LintGroupConfig::from(project).with_overrides(&addon.into());
+        let no_debug = config.custom().get("no_debug").expect("no_debug exists");
+        assert_eq!(no_debug.forbid(), Some("^debug_"));
+        assert_eq!(no_debug.severity(), Severity::Error);
+        let weapon_author = config
+            .custom()
+            .get("weapon_author")
+            .expect("weapon_author exists");
+        assert_eq!(weapon_author.class(), Some("^CfgWeapons/"));
+        assert_eq!(weapon_author.require(), &["author"]);
+        assert_eq!(weapon_author.severity(), Severity::Warning);
+    }
+
     #[test]
     fn empty() {
         let toml = "";
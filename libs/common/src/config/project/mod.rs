@@ -1,6 +1,10 @@
 //! Module for reading HEMTT project files
 
-use std::{collections::HashMap, path::PathBuf, sync::Once};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    sync::Once,
+};
 
 use serde::{Deserialize, Serialize};
 use tracing::warn;
@@ -9,14 +13,16 @@ use crate::error::Error;
 
 use super::deprecated;
 
+pub mod dependencies;
 pub mod files;
 pub mod hemtt;
 pub mod lint;
+pub mod schema;
 pub mod signing;
 pub mod version;
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 /// Configuration for a HEMTT project
 pub struct ProjectConfig {
     /// The name of the project
@@ -30,6 +36,7 @@ pub struct ProjectConfig {
     version: version::VersionConfig,
 
     /// Properties to be added to built PBOs
+    #[serde(serialize_with = "super::sorted")]
     properties: HashMap<String, String>,
 
     /// Files to be included in the root of the project, supports glob patterns
@@ -43,6 +50,13 @@ pub struct ProjectConfig {
 
     /// Signing specific configuration
     signing: signing::SigningConfig,
+
+    /// External mods the project depends on
+    dependencies: dependencies::DependenciesConfig,
+
+    /// Schemas for config files
+    #[serde(skip)]
+    schemas: Vec<schema::SchemaSource>,
 }
 
 impl ProjectConfig {
@@ -88,6 +102,21 @@ impl ProjectConfig {
         &self.lints
     }
 
+    #[must_use]
+    /// Schemas for config files, from the `schemas` folder next to the project config
+    pub fn schemas(&self) -> &[schema::SchemaSource] {
+        &self.schemas
+    }
+
+    #[must_use]
+    /// The config used for an addon, with the lint overrides of its `addon.toml`
+    pub fn with_addon(&self, addon: &super::AddonConfig) -> Self {
+        Self {
+            lints: self.lints.with_overrides(addon.lints()),
+            ..self.clone()
+        }
+    }
+
     #[must_use]
     /// HEMTT specific configuration
     pub const fn hemtt(&self) -> &hemtt::HemttConfig {
@@ -100,6 +129,12 @@ impl ProjectConfig {
         &self.signing
     }
 
+    #[must_use]
+    /// External mods the project depends on
+    pub const fn dependencies(&self) -> &dependencies::DependenciesConfig {
+        &self.dependencies
+    }
+
     /// Read a project file from disk
     ///
     /// # Errors
@@ -144,6 +179,9 @@ pub struct ProjectFile {
     #[serde(default)]
     signing: signing::SigningSectionFile,
 
+    #[serde(default)]
+    dependencies: BTreeMap<String, dependencies::DependencySectionFile>,
+
     #[serde(skip)]
     meta_path: PathBuf,
 }
@@ -197,6 +235,13 @@ impl TryFrom<ProjectFile> for ProjectConfig {
             files: file.files.into(),
             lints: file.lints.into(),
             signing: file.signing.into(),
+            dependencies: file.dependencies.into(),
+            schemas: file
+                .meta_path
+                .parent()
+                .map(|folder| schema::read(&folder.join("schemas")))
+                .transpose()?
+                .unwrap_or_default(),
         };
 
         let mut lints_path = file.meta_path;
@@ -225,7 +270,7 @@ impl TryFrom<ProjectFile> for ProjectConfig {
 }
 
 mod test_helper {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
     use super::{files, hemtt, lint, signing, version};
 
@@ -246,6 +291,7 @@ mod test_helper {
                 lints: lint::LintSectionFile::default(),
                 hemtt: hemtt::HemttSectionFile::default(),
                 signing: signing::SigningSectionFile::default(),
+                dependencies: BTreeMap::default(),
                 meta_path: std::path::PathBuf::default(),
             }
             .try_into()
@@ -0,0 +1,73 @@
+//! Schemas for config files, read from the `schemas` folder next to the project config
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+/// A schema file, parsed when the schema lints are compiled
+pub struct SchemaSource {
+    name: String,
+    path: String,
+    source: String,
+}
+
+impl SchemaSource {
+    #[must_use]
+    /// The name of the schema, from its file name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    /// The path of the schema file
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    /// The content of the schema file
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    #[must_use]
+    /// Is the schema written in JSON, instead of TOML
+    pub fn is_json(&self) -> bool {
+        Path::new(&self.path)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+    }
+}
+
+/// Read the `.toml` and `.json` schemas in a folder, sorted by name
+///
+/// # Errors
+/// [`Error::Io`] if a schema can not be read
+pub fn read(folder: &Path) -> Result<Vec<SchemaSource>, Error> {
+    if !folder.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut schemas = Vec::new();
+    for entry in std::fs::read_dir(folder)? {
+        let path = entry?.path();
+        let Some(ext) = path.extension().and_then(|ext| ext.to_str()) else {
+            continue;
+        };
+        if !["toml", "json"].contains(&ext.to_lowercase().as_str()) {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        schemas.push(SchemaSource {
+            name: name.to_string(),
+            path: path.display().to_string(),
+            source: std::fs::read_to_string(&path)?,
+        });
+    }
+    schemas.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(schemas)
+}
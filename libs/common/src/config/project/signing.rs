@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::BISignVersion;
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct SigningConfig {
     version: BISignVersion,
 
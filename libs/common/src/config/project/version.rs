@@ -1,8 +1,8 @@
 use std::{mem::MaybeUninit, sync::RwLock};
 
-use git2::Repository;
-use serde::{Deserialize, Serialize};
-use tracing::trace;
+use git2::{DescribeOptions, Repository};
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
+use tracing::{trace, warn};
 use vfs::VfsPath;
 
 use crate::{error::Error, version::Version};
@@ -13,6 +13,18 @@ pub struct VersionConfig {
     path: String,
     defined: Option<(u32, u32, u32, Option<u32>)>,
     git_hash: u8,
+    defines: bool,
+    git: Option<GitVersion>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// How the version is read from git tags
+pub enum GitVersion {
+    /// The nearest tag, such as `v1.2.3`
+    Tag,
+    /// The nearest tag, with the number of commits since it as the build number
+    Describe,
 }
 
 static VERSION: RwLock<MaybeUninit<Version>> = RwLock::new(MaybeUninit::uninit());
@@ -37,7 +49,10 @@ impl VersionConfig {
             }
         }
 
-        let mut version = self.internal_get(vfs)?;
+        let mut version = match self.git_get() {
+            Some(version) => version,
+            None => self.internal_get(vfs)?,
+        };
 
         if let Some(length) = self.git_hash() {
             let repo = Repository::discover(".")?;
@@ -65,6 +80,25 @@ impl VersionConfig {
         }
     }
 
+    /// The version from the nearest git tag, [`None`] to fall back to the configured version
+    fn git_get(&self) -> Option<Version> {
+        let git = self.git?;
+        let repo = Repository::discover(".")
+            .inspect_err(|e| {
+                warn!("no git repository to read the version from, falling back: {e}");
+            })
+            .ok()?;
+        let describe = repo
+            .describe(DescribeOptions::new().describe_tags())
+            .and_then(|describe| describe.format(None))
+            .inspect_err(|e| trace!("no git tag to read the version from, falling back: {e}"))
+            .ok()?;
+        trace!("reading version from git tag {describe}");
+        Version::try_from_git_describe(&describe, git == GitVersion::Describe)
+            .inspect_err(|e| warn!("{e}, falling back to the configured version"))
+            .ok()
+    }
+
     fn internal_get(&self, vfs: &VfsPath) -> Result<Version, Error> {
         // Check for a defined major version
         if let Some((major, minor, patch, build)) = self.defined {
@@ -87,6 +121,18 @@ impl VersionConfig {
         &self.path
     }
 
+    #[must_use]
+    /// How the version is read from git tags, [`None`] if it is not
+    pub const fn git(&self) -> Option<GitVersion> {
+        self.git
+    }
+
+    #[must_use]
+    /// Are the `VERSION_*` macros defined for the preprocessor
+    pub const fn defines(&self) -> bool {
+        self.defines
+    }
+
     #[must_use]
     /// Length of the git hash to use in the build number
     ///
@@ -100,6 +146,39 @@ impl VersionConfig {
     }
 }
 
+impl Serialize for VersionConfig {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("VersionConfig", 8)?;
+        state.serialize_field("path", &self.path)?;
+        if let Some((major, minor, patch, build)) = self.defined {
+            state.serialize_field("major", &major)?;
+            state.serialize_field("minor", &minor)?;
+            state.serialize_field("patch", &patch)?;
+            if let Some(build) = build {
+                state.serialize_field("build", &build)?;
+            } else {
+                state.skip_field("build")?;
+            }
+        } else {
+            state.skip_field("major")?;
+            state.skip_field("minor")?;
+            state.skip_field("patch")?;
+            state.skip_field("build")?;
+        }
+        state.serialize_field("git_hash", &self.git_hash)?;
+        state.serialize_field("defines", &self.defines)?;
+        if let Some(git) = self.git {
+            state.serialize_field("git", &git)?;
+        } else {
+            state.skip_field("git")?;
+        }
+        state.end()
+    }
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(PartialEq, Eq, Debug, Default, Clone, Serialize, Deserialize)]
 pub struct VersionSectionFile {
@@ -117,6 +196,12 @@ pub struct VersionSectionFile {
 
     #[serde(default)]
     git_hash: Option<u8>,
+
+    #[serde(default)]
+    defines: bool,
+
+    #[serde(default)]
+    git: Option<GitVersion>,
 }
 
 impl TryFrom<VersionSectionFile> for VersionConfig {
@@ -148,6 +233,8 @@ impl TryFrom<VersionSectionFile> for VersionConfig {
                 .transpose()
                 .map_err(Error::Version)?,
             git_hash: file.git_hash.unwrap_or(8),
+            defines: file.defines,
+            git: file.git,
         })
     }
 }
@@ -169,6 +256,38 @@ git_hash = 4
         let config = VersionConfig::try_from(file).expect("failed to convert");
         assert_eq!(config.path(), "addons/main/script_version.hpp");
         assert_eq!(config.git_hash(), Some(4));
+        assert!(!config.defines());
+    }
+
+    #[test]
+    fn git() {
+        let toml = r#"
+git = "describe"
+major = 1
+minor = 0
+patch = 0
+"#;
+        let file: VersionSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = VersionConfig::try_from(file).expect("failed to convert");
+        assert_eq!(config.git(), Some(GitVersion::Describe));
+
+        let toml = r#"
+git = "branch"
+"#;
+        assert!(toml::from_str::<VersionSectionFile>(toml).is_err());
+    }
+
+    #[test]
+    fn defines() {
+        let toml = "
+major = 1
+minor = 2
+patch = 3
+defines = true
+";
+        let file: VersionSectionFile = toml::from_str(toml).expect("failed to deserialize");
+        let config = VersionConfig::try_from(file).expect("failed to convert");
+        assert!(config.defines());
     }
 
     #[test]
@@ -0,0 +1,183 @@
+//! Read a `hemtt-workspace.toml`, listing projects that are built together
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// The name of the file at the root of a workspace
+pub const WORKSPACE_FILE: &str = "hemtt-workspace.toml";
+
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+/// Projects built together by `hemtt build --workspace`
+pub struct WorkspaceConfig {
+    #[serde(default)]
+    projects: BTreeMap<String, WorkspaceProject>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+/// A project in a workspace
+pub struct WorkspaceProject {
+    /// Folder of the project, relative to the workspace
+    path: PathBuf,
+    #[serde(default)]
+    /// Projects that are built before this one
+    depends: Vec<String>,
+}
+
+impl WorkspaceProject {
+    #[must_use]
+    /// Folder of the project, relative to the workspace
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[must_use]
+    /// Projects that are built before this one
+    pub fn depends(&self) -> &[String] {
+        &self.depends
+    }
+}
+
+impl WorkspaceConfig {
+    /// Read a workspace file from disk
+    ///
+    /// # Errors
+    /// [`crate::error::Error::Io`] if the file cannot be read
+    /// [`crate::error::Error::Toml`] if the file is not valid toml
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        Ok(toml::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    #[must_use]
+    /// The projects in the workspace, by name
+    pub const fn projects(&self) -> &BTreeMap<String, WorkspaceProject> {
+        &self.projects
+    }
+
+    /// The projects in the order they are built, each after the projects it depends on
+    ///
+    /// Projects that do not depend on each other are built in order of their names.
+    ///
+    /// # Errors
+    /// [`Error::WorkspaceUnknownDependency`] if a project depends on one that is not in the workspace
+    /// [`Error::WorkspaceCycle`] if projects depend on each other
+    pub fn build_order(&self) -> Result<Vec<(&str, &WorkspaceProject)>, Error> {
+        for (name, project) in &self.projects {
+            if let Some(missing) = project
+                .depends
+                .iter()
+                .find(|depend| !self.projects.contains_key(*depend))
+            {
+                return Err(Error::WorkspaceUnknownDependency(
+                    name.clone(),
+                    missing.clone(),
+                ));
+            }
+        }
+        let mut order: Vec<(&str, &WorkspaceProject)> = Vec::with_capacity(self.projects.len());
+        while order.len() < self.projects.len() {
+            let next = self.projects.iter().find(|(name, project)| {
+                !order.iter().any(|(built, _)| built == name)
+                    && project
+                        .depends
+                        .iter()
+                        .all(|depend| order.iter().any(|(built, _)| built == depend))
+            });
+            let Some((name, project)) = next else {
+                return Err(Error::WorkspaceCycle(
+                    self.projects
+                        .keys()
+                        .filter(|name| !order.iter().any(|(built, _)| built == name))
+                        .cloned()
+                        .collect(),
+                ));
+            };
+            order.push((name, project));
+        }
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn names(config: &WorkspaceConfig) -> Vec<&str> {
+        config
+            .build_order()
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    #[test]
+    fn build_order() {
+        let config = toml::from_str::<WorkspaceConfig>(
+            r#"
+[projects.maps]
+path = "maps"
+depends = ["main", "assets"]
+
+[projects.main]
+path = "mod"
+depends = ["assets"]
+
+[projects.assets]
+path = "assets"
+
+[projects.extra]
+path = "extra"
+"#,
+        )
+        .unwrap();
+        assert_eq!(names(&config), ["assets", "extra", "main", "maps"]);
+        assert_eq!(config.projects()["maps"].path(), Path::new("maps"));
+        assert_eq!(config.projects()["maps"].depends(), ["main", "assets"]);
+    }
+
+    #[test]
+    fn unknown_dependency() {
+        let config = toml::from_str::<WorkspaceConfig>(
+            r#"
+[projects.maps]
+path = "maps"
+depends = ["main"]
+"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            config.build_order(),
+            Err(Error::WorkspaceUnknownDependency(project, missing)) if project == "maps" && missing == "main"
+        ));
+    }
+
+    #[test]
+    fn cycle() {
+        let config = toml::from_str::<WorkspaceConfig>(
+            r#"
+[projects.a]
+path = "a"
+depends = ["b"]
+
+[projects.b]
+path = "b"
+depends = ["a"]
+
+[projects.c]
+path = "c"
+"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            config.build_order(),
+            Err(Error::WorkspaceCycle(projects)) if projects == ["a", "b"]
+        ));
+    }
+}
@@ -21,6 +21,11 @@ pub enum Error {
     #[error("Lints configuration source conflct. They can exist in either `project.toml` or `lints.toml`, not both.")]
     LintsConfigConflict,
 
+    #[error("Workspace project `{0}` depends on `{1}`, which is not in the workspace")]
+    WorkspaceUnknownDependency(String, String),
+    #[error("Workspace projects depend on each other: {}", .0.join(", "))]
+    WorkspaceCycle(Vec<String>),
+
     #[error("Git Error: {0}")]
     Git(#[from] git2::Error),
     #[error("IO Error: {0}")]
@@ -16,6 +16,8 @@
 use std::{fmt::Display, path::PathBuf};
 
 /// Files that may be used to contain the prefix, case insensitive, convert to lowercase
+///
+/// Ordered by precedence, when an addon contains multiple prefix files the first one in this list is used
 pub const FILES: [&str; 4] = [
     "$pboprefix$",
     "$pboprefix$.txt",
@@ -23,6 +25,15 @@ pub const FILES: [&str; 4] = [
     "$prefix$",
 ];
 
+#[must_use]
+/// The precedence of a prefix file, lower values take priority
+///
+/// Returns `None` if the file is not a prefix file
+pub fn precedence(filename: &str) -> Option<usize> {
+    let filename = filename.to_lowercase();
+    FILES.iter().position(|file| *file == filename)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// A prefix for a PBO
 pub struct Prefix(Vec<String>);
@@ -177,4 +188,13 @@ mod tests {
     fn empty() {
         assert!(Prefix::new("").is_err());
     }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(super::precedence("$PBOPREFIX$"), Some(0));
+        assert_eq!(super::precedence("$pboprefix$.txt"), Some(1));
+        assert_eq!(super::precedence("PboPrefix.txt"), Some(2));
+        assert_eq!(super::precedence("$PREFIX$"), Some(3));
+        assert_eq!(super::precedence("config.cpp"), None);
+    }
 }
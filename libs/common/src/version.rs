@@ -57,6 +57,30 @@ impl Version {
         })
     }
 
+    /// Read a version from the output of `git describe --tags`
+    ///
+    /// - `v1.2.3`
+    /// - `1.2.3-5-g1a2b3c4`, 5 commits after the tag
+    ///
+    /// The number of commits since the tag is used as the build number if `commits_as_build` is set
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tag is not a valid version
+    pub fn try_from_git_describe(describe: &str, commits_as_build: bool) -> Result<Self, Error> {
+        let (tag, commits) = describe
+            .rsplit_once("-g")
+            .and_then(|(rest, _)| rest.rsplit_once('-'))
+            .and_then(|(tag, commits)| commits.parse::<u32>().ok().map(|commits| (tag, commits)))
+            .unwrap_or((describe, 0));
+        let mut version = Self::try_from(tag.trim_start_matches(['v', 'V']))
+            .map_err(|_| Error::InvalidTag(tag.to_string()))?;
+        if commits_as_build {
+            version.build = Some(commits);
+        }
+        Ok(version)
+    }
+
     /// Set the build number
     pub fn set_build(&mut self, build: impl Into<String>) {
         self.hash = Some(build.into());
@@ -86,6 +110,12 @@ impl Version {
         self.build
     }
 
+    /// Git hash, set with [`Version::set_build`]
+    #[must_use]
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
     fn extract_version(lines: &[&str], component: &str) -> Result<u32, Error> {
         let error = match component {
             "MAJOR" => Error::ExpectedMajor,
@@ -264,6 +294,10 @@ pub enum Error {
     /// HEMTT found an invalid version component
     InvalidComponent(String),
 
+    #[error("Git tag is not a valid version: {0}")]
+    /// HEMTT found a git tag that is not a version
+    InvalidTag(String),
+
     #[error("Version definition conflict, can define either a path or components")]
     /// HEMTT found a conflict between a version path and version components
     VersionPathConflict,
@@ -274,6 +308,22 @@ pub enum Error {
 mod tests {
     use super::*;
 
+    #[test]
+    fn git_describe() {
+        let version = Version::try_from_git_describe("v1.2.3", false).unwrap();
+        assert_eq!(version.to_string(), "1.2.3");
+        let version = Version::try_from_git_describe("v1.2.3-5-g1a2b3c4", false).unwrap();
+        assert_eq!(version.to_string(), "1.2.3");
+        let version = Version::try_from_git_describe("1.2.3-5-g1a2b3c4", true).unwrap();
+        assert_eq!(version.to_string(), "1.2.3.5");
+        let version = Version::try_from_git_describe("V1.2.3", true).unwrap();
+        assert_eq!(version.to_string(), "1.2.3.0");
+        assert!(matches!(
+            Version::try_from_git_describe("release-5-g1a2b3c4", false),
+            Err(Error::InvalidTag(tag)) if tag == "release"
+        ));
+    }
+
     #[test]
     fn version() {
         let version = Version::try_from("1.0.0.0-d1a631b1").unwrap();
@@ -0,0 +1,34 @@
+use crate::Ident;
+
+#[derive(Debug, Clone)]
+/// A function declared in `CfgFunctions`
+pub struct CfgFunction {
+    name: String,
+    class: Ident,
+    file: Option<String>,
+}
+
+impl CfgFunction {
+    #[must_use]
+    pub const fn new(name: String, class: Ident, file: Option<String>) -> Self {
+        Self { name, class, file }
+    }
+
+    #[must_use]
+    /// The name of the function, `<tag>_fnc_<class>`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    /// The class declaring the function
+    pub const fn class(&self) -> &Ident {
+        &self.class
+    }
+
+    #[must_use]
+    /// The script of the function, from its `file` or the `file` of its category
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+}
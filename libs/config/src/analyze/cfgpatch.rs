@@ -6,14 +6,16 @@ use crate::Ident;
 pub struct CfgPatch {
     name: Ident,
     required_version: Version,
+    required_addons: Vec<String>,
 }
 
 impl CfgPatch {
     #[must_use]
-    pub const fn new(name: Ident, required_version: Version) -> Self {
+    pub const fn new(name: Ident, required_version: Version, required_addons: Vec<String>) -> Self {
         Self {
             name,
             required_version,
+            required_addons,
         }
     }
 
@@ -26,4 +28,10 @@ impl CfgPatch {
     pub const fn required_version(&self) -> &Version {
         &self.required_version
     }
+
+    #[must_use]
+    /// The patches in `requiredAddons[]`
+    pub fn required_addons(&self) -> &[String] {
+        &self.required_addons
+    }
 }
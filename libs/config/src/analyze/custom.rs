@@ -0,0 +1,335 @@
+//! User defined lints from `[lints.custom]`
+
+use std::{
+    collections::HashMap,
+    ops::Range,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use hemtt_common::config::{CustomLintConfig, LintConfig, LintGroupConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintManager, LintRunner, Lints},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+use regex::{Regex, RegexBuilder};
+
+use crate::{analyze::LintData, Class, Property};
+
+type Compiled = HashMap<(String, CustomLintConfig), Arc<Box<dyn Lint<LintData>>>>;
+
+/// Custom lints are compiled once, the first time they are used
+static COMPILED: LazyLock<RwLock<Compiled>> = LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Compile the custom lints of a project
+///
+/// # Errors
+/// Returns a list of codes if a custom lint is invalid
+///
+/// # Panics
+/// If the lock of the compiled lints is poisoned
+pub fn custom_lints(lints: &LintGroupConfig) -> Result<Lints<LintData>, Codes> {
+    let rules = lints.custom();
+    let mut names = rules.keys().collect::<Vec<_>>();
+    names.sort();
+    let mut lints: Lints<LintData> = Vec::with_capacity(names.len());
+    let mut errors: Codes = Vec::new();
+    for name in names {
+        let key = (name.clone(), rules[name].clone());
+        if let Some(lint) = COMPILED.read().expect("compiled lock poisoned").get(&key) {
+            lints.push(lint.clone());
+            continue;
+        }
+        match CustomLint::compile(name, &rules[name]) {
+            Ok(lint) => {
+                let lint: Arc<Box<dyn Lint<LintData>>> = Arc::new(Box::new(lint));
+                COMPILED
+                    .write()
+                    .expect("compiled lock poisoned")
+                    .insert(key, lint.clone());
+                lints.push(lint);
+            }
+            Err(error) => errors.push(Arc::new(error)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(lints)
+    } else {
+        Err(errors)
+    }
+}
+
+#[must_use]
+/// Check that the custom lints are valid, and do not replace a built-in lint
+///
+/// # Panics
+/// If the lock of the compiled lints is poisoned
+pub fn custom_check(lints: &LintGroupConfig) -> Codes {
+    let lints = match custom_lints(lints) {
+        Ok(lints) => lints,
+        Err(errors) => return errors,
+    };
+    let mut manager: LintManager<LintData> = LintManager::new(HashMap::new());
+    if let Err(errors) = manager.extend(
+        super::CONFIG_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    ) {
+        return errors;
+    }
+    manager.extend(lints).err().unwrap_or_default()
+}
+
+struct Rule {
+    class: Option<Regex>,
+    forbid: Option<Regex>,
+    require: Vec<String>,
+    message: Option<String>,
+}
+
+struct CustomLint {
+    ident: &'static str,
+    severity: Severity,
+    rule: Arc<Rule>,
+}
+
+impl CustomLint {
+    fn compile(name: &str, config: &CustomLintConfig) -> Result<Self, CodeCustomInvalid> {
+        let regex = |field: &str, pattern: Option<&str>| {
+            pattern
+                .map(|pattern| {
+                    RegexBuilder::new(pattern)
+                        .case_insensitive(true)
+                        .build()
+                        .map_err(|e| CodeCustomInvalid {
+                            message: format!(
+                                "Custom lint `{name}` has an invalid `{field}` pattern: {e}"
+                            ),
+                        })
+                })
+                .transpose()
+        };
+        let rule = Rule {
+            class: regex("class", config.class())?,
+            forbid: regex("forbid", config.forbid())?,
+            require: config.require().to_vec(),
+            message: config.message().map(ToString::to_string),
+        };
+        if rule.forbid.is_none() && rule.require.is_empty() {
+            return Err(CodeCustomInvalid {
+                message: format!(
+                    "Custom lint `{name}` has nothing to check, set `forbid` or `require`"
+                ),
+            });
+        }
+        Ok(Self {
+            // Lints are identified by a static str, each custom lint is only compiled once
+            ident: Box::leak(name.to_string().into_boxed_str()),
+            severity: config.severity(),
+            rule: Arc::new(rule),
+        })
+    }
+}
+
+impl Lint<LintData> for CustomLint {
+    fn ident(&self) -> &'static str {
+        self.ident
+    }
+
+    fn sort(&self) -> u32 {
+        u32::MAX
+    }
+
+    fn description(&self) -> &'static str {
+        "User defined lint from `[lints.custom]`"
+    }
+
+    fn documentation(&self) -> &'static str {
+        ""
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::new(self.severity, HashMap::new())
+    }
+
+    fn minimum_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner {
+            ident: self.ident,
+            rule: self.rule.clone(),
+        })]
+    }
+}
+
+struct Runner {
+    ident: &'static str,
+    rule: Arc<Rule>,
+}
+
+impl LintRunner<LintData> for Runner {
+    type Target = Class;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Class,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        let (name, properties) = match target {
+            Class::External { .. } => return vec![],
+            Class::Root { properties } => (None, properties),
+            Class::Local {
+                name, properties, ..
+            } => (Some(name), properties),
+        };
+        let path = name.map_or_else(
+            || data.path.clone(),
+            |name| format!("{}/{}", data.path, name.value),
+        );
+        let path = path.trim_start_matches('/');
+        if let Some(class) = &self.rule.class {
+            if !class.is_match(path) {
+                return vec![];
+            }
+        }
+        let entries = properties
+            .iter()
+            .filter_map(|p| match p {
+                Property::Entry { name, .. } | Property::MissingSemicolon(name, _) => Some(name),
+                Property::Class(_) | Property::Delete(_) => None,
+            })
+            .collect::<Vec<_>>();
+        let mut codes: Codes = Vec::new();
+        if let Some(forbid) = &self.rule.forbid {
+            for entry in entries.iter().filter(|entry| forbid.is_match(&entry.value)) {
+                codes.push(Arc::new(CodeCustom::new(
+                    self.ident,
+                    entry.span.clone(),
+                    self.rule
+                        .message
+                        .clone()
+                        .unwrap_or_else(|| format!("`{}` is not allowed in `{path}`", entry.value)),
+                    format!("matches `{forbid}`"),
+                    processed,
+                    config.severity(),
+                )));
+            }
+        }
+        if let Some(name) = name {
+            let missing = self
+                .rule
+                .require
+                .iter()
+                .filter(|required| {
+                    !entries.iter().any(|entry| {
+                        entry
+                            .value
+                            .eq_ignore_ascii_case(required.trim_end_matches("[]"))
+                    })
+                })
+                .map(|required| format!("`{required}`"))
+                .collect::<Vec<_>>();
+            if !missing.is_empty() {
+                codes.push(Arc::new(CodeCustom::new(
+                    self.ident,
+                    name.span.clone(),
+                    self.rule
+                        .message
+                        .clone()
+                        .unwrap_or_else(|| format!("`{path}` is missing required properties")),
+                    format!("missing {}", missing.join(", ")),
+                    processed,
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+pub struct CodeCustom {
+    ident: &'static str,
+    span: Range<usize>,
+    message: String,
+    label: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeCustom {
+    fn ident(&self) -> &'static str {
+        self.ident
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn label_message(&self) -> String {
+        self.label.clone()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(format!("reported by the custom lint `{}`", self.ident))
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeCustom {
+    #[must_use]
+    pub fn new(
+        ident: &'static str,
+        span: Range<usize>,
+        message: String,
+        label: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            ident,
+            span,
+            message,
+            label,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
+
+pub struct CodeCustomInvalid {
+    message: String,
+}
+
+impl Code for CodeCustomInvalid {
+    fn ident(&self) -> &'static str {
+        "ILC"
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
@@ -3,7 +3,7 @@ use std::{ops::Range, sync::Arc};
 use hemtt_common::config::{LintConfig, ProjectConfig};
 use hemtt_workspace::{
     lint::{AnyLintRunner, Lint, LintRunner},
-    reporting::{Code, Codes, Diagnostic, Processed},
+    reporting::{Code, Codes, Diagnostic, Fix, Processed},
 };
 
 use crate::{analyze::LintData, Item, Value};
@@ -117,6 +117,7 @@ impl LintRunner<LintData> for RunnerItem {
 pub struct CodeC01InvalidValue {
     span: Range<usize>,
     diagnostic: Option<Diagnostic>,
+    fix: Option<Fix>,
     value: String,
 }
 
@@ -150,6 +151,10 @@ impl Code for CodeC01InvalidValue {
         }
     }
 
+    fn fix(&self) -> Option<Fix> {
+        self.fix.clone()
+    }
+
     fn diagnostic(&self) -> Option<Diagnostic> {
         self.diagnostic.clone()
     }
@@ -162,16 +167,33 @@ impl CodeC01InvalidValue {
             value: processed.extract(span.clone()).to_string(),
             span,
             diagnostic: None,
+            fix: None,
         }
         .generate_processed(processed)
     }
 
     fn generate_processed(mut self, processed: &Processed) -> Self {
         self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self.fix = Fix::from_processed(processed, self.span.clone(), quote);
         self
     }
 }
 
+/// Quote an invalid value, `true` and `false` are not quoted as they are likely meant to be numbers
+fn quote(value: &str) -> Option<String> {
+    if value.contains('\n')
+        || value.eq_ignore_ascii_case("true")
+        || value.eq_ignore_ascii_case("false")
+    {
+        return None;
+    }
+    let value = value
+        .strip_prefix('\'')
+        .and_then(|v| v.strip_suffix('\''))
+        .unwrap_or(value);
+    Some(format!("\"{}\"", value.replace('"', "\"\"")))
+}
+
 pub struct CodeC01InvalidValueMacro {
     span: Range<usize>,
     diagnostic: Option<Diagnostic>,
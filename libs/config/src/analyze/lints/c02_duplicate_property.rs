@@ -1,4 +1,4 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, ops::Range, sync::Arc};
 
 use hemtt_common::config::{LintConfig, ProjectConfig};
 use hemtt_workspace::{
@@ -46,7 +46,7 @@ class MyClass {
 
 ### Explanation
 
-Properties on a class must be unique, regardless of the type of property.
+Properties on a class must be unique, regardless of the type of property. Arma 3 does not report duplicated properties, the last definition is used.
 "
     }
 
@@ -73,25 +73,39 @@ impl LintRunner<LintData> for Runner {
         let Some(processed) = processed else {
             return vec![];
         };
-        let mut seen: HashMap<String, Vec<(bool, Ident)>> = HashMap::new();
+        let mut seen: HashMap<String, Vec<Seen>> = HashMap::new();
         duplicate_properties_inner("", &target.0, &mut seen);
-        let mut codes: Codes = Vec::new();
-        for (_, idents) in seen {
-            if idents.len() > 1 && !idents.iter().all(|(class, _)| *class) {
-                codes.push(Arc::new(CodeC02DuplicateProperty::new(
-                    idents.iter().map(|(_, i)| i.clone()).collect(),
+        let mut duplicates = seen
+            .into_values()
+            .filter(|seen| seen.len() > 1 && !seen.iter().all(|(class, _, _)| *class))
+            .collect::<Vec<_>>();
+        // report in source order
+        duplicates.sort_by_key(|seen| seen[0].1.span.start);
+        duplicates
+            .into_iter()
+            .map(|seen| {
+                let used = seen.last().and_then(|(_, name, value)| {
+                    value.as_ref().map(|value| {
+                        format!("{} = {}", name.as_str(), processed.extract(value.clone()))
+                    })
+                });
+                Arc::new(CodeC02DuplicateProperty::new(
+                    seen.into_iter().map(|(_, name, _)| name).collect(),
+                    used,
                     processed,
-                )));
-            }
-        }
-        codes
+                )) as Arc<dyn Code>
+            })
+            .collect()
     }
 }
 
+/// Is the property a class, its name, and the span of its value
+type Seen = (bool, Ident, Option<Range<usize>>);
+
 fn duplicate_properties_inner(
     scope: &str,
     properties: &[Property],
-    seen: &mut HashMap<String, Vec<(bool, Ident)>>,
+    seen: &mut HashMap<String, Vec<Seen>>,
 ) {
     for property in properties {
         match property {
@@ -106,13 +120,19 @@ fn duplicate_properties_inner(
                 let entry = seen
                     .entry(format!("{}.{}", scope, name.value.to_lowercase()))
                     .or_default();
-                entry.push((true, name.clone()));
+                entry.push((true, name.clone(), None));
             }
-            Property::Entry { name, .. } | Property::MissingSemicolon(name, _) => {
+            Property::Entry { name, value, .. } => {
                 let entry = seen
                     .entry(format!("{}.{}", scope, name.value.to_lowercase()))
                     .or_default();
-                entry.push((false, name.clone()));
+                entry.push((false, name.clone(), Some(value.span())));
+            }
+            Property::MissingSemicolon(name, _) => {
+                let entry = seen
+                    .entry(format!("{}.{}", scope, name.value.to_lowercase()))
+                    .or_default();
+                entry.push((false, name.clone(), None));
             }
             _ => (),
         }
@@ -122,6 +142,7 @@ fn duplicate_properties_inner(
 #[allow(clippy::module_name_repetitions)]
 pub struct CodeC02DuplicateProperty {
     conflicts: Vec<Ident>,
+    used: Option<String>,
     diagnostic: Option<Diagnostic>,
 }
 
@@ -142,6 +163,12 @@ impl Code for CodeC02DuplicateProperty {
         "duplicate property".to_string()
     }
 
+    fn note(&self) -> Option<String> {
+        self.used
+            .as_ref()
+            .map(|used| format!("the last definition is used, `{used}`"))
+    }
+
     fn diagnostic(&self) -> Option<Diagnostic> {
         self.diagnostic.clone()
     }
@@ -149,9 +176,10 @@ impl Code for CodeC02DuplicateProperty {
 
 impl CodeC02DuplicateProperty {
     #[must_use]
-    pub fn new(conflicts: Vec<Ident>, processed: &Processed) -> Self {
+    pub fn new(conflicts: Vec<Ident>, used: Option<String>, processed: &Processed) -> Self {
         Self {
             conflicts,
+            used,
             diagnostic: None,
         }
         .generate_processed(processed)
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, sync::Arc};
+use std::sync::Arc;
 
 use hemtt_common::config::{LintConfig, ProjectConfig};
 use hemtt_workspace::{
@@ -50,7 +50,7 @@ class MyClass {
 
 ### Explanation
 
-Children classes can only be defined once in a class.
+Children classes can only be defined once in a class. Arma 3 does not report duplicated classes, the definitions are silently merged, with later properties overriding earlier ones.
 "
     }
 
@@ -83,7 +83,8 @@ impl LintRunner<LintData> for Runner {
 
 #[must_use]
 pub fn check(properties: &[Property], processed: &Processed) -> Codes {
-    let mut defined: HashMap<String, Vec<Class>> = HashMap::new();
+    // in order of the first definition, so the codes are reported in source order
+    let mut defined: Vec<(String, Vec<Class>)> = Vec::new();
     let mut codes = Vec::new();
     for property in properties {
         if let Property::Class(c) = property {
@@ -92,10 +93,7 @@ pub fn check(properties: &[Property], processed: &Processed) -> Codes {
                     codes.extend(check(properties, processed));
                 }
                 Class::External { name } => {
-                    defined
-                        .entry(name.value.to_lowercase())
-                        .or_default()
-                        .push(c.clone());
+                    define(&mut defined, name.value.to_lowercase(), c);
                 }
                 Class::Local {
                     name,
@@ -104,10 +102,7 @@ pub fn check(properties: &[Property], processed: &Processed) -> Codes {
                     err_missing_braces: _,
                 } => {
                     codes.extend(check(properties, processed));
-                    defined
-                        .entry(name.value.to_lowercase())
-                        .or_default()
-                        .push(c.clone());
+                    define(&mut defined, name.value.to_lowercase(), c);
                 }
             }
         }
@@ -122,6 +117,14 @@ pub fn check(properties: &[Property], processed: &Processed) -> Codes {
     codes
 }
 
+fn define(defined: &mut Vec<(String, Vec<Class>)>, name: String, class: &Class) {
+    if let Some((_, classes)) = defined.iter_mut().find(|(other, _)| other == &name) {
+        classes.push(class.clone());
+    } else {
+        defined.push((name, vec![class.clone()]));
+    }
+}
+
 #[allow(clippy::module_name_repetitions)]
 pub struct CodeC03DuplicateClasses {
     classes: Vec<Class>,
@@ -142,7 +145,11 @@ impl Code for CodeC03DuplicateClasses {
     }
 
     fn label_message(&self) -> String {
-        "defined multiple times".to_string()
+        "defined again here".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("the definitions are merged, later properties override earlier ones".to_string())
     }
 
     fn help(&self) -> Option<String> {
@@ -174,12 +181,17 @@ impl CodeC03DuplicateClasses {
     }
 
     fn generate_processed(mut self, processed: &Processed) -> Self {
-        let Some(name) = self.classes[0].name() else {
+        let Some(name) = self.classes[1].name() else {
             panic!("CodeC03DuplicateClasses::generate_processed called on class without name");
         };
         self.diagnostic = Diagnostic::from_code_processed(&self, name.span.clone(), processed);
         if let Some(diag) = &mut self.diagnostic {
-            for class in self.classes.iter().skip(1) {
+            for (i, class) in self.classes.iter().enumerate() {
+                let message = match i {
+                    0 => "first defined here",
+                    1 => continue,
+                    _ => "also defined here",
+                };
                 let map = processed
                     .mapping(class.name().expect("class should have name").span.start)
                     .expect("mapping should exist");
@@ -191,7 +203,7 @@ impl CodeC03DuplicateClasses {
                             ..map.original_start()
                                 + class.name().expect("class should have name").span.len(),
                     )
-                    .with_message("also defined here"),
+                    .with_message(message),
                 );
             }
         }
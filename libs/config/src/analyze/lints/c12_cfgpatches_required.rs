@@ -0,0 +1,360 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, Class, Config, Property};
+
+crate::analyze::lint!(LintC12CfgPatchesRequired);
+
+/// Entries every class in `CfgPatches` must have
+const REQUIRED: [(&str, &str); 4] = [
+    ("units", "units[]"),
+    ("weapons", "weapons[]"),
+    ("requiredversion", "requiredVersion"),
+    ("requiredaddons", "requiredAddons[]"),
+];
+
+impl Lint<LintData> for LintC12CfgPatchesRequired {
+    fn ident(&self) -> &'static str {
+        "cfgpatches_required"
+    }
+
+    fn sort(&self) -> u32 {
+        120
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on `config.cpp` files without a `CfgPatches` class for the addon, or with missing required entries"
+    }
+
+    fn documentation(&self) -> &'static str {
+r#"### Example
+
+**Incorrect**, in `addons/main/config.cpp`
+```hpp
+class CfgPatches {
+    class abe_main {
+        units[] = {};
+        requiredVersion = 2.18;
+    };
+};
+```
+
+**Correct**, in `addons/main/config.cpp`
+```hpp
+class CfgPatches {
+    class abe_main {
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = 2.18;
+        requiredAddons[] = {"cba_main"};
+    };
+};
+```
+
+### Explanation
+
+Every `config.cpp` should have a class in `CfgPatches` named after its addon folder, usually `{prefix}_{folder}`. Each class in `CfgPatches` should contain `units[]`, `weapons[]`, `requiredVersion`, and `requiredAddons[]`. They are used by the game to load the addons in the correct order, and by Zeus and the editor to find the units and weapons of the addon.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Config;
+    fn run(
+        &self,
+        project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Config,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        // The root file is the only source that was not included
+        let Some(root) = processed
+            .sources()
+            .into_iter()
+            .map(|(path, _)| path)
+            .find(|path| !processed.included_files().contains(path))
+        else {
+            return vec![];
+        };
+        if !root.filename().eq_ignore_ascii_case("config.cpp") {
+            return vec![];
+        }
+        let Some(first) = target.0.first() else {
+            return vec![];
+        };
+        let Some(Property::Class(Class::Local {
+            name: cfgpatches,
+            properties: patches,
+            ..
+        })) = target
+            .0
+            .iter()
+            .find(|p| p.name().value.eq_ignore_ascii_case("cfgpatches"))
+        else {
+            return vec![Arc::new(CodeC12MissingCfgPatches::new(
+                first.name().span.clone(),
+                processed,
+                config.severity(),
+            ))];
+        };
+        let mut codes: Codes = Vec::new();
+        let folder = root.parent().filename().to_lowercase();
+        let classes = patches
+            .iter()
+            .filter_map(|p| match p {
+                Property::Class(Class::Local {
+                    name, properties, ..
+                }) => Some((name, properties)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if !folder.is_empty()
+            && !classes.iter().any(|(name, _)| {
+                let name = name.value.to_lowercase();
+                name == folder || name.ends_with(&format!("_{folder}"))
+            })
+        {
+            codes.push(Arc::new(CodeC12MissingAddonClass::new(
+                cfgpatches.span.clone(),
+                project.map_or_else(
+                    || folder.clone(),
+                    |project| format!("{}_{folder}", project.prefix().to_lowercase()),
+                ),
+                processed,
+                config.severity(),
+            )));
+        }
+        for (name, properties) in classes {
+            let missing = REQUIRED
+                .iter()
+                .filter(|(required, _)| {
+                    !properties
+                        .iter()
+                        .any(|p| !p.is_class() && p.name().value.eq_ignore_ascii_case(required))
+                })
+                .map(|(_, display)| *display)
+                .collect::<Vec<_>>();
+            if !missing.is_empty() {
+                codes.push(Arc::new(CodeC12MissingEntries::new(
+                    name.span.clone(),
+                    name.value.clone(),
+                    missing,
+                    processed,
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+pub struct CodeC12MissingCfgPatches {
+    span: Range<usize>,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC12MissingCfgPatches {
+    fn ident(&self) -> &'static str {
+        "L-C12MC"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#cfgpatches_required")
+    }
+
+    fn message(&self) -> String {
+        "`config.cpp` has no `CfgPatches` class".to_string()
+    }
+
+    fn label_message(&self) -> String {
+        "`CfgPatches` was expected in this file".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("add a `CfgPatches` class, without it the game will not load the addon".to_string())
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC12MissingCfgPatches {
+    #[must_use]
+    pub fn new(span: Range<usize>, processed: &Processed, severity: Severity) -> Self {
+        Self {
+            span,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
+
+pub struct CodeC12MissingAddonClass {
+    span: Range<usize>,
+    expected: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC12MissingAddonClass {
+    fn ident(&self) -> &'static str {
+        "L-C12MA"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#cfgpatches_required")
+    }
+
+    fn message(&self) -> String {
+        "`CfgPatches` has no class for this addon".to_string()
+    }
+
+    fn label_message(&self) -> String {
+        format!("expected a class named `{}`", self.expected)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("classes in `CfgPatches` are usually named after the addon folder".to_string())
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC12MissingAddonClass {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        expected: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            expected,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
+
+pub struct CodeC12MissingEntries {
+    span: Range<usize>,
+    class: String,
+    missing: Vec<&'static str>,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC12MissingEntries {
+    fn ident(&self) -> &'static str {
+        "L-C12ME"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#cfgpatches_required")
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`CfgPatches` class `{}` is missing required entries",
+            self.class
+        )
+    }
+
+    fn label_message(&self) -> String {
+        format!(
+            "missing {}",
+            self.missing
+                .iter()
+                .map(|entry| format!("`{entry}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(format!(
+            "every class in `CfgPatches` should contain {}",
+            REQUIRED
+                .iter()
+                .map(|(_, entry)| format!("`{entry}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC12MissingEntries {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        class: String,
+        missing: Vec<&'static str>,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            class,
+            missing,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
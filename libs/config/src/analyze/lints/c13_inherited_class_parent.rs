@@ -0,0 +1,260 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, Class, Config, Property};
+
+crate::analyze::lint!(LintC13InheritedClassParent);
+
+impl Lint<LintData> for LintC13InheritedClassParent {
+    fn ident(&self) -> &'static str {
+        "inherited_class_parent"
+    }
+
+    fn sort(&self) -> u32 {
+        130
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on inherited classes that are redefined without a parent"
+    }
+
+    fn documentation(&self) -> &'static str {
+"### Example
+
+**Incorrect**
+```hpp
+class Car;
+class Car_F: Car {
+    class HitPoints;
+};
+class MyCar: Car_F {
+    class HitPoints {
+        class HitHull {
+            armor = 2;
+        };
+    };
+};
+```
+
+**Correct**
+```hpp
+class Car;
+class Car_F: Car {
+    class HitPoints;
+};
+class MyCar: Car_F {
+    class HitPoints: HitPoints {
+        class HitHull: HitHull {
+            armor = 2;
+        };
+    };
+};
+```
+
+### Explanation
+
+A class that is already inherited from the parent must keep its parent when it is redefined. Without one, the class no longer inherits any of its original properties, and the game reports `Updating base class` errors when another addon defines it with a parent.
+
+The lint can only check parents that are defined in the same config, classes that only extend an external class are not checked.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Config;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Config,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        let mut scopes = Vec::new();
+        check(&target.0, &[], &mut scopes, processed, config.severity())
+    }
+}
+
+/// The classes visible in a scope, and the classes it inherits from its parent
+struct Scope<'a> {
+    defined: Vec<&'a Class>,
+    inherited: &'a [Property],
+}
+
+fn check<'a>(
+    properties: &'a [Property],
+    inherited: &'a [Property],
+    scopes: &mut Vec<Scope<'a>>,
+    processed: &Processed,
+    severity: Severity,
+) -> Codes {
+    let mut codes: Codes = Vec::new();
+    scopes.push(Scope {
+        defined: Vec::new(),
+        inherited,
+    });
+    for property in properties {
+        let Property::Class(c) = property else {
+            continue;
+        };
+        match c {
+            Class::Root { properties } => {
+                codes.extend(check(properties, &[], scopes, processed, severity));
+            }
+            Class::External { .. } => {}
+            Class::Local {
+                name,
+                parent,
+                properties,
+                ..
+            } => {
+                if parent.is_none() {
+                    if let Some(original) = find(inherited, &name.value) {
+                        codes.push(Arc::new(CodeC13InheritedClassParent::new(
+                            name.span.clone(),
+                            original.name().expect("inherited class has a name").value.clone(),
+                            processed,
+                            severity,
+                        )));
+                    }
+                }
+                let parent = parent.as_ref().and_then(|parent| {
+                    resolve(scopes, &parent.value, parent.value.eq_ignore_ascii_case(&name.value))
+                });
+                codes.extend(check(
+                    properties,
+                    parent.map_or(&[], Class::properties),
+                    scopes,
+                    processed,
+                    severity,
+                ));
+            }
+        }
+        scopes
+            .last_mut()
+            .expect("scope was pushed")
+            .defined
+            .push(c);
+    }
+    scopes.pop();
+    codes
+}
+
+/// Find a class by name in a list of properties
+fn find<'a>(properties: &'a [Property], name: &str) -> Option<&'a Class> {
+    properties.iter().rev().find_map(|p| match p {
+        Property::Class(c) if c.name().is_some_and(|n| n.value.eq_ignore_ascii_case(name)) => {
+            Some(c)
+        }
+        _ => None,
+    })
+}
+
+/// Resolve the parent of a class, from the innermost scope outwards
+///
+/// A class that extends a class of the same name, `class HitPoints: HitPoints`,
+/// can only refer to the inherited class, or one in an outer scope
+fn resolve<'a>(scopes: &[Scope<'a>], name: &str, same_name: bool) -> Option<&'a Class> {
+    let mut scopes = scopes.iter().rev();
+    let current = scopes.next()?;
+    let local = if same_name {
+        None
+    } else {
+        current
+            .defined
+            .iter()
+            .rev()
+            .find(|c| c.name().is_some_and(|n| n.value.eq_ignore_ascii_case(name)))
+            .copied()
+    };
+    local.or_else(|| find(current.inherited, name)).or_else(|| {
+        scopes.find_map(|scope| {
+            scope
+                .defined
+                .iter()
+                .rev()
+                .find(|c| c.name().is_some_and(|n| n.value.eq_ignore_ascii_case(name)))
+                .copied()
+                .or_else(|| find(scope.inherited, name))
+        })
+    })
+}
+
+pub struct CodeC13InheritedClassParent {
+    span: Range<usize>,
+    class: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC13InheritedClassParent {
+    fn ident(&self) -> &'static str {
+        "L-C13"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#inherited_class_parent")
+    }
+
+    fn message(&self) -> String {
+        format!("inherited class `{}` is redefined without a parent", self.class)
+    }
+
+    fn label_message(&self) -> String {
+        "no parent".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("the class will no longer inherit any of its original properties".to_string())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "extend the inherited class with `class {}: {}`",
+            self.class, self.class
+        ))
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC13InheritedClassParent {
+    #[must_use]
+    pub fn new(span: Range<usize>, class: String, processed: &Processed, severity: Severity) -> Self {
+        Self {
+            span,
+            class,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -0,0 +1,337 @@
+use std::{collections::HashMap, ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+    WorkspacePath,
+};
+
+use crate::{analyze::LintData, Class, Config, Ident, Property, Str, Value};
+
+crate::analyze::lint!(LintC14CfgFunctionsFiles);
+
+impl Lint<LintData> for LintC14CfgFunctionsFiles {
+    fn ident(&self) -> &'static str {
+        "cfgfunctions_files"
+    }
+
+    fn sort(&self) -> u32 {
+        140
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on `CfgFunctions` entries whose file does not exist, and `fn_*.sqf` files that are not in `CfgFunctions`"
+    }
+
+    fn documentation(&self) -> &'static str {
+r#"### Example
+
+**Incorrect**, with only `fn_init.sqf` and `fn_oldInit.sqf` in `addons/main/functions`
+```hpp
+class CfgFunctions {
+    class ABE {
+        class Main {
+            file = "\z\abe\addons\main\functions";
+            class init {};
+            class respawn {};
+        };
+    };
+};
+```
+
+**Correct**
+```hpp
+class CfgFunctions {
+    class ABE {
+        class Main {
+            file = "\z\abe\addons\main\functions";
+            class init {};
+            class oldInit {};
+        };
+    };
+};
+```
+
+### Explanation
+
+Each function in `CfgFunctions` is compiled from its `file`, or from `fn_<name>.sqf` in the `file` folder of its category. If the file does not exist the game reports an error when it starts, and the function is never defined.
+
+A `fn_*.sqf` file in the folder of a category that is not registered is never compiled, which is usually a missing entry.
+
+Functions without a `file` on themselves or their category are not checked.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn minimum_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Config;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Config,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        // The root file is the only source that was not included
+        let Some(root) = processed
+            .sources()
+            .into_iter()
+            .map(|(path, _)| path)
+            .find(|path| !processed.included_files().contains(path))
+        else {
+            return vec![];
+        };
+        let mut codes: Codes = Vec::new();
+        // Categories can share a folder, so files are checked against every category using it
+        let mut folders: HashMap<String, (WorkspacePath, &Str, Vec<String>)> = HashMap::new();
+        for (_, cfgfunctions) in local_classes(&target.0)
+            .filter(|(name, _)| name.value.eq_ignore_ascii_case("cfgfunctions"))
+        {
+            for (_, tag) in local_classes(cfgfunctions) {
+                for (_, category) in local_classes(tag) {
+                    let folder = string_entry(category, "file");
+                    let folder_path = folder.and_then(|folder| locate(&root, folder.value()));
+                    if let (Some(folder), Some(path)) = (folder, &folder_path) {
+                        folders
+                            .entry(path.as_str().to_lowercase())
+                            .or_insert_with(|| (path.clone(), folder, Vec::new()))
+                            .2
+                            .extend(
+                                local_classes(category).map(|(name, _)| name.value.to_lowercase()),
+                            );
+                    }
+                    for (name, function) in local_classes(category) {
+                        let ext = string_entry(function, "ext").map_or(".sqf", Str::value);
+                        let (file, span) = if let Some(file) = string_entry(function, "file") {
+                            (file.value().to_string(), file.span())
+                        } else if let Some(folder) = folder {
+                            (
+                                format!("{}\\fn_{}{ext}", folder.value(), name.value),
+                                name.span.clone(),
+                            )
+                        } else {
+                            continue;
+                        };
+                        if locate(&root, &file).is_none() {
+                            codes.push(Arc::new(CodeC14MissingFile::new(
+                                span,
+                                name.value.clone(),
+                                file,
+                                processed,
+                                config.severity(),
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+        // A file that is never compiled is not an error on its own
+        let unregistered_severity = if config.severity() > Severity::Warning {
+            Severity::Warning
+        } else {
+            config.severity()
+        };
+        let mut folders = folders.into_values().collect::<Vec<_>>();
+        folders.sort_by_key(|(path, _, _)| path.as_str().to_string());
+        for (path, folder, registered) in folders {
+            let Ok(mut files) = path.read_dir() else {
+                continue;
+            };
+            files.sort_by_key(|file| file.as_str().to_string());
+            for file in files {
+                let filename = file.filename();
+                let Some(name) = filename
+                    .to_lowercase()
+                    .strip_prefix("fn_")
+                    .and_then(|name| name.strip_suffix(".sqf"))
+                    .map(ToString::to_string)
+                else {
+                    continue;
+                };
+                if !registered.contains(&name) && file.is_file().unwrap_or_default() {
+                    codes.push(Arc::new(CodeC14Unregistered::new(
+                        folder.span(),
+                        filename,
+                        processed,
+                        unregistered_severity,
+                    )));
+                }
+            }
+        }
+        codes
+    }
+}
+
+/// The local classes in a list of properties
+fn local_classes(properties: &[Property]) -> impl Iterator<Item = (&Ident, &[Property])> {
+    properties.iter().filter_map(|property| match property {
+        Property::Class(Class::Local {
+            name, properties, ..
+        }) => Some((name, properties.as_slice())),
+        _ => None,
+    })
+}
+
+/// A string entry in a list of properties
+fn string_entry<'a>(properties: &'a [Property], key: &str) -> Option<&'a Str> {
+    properties.iter().find_map(|property| match property {
+        Property::Entry {
+            name,
+            value: Value::Str(value),
+            ..
+        } if name.value.eq_ignore_ascii_case(key) => Some(value),
+        _ => None,
+    })
+}
+
+/// Find a file the game would load, paths in configs are relative to the game root
+fn locate(root: &WorkspacePath, path: &str) -> Option<WorkspacePath> {
+    let path = if path.starts_with(['\\', '/']) {
+        path.to_string()
+    } else {
+        format!("\\{path}")
+    };
+    root.locate(&path).ok().flatten().map(|located| located.path)
+}
+
+pub struct CodeC14MissingFile {
+    span: Range<usize>,
+    function: String,
+    file: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC14MissingFile {
+    fn ident(&self) -> &'static str {
+        "L-C14MF"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#cfgfunctions_files")
+    }
+
+    fn message(&self) -> String {
+        format!("the file of function `{}` does not exist", self.function)
+    }
+
+    fn label_message(&self) -> String {
+        "file not found".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(format!("expected `{}`", self.file))
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC14MissingFile {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        function: String,
+        file: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            function,
+            file,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
+
+pub struct CodeC14Unregistered {
+    span: Range<usize>,
+    file: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC14Unregistered {
+    fn ident(&self) -> &'static str {
+        "L-C14UR"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#cfgfunctions_files")
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` is not registered in `CfgFunctions`", self.file)
+    }
+
+    fn label_message(&self) -> String {
+        format!("`{}` is in this folder", self.file)
+    }
+
+    fn help(&self) -> Option<String> {
+        let name = self
+            .file
+            .get(3..self.file.len() - 4)
+            .unwrap_or(self.file.as_str());
+        Some(format!(
+            "add `class {name} {{}};` to the category, or remove the file"
+        ))
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC14Unregistered {
+    #[must_use]
+    pub fn new(span: Range<usize>, file: String, processed: &Processed, severity: Severity) -> Self {
+        Self {
+            span,
+            file,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
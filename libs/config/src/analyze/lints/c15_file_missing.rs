@@ -0,0 +1,273 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+    WorkspacePath,
+};
+
+use crate::{analyze::LintData, Item, Property, Str, Value};
+
+crate::analyze::lint!(LintC15FileMissing);
+
+/// Extensions of files that are checked
+const EXTENSIONS: [&str; 6] = ["paa", "p3d", "rtm", "wss", "ogg", "wav"];
+
+impl Lint<LintData> for LintC15FileMissing {
+    fn ident(&self) -> &'static str {
+        "file_missing"
+    }
+
+    fn sort(&self) -> u32 {
+        150
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on textures, models, animations, and sounds that do not exist"
+    }
+
+    fn documentation(&self) -> &'static str {
+r#"### Configuration
+
+- **prefixes**: Prefixes of files from outside of the project, such as other mods. Files in `\a3\` are always allowed.
+
+```toml
+[lints.config.file_missing]
+options.prefixes = [
+    "\\x\\cba\\addons",
+]
+```
+
+### Example
+
+**Incorrect**, when `addons/main/data/icon_ca.paa` does not exist
+```hpp
+class MyClass {
+    picture = "\z\abe\addons\main\data\icon_ca.paa";
+};
+```
+
+**Correct**
+```hpp
+class MyClass {
+    picture = "\z\abe\addons\main\data\icon_co.paa";
+};
+```
+
+### Explanation
+
+Files referenced by configs are not checked by the game until they are used, a missing texture is shown as white, and a missing model or sound is not shown at all. Values ending in `.paa`, `.p3d`, `.rtm`, `.wss`, `.ogg`, or `.wav` must point to a file in the project, or start with one of the `prefixes`. A `.paa` is also found when there is a `.png` or `.tga` with the same name, and a `.ogg` or `.wss` when there is a `.wav`, which are converted when building with `paa.enabled` or `audio.enabled` in the `addon.toml`. Textures of the stages in a `.rvmat` are reported by `rvmat_texture_missing` instead.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = crate::Property;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &crate::Property,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        let Property::Entry { value, .. } = target else {
+            return vec![];
+        };
+        // Reported by `rvmat_texture_missing`
+        if is_stage_texture(processed, data, target) {
+            return vec![];
+        }
+        let values = match value {
+            Value::Str(value) => vec![value],
+            Value::Array(values) => strings(&values.items),
+            _ => return vec![],
+        };
+        let Some((root, _)) = processed.sources().into_iter().next() else {
+            return vec![];
+        };
+        let prefixes = prefixes(config);
+        let mut codes: Codes = Vec::new();
+        for value in values {
+            if let Some(code) = check(&root, value, &prefixes, processed, config.severity()) {
+                codes.push(code);
+            }
+        }
+        codes
+    }
+}
+
+fn check(
+    root: &WorkspacePath,
+    value: &Str,
+    prefixes: &[String],
+    processed: &Processed,
+    severity: Severity,
+) -> Option<Arc<dyn Code>> {
+    let path = value.value();
+    let (_, ext) = path.rsplit_once('.')?;
+    if !EXTENSIONS.iter().any(|e| e.eq_ignore_ascii_case(ext)) {
+        return None;
+    }
+    if exists(processed, root, path, prefixes) {
+        return None;
+    }
+    let span = value.span().start + 1..value.span().end - 1;
+    Some(Arc::new(CodeC15FileMissing::new(
+        span,
+        path.to_string(),
+        processed,
+        severity,
+    )))
+}
+
+/// Strings in an array, including nested arrays such as `samples[]` of sound shaders
+fn strings(items: &[Item]) -> Vec<&Str> {
+    items
+        .iter()
+        .flat_map(|item| match item {
+            Item::Str(value) => vec![value],
+            Item::Array(items) => strings(items),
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// The `prefixes` option, always including `\a3\`
+pub(crate) fn prefixes(config: &LintConfig) -> Vec<String> {
+    let mut prefixes = vec!["a3".to_string()];
+    if let Some(toml::Value::Array(configured)) = config.option("prefixes") {
+        prefixes.extend(configured.iter().filter_map(|p| p.as_str().map(normalize)));
+    }
+    prefixes
+}
+
+/// Does the file exist in the project, start with one of the prefixes, or have a source that is converted while building
+///
+/// The files are located through [`Processed::locate`], so the result is checked again when a cached config is used
+pub(crate) fn exists(
+    processed: &Processed,
+    root: &WorkspacePath,
+    path: &str,
+    prefixes: &[String],
+) -> bool {
+    let normalized = normalize(path);
+    if prefixes
+        .iter()
+        .any(|prefix| normalized.starts_with(&format!("{prefix}\\")))
+    {
+        return true;
+    }
+    // Paths in configs are relative to the game root, with or without a leading slash
+    let path_root = format!("\\{}", path.trim_start_matches(['\\', '/']));
+    if processed.locate(root, &path_root).is_some() {
+        return true;
+    }
+    let Some((stem, ext)) = path_root.rsplit_once('.') else {
+        return false;
+    };
+    // Textures and sounds can be converted from their source while building
+    let sources: &[&str] = match ext.to_lowercase().as_str() {
+        "paa" => &["png", "tga"],
+        "ogg" | "wss" => &["wav"],
+        _ => &[],
+    };
+    sources
+        .iter()
+        .any(|source| processed.locate(root, &format!("{stem}.{source}")).is_some())
+}
+
+/// Is the property the `texture` of a stage in a material
+pub(crate) fn is_stage_texture(processed: &Processed, data: &LintData, target: &Property) -> bool {
+    let class = data.path.trim_start_matches('/');
+    target.name().as_str().eq_ignore_ascii_case("texture")
+        && !class.contains('/')
+        && class
+            .get(..5)
+            .is_some_and(|stage| stage.eq_ignore_ascii_case("stage"))
+        && is_rvmat(processed)
+}
+
+/// Is the processed file a material
+pub(crate) fn is_rvmat(processed: &Processed) -> bool {
+    processed.sources().into_iter().next().is_some_and(|(root, _)| {
+        root.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("rvmat"))
+    })
+}
+
+/// Lowercase with `\`, without leading or trailing slashes
+fn normalize(path: &str) -> String {
+    path.replace('/', "\\")
+        .trim_matches('\\')
+        .to_lowercase()
+}
+
+pub struct CodeC15FileMissing {
+    span: Range<usize>,
+    file: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC15FileMissing {
+    fn ident(&self) -> &'static str {
+        "L-C15"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#file_missing")
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` does not exist", self.file)
+    }
+
+    fn label_message(&self) -> String {
+        "file not found".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("if the file is from another mod, add its prefix to `options.prefixes`".to_string())
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC15FileMissing {
+    #[must_use]
+    pub fn new(span: Range<usize>, file: String, processed: &Processed, severity: Severity) -> Self {
+        Self {
+            span,
+            file,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -0,0 +1,175 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, Property, Value};
+
+use super::c15_file_missing::{exists, is_stage_texture, prefixes};
+
+crate::analyze::lint!(LintC16RvmatTextureMissing);
+
+impl Lint<LintData> for LintC16RvmatTextureMissing {
+    fn ident(&self) -> &'static str {
+        "rvmat_texture_missing"
+    }
+
+    fn sort(&self) -> u32 {
+        160
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on textures of `.rvmat` stages that do not exist"
+    }
+
+    fn documentation(&self) -> &'static str {
+r#"### Configuration
+
+- **prefixes**: Prefixes of files from outside of the project, such as other mods. Files in `\a3\` are always allowed.
+
+```toml
+[lints.config.rvmat_texture_missing]
+options.prefixes = [
+    "\\x\\cba\\addons",
+]
+```
+
+### Example
+
+**Incorrect**, when `addons/main/data/body_nohq.paa` does not exist
+```hpp
+class Stage1 {
+    texture = "\z\abe\addons\main\data\body_nohq.paa";
+    uvSource = "tex";
+};
+```
+
+**Correct**
+```hpp
+class Stage1 {
+    texture = "\z\abe\addons\main\data\body_no.paa";
+    uvSource = "tex";
+};
+```
+
+### Explanation
+
+A material with a missing texture is shown pink in-game, without any error. The `texture` of each stage must point to a file in the project, or start with one of the `prefixes`. Procedural textures, such as `#(argb,8,8,3)color(0.5,0.5,0.5,1)`, are not checked. A `.paa` is also found when there is a `.png` or `.tga` with the same name, which are converted when building with `paa.enabled` in the `addon.toml`.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = crate::Property;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &crate::Property,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        let Property::Entry {
+            value: Value::Str(value),
+            ..
+        } = target
+        else {
+            return vec![];
+        };
+        if !is_stage_texture(processed, data, target) {
+            return vec![];
+        }
+        let path = value.value();
+        if path.is_empty() || path.starts_with('#') {
+            return vec![];
+        }
+        let Some((root, _)) = processed.sources().into_iter().next() else {
+            return vec![];
+        };
+        if exists(processed, &root, path, &prefixes(config)) {
+            return vec![];
+        }
+        let span = value.span().start + 1..value.span().end - 1;
+        vec![Arc::new(CodeC16RvmatTextureMissing::new(
+            span,
+            path.to_string(),
+            processed,
+            config.severity(),
+        ))]
+    }
+}
+
+pub struct CodeC16RvmatTextureMissing {
+    span: Range<usize>,
+    file: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC16RvmatTextureMissing {
+    fn ident(&self) -> &'static str {
+        "L-C16"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#rvmat_texture_missing")
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` does not exist", self.file)
+    }
+
+    fn label_message(&self) -> String {
+        "texture not found".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("if the texture is from another mod, add its prefix to `options.prefixes`".to_string())
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("materials with a missing texture are shown pink in-game".to_string())
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC16RvmatTextureMissing {
+    #[must_use]
+    pub fn new(span: Range<usize>, file: String, processed: &Processed, severity: Severity) -> Self {
+        Self {
+            span,
+            file,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -0,0 +1,168 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, rvmat, Config};
+
+use super::c15_file_missing::is_rvmat;
+
+crate::analyze::lint!(LintC17RvmatShaderUnknown);
+
+impl Lint<LintData> for LintC17RvmatShaderUnknown {
+    fn ident(&self) -> &'static str {
+        "rvmat_shader_unknown"
+    }
+
+    fn sort(&self) -> u32 {
+        170
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on `PixelShaderID` and `VertexShaderID` values in `.rvmat` files that are not known to the game"
+    }
+
+    fn documentation(&self) -> &'static str {
+r#"### Example
+
+**Incorrect**
+```hpp
+PixelShaderID = "SuperShader";
+VertexShaderID = "Super";
+```
+
+**Correct**
+```hpp
+PixelShaderID = "Super";
+VertexShaderID = "Super";
+```
+
+### Explanation
+
+The game does not report a material with an unknown shader, it is shown pink in-game. `PixelShaderID` and `VertexShaderID` must be the name of a shader known to the game, such as `Super`, `Multi`, `NormalMap`, or `Glass`. It is a warning, as the game may know shaders that HEMTT does not.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = Config;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Config,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        if !is_rvmat(processed) {
+            return vec![];
+        }
+        let mut codes: Codes = Vec::new();
+        for (key, shader, known) in [
+            (
+                "PixelShaderID",
+                rvmat::pixel_shader(target),
+                rvmat::is_pixel_shader as fn(&str) -> bool,
+            ),
+            (
+                "VertexShaderID",
+                rvmat::vertex_shader(target),
+                rvmat::is_vertex_shader,
+            ),
+        ] {
+            let Some(shader) = shader else {
+                continue;
+            };
+            if known(shader.value()) {
+                continue;
+            }
+            codes.push(Arc::new(CodeC17RvmatShaderUnknown::new(
+                shader.span().start + 1..shader.span().end - 1,
+                key,
+                shader.value().to_string(),
+                processed,
+                config.severity(),
+            )));
+        }
+        codes
+    }
+}
+
+pub struct CodeC17RvmatShaderUnknown {
+    span: Range<usize>,
+    key: &'static str,
+    shader: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC17RvmatShaderUnknown {
+    fn ident(&self) -> &'static str {
+        "L-C17"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#rvmat_shader_unknown")
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` is not a known `{}`", self.shader, self.key)
+    }
+
+    fn label_message(&self) -> String {
+        "unknown shader".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("materials with an unknown shader are shown pink in-game".to_string())
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC17RvmatShaderUnknown {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        key: &'static str,
+        shader: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            key,
+            shader,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -0,0 +1,212 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, rvmat, Config};
+
+use super::c15_file_missing::is_rvmat;
+
+crate::analyze::lint!(LintC18RvmatStageCount);
+
+impl Lint<LintData> for LintC18RvmatStageCount {
+    fn ident(&self) -> &'static str {
+        "rvmat_stage_count"
+    }
+
+    fn sort(&self) -> u32 {
+        180
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on `.rvmat` files with a different number of stages than their pixel shader uses"
+    }
+
+    fn documentation(&self) -> &'static str {
+r##"### Example
+
+**Incorrect**
+```hpp
+PixelShaderID = "Super";
+VertexShaderID = "Super";
+class Stage1 {
+    texture = "\z\abe\addons\main\data\body_nohq.paa";
+};
+class Stage2 {
+    texture = "#(argb,8,8,3)color(0.5,0.5,0.5,1,DT)";
+};
+```
+
+**Correct**
+```hpp
+PixelShaderID = "Super";
+VertexShaderID = "Super";
+class Stage1 {
+    texture = "\z\abe\addons\main\data\body_nohq.paa";
+};
+class Stage2 {
+    texture = "#(argb,8,8,3)color(0.5,0.5,0.5,1,DT)";
+};
+class Stage3 {
+    texture = "#(argb,8,8,3)color(0,0,0,0,MC)";
+};
+class Stage4 {
+    texture = "#(argb,8,8,3)color(1,1,1,1,AS)";
+};
+class Stage5 {
+    texture = "\z\abe\addons\main\data\body_smdi.paa";
+};
+class Stage6 {
+    texture = "#(ai,64,64,1)fresnel(1.5,0.22)";
+};
+class Stage7 {
+    texture = "a3\data_f\env_land_co.paa";
+};
+```
+
+### Explanation
+
+Some pixel shaders read a fixed number of stages, `Super` uses `Stage1` to `Stage7`, and the `NormalMap...SpecularMap` shaders use one stage for each map in their name. A material missing a stage is shown pink in-game, and an extra stage is never used. `StageTI`, used for thermal imaging, is not counted.
+"##
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = Config;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Config,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        if !is_rvmat(processed) {
+            return vec![];
+        }
+        let Some(shader) = rvmat::pixel_shader(target) else {
+            return vec![];
+        };
+        let Some(expected) = rvmat::expected_stages(shader.value()) else {
+            return vec![];
+        };
+        let stages = rvmat::stages(target);
+        let missing = (1..=expected)
+            .filter(|number| !stages.contains(number))
+            .collect::<Vec<_>>();
+        if missing.is_empty() && stages.len() == expected {
+            return vec![];
+        }
+        vec![Arc::new(CodeC18RvmatStageCount::new(
+            shader.span().start + 1..shader.span().end - 1,
+            shader.value().to_string(),
+            expected,
+            stages.len(),
+            missing,
+            processed,
+            config.severity(),
+        ))]
+    }
+}
+
+pub struct CodeC18RvmatStageCount {
+    span: Range<usize>,
+    shader: String,
+    expected: usize,
+    found: usize,
+    missing: Vec<usize>,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC18RvmatStageCount {
+    fn ident(&self) -> &'static str {
+        "L-C18"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#rvmat_stage_count")
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` uses {} stages, found {}",
+            self.shader, self.expected, self.found
+        )
+    }
+
+    fn label_message(&self) -> String {
+        format!("uses `Stage1` to `Stage{}`", self.expected)
+    }
+
+    fn help(&self) -> Option<String> {
+        if self.missing.is_empty() {
+            Some(format!(
+                "remove the stages after `Stage{}`",
+                self.expected
+            ))
+        } else {
+            Some(format!(
+                "add {}",
+                self.missing
+                    .iter()
+                    .map(|number| format!("`Stage{number}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC18RvmatStageCount {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        shader: String,
+        expected: usize,
+        found: usize,
+        missing: Vec<usize>,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            shader,
+            expected,
+            found,
+            missing,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
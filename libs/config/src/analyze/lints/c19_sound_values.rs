@@ -0,0 +1,276 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, Item, Number, Property, Value};
+
+crate::analyze::lint!(LintC19SoundValues);
+
+/// Louder than this is likely a decibel or percentage value
+const MAX_VOLUME: f64 = 10.0;
+
+impl Lint<LintData> for LintC19SoundValues {
+    fn ident(&self) -> &'static str {
+        "sound_values"
+    }
+
+    fn sort(&self) -> u32 {
+        190
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on volumes, pitches, and ranges in `CfgSounds` and `CfgSoundShaders` that are not numbers or out of range"
+    }
+
+    fn documentation(&self) -> &'static str {
+r#"### Example
+
+**Incorrect**
+```hpp
+class CfgSoundShaders {
+    class abe_shot_SoundShader {
+        samples[] = {{"\z\abe\addons\main\sounds\shot.wss", 1}};
+        volume = 100;
+        range = "far";
+    };
+};
+class CfgSounds {
+    class abe_alarm {
+        sound[] = {"\z\abe\addons\main\sounds\alarm.ogg", "loud", 0};
+    };
+};
+```
+
+**Correct**
+```hpp
+class CfgSoundShaders {
+    class abe_shot_SoundShader {
+        samples[] = {{"\z\abe\addons\main\sounds\shot.wss", 1}};
+        volume = 1;
+        range = 1500;
+    };
+};
+class CfgSounds {
+    class abe_alarm {
+        sound[] = {"\z\abe\addons\main\sounds\alarm.ogg", "db+10", 1, 200};
+    };
+};
+```
+
+### Explanation
+
+The game does not report invalid sound values, the sound is silent or can not be heard from the expected distance instead.
+
+In `CfgSoundShaders`, `volume` must be a number between 0 and 10, or an expression in a string, and `range` must be a number greater than 0.
+
+In `CfgSounds`, `sound[]` is the file, volume, pitch, and optionally the distance. The volume must be a number between 0 and 10, or a decibel value such as `"db+10"`. The pitch and distance must be numbers greater than 0.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = crate::Property;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &crate::Property,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        let Property::Entry { name, value, .. } = target else {
+            return vec![];
+        };
+        // Only the entries of classes directly inside `CfgSounds` or `CfgSoundShaders`
+        let Some((root, class)) = data.path.trim_start_matches('/').split_once('/') else {
+            return vec![];
+        };
+        if class.contains('/') {
+            return vec![];
+        }
+        let name = name.as_str().to_lowercase();
+        let problems = if root.eq_ignore_ascii_case("cfgsoundshaders") {
+            match name.as_str() {
+                "volume" => match value {
+                    // Expressions using sound controllers, such as `"engineOn*camPos"`
+                    Value::Str(_) => vec![],
+                    Value::Number(number) => volume(number).into_iter().collect(),
+                    _ => vec![not_number(value.span(), "volume")],
+                },
+                "range" => match value {
+                    Value::Number(number) => positive(number, "range").into_iter().collect(),
+                    _ => vec![not_number(value.span(), "range")],
+                },
+                _ => vec![],
+            }
+        } else if root.eq_ignore_ascii_case("cfgsounds") && name == "sound" {
+            let Value::Array(sound) = value else {
+                return vec![];
+            };
+            sound_array(&sound.items, value.span())
+        } else {
+            vec![]
+        };
+        problems
+            .into_iter()
+            .map(|(span, message, label)| {
+                Arc::new(CodeC19SoundValues::new(
+                    span,
+                    message,
+                    label,
+                    processed,
+                    config.severity(),
+                )) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+type Problem = (Range<usize>, String, String);
+
+/// `sound[] = {file, volume, pitch, distance}` in `CfgSounds`
+fn sound_array(items: &[Item], span: Range<usize>) -> Vec<Problem> {
+    if items.len() < 3 {
+        return vec![(
+            span,
+            "`sound[]` requires a file, volume, and pitch".to_string(),
+            "missing values".to_string(),
+        )];
+    }
+    let mut problems = Vec::new();
+    match &items[1] {
+        Item::Number(number) => problems.extend(volume(number)),
+        Item::Str(value) if value.value().to_lowercase().starts_with("db") => {}
+        // Reported by `invalid_value`
+        Item::Invalid(_) => {}
+        item => problems.push(not_number(item_span(item), "volume")),
+    }
+    for (item, what) in items[2..].iter().take(2).zip(["pitch", "distance"]) {
+        match item {
+            Item::Number(number) => problems.extend(positive(number, what)),
+            Item::Invalid(_) => {}
+            item => problems.push(not_number(item_span(item), what)),
+        }
+    }
+    problems
+}
+
+fn volume(number: &Number) -> Option<Problem> {
+    let value = number.as_f64();
+    if (0.0..=MAX_VOLUME).contains(&value) {
+        return None;
+    }
+    Some((
+        number.span(),
+        format!("`volume` must be between 0 and {MAX_VOLUME}, found {number}"),
+        "volume out of range".to_string(),
+    ))
+}
+
+fn positive(number: &Number, what: &str) -> Option<Problem> {
+    if number.as_f64() > 0.0 {
+        return None;
+    }
+    Some((
+        number.span(),
+        format!("`{what}` must be greater than 0, found {number}"),
+        format!("{what} out of range"),
+    ))
+}
+
+fn not_number(span: Range<usize>, what: &str) -> Problem {
+    (
+        span,
+        format!("`{what}` must be a number"),
+        "not a number".to_string(),
+    )
+}
+
+fn item_span(item: &Item) -> Range<usize> {
+    match item {
+        Item::Str(value) => value.span(),
+        Item::Number(number) => number.span(),
+        Item::Array(items) => items
+            .first()
+            .map(item_span)
+            .zip(items.last().map(item_span))
+            .map_or(0..0, |(first, last)| first.start..last.end),
+        Item::Invalid(span) => span.clone(),
+    }
+}
+
+pub struct CodeC19SoundValues {
+    span: Range<usize>,
+    message: String,
+    label: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC19SoundValues {
+    fn ident(&self) -> &'static str {
+        "L-C19"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#sound_values")
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn label_message(&self) -> String {
+        self.label.clone()
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC19SoundValues {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        message: String,
+        label: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            message,
+            label,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
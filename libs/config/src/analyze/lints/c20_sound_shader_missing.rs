@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    addons::SoundShaderReference,
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+};
+
+use crate::analyze::{sound_shaders::SoundShaderUsage, LintData};
+
+crate::analyze::lint!(LintC20SoundShaderMissing);
+
+impl Lint<LintData> for LintC20SoundShaderMissing {
+    fn ident(&self) -> &'static str {
+        "sound_shader_missing"
+    }
+
+    fn sort(&self) -> u32 {
+        200
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on sound shaders used in `CfgSoundSets` that are not defined in `CfgSoundShaders`"
+    }
+
+    fn documentation(&self) -> &'static str {
+r#"### Example
+
+**Incorrect**
+```hpp
+class CfgSoundShaders {
+    class abe_shot_close_SoundShader {
+        samples[] = {{"\z\abe\addons\main\sounds\shot.wss", 1}};
+        volume = 1;
+        range = 100;
+    };
+};
+class CfgSoundSets {
+    class abe_shot_SoundSet {
+        soundShaders[] = {"abe_shot_close_SoundShader", "abe_shot_far_SoundShader"};
+    };
+};
+```
+
+**Correct**
+```hpp
+class CfgSoundShaders {
+    class abe_shot_close_SoundShader {
+        samples[] = {{"\z\abe\addons\main\sounds\shot.wss", 1}};
+        volume = 1;
+        range = 100;
+    };
+    class abe_shot_far_SoundShader {
+        samples[] = {{"\z\abe\addons\main\sounds\shot_far.wss", 1}};
+        volume = 1;
+        range = 1500;
+    };
+};
+class CfgSoundSets {
+    class abe_shot_SoundSet {
+        soundShaders[] = {"abe_shot_close_SoundShader", "abe_shot_far_SoundShader"};
+    };
+};
+```
+
+### Explanation
+
+Sound shaders used in `CfgSoundSets` that are using the project's prefix (`abe_` in this case) must be defined in `CfgSoundShaders` of any addon in the project. The game skips a missing sound shader without an error, and the sound set is silent at that distance.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = SoundShaderUsage;
+    fn run(
+        &self,
+        project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        target: &SoundShaderUsage,
+        _data: &LintData,
+    ) -> Codes {
+        let prefix = project.map(|project| format!("{}_", project.prefix().to_lowercase()));
+        let mut codes: Codes = Vec::new();
+        for reference in target.references() {
+            if prefix.as_ref().is_some_and(|prefix| {
+                !reference
+                    .shader()
+                    .to_lowercase()
+                    .starts_with(prefix.as_str())
+            }) {
+                continue;
+            }
+            if target.is_declared(reference.shader()) {
+                continue;
+            }
+            codes.push(Arc::new(CodeC20SoundShaderMissing::new(
+                reference,
+                config.severity(),
+            )));
+        }
+        codes
+    }
+}
+
+pub struct CodeC20SoundShaderMissing {
+    reference: SoundShaderReference,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC20SoundShaderMissing {
+    fn ident(&self) -> &'static str {
+        "L-C20"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#sound_shader_missing")
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "`{}` in `{}` is not defined in `CfgSoundShaders`",
+            self.reference.shader(),
+            self.reference.sound_set()
+        )
+    }
+
+    fn label_message(&self) -> String {
+        "sound shader not found".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("no addon of the project defines it".to_string())
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC20SoundShaderMissing {
+    #[must_use]
+    pub fn new(reference: &SoundShaderReference, severity: Severity) -> Self {
+        Self {
+            reference: reference.clone(),
+            severity,
+            diagnostic: None,
+        }
+        .generate()
+    }
+
+    fn generate(mut self) -> Self {
+        self.diagnostic = Some(
+            Diagnostic::from_code(&self).with_label(
+                Label::primary(
+                    self.reference.file().clone(),
+                    self.reference.span().clone(),
+                )
+                .with_message(self.label_message()),
+            ),
+        );
+        self
+    }
+}
@@ -0,0 +1,183 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, model_cfg, Config};
+
+crate::analyze::lint!(LintC21ModelSkeletonMissing);
+
+impl Lint<LintData> for LintC21ModelSkeletonMissing {
+    fn ident(&self) -> &'static str {
+        "model_skeleton_missing"
+    }
+
+    fn sort(&self) -> u32 {
+        210
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on `skeletonName` values in `model.cfg` files that are not defined in `CfgSkeletons`"
+    }
+
+    fn documentation(&self) -> &'static str {
+r#"### Example
+
+**Incorrect**, in `model.cfg`
+```hpp
+class CfgSkeletons {
+    class abe_banana_skeleton {
+        skeletonBones[] = {"peel", ""};
+    };
+};
+class CfgModels {
+    class abe_banana {
+        skeletonName = "abe_bananna_skeleton";
+        sections[] = {"peel"};
+    };
+};
+```
+
+**Correct**, in `model.cfg`
+```hpp
+class CfgSkeletons {
+    class abe_banana_skeleton {
+        skeletonBones[] = {"peel", ""};
+    };
+};
+class CfgModels {
+    class abe_banana {
+        skeletonName = "abe_banana_skeleton";
+        sections[] = {"peel"};
+    };
+};
+```
+
+### Explanation
+
+Every skeleton used by `CfgModels` must be defined in `CfgSkeletons` of the same `model.cfg`, skeletons shared by multiple folders can be added with `#include`. A missing skeleton is reported by binarize without the model it is used by, and the model has no animations in-game.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = Config;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Config,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        if !is_model_cfg(processed) {
+            return vec![];
+        }
+        let skeletons = model_cfg::skeletons(target)
+            .into_iter()
+            .filter_map(|skeleton| skeleton.name().map(|name| name.as_str().to_lowercase()))
+            .collect::<Vec<_>>();
+        let mut codes: Codes = Vec::new();
+        for model in model_cfg::models(target) {
+            let Some(skeleton) = model_cfg::skeleton_name(model) else {
+                continue;
+            };
+            if skeleton.value().is_empty()
+                || skeletons.contains(&skeleton.value().to_lowercase())
+            {
+                continue;
+            }
+            codes.push(Arc::new(CodeC21ModelSkeletonMissing::new(
+                skeleton.span(),
+                skeleton.value().to_string(),
+                processed,
+                config.severity(),
+            )));
+        }
+        codes
+    }
+}
+
+/// Is the processed file a `model.cfg`
+pub(crate) fn is_model_cfg(processed: &Processed) -> bool {
+    processed
+        .sources()
+        .into_iter()
+        .next()
+        .is_some_and(|(root, _)| root.filename().eq_ignore_ascii_case("model.cfg"))
+}
+
+pub struct CodeC21ModelSkeletonMissing {
+    span: Range<usize>,
+    skeleton: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC21ModelSkeletonMissing {
+    fn ident(&self) -> &'static str {
+        "L-C21"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#model_skeleton_missing")
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "skeleton `{}` is not defined in `CfgSkeletons`",
+            self.skeleton
+        )
+    }
+
+    fn label_message(&self) -> String {
+        "skeleton not found".to_string()
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC21ModelSkeletonMissing {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        skeleton: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            skeleton,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -0,0 +1,184 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+};
+
+use crate::{analyze::LintData, model_cfg, Config};
+
+use super::c21_model_skeleton_missing::is_model_cfg;
+
+crate::analyze::lint!(LintC22ModelBoneDuplicate);
+
+impl Lint<LintData> for LintC22ModelBoneDuplicate {
+    fn ident(&self) -> &'static str {
+        "model_bone_duplicate"
+    }
+
+    fn sort(&self) -> u32 {
+        220
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on bones defined more than once in a skeleton of a `model.cfg`"
+    }
+
+    fn documentation(&self) -> &'static str {
+r#"### Example
+
+**Incorrect**, in `model.cfg`
+```hpp
+class CfgSkeletons {
+    class abe_door_skeleton {
+        skeletonBones[] = {
+            "door", "",
+            "handle", "door",
+            "Door", ""
+        };
+    };
+};
+```
+
+**Correct**, in `model.cfg`
+```hpp
+class CfgSkeletons {
+    class abe_door_skeleton {
+        skeletonBones[] = {
+            "door", "",
+            "handle", "door"
+        };
+    };
+};
+```
+
+### Explanation
+
+`skeletonBones[]` is a list of bones, each followed by its parent. A bone can only be defined once, bone names are case-insensitive. Binarize reports a duplicate bone without the skeleton it is in, and the model may be animated incorrectly in-game.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = Config;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Config,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        if !is_model_cfg(processed) {
+            return vec![];
+        }
+        let mut codes: Codes = Vec::new();
+        for skeleton in model_cfg::skeletons(target) {
+            let bones = model_cfg::bones(skeleton);
+            for (index, bone) in bones.iter().enumerate() {
+                if bone.value().is_empty() {
+                    continue;
+                }
+                let Some(first) = bones[..index]
+                    .iter()
+                    .find(|other| other.value().eq_ignore_ascii_case(bone.value()))
+                else {
+                    continue;
+                };
+                codes.push(Arc::new(CodeC22ModelBoneDuplicate::new(
+                    bone.span(),
+                    first.span(),
+                    bone.value().to_string(),
+                    processed,
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+pub struct CodeC22ModelBoneDuplicate {
+    span: Range<usize>,
+    first: Range<usize>,
+    bone: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC22ModelBoneDuplicate {
+    fn ident(&self) -> &'static str {
+        "L-C22"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#model_bone_duplicate")
+    }
+
+    fn message(&self) -> String {
+        format!("bone `{}` is defined more than once", self.bone)
+    }
+
+    fn label_message(&self) -> String {
+        "duplicate bone".to_string()
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC22ModelBoneDuplicate {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        first: Range<usize>,
+        bone: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            first,
+            bone,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        if let Some(diag) = &mut self.diagnostic {
+            if let Some(map) = processed.mapping(self.first.start) {
+                let file = processed.source(map.source()).expect("mapping should have a source");
+                diag.labels.push(
+                    Label::secondary(
+                        file.0.clone(),
+                        map.original_start()..map.original_start() + self.first.len(),
+                    )
+                    .with_message("first defined here"),
+                );
+            }
+        }
+        self
+    }
+}
@@ -0,0 +1,156 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, model_cfg, Config};
+
+use super::c21_model_skeleton_missing::is_model_cfg;
+
+crate::analyze::lint!(LintC23ModelSectionsLowercase);
+
+impl Lint<LintData> for LintC23ModelSectionsLowercase {
+    fn ident(&self) -> &'static str {
+        "model_sections_lowercase"
+    }
+
+    fn sort(&self) -> u32 {
+        230
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on `sections[]` in `model.cfg` files that are not lowercase"
+    }
+
+    fn documentation(&self) -> &'static str {
+r#"### Example
+
+**Incorrect**, in `model.cfg`
+```hpp
+class CfgModels {
+    class abe_banana {
+        sections[] = {"Camo", "peel"};
+    };
+};
+```
+
+**Correct**, in `model.cfg`
+```hpp
+class CfgModels {
+    class abe_banana {
+        sections[] = {"camo", "peel"};
+    };
+};
+```
+
+### Explanation
+
+Selections are lowercase in binarized models, a section that is not lowercase is not found by `setObjectTexture` and `hiddenSelections[]`, and binarize only reports it as a missing section.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = Config;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Config,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        if !is_model_cfg(processed) {
+            return vec![];
+        }
+        model_cfg::models(target)
+            .into_iter()
+            .flat_map(model_cfg::sections)
+            .filter(|section| section.value() != section.value().to_lowercase())
+            .map(|section| {
+                Arc::new(CodeC23ModelSectionsLowercase::new(
+                    section.span(),
+                    section.value().to_string(),
+                    processed,
+                    config.severity(),
+                )) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+pub struct CodeC23ModelSectionsLowercase {
+    span: Range<usize>,
+    section: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC23ModelSectionsLowercase {
+    fn ident(&self) -> &'static str {
+        "L-C23"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#model_sections_lowercase")
+    }
+
+    fn message(&self) -> String {
+        format!("section `{}` is not lowercase", self.section)
+    }
+
+    fn label_message(&self) -> String {
+        "not lowercase".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!("use `{}`", self.section.to_lowercase()))
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC23ModelSectionsLowercase {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        section: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            section,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -0,0 +1,434 @@
+use std::{
+    ops::Range,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, Class, Property, Value};
+
+crate::analyze::lint!(LintC24PropertyValues);
+
+impl Lint<LintData> for LintC24PropertyValues {
+    fn ident(&self) -> &'static str {
+        "property_values"
+    }
+
+    fn sort(&self) -> u32 {
+        240
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports on well known properties of vehicles, weapons, and magazines with values the game does not accept"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"### Configuration
+
+- **properties**: Additional properties to check, for classes directly inside a root class such as `CfgVehicles`. Each property has one of `values`, a list of accepted numbers, `min` and `max`, the accepted range of numbers, or `not_empty`, a string that must not be empty in public classes. A property listed here replaces the built in check for the same property, and an entry that is not valid is reported as an invalid lint config.
+
+```toml
+[lints.config.property_values]
+options.properties = [
+    { class = "CfgVehicles", property = "abe_armorLevel", values = [1, 2, 3] },
+    { class = "CfgWeapons", property = "abe_heat", min = 0, max = 100 },
+    { class = "CfgVehicles", property = "editorSubcategory", not_empty = true },
+]
+```
+
+### Example
+
+**Incorrect**
+```hpp
+class CfgVehicles {
+    class abe_car: Car_F {
+        scope = 3;
+        side = 12;
+        displayName = "";
+    };
+};
+```
+
+**Correct**
+```hpp
+class CfgVehicles {
+    class abe_car: Car_F {
+        scope = 2;
+        side = 1;
+        displayName = "Banana Car";
+    };
+};
+```
+
+### Explanation
+
+The game does not report invalid values for these properties, the class is hidden, shown on the wrong side, or shown without a name instead.
+
+The built in checks are for the classes of `CfgVehicles`, `CfgWeapons`, `CfgMagazines`, and `CfgFactionClasses`:
+
+- `scope`, `scopeCurator`, and `scopeArsenal` must be 0, 1, or 2
+- `side` must be between 0 and 9
+- `displayName`, and `faction` in `CfgVehicles`, must not be empty in public classes, with `scope = 2`
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn check_config(&self, config: &LintConfig) -> Vec<String> {
+        custom(config.option("properties")).1
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+/// The accepted values of a property
+#[derive(Debug, Clone, PartialEq)]
+enum Rule {
+    /// One of the numbers
+    OneOf(Vec<f64>),
+    /// A number between the bounds, inclusive
+    Between(Option<f64>, Option<f64>),
+    /// A string that is not empty, in public classes
+    NotEmpty,
+}
+
+/// A property of the classes directly inside a root class
+struct Schema {
+    root: String,
+    property: String,
+    rule: Rule,
+}
+
+impl Schema {
+    fn new(root: &str, property: &str, rule: Rule) -> Self {
+        Self {
+            root: root.to_lowercase(),
+            property: property.to_lowercase(),
+            rule,
+        }
+    }
+}
+
+/// The properties checked by default
+fn bundled() -> Vec<Schema> {
+    let mut schema = Vec::new();
+    for root in ["CfgVehicles", "CfgWeapons", "CfgMagazines"] {
+        for scope in ["scope", "scopeCurator", "scopeArsenal"] {
+            schema.push(Schema::new(root, scope, Rule::OneOf(vec![0.0, 1.0, 2.0])));
+        }
+        schema.push(Schema::new(root, "displayName", Rule::NotEmpty));
+    }
+    schema.push(Schema::new(
+        "CfgVehicles",
+        "side",
+        Rule::Between(Some(0.0), Some(9.0)),
+    ));
+    schema.push(Schema::new("CfgVehicles", "faction", Rule::NotEmpty));
+    schema.push(Schema::new(
+        "CfgFactionClasses",
+        "side",
+        Rule::Between(Some(0.0), Some(9.0)),
+    ));
+    schema.push(Schema::new(
+        "CfgFactionClasses",
+        "displayName",
+        Rule::NotEmpty,
+    ));
+    schema
+}
+
+type Parsed = Vec<(Option<toml::Value>, Arc<[Schema]>)>;
+
+/// The schema of each `properties` option, parsed the first time it is used
+static PARSED: LazyLock<RwLock<Parsed>> = LazyLock::new(|| RwLock::new(Vec::new()));
+
+/// The properties added in the lint options, followed by the bundled properties
+fn schema(config: &LintConfig) -> Arc<[Schema]> {
+    let properties = config.option("properties");
+    if let Some((_, schema)) = PARSED
+        .read()
+        .expect("parsed lock poisoned")
+        .iter()
+        .find(|(key, _)| key.as_ref() == properties)
+    {
+        return schema.clone();
+    }
+    let schema: Arc<[Schema]> = custom(properties).0.into_iter().chain(bundled()).collect();
+    PARSED
+        .write()
+        .expect("parsed lock poisoned")
+        .push((properties.cloned(), schema.clone()));
+    schema
+}
+
+/// The properties added in the lint options, and the problems with the entries that are invalid
+fn custom(properties: Option<&toml::Value>) -> (Vec<Schema>, Vec<String>) {
+    let Some(properties) = properties else {
+        return (Vec::new(), Vec::new());
+    };
+    let Some(properties) = properties.as_array() else {
+        return (Vec::new(), vec!["option `properties` must be a list".to_string()]);
+    };
+    let mut schema = Vec::new();
+    let mut problems = Vec::new();
+    for (index, property) in properties.iter().enumerate() {
+        match entry(property) {
+            Ok(entry) => schema.push(entry),
+            Err(problem) => problems.push(format!(
+                "option `properties` entry {} {problem}",
+                index + 1
+            )),
+        }
+    }
+    (schema, problems)
+}
+
+fn entry(property: &toml::Value) -> Result<Schema, &'static str> {
+    let number = |value: &toml::Value| {
+        #[allow(clippy::cast_precision_loss)]
+        value
+            .as_float()
+            .or_else(|| value.as_integer().map(|i| i as f64))
+    };
+    let table = property.as_table().ok_or("must be a table")?;
+    let root = table
+        .get("class")
+        .and_then(toml::Value::as_str)
+        .ok_or("must have a `class`")?;
+    let property = table
+        .get("property")
+        .and_then(toml::Value::as_str)
+        .ok_or("must have a `property`")?;
+    let rule = if let Some(values) = table.get("values") {
+        let values = values.as_array().ok_or("`values` must be a list")?;
+        Rule::OneOf(
+            values
+                .iter()
+                .map(|value| number(value).ok_or("`values` must only contain numbers"))
+                .collect::<Result<_, _>>()?,
+        )
+    } else if table.contains_key("min") || table.contains_key("max") {
+        let bound = |key: &str| {
+            table
+                .get(key)
+                .map(|value| number(value).ok_or("`min` and `max` must be numbers"))
+                .transpose()
+        };
+        Rule::Between(bound("min")?, bound("max")?)
+    } else if table.get("not_empty").and_then(toml::Value::as_bool) == Some(true) {
+        Rule::NotEmpty
+    } else {
+        return Err("must have one of `values`, `min` and `max`, or `not_empty = true`");
+    };
+    Ok(Schema::new(root, property, rule))
+}
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = Class;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Class,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        // Only classes directly inside a root class, the path is of the parent
+        let root = data.path.trim_start_matches('/');
+        if root.is_empty() || root.contains('/') {
+            return vec![];
+        }
+        let root = root.to_lowercase();
+        let schema = schema(config);
+        let schema = schema
+            .iter()
+            .filter(|schema| schema.root == root)
+            .collect::<Vec<_>>();
+        if schema.is_empty() {
+            return vec![];
+        }
+        let public = target.properties().iter().any(|property| {
+            matches!(property, Property::Entry { name, value, .. }
+                if name.value.eq_ignore_ascii_case("scope")
+                    && matches!(number(&name.value, value), Some(Ok(scope)) if (scope - 2.0).abs() < f64::EPSILON))
+        });
+        let mut codes: Codes = Vec::new();
+        for property in target.properties() {
+            let Property::Entry { name, value, .. } = property else {
+                continue;
+            };
+            let Some(schema) = schema
+                .iter()
+                .find(|schema| schema.property == name.value.to_lowercase())
+            else {
+                continue;
+            };
+            if let Some((span, message, label)) = check(&name.value, value, &schema.rule, public) {
+                codes.push(Arc::new(CodeC24PropertyValues::new(
+                    span,
+                    message,
+                    label,
+                    processed,
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+type Problem = (Range<usize>, String, String);
+
+fn check(name: &str, value: &Value, rule: &Rule, public: bool) -> Option<Problem> {
+    match rule {
+        Rule::NotEmpty => {
+            let Value::Str(string) = value else {
+                return None;
+            };
+            if !public || !string.value().trim().is_empty() {
+                return None;
+            }
+            Some((
+                value.span(),
+                format!("`{name}` must not be empty in a public class"),
+                "empty".to_string(),
+            ))
+        }
+        Rule::OneOf(values) => {
+            let number = match number(name, value)? {
+                Ok(number) => number,
+                Err(problem) => return Some(problem),
+            };
+            if values.contains(&number) {
+                return None;
+            }
+            Some((
+                value.span(),
+                format!(
+                    "`{name}` must be {}, found {number}",
+                    list(&values.iter().map(ToString::to_string).collect::<Vec<_>>())
+                ),
+                "invalid value".to_string(),
+            ))
+        }
+        Rule::Between(min, max) => {
+            let number = match number(name, value)? {
+                Ok(number) => number,
+                Err(problem) => return Some(problem),
+            };
+            if min.is_none_or(|min| number >= min) && max.is_none_or(|max| number <= max) {
+                return None;
+            }
+            let expected = match (min, max) {
+                (Some(min), Some(max)) => format!("between {min} and {max}"),
+                (Some(min), None) => format!("at least {min}"),
+                (None, Some(max)) => format!("at most {max}"),
+                (None, None) => return None,
+            };
+            Some((
+                value.span(),
+                format!("`{name}` must be {expected}, found {number}"),
+                "out of range".to_string(),
+            ))
+        }
+    }
+}
+
+/// The number of a value, or a problem when it is a string that is not a number
+///
+/// Arrays are reported by `unexpected_array`, and expressions can not be checked
+fn number(name: &str, value: &Value) -> Option<Result<f64, Problem>> {
+    match value {
+        Value::Number(number) => Some(Ok(number.as_f64())),
+        Value::Str(string) => Some(string.value().trim().parse().map_err(|_| {
+            (
+                value.span(),
+                format!("`{name}` must be a number"),
+                "not a number".to_string(),
+            )
+        })),
+        _ => None,
+    }
+}
+
+/// `a`, `a or b`, `a, b, or c`
+fn list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [one] => one.clone(),
+        [first, second] => format!("{first} or {second}"),
+        [rest @ .., last] => format!("{}, or {last}", rest.join(", ")),
+    }
+}
+
+pub struct CodeC24PropertyValues {
+    span: Range<usize>,
+    message: String,
+    label: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC24PropertyValues {
+    fn ident(&self) -> &'static str {
+        "L-C24"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#property_values")
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn label_message(&self) -> String {
+        self.label.clone()
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC24PropertyValues {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        message: String,
+        label: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            message,
+            label,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
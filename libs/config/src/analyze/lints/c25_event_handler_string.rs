@@ -0,0 +1,205 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, Class, Property, Value};
+
+crate::analyze::lint!(LintC25EventHandlerString);
+
+impl Lint<LintData> for LintC25EventHandlerString {
+    fn ident(&self) -> &'static str {
+        "event_handler_string"
+    }
+
+    fn sort(&self) -> u32 {
+        250
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports event handlers in config with large code in their string"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"### Configuration
+
+- **max_length**: The longest code that is not reported, defaults to 60
+
+```toml
+[lints.config.event_handler_string]
+options.max_length = 40
+```
+
+### Example
+
+**Incorrect**
+```hpp
+class Extended_Init_EventHandlers {
+    class abe_car {
+        class abe_main {
+            init = "params ['_vehicle']; if (local _vehicle) then { _vehicle setFuel 0.5; _vehicle lock 2; };";
+        };
+    };
+};
+```
+
+**Correct**
+```hpp
+class Extended_Init_EventHandlers {
+    class abe_car {
+        class abe_main {
+            init = "call abe_main_fnc_initCar";
+        };
+    };
+};
+```
+
+### Explanation
+
+Code in config is compiled from its string when the event handler is run, is not checked by HEMTT, and is hard to read because of its escaped quotes. Event handlers in classes named `EventHandlers`, and in `Extended_*_EventHandlers` classes, should call a function compiled from a file instead.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::help()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+const DEFAULT_MAX_LENGTH: usize = 60;
+
+struct Runner;
+
+impl LintRunner<LintData> for Runner {
+    type Target = Class;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Class,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        let Some(name) = target.name() else {
+            return vec![];
+        };
+        if !data
+            .path
+            .split('/')
+            .chain(std::iter::once(name.value.as_str()))
+            .any(is_event_handlers)
+        {
+            return vec![];
+        }
+        let max_length = config
+            .option("max_length")
+            .and_then(toml::Value::as_integer)
+            .and_then(|max| usize::try_from(max).ok())
+            .unwrap_or(DEFAULT_MAX_LENGTH);
+        let mut codes: Codes = Vec::new();
+        for property in target.properties() {
+            let Property::Entry {
+                name,
+                value: Value::Str(code),
+                ..
+            } = property
+            else {
+                continue;
+            };
+            let length = code.value().trim().chars().count();
+            if length <= max_length {
+                continue;
+            }
+            codes.push(Arc::new(CodeC25EventHandlerString::new(
+                code.span(),
+                name.value.clone(),
+                length,
+                processed,
+                config.severity(),
+            )));
+        }
+        codes
+    }
+}
+
+/// `EventHandlers`, or `Extended_*_EventHandlers` of CBA
+fn is_event_handlers(class: &str) -> bool {
+    let class = class.to_lowercase();
+    class == "eventhandlers"
+        || (class.starts_with("extended_") && class.ends_with("_eventhandlers"))
+}
+
+pub struct CodeC25EventHandlerString {
+    span: Range<usize>,
+    property: String,
+    length: usize,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeC25EventHandlerString {
+    fn ident(&self) -> &'static str {
+        "L-C25"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/config.html#event_handler_string")
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "the event handler `{}` has code in its string",
+            self.property
+        )
+    }
+
+    fn label_message(&self) -> String {
+        format!("{} characters of code", self.length)
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("call a function compiled from a file".to_string())
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeC25EventHandlerString {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        property: String,
+        length: usize,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            property,
+            length,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -5,8 +5,12 @@ use hemtt_workspace::{
     reporting::{Codes, Processed},
 };
 
+mod cfgfunction;
 mod cfgpatch;
 mod chumsky;
+mod custom;
+mod schema;
+pub mod sound_shaders;
 
 pub mod lints {
     automod::dir!(pub "src/analyze/lints");
@@ -18,11 +22,42 @@ pub struct LintData {
 
 lint_manager!(config, vec![]);
 
+pub use cfgfunction::CfgFunction;
 pub use cfgpatch::CfgPatch;
 pub use chumsky::ChumskyCode;
+pub use custom::{custom_check, custom_lints};
+pub use schema::{schema_check, schema_lints};
+
+use sound_shaders::SoundShaderUsage;
 
 use crate::{Array, Class, Config, Expression, Item, Number, Property, Str, Value};
 
+#[must_use]
+/// Check the sound shaders used by an addon against the sound shaders declared by the project
+///
+/// Sound sets can use the shaders of any addon, so they are checked once every config is parsed
+pub fn analyze_sound_shaders(usage: &SoundShaderUsage, project: Option<&ProjectConfig>) -> Codes {
+    let mut manager = LintManager::new(
+        project.map_or_else(Default::default, |project| project.lints().config().clone()),
+    );
+    if let Err(lint_errors) = manager.extend(
+        CONFIG_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    ) {
+        return lint_errors;
+    }
+    manager.run(
+        &LintData {
+            path: String::new(),
+        },
+        project,
+        None,
+        usage,
+    )
+}
+
 /// Trait for rapifying objects
 pub trait Analyze: Sized + 'static {
     fn analyze(
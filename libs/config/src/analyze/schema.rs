@@ -0,0 +1,521 @@
+//! User provided schemas from `.hemtt/schemas`
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Range,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use hemtt_common::config::{LintConfig, ProjectConfig, SchemaSource};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintManager, LintRunner, Lints},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+
+use crate::{analyze::LintData, Class, Ident, Property, Value};
+
+type Compiled = HashMap<SchemaSource, Arc<Box<dyn Lint<LintData>>>>;
+
+/// Schemas are compiled once, the first time they are used
+static COMPILED: LazyLock<RwLock<Compiled>> = LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Compile the schemas of a project
+///
+/// # Errors
+/// Returns a list of codes if a schema is invalid
+///
+/// # Panics
+/// If the lock of the compiled schemas is poisoned
+pub fn schema_lints(project: &ProjectConfig) -> Result<Lints<LintData>, Codes> {
+    let mut lints: Lints<LintData> = Vec::with_capacity(project.schemas().len());
+    let mut errors: Codes = Vec::new();
+    for source in project.schemas() {
+        if let Some(lint) = COMPILED.read().expect("compiled lock poisoned").get(source) {
+            lints.push(lint.clone());
+            continue;
+        }
+        match SchemaLint::compile(source) {
+            Ok(lint) => {
+                let lint: Arc<Box<dyn Lint<LintData>>> = Arc::new(Box::new(lint));
+                COMPILED
+                    .write()
+                    .expect("compiled lock poisoned")
+                    .insert(source.clone(), lint.clone());
+                lints.push(lint);
+            }
+            Err(error) => errors.push(Arc::new(error)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(lints)
+    } else {
+        Err(errors)
+    }
+}
+
+#[must_use]
+/// Check that the schemas are valid, and do not replace a built-in or custom lint
+///
+/// # Panics
+/// If the lock of the compiled schemas is poisoned
+pub fn schema_check(project: &ProjectConfig) -> Codes {
+    let lints = match schema_lints(project) {
+        Ok(lints) => lints,
+        Err(errors) => return errors,
+    };
+    let mut manager: LintManager<LintData> = LintManager::new(HashMap::new());
+    if let Err(errors) = manager.extend(
+        super::CONFIG_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    ) {
+        return errors;
+    }
+    if let Ok(custom) = super::custom_lints(project.lints()) {
+        if let Err(errors) = manager.extend(custom) {
+            return errors;
+        }
+    }
+    manager.extend(lints).err().unwrap_or_default()
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// A schema file
+struct SchemaFile {
+    /// Regex of the class paths the schema applies to
+    class: String,
+    #[serde(default)]
+    severity: Option<Severity>,
+    /// Are properties that are not in the schema allowed
+    #[serde(default = "default_true")]
+    additional_properties: bool,
+    #[serde(default)]
+    properties: BTreeMap<String, PropertyFile>,
+}
+
+const fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PropertyFile {
+    #[serde(default, rename = "type")]
+    kind: Option<Kind>,
+    #[serde(default)]
+    required: bool,
+    #[serde(default)]
+    values: Vec<Accepted>,
+    #[serde(default)]
+    min: Option<f64>,
+    #[serde(default)]
+    max: Option<f64>,
+    #[serde(default)]
+    pattern: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Kind {
+    String,
+    Number,
+    Array,
+}
+
+impl Kind {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Array => "array",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+enum Accepted {
+    Number(f64),
+    String(String),
+}
+
+impl std::fmt::Display for Accepted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{number}"),
+            Self::String(string) => write!(f, "\"{string}\""),
+        }
+    }
+}
+
+struct PropertyRule {
+    /// The name as written in the schema
+    name: String,
+    kind: Option<Kind>,
+    required: bool,
+    values: Vec<Accepted>,
+    min: Option<f64>,
+    max: Option<f64>,
+    pattern: Option<Regex>,
+}
+
+struct Rule {
+    class: Regex,
+    additional_properties: bool,
+    properties: Vec<PropertyRule>,
+}
+
+impl Rule {
+    fn property(&self, name: &str) -> Option<&PropertyRule> {
+        self.properties
+            .iter()
+            .find(|rule| rule.name.eq_ignore_ascii_case(name))
+    }
+}
+
+struct SchemaLint {
+    ident: &'static str,
+    severity: Severity,
+    rule: Arc<Rule>,
+}
+
+impl SchemaLint {
+    fn compile(source: &SchemaSource) -> Result<Self, CodeSchemaInvalid> {
+        let invalid = |reason: String| CodeSchemaInvalid {
+            message: format!("Schema `{}` is invalid", source.name()),
+            path: source.path().to_string(),
+            reason,
+        };
+        let file: SchemaFile = if source.is_json() {
+            serde_json::from_str(source.source()).map_err(|e| invalid(e.to_string()))?
+        } else {
+            toml::from_str(source.source()).map_err(|e| invalid(e.to_string()))?
+        };
+        let regex = |field: &str, pattern: &str| {
+            RegexBuilder::new(pattern)
+                .case_insensitive(true)
+                .build()
+                .map_err(|e| invalid(format!("invalid `{field}` pattern: {e}")))
+        };
+        let mut properties = Vec::with_capacity(file.properties.len());
+        for (name, property) in file.properties {
+            let array = name.ends_with("[]");
+            let name = name.trim_end_matches("[]").to_string();
+            let kind = match (property.kind, array) {
+                (Some(kind), true) if kind != Kind::Array => {
+                    return Err(invalid(format!(
+                        "`{name}[]` is an array, but has the type `{}`",
+                        kind.name()
+                    )));
+                }
+                (None, true) => Some(Kind::Array),
+                (kind, _) => kind,
+            };
+            properties.push(PropertyRule {
+                pattern: property
+                    .pattern
+                    .as_deref()
+                    .map(|pattern| regex(&format!("properties.{name}.pattern"), pattern))
+                    .transpose()?,
+                name,
+                kind,
+                required: property.required,
+                values: property.values,
+                min: property.min,
+                max: property.max,
+            });
+        }
+        Ok(Self {
+            // Lints are identified by a static str, each schema is only compiled once
+            ident: Box::leak(source.name().to_string().into_boxed_str()),
+            severity: file.severity.unwrap_or(Severity::Warning),
+            rule: Arc::new(Rule {
+                class: regex("class", &file.class)?,
+                additional_properties: file.additional_properties,
+                properties,
+            }),
+        })
+    }
+}
+
+impl Lint<LintData> for SchemaLint {
+    fn ident(&self) -> &'static str {
+        self.ident
+    }
+
+    fn sort(&self) -> u32 {
+        u32::MAX
+    }
+
+    fn description(&self) -> &'static str {
+        "User provided schema from `.hemtt/schemas`"
+    }
+
+    fn documentation(&self) -> &'static str {
+        ""
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::new(self.severity, HashMap::new())
+    }
+
+    fn minimum_severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner {
+            ident: self.ident,
+            rule: self.rule.clone(),
+        })]
+    }
+}
+
+struct Runner {
+    ident: &'static str,
+    rule: Arc<Rule>,
+}
+
+impl LintRunner<LintData> for Runner {
+    type Target = Class;
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Class,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return vec![];
+        };
+        let Class::Local {
+            name, properties, ..
+        } = target
+        else {
+            return vec![];
+        };
+        let path = format!("{}/{}", data.path, name.value);
+        let path = path.trim_start_matches('/');
+        if !self.rule.class.is_match(path) {
+            return vec![];
+        }
+        let code = |span: Range<usize>, message: String, label: String| {
+            Arc::new(CodeSchema::new(
+                self.ident,
+                span,
+                message,
+                label,
+                processed,
+                config.severity(),
+            )) as Arc<dyn Code>
+        };
+        let mut codes: Codes = Vec::new();
+        let mut found: Vec<&Ident> = Vec::new();
+        for property in properties {
+            let Property::Entry { name, value, .. } = property else {
+                continue;
+            };
+            found.push(name);
+            let Some(rule) = self.rule.property(&name.value) else {
+                if !self.rule.additional_properties {
+                    codes.push(code(
+                        name.span.clone(),
+                        format!("`{}` is not in the schema `{}`", name.value, self.ident),
+                        "unknown property".to_string(),
+                    ));
+                }
+                continue;
+            };
+            if let Some((span, message, label)) = check(&name.value, value, rule) {
+                codes.push(code(span, message, label));
+            }
+        }
+        let missing = self
+            .rule
+            .properties
+            .iter()
+            .filter(|rule| {
+                rule.required
+                    && !found
+                        .iter()
+                        .any(|name| name.value.eq_ignore_ascii_case(&rule.name))
+            })
+            .map(|rule| format!("`{}`", rule.name))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            codes.push(code(
+                name.span.clone(),
+                format!("`{path}` is missing required properties"),
+                format!("missing {}", missing.join(", ")),
+            ));
+        }
+        codes
+    }
+}
+
+type Problem = (Range<usize>, String, String);
+
+fn check(name: &str, value: &Value, rule: &PropertyRule) -> Option<Problem> {
+    let kind = match value {
+        Value::Str(_) => Kind::String,
+        Value::Number(_) => Kind::Number,
+        Value::Array(_) | Value::UnexpectedArray(_) => Kind::Array,
+        // Expressions and invalid values are reported by other lints
+        _ => return None,
+    };
+    if let Some(expected) = rule.kind {
+        if expected != kind {
+            return Some((
+                value.span(),
+                format!("`{name}` must be a {}", expected.name()),
+                format!("expected a {}", expected.name()),
+            ));
+        }
+    }
+    let accepted = match value {
+        Value::Str(string) => Accepted::String(string.value().to_string()),
+        Value::Number(number) => Accepted::Number(number.as_f64()),
+        _ => return None,
+    };
+    if !rule.values.is_empty()
+        && !rule.values.iter().any(|v| match (v, &accepted) {
+            (Accepted::String(a), Accepted::String(b)) => a.eq_ignore_ascii_case(b),
+            (a, b) => a == b,
+        })
+    {
+        return Some((
+            value.span(),
+            format!(
+                "`{name}` must be one of {}",
+                rule.values
+                    .iter()
+                    .map(|v| format!("`{v}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            format!("found `{accepted}`"),
+        ));
+    }
+    match accepted {
+        Accepted::Number(number) => {
+            let expected = match (rule.min, rule.max) {
+                (Some(min), _) if number < min => rule.max.map_or_else(
+                    || format!("at least {min}"),
+                    |max| format!("between {min} and {max}"),
+                ),
+                (_, Some(max)) if number > max => rule.min.map_or_else(
+                    || format!("at most {max}"),
+                    |min| format!("between {min} and {max}"),
+                ),
+                _ => return None,
+            };
+            Some((
+                value.span(),
+                format!("`{name}` must be {expected}, found {number}"),
+                "out of range".to_string(),
+            ))
+        }
+        Accepted::String(string) => {
+            let pattern = rule.pattern.as_ref()?;
+            if pattern.is_match(&string) {
+                return None;
+            }
+            Some((
+                value.span(),
+                format!("`{name}` does not match `{pattern}`"),
+                "does not match".to_string(),
+            ))
+        }
+    }
+}
+
+pub struct CodeSchema {
+    ident: &'static str,
+    span: Range<usize>,
+    message: String,
+    label: String,
+    diagnostic: Option<Diagnostic>,
+    severity: Severity,
+}
+
+impl Code for CodeSchema {
+    fn ident(&self) -> &'static str {
+        self.ident
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn label_message(&self) -> String {
+        self.label.clone()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(format!("reported by the schema `{}`", self.ident))
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeSchema {
+    #[must_use]
+    pub fn new(
+        ident: &'static str,
+        span: Range<usize>,
+        message: String,
+        label: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            ident,
+            span,
+            message,
+            label,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
+
+pub struct CodeSchemaInvalid {
+    message: String,
+    path: String,
+    reason: String,
+}
+
+impl Code for CodeSchemaInvalid {
+    fn ident(&self) -> &'static str {
+        "ISC"
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(format!("{}: {}", self.path, self.reason.trim()))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        Some(Diagnostic::from_code(self))
+    }
+}
@@ -0,0 +1,74 @@
+//! Sound shaders declared and used across a project, checked by `sound_shader_missing`
+
+use std::{collections::HashSet, sync::Arc};
+
+use hemtt_workspace::{addons::SoundShaderReference, reporting::Processed};
+
+use crate::Config;
+
+#[derive(Debug, Default)]
+/// The sound shaders declared by the project, and the sound shaders used by an addon
+pub struct SoundShaderUsage {
+    declared: Arc<HashSet<String>>,
+    references: Vec<SoundShaderReference>,
+}
+
+impl SoundShaderUsage {
+    #[must_use]
+    /// Create the usage of an addon, the declared shaders are shared by every addon of the project
+    ///
+    /// The declared shaders must be lowercase
+    pub const fn new(
+        declared: Arc<HashSet<String>>,
+        references: Vec<SoundShaderReference>,
+    ) -> Self {
+        Self {
+            declared,
+            references,
+        }
+    }
+
+    #[must_use]
+    /// Is the shader declared by any addon of the project
+    pub fn is_declared(&self, shader: &str) -> bool {
+        self.declared.contains(&shader.to_lowercase())
+    }
+
+    #[must_use]
+    /// The sound shaders used by the addon
+    pub fn references(&self) -> &[SoundShaderReference] {
+        &self.references
+    }
+}
+
+#[must_use]
+/// The sound shaders used in `CfgSoundSets`, located in their original files
+///
+/// Shaders where `sound_shader_missing` is suppressed with `#pragma hemtt suppress` are skipped
+pub fn sound_shader_references(
+    config: &Config,
+    processed: &Processed,
+) -> Vec<SoundShaderReference> {
+    config
+        .get_sound_shader_references()
+        .into_iter()
+        .filter_map(|(shader, sound_set)| {
+            let map_start = processed.mapping(shader.span().start)?;
+            let map_end = processed.mapping(shader.span().end)?;
+            let file = map_start.original().path();
+            if processed.is_lint_suppressed(
+                "sound_shader_missing",
+                file,
+                map_start.original().start().line(),
+            ) {
+                return None;
+            }
+            Some(SoundShaderReference::new(
+                shader.value().to_string(),
+                sound_set.as_str().to_string(),
+                file.to_owned(),
+                map_start.original_start()..map_end.original_start(),
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,79 @@
+//! Format config files
+//!
+//! Formatting works on the source before it is preprocessed, so comments,
+//! macros, and preprocessor directives are kept as they are written.
+
+mod parse;
+mod write;
+
+/// Maximum width of a line before an array is wrapped
+pub const MAX_WIDTH: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A part of the file that can not be formatted
+pub struct Error {
+    line: usize,
+    column: usize,
+    message: &'static str,
+}
+
+impl Error {
+    fn new(source: &str, offset: usize, message: &'static str) -> Self {
+        let before = &source[..offset];
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1,
+            message,
+        }
+    }
+
+    #[must_use]
+    /// The 1-based line of the error
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    #[must_use]
+    /// The 1-based column of the error
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    #[must_use]
+    /// Why the source could not be formatted
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Format a config file
+///
+/// - Classes and properties are indented by 4 spaces per class
+/// - Runs of blank lines are reduced to one, and removed at the start and end of a class
+/// - Arrays are written on one line if they fit in [`MAX_WIDTH`], otherwise each element is written on its own line
+/// - Comments, macros, and preprocessor directives are kept
+///
+/// # Errors
+/// [`Error`] if the source uses syntax that can not be formatted, such as a
+/// preprocessor directive inside the definition of a class
+pub fn format(source: &str) -> Result<String, Error> {
+    let crlf = source.contains("\r\n");
+    let source = source.replace("\r\n", "\n");
+    let items = parse::Parser::new(&source)
+        .file()
+        .map_err(|(offset, message)| Error::new(&source, offset, message))?;
+    let formatted = write::Writer::default().file(&items);
+    Ok(if crlf {
+        formatted.replace('\n', "\r\n")
+    } else {
+        formatted
+    })
+}
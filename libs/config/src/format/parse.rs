@@ -0,0 +1,358 @@
+//! A lossless parser for unprocessed configs
+
+/// The byte offset and reason of a parse failure
+pub type Error = (usize, &'static str);
+
+#[derive(Debug)]
+pub enum Item {
+    /// One or more blank lines
+    Blank,
+    /// A comment, `trailing` if it is on the same line as the previous item
+    Comment { text: String, trailing: bool },
+    /// A preprocessor directive, including any continued lines
+    Directive(String),
+    /// A class, `body` is `None` for an external class
+    Class {
+        name: String,
+        parent: Option<String>,
+        body: Option<Vec<Self>>,
+    },
+    Property {
+        name: String,
+        array: bool,
+        operator: &'static str,
+        value: Value,
+    },
+    /// Anything else, such as a macro, and whether it ended with a `;`
+    Statement { text: String, semicolon: bool },
+}
+
+#[derive(Debug)]
+pub enum Value {
+    Raw(String),
+    Array(Vec<Element>),
+}
+
+#[derive(Debug)]
+pub enum Element {
+    Raw(String),
+    Array(Vec<Self>),
+    Comment { text: String, trailing: bool },
+    Directive(String),
+}
+
+pub struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub const fn new(source: &'a str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    pub fn file(&mut self) -> Result<Vec<Item>, Error> {
+        let items = self.items(false)?;
+        if self.pos < self.source.len() {
+            return Err((self.pos, "unexpected `}`"));
+        }
+        Ok(items)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn starts_with(&self, pattern: &str) -> bool {
+        self.source[self.pos..].starts_with(pattern)
+    }
+
+    fn at_comment(&self) -> bool {
+        self.starts_with("//") || self.starts_with("/*")
+    }
+
+    /// Skip whitespace, returning the number of newlines skipped
+    fn whitespace(&mut self) -> usize {
+        let mut newlines = 0;
+        while let Some(c) = self.peek() {
+            if c == '\n' {
+                newlines += 1;
+            } else if !c.is_whitespace() {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+        newlines
+    }
+
+    /// Skip whitespace that is not allowed to contain comments or directives
+    fn inline_whitespace(&mut self) -> Result<(), Error> {
+        self.whitespace();
+        if self.at_comment() {
+            return Err((self.pos, "comments are not supported here"));
+        }
+        if self.peek() == Some('#') {
+            return Err((self.pos, "preprocessor directives are not supported here"));
+        }
+        Ok(())
+    }
+
+    fn comment(&mut self) -> Result<String, Error> {
+        let start = self.pos;
+        if self.starts_with("//") {
+            let end = self.source[start..]
+                .find('\n')
+                .map_or(self.source.len(), |i| start + i);
+            self.pos = end;
+        } else {
+            let Some(end) = self.source[start + 2..].find("*/") else {
+                return Err((start, "unclosed comment"));
+            };
+            self.pos = start + 2 + end + 2;
+        }
+        Ok(self.source[start..self.pos].trim_end().to_string())
+    }
+
+    fn directive(&mut self) -> String {
+        let start = self.pos;
+        loop {
+            let end = self.source[self.pos..]
+                .find('\n')
+                .map_or(self.source.len(), |i| self.pos + i);
+            self.pos = end;
+            if !self.source[start..end].trim_end().ends_with('\\') || end == self.source.len() {
+                break;
+            }
+            self.pos += 1;
+        }
+        self.source[start..self.pos]
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Skip a string starting at the current position
+    fn string(&mut self) -> Result<(), Error> {
+        let start = self.pos;
+        let quote = self.peek().expect("string starts with a quote");
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                Some(c) if c == quote => {
+                    self.pos += 1;
+                    // quotes are escaped by doubling them
+                    if self.peek() != Some(quote) {
+                        return Ok(());
+                    }
+                    self.pos += 1;
+                }
+                Some('\n') | None => return Err((start, "unclosed string")),
+                Some(c) => self.pos += c.len_utf8(),
+            }
+        }
+    }
+
+    /// Read until one of `stops` outside of strings and parentheses
+    fn until(&mut self, stops: &[char]) -> Result<String, Error> {
+        let start = self.pos;
+        let mut depth = 0usize;
+        while let Some(c) = self.peek() {
+            match c {
+                '"' | '\'' => {
+                    self.string()?;
+                    continue;
+                }
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                '/' if depth == 0 && self.at_comment() => break,
+                _ if depth == 0 && stops.contains(&c) => break,
+                _ => {}
+            }
+            self.pos += c.len_utf8();
+        }
+        if depth != 0 {
+            return Err((start, "unclosed parenthesis"));
+        }
+        Ok(self.source[start..self.pos].trim().to_string())
+    }
+
+    /// Read a name, which can be a macro with arguments
+    fn name(&mut self) -> Result<String, Error> {
+        let start = self.pos;
+        let name = self.until(&[
+            ' ', '\t', '\r', '\n', ':', ';', '{', '}', '=', '[', ']', '+', ',', '"', '\'',
+        ])?;
+        if name.is_empty() {
+            return Err((start, "expected a name"));
+        }
+        Ok(name)
+    }
+
+    fn items(&mut self, nested: bool) -> Result<Vec<Item>, Error> {
+        let mut items = Vec::new();
+        loop {
+            let newlines = self.whitespace();
+            match self.peek() {
+                None if nested => return Err((self.pos, "unclosed class")),
+                None | Some('}') => return Ok(items),
+                _ => {}
+            }
+            if newlines >= 2 && !items.is_empty() {
+                items.push(Item::Blank);
+            }
+            if self.at_comment() {
+                let trailing = newlines == 0 && (nested || !items.is_empty());
+                items.push(Item::Comment {
+                    text: self.comment()?,
+                    trailing,
+                });
+            } else if self.peek() == Some('#') {
+                items.push(Item::Directive(self.directive()));
+            } else if self.peek() == Some(';') {
+                // stray semicolons are removed
+                self.pos += 1;
+            } else {
+                items.push(self.statement()?);
+            }
+        }
+    }
+
+    fn statement(&mut self) -> Result<Item, Error> {
+        let start = self.pos;
+        let name = self.name()?;
+        if name == "class" {
+            return self.class();
+        }
+        let array = {
+            self.inline_whitespace()?;
+            if self.peek() == Some('[') {
+                self.pos += 1;
+                self.inline_whitespace()?;
+                if self.peek() != Some(']') {
+                    return Err((self.pos, "expected `]`"));
+                }
+                self.pos += 1;
+                true
+            } else {
+                false
+            }
+        };
+        self.inline_whitespace()?;
+        let operator = if self.starts_with("+=") {
+            self.pos += 2;
+            "+="
+        } else if self.peek() == Some('=') {
+            self.pos += 1;
+            "="
+        } else if !array {
+            // a macro on its own, such as `ADDON_LIST;`
+            self.pos = start;
+            let text = self.until(&[';', '{', '}', '\n'])?;
+            let semicolon = self.peek() == Some(';');
+            if semicolon {
+                self.pos += 1;
+            } else if self.peek() == Some('{') {
+                return Err((self.pos, "expected `=`"));
+            }
+            return Ok(Item::Statement { text, semicolon });
+        } else {
+            return Err((self.pos, "expected `=`"));
+        };
+        self.inline_whitespace()?;
+        let value = if array && self.peek() == Some('{') {
+            self.pos += 1;
+            Value::Array(self.elements()?)
+        } else {
+            let value = self.until(&[';', '}', '\n'])?;
+            if value.is_empty() {
+                return Err((self.pos, "expected a value"));
+            }
+            Value::Raw(value)
+        };
+        self.semicolon();
+        Ok(Item::Property {
+            name,
+            array,
+            operator,
+            value,
+        })
+    }
+
+    /// Consume a `;` on the same line, if there is one
+    fn semicolon(&mut self) {
+        let before = self.pos;
+        while matches!(self.peek(), Some(' ' | '\t')) {
+            self.pos += 1;
+        }
+        if self.peek() == Some(';') {
+            self.pos += 1;
+        } else {
+            self.pos = before;
+        }
+    }
+
+    fn class(&mut self) -> Result<Item, Error> {
+        self.inline_whitespace()?;
+        let name = self.name()?;
+        self.inline_whitespace()?;
+        let parent = if self.peek() == Some(':') {
+            self.pos += 1;
+            self.inline_whitespace()?;
+            let parent = self.name()?;
+            self.inline_whitespace()?;
+            Some(parent)
+        } else {
+            None
+        };
+        let body = match self.peek() {
+            Some('{') => {
+                self.pos += 1;
+                let body = self.items(true)?;
+                // `items` only returns inside a class at a `}`
+                self.pos += 1;
+                Some(body)
+            }
+            // a class with a parent needs a body, add an empty one
+            Some(';') if parent.is_some() => Some(Vec::new()),
+            Some(';') => None,
+            _ => return Err((self.pos, "expected `{` or `;`")),
+        };
+        self.semicolon();
+        Ok(Item::Class { name, parent, body })
+    }
+
+    fn elements(&mut self) -> Result<Vec<Element>, Error> {
+        let mut elements = Vec::new();
+        loop {
+            let newlines = self.whitespace();
+            match self.peek() {
+                None => return Err((self.pos, "unclosed array")),
+                Some('}') => {
+                    self.pos += 1;
+                    return Ok(elements);
+                }
+                Some(',') => self.pos += 1,
+                Some('{') => {
+                    self.pos += 1;
+                    elements.push(Element::Array(self.elements()?));
+                }
+                Some('#') => elements.push(Element::Directive(self.directive())),
+                _ if self.at_comment() => {
+                    let trailing = newlines == 0 && !elements.is_empty();
+                    elements.push(Element::Comment {
+                        text: self.comment()?,
+                        trailing,
+                    });
+                }
+                _ => {
+                    let element = self.until(&[',', '{', '}', ';'])?;
+                    if element.is_empty() {
+                        return Err((self.pos, "expected `}`"));
+                    }
+                    elements.push(Element::Raw(element));
+                }
+            }
+        }
+    }
+}
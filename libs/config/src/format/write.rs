@@ -0,0 +1,153 @@
+use super::{
+    parse::{Element, Item, Value},
+    MAX_WIDTH,
+};
+
+const INDENT: &str = "    ";
+
+#[derive(Default)]
+pub struct Writer {
+    lines: Vec<String>,
+}
+
+impl Writer {
+    pub fn file(mut self, items: &[Item]) -> String {
+        self.items(items, 0);
+        while self.lines.last().is_some_and(String::is_empty) {
+            self.lines.pop();
+        }
+        if self.lines.is_empty() {
+            return String::new();
+        }
+        let mut out = self.lines.join("\n");
+        out.push('\n');
+        out
+    }
+
+    fn line(&mut self, depth: usize, text: &str) {
+        self.lines.push(format!("{}{text}", INDENT.repeat(depth)));
+    }
+
+    /// Write text that may span multiple lines, only the first line is indented
+    fn verbatim(&mut self, depth: usize, text: &str) {
+        let mut lines = text.lines();
+        if let Some(first) = lines.next() {
+            self.line(depth, first);
+        }
+        self.lines.extend(lines.map(str::to_string));
+    }
+
+    /// Append a comment to the previous line
+    fn trailing(&mut self, depth: usize, text: &str) {
+        if text.contains('\n') {
+            self.verbatim(depth, text);
+            return;
+        }
+        match self.lines.last_mut() {
+            Some(last) if !last.is_empty() => {
+                last.push(' ');
+                last.push_str(text);
+            }
+            _ => self.line(depth, text),
+        }
+    }
+
+    fn items(&mut self, items: &[Item], depth: usize) {
+        for item in items {
+            match item {
+                Item::Blank => {
+                    if self.lines.last().is_some_and(|l| !l.is_empty()) {
+                        self.lines.push(String::new());
+                    }
+                }
+                Item::Comment { text, trailing } => {
+                    if *trailing {
+                        self.trailing(depth, text);
+                    } else {
+                        self.verbatim(depth, text);
+                    }
+                }
+                Item::Directive(text) => self.verbatim(depth, text),
+                Item::Class { name, parent, body } => {
+                    let head = parent.as_ref().map_or_else(
+                        || format!("class {name}"),
+                        |parent| format!("class {name}: {parent}"),
+                    );
+                    match body {
+                        None => self.line(depth, &format!("{head};")),
+                        Some(body) if body.is_empty() => self.line(depth, &format!("{head} {{}};")),
+                        Some(body) => {
+                            self.line(depth, &format!("{head} {{"));
+                            self.items(body, depth + 1);
+                            while self.lines.last().is_some_and(String::is_empty) {
+                                self.lines.pop();
+                            }
+                            self.line(depth, "};");
+                        }
+                    }
+                }
+                Item::Property {
+                    name,
+                    array,
+                    operator,
+                    value,
+                } => {
+                    let brackets = if *array { "[]" } else { "" };
+                    let head = format!("{name}{brackets} {operator} ");
+                    match value {
+                        Value::Raw(value) => self.line(depth, &format!("{head}{value};")),
+                        Value::Array(elements) => self.array(depth, &head, elements, ";"),
+                    }
+                }
+                Item::Statement { text, semicolon } => {
+                    let semicolon = if *semicolon { ";" } else { "" };
+                    self.verbatim(depth, &format!("{text}{semicolon}"));
+                }
+            }
+        }
+    }
+
+    /// Write an array, on one line if it fits, otherwise with one element per line
+    fn array(&mut self, depth: usize, head: &str, elements: &[Element], end: &str) {
+        if let Some(inline) = inline(elements) {
+            let line = format!("{head}{inline}{end}");
+            if INDENT.len() * depth + line.len() <= MAX_WIDTH {
+                self.line(depth, &line);
+                return;
+            }
+        }
+        self.line(depth, &format!("{head}{{"));
+        let last = elements
+            .iter()
+            .rposition(|e| matches!(e, Element::Raw(_) | Element::Array(_)));
+        for (i, element) in elements.iter().enumerate() {
+            let comma = if Some(i) == last { "" } else { "," };
+            match element {
+                Element::Raw(text) => self.verbatim(depth + 1, &format!("{text}{comma}")),
+                Element::Array(elements) => self.array(depth + 1, "", elements, comma),
+                Element::Comment { text, trailing } => {
+                    if *trailing {
+                        self.trailing(depth + 1, text);
+                    } else {
+                        self.verbatim(depth + 1, text);
+                    }
+                }
+                Element::Directive(text) => self.verbatim(depth + 1, text),
+            }
+        }
+        self.line(depth, &format!("}}{end}"));
+    }
+}
+
+/// An array on a single line, if it has no comments or directives
+fn inline(elements: &[Element]) -> Option<String> {
+    let mut parts = Vec::with_capacity(elements.len());
+    for element in elements {
+        match element {
+            Element::Raw(text) if !text.contains('\n') => parts.push(text.clone()),
+            Element::Array(elements) => parts.push(inline(elements)?),
+            _ => return None,
+        }
+    }
+    Some(format!("{{{}}}", parts.join(", ")))
+}
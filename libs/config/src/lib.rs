@@ -7,9 +7,13 @@
 use std::sync::Arc;
 
 pub mod analyze;
+pub mod format;
 mod model;
+pub mod model_cfg;
 pub mod parse;
 pub mod rapify;
+pub mod rvmat;
+pub mod sqm;
 pub use model::*;
 
 use analyze::{Analyze, CfgPatch, ChumskyCode, LintData};
@@ -51,6 +55,10 @@ pub fn parse(
                     .map(|l| (**l).clone())
                     .collect::<Vec<_>>(),
             )?;
+            if let Some(project) = project {
+                manager.extend(analyze::custom_lints(project.lints())?)?;
+                manager.extend(analyze::schema_lints(project)?)?;
+            }
             Ok(ConfigReport {
                 codes: config.analyze(
                     &LintData {
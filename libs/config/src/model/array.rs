@@ -10,6 +10,20 @@ pub struct Array {
     pub(crate) span: Range<usize>,
 }
 
+impl Array {
+    #[must_use]
+    /// Get the items
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    #[must_use]
+    /// Is the array expanding its parent's array, with `+=`
+    pub const fn expand(&self) -> bool {
+        self.expand
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 /// An array value
 pub enum Item {
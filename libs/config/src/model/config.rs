@@ -1,6 +1,11 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
 use hemtt_common::version::Version;
 
-use crate::{analyze::CfgPatch, Class, Number, Property, Value};
+use crate::{
+    analyze::{CfgFunction, CfgPatch},
+    Class, Ident, Item, Number, Property, Str, Value,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 /// A config file
@@ -32,17 +37,35 @@ impl Config {
                         }) = patch
                         {
                             let mut required_version = Version::new(0, 0, 0, None);
+                            let mut required_addons = Vec::new();
                             for property in properties {
                                 if let Property::Entry { name, value, .. } = property {
-                                    if name.as_str().to_lowercase() == "requiredversion" {
-                                        if let Value::Number(Number::Float32 { value, .. }) = value
-                                        {
+                                    match (name.as_str().to_lowercase().as_str(), value) {
+                                        (
+                                            "requiredversion",
+                                            Value::Number(Number::Float32 { value, .. }),
+                                        ) => {
                                             required_version = Version::from(*value);
                                         }
+                                        ("requiredaddons", Value::Array(array)) => {
+                                            required_addons.extend(array.items.iter().filter_map(
+                                                |item| match item {
+                                                    Item::Str(value) => {
+                                                        Some(value.value().to_string())
+                                                    }
+                                                    _ => None,
+                                                },
+                                            ));
+                                        }
+                                        _ => {}
                                     }
                                 }
                             }
-                            patches.push(CfgPatch::new(name.clone(), required_version));
+                            patches.push(CfgPatch::new(
+                                name.clone(),
+                                required_version,
+                                required_addons,
+                            ));
                         }
                     }
                 }
@@ -50,4 +73,245 @@ impl Config {
         }
         patches
     }
+
+    #[must_use]
+    /// Get the functions declared in `CfgFunctions`
+    pub fn get_functions(&self) -> Vec<CfgFunction> {
+        let mut functions = Vec::new();
+        for cfgfunctions in local_classes(&self.0)
+            .filter(|(name, _)| name.as_str().eq_ignore_ascii_case("cfgfunctions"))
+        {
+            for (tag, tag_properties) in local_classes(cfgfunctions.1) {
+                let tag = string_entry(tag_properties, "tag").unwrap_or_else(|| tag.as_str());
+                for (_, category_properties) in local_classes(tag_properties) {
+                    let folder = string_entry(category_properties, "file");
+                    for (name, properties) in local_classes(category_properties) {
+                        let file = string_entry(properties, "file")
+                            .map(ToString::to_string)
+                            .or_else(|| {
+                                folder.map(|folder| format!("{folder}\\fn_{}.sqf", name.as_str()))
+                            });
+                        functions.push(CfgFunction::new(
+                            format!("{tag}_fnc_{}", name.as_str()),
+                            name.clone(),
+                            file,
+                        ));
+                    }
+                }
+            }
+        }
+        functions
+    }
+
+    #[must_use]
+    /// Get the function names used in strings, such as in event handlers
+    ///
+    /// Names are lowercase, and only words containing `_fnc_` are included
+    pub fn get_references(&self) -> Vec<String> {
+        fn item_references(item: &Item, references: &mut Vec<String>) {
+            match item {
+                Item::Str(s) => words(s.value(), references),
+                Item::Array(items) => items.iter().for_each(|i| item_references(i, references)),
+                Item::Number(_) | Item::Invalid(_) => {}
+            }
+        }
+        fn property_references(properties: &[Property], references: &mut Vec<String>) {
+            for property in properties {
+                match property {
+                    Property::Entry { value, .. } => match value {
+                        Value::Str(s) => words(s.value(), references),
+                        Value::Array(a) | Value::UnexpectedArray(a) => {
+                            a.items.iter().for_each(|i| item_references(i, references));
+                        }
+                        Value::Number(_) | Value::Expression(_) | Value::Invalid(_) => {}
+                    },
+                    Property::Class(
+                        Class::Local { properties: p, .. } | Class::Root { properties: p },
+                    ) => {
+                        property_references(p, references);
+                    }
+                    Property::Class(Class::External { .. })
+                    | Property::Delete(_)
+                    | Property::MissingSemicolon(_, _) => {}
+                }
+            }
+        }
+        let mut references = Vec::new();
+        property_references(&self.0, &mut references);
+        references.sort();
+        references.dedup();
+        references
+    }
+
+    #[must_use]
+    /// Get the classes declared in `CfgSoundShaders`, including external classes
+    pub fn get_sound_shaders(&self) -> Vec<String> {
+        local_classes(&self.0)
+            .filter(|(name, _)| name.as_str().eq_ignore_ascii_case("cfgsoundshaders"))
+            .flat_map(|(_, properties)| properties)
+            .filter_map(|property| match property {
+                Property::Class(class) => class.name().map(|name| name.as_str().to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[must_use]
+    /// Get the sound shaders used in `soundShaders[]` of `CfgSoundSets`, with the sound set using them
+    pub fn get_sound_shader_references(&self) -> Vec<(Str, Ident)> {
+        let mut references = Vec::new();
+        for (sound_set, properties) in local_classes(&self.0)
+            .filter(|(name, _)| name.as_str().eq_ignore_ascii_case("cfgsoundsets"))
+            .flat_map(|(_, properties)| local_classes(properties))
+        {
+            for property in properties {
+                let Property::Entry {
+                    name,
+                    value: Value::Array(used),
+                    ..
+                } = property
+                else {
+                    continue;
+                };
+                if !name.as_str().eq_ignore_ascii_case("soundShaders") {
+                    continue;
+                }
+                for item in &used.items {
+                    if let Item::Str(shader) = item {
+                        references.push((shader.clone(), sound_set.clone()));
+                    }
+                }
+            }
+        }
+        references
+    }
+}
+
+/// The local classes in a list of properties
+fn local_classes(properties: &[Property]) -> impl Iterator<Item = (&Ident, &[Property])> {
+    properties.iter().filter_map(|property| match property {
+        Property::Class(Class::Local {
+            name, properties, ..
+        }) => Some((name, properties.as_slice())),
+        _ => None,
+    })
+}
+
+/// The value of a string entry in a list of properties
+fn string_entry<'a>(properties: &'a [Property], key: &str) -> Option<&'a str> {
+    properties.iter().find_map(|property| match property {
+        Property::Entry {
+            name,
+            value: Value::Str(s),
+            ..
+        } if name.as_str().eq_ignore_ascii_case(key) => Some(s.value()),
+        _ => None,
+    })
+}
+
+/// Add the words of a string that look like function names
+fn words(value: &str, references: &mut Vec<String>) {
+    references.extend(
+        value
+            .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .filter(|word| word.to_ascii_lowercase().contains("_fnc_"))
+            .map(str::to_ascii_lowercase),
+    );
+}
+
+impl Display for Config {
+    /// Writes the config as source, with nested classes indented
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for property in &self.0 {
+            write_property(f, property, 0)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_property(f: &mut Formatter<'_>, property: &Property, depth: usize) -> FmtResult {
+    let indent = "    ".repeat(depth);
+    match property {
+        Property::Entry { name, value, .. } => {
+            let name = name.as_str();
+            match value {
+                Value::Str(value) => writeln!(f, "{indent}{name} = {};", quote(value.value())),
+                Value::Number(value) => writeln!(f, "{indent}{name} = {};", number(value)),
+                Value::Expression(value) => {
+                    writeln!(f, "{indent}{name} = __EVAL({});", value.value)
+                }
+                Value::Array(array) => writeln!(
+                    f,
+                    "{indent}{name}[] {} {};",
+                    if array.expand { "+=" } else { "=" },
+                    items(&array.items)
+                ),
+                Value::UnexpectedArray(array) => {
+                    writeln!(f, "{indent}{name} = {};", items(&array.items))
+                }
+                Value::Invalid(_) => Ok(()),
+            }
+        }
+        Property::Class(Class::Local {
+            name,
+            parent,
+            properties,
+            ..
+        }) => {
+            write!(f, "{indent}class {}", name.as_str())?;
+            if let Some(parent) = parent {
+                write!(f, ": {}", parent.as_str())?;
+            }
+            if properties.is_empty() {
+                return writeln!(f, " {{}};");
+            }
+            writeln!(f, " {{")?;
+            for property in properties {
+                write_property(f, property, depth + 1)?;
+            }
+            writeln!(f, "{indent}}};")
+        }
+        Property::Class(Class::External { name }) => {
+            writeln!(f, "{indent}class {};", name.as_str())
+        }
+        Property::Class(Class::Root { properties }) => {
+            for property in properties {
+                write_property(f, property, depth)?;
+            }
+            Ok(())
+        }
+        Property::Delete(name) => writeln!(f, "{indent}delete {};", name.as_str()),
+        Property::MissingSemicolon(..) => Ok(()),
+    }
+}
+
+fn items(items: &[Item]) -> String {
+    let items = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Str(value) => Some(quote(value.value())),
+            Item::Number(value) => Some(number(value)),
+            Item::Array(items) => Some(self::items(items)),
+            Item::Invalid(_) => None,
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", items.join(", "))
+}
+
+/// Quotes are doubled, and new lines are written as `" \n "` so they can be read back
+fn quote(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value.replace('"', "\"\"").replace('\n', "\" \\n \"")
+    )
+}
+
+/// Floats always have a decimal point, so they are not read back as integers
+fn number(number: &Number) -> String {
+    match number {
+        Number::Float32 { value, .. } if value.is_finite() && value.fract() == 0.0 => {
+            format!("{value:.1}")
+        }
+        _ => number.to_string(),
+    }
 }
@@ -6,3 +6,11 @@ pub struct Expression {
     pub(crate) value: String,
     pub(crate) span: Range<usize>,
 }
+
+impl Expression {
+    #[must_use]
+    /// Get the value
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
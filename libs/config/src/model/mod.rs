@@ -5,6 +5,8 @@ mod expression;
 mod ident;
 mod number;
 mod property;
+mod resolve;
+mod serialize;
 mod str;
 mod value;
 
@@ -16,4 +18,5 @@ pub use expression::Expression;
 pub use ident::Ident;
 pub use number::Number;
 pub use property::Property;
+pub use resolve::ResolvedClass;
 pub use value::Value;
@@ -69,6 +69,17 @@ impl Number {
         }
     }
 
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    /// Get the value of the number as a float
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            Self::Int32 { value, .. } => f64::from(*value),
+            Self::Int64 { value, .. } => *value as f64,
+            Self::Float32 { value, .. } => f64::from(*value),
+        }
+    }
+
     #[must_use]
     /// Get the range of the number
     pub fn span(&self) -> Range<usize> {
@@ -0,0 +1,356 @@
+use crate::{Array, Class, Config, Ident, Property, Value};
+
+/// Classes nested deeper than this are assumed to inherit from themselves
+const MAX_DEPTH: usize = 64;
+
+#[derive(Clone, Debug, PartialEq)]
+/// A class with its inheritance resolved
+pub struct ResolvedClass {
+    name: Ident,
+    parents: Vec<Ident>,
+    missing: Option<Ident>,
+    properties: Vec<Property>,
+}
+
+impl ResolvedClass {
+    #[must_use]
+    /// The name of the class
+    pub const fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    #[must_use]
+    /// The classes it inherits from, closest first
+    pub fn parents(&self) -> &[Ident] {
+        &self.parents
+    }
+
+    #[must_use]
+    /// The parent that could not be found, ending the inheritance early
+    pub const fn missing(&self) -> Option<&Ident> {
+        self.missing.as_ref()
+    }
+
+    #[must_use]
+    /// The properties of the class, including inherited ones
+    ///
+    /// Nested classes are resolved as well, and have no parent
+    pub fn properties(&self) -> &[Property] {
+        &self.properties
+    }
+
+    #[must_use]
+    /// The class as a config, with the properties of its parents
+    pub fn to_config(&self) -> Config {
+        Config(vec![Property::Class(Class::Local {
+            name: self.name.clone(),
+            parent: None,
+            properties: self.properties.clone(),
+            err_missing_braces: false,
+        })])
+    }
+}
+
+#[derive(Clone, Copy)]
+/// The classes visible from inside a class
+struct Scope<'a> {
+    /// The properties defined in the class
+    own: &'a [Property],
+    /// The resolved properties of its parent
+    inherited: &'a [Property],
+}
+
+/// A class found in a scope
+struct Found<'a> {
+    /// The scope it was found in
+    level: usize,
+    class: &'a Class,
+    /// Was it inherited, and already resolved
+    resolved: bool,
+}
+
+impl Config {
+    #[must_use]
+    /// Merge configs in load order, later configs extend and override earlier ones
+    ///
+    /// Like the game, a class defined again takes the parent of the later definition,
+    /// `class Name;` does not change a class that is already defined, and `delete` removes it
+    pub fn merge(configs: impl IntoIterator<Item = Self>) -> Self {
+        let mut merged = Vec::new();
+        for config in configs {
+            merge_properties(&mut merged, config.0);
+        }
+        Self(merged)
+    }
+
+    #[must_use]
+    /// Find a class by its path, such as `CfgVehicles/MyTank`, and resolve its inheritance
+    ///
+    /// Names are case-insensitive, [`None`] if the class is not defined
+    pub fn resolve(&self, path: &str) -> Option<ResolvedClass> {
+        let path = path
+            .split('/')
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>();
+        if path.is_empty() {
+            return None;
+        }
+        walk(
+            &[Scope {
+                own: &self.0,
+                inherited: &[],
+            }],
+            &path,
+        )
+    }
+}
+
+fn walk(scopes: &[Scope], path: &[&str]) -> Option<ResolvedClass> {
+    let scope = scopes.last().expect("scopes are not empty");
+    let (class, resolved) = local_class(scope.own, path[0])
+        .map(|class| (class, false))
+        .or_else(|| local_class(scope.inherited, path[0]).map(|class| (class, true)))?;
+    let name = class.name().expect("local classes have a name");
+    if path.len() == 1 {
+        return Some(if resolved {
+            ResolvedClass {
+                name: name.clone(),
+                parents: Vec::new(),
+                missing: None,
+                properties: class.properties().to_vec(),
+            }
+        } else {
+            resolve_class(scopes, class, 0)
+        });
+    }
+    let inherited = if resolved {
+        Vec::new()
+    } else {
+        inherit(scopes, class, 0).1
+    };
+    let mut inner = scopes.to_vec();
+    inner.push(Scope {
+        own: class.properties(),
+        inherited: &inherited,
+    });
+    walk(&inner, &path[1..])
+}
+
+/// Resolve a class defined in the innermost scope
+fn resolve_class(scopes: &[Scope], class: &Class, depth: usize) -> ResolvedClass {
+    let name = class.name().expect("local classes have a name").clone();
+    let ((parents, missing), inherited) = inherit(scopes, class, depth);
+    let mut inner = scopes.to_vec();
+    inner.push(Scope {
+        own: class.properties(),
+        inherited: &inherited,
+    });
+    let mut properties = inherited.clone();
+    for property in class.properties() {
+        match property {
+            Property::Entry {
+                name,
+                value: Value::Array(array),
+                expected_array,
+            } if array.expand => {
+                let mut items = match entry(&properties, name.as_str()) {
+                    Some(Value::Array(existing)) => existing.items.clone(),
+                    _ => Vec::new(),
+                };
+                items.extend(array.items.iter().cloned());
+                set(
+                    &mut properties,
+                    Property::Entry {
+                        name: name.clone(),
+                        value: Value::Array(Array {
+                            expand: false,
+                            items,
+                            span: array.span.clone(),
+                        }),
+                        expected_array: *expected_array,
+                    },
+                );
+            }
+            Property::Entry { .. } => set(&mut properties, property.clone()),
+            Property::Class(child @ Class::Local { name, .. }) => {
+                let resolved = resolve_class(&inner, child, depth + 1);
+                set(
+                    &mut properties,
+                    Property::Class(Class::Local {
+                        name: name.clone(),
+                        parent: None,
+                        properties: resolved.properties,
+                        err_missing_braces: false,
+                    }),
+                );
+            }
+            Property::Class(Class::External { name }) => {
+                if position(&properties, name.as_str()).is_none() {
+                    properties.push(property.clone());
+                }
+            }
+            Property::Delete(name) => {
+                if let Some(index) = position(&properties, name.as_str()) {
+                    properties.remove(index);
+                }
+            }
+            Property::Class(Class::Root { .. }) | Property::MissingSemicolon(..) => {}
+        }
+    }
+    ResolvedClass {
+        name,
+        parents,
+        missing,
+        properties,
+    }
+}
+
+/// The parents of a class, and the resolved properties of its parent
+fn inherit(
+    scopes: &[Scope],
+    class: &Class,
+    depth: usize,
+) -> ((Vec<Ident>, Option<Ident>), Vec<Property>) {
+    let Some(parent) = class.parent() else {
+        return ((Vec::new(), None), Vec::new());
+    };
+    if depth > MAX_DEPTH {
+        return ((Vec::new(), Some(parent.clone())), Vec::new());
+    }
+    let name = class.name().expect("local classes have a name");
+    let Some(found) = find_class(scopes, parent.as_str(), name.as_str()) else {
+        return ((vec![parent.clone()], Some(parent.clone())), Vec::new());
+    };
+    if found.resolved {
+        return (
+            (vec![parent.clone()], None),
+            found.class.properties().to_vec(),
+        );
+    }
+    let resolved = resolve_class(&scopes[..=found.level], found.class, depth + 1);
+    let mut parents = vec![parent.clone()];
+    parents.extend(resolved.parents);
+    ((parents, resolved.missing), resolved.properties)
+}
+
+/// Find the parent of a class, skipping the class itself
+///
+/// The class and its siblings are searched first, then the classes it inherits, then each enclosing class
+fn find_class<'a>(scopes: &[Scope<'a>], name: &str, skip: &str) -> Option<Found<'a>> {
+    let innermost = scopes.len() - 1;
+    scopes.iter().enumerate().rev().find_map(|(level, scope)| {
+        let own = if level == innermost && name.eq_ignore_ascii_case(skip) {
+            None
+        } else {
+            local_class(scope.own, name)
+        };
+        own.map(|class| Found {
+            level,
+            class,
+            resolved: false,
+        })
+        .or_else(|| {
+            local_class(scope.inherited, name).map(|class| Found {
+                level,
+                class,
+                resolved: true,
+            })
+        })
+    })
+}
+
+fn local_class<'a>(properties: &'a [Property], name: &str) -> Option<&'a Class> {
+    properties.iter().find_map(|property| match property {
+        Property::Class(class @ Class::Local { name: n, .. })
+            if n.as_str().eq_ignore_ascii_case(name) =>
+        {
+            Some(class)
+        }
+        _ => None,
+    })
+}
+
+fn entry<'a>(properties: &'a [Property], name: &str) -> Option<&'a Value> {
+    properties.iter().find_map(|property| match property {
+        Property::Entry { name: n, value, .. } if n.as_str().eq_ignore_ascii_case(name) => {
+            Some(value)
+        }
+        _ => None,
+    })
+}
+
+fn position(properties: &[Property], name: &str) -> Option<usize> {
+    properties.iter().position(|property| {
+        !matches!(property, Property::MissingSemicolon(..))
+            && property.name().as_str().eq_ignore_ascii_case(name)
+    })
+}
+
+/// Replace the property with the same name, or add it
+fn set(properties: &mut Vec<Property>, property: Property) {
+    if let Some(index) = position(properties, property.name().as_str()) {
+        properties[index] = property;
+    } else {
+        properties.push(property);
+    }
+}
+
+/// Merge a later definition of a class into an earlier one
+fn merge_properties(target: &mut Vec<Property>, source: Vec<Property>) {
+    for property in source {
+        match property {
+            Property::Entry {
+                ref name,
+                value: Value::Array(ref array),
+                ..
+            } if array.expand => {
+                if let Some(Property::Entry {
+                    value: Value::Array(existing),
+                    ..
+                }) = position(target, name.as_str()).map(|index| &mut target[index])
+                {
+                    existing.items.extend(array.items.iter().cloned());
+                } else {
+                    target.push(property);
+                }
+            }
+            Property::Entry { .. } => set(target, property),
+            Property::Class(Class::Local {
+                name,
+                parent,
+                properties,
+                err_missing_braces,
+            }) => match position(target, name.as_str()).map(|index| &mut target[index]) {
+                Some(Property::Class(Class::Local {
+                    parent: existing_parent,
+                    properties: existing,
+                    ..
+                })) => {
+                    *existing_parent = parent;
+                    merge_properties(existing, properties);
+                }
+                _ => set(
+                    target,
+                    Property::Class(Class::Local {
+                        name,
+                        parent,
+                        properties,
+                        err_missing_braces,
+                    }),
+                ),
+            },
+            Property::Class(Class::External { ref name }) => {
+                if position(target, name.as_str()).is_none() {
+                    target.push(property);
+                }
+            }
+            Property::Delete(name) => {
+                if let Some(index) = position(target, name.as_str()) {
+                    target.remove(index);
+                }
+            }
+            Property::Class(Class::Root { .. }) | Property::MissingSemicolon(..) => {}
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+use crate::{Class, Config, Item, Number, Property, Value};
+
+impl Config {
+    /// The config as pretty JSON
+    ///
+    /// Properties are an object, classes are objects with a `parent`, which is `null`
+    /// if the class has none, and their `properties`. External classes are `{ "external": true }`.
+    ///
+    /// # Errors
+    /// If the config can not be serialized
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// The config as YAML, in the same shape as [`Config::to_json`]
+    ///
+    /// # Errors
+    /// If the config can not be serialized
+    pub fn to_yaml(&self) -> Result<String, serde_json::Error> {
+        let mut out = String::new();
+        yaml::document(&mut out, &serde_json::to_value(self)?);
+        Ok(out)
+    }
+}
+
+impl Serialize for Config {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Properties(&self.0).serialize(serializer)
+    }
+}
+
+/// The properties of a class, in the order they are defined
+struct Properties<'a>(&'a [Property]);
+
+impl Serialize for Properties<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for property in self.0 {
+            match property {
+                Property::Entry { name, value, .. } => {
+                    if !matches!(value, Value::Invalid(_)) {
+                        map.serialize_entry(name.as_str(), value)?;
+                    }
+                }
+                Property::Class(class @ (Class::Local { name, .. } | Class::External { name })) => {
+                    map.serialize_entry(name.as_str(), class)?;
+                }
+                Property::Class(Class::Root { .. })
+                | Property::Delete(_)
+                | Property::MissingSemicolon(..) => {}
+            }
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Class {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Local {
+                parent, properties, ..
+            } => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("parent", &parent.as_ref().map(crate::Ident::as_str))?;
+                map.serialize_entry("properties", &Properties(properties))?;
+                map.end()
+            }
+            Self::External { .. } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("external", &true)?;
+                map.end()
+            }
+            Self::Root { properties } => Properties(properties).serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Str(value) => serializer.serialize_str(value.value()),
+            Self::Number(number) => number.serialize(serializer),
+            Self::Expression(expression) => {
+                serializer.serialize_str(&format!("__EVAL({})", expression.value()))
+            }
+            Self::Array(array) | Self::UnexpectedArray(array) => {
+                Items(array.items()).serialize(serializer)
+            }
+            Self::Invalid(_) => serializer.serialize_none(),
+        }
+    }
+}
+
+impl Serialize for Number {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Int32 { value, .. } => serializer.serialize_i32(*value),
+            Self::Int64 { value, .. } => serializer.serialize_i64(*value),
+            // the shortest text of the `f32`, so it is not widened to `0.10000000149011612`
+            Self::Float32 { value, .. } => serializer.serialize_f64(
+                value
+                    .to_string()
+                    .parse()
+                    .unwrap_or_else(|_| f64::from(*value)),
+            ),
+        }
+    }
+}
+
+/// The items of an array, without invalid items
+struct Items<'a>(&'a [Item]);
+
+impl Serialize for Items<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().filter_map(|item| match item {
+            Item::Invalid(_) => None,
+            item => Some(ItemValue(item)),
+        }))
+    }
+}
+
+struct ItemValue<'a>(&'a Item);
+
+impl Serialize for ItemValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Item::Str(value) => serializer.serialize_str(value.value()),
+            Item::Number(number) => number.serialize(serializer),
+            Item::Array(items) => Items(items).serialize(serializer),
+            Item::Invalid(_) => serializer.serialize_none(),
+        }
+    }
+}
+
+/// A YAML emitter for the JSON shape of a config, in block style
+mod yaml {
+    use serde_json::{Map, Value};
+
+    pub fn document(out: &mut String, value: &Value) {
+        match value {
+            Value::Object(map) if !map.is_empty() => object(out, map, 0, false),
+            Value::Array(items) if !items.is_empty() => array(out, items, 0, false),
+            value => {
+                out.push_str(&scalar(value));
+                out.push('\n');
+            }
+        }
+    }
+
+    /// Write the entries of an object, `inline` if the first entry follows a `- `
+    fn object(out: &mut String, map: &Map<String, Value>, indent: usize, inline: bool) {
+        for (i, (key, value)) in map.iter().enumerate() {
+            if i != 0 || !inline {
+                pad(out, indent);
+            }
+            out.push_str(&string(key));
+            out.push(':');
+            match value {
+                Value::Object(map) if !map.is_empty() => {
+                    out.push('\n');
+                    object(out, map, indent + 2, false);
+                }
+                Value::Array(items) if !items.is_empty() => {
+                    out.push('\n');
+                    array(out, items, indent, false);
+                }
+                value => {
+                    out.push(' ');
+                    out.push_str(&scalar(value));
+                    out.push('\n');
+                }
+            }
+        }
+    }
+
+    /// Write the items of an array, `inline` if the first item follows a `- `
+    fn array(out: &mut String, items: &[Value], indent: usize, inline: bool) {
+        for (i, value) in items.iter().enumerate() {
+            if i != 0 || !inline {
+                pad(out, indent);
+            }
+            out.push_str("- ");
+            match value {
+                Value::Object(map) if !map.is_empty() => object(out, map, indent + 2, true),
+                Value::Array(items) if !items.is_empty() => array(out, items, indent + 2, true),
+                value => {
+                    out.push_str(&scalar(value));
+                    out.push('\n');
+                }
+            }
+        }
+    }
+
+    fn pad(out: &mut String, indent: usize) {
+        out.push_str(&" ".repeat(indent));
+    }
+
+    fn scalar(value: &Value) -> String {
+        match value {
+            Value::Null => String::from("null"),
+            Value::Bool(value) => value.to_string(),
+            Value::Number(number) => number.to_string(),
+            Value::String(value) => string(value),
+            Value::Array(_) => String::from("[]"),
+            Value::Object(_) => String::from("{}"),
+        }
+    }
+
+    /// A string, quoted when it would be read as something else
+    fn string(value: &str) -> String {
+        if plain(value) {
+            value.to_string()
+        } else {
+            // JSON escapes are valid in a double quoted YAML string
+            Value::String(value.to_string()).to_string()
+        }
+    }
+
+    /// Can the string be written without quotes
+    fn plain(value: &str) -> bool {
+        let Some(first) = value.chars().next() else {
+            return false;
+        };
+        !(first.is_ascii_digit()
+            || "-?:,[]{}#&*!|>'\"%@`+.~ ".contains(first)
+            || value.ends_with([' ', ':'])
+            || value.contains(": ")
+            || value.contains(" #")
+            || value.chars().any(char::is_control)
+            || matches!(
+                value.to_lowercase().as_str(),
+                "null" | "true" | "false" | "yes" | "no" | "on" | "off" | "y" | "n"
+            )
+            || value.parse::<f64>().is_ok())
+    }
+}
@@ -0,0 +1,95 @@
+//! Model configs, `model.cfg`, which describe the skeletons, sections, and animations of models for binarize
+//!
+//! They are preprocessed and parsed like any other config, but are not rapified, binarize reads the text.
+
+use crate::{Class, Config, Item, Property, Str, Value};
+
+#[must_use]
+/// The skeletons defined in `CfgSkeletons`
+pub fn skeletons(config: &Config) -> Vec<&Class> {
+    classes(config, "CfgSkeletons")
+}
+
+#[must_use]
+/// The models defined in `CfgModels`
+pub fn models(config: &Config) -> Vec<&Class> {
+    classes(config, "CfgModels")
+}
+
+#[must_use]
+/// The bones of a skeleton, from every other item of `skeletonBones[]`
+///
+/// The items in between are the parents of the bones
+pub fn bones(skeleton: &Class) -> Vec<&Str> {
+    array(skeleton, "skeletonBones")
+        .iter()
+        .step_by(2)
+        .filter_map(|item| match item {
+            Item::Str(bone) => Some(bone),
+            _ => None,
+        })
+        .collect()
+}
+
+#[must_use]
+/// The `skeletonName` of a model
+pub fn skeleton_name(model: &Class) -> Option<&Str> {
+    model
+        .properties()
+        .iter()
+        .find_map(|property| match property {
+            Property::Entry {
+                name,
+                value: Value::Str(value),
+                ..
+            } if name.as_str().eq_ignore_ascii_case("skeletonName") => Some(value),
+            _ => None,
+        })
+}
+
+#[must_use]
+/// The `sections[]` of a model
+pub fn sections(model: &Class) -> Vec<&Str> {
+    array(model, "sections")
+        .iter()
+        .filter_map(|item| match item {
+            Item::Str(section) => Some(section),
+            _ => None,
+        })
+        .collect()
+}
+
+fn classes<'a>(config: &'a Config, root: &str) -> Vec<&'a Class> {
+    config
+        .0
+        .iter()
+        .filter_map(|property| match property {
+            Property::Class(class @ Class::Local { name, .. })
+                if name.as_str().eq_ignore_ascii_case(root) =>
+            {
+                Some(class)
+            }
+            _ => None,
+        })
+        .flat_map(Class::properties)
+        .filter_map(|property| match property {
+            Property::Class(class) => Some(class),
+            _ => None,
+        })
+        .collect()
+}
+
+fn array<'a>(class: &'a Class, key: &str) -> &'a [Item] {
+    class
+        .properties()
+        .iter()
+        .find_map(|property| match property {
+            Property::Entry {
+                name,
+                value: Value::Array(array),
+                ..
+            } if name.as_str().eq_ignore_ascii_case(key) => Some(array.items.as_slice()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
@@ -1,10 +1,11 @@
 use std::iter::Sum;
 
-use hemtt_common::io::{compressed_int_len, WriteExt};
+use byteorder::ReadBytesExt;
+use hemtt_common::io::{compressed_int_len, ReadExt, WriteExt};
 
-use crate::{Array, Item};
+use crate::{Array, Item, Number, Str};
 
-use super::Rapify;
+use super::{invalid_data, Derapify, Rapify};
 
 impl Rapify for Array {
     fn rapify<O: std::io::Write>(
@@ -69,3 +70,30 @@ impl Rapify for Item {
         }
     }
 }
+
+impl Derapify for Array {
+    fn derapify<I: std::io::Read + std::io::Seek>(input: &mut I) -> Result<Self, std::io::Error> {
+        Ok(Self {
+            expand: false,
+            items: derapify_items(input)?,
+            span: 0..0,
+        })
+    }
+}
+
+fn derapify_items<I: std::io::Read + std::io::Seek>(
+    input: &mut I,
+) -> Result<Vec<Item>, std::io::Error> {
+    let len = input.read_compressed_int()?;
+    (0..len)
+        .map(|_| {
+            let code = input.read_u8()?;
+            Ok(match code {
+                0 => Item::Str(Str::derapify(input)?),
+                1 | 2 | 6 => Item::Number(Number::derapify_code(input, code)?),
+                3 => Item::Array(derapify_items(input)?),
+                _ => return Err(invalid_data(format!("invalid array item code {code}"))),
+            })
+        })
+        .collect()
+}
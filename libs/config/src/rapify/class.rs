@@ -1,11 +1,11 @@
-use std::io::Cursor;
+use std::io::{Cursor, Read, Seek, SeekFrom};
 
-use byteorder::{LittleEndian, WriteBytesExt};
-use hemtt_common::io::{compressed_int_len, WriteExt};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use hemtt_common::io::{compressed_int_len, ReadExt, WriteExt};
 
-use crate::{Class, Ident, Property};
+use crate::{Array, Class, Ident, Property, Value};
 
-use super::Rapify;
+use super::{invalid_data, read_string, Derapify, Rapify, MAX_DEPTH};
 
 impl Rapify for Class {
     fn rapify<O: std::io::Write>(
@@ -110,3 +110,88 @@ impl Rapify for Class {
         }
     }
 }
+
+impl Class {
+    /// Read a class body, [`Class::Root`] if no name is given
+    pub(crate) fn derapify_body<I: Read + Seek>(
+        input: &mut I,
+        name: Option<Ident>,
+        depth: usize,
+    ) -> Result<Self, std::io::Error> {
+        if depth > MAX_DEPTH {
+            return Err(invalid_data(format!(
+                "classes are nested deeper than {MAX_DEPTH}"
+            )));
+        }
+        let parent = read_string(input)?;
+        let len = input.read_compressed_int()?;
+        let mut properties = Vec::new();
+        // class bodies are stored after the properties, and are read once all properties are known
+        let mut bodies = Vec::new();
+        for _ in 0..len {
+            let code = input.read_u8()?;
+            match code {
+                0 => {
+                    let name = ident(input)?;
+                    let offset = input.read_u32::<LittleEndian>()?;
+                    bodies.push((properties.len(), name, offset));
+                }
+                1 => {
+                    let value_code = input.read_u8()?;
+                    let name = ident(input)?;
+                    properties.push(Property::Entry {
+                        name,
+                        value: Value::derapify_code(input, value_code)?,
+                        expected_array: false,
+                    });
+                }
+                2 | 5 => {
+                    if code == 5 {
+                        input.read_u32::<LittleEndian>()?;
+                    }
+                    let name = ident(input)?;
+                    let mut array = Array::derapify(input)?;
+                    array.expand = code == 5;
+                    properties.push(Property::Entry {
+                        name,
+                        value: Value::Array(array),
+                        expected_array: true,
+                    });
+                }
+                3 => properties.push(Property::Class(Self::External {
+                    name: ident(input)?,
+                })),
+                4 => properties.push(Property::Delete(ident(input)?)),
+                _ => return Err(invalid_data(format!("invalid property code {code}"))),
+            }
+        }
+        for (index, name, offset) in bodies.into_iter().rev() {
+            input.seek(SeekFrom::Start(u64::from(offset)))?;
+            let class = Self::derapify_body(input, Some(name), depth + 1)?;
+            properties.insert(index, Property::Class(class));
+        }
+        Ok(match name {
+            Some(name) => Self::Local {
+                name,
+                parent: if parent.is_empty() {
+                    None
+                } else {
+                    Some(Ident {
+                        value: parent,
+                        span: 0..0,
+                    })
+                },
+                properties,
+                err_missing_braces: false,
+            },
+            None => Self::Root { properties },
+        })
+    }
+}
+
+fn ident<I: Read>(input: &mut I) -> Result<Ident, std::io::Error> {
+    Ok(Ident {
+        value: read_string(input)?,
+        span: 0..0,
+    })
+}
@@ -1,10 +1,10 @@
-use std::io::Cursor;
+use std::io::{Cursor, Read, Seek, SeekFrom};
 
-use byteorder::{LittleEndian, WriteBytesExt};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::{Class, Config};
 
-use super::Rapify;
+use super::{invalid_data, Derapify, Rapify, MAGIC};
 
 impl Rapify for Config {
     fn rapify<O: std::io::Write>(
@@ -12,7 +12,7 @@ impl Rapify for Config {
         output: &mut O,
         _offset: usize,
     ) -> Result<usize, std::io::Error> {
-        output.write_all(b"\0raP")?;
+        output.write_all(MAGIC)?;
         output.write_all(b"\0\0\0\0\x08\0\0\0")?;
 
         let root_class = Class::Root {
@@ -40,3 +40,20 @@ impl Rapify for Config {
         root_class.rapified_length() + 20 // metadata
     }
 }
+
+impl Derapify for Config {
+    fn derapify<I: Read + Seek>(input: &mut I) -> Result<Self, std::io::Error> {
+        let mut magic = [0; 4];
+        input.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("not a rapified config".to_string()));
+        }
+        input.seek(SeekFrom::Current(8))?;
+        // offset of the enums, which HEMTT does not use
+        input.read_u32::<LittleEndian>()?;
+        let Class::Root { properties } = Class::derapify_body(input, None, 0)? else {
+            unreachable!("unnamed classes are the root")
+        };
+        Ok(Self(properties))
+    }
+}
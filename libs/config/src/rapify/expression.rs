@@ -2,7 +2,7 @@ use hemtt_common::io::WriteExt;
 
 use crate::Expression;
 
-use super::Rapify;
+use super::{read_string, Derapify, Rapify};
 
 impl Rapify for Expression {
     fn rapify<O: std::io::Write>(
@@ -23,6 +23,15 @@ impl Rapify for Expression {
     }
 }
 
+impl Derapify for Expression {
+    fn derapify<I: std::io::Read + std::io::Seek>(input: &mut I) -> Result<Self, std::io::Error> {
+        Ok(Self {
+            value: read_string(input)?,
+            span: 0..0,
+        })
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -9,7 +9,9 @@ mod property;
 mod str;
 mod value;
 
-use std::io::Write;
+use std::io::{Read, Seek, Write};
+
+use byteorder::ReadBytesExt;
 
 /// Trait for rapifying objects
 pub trait Rapify {
@@ -25,3 +27,40 @@ pub trait Rapify {
         3
     }
 }
+
+/// Trait for reading rapified objects
+pub trait Derapify: Sized {
+    /// Read the object from the rapified input stream
+    ///
+    /// # Errors
+    /// if the input stream fails or does not contain a valid object
+    fn derapify<I: Read + Seek>(input: &mut I) -> Result<Self, std::io::Error>;
+}
+
+/// Classes nested deeper than this are treated as invalid, to guard against cyclic offsets
+const MAX_DEPTH: usize = 64;
+
+/// The first bytes of a rapified file
+pub const MAGIC: &[u8; 4] = b"\0raP";
+
+#[must_use]
+/// Does the data start like a rapified file
+pub fn is_rapified(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+/// Read a null-terminated string, replacing any invalid UTF-8
+fn read_string<I: Read>(input: &mut I) -> Result<String, std::io::Error> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = input.read_u8()?;
+        if byte == 0 {
+            return Ok(String::from_utf8_lossy(&bytes).into_owned());
+        }
+        bytes.push(byte);
+    }
+}
+
+fn invalid_data(message: String) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+}
@@ -1,8 +1,8 @@
-use byteorder::{LittleEndian, WriteBytesExt};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::Number;
 
-use super::Rapify;
+use super::{invalid_data, Rapify};
 
 impl Rapify for Number {
     fn rapify<O: std::io::Write>(
@@ -44,6 +44,30 @@ impl Rapify for Number {
     }
 }
 
+impl Number {
+    /// Read a number with the given rapified element code
+    pub(crate) fn derapify_code<I: std::io::Read>(
+        input: &mut I,
+        code: u8,
+    ) -> Result<Self, std::io::Error> {
+        Ok(match code {
+            1 => Self::Float32 {
+                value: input.read_f32::<LittleEndian>()?,
+                span: 0..0,
+            },
+            2 => Self::Int32 {
+                value: input.read_i32::<LittleEndian>()?,
+                span: 0..0,
+            },
+            6 => Self::Int64 {
+                value: input.read_i64::<LittleEndian>()?,
+                span: 0..0,
+            },
+            _ => return Err(invalid_data(format!("invalid number code {code}"))),
+        })
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -1,6 +1,6 @@
-use crate::Value;
+use crate::{Expression, Number, Str, Value};
 
-use super::Rapify;
+use super::{invalid_data, Derapify, Rapify};
 
 impl Rapify for Value {
     fn rapify<O: std::io::Write>(
@@ -39,3 +39,18 @@ impl Rapify for Value {
         }
     }
 }
+
+impl Value {
+    /// Read a value with the given rapified element code
+    pub(crate) fn derapify_code<I: std::io::Read + std::io::Seek>(
+        input: &mut I,
+        code: u8,
+    ) -> Result<Self, std::io::Error> {
+        Ok(match code {
+            0 => Self::Str(Str::derapify(input)?),
+            1 | 2 | 6 => Self::Number(Number::derapify_code(input, code)?),
+            4 => Self::Expression(Expression::derapify(input)?),
+            _ => return Err(invalid_data(format!("invalid value code {code}"))),
+        })
+    }
+}
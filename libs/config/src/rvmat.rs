@@ -0,0 +1,293 @@
+//! Materials, `*.rvmat`, which are configs describing the shaders and textures of a surface
+//!
+//! The shaders are set by `PixelShaderID` and `VertexShaderID`, and the textures by the `Stage1` to `StageN` classes.
+
+use crate::{Class, Config, Property, Str, Value};
+
+/// Pixel shaders known to the game
+pub const PIXEL_SHADERS: &[&str] = &[
+    "AlphaNoShadow",
+    "AlphaShadow",
+    "CalmWater",
+    "Caustics",
+    "Cloud",
+    "Collimator",
+    "Crater1",
+    "Crater2",
+    "Crater3",
+    "Crater4",
+    "Crater5",
+    "Crater6",
+    "Crater7",
+    "Crater8",
+    "Crater9",
+    "Crater10",
+    "Crater11",
+    "Crater12",
+    "Crater13",
+    "Crater14",
+    "DepthOnly",
+    "Detail",
+    "DetailMacroAS",
+    "Dummy",
+    "Dummy0",
+    "Empty",
+    "Glass",
+    "Grass",
+    "GrassAToC",
+    "Horizon",
+    "Interpolation",
+    "InterpolationAlpha",
+    "LODDiag",
+    "Multi",
+    "NonTL",
+    "NonTLFlare",
+    "NonTLFlareLight",
+    "NonTLFlareNew",
+    "NonTLFlareNewNoOcclusion",
+    "Normal",
+    "NormalDXTA",
+    "NormalMap",
+    "NormalMapDetailMacroASSpecularDIMap",
+    "NormalMapDetailMacroASSpecularMap",
+    "NormalMapDetailSpecularDIMap",
+    "NormalMapDetailSpecularMap",
+    "NormalMapDiffuse",
+    "NormalMapDiffuseMacroAS",
+    "NormalMapGrass",
+    "NormalMapMacroAS",
+    "NormalMapMacroASSpecularDIMap",
+    "NormalMapMacroASSpecularMap",
+    "NormalMapSpecularDIMap",
+    "NormalMapSpecularMap",
+    "NormalMapSpecularThrough",
+    "NormalMapSpecularThroughSimple",
+    "NormalMapThrough",
+    "NormalMapThroughLowEnd",
+    "NormalMapThroughSimple",
+    "NormalPiP",
+    "Point",
+    "Refract",
+    "Road",
+    "Road2Pass",
+    "Shore",
+    "ShoreFoam",
+    "ShoreWet",
+    "SimulWeatherClouds",
+    "SimulWeatherCloudsCPU",
+    "SimulWeatherCloudsWithLightning",
+    "SimulWeatherCloudsWithLightningCPU",
+    "Skin",
+    "Sprite",
+    "SpriteExtTi",
+    "SpriteRefract",
+    "SpriteRefractSimple",
+    "SpriteSimple",
+    "Super",
+    "SuperAToC",
+    "SuperExt",
+    "SuperHair",
+    "SuperHairAtoC",
+    "Terrain1",
+    "Terrain2",
+    "Terrain3",
+    "Terrain4",
+    "Terrain5",
+    "Terrain6",
+    "Terrain7",
+    "Terrain8",
+    "Terrain9",
+    "Terrain10",
+    "Terrain11",
+    "Terrain12",
+    "Terrain13",
+    "Terrain14",
+    "Terrain15",
+    "TerrainGrass1",
+    "TerrainGrass2",
+    "TerrainGrass3",
+    "TerrainGrass4",
+    "TerrainGrass5",
+    "TerrainGrass6",
+    "TerrainGrass7",
+    "TerrainGrass8",
+    "TerrainGrass9",
+    "TerrainGrass10",
+    "TerrainGrass11",
+    "TerrainGrass12",
+    "TerrainGrass13",
+    "TerrainGrass14",
+    "TerrainGrass15",
+    "TerrainGrassX",
+    "TerrainNoDetailSNX",
+    "TerrainNoDetailX",
+    "TerrainSimple1",
+    "TerrainSimple2",
+    "TerrainSimple3",
+    "TerrainSimple4",
+    "TerrainSimple5",
+    "TerrainSimple6",
+    "TerrainSimple7",
+    "TerrainSimple8",
+    "TerrainSimple9",
+    "TerrainSimple10",
+    "TerrainSimple11",
+    "TerrainSimple12",
+    "TerrainSimple13",
+    "TerrainSimple14",
+    "TerrainSimple15",
+    "TerrainSimpleSNX",
+    "TerrainSimpleX",
+    "TerrainSNX",
+    "TerrainX",
+    "Tree",
+    "TreeAdv",
+    "TreeAdvAToC",
+    "TreeAdvSimple",
+    "TreeAdvSimpleAToC",
+    "TreeAdvTrans",
+    "TreeAdvTransAToC",
+    "TreeAdvTrunk",
+    "TreeAdvTrunkSimple",
+    "TreeAToC",
+    "TreePRT",
+    "TreeSimple",
+    "TreeSN",
+    "Water",
+    "WaterSimple",
+    "White",
+    "WhiteAlpha",
+];
+
+/// Vertex shaders known to the game
+pub const VERTEX_SHADERS: &[&str] = &[
+    "Basic",
+    "BasicAS",
+    "BasicFade",
+    "CalmWater",
+    "Dummy2",
+    "Dummy3",
+    "Dummy4",
+    "Glass",
+    "Grass",
+    "Multi",
+    "NormalMap",
+    "NormalMapAS",
+    "NormalMapDiffuse",
+    "NormalMapDiffuseAS",
+    "NormalMapSpecularThrough",
+    "NormalMapSpecularThroughNoFade",
+    "NormalMapThrough",
+    "NormalMapThroughNoFade",
+    "Point",
+    "Refract",
+    "Road",
+    "ShadowVolume",
+    "Shore",
+    "SimulWeatherClouds",
+    "SimulWeatherCloudsCPU",
+    "SimulWeatherCloudsGS",
+    "Skin",
+    "Sprite",
+    "SpriteOnSurface",
+    "Star",
+    "Super",
+    "Terrain",
+    "TerrainGrass",
+    "Tree",
+    "TreeAdv",
+    "TreeAdvModNormals",
+    "TreeAdvNoFade",
+    "TreeAdvTrunk",
+    "TreeNoFade",
+    "TreePRT",
+    "TreePRTNoFade",
+    "UnderwaterOcclusion",
+    "VolCloud",
+    "Water",
+    "WaterSimple",
+];
+
+/// Pixel shaders with a fixed number of stages, not counting `StageTI`
+const STAGES: [(&str, usize); 9] = [
+    ("NormalMapSpecularMap", 2),
+    ("NormalMapSpecularDIMap", 2),
+    ("NormalMapDetailSpecularMap", 3),
+    ("NormalMapDetailSpecularDIMap", 3),
+    ("NormalMapMacroASSpecularMap", 4),
+    ("NormalMapMacroASSpecularDIMap", 4),
+    ("NormalMapDetailMacroASSpecularMap", 5),
+    ("NormalMapDetailMacroASSpecularDIMap", 5),
+    ("Super", 7),
+];
+
+#[must_use]
+/// Is the pixel shader known to the game, names are case-insensitive
+pub fn is_pixel_shader(name: &str) -> bool {
+    PIXEL_SHADERS.iter().any(|s| s.eq_ignore_ascii_case(name))
+}
+
+#[must_use]
+/// Is the vertex shader known to the game, names are case-insensitive
+pub fn is_vertex_shader(name: &str) -> bool {
+    VERTEX_SHADERS.iter().any(|s| s.eq_ignore_ascii_case(name))
+}
+
+#[must_use]
+/// The number of stages the pixel shader reads, [`None`] if it is not fixed
+pub fn expected_stages(pixel_shader: &str) -> Option<usize> {
+    STAGES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(pixel_shader))
+        .map(|(_, stages)| *stages)
+}
+
+#[must_use]
+/// The number of a stage class, `Stage3` is `3`
+///
+/// [`None`] for other classes, including `StageTI` for thermal imaging
+pub fn stage_number(name: &str) -> Option<usize> {
+    let (prefix, number) = name.split_at_checked(5)?;
+    if !prefix.eq_ignore_ascii_case("stage") {
+        return None;
+    }
+    number.parse().ok()
+}
+
+#[must_use]
+/// The value of `PixelShaderID`
+pub fn pixel_shader(config: &Config) -> Option<&Str> {
+    shader(config, "PixelShaderID")
+}
+
+#[must_use]
+/// The value of `VertexShaderID`
+pub fn vertex_shader(config: &Config) -> Option<&Str> {
+    shader(config, "VertexShaderID")
+}
+
+#[must_use]
+/// The numbered stages of the material, from `Stage1` onwards
+pub fn stages(config: &Config) -> Vec<usize> {
+    config
+        .0
+        .iter()
+        .filter_map(|property| match property {
+            Property::Class(Class::Local { name, .. }) => {
+                stage_number(name.as_str()).filter(|number| *number > 0)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn shader<'a>(config: &'a Config, key: &str) -> Option<&'a Str> {
+    config.0.iter().find_map(|property| match property {
+        Property::Entry {
+            name,
+            value: Value::Str(value),
+            ..
+        } if name.as_str().eq_ignore_ascii_case(key) => Some(value),
+        _ => None,
+    })
+}
@@ -0,0 +1,41 @@
+//! Mission files, `mission.sqm`, which are configs saved by the Eden editor
+//!
+//! Text missions are parsed like any other config, and binarized missions are rapified configs.
+
+use crate::{Config, Number, Property, Value};
+
+#[must_use]
+/// The `version` of the mission format
+///
+/// The game only reads it from the first property of the mission, [`None`] if it is not there or not a number
+pub fn version(config: &Config) -> Option<i64> {
+    match config.0.first()? {
+        Property::Entry {
+            name,
+            value: Value::Number(number),
+            ..
+        } if name.as_str().eq_ignore_ascii_case("version") => match number {
+            Number::Int32 { value, .. } => Some(i64::from(*value)),
+            Number::Int64 { value, .. } => Some(*value),
+            Number::Float32 { .. } => None,
+        },
+        _ => None,
+    }
+}
+
+#[must_use]
+/// Should the mission be binarized, from the "Binarize the Scenario File" option in Eden
+///
+/// `binarizationWanted = 0;` is written when the option is disabled, missions without it are binarized
+pub fn binarization_wanted(config: &Config) -> bool {
+    !config.0.iter().any(|property| {
+        matches!(
+            property,
+            Property::Entry {
+                name,
+                value: Value::Number(Number::Int32 { value: 0, .. }),
+                ..
+            } if name.as_str().eq_ignore_ascii_case("binarizationWanted")
+        )
+    })
+}
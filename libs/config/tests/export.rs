@@ -0,0 +1,115 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::LayerType;
+
+fn config() -> hemtt_config::Config {
+    read("config.hpp")
+}
+
+fn read(file: &str) -> hemtt_config::Config {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(
+            &std::path::PathBuf::from("tests/export/"),
+            LayerType::Source,
+        )
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let source = workspace.join(file).unwrap();
+    let processed = Processor::run(&source).unwrap();
+    hemtt_config::parse(None, &processed).unwrap().into_config()
+}
+
+#[test]
+fn json() {
+    assert_eq!(
+        config().to_json().unwrap(),
+        r#"{
+  "CfgExample": {
+    "parent": null,
+    "properties": {
+      "Base": {
+        "external": true
+      },
+      "Example": {
+        "parent": "Base",
+        "properties": {
+          "name": "Example",
+          "count": 3,
+          "large": 3000000000,
+          "ratio": 0.5,
+          "list": [
+            1,
+            "two",
+            [
+              3.5
+            ]
+          ],
+          "extra": [
+            "more"
+          ],
+          "time": "__EVAL(1 + 1)"
+        }
+      }
+    }
+  }
+}"#
+    );
+}
+
+#[test]
+fn yaml() {
+    assert_eq!(
+        config().to_yaml().unwrap(),
+        r"CfgExample:
+  parent: null
+  properties:
+    Base:
+      external: true
+    Example:
+      parent: Base
+      properties:
+        name: Example
+        count: 3
+        large: 3000000000
+        ratio: 0.5
+        list:
+        - 1
+        - two
+        - - 3.5
+        extra:
+        - more
+        time: __EVAL(1 + 1)
+"
+    );
+}
+
+#[test]
+fn yaml_quoting() {
+    assert_eq!(
+        read("quoting.hpp").to_yaml().unwrap(),
+        r##"Quoting:
+  parent: null
+  properties:
+    Empty:
+      parent: null
+      properties: {}
+    empty: ""
+    bool: "true"
+    "yes": "Yes"
+    number: "123"
+    float: 0.1
+    colon: "a: b"
+    comment: "#x"
+    dash: "-dash"
+    spaced: " lead"
+    quote: say "hi"
+    quoted: "\"hi\""
+    plain: a-b c
+    arrays:
+    - []
+    - - - 1
+    - []
+"##
+    );
+}
@@ -0,0 +1,12 @@
+class CfgExample {
+    class Base;
+    class Example: Base {
+        name = "Example";
+        count = 3;
+        large = 3000000000;
+        ratio = 0.5;
+        list[] = {1, "two", {3.5}};
+        extra[] += {"more"};
+        time = __EVAL(1 + 1);
+    };
+};
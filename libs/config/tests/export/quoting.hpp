@@ -0,0 +1,16 @@
+class Quoting {
+    class Empty {};
+    empty = "";
+    bool = "true";
+    yes = "Yes";
+    number = "123";
+    float = 0.1;
+    colon = "a: b";
+    comment = "#x";
+    dash = "-dash";
+    spaced = " lead";
+    quote = "say ""hi""";
+    quoted = """hi""";
+    plain = "a-b c";
+    arrays[] = {{}, {{1}}, {}};
+};
@@ -0,0 +1,37 @@
+#![allow(clippy::unwrap_used)]
+
+const ROOT: &str = "tests/format/";
+
+macro_rules! fmt {
+    ($file:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<format_ $file>]() {
+                let source =
+                    std::fs::read_to_string(format!("{ROOT}{}.hpp", stringify!($file))).unwrap();
+                let formatted = hemtt_config::format::format(&source).unwrap();
+                assert_eq!(
+                    formatted,
+                    hemtt_config::format::format(&formatted).unwrap(),
+                    "formatting is not stable"
+                );
+                insta::assert_snapshot!(formatted);
+            }
+        }
+    };
+}
+
+fmt!(basic);
+
+#[test]
+fn format_directive_in_class_definition() {
+    let error =
+        hemtt_config::format::format("class Test\n#ifdef PARENT\n    : Parent\n#endif\n{};\n")
+            .unwrap_err();
+    assert_eq!(error.line(), 2);
+    assert_eq!(error.column(), 1);
+    assert_eq!(
+        error.message(),
+        "preprocessor directives are not supported here"
+    );
+}
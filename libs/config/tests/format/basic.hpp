@@ -0,0 +1,48 @@
+#include "script_component.hpp"
+
+
+
+// Patches for the addon
+class CfgPatches
+{
+  class ADDON {
+    name = COMPONENT_NAME;
+        units[]={};
+    weapons[] = {
+    };
+    requiredVersion=REQUIRED_VERSION;
+    requiredAddons[] = {"cba_main","ace_common"};   // dependencies
+    author = "ABE";
+    authors[]={"Alpha", "Bravo"};
+    VERSION_CONFIG;
+  };
+};
+
+class CfgVehicles {
+
+
+    class Car;
+    class Car_F:Car{
+        class HitPoints;
+    };
+    class GVAR(car) : Car_F { // the car
+        scope=2;
+        /* block
+           comment */
+        displayName = CSTRING(Car);
+        class HitPoints:HitPoints{};
+
+        hiddenSelectionsTextures[] = {QPATHTOF(data\body_co.paa), QPATHTOF(data\wheels_co.paa), QPATHTOF(data\glass_ca.paa)};
+        magazines[] += {
+            "Magazine_1", // first
+            // second
+            "Magazine_2"
+        };
+        positions[] = {{0, 1, 2}, {3, 4, 5}};
+    };
+};
+
+#define SOME_MACRO(x) \
+    class x { \
+        value = 1; \
+    }
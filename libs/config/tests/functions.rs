@@ -0,0 +1,47 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::LayerType;
+
+#[test]
+fn functions() {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(
+            &std::path::PathBuf::from("tests/functions/"),
+            LayerType::Source,
+        )
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let source = workspace.join("config.hpp").unwrap();
+    let processed = Processor::run(&source).unwrap();
+    let config = hemtt_config::parse(None, &processed).unwrap().into_config();
+
+    let functions = config.get_functions();
+    assert_eq!(
+        functions
+            .iter()
+            .map(|f| (f.name(), f.file()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "ABE_fnc_init",
+                Some("\\z\\abe\\addons\\main\\functions\\fn_init.sqf")
+            ),
+            (
+                "ABE_fnc_handleKilled",
+                Some("\\z\\abe\\addons\\main\\handleKilled.sqf")
+            ),
+            ("ABM_fnc_unused", None),
+        ]
+    );
+    assert_eq!(functions[0].class().as_str(), "init");
+
+    assert_eq!(
+        config.get_references(),
+        vec![
+            "abe_fnc_handlekilled".to_string(),
+            "abe_fnc_init".to_string(),
+            "ace_common_fnc_log".to_string(),
+        ]
+    );
+}
@@ -0,0 +1,31 @@
+class CfgFunctions {
+    class ABE {
+        class Common {
+            file = "\z\abe\addons\main\functions";
+            class init {};
+            class handleKilled {
+                file = "\z\abe\addons\main\handleKilled.sqf";
+            };
+        };
+    };
+    class ABE_Misc {
+        tag = "ABM";
+        class Other {
+            class unused {};
+        };
+    };
+};
+class Extended_PreInit_EventHandlers {
+    class abe_main {
+        init = "call ABE_fnc_init";
+    };
+};
+class CfgVehicles {
+    class Man;
+    class abe_unit: Man {
+        class EventHandlers {
+            killed = "_this call ABE_fnc_handleKilled; _this call abe_fnc_handleKilled";
+        };
+        actions[] = {"hint 'abe'", {"ace_common_fnc_log"}};
+    };
+};
@@ -1,8 +1,17 @@
 #![allow(clippy::unwrap_used)]
 
+use std::{collections::HashSet, sync::Arc};
+
 use hemtt_common::config::ProjectConfig;
+use hemtt_config::analyze::{
+    analyze_sound_shaders,
+    sound_shaders::{sound_shader_references, SoundShaderUsage},
+};
 use hemtt_preprocessor::Processor;
-use hemtt_workspace::{reporting::WorkspaceFiles, LayerType};
+use hemtt_workspace::{
+    reporting::{Codes, WorkspaceFiles},
+    LayerType,
+};
 
 const ROOT: &str = "tests/lints/";
 
@@ -19,9 +28,11 @@ macro_rules! lint {
 
 lint!(c01_invalid_value);
 lint!(c01m_invalid_value_macro);
+lint!(c02_duplicate_property);
 lint!(c02_duplicate_property_shadow_property);
 lint!(c03_duplicate_class);
 lint!(c03_duplicate_external);
+lint!(c03_duplicate_nested);
 lint!(c04_missing_parent);
 lint!(c05_parent_case);
 lint!(c06_unexpected_array);
@@ -30,29 +41,223 @@ lint!(c08_missing_semicolon);
 lint!(c09_magwell_missing_magazine);
 lint!(c10_class_missing_braces);
 lint!(c11_file_type);
+lint!(c13_inherited_class_parent);
+lint!(c19_sound_values);
+lint!(c25_event_handler_string);
+lint!(ignore);
+lint!(pragma_suppress);
+
+#[test]
+fn config_error_pragma_suppress_unknown() {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&std::path::PathBuf::from(ROOT), LayerType::Source)
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let source = workspace.join("pragma_suppress_unknown.hpp").unwrap();
+    let Err((_, hemtt_preprocessor::Error::Code(code))) = Processor::run(&source) else {
+        panic!("an unknown lint should not be suppressed");
+    };
+    assert_eq!(code.ident(), "PE21");
+    assert_eq!(
+        code.help().as_deref(),
+        Some("did you mean `inherited_class_parent`?")
+    );
+}
+
+#[test]
+fn config_error_c12_cfgpatches_required() {
+    insta::assert_snapshot!(["main", "other", "empty"]
+        .iter()
+        .map(|addon| lint_path(&format!(
+            "c12_cfgpatches_required/addons/{addon}/config.cpp"
+        )))
+        .collect::<Vec<_>>()
+        .join("\n"));
+}
+
+#[test]
+fn config_error_c14_cfgfunctions_files() {
+    insta::assert_snapshot!(lint_path("c14_cfgfunctions_files/config.hpp"));
+}
+
+#[test]
+fn config_error_c15_file_missing() {
+    let project = ProjectConfig::from_file(
+        &std::path::PathBuf::from(ROOT).join("c15_file_missing/project.toml"),
+    )
+    .unwrap();
+    insta::assert_snapshot!(lint_project("c15_file_missing/config.hpp", &project));
+}
+
+#[test]
+fn config_error_c24_property_values() {
+    let project = ProjectConfig::from_file(
+        &std::path::PathBuf::from(ROOT).join("c24_property_values/project.toml"),
+    )
+    .unwrap();
+    insta::assert_snapshot!(lint_project("c24_property_values/config.hpp", &project));
+}
+
+#[test]
+fn config_error_c24_property_values_invalid() {
+    let project = ProjectConfig::from_file(
+        &std::path::PathBuf::from(ROOT).join("c24_property_values/project_invalid.toml"),
+    )
+    .unwrap();
+    let codes = hemtt_config::analyze::lint_check(project.lints().config().clone());
+    assert_eq!(
+        codes
+            .iter().map(|code| code.message()).collect::<Vec<_>>(),
+        [
+            "Lint `property_values` option `properties` entry 2 must have one of `values`, `min` and `max`, or `not_empty = true`",
+            "Lint `property_values` option `properties` entry 3 must have a `class`",
+            "Lint `property_values` option `properties` entry 4 `values` must only contain numbers",
+        ]
+    );
+}
+
+#[test]
+fn config_error_c16_rvmat_texture_missing() {
+    insta::assert_snapshot!(lint_path("c16_rvmat_texture_missing/body.rvmat"));
+}
+
+#[test]
+fn config_error_c17_rvmat_shader_unknown() {
+    insta::assert_snapshot!(lint_path("c17_rvmat_shader_unknown.rvmat"));
+}
+
+#[test]
+fn config_error_c18_rvmat_stage_count() {
+    insta::assert_snapshot!(lint_path("c18_rvmat_stage_count.rvmat"));
+}
+
+#[test]
+fn config_error_c20_sound_shader_missing() {
+    let project = ProjectConfig::test_project();
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(
+            &std::path::PathBuf::from(ROOT).join("c20_sound_shader_missing"),
+            LayerType::Source,
+        )
+        .finish(
+            Some(project.clone()),
+            false,
+            &hemtt_common::config::PDriveOption::Disallow,
+        )
+        .unwrap();
+    let mut declared = HashSet::new();
+    let mut references = Vec::new();
+    for addon in ["main", "other"] {
+        let processed = Processor::run(
+            &workspace
+                .join(format!("addons/{addon}/config.cpp"))
+                .unwrap(),
+        )
+        .unwrap();
+        let report = hemtt_config::parse(Some(&project), &processed).unwrap();
+        // shaders are only checked once every config is parsed
+        assert!(report.codes().iter().all(|code| code.ident() != "L-C20"));
+        declared.extend(
+            report
+                .config()
+                .get_sound_shaders()
+                .iter()
+                .map(|shader| shader.to_lowercase()),
+        );
+        references.extend(sound_shader_references(report.config(), &processed));
+    }
+    let usage = SoundShaderUsage::new(Arc::new(declared), references);
+    let workspacefiles = WorkspaceFiles::new();
+    insta::assert_snapshot!(analyze_sound_shaders(&usage, Some(&project))
+        .iter()
+        .map(|e| e.diagnostic().unwrap().to_string(&workspacefiles))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace('\r', ""));
+}
+
+#[test]
+fn config_error_c21_model_skeleton_missing() {
+    insta::assert_snapshot!(lint_path("c21_model_skeleton_missing/model.cfg"));
+}
+
+#[test]
+fn config_error_c22_model_bone_duplicate() {
+    insta::assert_snapshot!(lint_path("c22_model_bone_duplicate/model.cfg"));
+}
+
+#[test]
+fn config_error_c23_model_sections_lowercase() {
+    insta::assert_snapshot!(lint_path("c23_model_sections_lowercase/model.cfg"));
+}
+
+#[test]
+fn config_error_custom() {
+    let project =
+        ProjectConfig::from_file(&std::path::PathBuf::from(ROOT).join("custom/project.toml"))
+            .unwrap();
+    insta::assert_snapshot!(lint_project("custom/config.hpp", &project));
+}
+
+#[test]
+fn config_error_schema() {
+    let project =
+        ProjectConfig::from_file(&std::path::PathBuf::from(ROOT).join("schema/project.toml"))
+            .unwrap();
+    insta::assert_snapshot!(lint_project("schema/config.hpp", &project));
+}
+
+#[test]
+fn config_fix_c01_invalid_value() {
+    let (_, codes) = codes("c01_invalid_value.hpp", &ProjectConfig::test_project());
+    let fixes = codes
+        .iter()
+        .filter_map(|code| code.fix())
+        .map(|fix| (fix.original().to_string(), fix.replacement().to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fixes,
+        vec![
+            ("something".to_string(), "\"something\"".to_string()),
+            ("'test'".to_string(), "\"test\"".to_string()),
+        ]
+    );
+}
 
 fn lint(file: &str) -> String {
+    lint_path(&format!("{file}.hpp"))
+}
+
+fn lint_path(path: &str) -> String {
+    lint_project(path, &ProjectConfig::test_project())
+}
+
+fn lint_project(path: &str, project: &ProjectConfig) -> String {
+    let (workspacefiles, codes) = codes(path, project);
+    codes
+        .iter()
+        .map(|e| e.diagnostic().unwrap().to_string(&workspacefiles))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace('\r', "")
+}
+
+fn codes(path: &str, project: &ProjectConfig) -> (WorkspaceFiles, Codes) {
     let folder = std::path::PathBuf::from(ROOT);
     let workspace = hemtt_workspace::Workspace::builder()
         .physical(&folder, LayerType::Source)
         .finish(
-            Some(ProjectConfig::test_project()),
+            Some(project.clone()),
             false,
             &hemtt_common::config::PDriveOption::Disallow,
         )
         .unwrap();
-    let source = workspace.join(format!("{file}.hpp")).unwrap();
+    let source = workspace.join(path).unwrap();
     let processed = Processor::run(&source).unwrap();
-    let parsed = hemtt_config::parse(Some(&ProjectConfig::test_project()), &processed);
+    let parsed = hemtt_config::parse(Some(project), &processed);
     let workspacefiles = WorkspaceFiles::new();
     match parsed {
-        Ok(config) => config
-            .codes()
-            .iter()
-            .map(|e| e.diagnostic().unwrap().to_string(&workspacefiles))
-            .collect::<Vec<_>>()
-            .join("\n")
-            .replace('\r', ""),
+        Ok(config) => (workspacefiles, config.codes().to_vec()),
         // Errors may occur, but they should be handled, if one is not a handler should be created
         Err(e) => {
             for e in &e {
@@ -0,0 +1,8 @@
+class CfgVehicles {
+    class Car;
+    class MyCar: Car {
+        scope = 1;
+        displayName = "My Car";
+        scope = 2;
+    };
+};
@@ -0,0 +1,15 @@
+class CfgVehicles {
+    class Car;
+    class MyCar: Car {
+        class data {
+            first = 1;
+        };
+        class other {};
+        class data {
+            second = 2;
+        };
+        class DATA {
+            third = 3;
+        };
+    };
+};
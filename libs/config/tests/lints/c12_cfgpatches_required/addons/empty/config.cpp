@@ -0,0 +1,3 @@
+class CfgVehicles {
+    class abe_banana;
+};
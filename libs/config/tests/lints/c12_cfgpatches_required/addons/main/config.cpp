@@ -0,0 +1,12 @@
+class CfgPatches {
+    class abe_main {
+        units[] = {};
+        requiredVersion = 2.18;
+    };
+    class abe_main_compat {
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = 2.18;
+        requiredAddons[] = {"abe_main"};
+    };
+};
@@ -0,0 +1,8 @@
+class CfgPatches {
+    class abe_main {
+        units[] = {};
+        weapons[] = {};
+        requiredVersion = 2.18;
+        requiredAddons[] = {};
+    };
+};
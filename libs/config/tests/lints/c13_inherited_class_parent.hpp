@@ -0,0 +1,25 @@
+class Car;
+class Car_F: Car {
+    class HitPoints {
+        class HitHull;
+        class HitEngine;
+    };
+};
+class MyCar: Car_F {
+    class HitPoints {
+        armor = 1;
+    };
+};
+class MyOtherCar: Car_F {
+    class HitPoints: HitPoints {
+        class HitHull {
+            armor = 2;
+        };
+        class HitEngine: HitEngine {
+            armor = 2;
+        };
+        class HitFuel {
+            armor = 2;
+        };
+    };
+};
@@ -0,0 +1,22 @@
+class CfgFunctions {
+    class ABE {
+        class Main {
+            file = "\c14_cfgfunctions_files\functions";
+            class init {};
+            class respawn {};
+        };
+        class Shared {
+            file = "c14_cfgfunctions_files\functions";
+            class shared {};
+        };
+        class Misc {
+            class handler {
+                file = "\c14_cfgfunctions_files\misc\handler.sqf";
+            };
+            class missing {
+                file = "\c14_cfgfunctions_files\misc\missing.sqf";
+            };
+            class noFile {};
+        };
+    };
+};
@@ -0,0 +1,21 @@
+class CfgWeapons {
+    class abe_rifle {
+        picture = "\c15_file_missing\data\icon_co.paa";
+        uiPicture = "c15_file_missing\data\icon_ca.paa";
+        icon = "\c15_file_missing\data\logo_co.paa";
+        model = "\A3\Weapons_F\Rifles\MX\MX_F.p3d";
+        class Single {
+            sound[] = {"\x\cba\addons\main\click.wss", 1, 1};
+            soundBegin[] = {"\c15_file_missing\sounds\shot.ogg", 1, 1};
+            soundHit[] = {"\c15_file_missing\sounds\hit.ogg", 1, 1};
+        };
+    };
+};
+class CfgSoundShaders {
+    class abe_shot_SoundShader {
+        samples[] = {
+            {"\c15_file_missing\sounds\shot.wss", 1},
+            {"\c15_file_missing\sounds\echo.wss", 1}
+        };
+    };
+};
@@ -0,0 +1,31 @@
+class CfgSoundShaders {
+    class abe_shot_SoundShader {
+        samples[] = {{"\a3\sounds_f\shot.wss", 1}};
+        volume = 100;
+        range = "far";
+    };
+    class abe_engine_SoundShader {
+        samples[] = {{"\a3\sounds_f\engine.wss", 1}};
+        volume = "engineOn*camPos";
+        range = 0;
+    };
+    class abe_good_SoundShader {
+        samples[] = {{"\a3\sounds_f\good.wss", 1}};
+        volume = 1.5;
+        range = 1500;
+    };
+};
+class CfgSounds {
+    class abe_alarm {
+        sound[] = {"\a3\sounds_f\alarm.ogg", "loud", 0};
+    };
+    class abe_beep {
+        sound[] = {"\a3\sounds_f\beep.ogg"};
+    };
+    class abe_siren {
+        sound[] = {"\a3\sounds_f\siren.ogg", "db+10", 1, 200};
+    };
+    class abe_bell {
+        sound[] = {"\a3\sounds_f\bell.ogg", -1, 1, -5};
+    };
+};
@@ -0,0 +1,24 @@
+class CfgSoundShaders {
+    class abe_shot_close_SoundShader {
+        samples[] = {{"\a3\sounds_f\shot.wss", 1}};
+        volume = 1;
+        range = 100;
+    };
+    class abe_shot_tail_SoundShader;
+};
+class CfgSoundSets {
+    class abe_shot_SoundSet {
+        soundShaders[] = {
+            "abe_shot_close_SoundShader",
+            "ABE_Shot_Tail_SoundShader",
+            "abe_other_shot_SoundShader",
+            "abe_shot_far_SoundShader",
+            "abeX_shot_SoundShader",
+            "MX_closeShot_SoundShader"
+        };
+    };
+    class abe_shot_suppressed_SoundSet {
+        #pragma hemtt suppress sound_shader_missing
+        soundShaders[] = {"abe_shot_distant_SoundShader"};
+    };
+};
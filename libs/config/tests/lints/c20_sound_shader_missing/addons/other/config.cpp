@@ -0,0 +1,7 @@
+class CfgSoundShaders {
+    class abe_other_shot_SoundShader {
+        samples[] = {{"\a3\sounds_f\shot.wss", 1}};
+        volume = 1;
+        range = 100;
+    };
+};
@@ -0,0 +1,35 @@
+class CfgVehicles {
+    class Car_F;
+    class abe_car_base: Car_F {
+        scope = 1;
+        displayName = "";
+        side = 1;
+    };
+    class abe_car: abe_car_base {
+        scope = 3;
+        side = 12;
+        displayName = "";
+        faction = "abe_faction";
+        abe_armorLevel = 4;
+    };
+    class abe_truck: abe_car_base {
+        scope = 2;
+        scopeCurator = "2";
+        side = "TWest";
+        displayName = "Banana Truck";
+        faction = "";
+        abe_armorLevel = 2;
+    };
+};
+class CfgWeapons {
+    class Rifle_Base_F;
+    class abe_rifle: Rifle_Base_F {
+        scope = 2;
+        scopeArsenal = 2;
+        displayName = "Banana Rifle";
+        abe_heat = 120;
+        class Single {
+            scope = 5;
+        };
+    };
+};
@@ -0,0 +1,17 @@
+class CfgVehicles {
+    class Car_F;
+    class abe_car: Car_F {
+        class EventHandlers {
+            init = "params ['_vehicle']; if (local _vehicle) then { _vehicle setFuel 0.5; _vehicle lock 2; };";
+            killed = "_this call abe_main_fnc_killed";
+        };
+        displayName = "params ['_vehicle']; if (local _vehicle) then { _vehicle setFuel 0.5; _vehicle lock 2; };";
+    };
+};
+class Extended_Init_EventHandlers {
+    class abe_car {
+        class abe_main {
+            init = "params ['_vehicle']; [_vehicle] call abe_main_fnc_initCar; [_vehicle] call abe_main_fnc_lock;";
+        };
+    };
+};
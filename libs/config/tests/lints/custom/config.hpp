@@ -0,0 +1,14 @@
+class CfgWeapons {
+    class Rifle_Base_F;
+    class abe_rifle: Rifle_Base_F {
+        author = "ABE";
+        magazines[] = {"30Rnd_556x45_Stanag"};
+        debug_name = "rifle";
+    };
+    class abe_pistol: Rifle_Base_F {
+        DEBUG_scope = 2;
+        class Single {
+            reloadTime = 0.1;
+        };
+    };
+};
@@ -0,0 +1,26 @@
+class Car;
+class Car_F: Car {
+    class HitPoints {
+        class HitHull;
+        class HitEngine;
+    };
+};
+class MyCar: Car_F {
+    class HitPoints: HitPoints {
+        // hemtt-ignore L-C13
+        class HitHull {
+            armor = 2;
+        };
+        class HitEngine { // hemtt-ignore L-C13
+            armor = 2;
+        };
+    };
+};
+class MyOtherCar: Car_F {
+    class HitPoints: HitPoints {
+        // hemtt-ignore L-C01
+        class HitHull {
+            armor = 2;
+        };
+    };
+};
@@ -0,0 +1,30 @@
+class Car;
+class Car_F: Car {
+    class HitPoints {
+        class HitHull;
+        class HitEngine;
+        class HitFuel;
+    };
+};
+class MyCar: Car_F {
+    class HitPoints: HitPoints {
+        #pragma hemtt suppress inherited_class_parent
+        class HitHull {
+            armor = 2;
+        };
+        class HitEngine {
+            armor = 2;
+        };
+        #pragma hemtt suppress inherited_class_parent file
+        class HitFuel {
+            armor = 2;
+        };
+    };
+};
+class MyOtherCar: Car_F {
+    class HitPoints: HitPoints {
+        class HitHull {
+            armor = 2;
+        };
+    };
+};
@@ -0,0 +1,4 @@
+class CfgVehicles {
+    #pragma hemtt suppress inherited_class_parnt
+    class Car;
+};
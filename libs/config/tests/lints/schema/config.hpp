@@ -0,0 +1,17 @@
+class CfgVehicles {
+    class abe_car {
+        side = 4;
+        class ACE_Actions {
+            class abe_open {
+                displayName = "Open";
+                distance = 12;
+                icon = "\abe\icon.png";
+                exceptions[] = {};
+            };
+            class abe_close {
+                distance = "far";
+                condition = "true";
+            };
+        };
+    };
+};
@@ -1,8 +1,12 @@
 #![allow(clippy::unwrap_used)]
 
-use std::io::Read;
+use std::io::{Cursor, Read};
 
-use hemtt_config::rapify::Rapify;
+use chumsky::Parser;
+use hemtt_config::{
+    rapify::{Derapify, Rapify},
+    Config,
+};
 use hemtt_preprocessor::Processor;
 use hemtt_workspace::LayerType;
 
@@ -75,4 +79,28 @@ fn rapify(dir: &str) {
         file.read_to_end(&mut expected).unwrap();
         assert_eq!(output, expected);
     };
+
+    // derapify, and rapify again, both directly and through the printed source
+    let derapified = Config::derapify(&mut Cursor::new(&output)).unwrap();
+    let mut rerapified = Vec::new();
+    derapified.rapify(&mut rerapified, 0).unwrap();
+    assert_eq!(rerapified, output);
+    let source = derapified.to_string();
+    let reparsed = hemtt_config::parse::config()
+        .parse(source.as_str())
+        .unwrap();
+    let mut rerapified = Vec::new();
+    reparsed.rapify(&mut rerapified, 0).unwrap();
+    assert_eq!(rerapified, output, "{source}");
+}
+
+#[test]
+fn config_derapify_cyclic() {
+    // a root class containing a class whose body is the root class again
+    let mut data = b"\0raP".to_vec();
+    data.extend([0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0]);
+    data.extend(b"\0\x01\x00a\0");
+    data.extend(16u32.to_le_bytes());
+    let err = Config::derapify(&mut Cursor::new(&data)).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
 }
@@ -0,0 +1,102 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_config::{Config, Property};
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::LayerType;
+
+fn config(file: &str) -> Config {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(
+            &std::path::PathBuf::from("tests/resolve/"),
+            LayerType::Source,
+        )
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let source = workspace.join(file).unwrap();
+    let processed = Processor::run(&source).unwrap();
+    hemtt_config::parse(None, &processed).unwrap().into_config()
+}
+
+#[test]
+fn resolve() {
+    let config = Config::merge([config("base.hpp"), config("config.hpp")]);
+
+    let tank = config.resolve("CfgVehicles/MyTank").unwrap();
+    assert_eq!(tank.name().as_str(), "MyTank");
+    assert_eq!(
+        tank.parents()
+            .iter()
+            .map(hemtt_config::Ident::as_str)
+            .collect::<Vec<_>>(),
+        vec!["Tank", "All"]
+    );
+    assert!(tank.missing().is_none());
+    assert_eq!(
+        tank.to_config().to_string(),
+        r#"class MyTank {
+    scope = 2;
+    displayName = "My Tank";
+    armor = 500;
+    class Turrets {
+        class MainTurret {
+            gunnerName = "Commander";
+            weapons[] = {"Cannon"};
+        };
+    };
+    weapons[] = {"Smoke"};
+};
+"#
+    );
+
+    let turret = config
+        .resolve("cfgvehicles/mytank/turrets/mainturret")
+        .unwrap();
+    assert!(turret.properties().iter().any(|p| matches!(
+        p,
+        Property::Entry { name, .. } if name.as_str() == "weapons"
+    )));
+
+    let lost = config.resolve("CfgVehicles/MyLostTank").unwrap();
+    assert_eq!(lost.missing().unwrap().as_str(), "LostTank");
+
+    assert!(config.resolve("CfgVehicles/Missing").is_none());
+    assert!(config.resolve("CfgVehicles/Tank/Missing").is_none());
+}
+
+#[test]
+fn merge() {
+    let config = Config::merge([
+        config("base.hpp"),
+        config("config.hpp"),
+        config("patch.hpp"),
+    ]);
+
+    let tank = config.resolve("CfgVehicles/Tank").unwrap();
+    assert_eq!(
+        tank.parents()
+            .iter()
+            .map(hemtt_config::Ident::as_str)
+            .collect::<Vec<_>>(),
+        vec!["All"]
+    );
+
+    let my_tank = config.resolve("CfgVehicles/MyTank").unwrap();
+    assert!(my_tank.parents().is_empty());
+    assert_eq!(
+        my_tank.to_config().to_string(),
+        r#"class MyTank {
+    scope = 2;
+    displayName = "My Tank";
+    weapons[] = {"Smoke"};
+    class Turrets {
+        class MainTurret {
+            gunnerName = "Commander";
+        };
+    };
+    armor = 1000;
+};
+"#
+    );
+
+    assert!(config.resolve("CfgVehicles/MyLostTank").is_none());
+}
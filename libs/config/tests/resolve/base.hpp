@@ -0,0 +1,16 @@
+class CfgVehicles {
+    class All {
+        scope = 0;
+        displayName = "All";
+        weapons[] = {"Horn"};
+    };
+    class Tank: All {
+        armor = 500;
+        class Turrets {
+            class MainTurret {
+                gunnerName = "Gunner";
+                weapons[] = {"Cannon"};
+            };
+        };
+    };
+};
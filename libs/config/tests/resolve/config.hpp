@@ -0,0 +1,19 @@
+class CfgVehicles {
+    class Tank;
+    class MyTank: Tank {
+        scope = 2;
+        displayName = "My Tank";
+        weapons[] += {"Smoke"};
+        class Turrets: Turrets {
+            class MainTurret: MainTurret {
+                gunnerName = "Commander";
+            };
+        };
+    };
+    class MyLostTank: LostTank {
+        scope = 2;
+    };
+    class All {
+        delete weapons;
+    };
+};
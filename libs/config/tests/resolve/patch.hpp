@@ -0,0 +1,7 @@
+class CfgVehicles {
+    class Tank;
+    class MyTank {
+        armor = 1000;
+    };
+    delete MyLostTank;
+};
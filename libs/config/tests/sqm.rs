@@ -0,0 +1,38 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_config::{
+    rapify::{is_rapified, Derapify, Rapify},
+    sqm, Config,
+};
+use hemtt_preprocessor::Processor;
+use hemtt_workspace::LayerType;
+
+#[test]
+fn mission() {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&std::path::PathBuf::from("tests/sqm/"), LayerType::Source)
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let source = workspace.join("mission.sqm").unwrap();
+    let processed = Processor::run(&source).unwrap();
+    let config = hemtt_config::parse(None, &processed).unwrap().into_config();
+    assert_eq!(sqm::version(&config), Some(54));
+    assert!(!sqm::binarization_wanted(&config));
+
+    let mut buffer = Vec::new();
+    config.rapify(&mut buffer, 0).unwrap();
+    assert!(is_rapified(&buffer));
+    let derapified = Config::derapify(&mut std::io::Cursor::new(buffer)).unwrap();
+    assert_eq!(sqm::version(&derapified), Some(54));
+    assert_eq!(
+        derapified.to_string(),
+        r#"version = 54;
+binarizationWanted = 0;
+class Mission {
+    class Attributes {
+        init = "hint ""a"";" \n "hint ""b"";";
+    };
+};
+"#
+    );
+}
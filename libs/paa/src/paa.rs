@@ -6,7 +6,7 @@ use std::{
 };
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
-use image::{EncodableLayout, RgbaImage};
+use image::{imageops::FilterType, EncodableLayout, RgbaImage};
 use texpresso::{Format, Params};
 
 use crate::{MipMap, PaXType};
@@ -103,81 +103,153 @@ impl Paa {
         }
     }
 
-    /// Write the Paa to the given output
+    #[must_use]
+    /// The format an image is written as, DXT1 if it is fully opaque, DXT5 otherwise
+    pub fn format_for(image: &RgbaImage) -> PaXType {
+        if image.pixels().all(|p| p.0[3] == 255) {
+            PaXType::DXT1
+        } else {
+            PaXType::DXT5
+        }
+    }
+
+    /// Write an image as a Paa to the given output, in the format from [`Self::format_for`]
     ///
     /// # Errors
-    /// [`std::io::Error`] if the output is not writable
+    /// [`std::io::Error`] if the output is not writable, or the image can not be a Paa
     pub fn write(image: &RgbaImage, output: &mut impl Write) -> Result<(), Error> {
-        let algo: Format = PaXType::DXT5.clone().into();
-        output.write_all(&PaXType::DXT5.as_bytes())?; // 2
+        Self::write_as(image, &Self::format_for(image), output)
+    }
+
+    /// Write an image as a Paa to the given output, with mipmaps down to 4x4
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if the output is not writable, the format is not DXT1 or DXT5,
+    /// or the sides of the image are not powers of two
+    pub fn write_as(
+        image: &RgbaImage,
+        format: &PaXType,
+        output: &mut impl Write,
+    ) -> Result<(), Error> {
+        if !matches!(format, PaXType::DXT1 | PaXType::DXT5) {
+            return Err(Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "only DXT1 and DXT5 can be written",
+            ));
+        }
+        if !image.width().is_power_of_two()
+            || !image.height().is_power_of_two()
+            || image.width() < 4
+            || image.height() < 4
+            || image.width() > 4096
+            || image.height() > 4096
+        {
+            return Err(Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "size must be a power of two between 4 and 4096, found {}x{}",
+                    image.width(),
+                    image.height()
+                ),
+            ));
+        }
+        let algo: Format = format.clone().into();
+
+        let maps = compress_mipmaps(image, algo);
+        if maps[0].2.len() > 0x00FF_FFFF {
+            return Err(Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "image is too large for an uncompressed mipmap",
+            ));
+        }
+
+        output.write_all(&format.as_bytes())?; // 2
+        let mut pos = 2;
 
         // Average Color
-        output.write_all(b"GGATAVGC")?; // 8
+        output.write_all(b"GGATCGVA")?; // 8
         output.write_u32::<LittleEndian>(size_of::<u32>() as u32)?; // 4
-        let avg_color = image
-            .pixels()
-            .map(|p| {
-                [
-                    u32::from(p.0[0]),
-                    u32::from(p.0[1]),
-                    u32::from(p.0[2]),
-                    u32::from(p.0[3]),
-                ]
-            })
-            .fold([0, 0, 0, 0], |mut acc, p| {
-                acc[0] += p[0];
-                acc[1] += p[1];
-                acc[2] += p[2];
-                acc[3] += p[3];
-                acc
-            });
+        let pixels = u64::from(image.width()) * u64::from(image.height());
+        let avg_color = image.pixels().fold([0u64; 4], |mut acc, p| {
+            for (acc, channel) in acc.iter_mut().zip(p.0) {
+                *acc += u64::from(channel);
+            }
+            acc
+        });
         output.write_u32::<LittleEndian>(u32::from_le_bytes([
-            (avg_color[0] / (image.width() * image.height())) as u8,
-            (avg_color[1] / (image.width() * image.height())) as u8,
-            (avg_color[2] / (image.width() * image.height())) as u8,
-            (avg_color[3] / (image.width() * image.height())) as u8,
+            (avg_color[2] / pixels) as u8,
+            (avg_color[1] / pixels) as u8,
+            (avg_color[0] / pixels) as u8,
+            (avg_color[3] / pixels) as u8,
         ]))?; // 4
+        pos += 8 + 4 + 4;
 
         // Max Color
         output.write_all(b"GGATCXAM")?; // 8
         output.write_u32::<LittleEndian>(size_of::<u32>() as u32)?; // 4
-        let max_color = image
-            .pixels()
-            .map(|p| p.0)
-            .fold([0, 0, 0, 0], |mut acc, p| {
-                acc[0] = acc[0].max(p[0]);
-                acc[1] = acc[1].max(p[1]);
-                acc[2] = acc[2].max(p[2]);
-                acc[3] = acc[3].max(p[3]);
-                acc
-            });
-        output.write_u32::<LittleEndian>(u32::from_le_bytes(max_color))?; // 4
+        output.write_u32::<LittleEndian>(u32::MAX)?; // 4
+        pos += 8 + 4 + 4;
+
+        // Alpha Flag, the alpha is interpolated
+        if format == &PaXType::DXT5 {
+            output.write_all(b"GGATGALF")?; // 8
+            output.write_u32::<LittleEndian>(size_of::<u32>() as u32)?; // 4
+            output.write_u32::<LittleEndian>(1)?; // 4
+            pos += 8 + 4 + 4;
+        }
 
         // Offset Table
         output.write_all(b"GGATSFFO")?; // 8
         output.write_u32::<LittleEndian>(16 * size_of::<u32>() as u32)?; // 4
-        let pos = 2 + 8 + 4 + 4 + 8 + 4 + 4 + 8 + 4 + (16 * 4);
-        output.write_u32::<LittleEndian>(pos)?; // 4
-        for _ in 0..15 {
-            // 15 * 4
-            output.write_u32::<LittleEndian>(0)?;
+        pos += 8 + 4 + 16 * 4;
+        for i in 0..16 {
+            if let Some((_, _, buffer)) = maps.get(i) {
+                output.write_u32::<LittleEndian>(pos)?; // 4
+                pos += 2 + 2 + 3 + buffer.len() as u32;
+            } else {
+                output.write_u32::<LittleEndian>(0)?; // 4
+            }
         }
 
-        // Write main image
-        output.write_u16::<LittleEndian>(image.width() as u16)?;
-        output.write_u16::<LittleEndian>(image.height() as u16)?;
-        let size = algo.compressed_size(image.width() as usize, image.height() as usize);
-        output.write_u24::<LittleEndian>(size as u32)?;
+        // Write mipmaps
+        for (width, height, buffer) in &maps {
+            output.write_u16::<LittleEndian>(*width)?;
+            output.write_u16::<LittleEndian>(*height)?;
+            output.write_u24::<LittleEndian>(buffer.len() as u32)?;
+            output.write_all(buffer)?;
+        }
+        // End of mipmaps
+        output.write_u16::<LittleEndian>(0)?;
+        output.write_u16::<LittleEndian>(0)?;
+
+        Ok(())
+    }
+}
+
+/// Compress an image and its mipmaps, each half the size of the previous one,
+/// until the shortest side is 4 pixels
+fn compress_mipmaps(image: &RgbaImage, algo: Format) -> Vec<(u16, u16, Vec<u8>)> {
+    let mut maps = Vec::new();
+    let mut map = image.clone();
+    loop {
+        let size = algo.compressed_size(map.width() as usize, map.height() as usize);
         let mut buffer = vec![0; size];
         algo.compress(
-            image.as_bytes(),
-            image.width() as usize,
-            image.height() as usize,
+            map.as_bytes(),
+            map.width() as usize,
+            map.height() as usize,
             Params::default(),
             &mut buffer,
         );
-        output.write_all(&buffer)?;
-
-        Ok(())
+        maps.push((map.width() as u16, map.height() as u16, buffer));
+        if maps.len() == 16 || map.width() <= 4 || map.height() <= 4 {
+            return maps;
+        }
+        map = image::imageops::resize(
+            &map,
+            map.width() / 2,
+            map.height() / 2,
+            FilterType::Triangle,
+        );
     }
 }
@@ -0,0 +1,47 @@
+#![allow(clippy::unwrap_used)]
+
+use hemtt_paa::{PaXType, Paa};
+use image::{Rgba, RgbaImage};
+
+fn roundtrip(image: &RgbaImage) -> Paa {
+    let mut buffer = Vec::new();
+    Paa::write(image, &mut buffer).unwrap();
+    Paa::read(std::io::Cursor::new(buffer)).unwrap()
+}
+
+#[test]
+fn write_opaque() {
+    let image = RgbaImage::from_pixel(64, 32, Rgba([255, 0, 0, 255]));
+    let paa = roundtrip(&image);
+    assert_eq!(paa.format(), &PaXType::DXT1);
+    assert_eq!(paa.taggs().len(), 3);
+    assert!(!paa.taggs().contains_key(&"GALF".to_string()));
+    assert_eq!(
+        paa.maps()
+            .iter()
+            .map(|map| (map.width(), map.height()))
+            .collect::<Vec<_>>(),
+        vec![(64, 32), (32, 16), (16, 8), (8, 4)]
+    );
+    assert_eq!(
+        paa.maps()[0].get_image().to_rgba8().get_pixel(0, 0),
+        &Rgba([255, 0, 0, 255])
+    );
+}
+
+#[test]
+fn write_transparent() {
+    let mut image = RgbaImage::from_pixel(16, 16, Rgba([0, 0, 255, 255]));
+    image.put_pixel(0, 0, Rgba([0, 0, 255, 0]));
+    let paa = roundtrip(&image);
+    assert_eq!(paa.format(), &PaXType::DXT5);
+    assert_eq!(paa.taggs().len(), 4);
+    assert!(paa.taggs().contains_key(&"GALF".to_string()));
+    assert_eq!(paa.maps().len(), 3);
+}
+
+#[test]
+fn write_not_power_of_two() {
+    let image = RgbaImage::new(48, 64);
+    assert!(Paa::write(&image, &mut Vec::new()).is_err());
+}
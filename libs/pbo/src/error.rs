@@ -14,6 +14,9 @@ pub enum Error {
     #[error("File is too large for PBO format")]
     /// File is too large for PBO format
     FileTooLarge,
+    #[error("Invalid LZSS compressed data")]
+    /// Invalid LZSS compressed data
+    InvalidCompressedData,
     #[error("HEMTT does not support signing PBOs with no files")]
     /// HEMTT does not support signing PBOs with no files
     NoFiles,
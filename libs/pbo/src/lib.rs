@@ -13,15 +13,18 @@ use std::io::{Read, Write};
 
 mod error;
 pub mod file;
+pub mod lzss;
+pub mod mmap;
 mod model;
 mod read;
 pub mod tests;
 mod write;
 
 pub use error::Error;
+pub use mmap::Mmap;
 pub use model::{Checksum, Header, Mime};
 pub use read::ReadablePbo;
-pub use write::WritablePbo;
+pub use write::{stream, ChecksumWriter, WritablePbo};
 
 // Re-exported from common
 pub use hemtt_common::BISignVersion;
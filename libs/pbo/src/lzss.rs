@@ -0,0 +1,173 @@
+//! LZSS compression used by compressed (`Cprs`) PBO entries
+//!
+//! The stream is made of groups of 8 items, each group preceded by a flag byte.
+//! A set bit is a literal byte, an unset bit is a 2 byte pointer
+//! back into the previous 4095 bytes with a length of 3 to 18.
+//! The stream is followed by a 4 byte checksum of the uncompressed data.
+
+use crate::Error;
+
+const WINDOW: usize = 4096;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 18;
+const MAX_CHAIN: usize = 256;
+const HASH_SIZE: usize = 1 << 16;
+const NONE: usize = usize::MAX;
+
+#[must_use]
+/// Compress data, including the trailing checksum
+pub fn compress(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(input.len() / 2 + 8);
+    let mut head = vec![NONE; HASH_SIZE];
+    let mut prev = vec![NONE; input.len()];
+
+    let mut pos = 0;
+    let mut flag_index = 0;
+    let mut flag_bit = 8;
+    while pos < input.len() {
+        if flag_bit == 8 {
+            flag_index = output.len();
+            output.push(0);
+            flag_bit = 0;
+        }
+        let (length, distance) = longest_match(input, pos, &head, &prev);
+        if length >= MIN_MATCH {
+            output.push((distance & 0xFF) as u8);
+            output.push((((distance >> 4) & 0xF0) | (length - MIN_MATCH)) as u8);
+            for i in pos..pos + length {
+                insert(input, i, &mut head, &mut prev);
+            }
+            pos += length;
+        } else {
+            output[flag_index] |= 1 << flag_bit;
+            output.push(input[pos]);
+            insert(input, pos, &mut head, &mut prev);
+            pos += 1;
+        }
+        flag_bit += 1;
+    }
+    output.extend_from_slice(&checksum(input).to_le_bytes());
+    output
+}
+
+/// Decompress data, verifying the trailing checksum
+///
+/// # Errors
+/// [`Error::InvalidCompressedData`] if the data is truncated or the checksum does not match
+pub fn decompress(input: &[u8], size: usize) -> Result<Vec<u8>, Error> {
+    let mut output = Vec::with_capacity(size);
+    let mut input = input.iter().copied();
+    let mut next = || input.next().ok_or(Error::InvalidCompressedData);
+    while output.len() < size {
+        let flags = next()?;
+        for bit in 0..8 {
+            if output.len() >= size {
+                break;
+            }
+            if flags & (1 << bit) != 0 {
+                output.push(next()?);
+                continue;
+            }
+            let low = usize::from(next()?);
+            let high = usize::from(next()?);
+            let distance = low | ((high & 0xF0) << 4);
+            let length = (high & 0x0F) + MIN_MATCH;
+            for _ in 0..length {
+                // pointers before the start of the data refer to spaces
+                let byte = if distance > output.len() {
+                    b' '
+                } else {
+                    output[output.len() - distance]
+                };
+                output.push(byte);
+            }
+        }
+    }
+    output.truncate(size);
+    let expected = u32::from_le_bytes([next()?, next()?, next()?, next()?]);
+    if expected != checksum(&output) {
+        return Err(Error::InvalidCompressedData);
+    }
+    Ok(output)
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.iter()
+        .fold(0u32, |sum, byte| sum.wrapping_add(u32::from(*byte)))
+}
+
+fn hash(data: &[u8]) -> usize {
+    ((usize::from(data[0]) << 8) ^ (usize::from(data[1]) << 4) ^ usize::from(data[2])) % HASH_SIZE
+}
+
+fn insert(input: &[u8], pos: usize, head: &mut [usize], prev: &mut [usize]) {
+    if pos + MIN_MATCH <= input.len() {
+        let hash = hash(&input[pos..]);
+        prev[pos] = head[hash];
+        head[hash] = pos;
+    }
+}
+
+fn longest_match(input: &[u8], pos: usize, head: &[usize], prev: &[usize]) -> (usize, usize) {
+    if pos + MIN_MATCH > input.len() {
+        return (0, 0);
+    }
+    let max = MAX_MATCH.min(input.len() - pos);
+    let mut best = (0, 0);
+    let mut candidate = head[hash(&input[pos..])];
+    let mut chain = 0;
+    while candidate != NONE && pos - candidate < WINDOW && chain < MAX_CHAIN {
+        let length = input[candidate..]
+            .iter()
+            .zip(&input[pos..pos + max])
+            .take_while(|(a, b)| a == b)
+            .count();
+        if length > best.0 {
+            best = (length, pos - candidate);
+            if length == max {
+                break;
+            }
+        }
+        candidate = prev[candidate];
+        chain += 1;
+    }
+    best
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{compress, decompress};
+
+    #[test]
+    fn round_trip() {
+        let data = b"class CfgPatches { class test_main { units[] = {}; weapons[] = {}; }; }; class CfgPatches { class test_other { units[] = {}; }; };".repeat(20);
+        let compressed = compress(&data);
+        assert!(compressed.len() < data.len());
+        assert_eq!(decompress(&compressed, data.len()).unwrap(), data);
+    }
+
+    #[test]
+    fn incompressible() {
+        let data = (0..=255u8).collect::<Vec<_>>();
+        let compressed = compress(&data);
+        assert_eq!(decompress(&compressed, data.len()).unwrap(), data);
+    }
+
+    #[test]
+    fn overlapping() {
+        let data = b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaab".to_vec();
+        let compressed = compress(&data);
+        assert!(compressed.len() < data.len());
+        assert_eq!(decompress(&compressed, data.len()).unwrap(), data);
+    }
+
+    #[test]
+    fn bad_checksum() {
+        let data = b"hello hello hello hello".to_vec();
+        let mut compressed = compress(&data);
+        let last = compressed.len() - 1;
+        compressed[last] ^= 0xFF;
+        assert!(decompress(&compressed, data.len()).is_err());
+    }
+}
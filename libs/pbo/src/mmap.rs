@@ -0,0 +1,128 @@
+//! Read-only memory maps of files, so large PBOs are read without copying them into memory
+
+use std::{fs::File, ptr::NonNull};
+
+/// A read-only memory map of a file
+///
+/// The file must not be modified while it is mapped,
+/// the mapped bytes would change underneath any borrow of them
+pub struct Mmap {
+    ptr: NonNull<u8>,
+    len: usize,
+}
+
+// The mapping is read-only, and is only unmapped when dropped
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl Mmap {
+    /// Map the whole file
+    ///
+    /// # Errors
+    /// if the file can not be mapped
+    pub fn open(file: &File) -> Result<Self, std::io::Error> {
+        let len = usize::try_from(file.metadata()?.len())
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::OutOfMemory))?;
+        if len == 0 {
+            // empty files can not be mapped
+            return Ok(Self {
+                ptr: NonNull::dangling(),
+                len,
+            });
+        }
+        let ptr = sys::map(file, len)?;
+        Ok(Self { ptr, len })
+    }
+}
+
+impl AsRef<[u8]> for Mmap {
+    fn as_ref(&self) -> &[u8] {
+        // the mapping is valid for `len` bytes until it is dropped
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl std::ops::Deref for Mmap {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_ref()
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            sys::unmap(self.ptr, self.len);
+        }
+    }
+}
+
+#[cfg(unix)]
+mod sys {
+    use std::{fs::File, os::unix::io::AsRawFd, ptr::NonNull};
+
+    pub fn map(file: &File, len: usize) -> Result<NonNull<u8>, std::io::Error> {
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(std::io::Error::last_os_error());
+        }
+        NonNull::new(ptr.cast()).ok_or_else(std::io::Error::last_os_error)
+    }
+
+    pub fn unmap(ptr: NonNull<u8>, len: usize) {
+        unsafe {
+            libc::munmap(ptr.as_ptr().cast(), len);
+        }
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::{fs::File, os::windows::io::AsRawHandle, ptr::NonNull};
+
+    use winapi::um::{
+        handleapi::CloseHandle,
+        memoryapi::{CreateFileMappingW, MapViewOfFile, UnmapViewOfFile, FILE_MAP_READ},
+        winnt::PAGE_READONLY,
+    };
+
+    pub fn map(file: &File, _len: usize) -> Result<NonNull<u8>, std::io::Error> {
+        unsafe {
+            let mapping = CreateFileMappingW(
+                file.as_raw_handle().cast(),
+                std::ptr::null_mut(),
+                PAGE_READONLY,
+                0,
+                0,
+                std::ptr::null(),
+            );
+            if mapping.is_null() {
+                return Err(std::io::Error::last_os_error());
+            }
+            let ptr = MapViewOfFile(mapping, FILE_MAP_READ, 0, 0, 0);
+            // the view keeps the mapping open until it is unmapped
+            let error = ptr.is_null().then(std::io::Error::last_os_error);
+            CloseHandle(mapping);
+            match error {
+                Some(error) => Err(error),
+                None => Ok(NonNull::new_unchecked(ptr.cast())),
+            }
+        }
+    }
+
+    pub fn unmap(ptr: NonNull<u8>, _len: usize) {
+        unsafe {
+            UnmapViewOfFile(ptr.as_ptr().cast());
+        }
+    }
+}
@@ -28,6 +28,26 @@ impl Header {
         }
     }
 
+    #[must_use]
+    /// Convert the header of an uncompressed file to one for its compressed data
+    pub(crate) fn into_compressed(self, size: u32) -> Self {
+        Self {
+            mime: Mime::Cprs,
+            original: self.size,
+            size,
+            ..self
+        }
+    }
+
+    #[must_use]
+    /// Remove the timestamp, so the header does not depend on when the file was added
+    pub(crate) fn without_timestamp(self) -> Self {
+        Self {
+            timestamp: 0,
+            ..self
+        }
+    }
+
     #[must_use]
     /// Create a new header for an property
     pub fn property() -> Self {
@@ -8,6 +8,7 @@ use sha1::{Digest, Sha1};
 use crate::{
     error::Error,
     file::File,
+    mmap::Mmap,
     model::{Checksum, Header, Mime},
     BISignVersion, ReadPbo, WritePbo,
 };
@@ -111,15 +112,11 @@ impl<I: Seek + Read> ReadablePbo<I> {
     /// # Errors
     /// if the file cannot be read
     pub fn file(&mut self, name: &str) -> Result<Option<File<I>>, Error> {
-        self.input.seek(SeekFrom::Start(self.blob_start))?;
-        for header in &self.headers {
-            if header.filename().to_lowercase() == name.replace('/', "\\").to_lowercase() {
-                return Ok(Some(File::new(header, &mut self.input)));
-            }
-            self.input
-                .seek(SeekFrom::Current(i64::from(header.size())))?;
-        }
-        Ok(None)
+        let Some((header, offset)) = locate(&self.headers, self.blob_start, name) else {
+            return Ok(None);
+        };
+        self.input.seek(SeekFrom::Start(offset))?;
+        Ok(Some(File::new(header, &mut self.input)))
     }
 
     /// Find the offset of a file
@@ -127,14 +124,7 @@ impl<I: Seek + Read> ReadablePbo<I> {
     /// # Errors
     /// if the file cannot be read
     pub fn file_offset(&self, name: &str) -> Result<Option<u64>, Error> {
-        let mut offset = self.blob_start;
-        for header in &self.headers {
-            if header.filename().to_lowercase() == name.replace('/', "\\").to_lowercase() {
-                return Ok(Some(offset));
-            }
-            offset += header.size() as u64;
-        }
-        Ok(None)
+        Ok(locate(&self.headers, self.blob_start, name).map(|(_, offset)| offset))
     }
 
     /// Check if the files are sorted correctly
@@ -267,3 +257,78 @@ impl<I: Seek + Read> ReadablePbo<I> {
         Ok(hasher.finalize().to_vec().into())
     }
 }
+
+impl ReadablePbo<Cursor<Mmap>> {
+    /// Read a PBO from a memory map of the file, without reading it into memory
+    ///
+    /// The file must not be modified while the PBO is open
+    ///
+    /// # Errors
+    /// if the file can not be mapped, or is not a valid PBO
+    pub fn mmap(file: &std::fs::File) -> Result<Self, Error> {
+        Self::from_bytes(Mmap::open(file)?)
+    }
+}
+
+impl<B: AsRef<[u8]>> ReadablePbo<Cursor<B>> {
+    /// Read a PBO from bytes, such as a memory mapped file
+    ///
+    /// The files can be borrowed from the bytes with [`Self::file_bytes`],
+    /// without copying them
+    ///
+    /// # Errors
+    /// if the bytes are not a valid PBO
+    pub fn from_bytes(bytes: B) -> Result<Self, Error> {
+        Self::from(Cursor::new(bytes))
+    }
+
+    /// Borrow the data of a file, as it is stored in the PBO
+    ///
+    /// # Errors
+    /// if the data of the file is outside of the PBO
+    pub fn file_bytes(&self, name: &str) -> Result<Option<&[u8]>, Error> {
+        let Some((header, offset)) = locate(&self.headers, self.blob_start, name) else {
+            return Ok(None);
+        };
+        let start = usize::try_from(offset).map_err(|_| Error::FileTooLarge)?;
+        self.input
+            .get_ref()
+            .as_ref()
+            .get(start..start + header.size() as usize)
+            .map(Some)
+            .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into())
+    }
+
+    /// Borrow the data of every file, in the order they are stored in the PBO
+    ///
+    /// # Errors
+    /// if the data of a file is outside of the PBO
+    pub fn files_bytes(&self) -> Result<Vec<(&Header, &[u8])>, Error> {
+        let bytes = self.input.get_ref().as_ref();
+        let mut offset = usize::try_from(self.blob_start).map_err(|_| Error::FileTooLarge)?;
+        self.headers
+            .iter()
+            .map(|header| {
+                let end = offset + header.size() as usize;
+                let data = bytes
+                    .get(offset..end)
+                    .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::UnexpectedEof))?;
+                offset = end;
+                Ok((header, data))
+            })
+            .collect()
+    }
+}
+
+/// Find the header of a file, and the offset of its data
+fn locate<'a>(headers: &'a [Header], blob_start: u64, name: &str) -> Option<(&'a Header, u64)> {
+    let name = name.replace('/', "\\").to_lowercase();
+    let mut offset = blob_start;
+    for header in headers {
+        if header.filename().to_lowercase() == name {
+            return Some((header, offset));
+        }
+        offset += header.size() as u64;
+    }
+    None
+}
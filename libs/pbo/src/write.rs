@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     io::{BufWriter, Cursor, Read, Seek, SeekFrom, Write},
 };
 
@@ -7,13 +7,14 @@ use hemtt_common::io::WriteExt;
 use indexmap::IndexMap;
 use sha1::{Digest, Sha1};
 
-use crate::{error::Error, model::Header, Checksum, WritePbo};
+use crate::{error::Error, lzss, model::Header, Checksum, Mime, WritePbo};
 
 #[derive(Default)]
 /// A PBO file that can be written to
 pub struct WritablePbo<I: Seek + Read> {
     properties: IndexMap<String, String>,
     files: HashMap<String, (I, Header)>,
+    compress: HashSet<String>,
 }
 
 impl<I: Seek + Read> WritablePbo<I> {
@@ -23,6 +24,7 @@ impl<I: Seek + Read> WritablePbo<I> {
         Self {
             properties: IndexMap::new(),
             files: HashMap::new(),
+            compress: HashSet::new(),
         }
     }
 
@@ -46,6 +48,22 @@ impl<I: Seek + Read> WritablePbo<I> {
         ))
     }
 
+    /// Add a file to the PBO that will be compressed when written
+    ///
+    /// The file is stored uncompressed if compression does not reduce its size
+    ///
+    /// # Errors
+    /// if the file cannot be read
+    pub fn add_file_compressed<S: Into<String>>(
+        &mut self,
+        name: S,
+        input: I,
+    ) -> Result<Option<(I, Header)>, Error> {
+        let name = name.into().replace('/', "\\");
+        self.compress.insert(name.clone());
+        self.add_file(name, input)
+    }
+
     /// Add a file with a custom header
     ///
     /// # Errors
@@ -122,18 +140,27 @@ impl<I: Seek + Read> WritablePbo<I> {
         &self.properties
     }
 
+    /// Sort the properties and remove the timestamps of all files,
+    /// so the same contents always write the same PBO
+    pub fn normalize(&mut self) {
+        self.properties.sort_keys();
+        for (_, header) in self.files.values_mut() {
+            *header = std::mem::take(header).without_timestamp();
+        }
+    }
+
     /// Write the PBO to a file
     ///
+    /// The contents of files are streamed to the output, compressed files are compressed
+    /// once for the size in their header and again when they are written,
+    /// so only one file is held in memory at a time
+    ///
     /// # Errors
     /// if the file cannot be written
     ///
     /// # Panics
     /// if a file does not exist but a header is present
-    pub fn write<O: Write + Send>(
-        &mut self,
-        output: &mut O,
-        properties: bool,
-    ) -> Result<Checksum, Error> {
+    pub fn write<O: Write>(&mut self, output: &mut O, properties: bool) -> Result<Checksum, Error> {
         let mut headers: Cursor<Vec<u8>> = Cursor::new(Vec::new());
         if properties {
             Header::property().write_pbo(&mut headers)?;
@@ -154,7 +181,13 @@ impl<I: Seek + Read> WritablePbo<I> {
             headers.write_all(&[0])?;
         }
 
-        let files_sorted = self.files_sorted();
+        let mut files_sorted = self.files_sorted();
+
+        for header in &mut files_sorted {
+            if let Some(data) = self.compressed(header)? {
+                *header = header.clone().into_compressed(data.len() as u32);
+            }
+        }
 
         for header in &files_sorted {
             header.write_pbo(&mut headers)?;
@@ -162,43 +195,94 @@ impl<I: Seek + Read> WritablePbo<I> {
 
         Header::default().write_pbo(&mut headers)?;
 
-        let mut hasher = Sha1::new();
-
-        let mut buffered_output = BufWriter::new(output);
-
-        buffered_output.write_all(headers.get_ref())?;
-        hasher.update(headers.get_ref());
+        let mut output = ChecksumWriter::new(BufWriter::new(output));
+        output.write_all(headers.get_ref())?;
 
         for header in &files_sorted {
+            // files added with a compressed header are written as they are
+            if header.mime() == &Mime::Cprs && self.compress.contains(header.filename()) {
+                let data = self
+                    .compressed(header)?
+                    .expect("compressed file should still compress");
+                output.write_all(&data)?;
+                continue;
+            }
             let file = self
                 .file(header.filename())?
                 .expect("file with header should exist");
-            let mut buffer = Vec::with_capacity(header.size() as usize);
-            file.read_to_end(&mut buffer)?;
-
-            if header.size() > 1_000_000 {
-                // pay the paralellization cost for large files
-                std::thread::scope(|s| {
-                    s.spawn(|| {
-                        hasher.update(&*buffer);
-                    });
-                    s.spawn(|| {
-                        buffered_output
-                            .write_all(&buffer)
-                            .expect("failed to write file");
-                    });
-                });
-            } else {
-                buffered_output.write_all(&buffer)?;
-                hasher.update(&buffer);
-            }
+            stream(file, &mut output, u64::from(header.size()))?;
+        }
+
+        let (mut output, checksum) = output.finish();
+        output.write_all(&[0])?;
+        output.write_all(checksum.as_ref())?;
+        output.flush()?;
+
+        Ok(checksum)
+    }
+
+    /// The compressed contents of a file added with [`Self::add_file_compressed`],
+    /// [`None`] if it was not added compressed, or compression does not reduce its size
+    fn compressed(&mut self, header: &Header) -> Result<Option<Vec<u8>>, Error> {
+        if !self.compress.contains(header.filename()) {
+            return Ok(None);
         }
+        let file = self
+            .file(header.filename())?
+            .expect("file with header should exist");
+        let mut buffer = Vec::with_capacity(header.original().max(header.size()) as usize);
+        file.read_to_end(&mut buffer)?;
+        let data = lzss::compress(&buffer);
+        Ok((data.len() < buffer.len()).then_some(data))
+    }
+}
+
+/// Copy exactly `size` bytes from the input to the output, without holding them in memory
+///
+/// Used for the files of a PBO, and the files of release archives
+///
+/// # Errors
+/// if the input is shorter than `size`, or the output can not be written
+pub fn stream<R: Read, W: Write + ?Sized>(
+    input: R,
+    output: &mut W,
+    size: u64,
+) -> Result<(), Error> {
+    if std::io::copy(&mut input.take(size), output)? != size {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+    }
+    Ok(())
+}
 
-        let checksum = hasher.finalize();
-        buffered_output.write_all(&[0])?;
-        buffered_output.write_all(&checksum)?;
-        buffered_output.flush()?;
+/// A writer that hashes everything written to it, for the checksum of a PBO
+pub struct ChecksumWriter<W: Write> {
+    inner: W,
+    hasher: Sha1,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    /// Wrap a writer
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: Sha1::new(),
+        }
+    }
+
+    /// Get the inner writer, and the checksum of everything written
+    pub fn finish(self) -> (W, Checksum) {
+        (self.inner, self.hasher.finalize().to_vec().into())
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
 
-        Ok(checksum.to_vec().into())
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
     }
 }
@@ -4,7 +4,7 @@ use std::{fs::File, io::Read};
 
 use hemtt_pbo::{
     tests::{file, header, pbo},
-    Checksum, Mime, WritablePbo,
+    Checksum, Mime, ReadablePbo, WritablePbo,
 };
 
 use sha1::{Digest, Sha1};
@@ -182,3 +182,61 @@ fn ace_weather_8bd4922f() {
         "#include \"script_component.hpp\"\r\n\r\n#include \"XEH_PREP.hpp\"\r\n",
     );
 }
+
+#[test]
+fn ace_weather_bytes() {
+    let bytes = std::fs::read("tests/ace_weather.pbo_cba6f72c").unwrap();
+    let mut stream =
+        ReadablePbo::from(File::open("tests/ace_weather.pbo_cba6f72c").unwrap()).unwrap();
+    let borrowed = ReadablePbo::from_bytes(bytes.as_slice()).unwrap();
+    assert_eq!(borrowed.properties(), stream.properties());
+    let files = borrowed.files_bytes().unwrap();
+    assert_eq!(files.len(), stream.files().len());
+    for (header, data) in files {
+        let mut expected = Vec::new();
+        stream
+            .file(header.filename())
+            .unwrap()
+            .unwrap()
+            .read_to_end(&mut expected)
+            .unwrap();
+        assert_eq!(data, expected.as_slice());
+        assert_eq!(
+            borrowed.file_bytes(header.filename()).unwrap(),
+            Some(expected.as_slice())
+        );
+    }
+    assert_eq!(borrowed.file_bytes("missing.sqf").unwrap(), None);
+    assert_eq!(
+        borrowed.file_bytes("xeh_prestart.sqf").unwrap(),
+        Some(&b"#include \"script_component.hpp\"\r\n\r\n#include \"XEH_PREP.hpp\"\r\n"[..])
+    );
+}
+
+#[test]
+fn ace_weather_mmap() {
+    let bytes = std::fs::read("tests/ace_weather.pbo_cba6f72c").unwrap();
+    let borrowed = ReadablePbo::from_bytes(bytes.as_slice()).unwrap();
+    let mapped = ReadablePbo::mmap(&File::open("tests/ace_weather.pbo_cba6f72c").unwrap()).unwrap();
+    assert_eq!(mapped.properties(), borrowed.properties());
+    assert_eq!(mapped.checksum(), borrowed.checksum());
+    let files = |pbo: &[(&hemtt_pbo::Header, &[u8])]| {
+        pbo.iter()
+            .map(|(header, data)| (header.filename().to_string(), data.to_vec()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        files(&mapped.files_bytes().unwrap()),
+        files(&borrowed.files_bytes().unwrap())
+    );
+}
+
+#[test]
+fn mmap_empty() {
+    let path = std::env::temp_dir().join(format!("hemtt_pbo_empty_{}", std::process::id()));
+    File::create(&path).unwrap();
+    let file = File::open(&path).unwrap();
+    assert!(hemtt_pbo::Mmap::open(&file).unwrap().is_empty());
+    assert!(ReadablePbo::mmap(&file).is_err());
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,48 @@
+#![allow(clippy::unwrap_used)]
+
+use std::{fs::File, io::Cursor, io::Read};
+
+use hemtt_pbo::{ReadablePbo, WritablePbo};
+
+/// Copy the files and properties of a PBO in the given order, then normalize it
+fn rewrite(reverse: bool) -> Vec<u8> {
+    let mut pbo = ReadablePbo::from(File::open("tests/ace_weather.pbo_cba6f72c").unwrap()).unwrap();
+    let mut files = pbo.files();
+    let mut properties = pbo.properties().clone().into_iter().collect::<Vec<_>>();
+    if reverse {
+        files.reverse();
+        properties.reverse();
+    }
+    let mut new_pbo = WritablePbo::new();
+    for header in files {
+        let mut data = Vec::new();
+        pbo.file(header.filename())
+            .unwrap()
+            .unwrap()
+            .read_to_end(&mut data)
+            .unwrap();
+        new_pbo
+            .add_file_with_header(header, Cursor::new(data))
+            .unwrap();
+    }
+    for (key, value) in properties {
+        new_pbo.add_property(key, value);
+    }
+    new_pbo.normalize();
+    let mut output = Cursor::new(Vec::new());
+    new_pbo.write(&mut output, true).unwrap();
+    output.into_inner()
+}
+
+#[test]
+fn normalize() {
+    let output = rewrite(false);
+    assert_eq!(output, rewrite(true));
+
+    let pbo = ReadablePbo::from(Cursor::new(output)).unwrap();
+    assert_eq!(pbo.files().len(), 41);
+    assert!(pbo.files().iter().all(|header| header.timestamp() == 0));
+    let mut keys = pbo.properties().keys().cloned().collect::<Vec<_>>();
+    assert_eq!(keys.remove(0), "prefix");
+    assert!(keys.is_sorted());
+}
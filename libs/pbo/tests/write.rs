@@ -0,0 +1,51 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::Cursor;
+
+use hemtt_pbo::{lzss, Mime, ReadablePbo, WritablePbo};
+
+#[test]
+fn write_compressed() {
+    let text = b"class CfgPatches {};\n".repeat(64);
+    // every byte is different, so compression does not reduce the size
+    let noise = (0..=255).collect::<Vec<u8>>();
+    let mut pbo = WritablePbo::new();
+    pbo.add_file_compressed("config.cpp", Cursor::new(text.clone()))
+        .unwrap();
+    pbo.add_file_compressed("noise.bin", Cursor::new(noise.clone()))
+        .unwrap();
+    pbo.add_file("plain.txt", Cursor::new(b"plain".to_vec()))
+        .unwrap();
+    let mut output = Vec::new();
+    pbo.write(&mut output, true).unwrap();
+
+    let mut read = ReadablePbo::from(Cursor::new(output)).unwrap();
+    let headers = read.files_sorted();
+    assert_eq!(headers[0].mime(), &Mime::Cprs);
+    assert_eq!(headers[0].original() as usize, text.len());
+    assert_eq!(headers[1].mime(), &Mime::Blank);
+    assert_eq!(headers[1].size() as usize, noise.len());
+    assert_eq!(headers[2].mime(), &Mime::Blank);
+
+    let read_file = |read: &mut ReadablePbo<_>, name: &str| {
+        let mut data = Vec::new();
+        std::io::Read::read_to_end(&mut read.file(name).unwrap().unwrap(), &mut data).unwrap();
+        data
+    };
+    let compressed = read_file(&mut read, "config.cpp");
+    assert_eq!(
+        lzss::decompress(&compressed, headers[0].original() as usize).unwrap(),
+        text
+    );
+    assert_eq!(read_file(&mut read, "noise.bin"), noise);
+    assert_eq!(read_file(&mut read, "plain.txt"), b"plain");
+
+    // a file copied with its compressed header is written as it is
+    let mut copy = WritablePbo::new();
+    copy.add_file_with_header(headers[0].clone(), Cursor::new(compressed.clone()))
+        .unwrap();
+    let mut output = Vec::new();
+    copy.write(&mut output, true).unwrap();
+    let mut read = ReadablePbo::from(Cursor::new(output)).unwrap();
+    assert_eq!(read_file(&mut read, "config.cpp"), compressed);
+}
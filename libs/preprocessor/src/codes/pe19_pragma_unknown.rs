@@ -5,6 +5,9 @@ use hemtt_workspace::reporting::{Code, Token};
 
 use crate::Error;
 
+/// The commands of `#pragma hemtt`
+const COMMANDS: &[&str] = &["suppress", "flag", "flag_as_entry"];
+
 #[allow(unused)]
 /// An unknown `#pragma` directive
 ///
@@ -34,7 +37,7 @@ impl Code for PragmaUnknown {
     }
 
     fn help(&self) -> Option<String> {
-        let similar = similar_values(self.token.to_string().as_str(), &["suppress"]);
+        let similar = similar_values(self.token.to_string().as_str(), COMMANDS);
         if similar.is_empty() {
             None
         } else {
@@ -50,7 +53,7 @@ impl Code for PragmaUnknown {
     }
 
     fn suggestion(&self) -> Option<String> {
-        let similar = similar_values(self.token.to_string().as_str(), &["suppress"]);
+        let similar = similar_values(self.token.to_string().as_str(), COMMANDS);
         if similar.is_empty() {
             None
         } else {
@@ -1,12 +1,15 @@
 use std::sync::Arc;
 
 use hemtt_common::similar_values;
-use hemtt_workspace::reporting::{Code, Token};
+use hemtt_workspace::{
+    lint::lint_names,
+    reporting::{Code, Token},
+};
 
 use crate::{processor::pragma::Suppress, Error};
 
 #[allow(unused)]
-/// An unknown `#pragma hemtt suppress` code
+/// An unknown `#pragma hemtt suppress` code, neither a preprocessor warning nor the name of a lint
 ///
 /// ```cpp
 /// #pragma hemtt suppress unknown
@@ -34,7 +37,9 @@ impl Code for PragmaInvalidSuppress {
     }
 
     fn help(&self) -> Option<String> {
-        let similar = similar_values(self.token.to_string().as_str(), Suppress::as_slice());
+        let mut names = Suppress::as_slice().to_vec();
+        names.extend(lint_names());
+        let similar = similar_values(self.token.to_string().as_str(), &names);
         if similar.is_empty() {
             None
         } else {
@@ -5,10 +5,10 @@ use hemtt_workspace::reporting::{Code, Token};
 use crate::Error;
 
 #[allow(unused)]
-/// Use of `#if __has_include`
+/// Invalid use of `#if __has_include`
 ///
 /// ```cpp
-/// #pragma hemtt flag unknown
+/// #if __has_include(file.hpp)
 /// ```
 pub struct IfHasInclude {
     /// The [`Token`] of the code
@@ -25,15 +25,15 @@ impl Code for IfHasInclude {
     }
 
     fn message(&self) -> String {
-        "use of `__has_include`".to_string()
+        "invalid use of `__has_include`".to_string()
     }
 
     fn note(&self) -> Option<String> {
-        Some("use of `#if __has_include` will prevent HEMTT from rapifying the file".to_string())
+        Some("`__has_include` expects a path wrapped in quotes or angle brackets, like `__has_include(\"file.hpp\")`".to_string())
     }
 
     fn help(&self) -> Option<String> {
-        Some(String::from("use `#pragma hemtt flag pe23_ignore_has_include`\nto have HEMTT act as if the include was not found.\nThis will still prevent HEMTT from rapifying the file\nbut will allow the false branch to be validated."))
+        Some(String::from("use `#pragma hemtt flag pe23_ignore_has_include`\nto have HEMTT act as if the include was not found.\nThis will prevent HEMTT from rapifying the file,\nleaving `__has_include` to be checked by the game."))
     }
 }
 
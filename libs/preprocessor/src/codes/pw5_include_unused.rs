@@ -0,0 +1,59 @@
+use hemtt_workspace::{
+    position::Position,
+    reporting::{Code, Diagnostic, Label, Severity},
+    WorkspacePath,
+};
+
+/// An included file that did not add to the output
+pub struct IncludeUnused {
+    /// The position of the path in the `#include`
+    position: Position,
+    /// The file that was included
+    file: WorkspacePath,
+}
+
+impl Code for IncludeUnused {
+    fn ident(&self) -> &'static str {
+        Self::IDENT
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Help
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` is included but not used", self.file)
+    }
+
+    fn label_message(&self) -> String {
+        "unused include".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(
+            "no tokens or macros of the file, or the files it includes, are used\nthe file is rebuilt every time the include changes"
+                .to_string(),
+        )
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        let mut diag = Diagnostic::new(self.ident(), self.message()).set_severity(self.severity());
+        diag = diag.with_label(
+            Label::primary(self.position.path().clone(), self.position.span())
+                .with_message(self.label_message()),
+        );
+        if let Some(note) = self.note() {
+            diag = diag.with_note(note);
+        }
+        Some(diag)
+    }
+}
+
+impl IncludeUnused {
+    pub const IDENT: &'static str = "PW5";
+
+    #[must_use]
+    pub const fn new(position: Position, file: WorkspacePath) -> Self {
+        Self { position, file }
+    }
+}
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use hemtt_workspace::{
-    position::Position,
+    position::{LineCol, Position},
     reporting::{Symbol, Token},
     WorkspacePath,
 };
@@ -40,7 +40,7 @@ const BUILTIN_GEN: [&str; 6] = [
 ];
 
 /// Built-in macros that HEMTT supports, special cases
-const BUILTIN_SPECIAL: [&str; 1] = ["__EVAL"];
+const BUILTIN_SPECIAL: [&str; 2] = ["__EVAL", "__has_include"];
 
 /// Built-in macros that HEMTT intentionally does not support
 const BUILTIN_PROTEST: [&str; 18] = [
@@ -76,6 +76,76 @@ impl Defines {
         BUILTIN_PROTEST.contains(&key)
     }
 
+    /// Define the `VERSION_*` macros, if enabled with `defines` under `[version]` of the project
+    pub fn insert_version(&mut self, path: &WorkspacePath) {
+        let Some(project) = path.workspace().project() else {
+            return;
+        };
+        if !project.version().defines() {
+            return;
+        }
+        let Ok(version) = project.version().get(&path.vfs().root()) else {
+            return;
+        };
+        let position = Position::new(LineCol(0, (1, 0)), LineCol(0, (1, 0)), path.clone());
+        let build = version.build().unwrap_or_default();
+        let mut values = vec![
+            ("VERSION_MAJOR", Symbol::Digit(version.major() as usize)),
+            ("VERSION_MINOR", Symbol::Digit(version.minor() as usize)),
+            ("VERSION_PATCH", Symbol::Digit(version.patch() as usize)),
+            ("VERSION_BUILD", Symbol::Digit(build as usize)),
+            (
+                "VERSION_STR",
+                Symbol::Word(format!(
+                    "{}.{}.{}.{build}",
+                    version.major(),
+                    version.minor(),
+                    version.patch()
+                )),
+            ),
+        ];
+        if let Some(hash) = version.hash() {
+            values.push(("VERSION_GIT_HASH", Symbol::Word(hash.to_string())));
+        }
+        for (key, symbol) in values {
+            self.global.insert(
+                Arc::from(key),
+                (
+                    Arc::new(Token::new(Symbol::Word(key.to_string()), position.clone())),
+                    Definition::Value(vec![Arc::new(Token::new(symbol, position.clone()))]),
+                    DefineSource::Generated,
+                ),
+            );
+        }
+    }
+
+    /// Define the macros of the command being run, such as `defines` under `[hemtt.dev]` of the project
+    ///
+    /// A macro without a value is defined as `1`, `NAME=` defines it as empty
+    pub fn insert_workspace(&mut self, path: &WorkspacePath) {
+        let position = Position::new(LineCol(0, (1, 0)), LineCol(0, (1, 0)), path.clone());
+        for define in path.workspace().defines() {
+            let (key, value) = define.split_once('=').unwrap_or((define, "1"));
+            let (key, value) = (key.trim(), value.trim());
+            let value = if value.is_empty() {
+                Vec::new()
+            } else {
+                let symbol = value
+                    .parse::<usize>()
+                    .map_or_else(|_| Symbol::Word(value.to_string()), Symbol::Digit);
+                vec![Arc::new(Token::new(symbol, position.clone()))]
+            };
+            self.global.insert(
+                Arc::from(key),
+                (
+                    Arc::new(Token::new(Symbol::Word(key.to_string()), position.clone())),
+                    Definition::Value(value),
+                    DefineSource::Generated,
+                ),
+            );
+        }
+    }
+
     pub fn contains_key(&self, key: &str) -> bool {
         if BUILTIN_GEN.contains(&key) {
             return true;
@@ -207,6 +207,7 @@ impl Processor {
             buffer.push(Output::Direct(ident));
             return Ok(());
         };
+        self.used_files.insert(source.position().path().clone());
         match body {
             Definition::Function(function) => {
                 let Some(args) = self.call_read_args(callsite, pragma, stream)? else {
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use hemtt_workspace::{
+    lint::is_lint,
     path::LocateResult,
     position::Position,
-    reporting::{Output, Symbol, Token},
+    reporting::{Output, Probe, SuppressedLint, Symbol, Token},
 };
 use peekmore::{PeekMore, PeekMoreIterator};
 use tracing::debug;
@@ -14,7 +15,8 @@ use crate::{
         pe14_include_unexpected_suffix::IncludeUnexpectedSuffix,
         pe15_if_invalid_operator::IfInvalidOperator,
         pe16_if_incompatible_types::IfIncompatibleType, pe19_pragma_unknown::PragmaUnknown,
-        pe20_pragma_invalid_scope::PragmaInvalidScope, pe23_if_has_include::IfHasInclude,
+        pe20_pragma_invalid_scope::PragmaInvalidScope,
+        pe21_pragma_invalid_suppress::PragmaInvalidSuppress, pe23_if_has_include::IfHasInclude,
         pe2_unexpected_eof::UnexpectedEOF, pe3_expected_ident::ExpectedIdent,
         pe4_unknown_directive::UnknownDirective, pe6_change_builtin::ChangeBuiltin,
         pe7_if_unit_or_function::IfUnitOrFunction, pe8_if_undefined::IfUndefined,
@@ -23,12 +25,12 @@ use crate::{
     defines::{DefineSource, Defines},
     definition::{Definition, FunctionDefinition},
     ifstate::IfState,
-    processor::pragma::Flag,
+    processor::{pragma::Flag, PendingInclude},
     Error,
 };
 
 use super::{
-    pragma::{Pragma, Scope},
+    pragma::{Pragma, Scope, Suppress},
     Processor,
 };
 
@@ -104,12 +106,23 @@ impl Processor {
                 match command.to_string().as_str() {
                     "suppress" => {
                         let (code, scope) = self.read_pragma(&command, pragma, stream)?;
-                        pragma.suppress(&code, scope)?;
+                        let name = code.symbol().to_string();
+                        if Suppress::is_preprocessor(&name) {
+                            pragma.suppress(&code, scope)?;
+                        } else if is_lint(&name) {
+                            self.suppress_lint(&code, scope);
+                        } else {
+                            return Err(PragmaInvalidSuppress::code(code.as_ref().clone()));
+                        }
                     }
                     "flag" => {
                         let (code, scope) = self.read_pragma(&command, pragma, stream)?;
                         pragma.flag(&code, scope)?;
                     }
+                    "flag_as_entry" => {
+                        Self::expect_nothing_to_newline(stream)?;
+                        self.entry = true;
+                    }
                     _ => return Err(PragmaUnknown::code(command.as_ref().clone())),
                 }
                 Ok(())
@@ -149,6 +162,18 @@ impl Processor {
         Ok((code, scope))
     }
 
+    /// Suppress a lint from the line after the pragma, lints are checked after preprocessing
+    fn suppress_lint(&mut self, code: &Arc<Token>, scope: Scope) {
+        let line = code.position().start().line();
+        let (file, lines) = match scope {
+            Scope::Line => (Some(code.position().path().clone()), line + 1..line + 2),
+            Scope::File => (Some(code.position().path().clone()), line + 1..usize::MAX),
+            Scope::Config => (None, 0..usize::MAX),
+        };
+        self.suppressed_lints
+            .push(SuppressedLint::new(code.symbol().to_string(), file, lines));
+    }
+
     #[allow(clippy::needless_pass_by_ref_mut)]
     pub(crate) fn directive_include(
         &mut self,
@@ -203,6 +228,14 @@ impl Processor {
             .file_stack
             .last()
             .expect("root file should always be present");
+        let position = match (path.first(), path.last()) {
+            (Some(first), Some(last)) => Position::new(
+                *first.position().start(),
+                *last.position().end(),
+                current.clone(),
+            ),
+            _ => open.position().clone(),
+        };
         let path = {
             let Ok(Some(LocateResult {
                 path: found_path,
@@ -225,6 +258,12 @@ impl Processor {
             found_path
         };
         let tokens = crate::parse::file(&path)?;
+        self.pending_includes.push(PendingInclude {
+            parent: current.clone(),
+            file: path.clone(),
+            position,
+            suppressed: pragma.is_suppressed(&Suppress::Pw5IncludeUnused),
+        });
         self.file_stack.push(path.clone());
         self.included_files.push(path);
         let mut stream = tokens.into_iter().peekmore();
@@ -374,12 +413,10 @@ impl Processor {
                 self.skip_to_after_newline(stream, None);
                 return Ok(());
             }
-            return Err(IfHasInclude::code(
-                left.first()
-                    .expect("left is not empty, must exist")
-                    .as_ref()
-                    .clone(),
-            ));
+            let found = self.has_include(&left)?;
+            self.ifstates.push_if(command, found);
+            Self::expect_nothing_to_newline(stream)?;
+            return Ok(());
         }
         let (left, left_defined) = if left.len() == 1 {
             resolve_value(
@@ -435,6 +472,11 @@ impl Processor {
                 (right, false)
             }
         };
+        for (value, defined) in [(&left, left_defined), (&right, right_defined)] {
+            if let Some(token) = value.first().filter(|_| defined) {
+                self.used_files.insert(token.position().path().clone());
+            }
+        }
         let operator = operators
             .iter()
             .map(std::string::ToString::to_string)
@@ -484,6 +526,39 @@ impl Processor {
         Ok(())
     }
 
+    /// Checks if the file in `__has_include("file.hpp")` can be found,
+    /// using the same lookup as `#include`
+    fn has_include(&mut self, tokens: &[Arc<Token>]) -> Result<bool, Error> {
+        let invalid = || IfHasInclude::code(tokens[0].as_ref().clone());
+        let [_, open, path @ .., close] = tokens else {
+            return Err(invalid());
+        };
+        if open.symbol() != &Symbol::LeftParenthesis || close.symbol() != &Symbol::RightParenthesis
+        {
+            return Err(invalid());
+        }
+        let [open, path @ .., close] = path else {
+            return Err(invalid());
+        };
+        if !open.symbol().is_include_enclosure()
+            || open.symbol().matching_enclosure().as_ref() != Some(close.symbol())
+            || path.is_empty()
+        {
+            return Err(invalid());
+        }
+        let current = self
+            .file_stack
+            .last()
+            .expect("root file should always be present");
+        let path = path
+            .iter()
+            .map(std::string::ToString::to_string)
+            .collect::<String>();
+        let found = matches!(current.locate(&path), Ok(Some(_)));
+        self.probes.push(Probe::new(current.clone(), path, found));
+        Ok(found)
+    }
+
     pub(crate) fn directive_ifdef(
         &mut self,
         command: Arc<Token>,
@@ -495,6 +570,9 @@ impl Processor {
             return Err(ExpectedIdent::code(ident.as_ref().clone()));
         }
         let ident_string = ident.symbol().to_string();
+        if let Some((source, _, _)) = self.defines.get_readonly(&ident_string) {
+            self.used_files.insert(source.position().path().clone());
+        }
         self.ifstates
             .push_if(command, self.defines.contains_key(&ident_string) == outcome);
         Self::expect_nothing_to_newline(stream)
@@ -1,15 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use hemtt_workspace::{
     position::Position,
-    reporting::{Codes, Output, Processed, Symbol, Token},
+    reporting::{Codes, Include, Output, Probe, Processed, SuppressedLint, Symbol, Token},
     WorkspacePath,
 };
 use peekmore::{PeekMore, PeekMoreIterator};
 
 use crate::codes::pe3_expected_ident::ExpectedIdent;
 use crate::codes::pw2_invalid_config_case::InvalidConfigCase;
+use crate::codes::pw5_include_unused::IncludeUnused;
 use crate::codes::{pe18_eoi_ifstate::EoiIfState, pe25_exec::ExecNotSupported};
 use crate::codes::{
     pe26_unsupported_builtin::BuiltInNotSupported, pe2_unexpected_eof::UnexpectedEOF,
@@ -25,6 +26,15 @@ mod directives;
 pub mod pragma;
 mod whitespace;
 
+/// An `#include`, before it is known if it was used
+pub struct PendingInclude {
+    pub(crate) parent: WorkspacePath,
+    pub(crate) file: WorkspacePath,
+    pub(crate) position: Position,
+    /// `pw5_include_unused` was suppressed for the include
+    pub(crate) suppressed: bool,
+}
+
 #[derive(Default)]
 /// Arma 3 Preprocessor
 pub struct Processor {
@@ -34,6 +44,11 @@ pub struct Processor {
     included_files: Vec<WorkspacePath>,
     file_stack: Vec<WorkspacePath>,
 
+    /// Each `#include`, in the order they were processed
+    pub(crate) pending_includes: Vec<PendingInclude>,
+    /// Files with macros that were used, which may not add tokens to the output
+    pub(crate) used_files: HashSet<WorkspacePath>,
+
     pub(crate) token_count: usize,
 
     macros: HashMap<String, Vec<Position>>,
@@ -53,6 +68,15 @@ pub struct Processor {
 
     /// The preprocessor was able to run checks, but the output should not be rapified
     pub(crate) no_rapify: bool,
+
+    /// Lints suppressed with `#pragma hemtt suppress`, checked after preprocessing
+    pub(crate) suppressed_lints: Vec<SuppressedLint>,
+
+    /// The file was flagged with `#pragma hemtt flag_as_entry`
+    pub(crate) entry: bool,
+
+    /// Files looked for with `__has_include`
+    pub(crate) probes: Vec<Probe>,
 }
 
 impl Processor {
@@ -68,6 +92,8 @@ impl Processor {
     /// See [`Error`]
     pub fn run(path: &WorkspacePath) -> Result<Processed, (Vec<WorkspacePath>, Error)> {
         let mut processor = Self::default();
+        processor.defines.insert_version(path);
+        processor.defines.insert_workspace(path);
 
         processor.file_stack.push(path.clone());
 
@@ -87,6 +113,14 @@ impl Processor {
             ));
         }
 
+        let includes = processor.resolve_includes(&buffer);
+        for include in includes.iter().filter(|include| include.unused_in(path)) {
+            processor.warnings.push(Arc::new(IncludeUnused::new(
+                include.position().clone(),
+                include.file().clone(),
+            )));
+        }
+
         if path.filename() == "Config.cpp" {
             processor
                 .warnings
@@ -100,10 +134,77 @@ impl Processor {
             processor.usage,
             processor.warnings,
             processor.no_rapify,
+            processor.included_files.clone(),
+            includes,
         )
+        .map(|processed| {
+            processed
+                .with_suppressed_lints(processor.suppressed_lints)
+                .with_entry(processor.entry)
+                .with_probes(processor.probes)
+        })
         .map_err(|e| (processor.included_files, e.into()))
     }
 
+    /// Resolve which includes were used, from the files that added tokens to the output
+    fn resolve_includes(&self, buffer: &[Output]) -> Vec<Include> {
+        fn contributed(output: &[Output], files: &mut HashSet<WorkspacePath>) {
+            for output in output {
+                match output {
+                    Output::Direct(token) => {
+                        if !token.symbol().is_whitespace()
+                            && !token.symbol().is_newline()
+                            && !token.symbol().is_eoi()
+                        {
+                            files.insert(token.position().path().clone());
+                        }
+                    }
+                    Output::Macro(root, output) => {
+                        files.insert(root.position().path().clone());
+                        contributed(output, files);
+                    }
+                }
+            }
+        }
+        fn used(
+            file: &WorkspacePath,
+            includes: &[PendingInclude],
+            files: &HashSet<WorkspacePath>,
+            visited: &mut Vec<WorkspacePath>,
+        ) -> bool {
+            if files.contains(file) {
+                return true;
+            }
+            if visited.contains(file) {
+                return false;
+            }
+            visited.push(file.clone());
+            includes
+                .iter()
+                .filter(|include| &include.parent == file)
+                .any(|include| used(&include.file, includes, files, visited))
+        }
+        let mut files = self.used_files.clone();
+        contributed(buffer, &mut files);
+        self.pending_includes
+            .iter()
+            .map(|include| {
+                Include::new(
+                    include.parent.clone(),
+                    include.file.clone(),
+                    include.position.clone(),
+                    used(
+                        &include.file,
+                        &self.pending_includes,
+                        &files,
+                        &mut Vec::new(),
+                    ),
+                    include.suppressed,
+                )
+            })
+            .collect()
+    }
+
     fn file(
         &mut self,
         pragma: &mut Pragma,
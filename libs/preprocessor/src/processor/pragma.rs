@@ -138,11 +138,19 @@ impl TryFrom<&str> for Flag {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Suppress {
     Pw3PaddedArg,
+    Pw5IncludeUnused,
 }
 
 impl Suppress {
     pub const fn as_slice() -> &'static [&'static str] {
-        &["pw3_padded_arg"]
+        &["pw3_padded_arg", "pw5_include_unused"]
+    }
+
+    /// Is the code for the preprocessor, such as `pw3_padded_arg`, instead of the ident of a lint
+    pub fn is_preprocessor(code: &str) -> bool {
+        code.strip_prefix("pw")
+            .or_else(|| code.strip_prefix("pe"))
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
     }
 }
 
@@ -152,6 +160,7 @@ impl TryFrom<&str> for Suppress {
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value {
             "pw3_padded_arg" => Ok(Self::Pw3PaddedArg),
+            "pw5_include_unused" => Ok(Self::Pw5IncludeUnused),
             _ => Err(()),
         }
     }
@@ -18,8 +18,13 @@ macro_rules! bootstrap {
 
 fn check(dir: &str) {
     let folder = std::path::PathBuf::from(ROOT).join(dir);
+    // the defines of a command, one per line
+    let defines = std::fs::read_to_string(folder.join("defines.txt"))
+        .map(|defines| defines.lines().map(str::to_string).collect::<Vec<_>>())
+        .unwrap_or_default();
     let workspace = hemtt_workspace::Workspace::builder()
         .physical(&folder, LayerType::Source)
+        .defines(&defines)
         .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
         .unwrap();
     let source = workspace.join("source.hpp").unwrap();
@@ -62,11 +67,13 @@ bootstrap!(define_nested);
 bootstrap!(define_single);
 bootstrap!(define_undef);
 bootstrap!(define_use_define);
+bootstrap!(define_workspace);
 bootstrap!(define_variable_recursion);
 bootstrap!(define_with_dash);
 bootstrap!(group_unit);
 bootstrap!(hashtag_outside_macro);
 bootstrap!(if_digits);
+bootstrap!(if_has_include);
 bootstrap!(if_nested);
 bootstrap!(if_operators);
 bootstrap!(if_pass);
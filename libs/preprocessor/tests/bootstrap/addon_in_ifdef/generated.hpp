@@ -0,0 +1,5 @@
+
+
+hello_world = "hello_world";
+class RscDiary { 
+};
@@ -0,0 +1,2 @@
+
+if (serverCommandAvailable '#kick') exitWith {};
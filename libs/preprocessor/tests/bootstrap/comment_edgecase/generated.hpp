@@ -0,0 +1,2 @@
+
+if (!isNil "test_magnification") exitWith {0.25/test_magnification};
@@ -0,0 +1,12 @@
+url1 = 'http://www.zombo.com';
+url2 = "http://www.zombo.com";
+
+"'//'
+'// a
+""'//""
+"
+
+'"//"
+"// c
+''"//''
+'
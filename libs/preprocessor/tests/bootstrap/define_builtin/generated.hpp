@@ -0,0 +1,10 @@
+0 
+1 
+2
+3 
+"\source.hpp"
+
+7
+0 
+1 
+2 
@@ -0,0 +1,3 @@
+
+
+value = "Hello John";
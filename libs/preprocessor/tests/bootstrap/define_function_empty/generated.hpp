@@ -0,0 +1,2 @@
+systemChat "hello world"
+
@@ -0,0 +1,4 @@
+class _xx_item { 
+    name = "item"; 
+    count = 0; 
+};
@@ -0,0 +1,4 @@
+
+	path = 1;
+	
+value = QUOTE(hello - Hello);
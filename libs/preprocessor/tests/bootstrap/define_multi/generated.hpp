@@ -0,0 +1,3 @@
+
+alpha = 1;
+bravo = 2;
@@ -0,0 +1,6 @@
+
+
+value = "Hello Brett";
+
+
+value = "Hello Brett";
@@ -0,0 +1,4 @@
+
+var2_var1
+var1_var2
+var1_1
@@ -0,0 +1,7 @@
+
+    
+"ACE3 - test"
+
+
+    
+"ACE3 - Test"
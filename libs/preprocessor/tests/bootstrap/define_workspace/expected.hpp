@@ -0,0 +1,5 @@
+debug = 1;
+release = 0;
+level = 2;
+empty = ;
+value = 1;
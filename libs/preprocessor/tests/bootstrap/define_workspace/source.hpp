@@ -0,0 +1,11 @@
+#ifdef DEBUG
+debug = 1;
+#endif
+#ifndef RELEASE
+release = 0;
+#endif
+#if LOG_LEVEL > 1
+level = LOG_LEVEL;
+#endif
+empty = EMPTY;
+value = DEBUG;
@@ -0,0 +1,25 @@
+
+
+
+
+    class unit0 { 
+        vehicle = "B_Soldier_F"; 
+        side = WEST; 
+                rank = "SERGEANT"; 
+                position[] = { 
+            "5 * floor ((0 + 1) / 2) * (-1 ^ (0 + 1))", 
+            "-5 * floor ((0 + 1) / 2)", 
+            0 
+        }; 
+    };
+
+    class unit1 { 
+        vehicle = "B_Soldier_F"; 
+        side = WEST; 
+                                                rank = "PRIVATE"; 
+                                                position[] = { 
+            "5 * floor ((1 + 1) / 2) * (-1 ^ (1 + 1))", 
+            "-5 * floor ((1 + 1) / 2)", 
+            0 
+        }; 
+    };
@@ -0,0 +1,4 @@
+
+val = false;
+
+val = false;
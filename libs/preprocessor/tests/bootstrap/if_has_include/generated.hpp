@@ -0,0 +1,3 @@
+compat = 1;
+
+missing = 0;
@@ -0,0 +1 @@
+compat = 1;
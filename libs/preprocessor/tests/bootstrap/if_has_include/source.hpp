@@ -0,0 +1,11 @@
+#if __has_include("include\compat.hpp")
+#include "include\compat.hpp"
+#else
+compat = 0;
+#endif
+
+#if __has_include("include\missing.hpp")
+missing = 1;
+#else
+missing = 0;
+#endif
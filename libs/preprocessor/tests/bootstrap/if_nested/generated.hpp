@@ -0,0 +1,8 @@
+
+
+
+
+test = "shown";
+skip = "shown";
+
+skip = "shown";
@@ -0,0 +1,12 @@
+data = "matched flag";
+
+data = "matched 0";
+
+data = "matched not zero";
+
+
+data = "matched greater";
+
+data = "matched greater 2";
+
+rank = "SERGEANT";
@@ -0,0 +1,6 @@
+test = "in include";
+
+
+value = "from include";
+
+class nothing {};
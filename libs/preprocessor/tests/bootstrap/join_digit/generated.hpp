@@ -0,0 +1,2 @@
+
+data = 12;
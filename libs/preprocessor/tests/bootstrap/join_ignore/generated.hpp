@@ -0,0 +1,5 @@
+private _test = [];
+_test pushBack "#";
+_test pushBack "##";
+_test pushBack '###';
+_test pushBack '####';
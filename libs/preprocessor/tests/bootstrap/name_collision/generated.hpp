@@ -0,0 +1,8 @@
+
+
+class TransportItems {
+    class _xx_mod_painkillers { 
+    name = "mod_painkillers"; 
+    count = 1; 
+};
+};
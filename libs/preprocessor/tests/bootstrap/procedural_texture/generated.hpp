@@ -0,0 +1,2 @@
+animTextureNormal = "#(argb,8,8,3)color(0,0,0,0.9)";
+
@@ -0,0 +1,5 @@
+
+class test {
+    value = 123;
+    value = "123";
+};
@@ -0,0 +1,4 @@
+
+value = test_myVar;
+value = "test_myVar";
+value = "My variable is ""test_myVar""";
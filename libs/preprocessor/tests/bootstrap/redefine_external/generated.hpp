@@ -0,0 +1,3 @@
+
+
+systemChat str (2 + 2)
@@ -0,0 +1,5 @@
+private _data = _this#0;
+private _index = 0;
+private _data2 = _this #_index;
+private _data3 = _this#_index;
+private _data4 = _this # _index;
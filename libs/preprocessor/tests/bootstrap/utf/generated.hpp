@@ -0,0 +1 @@
+value = "Type 90 Kyū-maru";
@@ -0,0 +1 @@
+#define DEBUG_MODE
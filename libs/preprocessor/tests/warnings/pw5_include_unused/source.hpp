@@ -0,0 +1,9 @@
+#include "component.hpp"
+#include "unused.hpp"
+#include "flags.hpp"
+#pragma hemtt suppress pw5_include_unused
+#include "suppressed.hpp"
+
+#ifdef DEBUG_MODE
+value = GREETING;
+#endif
@@ -0,0 +1 @@
+#define ALSO_UNUSED 2
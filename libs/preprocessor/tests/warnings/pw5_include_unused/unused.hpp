@@ -0,0 +1 @@
+#define UNUSED_VALUE 1
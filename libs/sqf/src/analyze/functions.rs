@@ -0,0 +1,161 @@
+//! Functions declared and used across a project, checked by `unused_function`
+
+use std::collections::{HashMap, HashSet};
+
+use hemtt_workspace::{addons::DeclaredFunction, reporting::Processed};
+
+use crate::{BinaryCommand, Expression, Statements};
+
+#[derive(Debug, Default)]
+/// The functions declared by each addon, and the files that use each name
+pub struct FunctionUsage {
+    declared: HashMap<String, Vec<DeclaredFunction>>,
+    references: HashMap<String, HashSet<String>>,
+    entries: HashSet<String>,
+}
+
+impl FunctionUsage {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a function declared by an addon
+    pub fn declare(&mut self, addon: &str, function: DeclaredFunction) {
+        self.declared
+            .entry(addon.to_lowercase())
+            .or_default()
+            .push(function);
+    }
+
+    /// Add a name used by a file
+    pub fn reference(&mut self, name: &str, file: &str) {
+        self.references
+            .entry(name.to_lowercase())
+            .or_default()
+            .insert(normalize(file));
+    }
+
+    /// Add a file flagged with `#pragma hemtt flag_as_entry`, used from outside of the project
+    pub fn entry(&mut self, file: &str) {
+        self.entries.insert(normalize(file));
+    }
+
+    #[must_use]
+    /// The functions declared by an addon
+    pub fn declared(&self, addon: &str) -> &[DeclaredFunction] {
+        self.declared
+            .get(&addon.to_lowercase())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    #[must_use]
+    /// Is the function used by any file other than its own script, or is its script an entry
+    pub fn is_used(&self, function: &DeclaredFunction) -> bool {
+        let source = function.source().map(normalize);
+        if let Some(source) = &source {
+            if self
+                .entries
+                .iter()
+                .any(|entry| source.ends_with(entry.as_str()))
+            {
+                return true;
+            }
+        }
+        let Some(files) = self.references.get(&function.name().to_lowercase()) else {
+            return false;
+        };
+        files.iter().any(|file| {
+            source
+                .as_ref()
+                .is_none_or(|source| !source.ends_with(file.as_str()))
+        })
+    }
+}
+
+#[must_use]
+/// The functions compiled by `PREP` macros in a file, and the function names it uses
+///
+/// `PREP` expands to `[<file>, <name>] call CBA_fnc_compileFunction`, the name in it is not a use
+pub fn scan(
+    statements: &Statements,
+    processed: &Processed,
+) -> (Vec<DeclaredFunction>, HashSet<String>) {
+    let expressions = statements
+        .content()
+        .iter()
+        .flat_map(crate::Statement::walk_expressions)
+        .collect::<Vec<_>>();
+    let mut declared = Vec::new();
+    let mut compiled = HashSet::new();
+    for expression in &expressions {
+        let Expression::BinaryCommand(BinaryCommand::Named(command), lhs, rhs, _) = expression
+        else {
+            continue;
+        };
+        if !command.eq_ignore_ascii_case("call") {
+            continue;
+        }
+        let Expression::Variable(function, _) = &**rhs else {
+            continue;
+        };
+        if !function.eq_ignore_ascii_case("cba_fnc_compilefunction") {
+            continue;
+        }
+        let Expression::Array(items, _) = &**lhs else {
+            continue;
+        };
+        let [Expression::String(source, _, _), Expression::String(name, span, _)] =
+            items.as_slice()
+        else {
+            continue;
+        };
+        compiled.insert(span.start);
+        // The widest mapping is the `PREP` in the original file
+        let Some(map) = processed
+            .mappings(span.start)
+            .into_iter()
+            .min_by_key(|map| map.processed_start().offset())
+        else {
+            continue;
+        };
+        let Some((file, _)) = processed.source(map.source()) else {
+            continue;
+        };
+        declared.push(DeclaredFunction::new(
+            name.to_string(),
+            Some(source.to_string()),
+            file.clone(),
+            map.original_start()..map.original_end(),
+        ));
+    }
+    let mut references = HashSet::new();
+    for expression in expressions {
+        match expression {
+            Expression::Variable(name, _) if is_function(name) => {
+                references.insert(name.to_lowercase());
+            }
+            Expression::String(value, span, _) if !compiled.contains(&span.start) => {
+                references.extend(
+                    value
+                        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .filter(|word| is_function(word))
+                        .map(str::to_lowercase),
+                );
+            }
+            _ => {}
+        }
+    }
+    (declared, references)
+}
+
+fn is_function(name: &str) -> bool {
+    name.to_lowercase().contains("_fnc_")
+}
+
+/// Compare paths with `/`, without case or a leading slash
+fn normalize(path: &str) -> String {
+    path.replace('\\', "/")
+        .trim_start_matches('/')
+        .to_lowercase()
+}
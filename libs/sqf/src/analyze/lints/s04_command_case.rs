@@ -1,7 +1,7 @@
 use std::{ops::Range, sync::Arc};
 
 use hemtt_common::config::{LintConfig, ProjectConfig};
-use hemtt_workspace::{lint::{AnyLintRunner, Lint, LintRunner}, reporting::{Code, Codes, Diagnostic, Processed, Severity}};
+use hemtt_workspace::{lint::{AnyLintRunner, Lint, LintRunner}, reporting::{Code, Codes, Diagnostic, Fix, Processed, Severity}};
 
 use crate::{analyze::LintData, Expression};
 
@@ -102,6 +102,7 @@ pub struct CodeS04CommandCase {
     include: bool,
     severity: Severity,
     diagnostic: Option<Diagnostic>,
+    fix: Option<Fix>,
 }
 
 impl Code for CodeS04CommandCase {
@@ -133,6 +134,10 @@ impl Code for CodeS04CommandCase {
         Some(format!("\"{}\"", self.wiki))
     }
 
+    fn fix(&self) -> Option<Fix> {
+        self.fix.clone()
+    }
+
     fn diagnostic(&self) -> Option<Diagnostic> {
         self.diagnostic.clone()
     }
@@ -147,7 +152,8 @@ impl CodeS04CommandCase {
             }),
             severity,
             diagnostic: None,
-            
+            fix: None,
+
             span,
             used,
             wiki,
@@ -157,6 +163,11 @@ impl CodeS04CommandCase {
 
     fn generate_processed(mut self, processed: &Processed) -> Self {
         self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self.fix = Fix::from_processed(processed, self.span.clone(), |original| {
+            original
+                .eq_ignore_ascii_case(&self.wiki)
+                .then(|| self.wiki.clone())
+        });
         self
     }
 }
@@ -0,0 +1,330 @@
+use std::{collections::HashSet, ops::Range, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, BinaryCommand, Expression, Statement, Statements, UnaryCommand};
+
+crate::analyze::lint!(LintS25UndefinedVariable);
+
+impl Lint<LintData> for LintS25UndefinedVariable {
+    fn ident(&self) -> &'static str {
+        "undefined_variable"
+    }
+
+    fn sort(&self) -> u32 {
+        250
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports local variables that are read before they are defined"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"### Example
+
+**Incorrect**
+```sqf
+if (alive player) then {
+    private _health = 1 - damage player;
+};
+hint str _health;
+```
+**Correct**
+```sqf
+private _health = 0;
+if (alive player) then {
+    _health = 1 - damage player;
+};
+hint str _health;
+```
+
+### Explanation
+
+A local variable only exists in the scope it was defined in, reading it before it is defined, or after its scope has ended, will return `nil`.
+
+Variables are defined by assigning them, `private`, `params`, and `for "_i"` loops. Code that is run immediately, like the code of `if`, `while`, `forEach`, and `call`, can read the variables of the scope around it. Any other code, such as functions and event handlers, is not checked, since it can be called from anywhere.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning().with_enabled(false)
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+/// Variables that are provided by the engine
+static MAGIC: [&str; 17] = [
+    "_this",
+    "_x",
+    "_y",
+    "_foreachindex",
+    "_exception",
+    "_thisscript",
+    "_thisfsm",
+    "_thiseventhandler",
+    "_thisargs",
+    "_thistype",
+    "_thisid",
+    "_thisscriptedeventhandler",
+    "_fnc_scriptname",
+    "_fnc_scriptnameparent",
+    "_fnc_scriptnameshort",
+    "_time",
+    "_event",
+];
+
+/// Commands that run their code arguments immediately, in the current scope
+pub(crate) static INLINE_BINARY: [&str; 11] = [
+    "then", "else", "exitwith", "do", "foreach", "count", "select", "apply", "findif", "call",
+    "catch",
+];
+pub(crate) static INLINE_UNARY: [&str; 6] = ["call", "while", "waituntil", "try", "default", "for"];
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = crate::Statements;
+
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &Self::Target,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return Vec::new();
+        };
+        // Nested code is walked from the root, where the surrounding scope is known
+        if target.source() != processed.as_str() {
+            return Vec::new();
+        }
+        let mut walker = Walker::default();
+        walker.statements(target, &mut HashSet::new());
+        walker
+            .undefined
+            .into_iter()
+            .map(|(name, span)| {
+                Arc::new(CodeS25UndefinedVariable::new(
+                    name,
+                    span,
+                    processed,
+                    config.severity(),
+                )) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+#[derive(Default)]
+struct Walker {
+    /// The first read of each undefined variable
+    undefined: Vec<(String, Range<usize>)>,
+    reported: HashSet<String>,
+}
+
+impl Walker {
+    fn statements(&mut self, statements: &Statements, defined: &mut HashSet<String>) {
+        for statement in statements.content() {
+            match statement {
+                Statement::AssignGlobal(name, expression, _)
+                | Statement::AssignLocal(name, expression, _) => {
+                    self.expression(expression, defined);
+                    if name.starts_with('_') {
+                        defined.insert(name.to_lowercase());
+                    }
+                }
+                Statement::Expression(expression, _) => self.expression(expression, defined),
+            }
+        }
+    }
+
+    fn expression(&mut self, expression: &Expression, defined: &mut HashSet<String>) {
+        match expression {
+            Expression::Variable(name, span) => {
+                let lower = name.to_lowercase();
+                if lower.starts_with('_')
+                    && !defined.contains(&lower)
+                    && !MAGIC.contains(&lower.as_str())
+                    && self.reported.insert(lower)
+                {
+                    self.undefined.push((name.clone(), span.clone()));
+                }
+            }
+            Expression::Array(items, _) | Expression::ConsumeableArray(items, _) => {
+                for item in items {
+                    self.expression(item, defined);
+                }
+            }
+            Expression::UnaryCommand(command, child, _) => {
+                let name = command.as_str().to_lowercase();
+                if INLINE_UNARY.contains(&name.as_str()) {
+                    self.inline(child, defined);
+                } else {
+                    self.expression(child, defined);
+                }
+                if let UnaryCommand::Named(_) = command {
+                    if name == "private" || name == "params" {
+                        define(&name, child, defined);
+                    }
+                }
+            }
+            Expression::BinaryCommand(command, left, right, _) => {
+                let name = command.as_str().to_lowercase();
+                if matches!(command, BinaryCommand::Associate | BinaryCommand::Else)
+                    || INLINE_BINARY.contains(&name.as_str())
+                {
+                    let mut inner = defined.clone();
+                    if name == "do" {
+                        inner.extend(loop_variables(left));
+                    }
+                    self.inline(left, &inner);
+                    self.inline(right, &inner);
+                } else {
+                    self.expression(left, defined);
+                    self.expression(right, defined);
+                }
+                if name == "params" {
+                    define(&name, right, defined);
+                }
+            }
+            // Code that is not run immediately can be called from any scope
+            Expression::Code(_)
+            | Expression::String(..)
+            | Expression::Number(..)
+            | Expression::Boolean(..)
+            | Expression::NularCommand(..) => {}
+        }
+    }
+
+    /// Walk an argument of a command that runs its code in a new scope inside the current one
+    fn inline(&mut self, expression: &Expression, defined: &HashSet<String>) {
+        match expression {
+            Expression::Code(statements) => self.statements(statements, &mut defined.clone()),
+            Expression::Array(items, _) => {
+                for item in items {
+                    self.inline(item, defined);
+                }
+            }
+            _ => self.expression(expression, &mut defined.clone()),
+        }
+    }
+}
+
+/// Define the variables of `private` or `params`
+fn define(command: &str, argument: &Expression, defined: &mut HashSet<String>) {
+    let names = match argument {
+        Expression::String(name, _, _) if command == "private" => vec![name],
+        Expression::Array(items, _) => items
+            .iter()
+            .filter_map(|item| match item {
+                Expression::String(name, _, _) => Some(name),
+                Expression::Array(param, _) if command == "params" => match param.first() {
+                    Some(Expression::String(name, _, _)) => Some(name),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect(),
+        _ => return,
+    };
+    defined.extend(names.into_iter().map(|name| name.to_lowercase()));
+}
+
+/// Variables defined by a `for` loop, `for "_i"` or the init code of `for [{}, {}, {}]`
+fn loop_variables(expression: &Expression) -> Vec<String> {
+    match expression {
+        Expression::BinaryCommand(_, left, _, _) => loop_variables(left),
+        Expression::UnaryCommand(UnaryCommand::Named(command), child, _)
+            if command.eq_ignore_ascii_case("for") =>
+        {
+            match &**child {
+                Expression::String(name, _, _) => vec![name.to_lowercase()],
+                Expression::Array(items, _) => match items.first() {
+                    Some(Expression::Code(init)) => init
+                        .content()
+                        .iter()
+                        .filter_map(|statement| match statement {
+                            Statement::AssignGlobal(name, _, _)
+                            | Statement::AssignLocal(name, _, _) => Some(name.to_lowercase()),
+                            Statement::Expression(..) => None,
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                },
+                _ => Vec::new(),
+            }
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeS25UndefinedVariable {
+    name: String,
+    span: Range<usize>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeS25UndefinedVariable {
+    fn ident(&self) -> &'static str {
+        "L-S25"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/sqf.html#undefined_variable")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` is read before it is defined", self.name)
+    }
+
+    fn label_message(&self) -> String {
+        "not defined in this scope".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("local variables are only available in the scope they are defined in, and the scopes inside it".to_string())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "define `{}` with `private` before it is used",
+            self.name
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeS25UndefinedVariable {
+    #[must_use]
+    pub fn new(name: String, span: Range<usize>, processed: &Processed, severity: Severity) -> Self {
+        Self {
+            name,
+            span,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
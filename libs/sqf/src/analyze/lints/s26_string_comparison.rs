@@ -0,0 +1,187 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, BinaryCommand, Expression};
+
+crate::analyze::lint!(LintS26StringComparison);
+
+impl Lint<LintData> for LintS26StringComparison {
+    fn ident(&self) -> &'static str {
+        "string_comparison"
+    }
+
+    fn sort(&self) -> u32 {
+        260
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for strings compared with `==` or `!=`, which ignore case"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"**Disabled by default**, enable it with
+
+```toml
+[lints.sqf]
+string_comparison = true
+```
+
+### Example
+
+**Incorrect**
+```sqf
+if (typeOf _vehicle == "B_MRAP_01_F") then {};
+```
+**Correct**
+```sqf
+if (typeOf _vehicle isEqualTo "B_MRAP_01_F") then {};
+if (toLower typeOf _vehicle == toLower "B_MRAP_01_F") then {};
+```
+
+### Explanation
+
+`==` and `!=` compare strings without case, `"abc" == "ABC"` is `true`. `isEqualTo` and `isNotEqualTo` compare strings with case, and are faster. Using `==` when the case matters, or `isEqualTo` when it does not, is a common source of bugs.
+
+Only comparisons with a string that contains letters are reported, as the two are the same otherwise.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::help().with_enabled(false)
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = crate::Expression;
+
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &Self::Target,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return Vec::new();
+        };
+        let Expression::BinaryCommand(
+            command @ (BinaryCommand::Eq | BinaryCommand::NotEq),
+            lhs,
+            rhs,
+            span,
+        ) = target
+        else {
+            return Vec::new();
+        };
+        let has_letters = |expression: &Expression| {
+            matches!(expression, Expression::String(string, _, _) if string.chars().any(char::is_alphabetic))
+        };
+        if !has_letters(lhs) && !has_letters(rhs) {
+            return Vec::new();
+        }
+        vec![Arc::new(CodeS26StringComparison::new(
+            span.clone(),
+            matches!(command, BinaryCommand::NotEq),
+            processed,
+            config.severity(),
+        ))]
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeS26StringComparison {
+    span: Range<usize>,
+    negated: bool,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeS26StringComparison {
+    fn ident(&self) -> &'static str {
+        "L-S26"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/sqf.html#string_comparison")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` compares strings without case", self.operator())
+    }
+
+    fn label_message(&self) -> String {
+        "case is ignored".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some(format!(
+            "`{}` compares strings with case",
+            self.replacement()
+        ))
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "use `{}` if the case matters, or `toLower` both sides if it does not",
+            self.replacement()
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeS26StringComparison {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        negated: bool,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            negated,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+
+    const fn operator(&self) -> &'static str {
+        if self.negated {
+            "!="
+        } else {
+            "=="
+        }
+    }
+
+    const fn replacement(&self) -> &'static str {
+        if self.negated {
+            "isNotEqualTo"
+        } else {
+            "isEqualTo"
+        }
+    }
+}
@@ -0,0 +1,353 @@
+use std::{ops::Range, sync::Arc};
+
+use arma3_wiki::model::{Arg, Call, Syntax, Value};
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, BinaryCommand, Expression, UnaryCommand};
+
+crate::analyze::lint!(LintS27CommandArguments);
+
+impl Lint<LintData> for LintS27CommandArguments {
+    fn ident(&self) -> &'static str {
+        "command_arguments"
+    }
+
+    fn sort(&self) -> u32 {
+        270
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for commands given a literal of a type they do not accept"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Example
+
+**Incorrect**
+```sqf
+private _first = 5 select 0;
+player setPosASL [100, 200];
+```
+**Correct**
+```sqf
+private _first = [5] select 0;
+player setPosASL [100, 200, 0];
+```
+
+### Explanation
+
+The types each command accepts are known from the wiki. A string, number, boolean, code, or array written directly as the argument of a command must be one of those types, and arrays used as positions must have the right number of elements. Using the wrong type will cause an error when the script is run.
+
+Only literals are checked, variables and the results of other commands are not.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The type of a literal argument
+enum Found {
+    String,
+    Number,
+    Boolean,
+    Code,
+    Array(usize),
+}
+
+impl Found {
+    const fn from_expression(expression: &Expression) -> Option<Self> {
+        Some(match expression {
+            Expression::String(..) => Self::String,
+            Expression::Number(..) => Self::Number,
+            Expression::Boolean(..) => Self::Boolean,
+            Expression::Code(_) => Self::Code,
+            Expression::Array(items, _) => Self::Array(items.len()),
+            _ => return None,
+        })
+    }
+
+    fn description(self) -> String {
+        match self {
+            Self::String => "String".to_string(),
+            Self::Number => "Number".to_string(),
+            Self::Boolean => "Boolean".to_string(),
+            Self::Code => "Code".to_string(),
+            Self::Array(1) => "Array of 1 element".to_string(),
+            Self::Array(len) => format!("Array of {len} elements"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Unary,
+    Left,
+    Right,
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Expression;
+
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Self::Target,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return Vec::new();
+        };
+        let (_, database) = data;
+        let (name, operands) = match target {
+            Expression::UnaryCommand(UnaryCommand::Named(name), rhs, _) => {
+                (name, vec![(Side::Unary, &**rhs)])
+            }
+            Expression::BinaryCommand(BinaryCommand::Named(name), lhs, rhs, _) => {
+                (name, vec![(Side::Left, &**lhs), (Side::Right, &**rhs)])
+            }
+            _ => return Vec::new(),
+        };
+        let Some(command) = database.wiki().commands().get(name) else {
+            return Vec::new();
+        };
+        let syntaxes = command
+            .syntax()
+            .iter()
+            .filter(|syntax| match syntax.call() {
+                Call::Unary(_) => operands.len() == 1,
+                Call::Binary(_, _) => operands.len() == 2,
+                Call::Nular => false,
+            })
+            .collect::<Vec<_>>();
+        if syntaxes.is_empty() {
+            return Vec::new();
+        }
+        let mut codes: Codes = Vec::new();
+        for (side, operand) in operands {
+            let Some(found) = Found::from_expression(operand) else {
+                continue;
+            };
+            let mut expected = Vec::new();
+            let mut accepted = false;
+            for syntax in &syntaxes {
+                match expected_value(syntax, side) {
+                    Some(Expected::Value(value)) => {
+                        if accepts(value, found) {
+                            accepted = true;
+                        }
+                        describe(value, &mut expected);
+                    }
+                    Some(Expected::Array) => {
+                        if matches!(found, Found::Array(_)) {
+                            accepted = true;
+                        }
+                        describe(&Value::ArrayUnknown, &mut expected);
+                    }
+                    // The wiki does not describe this argument, assume it is fine
+                    None => accepted = true,
+                }
+            }
+            if !accepted {
+                let span = operand.span();
+                codes.push(Arc::new(CodeS27CommandArguments::new(
+                    // The span of an array does not include the closing bracket
+                    if operand.is_array() {
+                        span.start..span.end + 1
+                    } else {
+                        span
+                    },
+                    command.name().to_string(),
+                    side,
+                    found,
+                    expected,
+                    processed,
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+enum Expected<'a> {
+    Value(&'a Value),
+    Array,
+}
+
+/// The type a syntax expects on one side of the command
+fn expected_value(syntax: &Syntax, side: Side) -> Option<Expected<'_>> {
+    let ((Call::Unary(arg), Side::Unary)
+    | (Call::Binary(arg, _), Side::Left)
+    | (Call::Binary(_, arg), Side::Right)) = (syntax.call(), side)
+    else {
+        return None;
+    };
+    match arg {
+        Arg::Item(name) => syntax
+            .params()
+            .iter()
+            .find(|param| param.name() == name)
+            .map(|param| Expected::Value(param.typ())),
+        Arg::Array(_) => Some(Expected::Array),
+    }
+}
+
+fn accepts(value: &Value, found: Found) -> bool {
+    match value {
+        Value::OneOf(values) => values.iter().any(|(value, _)| accepts(value, found)),
+        Value::Anything | Value::Unknown | Value::Nothing | Value::HashMapKey => true,
+        Value::String => found == Found::String,
+        Value::Number => found == Found::Number,
+        Value::Boolean => found == Found::Boolean,
+        Value::Code => found == Found::Code,
+        Value::Position2d => matches!(found, Found::Array(len) if len >= 2),
+        Value::Position3d
+        | Value::Position3dASL
+        | Value::Position3DASLW
+        | Value::Position3dATL
+        | Value::Position3dAGL
+        | Value::Position3dAGLS
+        | Value::Position3dRelative
+        | Value::Vector3d
+        | Value::ArrayColorRgb => found == Found::Array(3),
+        Value::ArrayColorRgba => found == Found::Array(4),
+        Value::ArraySized { .. }
+        | Value::ArrayUnknown
+        | Value::ArrayUnsized { .. }
+        | Value::ArrayDate
+        | Value::ArrayColor
+        | Value::Position
+        | Value::TurretPath
+        | Value::UnitLoadoutArray
+        | Value::Waypoint => matches!(found, Found::Array(_)),
+        _ => false,
+    }
+}
+
+/// Add the names of the types a value accepts, without duplicates
+fn describe(value: &Value, names: &mut Vec<String>) {
+    let name = match value {
+        Value::OneOf(values) => {
+            for (value, _) in values {
+                describe(value, names);
+            }
+            return;
+        }
+        Value::ArraySized { .. }
+        | Value::ArrayUnknown
+        | Value::ArrayUnsized { .. }
+        | Value::ArrayDate
+        | Value::ArrayColor
+        | Value::Position
+        | Value::TurretPath
+        | Value::UnitLoadoutArray
+        | Value::Waypoint => "Array".to_string(),
+        Value::ArrayColorRgb => "Array of 3 elements".to_string(),
+        Value::ArrayColorRgba => "Array of 4 elements".to_string(),
+        Value::HashMapUnknown | Value::HashMapKnownKeys(_) => "HashMap".to_string(),
+        value => value.to_string(),
+    };
+    if !names.contains(&name) {
+        names.push(name);
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeS27CommandArguments {
+    span: Range<usize>,
+    command: String,
+    side: Side,
+    found: Found,
+    expected: Vec<String>,
+
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeS27CommandArguments {
+    fn ident(&self) -> &'static str {
+        "L-S27"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/sqf.html#command_arguments")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        let article = if matches!(self.found, Found::Array(_)) {
+            "an"
+        } else {
+            "a"
+        };
+        let found = format!("{article} {}", self.found.description());
+        match self.side {
+            Side::Unary => format!("`{}` does not accept {found}", self.command),
+            Side::Left => format!("`{}` does not accept {found} on the left", self.command),
+            Side::Right => format!("`{}` does not accept {found} on the right", self.command),
+        }
+    }
+
+    fn label_message(&self) -> String {
+        format!("found {}", self.found.description())
+    }
+
+    fn note(&self) -> Option<String> {
+        let expected = match self.expected.as_slice() {
+            [] => return None,
+            [one] => one.clone(),
+            [rest @ .., last] => format!("{}, or {last}", rest.join(", ")),
+        };
+        Some(format!("expected {expected}"))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeS27CommandArguments {
+    #[must_use]
+    fn new(
+        span: Range<usize>,
+        command: String,
+        side: Side,
+        found: Found,
+        expected: Vec<String>,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            command,
+            side,
+            found,
+            expected,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    addons::DeclaredFunction,
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+};
+
+use crate::analyze::{functions::FunctionUsage, LintData};
+
+crate::analyze::lint!(LintS28UnusedFunction);
+
+impl Lint<LintData> for LintS28UnusedFunction {
+    fn ident(&self) -> &'static str {
+        "unused_function"
+    }
+
+    fn sort(&self) -> u32 {
+        280
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for functions that are declared but never used"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"**Disabled by default**, enable it with
+
+```toml
+[lints.sqf]
+unused_function = true
+```
+
+### Configuration
+
+- **ignore**: Functions that are used outside of the project, such as entry points for event handlers or other mods
+
+```toml
+[lints.sqf.unused_function]
+enabled = true
+options.ignore = [
+    "abe_main_fnc_handleRespawn",
+]
+```
+
+### Example
+
+**Incorrect**
+```cpp
+// XEH_PREP.hpp
+PREP(init);
+PREP(oldInit); // not used by any script or config
+```
+
+### Explanation
+
+Functions declared in `CfgFunctions` or with a `PREP` macro are compiled when the game starts, even if nothing uses them. A function is used if its name appears in any script other than its own, or in any string of a `config.cpp`, such as an event handler. Functions are only checked when every addon is built, as with `--only` a function may be used by an addon that is not built.
+
+Functions called by name built at runtime, such as `missionNamespace getVariable format ["%1_fnc_%2", ...]`, can not be found, add them to `ignore`, or flag their script with `#pragma hemtt flag_as_entry`.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::help().with_enabled(false)
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = FunctionUsage;
+
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&Processed>,
+        target: &Self::Target,
+        data: &LintData,
+    ) -> Codes {
+        let ignore = if let Some(toml::Value::Array(ignore)) = config.option("ignore") {
+            ignore
+                .iter()
+                .filter_map(|i| i.as_str().map(str::to_lowercase))
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+        let mut codes: Codes = Vec::new();
+        for function in target.declared(data.0.name()) {
+            if ignore.contains(&function.name().to_lowercase()) || target.is_used(function) {
+                continue;
+            }
+            codes.push(Arc::new(CodeS28UnusedFunction::new(
+                function,
+                config.severity(),
+            )));
+        }
+        codes
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeS28UnusedFunction {
+    function: DeclaredFunction,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeS28UnusedFunction {
+    fn ident(&self) -> &'static str {
+        "L-S28"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/sqf.html#unused_function")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` is never used", self.function.name())
+    }
+
+    fn label_message(&self) -> String {
+        "declared here".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("no other script or config in the project uses it".to_string())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(
+            "remove the function, or add it to `options.ignore` if it is used outside of the project"
+                .to_string(),
+        )
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeS28UnusedFunction {
+    #[must_use]
+    pub fn new(function: &DeclaredFunction, severity: Severity) -> Self {
+        Self {
+            function: function.clone(),
+            severity,
+            diagnostic: None,
+        }
+        .generate()
+    }
+
+    fn generate(mut self) -> Self {
+        self.diagnostic = Some(
+            Diagnostic::from_code(&self).with_label(
+                Label::primary(self.function.file().clone(), self.function.span().clone())
+                    .with_message(self.label_message()),
+            ),
+        );
+        self
+    }
+}
@@ -0,0 +1,222 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, BinaryCommand, Expression, UnaryCommand};
+
+crate::analyze::lint!(LintS29UnlocalizedString);
+
+/// Commands that show their argument to the player
+const UNARY: &[&str] = &[
+    "hint",
+    "hintc",
+    "hintsilent",
+    "systemchat",
+    "titletext",
+    "cuttext",
+    "parsetext",
+];
+
+/// Commands that show their right argument to the player, sent by the unit or channel on the left
+const BINARY: &[&str] = &[
+    "commandchat",
+    "customchat",
+    "globalchat",
+    "groupchat",
+    "sidechat",
+    "vehiclechat",
+    "cuttext",
+    "titletext",
+];
+
+impl Lint<LintData> for LintS29UnlocalizedString {
+    fn ident(&self) -> &'static str {
+        "unlocalized_string"
+    }
+
+    fn sort(&self) -> u32 {
+        290
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for strings shown to the player that are not localized"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"**Disabled by default**, enable it with
+
+```toml
+[lints.sqf]
+unlocalized_string = true
+```
+
+### Example
+
+**Incorrect**
+```sqf
+hint "Vehicle repaired";
+player sideChat format ["%1 is down", name _unit];
+titleText ["Mission complete", "PLAIN"];
+```
+**Correct**
+```sqf
+hint localize "STR_ABE_Main_VehicleRepaired";
+player sideChat format [localize "STR_ABE_Main_UnitDown", name _unit];
+titleText [localize "STR_ABE_Main_MissionComplete", "PLAIN"];
+```
+
+### Explanation
+
+Text shown with `hint`, `systemChat`, `titleText`, `cutText`, the chat commands, and similar commands is read by the player. Strings written directly in the script are always shown in the same language, strings from a stringtable are shown in the language of the player.
+
+Strings are reported when they are the text of the command, the first element of its array, or the format of a `format` passed to it. Strings without letters are not reported.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::help().with_enabled(false)
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = crate::Expression;
+
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &Self::Target,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return Vec::new();
+        };
+        let (command, text) = match target {
+            Expression::UnaryCommand(UnaryCommand::Named(command), text, _)
+                if UNARY.contains(&command.to_lowercase().as_str()) =>
+            {
+                (command, text)
+            }
+            Expression::BinaryCommand(BinaryCommand::Named(command), _, text, _)
+                if BINARY.contains(&command.to_lowercase().as_str()) =>
+            {
+                (command, text)
+            }
+            _ => return Vec::new(),
+        };
+        let Some((string, span)) = shown(text) else {
+            return Vec::new();
+        };
+        if !string.chars().any(char::is_alphabetic) {
+            return Vec::new();
+        }
+        vec![Arc::new(CodeS29UnlocalizedString::new(
+            span.clone(),
+            command.clone(),
+            string.to_string(),
+            processed,
+            config.severity(),
+        ))]
+    }
+}
+
+/// The string shown by a command, directly, as the first element of an array, or as a format
+fn shown(text: &Expression) -> Option<(&str, &Range<usize>)> {
+    match text {
+        Expression::String(string, span, _) => Some((string, span)),
+        Expression::Array(items, _) => shown(items.first()?),
+        Expression::UnaryCommand(UnaryCommand::Named(command), arguments, _)
+            if command.eq_ignore_ascii_case("format") =>
+        {
+            let Expression::Array(items, _) = &**arguments else {
+                return None;
+            };
+            let Some(Expression::String(string, span, _)) = items.first() else {
+                return None;
+            };
+            Some((string, span))
+        }
+        _ => None,
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeS29UnlocalizedString {
+    span: Range<usize>,
+    command: String,
+    string: String,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeS29UnlocalizedString {
+    fn ident(&self) -> &'static str {
+        "L-S29"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/sqf.html#unlocalized_string")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` shows a string that is not localized", self.command)
+    }
+
+    fn label_message(&self) -> String {
+        "not localized".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        if self.string.to_lowercase().starts_with("str_") {
+            Some(format!(
+                "the key is shown as is, use `localize \"{}\"`",
+                self.string
+            ))
+        } else {
+            Some("add the string to a stringtable, and use `localize` with its key".to_string())
+        }
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeS29UnlocalizedString {
+    #[must_use]
+    pub fn new(
+        span: Range<usize>,
+        command: String,
+        string: String,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            command,
+            string,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -0,0 +1,185 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Processed, Severity},
+    WorkspacePath,
+};
+
+use crate::analyze::{
+    privates::{Privates, Shadow},
+    LintData,
+};
+
+crate::analyze::lint!(LintS30PrivateShadow);
+
+impl Lint<LintData> for LintS30PrivateShadow {
+    fn ident(&self) -> &'static str {
+        "private_shadow"
+    }
+
+    fn sort(&self) -> u32 {
+        300
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports privates that shadow a private of an outer scope"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Example
+
+**Incorrect**
+```sqf
+private _total = 0;
+{
+    private _total = _total + _x;
+} forEach _values;
+hint str _total;
+```
+**Correct**
+```sqf
+private _total = 0;
+{
+    _total = _total + _x;
+} forEach _values;
+hint str _total;
+```
+
+### Explanation
+
+`private` creates a new variable in the current scope, hiding the variable of the same name in the scope around it. Changes made to the new variable are lost when the scope ends, which is rarely intended.
+
+Code that is run immediately, like the code of `if`, `while`, `forEach`, and `call`, is checked against the privates around it. Any other code, such as functions and event handlers, is expected to declare its own privates.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning().with_enabled(false)
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = crate::Statements;
+
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &Self::Target,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return Vec::new();
+        };
+        // Nested code is walked from the root, where the surrounding scope is known
+        if target.source() != processed.as_str() {
+            return Vec::new();
+        }
+        Privates::walk(target)
+            .shadows
+            .into_iter()
+            .map(|shadow| {
+                Arc::new(CodeS30PrivateShadow::new(
+                    shadow,
+                    processed,
+                    config.severity(),
+                )) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeS30PrivateShadow {
+    name: String,
+    span: Range<usize>,
+    outer: Range<usize>,
+    assignment: Option<String>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeS30PrivateShadow {
+    fn ident(&self) -> &'static str {
+        "L-S30"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/sqf.html#private_shadow")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` shadows a private of an outer scope", self.name)
+    }
+
+    fn label_message(&self) -> String {
+        "shadows the outer private".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "remove `private` to use the outer `{}`, or use a different name",
+            self.name
+        ))
+    }
+
+    fn suggestion(&self) -> Option<String> {
+        self.assignment.clone()
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeS30PrivateShadow {
+    #[must_use]
+    pub fn new(shadow: Shadow, processed: &Processed, severity: Severity) -> Self {
+        Self {
+            name: shadow.name,
+            span: shadow.span,
+            outer: shadow.outer,
+            assignment: shadow.assignment,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        let Some(mut diag) = Diagnostic::from_code_processed(&self, self.span.clone(), processed)
+        else {
+            return self;
+        };
+        if let Some((file, span)) = get_span_info(self.outer.clone(), processed) {
+            diag = diag.with_label(Label::secondary(file, span).with_message("outer private"));
+        }
+        self.diagnostic = Some(diag);
+        self
+    }
+}
+
+fn get_span_info(
+    span: Range<usize>,
+    processed: &Processed,
+) -> Option<(WorkspacePath, Range<usize>)> {
+    let map_start = processed.mapping(span.start)?;
+    let map_end = processed.mapping(span.end)?;
+    let map_file = processed.source(map_start.source())?;
+    Some((
+        map_file.0.clone(),
+        map_start.original_start()..map_end.original_start(),
+    ))
+}
@@ -0,0 +1,154 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::analyze::{privates::Privates, LintData};
+
+crate::analyze::lint!(LintS31UnusedPrivate);
+
+impl Lint<LintData> for LintS31UnusedPrivate {
+    fn ident(&self) -> &'static str {
+        "unused_private"
+    }
+
+    fn sort(&self) -> u32 {
+        310
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports privates that are declared but never read"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Example
+
+**Incorrect**
+```sqf
+private _unit = player;
+private _health = damage player;
+hint str damage _unit;
+```
+**Correct**
+```sqf
+private _unit = player;
+hint str damage _unit;
+```
+
+### Explanation
+
+A private that is never read is usually left over from a change, or is a typo of the variable that was meant to be used.
+
+Variables of `params` are not reported, since they are part of how the code is called. A private that is read by code that is not run immediately, such as a function or event handler defined in the same scope, is treated as read.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::help().with_enabled(false)
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = crate::Statements;
+
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &Self::Target,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return Vec::new();
+        };
+        // Nested code is walked from the root, where the surrounding scope is known
+        if target.source() != processed.as_str() {
+            return Vec::new();
+        }
+        Privates::walk(target)
+            .unused
+            .into_iter()
+            .map(|unused| {
+                Arc::new(CodeS31UnusedPrivate::new(
+                    unused.name,
+                    unused.span,
+                    processed,
+                    config.severity(),
+                )) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeS31UnusedPrivate {
+    name: String,
+    span: Range<usize>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeS31UnusedPrivate {
+    fn ident(&self) -> &'static str {
+        "L-S31"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/sqf.html#unused_private")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("`{}` is declared but never read", self.name)
+    }
+
+    fn label_message(&self) -> String {
+        "never read".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!(
+            "remove `{}`, or check for a typo where it should be used",
+            self.name
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeS31UnusedPrivate {
+    #[must_use]
+    pub fn new(
+        name: String,
+        span: Range<usize>,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            name,
+            span,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
@@ -0,0 +1,374 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{analyze::LintData, BinaryCommand, Expression, Statement, Statements};
+
+use super::s25_undefined_variable::{INLINE_BINARY, INLINE_UNARY};
+
+crate::analyze::lint!(LintS32SpecialVariables);
+
+impl Lint<LintData> for LintS32SpecialVariables {
+    fn ident(&self) -> &'static str {
+        "special_variables"
+    }
+
+    fn sort(&self) -> u32 {
+        320
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports `_x`, `_y`, `_forEachIndex`, and `_this` used outside of the code where the engine defines them"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r"### Example
+
+**Incorrect**
+```sqf
+private _alive = { alive _this } count _this;
+{
+    systemChat str _forEachIndex;
+} count allPlayers;
+hint str _x;
+```
+**Correct**
+```sqf
+private _alive = { alive _x } count _this;
+{
+    systemChat str _forEachIndex;
+} forEach allPlayers;
+```
+
+### Explanation
+
+The engine defines `_x` and `_y` in the code of `forEach`, `count`, `apply`, `select`, and `findIf`, and `_forEachIndex` only in the code of `forEach`. Outside of that code, they are `nil`, or the variables of an outer loop.
+
+`_this` is not changed by these commands, inside their code it is still the arguments of the surrounding code. Using it there is fine, but in a loop over `_this` that never uses `_x`, the current element was most likely meant.
+
+Code that is not run immediately, such as functions and event handlers, is only checked inside its own loops, since it can be called from anywhere.
+"
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning().with_enabled(false)
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+/// Commands that define `_x` and `_forEachIndex` for their code on the left
+static FOREACH: [&str; 2] = ["foreach", "foreachreversed"];
+/// Commands that define `_x` for their code on the left
+static LEFT: [&str; 1] = ["count"];
+/// Commands that define `_x` for their code on the right
+static RIGHT: [&str; 3] = ["apply", "select", "findif"];
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = crate::Statements;
+
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &Self::Target,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return Vec::new();
+        };
+        // Nested code is walked from the root, where the surrounding code is known
+        if target.source() != processed.as_str() {
+            return Vec::new();
+        }
+        let mut walker = Walker::default();
+        walker.statements(
+            target,
+            Context {
+                element: Known::Undefined,
+                index: Known::Undefined,
+                command: None,
+            },
+        );
+        walker
+            .misused
+            .into_iter()
+            .map(|(variant, span)| {
+                Arc::new(CodeS32SpecialVariables::new(
+                    variant,
+                    span,
+                    processed,
+                    config.severity(),
+                )) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Known {
+    Defined,
+    Undefined,
+    /// Code that is not run immediately can be called from anywhere
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Context {
+    /// `_x` and `_y`
+    element: Known,
+    /// `_forEachIndex`
+    index: Known,
+    /// The loop command, when the current code is the code of a loop over `_this`
+    /// that does not use the current element
+    command: Option<&'static str>,
+}
+
+impl Context {
+    const fn nested(self) -> Self {
+        Self {
+            command: None,
+            ..self
+        }
+    }
+}
+
+#[derive(Default)]
+struct Walker {
+    misused: Vec<(Variant, Range<usize>)>,
+}
+
+impl Walker {
+    fn statements(&mut self, statements: &Statements, context: Context) {
+        for statement in statements.content() {
+            match statement {
+                Statement::AssignGlobal(_, expression, _)
+                | Statement::AssignLocal(_, expression, _)
+                | Statement::Expression(expression, _) => self.expression(expression, context),
+            }
+        }
+    }
+
+    fn expression(&mut self, expression: &Expression, context: Context) {
+        match expression {
+            Expression::Variable(name, span) => {
+                let lower = name.to_lowercase();
+                let variant = match lower.as_str() {
+                    "_x" | "_y" if context.element == Known::Undefined => {
+                        Variant::Element(name.clone())
+                    }
+                    "_foreachindex" if context.index == Known::Undefined => Variant::Index,
+                    "_this" => match context.command {
+                        Some(command) => Variant::This(command),
+                        None => return,
+                    },
+                    _ => return,
+                };
+                self.misused.push((variant, span.clone()));
+            }
+            Expression::Array(items, _) | Expression::ConsumeableArray(items, _) => {
+                for item in items {
+                    self.expression(item, context);
+                }
+            }
+            Expression::UnaryCommand(command, child, _) => {
+                let name = command.as_str().to_lowercase();
+                if INLINE_UNARY.contains(&name.as_str()) {
+                    self.inline(child, context.nested());
+                } else {
+                    self.expression(child, context);
+                }
+            }
+            Expression::BinaryCommand(command, left, right, _) => {
+                let name = command.as_str().to_lowercase();
+                let looped = |command: &'static str, index: bool, code, array| Context {
+                    element: Known::Defined,
+                    index: if index { Known::Defined } else { context.index },
+                    command: (is_this(array) && !uses_element(code)).then_some(command),
+                };
+                if let Some(command) = FOREACH.iter().find(|c| **c == name) {
+                    self.inline(left, looped(command, true, left, right));
+                    self.expression(right, context);
+                } else if let Some(command) = LEFT.iter().find(|c| **c == name) {
+                    self.inline(left, looped(command, false, left, right));
+                    self.expression(right, context);
+                } else if let Some(command) = RIGHT.iter().find(|c| **c == name) {
+                    self.expression(left, context);
+                    self.inline(right, looped(command, false, right, left));
+                } else if matches!(command, BinaryCommand::Associate | BinaryCommand::Else)
+                    || INLINE_BINARY.contains(&name.as_str())
+                {
+                    self.inline(left, context.nested());
+                    self.inline(right, context.nested());
+                } else {
+                    self.expression(left, context);
+                    self.expression(right, context);
+                }
+            }
+            Expression::Code(statements) => self.statements(
+                statements,
+                Context {
+                    element: Known::Unknown,
+                    index: Known::Unknown,
+                    command: None,
+                },
+            ),
+            Expression::String(..)
+            | Expression::Number(..)
+            | Expression::Boolean(..)
+            | Expression::NularCommand(..) => {}
+        }
+    }
+
+    /// Walk an argument of a command that runs its code immediately
+    fn inline(&mut self, expression: &Expression, context: Context) {
+        match expression {
+            Expression::Code(statements) => self.statements(statements, context),
+            Expression::Array(items, _) => {
+                for item in items {
+                    self.inline(item, context);
+                }
+            }
+            _ => self.expression(expression, context.nested()),
+        }
+    }
+}
+
+/// Is the expression the `_this` variable
+fn is_this(expression: &Expression) -> bool {
+    matches!(expression, Expression::Variable(name, _) if name.eq_ignore_ascii_case("_this"))
+}
+
+/// Does the code use `_x` or `_y` anywhere, including in nested code
+fn uses_element(expression: &Expression) -> bool {
+    match expression {
+        Expression::Variable(name, _) => {
+            name.eq_ignore_ascii_case("_x") || name.eq_ignore_ascii_case("_y")
+        }
+        Expression::Array(items, _) | Expression::ConsumeableArray(items, _) => {
+            items.iter().any(uses_element)
+        }
+        Expression::UnaryCommand(_, child, _) => uses_element(child),
+        Expression::BinaryCommand(_, left, right, _) => uses_element(left) || uses_element(right),
+        Expression::Code(statements) => statements.content().iter().any(|statement| match statement {
+            Statement::AssignGlobal(_, expression, _)
+            | Statement::AssignLocal(_, expression, _)
+            | Statement::Expression(expression, _) => uses_element(expression),
+        }),
+        Expression::String(..)
+        | Expression::Number(..)
+        | Expression::Boolean(..)
+        | Expression::NularCommand(..) => false,
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Variant {
+    /// `_x` or `_y` outside of a loop
+    Element(String),
+    /// `_forEachIndex` outside of `forEach`
+    Index,
+    /// `_this` in the code of a loop
+    This(&'static str),
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeS32SpecialVariables {
+    variant: Variant,
+    span: Range<usize>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeS32SpecialVariables {
+    fn ident(&self) -> &'static str {
+        "L-S32"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/sqf.html#special_variables")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        match &self.variant {
+            Variant::Element(name) => format!("`{name}` is used outside of a loop"),
+            Variant::Index => "`_forEachIndex` is used outside of `forEach`".to_string(),
+            Variant::This(_) => "`_this` is not the current element".to_string(),
+        }
+    }
+
+    fn label_message(&self) -> String {
+        match &self.variant {
+            Variant::Element(_) | Variant::Index => "not defined here".to_string(),
+            Variant::This(command) => format!("not changed by `{command}`"),
+        }
+    }
+
+    fn note(&self) -> Option<String> {
+        match &self.variant {
+            Variant::Element(_) => Some(
+                "`_x` and `_y` are defined in the code of `forEach`, `count`, `apply`, `select`, and `findIf`"
+                    .to_string(),
+            ),
+            Variant::Index => {
+                Some("`_forEachIndex` is only defined in the code of `forEach`".to_string())
+            }
+            Variant::This(_) => Some(
+                "inside a loop over `_this`, `_this` is still the whole array".to_string(),
+            ),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        match &self.variant {
+            Variant::This(_) => Some("use `_x` for the current element".to_string()),
+            _ => None,
+        }
+    }
+
+    fn suggestion(&self) -> Option<String> {
+        match &self.variant {
+            Variant::This(_) => Some("_x".to_string()),
+            _ => None,
+        }
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeS32SpecialVariables {
+    #[must_use]
+    fn new(
+        variant: Variant,
+        span: Range<usize>,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            variant,
+            span,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
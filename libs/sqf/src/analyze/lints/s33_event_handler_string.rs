@@ -0,0 +1,229 @@
+use std::{ops::Range, sync::Arc};
+
+use arma3_wiki::model::EventHandlerNamespace;
+use hemtt_common::config::{LintConfig, ProjectConfig};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Processed, Severity},
+};
+
+use crate::{
+    analyze::LintData,
+    parser::{self, database::Database, lexer},
+    BinaryCommand, Expression, UnaryCommand,
+};
+
+crate::analyze::lint!(LintS33EventHandlerString);
+
+impl Lint<LintData> for LintS33EventHandlerString {
+    fn ident(&self) -> &'static str {
+        "event_handler_string"
+    }
+
+    fn sort(&self) -> u32 {
+        330
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports event handlers that are given large code as a string"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"### Configuration
+
+- **max_length**: The longest string that is not reported, defaults to 60
+- **check_syntax**: Parse the code of every string event handler, and report code that is not valid SQF, defaults to `false`
+
+```toml
+[lints.sqf.event_handler_string]
+options.max_length = 40
+options.check_syntax = true
+```
+
+### Example
+
+**Incorrect**
+```sqf
+player addEventHandler ["Killed", "params ['_unit', '_killer']; systemChat format ['%1 was killed by %2', name _unit, name _killer];"];
+```
+**Correct**
+```sqf
+player addEventHandler ["Killed", {
+    params ["_unit", "_killer"];
+    systemChat format ["%1 was killed by %2", name _unit, name _killer];
+}];
+```
+```sqf
+player addEventHandler ["Killed", { call abe_main_fnc_killed }];
+```
+
+### Explanation
+
+Code in a string is compiled every time the event handler is added, is not checked by HEMTT, and is hard to read because of its escaped quotes. Code blocks, or functions compiled from files, are compiled once and checked like any other code.
+"#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::help()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+const DEFAULT_MAX_LENGTH: usize = 60;
+
+struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Expression;
+
+    fn run(
+        &self,
+        _project: Option<&ProjectConfig>,
+        config: &LintConfig,
+        processed: Option<&Processed>,
+        target: &Self::Target,
+        data: &LintData,
+    ) -> Codes {
+        let Some(processed) = processed else {
+            return Vec::new();
+        };
+        let (Expression::BinaryCommand(BinaryCommand::Named(command), _, arguments, _)
+        | Expression::UnaryCommand(UnaryCommand::Named(command), arguments, _)) = target
+        else {
+            return Vec::new();
+        };
+        let Expression::Array(arguments, _) = &**arguments else {
+            return Vec::new();
+        };
+        let Some(Expression::String(code, span, _)) = arguments.get(1) else {
+            return Vec::new();
+        };
+        if EventHandlerNamespace::by_command(command).is_empty() {
+            return Vec::new();
+        }
+        let max_length = config
+            .option("max_length")
+            .and_then(toml::Value::as_integer)
+            .and_then(|max| usize::try_from(max).ok())
+            .unwrap_or(DEFAULT_MAX_LENGTH);
+        let check_syntax = config
+            .option("check_syntax")
+            .and_then(toml::Value::as_bool)
+            .unwrap_or(false);
+        if check_syntax && !is_valid(&data.1, processed, code, span) {
+            return vec![Arc::new(CodeS33EventHandlerString::new(
+                span.clone(),
+                command.clone(),
+                Variant::Invalid,
+                processed,
+                config.severity(),
+            ))];
+        }
+        if code.chars().count() <= max_length {
+            return Vec::new();
+        }
+        vec![Arc::new(CodeS33EventHandlerString::new(
+            span.clone(),
+            command.clone(),
+            Variant::Long(code.chars().count()),
+            processed,
+            config.severity(),
+        ))]
+    }
+}
+
+/// Parse the code of a string, in place of the string in the processed file
+fn is_valid(database: &Database, processed: &Processed, code: &str, span: &Range<usize>) -> bool {
+    let Ok(mut tokens) = lexer::run(code) else {
+        return false;
+    };
+    lexer::strip_comments(&mut tokens);
+    lexer::strip_noop(&mut tokens);
+    // The code starts after the opening quote
+    let offset = span.start + 1;
+    let tokens = tokens
+        .into_iter()
+        .map(|(token, range)| (token, range.start + offset..range.end + offset));
+    parser::run_for_tokens(database, processed, tokens).is_ok()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Variant {
+    /// The length of the code
+    Long(usize),
+    Invalid,
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeS33EventHandlerString {
+    span: Range<usize>,
+    command: String,
+    variant: Variant,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeS33EventHandlerString {
+    fn ident(&self) -> &'static str {
+        "L-S33"
+    }
+
+    fn link(&self) -> Option<&str> {
+        Some("/analysis/sqf.html#event_handler_string")
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        match self.variant {
+            Variant::Long(_) => format!("`{}` is given code as a string", self.command),
+            Variant::Invalid => {
+                format!("`{}` is given a string that is not valid SQF", self.command)
+            }
+        }
+    }
+
+    fn label_message(&self) -> String {
+        match self.variant {
+            Variant::Long(length) => format!("{length} characters of code"),
+            Variant::Invalid => "invalid code".to_string(),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("use a code block, or call a function compiled from a file".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeS33EventHandlerString {
+    #[must_use]
+    fn new(
+        span: Range<usize>,
+        command: String,
+        variant: Variant,
+        processed: &Processed,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            span,
+            command,
+            variant,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed(processed)
+    }
+
+    fn generate_processed(mut self, processed: &Processed) -> Self {
+        self.diagnostic = Diagnostic::from_code_processed(&self, self.span.clone(), processed);
+        self
+    }
+}
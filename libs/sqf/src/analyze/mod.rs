@@ -1,9 +1,12 @@
+pub mod functions;
+pub mod privates;
 pub mod lints {
     automod::dir!(pub "src/analyze/lints");
 }
 
 use std::sync::Arc;
 
+use functions::FunctionUsage;
 use hemtt_common::config::ProjectConfig;
 use hemtt_workspace::{
     addons::Addon,
@@ -41,25 +44,42 @@ pub fn analyze(
     addon: Arc<Addon>,
     database: Arc<Database>,
 ) -> Codes {
+    let manager = match manager(project) {
+        Ok(manager) => manager,
+        Err(lint_errors) => return lint_errors,
+    };
+    statements.analyze(&(addon, database), project, processed, &manager)
+}
+
+#[must_use]
+/// Analyze the functions of an addon, after every file of the project has been scanned
+pub fn analyze_functions(
+    usage: &FunctionUsage,
+    project: Option<&ProjectConfig>,
+    addon: Arc<Addon>,
+    database: Arc<Database>,
+) -> Codes {
+    let manager = match manager(project) {
+        Ok(manager) => manager,
+        Err(lint_errors) => return lint_errors,
+    };
+    manager.run(&(addon, database), project, None, usage)
+}
+
+fn manager(project: Option<&ProjectConfig>) -> Result<LintManager<LintData>, Codes> {
     let mut manager: LintManager<LintData> = LintManager::new(
         project.map_or_else(Default::default, |project| project.lints().sqf().clone()),
     );
-    if let Err(lint_errors) =
-        manager.extend(SQF_LINTS.iter().map(|l| (**l).clone()).collect::<Vec<_>>())
-    {
-        return lint_errors;
-    }
-    if let Err(lint_errors) = manager.push_group(
+    manager.extend(SQF_LINTS.iter().map(|l| (**l).clone()).collect::<Vec<_>>())?;
+    manager.push_group(
         vec![
             Arc::new(Box::new(LintS02EventUnknown)),
             Arc::new(Box::new(LintS02EventIncorrectCommand)),
             Arc::new(Box::new(LintS02EventInsufficientVersion)),
         ],
         Box::new(EventHandlerRunner),
-    ) {
-        return lint_errors;
-    }
-    statements.analyze(&(addon, database), project, processed, &manager)
+    )?;
+    Ok(manager)
 }
 
 pub type LintData = (Arc<Addon>, Arc<Database>);
@@ -0,0 +1,240 @@
+//! Private variables of a file, checked by `private_shadow` and `unused_private`
+
+use std::{collections::HashMap, ops::Range};
+
+use crate::{BinaryCommand, Expression, Statement, Statements, UnaryCommand};
+
+use super::lints::s25_undefined_variable::{INLINE_BINARY, INLINE_UNARY};
+
+#[derive(Debug, Clone)]
+/// A private that shadows a private of an outer scope
+pub struct Shadow {
+    pub name: String,
+    pub span: Range<usize>,
+    pub outer: Range<usize>,
+    /// The statement without `private`, when it was an assignment
+    pub assignment: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+/// A private that is never read
+pub struct Unused {
+    pub name: String,
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, Default)]
+/// The privates of a file, walked from its root
+pub struct Privates {
+    pub shadows: Vec<Shadow>,
+    pub unused: Vec<Unused>,
+    scopes: Vec<Scope>,
+}
+
+#[derive(Debug, Default)]
+struct Scope {
+    /// Code that is not run immediately, privates outside of it are not shadowed
+    detached: bool,
+    variables: HashMap<String, Declared>,
+}
+
+#[derive(Debug)]
+struct Declared {
+    name: String,
+    span: Range<usize>,
+    read: bool,
+    /// `params` are part of the signature of the code, and are not reported when unused
+    report: bool,
+}
+
+impl Privates {
+    #[must_use]
+    pub fn walk(statements: &Statements) -> Self {
+        let mut privates = Self::default();
+        privates.scope(statements, false);
+        privates.shadows.sort_by_key(|shadow| shadow.span.start);
+        privates.unused.sort_by_key(|unused| unused.span.start);
+        privates
+    }
+
+    fn scope(&mut self, statements: &Statements, detached: bool) {
+        self.scopes.push(Scope {
+            detached,
+            variables: HashMap::new(),
+        });
+        self.statements(statements);
+        let scope = self.scopes.pop().expect("scope was pushed");
+        self.unused.extend(
+            scope
+                .variables
+                .into_values()
+                .filter(|declared| declared.report && !declared.read)
+                .map(|declared| Unused {
+                    name: declared.name,
+                    span: declared.span,
+                }),
+        );
+    }
+
+    fn statements(&mut self, statements: &Statements) {
+        for statement in statements.content() {
+            match statement {
+                Statement::AssignLocal(name, expression, span) => {
+                    self.expression(expression);
+                    let assignment = format!("{name} = {}", expression.source());
+                    self.declare(name, span.clone(), true, Some(assignment));
+                }
+                Statement::AssignGlobal(_, expression, _)
+                | Statement::Expression(expression, _) => {
+                    self.expression(expression);
+                }
+            }
+        }
+    }
+
+    fn expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Variable(name, _) => self.read(name),
+            Expression::Array(items, _) | Expression::ConsumeableArray(items, _) => {
+                for item in items {
+                    self.expression(item);
+                }
+            }
+            Expression::UnaryCommand(command, child, _) => {
+                let name = command.as_str().to_lowercase();
+                if INLINE_UNARY.contains(&name.as_str()) {
+                    self.inline(child);
+                } else {
+                    self.expression(child);
+                }
+                if let UnaryCommand::Named(_) = command {
+                    if name == "private" || name == "params" {
+                        self.define(&name, child);
+                    } else if name == "isnil" {
+                        // `isNil "_name"` reads the variable by name
+                        if let Expression::String(variable, _, _) = &**child {
+                            self.read(variable);
+                        }
+                    }
+                }
+            }
+            Expression::BinaryCommand(command, left, right, _) => {
+                let name = command.as_str().to_lowercase();
+                if matches!(command, BinaryCommand::Associate | BinaryCommand::Else)
+                    || INLINE_BINARY.contains(&name.as_str())
+                {
+                    self.inline(left);
+                    self.inline(right);
+                } else {
+                    self.expression(left);
+                    self.expression(right);
+                }
+                if name == "params" {
+                    self.define(&name, right);
+                }
+            }
+            // Code that is not run immediately may still read the privates around it, when it is called
+            Expression::Code(statements) => self.scope(statements, true),
+            Expression::String(..)
+            | Expression::Number(..)
+            | Expression::Boolean(..)
+            | Expression::NularCommand(..) => {}
+        }
+    }
+
+    /// Walk an argument of a command that runs its code in a new scope inside the current one
+    fn inline(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Code(statements) => self.scope(statements, false),
+            Expression::Array(items, _) => {
+                for item in items {
+                    self.inline(item);
+                }
+            }
+            _ => self.expression(expression),
+        }
+    }
+
+    /// Define the variables of `private` or `params`
+    fn define(&mut self, command: &str, argument: &Expression) {
+        let names = match argument {
+            Expression::String(name, span, _) if command == "private" => {
+                vec![(name, span.clone())]
+            }
+            Expression::Array(items, _) => items
+                .iter()
+                .filter_map(|item| match item {
+                    Expression::String(name, span, _) => Some((name, span.clone())),
+                    Expression::Array(param, _) if command == "params" => match param.first() {
+                        Some(Expression::String(name, span, _)) => Some((name, span.clone())),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect(),
+            _ => return,
+        };
+        for (name, span) in names {
+            // `params [""]` skips an argument
+            if name.starts_with('_') {
+                self.declare(name, span, command == "private", None);
+            }
+        }
+    }
+
+    fn declare(
+        &mut self,
+        name: &str,
+        span: Range<usize>,
+        report: bool,
+        assignment: Option<String>,
+    ) {
+        let lower = name.to_lowercase();
+        for (depth, scope) in self.scopes.iter().rev().enumerate() {
+            if depth > 0 {
+                if let Some(outer) = scope.variables.get(&lower) {
+                    self.shadows.push(Shadow {
+                        name: name.to_string(),
+                        span: span.clone(),
+                        outer: outer.span.clone(),
+                        assignment,
+                    });
+                    break;
+                }
+            }
+            // Privates outside of detached code are not shadowed
+            if scope.detached {
+                break;
+            }
+        }
+        let scope = self.scopes.last_mut().expect("a scope is always open");
+        let previous = scope.variables.insert(
+            lower,
+            Declared {
+                name: name.to_string(),
+                span,
+                read: false,
+                report,
+            },
+        );
+        // Declaring a private again in the same scope replaces the first declaration
+        if let Some(previous) = previous.filter(|previous| previous.report && !previous.read) {
+            self.unused.push(Unused {
+                name: previous.name,
+                span: previous.span,
+            });
+        }
+    }
+
+    fn read(&mut self, name: &str) {
+        let lower = name.to_lowercase();
+        if let Some(declared) = self
+            .scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.variables.get_mut(&lower))
+        {
+            declared.read = true;
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Maps the instructions of a [`Compiled`] script back to the original source files,
+//! before they were preprocessed.
+//!
+//! Each code constant is identified by its path through the constants, starting with its index
+//! in [`Compiled::constants_cache`], followed by the indices inside any arrays containing it.
+
+use std::sync::Arc;
+
+use hemtt_workspace::reporting::Processed;
+use serde::Serialize;
+
+use super::serializer::{Compiled, Constant, Instruction, SourceInfo};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Debug information for a compiled script
+pub struct DebugMap {
+    /// The index of the code constant that is run when the script is called
+    pub entry_point: u16,
+    /// The original files referenced by the instructions
+    pub files: Vec<Arc<str>>,
+    /// The code constants of the script
+    pub code: Vec<DebugCode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Debug information for a single code constant
+pub struct DebugCode {
+    /// The path to the code constant
+    pub constant: Vec<usize>,
+    /// The instructions with a location in the source
+    pub instructions: Vec<DebugInstruction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// The original location of a single instruction
+pub struct DebugInstruction {
+    /// The index of the instruction in its code constant
+    pub index: usize,
+    /// The byte offset in the preprocessed script, as stored in the instruction
+    pub offset: u32,
+    /// The index of the original file in [`DebugMap::files`]
+    pub file: usize,
+    /// The line in the original file, starting at 1
+    pub line: usize,
+    /// The column in the original file, starting at 0
+    pub column: usize,
+}
+
+impl Compiled {
+    #[must_use]
+    /// Maps the instructions of this script back to the original source files
+    pub fn debug_map(&self, processed: &Processed) -> DebugMap {
+        let mut builder = Builder {
+            processed,
+            chars: processed
+                .as_str()
+                .char_indices()
+                .map(|(byte, _)| byte)
+                .collect(),
+            files: Vec::new(),
+            code: Vec::new(),
+        };
+        for (index, constant) in self.constants_cache.iter().enumerate() {
+            builder.constant(constant, vec![index]);
+        }
+        DebugMap {
+            entry_point: self.entry_point,
+            files: builder.files,
+            code: builder.code,
+        }
+    }
+}
+
+struct Builder<'a> {
+    processed: &'a Processed,
+    /// The byte offset of each char in the processed output
+    chars: Vec<usize>,
+    files: Vec<Arc<str>>,
+    code: Vec<DebugCode>,
+}
+
+impl Builder<'_> {
+    fn constant(&mut self, constant: &Constant, path: Vec<usize>) {
+        match constant {
+            Constant::Code(instructions) => {
+                let instructions = instructions
+                    .contents
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, instruction)| self.instruction(index, instruction))
+                    .collect();
+                self.code.push(DebugCode {
+                    constant: path,
+                    instructions,
+                });
+            }
+            Constant::Array(items) | Constant::ConsumeableArray(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    let mut path = path.clone();
+                    path.push(index);
+                    self.constant(item, path);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn instruction(&mut self, index: usize, instruction: &Instruction) -> Option<DebugInstruction> {
+        let info = match instruction {
+            Instruction::CallUnary(_, info)
+            | Instruction::CallBinary(_, info)
+            | Instruction::CallNular(_, info)
+            | Instruction::AssignTo(_, info)
+            | Instruction::AssignToLocal(_, info)
+            | Instruction::GetVariable(_, info) => info,
+            // Arrays made from constants have no location
+            Instruction::MakeArray(_, info) if *info != SourceInfo::default() => info,
+            _ => return None,
+        };
+        let offset = self
+            .chars
+            .binary_search(&(info.offset as usize))
+            .unwrap_or_else(|offset| offset);
+        let original = self.processed.mapping(offset)?.original();
+        let path: Arc<str> = original.path().as_str().into();
+        let file = self
+            .files
+            .iter()
+            .position(|file| file == &path)
+            .unwrap_or_else(|| {
+                self.files.push(path);
+                self.files.len() - 1
+            });
+        Some(DebugInstruction {
+            index,
+            offset: info.offset,
+            file,
+            line: original.start().line(),
+            column: original.start().column(),
+        })
+    }
+}
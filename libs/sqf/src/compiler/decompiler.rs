@@ -0,0 +1,284 @@
+//! Reconstructs [`Statements`] from a [`Compiled`] script.
+//!
+//! The instructions are replayed against a stack of [`Expression`]s, the same way the game would
+//! evaluate them, which gives back the tree that was compiled. Compiled scripts do not keep the
+//! original spans, so the spans of the reconstructed tree are the byte offsets from each instruction's
+//! debug information, or empty when none is available.
+
+use std::{ops::Range, sync::Arc};
+
+use super::serializer::{CodePointer, Compiled, Constant, Instruction, Instructions, SourceInfo};
+use crate::{
+    BinaryCommand, Expression, NularCommand, Scalar, Statement, Statements, StringWrapper,
+    UnaryCommand,
+};
+
+impl Compiled {
+    /// Converts this compiled script back into a statements list, starting at the entry point.
+    ///
+    /// # Errors
+    /// [`DecompileError`] is returned if the script references missing constants or names,
+    /// or if the instructions do not form valid statements.
+    pub fn decompile(&self) -> DecompileResult<Statements> {
+        let Some(Constant::Code(entry_point)) = self.get_constant(self.entry_point) else {
+            return Err(DecompileError::InvalidEntryPoint(self.entry_point));
+        };
+        let root_source = match entry_point.source_pointer {
+            CodePointer::Constant(index) => match u16::try_from(index)
+                .ok()
+                .and_then(|index| self.get_constant(index))
+            {
+                Some(Constant::String(source)) => Some(source.clone()),
+                _ => None,
+            },
+            CodePointer::Source { .. } => None,
+        };
+        Decompiler {
+            compiled: self,
+            root_source,
+        }
+        .statements(entry_point)
+    }
+}
+
+struct Decompiler<'a> {
+    compiled: &'a Compiled,
+    /// The source of the entry point, nested code blocks point into it
+    root_source: Option<Arc<str>>,
+}
+
+impl Decompiler<'_> {
+    fn statements(&self, instructions: &Instructions) -> DecompileResult<Statements> {
+        let mut content = Vec::new();
+        let mut stack = Vec::new();
+        for instruction in &instructions.contents {
+            match *instruction {
+                Instruction::EndStatement => {
+                    if let Some(statement) = Self::finish_statement(&mut stack)? {
+                        content.push(statement);
+                    }
+                }
+                Instruction::Push(index) => {
+                    stack.push(self.constant(self.get_constant(index)?)?);
+                }
+                Instruction::CallNular(index, info) => {
+                    stack.push(Expression::NularCommand(
+                        NularCommand {
+                            name: self.get_name(index)?.to_string(),
+                        },
+                        span(info),
+                    ));
+                }
+                Instruction::CallUnary(index, info) => {
+                    let child = pop(&mut stack, instruction)?;
+                    stack.push(Expression::UnaryCommand(
+                        unary_command(self.get_name(index)?),
+                        Box::new(child),
+                        span(info),
+                    ));
+                }
+                Instruction::CallBinary(index, info) => {
+                    let right = pop(&mut stack, instruction)?;
+                    let left = pop(&mut stack, instruction)?;
+                    stack.push(Expression::BinaryCommand(
+                        binary_command(self.get_name(index)?),
+                        Box::new(left),
+                        Box::new(right),
+                        span(info),
+                    ));
+                }
+                Instruction::AssignTo(index, info) | Instruction::AssignToLocal(index, info) => {
+                    let value = pop(&mut stack, instruction)?;
+                    if !stack.is_empty() {
+                        return Err(DecompileError::UnusedValues(stack.len()));
+                    }
+                    let name = self.get_name(index)?.to_string();
+                    content.push(if matches!(instruction, Instruction::AssignTo(..)) {
+                        Statement::AssignGlobal(name, value, span(info))
+                    } else {
+                        Statement::AssignLocal(name, value, span(info))
+                    });
+                }
+                Instruction::GetVariable(index, info) => {
+                    stack.push(Expression::Variable(
+                        self.get_name(index)?.to_string(),
+                        span(info),
+                    ));
+                }
+                Instruction::MakeArray(len, info) => {
+                    let len = usize::from(len);
+                    if stack.len() < len {
+                        return Err(DecompileError::StackUnderflow(instruction.name()));
+                    }
+                    let items = stack.split_off(stack.len() - len);
+                    stack.push(Expression::Array(items, array_span(info)));
+                }
+            }
+        }
+        if let Some(statement) = Self::finish_statement(&mut stack)? {
+            content.push(statement);
+        }
+
+        let (source, span) = match instructions.source_pointer {
+            CodePointer::Source { offset, length } => {
+                let (offset, length) = (offset as usize, length as usize);
+                let source = self
+                    .root_source
+                    .as_ref()
+                    .and_then(|root| root.get(offset..offset + length))
+                    .map_or_else(|| render(&content), Arc::from);
+                (source, offset..offset + length)
+            }
+            CodePointer::Constant(_) => (
+                self.root_source.clone().unwrap_or_else(|| render(&content)),
+                0..0,
+            ),
+        };
+        Ok(Statements {
+            content,
+            source,
+            span,
+        })
+    }
+
+    fn finish_statement(stack: &mut Vec<Expression>) -> DecompileResult<Option<Statement>> {
+        match stack.len() {
+            0 => Ok(None),
+            1 => {
+                let expression = stack.pop().expect("stack has one expression");
+                let span = expression.span();
+                Ok(Some(Statement::Expression(expression, span)))
+            }
+            len => Err(DecompileError::UnusedValues(len)),
+        }
+    }
+
+    fn constant(&self, constant: &Constant) -> DecompileResult<Expression> {
+        Ok(match constant {
+            Constant::Code(instructions) => Expression::Code(self.statements(instructions)?),
+            Constant::String(string) => {
+                Expression::String(string.clone(), 0..0, StringWrapper::DoubleQuote)
+            }
+            Constant::Scalar(number) => Expression::Number(Scalar(*number), 0..0),
+            Constant::Boolean(boolean) => Expression::Boolean(*boolean, 0..0),
+            Constant::Array(items) => Expression::Array(
+                items
+                    .iter()
+                    .map(|item| self.constant(item))
+                    .collect::<DecompileResult<_>>()?,
+                array_span(SourceInfo::default()),
+            ),
+            Constant::ConsumeableArray(items) => Expression::ConsumeableArray(
+                items
+                    .iter()
+                    .map(|item| self.constant(item))
+                    .collect::<DecompileResult<_>>()?,
+                array_span(SourceInfo::default()),
+            ),
+            Constant::NularCommand(command) => Expression::NularCommand(
+                NularCommand {
+                    name: command.to_string(),
+                },
+                0..0,
+            ),
+        })
+    }
+
+    fn get_constant(&self, index: u16) -> DecompileResult<&Constant> {
+        self.compiled
+            .get_constant(index)
+            .ok_or(DecompileError::InvalidConstantIndex(index))
+    }
+
+    fn get_name(&self, index: u16) -> DecompileResult<&Arc<str>> {
+        self.compiled
+            .get_name(index)
+            .ok_or(DecompileError::InvalidNameIndex(index))
+    }
+}
+
+fn pop(stack: &mut Vec<Expression>, instruction: &Instruction) -> DecompileResult<Expression> {
+    stack
+        .pop()
+        .ok_or_else(|| DecompileError::StackUnderflow(instruction.name()))
+}
+
+const fn span(info: SourceInfo) -> Range<usize> {
+    info.offset as usize..info.offset as usize
+}
+
+/// Array spans exclude the opening bracket, see [`Expression::span`]
+const fn array_span(info: SourceInfo) -> Range<usize> {
+    info.offset as usize + 1..info.offset as usize + 1
+}
+
+fn unary_command(name: &str) -> UnaryCommand {
+    match name {
+        "+" => UnaryCommand::Plus,
+        "-" => UnaryCommand::Minus,
+        "!" => UnaryCommand::Not,
+        _ => UnaryCommand::Named(name.to_string()),
+    }
+}
+
+fn binary_command(name: &str) -> BinaryCommand {
+    match name {
+        "||" | "or" => BinaryCommand::Or,
+        "&&" | "and" => BinaryCommand::And,
+        "==" => BinaryCommand::Eq,
+        "!=" => BinaryCommand::NotEq,
+        ">" => BinaryCommand::Greater,
+        "<" => BinaryCommand::Less,
+        ">=" => BinaryCommand::GreaterEq,
+        "<=" => BinaryCommand::LessEq,
+        ">>" => BinaryCommand::ConfigPath,
+        ":" => BinaryCommand::Associate,
+        "else" => BinaryCommand::Else,
+        "+" => BinaryCommand::Add,
+        "-" => BinaryCommand::Sub,
+        "max" => BinaryCommand::Max,
+        "min" => BinaryCommand::Min,
+        "*" => BinaryCommand::Mul,
+        "/" => BinaryCommand::Div,
+        "%" => BinaryCommand::Rem,
+        "mod" => BinaryCommand::Mod,
+        "atan2" => BinaryCommand::Atan2,
+        "^" => BinaryCommand::Exp,
+        "#" => BinaryCommand::Select,
+        _ => BinaryCommand::Named(name.to_string()),
+    }
+}
+
+/// Renders statements as source, used when the original source is not available
+fn render(content: &[Statement]) -> Arc<str> {
+    content
+        .iter()
+        .map(|statement| match statement {
+            Statement::AssignGlobal(name, expression, _) => {
+                format!("{name} = {}", expression.source())
+            }
+            Statement::AssignLocal(name, expression, _) => {
+                format!("private {name} = {}", expression.source())
+            }
+            Statement::Expression(expression, _) => expression.source(),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+        .into()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DecompileError {
+    #[error("entry point {0} is not a code constant")]
+    InvalidEntryPoint(u16),
+    #[error("invalid constant index {0}, not found in compiled context")]
+    InvalidConstantIndex(u16),
+    #[error("invalid command/name index {0}, not found in compiled context")]
+    InvalidNameIndex(u16),
+    #[error("{0} expected a value on the stack")]
+    StackUnderflow(&'static str),
+    #[error("statement left {0} values on the stack")]
+    UnusedValues(usize),
+}
+
+type DecompileResult<T> = Result<T, DecompileError>;
@@ -7,8 +7,11 @@
 //! which can generate these lists automatically.
 //!
 //! The main entrypoint to this is the [`Statements`][crate::Statements] struct, which can be
-//! converted to a serializable [`Compiled`] via [`Statements::compile`][crate::Statements].
+//! converted to a serializable [`Compiled`] via [`Statements::compile`][crate::Statements],
+//! and converted back with [`Compiled::decompile`].
 
+pub mod debug;
+pub mod decompiler;
 pub mod optimizer;
 pub mod serializer;
 
@@ -17,26 +20,28 @@ use std::{ops::Range, sync::Arc};
 use hemtt_workspace::reporting::Processed;
 use serializer::CodePointer;
 
-use self::serializer::{Compiled, Constant, Instruction, Instructions, SourceInfo};
+use self::serializer::{Compiled, Constant, Instruction, Instructions, Revision, SourceInfo};
 use crate::{Error, Expression, Statement, Statements};
 
 impl Statements {
     /// Converts this statements list into a [`Compiled`].
     /// A file name must be provided for debugging purposes.
+    /// Only features of the given [`Revision`] are used.
     ///
     /// # Errors
     /// [`CompileError`] is returned if the statements list contains an invalid name.
-    pub fn compile(&self, processed: &Processed) -> CompileResult<Compiled> {
+    pub fn compile(&self, processed: &Processed, revision: Revision) -> CompileResult<Compiled> {
         let mut ctx = Context {
             constants_cache: Vec::new(),
             names_cache: Vec::new(),
+            revision,
         };
         let entrypoint_code = self.compile_to_instructions(processed, &mut ctx, true)?;
         let entrypoint_index = ctx.constants_cache.len() as u16;
         ctx.constants_cache.push(Constant::Code(entrypoint_code));
         Ok(Compiled {
             entry_point: entrypoint_index,
-            constants_cache_compression: true,
+            constants_cache_compression: revision.compressed_constants(),
             constants_cache: ctx.constants_cache,
             names_cache: ctx.names_cache,
             file_names: processed
@@ -54,9 +59,12 @@ impl Statements {
     pub fn compile_to_writer(
         &self,
         processed: &Processed,
+        revision: Revision,
         mut writer: impl std::io::Write,
     ) -> Result<(), Error> {
-        Ok(self.compile(processed)?.serialize(&mut writer)?)
+        Ok(self
+            .compile(processed, revision)?
+            .serialize(&mut writer, revision)?)
     }
 
     pub(crate) fn compile_to_instructions(
@@ -186,10 +194,10 @@ impl Expression {
                 push_constant(constant, instructions, ctx)?;
             }
             None => match *self {
-                Self::ConsumeableArray(..) => {
-                    unreachable!("couldn't make ConsumeableArray a const");
-                }
-                Self::Array(ref array, ref location) => {
+                // A consumable array is only a constant when all of its items are,
+                // `nil` is not with revisions that can't store nular commands as constants
+                Self::ConsumeableArray(ref array, ref location)
+                | Self::Array(ref array, ref location) => {
                     let array_len = array
                         .len()
                         .try_into()
@@ -268,7 +276,9 @@ impl Expression {
                 .map(|value| value.clone().compile_constant(processed, ctx))
                 .collect::<CompileResult<Option<Vec<Constant>>>>()?
                 .map(Constant::ConsumeableArray),
-            Self::NularCommand(ref command, ..) if command.is_constant() => {
+            Self::NularCommand(ref command, ..)
+                if command.is_constant() && ctx.revision.nular_constants() =>
+            {
                 let command = try_normalize_name(&command.name)?;
                 debug_assert_ne!(
                     &*command, "true",
@@ -299,6 +309,7 @@ type CompileResult<T = ()> = Result<T, CompileError>;
 pub(crate) struct Context {
     constants_cache: Vec<Constant>,
     names_cache: Vec<Arc<str>>,
+    revision: Revision,
 }
 
 impl Context {
@@ -1,4 +1,6 @@
 //! Optimizes sqf by evaulating expressions when possible and looking for arrays that can be consumed
+//!
+//! Statements after an unconditional `if (true) exitWith {}` are removed, as they can never run
 //! `ToDo`: what commands consume arrays
 //!
 use crate::{BinaryCommand, Expression, Statement, Statements, UnaryCommand};
@@ -10,7 +12,18 @@ impl Statements {
     /// optimize Statements
     #[must_use]
     pub fn optimize(mut self) -> Self {
-        self.content = self.content.into_iter().map(Statement::optimize).collect();
+        let mut content = Vec::with_capacity(self.content.len());
+        for statement in self.content {
+            let statement = statement.optimize();
+            let exits = statement.is_unconditional_exit();
+            content.push(statement);
+            if exits {
+                #[cfg(debug_assertions)]
+                trace!("optimizing unreachable statements after exitWith");
+                break;
+            }
+        }
+        self.content = content;
         self
     }
 }
@@ -29,6 +42,26 @@ impl Statement {
             Self::Expression(expression, right) => Self::Expression(expression.optimize(), right),
         }
     }
+
+    /// Is the statement `if (true) exitWith {}`, after optimization
+    #[must_use]
+    fn is_unconditional_exit(&self) -> bool {
+        let Self::Expression(
+            Expression::BinaryCommand(BinaryCommand::Named(name), condition, _, _),
+            _,
+        ) = self
+        else {
+            return false;
+        };
+        if !name.eq_ignore_ascii_case("exitwith") {
+            return false;
+        }
+        matches!(
+            &**condition,
+            Expression::UnaryCommand(UnaryCommand::Named(name), value, _)
+                if name.eq_ignore_ascii_case("if") && matches!(**value, Expression::Boolean(true, _))
+        )
+    }
 }
 
 impl Expression {
@@ -16,6 +16,47 @@ use std::sync::Arc;
 
 pub const VERSION: u32 = 1;
 
+/// A revision of the features used in SQFC files, all revisions share the same header [`VERSION`].
+///
+/// Older game branches can not load files that use features from newer revisions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Revision {
+    /// Uncompressed constants, nular commands are always called
+    Initial = 1,
+    #[default]
+    /// Compressed constants, and nular commands with a constant result stored as constants
+    Latest = 2,
+}
+
+impl Revision {
+    #[must_use]
+    /// Returns the revision with the given number, if it exists
+    pub const fn from_number(number: u32) -> Option<Self> {
+        match number {
+            1 => Some(Self::Initial),
+            2 => Some(Self::Latest),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn number(self) -> u32 {
+        self as u32
+    }
+
+    #[must_use]
+    /// Can the constants cache be compressed?
+    pub const fn compressed_constants(self) -> bool {
+        matches!(self, Self::Latest)
+    }
+
+    #[must_use]
+    /// Can nular commands be stored as constants?
+    pub const fn nular_constants(self) -> bool {
+        matches!(self, Self::Latest)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct SourceInfo {
     pub offset: u32,
@@ -292,8 +333,22 @@ impl Constant {
     ///
     /// # Errors
     /// [`std::io::Error`] if an error occurs while writing to the writer.
-    /// [`SerializeError`] if the constant contains an invalid index.
-    pub fn serialize(&self, compiled: &Compiled, writer: &mut impl Write) -> SerializeResult {
+    /// [`SerializeError`] if the constant contains an invalid index,
+    /// or is not supported by the revision.
+    pub fn serialize(
+        &self,
+        compiled: &Compiled,
+        revision: Revision,
+        writer: &mut impl Write,
+    ) -> SerializeResult {
+        if let Self::NularCommand(ref command) = *self {
+            if !revision.nular_constants() {
+                return Err(SerializeError::UnsupportedNularConstant(
+                    command.to_string(),
+                    revision.number(),
+                ));
+            }
+        }
         writer.write_u8(self.to_byte())?;
 
         match *self {
@@ -313,7 +368,7 @@ impl Constant {
                 let array_len = try_truncate_or(array.len(), SerializeError::ArrayTooLong)?;
                 writer.write_u32::<LE>(array_len)?;
                 for constant in array {
-                    constant.serialize(compiled, writer)?;
+                    constant.serialize(compiled, revision, writer)?;
                 }
             }
             Self::NularCommand(ref command) => {
@@ -461,8 +516,9 @@ impl Compiled {
     ///
     /// # Errors
     /// [`std::io::Error`] if an error occurs while writing to the writer.
-    /// [`SerializeError`] if the script contains an invalid index.
-    pub fn serialize(&self, writer: &mut impl Write) -> SerializeResult {
+    /// [`SerializeError`] if the script contains an invalid index,
+    /// or a constant that is not supported by the revision.
+    pub fn serialize(&self, writer: &mut impl Write, revision: Revision) -> SerializeResult {
         // Version info
         writer.write_u32::<LE>(VERSION)?;
 
@@ -479,14 +535,16 @@ impl Compiled {
             Ok(())
         })?;
 
-        if self.constants_cache_compression {
+        if self.constants_cache_compression && revision.compressed_constants() {
             // Constants compressed
             writer.write_u8(BlockType::ConstantsCompressed as u8)?;
-            serialize_compress(writer, |buffer| self.serialize_constants_cache(buffer))?;
+            serialize_compress(writer, |buffer| {
+                self.serialize_constants_cache(buffer, revision)
+            })?;
         } else {
             // Constants
             writer.write_u8(BlockType::Constants as u8)?;
-            self.serialize_constants_cache(writer)?;
+            self.serialize_constants_cache(writer, revision)?;
         };
 
         // Location info
@@ -505,12 +563,16 @@ impl Compiled {
         Ok(())
     }
 
-    fn serialize_constants_cache(&self, writer: &mut impl Write) -> SerializeResult {
+    fn serialize_constants_cache(
+        &self,
+        writer: &mut impl Write,
+        revision: Revision,
+    ) -> SerializeResult {
         let constants_cache_len =
             try_truncate_or(self.constants_cache.len(), SerializeError::ListTooLongBlock)?;
         writer.write_u16::<LE>(constants_cache_len)?;
         for constant in &self.constants_cache {
-            constant.serialize(self, writer)?;
+            constant.serialize(self, revision, writer)?;
         }
 
         Ok(())
@@ -697,6 +759,8 @@ pub enum SerializeError {
     InvalidNameIndex(u16),
     #[error("Lzo error {0}")]
     LzoError(#[from] hemtt_lzo::LzoError),
+    #[error("nular command `{0}` cannot be a constant in sqfc revision {1}")]
+    UnsupportedNularConstant(String, u32),
 }
 
 type SerializeResult<T = ()> = Result<T, SerializeError>;
@@ -7,6 +7,9 @@ pub enum Error {
     CompileError(#[from] crate::compiler::CompileError),
     #[cfg(feature = "compiler")]
     #[error(transparent)]
+    DecompileError(#[from] crate::compiler::decompiler::DecompileError),
+    #[cfg(feature = "compiler")]
+    #[error(transparent)]
     SerializeError(#[from] crate::compiler::serializer::SerializeError),
     #[error("Custom command error: {0}")]
     CustomCommandError(String),
@@ -43,66 +43,69 @@ impl Statements {
 
     #[must_use]
     /// Gets the highest version required by any command in this code chunk.
+    ///
+    /// Commands without a known version, variables, and literals are skipped,
+    /// the arguments of every command and the contents of arrays and code are checked.
     pub fn required_version(&self, database: &Database) -> (String, Version, Range<usize>) {
+        type Usage = (String, Version, Range<usize>);
+        /// The usage with the higher version, the first one if they are equal
+        fn newest(current: Option<Usage>, other: Option<Usage>) -> Option<Usage> {
+            match (current, other) {
+                (Some(current), Some(other)) if other.1 > current.1 => Some(other),
+                (None, other) => other,
+                (current, _) => current,
+            }
+        }
         // TODO can probably replace String with Rc<str>
-        fn extract_expression(
-            expression: &Expression,
-            database: &Database,
-        ) -> Option<(String, Version, Range<usize>)> {
+        fn extract_expression(expression: &Expression, database: &Database) -> Option<Usage> {
+            let command = |command: &str, span: &Range<usize>| {
+                database
+                    .command_version(command)
+                    .map(|version| (command.to_string(), *version, span.clone()))
+            };
             match expression {
-                Expression::NularCommand(command, span) => Some((
-                    command.as_str().to_string(),
-                    *database.command_version(command.as_str())?,
-                    span.clone(),
-                )),
-                Expression::UnaryCommand(command, children, span) => {
-                    let command_version = database.command_version(command.as_str())?;
-                    let left = extract_expression(children, database)?;
-                    let left_version = database.command_version(&left.0)?;
-                    if command_version > left_version {
-                        Some((command.as_str().to_string(), *command_version, span.clone()))
-                    } else {
-                        Some((left.0, left.1, left.2))
-                    }
+                Expression::NularCommand(nular, span) => command(nular.as_str(), span),
+                Expression::UnaryCommand(unary, child, span) => newest(
+                    command(unary.as_str(), span),
+                    extract_expression(child, database),
+                ),
+                Expression::BinaryCommand(binary, left, right, span) => newest(
+                    newest(
+                        command(binary.as_str(), span),
+                        extract_expression(left, database),
+                    ),
+                    extract_expression(right, database),
+                ),
+                Expression::Array(items, _) | Expression::ConsumeableArray(items, _) => {
+                    items.iter().fold(None, |found, item| {
+                        newest(found, extract_expression(item, database))
+                    })
                 }
-                Expression::BinaryCommand(command, left, right, span) => {
-                    let command_version = database.command_version(command.as_str())?;
-                    let left = extract_expression(left, database)?;
-                    let left_version = database.command_version(&left.0)?;
-                    let right = extract_expression(right, database)?;
-                    let right_version = database.command_version(&right.0)?;
-                    if command_version > left_version && command_version > right_version {
-                        Some((command.as_str().to_string(), *command_version, span.clone()))
-                    } else if left_version > right_version {
-                        Some((left.0, left.1, left.2))
+                Expression::Code(statements) => {
+                    let usage = statements.required_version(database);
+                    if usage.0.is_empty() {
+                        None
                     } else {
-                        Some((right.0, right.1, right.2))
+                        Some(usage)
                     }
                 }
-                Expression::Code(statements) => {
-                    let (command, version, span) = statements.required_version(database);
-                    Some((command.as_str().to_string(), version, span))
-                }
-                _ => None,
+                Expression::String(..)
+                | Expression::Number(..)
+                | Expression::Boolean(..)
+                | Expression::Variable(..) => None,
             }
         }
-        let mut version = Version::new(0, 0);
-        let mut span = 0..0;
-        let mut command = String::new();
+        let mut found = None;
         for statement in &self.content {
-            if let Some((used_command, command_version, command_span)) = match statement {
+            match statement {
                 Statement::AssignGlobal(_, expression, _)
                 | Statement::AssignLocal(_, expression, _)
-                | Statement::Expression(expression, _) => extract_expression(expression, database),
-            } {
-                if command_version > version {
-                    command = used_command.to_string();
-                    version = command_version;
-                    span = command_span.clone();
+                | Statement::Expression(expression, _) => {
+                    found = newest(found, extract_expression(expression, database));
                 }
             }
         }
-        (command, version, span)
+        found.unwrap_or_else(|| (String::new(), Version::new(0, 0), 0..0))
     }
 }
 
@@ -4,8 +4,18 @@ use std::sync::Arc;
 
 use hemtt_common::config::ProjectConfig;
 use hemtt_preprocessor::Processor;
-use hemtt_sqf::{analyze::analyze, parser::database::Database};
-use hemtt_workspace::{addons::Addon, reporting::WorkspaceFiles, LayerType};
+use hemtt_sqf::{
+    analyze::{
+        analyze, analyze_functions,
+        functions::{scan, FunctionUsage},
+    },
+    parser::database::Database,
+};
+use hemtt_workspace::{
+    addons::Addon,
+    reporting::{Codes, WorkspaceFiles},
+    LayerType,
+};
 
 const ROOT: &str = "tests/lints/";
 
@@ -38,8 +48,75 @@ lint!(s21_invalid_comparisons);
 lint!(s22_this_call);
 lint!(s23_reassign_reserved_variable);
 lint!(s24_marker_spam);
+lint!(s25_undefined_variable);
+lint!(s26_string_comparison);
+lint!(s27_command_arguments);
+lint!(s29_unlocalized_string);
+lint!(s30_s31_privates);
+lint!(s32_special_variables);
+lint!(s33_event_handler_string);
+
+#[test]
+fn simple_s28_unused_function() {
+    let file = "s28_unused_function";
+    let folder = std::path::PathBuf::from(ROOT);
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&folder, LayerType::Source)
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let source = workspace.join(format!("{file}.sqf")).unwrap();
+    let processed = Processor::run(&source).unwrap();
+    let database = Arc::new(Database::a3(false));
+    let config = ProjectConfig::from_file(&folder.join(format!("{file}.toml"))).unwrap();
+    let sqf = hemtt_sqf::parser::run(&database, &processed).unwrap();
+
+    let addon = Addon::test_addon();
+    let mut usage = FunctionUsage::new();
+    let (declared, references) = scan(&sqf, &processed);
+    for function in declared {
+        usage.declare(addon.name(), function);
+    }
+    for name in references {
+        usage.reference(&name, source.as_str());
+    }
+    let workspace_files = WorkspaceFiles::new();
+    let output = analyze_functions(&usage, Some(&config), Arc::new(addon), database)
+        .iter()
+        .map(|e| e.diagnostic().unwrap().to_string(&workspace_files))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace('\r', "");
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn fix_s04_command_case() {
+    let (_, codes) = codes("s04_command_case");
+    let fixes = codes
+        .iter()
+        .filter_map(|code| code.fix())
+        .map(|fix| (fix.original().to_string(), fix.replacement().to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fixes,
+        vec![
+            ("surfaceiswater".to_string(), "surfaceIsWater".to_string()),
+            ("Player".to_string(), "player".to_string()),
+        ]
+    );
+}
 
 fn lint(file: &str) -> String {
+    let (workspace_files, codes) = codes(file);
+    codes
+        .iter()
+        .map(|e| e.diagnostic().unwrap().to_string(&workspace_files))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace('\r', "")
+}
+
+fn codes(file: &str) -> (WorkspaceFiles, Codes) {
     let folder = std::path::PathBuf::from(ROOT);
     let workspace = hemtt_workspace::Workspace::builder()
         .physical(&folder, LayerType::Source)
@@ -50,7 +127,12 @@ fn lint(file: &str) -> String {
     let database = Arc::new(Database::a3(false));
     let workspace_files = WorkspaceFiles::new();
 
-    let config_path_full = std::path::PathBuf::from(ROOT).join("project_tests.toml");
+    // Lints that are disabled by default are enabled by a config next to the test
+    let config_path_full = [format!("{file}.toml"), "project_tests.toml".to_string()]
+        .into_iter()
+        .map(|config| folder.join(config))
+        .find(|config| config.exists())
+        .unwrap();
     let config = ProjectConfig::from_file(&config_path_full).unwrap();
 
     match hemtt_sqf::parser::run(&database, &processed) {
@@ -62,12 +144,7 @@ fn lint(file: &str) -> String {
                 Arc::new(Addon::test_addon()),
                 database.clone(),
             );
-            codes
-                .iter()
-                .map(|e| e.diagnostic().unwrap().to_string(&workspace_files))
-                .collect::<Vec<_>>()
-                .join("\n")
-                .replace('\r', "")
+            (workspace_files, codes)
         }
         Err(hemtt_sqf::parser::ParserError::ParsingError(e)) => {
             for error in e {
@@ -17,6 +17,7 @@ macro_rules! optimize {
 }
 
 optimize!(consume_array);
+optimize!(exit_with);
 optimize!(static_math);
 optimize!(scalar);
 optimize!(string_case);
@@ -3,7 +3,11 @@
 use std::path::PathBuf;
 
 use hemtt_preprocessor::Processor;
-use hemtt_sqf::{parser::database::Database, Statement};
+use hemtt_sqf::{
+    compiler::serializer::{Constant, Revision},
+    parser::database::Database,
+    Expression, Statement,
+};
 use hemtt_workspace::{reporting::WorkspaceFiles, LayerType};
 
 const ROOT: &str = "tests/simple/";
@@ -32,6 +36,86 @@ simple!(include);
 simple!(oneline);
 simple!(semicolons);
 
+#[test]
+fn debug_map() {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&PathBuf::from(ROOT), LayerType::Source)
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let source = workspace.join("debug_map.sqf").unwrap();
+    let processed = Processor::run(&source).unwrap();
+    let parsed = hemtt_sqf::parser::run(&Database::a3(false), &processed).unwrap();
+    let map = parsed
+        .compile(&processed, Revision::Latest)
+        .unwrap()
+        .debug_map(&processed);
+    insta::assert_debug_snapshot!(map);
+}
+
+#[test]
+fn revision_initial() {
+    fn nular(constant: &Constant) -> bool {
+        match constant {
+            Constant::NularCommand(_) => true,
+            Constant::Array(items) | Constant::ConsumeableArray(items) => items.iter().any(nular),
+            _ => false,
+        }
+    }
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&PathBuf::from(ROOT), LayerType::Source)
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let source = workspace.join("revision.sqf").unwrap();
+    let processed = Processor::run(&source).unwrap();
+    let parsed = hemtt_sqf::parser::run(&Database::a3(false), &processed).unwrap();
+
+    let latest = parsed.compile(&processed, Revision::Latest).unwrap();
+    assert!(latest.constants_cache.iter().any(nular));
+    assert!(latest
+        .serialize(&mut Vec::new(), Revision::Initial)
+        .is_err());
+
+    let initial = parsed.compile(&processed, Revision::Initial).unwrap();
+    assert!(!initial.constants_cache_compression);
+    assert!(!initial.constants_cache.iter().any(nular));
+    let decompiled = initial.decompile().unwrap();
+    assert_eq!(render(decompiled.content()), render(parsed.content()));
+    let mut buffer = Vec::new();
+    initial.serialize(&mut buffer, Revision::Initial).unwrap();
+    assert_eq!(&buffer[..4], &1u32.to_le_bytes());
+}
+
+#[test]
+fn revision_initial_optimized() {
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&PathBuf::from(ROOT), LayerType::Source)
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let source = workspace.join("revision_optimized.sqf").unwrap();
+    let processed = Processor::run(&source).unwrap();
+    let parsed = hemtt_sqf::parser::run(&Database::a3(false), &processed)
+        .unwrap()
+        .optimize();
+
+    // `nil` can't be a constant, so the consumable arrays are built on use
+    let initial = parsed.compile(&processed, Revision::Initial).unwrap();
+    assert!(!initial
+        .constants_cache
+        .iter()
+        .any(|constant| matches!(constant, Constant::ConsumeableArray(_))));
+    let decompiled = initial.decompile().unwrap();
+    assert_eq!(render(decompiled.content()), render(parsed.content()));
+    initial
+        .serialize(&mut Vec::new(), Revision::Initial)
+        .unwrap();
+
+    let latest = parsed.compile(&processed, Revision::Latest).unwrap();
+    assert!(latest
+        .constants_cache
+        .iter()
+        .any(|constant| matches!(constant, Constant::ConsumeableArray(_))));
+}
+
 fn simple(file: &str) -> (Vec<u8>, Vec<Statement>) {
     let workspace = hemtt_workspace::Workspace::builder()
         .physical(&PathBuf::from(ROOT), LayerType::Source)
@@ -57,8 +141,16 @@ fn simple(file: &str) -> (Vec<u8>, Vec<Statement>) {
         Err(e) => panic!("{e:?}"),
     };
     assert_ne!(parsed.content().len(), 0);
+    let decompiled = parsed
+        .compile(&processed, Revision::Latest)
+        .unwrap()
+        .decompile()
+        .unwrap();
+    assert_eq!(render(decompiled.content()), render(parsed.content()));
     let mut buffer = Vec::new();
-    parsed.compile_to_writer(&processed, &mut buffer).unwrap();
+    parsed
+        .compile_to_writer(&processed, Revision::Latest, &mut buffer)
+        .unwrap();
     std::fs::write(format!("tests/simple/{file}.sqfc"), &buffer).unwrap();
     std::fs::write(
         format!("tests/simple/{file}.sqfast"),
@@ -67,3 +159,39 @@ fn simple(file: &str) -> (Vec<u8>, Vec<Statement>) {
     .unwrap();
     (buffer, parsed.content().to_vec())
 }
+
+/// Renders statements ignoring spans, original source, and the case of names
+fn render(statements: &[Statement]) -> String {
+    fn expression(value: &Expression) -> String {
+        match value {
+            Expression::Code(code) => format!("{{{}}}", render(code.content())),
+            Expression::String(string, _, _) => format!("\"{string}\""),
+            Expression::Array(items, _) | Expression::ConsumeableArray(items, _) => format!(
+                "[{}]",
+                items.iter().map(expression).collect::<Vec<_>>().join(",")
+            ),
+            Expression::UnaryCommand(command, child, _) => {
+                format!("{} ({})", command.as_str(), expression(child))
+            }
+            Expression::BinaryCommand(command, left, right, _) => format!(
+                "({}) {} ({})",
+                expression(left),
+                command.as_str(),
+                expression(right)
+            ),
+            _ => value.source(),
+        }
+    }
+    statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::AssignGlobal(name, value, _) => format!("{name} = {}", expression(value)),
+            Statement::AssignLocal(name, value, _) => {
+                format!("private {name} = {}", expression(value))
+            }
+            Statement::Expression(value, _) => expression(value),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+        .to_lowercase()
+}
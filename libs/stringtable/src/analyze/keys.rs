@@ -0,0 +1,225 @@
+//! Keys defined by the stringtables of a project, and the keys used by its configs and scripts
+
+use std::{collections::HashSet, ops::Range};
+
+use hemtt_workspace::{reporting::Processed, WorkspacePath};
+
+use crate::analyze::{lints::l01_sorted::StringtableData, scan::scan};
+
+#[derive(Debug, Clone)]
+/// A key, and where it is defined or used
+pub struct KeyLocation {
+    key: String,
+    file: WorkspacePath,
+    span: Range<usize>,
+}
+
+impl KeyLocation {
+    #[must_use]
+    pub const fn new(key: String, file: WorkspacePath, span: Range<usize>) -> Self {
+        Self { key, file, span }
+    }
+
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    #[must_use]
+    pub const fn file(&self) -> &WorkspacePath {
+        &self.file
+    }
+
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+#[derive(Debug, Default)]
+/// The keys defined by each stringtable, and the keys used by each processed file
+pub struct KeyUsage {
+    defined: Vec<KeyLocation>,
+    references: Vec<KeyLocation>,
+    /// The lowercase keys of [`Self::defined`]
+    defined_keys: HashSet<String>,
+    /// The lowercase keys of [`Self::references`]
+    used_keys: HashSet<String>,
+    /// Only some files of the project were checked
+    partial: bool,
+}
+
+impl KeyUsage {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the keys defined by the stringtables
+    pub fn define(&mut self, stringtables: &[StringtableData]) {
+        for (_, path, existing) in stringtables {
+            for key in scan(existing) {
+                if key.id.is_empty() {
+                    continue;
+                }
+                self.defined_keys.insert(key.id.to_lowercase());
+                self.defined.push(KeyLocation {
+                    key: key.id,
+                    file: path.clone(),
+                    span: key.span,
+                });
+            }
+        }
+    }
+
+    /// Add the keys used by a processed config or script
+    pub fn reference(&mut self, processed: &Processed) {
+        self.extend(references(processed));
+    }
+
+    /// Add keys used by the configs and scripts
+    pub fn extend(&mut self, references: impl IntoIterator<Item = KeyLocation>) {
+        for reference in references {
+            self.used_keys.insert(reference.key.to_lowercase());
+            self.references.push(reference);
+        }
+    }
+
+    /// Only some files of the project were checked, so keys can not be reported as unused
+    pub const fn set_partial(&mut self) {
+        self.partial = true;
+    }
+
+    #[must_use]
+    /// Were only some files of the project checked
+    pub const fn is_partial(&self) -> bool {
+        self.partial
+    }
+
+    #[must_use]
+    /// The keys defined by the stringtables
+    pub fn defined(&self) -> &[KeyLocation] {
+        &self.defined
+    }
+
+    #[must_use]
+    /// The keys used by the configs and scripts
+    pub fn references(&self) -> &[KeyLocation] {
+        &self.references
+    }
+
+    #[must_use]
+    /// Is the key defined by any stringtable
+    pub fn is_defined(&self, key: &str) -> bool {
+        self.defined_keys.contains(&key.to_lowercase())
+    }
+
+    #[must_use]
+    /// Is the key used by any config or script
+    pub fn is_used(&self, key: &str) -> bool {
+        self.used_keys.contains(&key.to_lowercase())
+    }
+}
+
+#[must_use]
+/// The keys used by a processed config or script
+///
+/// A key is used when it follows a `$`, as in configs and `localize "$STR_..."`,
+/// or when it is the whole content of a string, as in `localize "STR_..."`
+pub fn references(processed: &Processed) -> Vec<KeyLocation> {
+    let chars = processed.as_str().chars().collect::<Vec<_>>();
+    let mut references = Vec::new();
+    let mut start = 0;
+    while start + 4 <= chars.len() {
+        if !chars[start..start + 4]
+            .iter()
+            .map(char::to_ascii_lowercase)
+            .eq("str_".chars())
+        {
+            start += 1;
+            continue;
+        }
+        let end = start
+            + chars[start..]
+                .iter()
+                .take_while(|c| c.is_ascii_alphanumeric() || **c == '_')
+                .count();
+        let before = start.checked_sub(1).map(|i| chars[i]);
+        let used = match before {
+            Some('$') => true,
+            Some(quote @ ('"' | '\'')) => chars.get(end) == Some(&quote),
+            _ => false,
+        };
+        if used {
+            if let Some((file, span)) = locate(processed, start, end) {
+                references.push(KeyLocation {
+                    key: chars[start..end].iter().collect(),
+                    file,
+                    span,
+                });
+            }
+        }
+        start = end;
+    }
+    references
+}
+
+/// Find a key in the original source, from its position in the processed output
+///
+/// A key from a macro, such as `LSTRING(Name)`, points at the whole macro call
+fn locate(
+    processed: &Processed,
+    start: usize,
+    end: usize,
+) -> Option<(WorkspacePath, Range<usize>)> {
+    let mappings = processed.mappings(start);
+    // The outermost macro is the last of the widest macro mappings
+    if let Some(call) = mappings
+        .iter()
+        .rev()
+        .filter(|map| map.was_macro())
+        .min_by_key(|map| map.processed_start().offset())
+    {
+        let (file, source) = processed.source(call.source())?;
+        return Some((
+            file.clone(),
+            call.original_start()..call_end(source, call.original_end()),
+        ));
+    }
+    let first = mappings
+        .into_iter()
+        .max_by_key(|map| map.processed_start().offset())?;
+    let last = processed
+        .mappings(end - 1)
+        .into_iter()
+        .max_by_key(|map| map.processed_start().offset())?;
+    if first.source() != last.source() {
+        return None;
+    }
+    let (file, _) = processed.source(first.source())?;
+    Some((file.clone(), first.original_start()..last.original_end()))
+}
+
+/// The end of the arguments of a macro call, or the end of its name when it has none
+fn call_end(source: &str, name_end: usize) -> usize {
+    let Some(arguments) = source.get(name_end..) else {
+        return name_end;
+    };
+    if !arguments.starts_with('(') {
+        return name_end;
+    }
+    let mut depth = 0;
+    for (i, c) in arguments.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return name_end + i + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    name_end
+}
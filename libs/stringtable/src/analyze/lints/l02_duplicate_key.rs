@@ -0,0 +1,145 @@
+use std::{collections::HashMap, ops::Range, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Severity},
+    WorkspacePath,
+};
+
+use crate::analyze::{lints::l01_sorted::StringtableData, scan::scan, LintData};
+
+crate::analyze::lint!(LintL02DuplicateKey);
+
+impl Lint<LintData> for LintL02DuplicateKey {
+    fn ident(&self) -> &'static str {
+        "duplicate_key"
+    }
+
+    fn sort(&self) -> u32 {
+        20
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for keys that are defined more than once"
+    }
+
+    fn documentation(&self) -> &'static str {
+        "Keys are case insensitive and shared between every stringtable in the game, only one of the definitions will be used."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = Vec<StringtableData>;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &Vec<StringtableData>,
+        _data: &LintData,
+    ) -> Codes {
+        let mut codes: Codes = Vec::new();
+        let mut seen: HashMap<String, (WorkspacePath, Range<usize>)> = HashMap::new();
+        for (_, path, existing) in target {
+            for key in scan(existing) {
+                if key.id.is_empty() {
+                    continue;
+                }
+                if let Some(first) = seen.get(&key.id.to_lowercase()) {
+                    codes.push(Arc::new(CodeStringtableDuplicateKey::new(
+                        key.id,
+                        (path.clone(), key.span),
+                        first.clone(),
+                        config.severity(),
+                    )));
+                } else {
+                    seen.insert(key.id.to_lowercase(), (path.clone(), key.span));
+                }
+            }
+        }
+        codes
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeStringtableDuplicateKey {
+    id: String,
+    duplicate: (WorkspacePath, Range<usize>),
+    first: (WorkspacePath, Range<usize>),
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeStringtableDuplicateKey {
+    fn ident(&self) -> &'static str {
+        "L-L02"
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("Key `{}` is defined more than once", self.id)
+    }
+
+    fn label_message(&self) -> String {
+        "duplicate key".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("keys are case insensitive, only one definition will be used".to_string())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("remove or rename one of the keys".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeStringtableDuplicateKey {
+    #[must_use]
+    pub fn new(
+        id: String,
+        duplicate: (WorkspacePath, Range<usize>),
+        first: (WorkspacePath, Range<usize>),
+        severity: Severity,
+    ) -> Self {
+        Self {
+            id,
+            duplicate,
+            first,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(
+            Diagnostic::from_code(&self)
+                .with_label(
+                    Label::primary(self.duplicate.0.clone(), self.duplicate.1.clone())
+                        .with_message(self.label_message()),
+                )
+                .with_label(
+                    Label::secondary(self.first.0.clone(), self.first.1.clone())
+                        .with_message("first defined here"),
+                ),
+        );
+        self
+    }
+}
@@ -0,0 +1,129 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Severity},
+    WorkspacePath,
+};
+
+use crate::analyze::{lints::l01_sorted::StringtableData, scan::scan, LintData};
+
+crate::analyze::lint!(LintL03NoOriginal);
+
+impl Lint<LintData> for LintL03NoOriginal {
+    fn ident(&self) -> &'static str {
+        "no_original"
+    }
+
+    fn sort(&self) -> u32 {
+        30
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for keys without an `Original` or `English` entry"
+    }
+
+    fn documentation(&self) -> &'static str {
+        "The game falls back to `Original`, or `English`, when a key has no entry for the selected language. A key without either will show nothing in the missing languages."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = StringtableData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &StringtableData,
+        _data: &LintData,
+    ) -> Codes {
+        let (_, path, existing) = target;
+        scan(existing)
+            .into_iter()
+            .filter(|key| {
+                !key.entries.iter().any(|(name, _)| {
+                    name.eq_ignore_ascii_case("original") || name.eq_ignore_ascii_case("english")
+                })
+            })
+            .map(|key| {
+                Arc::new(CodeStringtableNoOriginal::new(
+                    key.id,
+                    path.clone(),
+                    key.span,
+                    config.severity(),
+                )) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeStringtableNoOriginal {
+    id: String,
+    path: WorkspacePath,
+    span: Range<usize>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeStringtableNoOriginal {
+    fn ident(&self) -> &'static str {
+        "L-L03"
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("Key `{}` has no `Original` entry", self.id)
+    }
+
+    fn label_message(&self) -> String {
+        "no `Original` or `English`".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("languages without an entry will show nothing".to_string())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("add an `Original` entry to the key".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeStringtableNoOriginal {
+    #[must_use]
+    pub fn new(id: String, path: WorkspacePath, span: Range<usize>, severity: Severity) -> Self {
+        Self {
+            id,
+            path,
+            span,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self).with_label(
+            Label::primary(self.path.clone(), self.span.clone()).with_message(self.label_message()),
+        ));
+        self
+    }
+}
@@ -0,0 +1,147 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Severity},
+    WorkspacePath,
+};
+
+use crate::{
+    analyze::{lints::l01_sorted::StringtableData, scan::scan, LintData},
+    ALL_LANGUAGES,
+};
+
+crate::analyze::lint!(LintL04InvalidLanguage);
+
+impl Lint<LintData> for LintL04InvalidLanguage {
+    fn ident(&self) -> &'static str {
+        "invalid_language"
+    }
+
+    fn sort(&self) -> u32 {
+        40
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for entries that are not a supported language"
+    }
+
+    fn documentation(&self) -> &'static str {
+        "Entries of a key must be `Original` or one of the languages from the [Arma 3 Wiki](https://community.bistudio.com/wiki/Stringtable.xml#Supported_Languages), anything else is ignored by the game."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = StringtableData;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &hemtt_common::config::LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &StringtableData,
+        _data: &LintData,
+    ) -> Codes {
+        let (_, path, existing) = target;
+        let mut codes: Codes = Vec::new();
+        for key in scan(existing) {
+            for (language, span) in key.entries {
+                if language.eq_ignore_ascii_case("original")
+                    || ALL_LANGUAGES
+                        .iter()
+                        .any(|known| known.eq_ignore_ascii_case(&language))
+                {
+                    continue;
+                }
+                codes.push(Arc::new(CodeStringtableInvalidLanguage::new(
+                    language,
+                    key.id.clone(),
+                    path.clone(),
+                    span,
+                    config.severity(),
+                )));
+            }
+        }
+        codes
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeStringtableInvalidLanguage {
+    language: String,
+    id: String,
+    path: WorkspacePath,
+    span: Range<usize>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeStringtableInvalidLanguage {
+    fn ident(&self) -> &'static str {
+        "L-L04"
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "Key `{}` has an entry for an unknown language `{}`",
+            self.id, self.language
+        )
+    }
+
+    fn label_message(&self) -> String {
+        "unknown language".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("the entry is ignored by the game".to_string())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some(format!("supported languages are `Original`, `{}`", ALL_LANGUAGES.join("`, `")))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeStringtableInvalidLanguage {
+    #[must_use]
+    pub fn new(
+        language: String,
+        id: String,
+        path: WorkspacePath,
+        span: Range<usize>,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            language,
+            id,
+            path,
+            span,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self).with_label(
+            Label::primary(self.path.clone(), self.span.clone()).with_message(self.label_message()),
+        ));
+        self
+    }
+}
@@ -0,0 +1,149 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::{config::LintConfig, similar_values};
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Severity},
+    WorkspacePath,
+};
+
+use crate::analyze::{
+    keys::{KeyLocation, KeyUsage},
+    LintData,
+};
+
+crate::analyze::lint!(LintL05UndefinedKey);
+
+impl Lint<LintData> for LintL05UndefinedKey {
+    fn ident(&self) -> &'static str {
+        "undefined_key"
+    }
+
+    fn sort(&self) -> u32 {
+        50
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for keys of the project that are used but not defined in any stringtable"
+    }
+
+    fn documentation(&self) -> &'static str {
+        "Keys used by configs, with `$STR_`, and by scripts, with `localize`, are checked against the stringtables of the project. Only keys starting with `STR_` and the prefix of the project are checked, keys from other mods and the game are not reported. A key that is not defined will show as an empty string, or as the key itself."
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::error()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = KeyUsage;
+    fn run(
+        &self,
+        project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &KeyUsage,
+        _data: &LintData,
+    ) -> Codes {
+        let Some(project) = project else {
+            return Vec::new();
+        };
+        let prefix = format!("str_{}_", project.prefix().to_lowercase());
+        let defined = target
+            .defined()
+            .iter()
+            .map(KeyLocation::key)
+            .collect::<Vec<_>>();
+        target
+            .references()
+            .iter()
+            .filter(|r| r.key().to_lowercase().starts_with(&prefix) && !target.is_defined(r.key()))
+            .map(|r| {
+                Arc::new(CodeStringtableUndefinedKey::new(
+                    r.key().to_string(),
+                    r.file().clone(),
+                    r.span(),
+                    similar_values(r.key(), &defined)
+                        .into_iter()
+                        .map(std::string::ToString::to_string)
+                        .collect(),
+                    config.severity(),
+                )) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeStringtableUndefinedKey {
+    id: String,
+    path: WorkspacePath,
+    span: Range<usize>,
+    similar: Vec<String>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeStringtableUndefinedKey {
+    fn ident(&self) -> &'static str {
+        "L-L05"
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("Key `{}` is not defined in any stringtable", self.id)
+    }
+
+    fn label_message(&self) -> String {
+        "undefined key".to_string()
+    }
+
+    fn help(&self) -> Option<String> {
+        if self.similar.is_empty() {
+            Some("add the key to a stringtable of the project".to_string())
+        } else {
+            Some(format!("did you mean `{}`?", self.similar.join("`, `")))
+        }
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeStringtableUndefinedKey {
+    #[must_use]
+    pub fn new(
+        id: String,
+        path: WorkspacePath,
+        span: Range<usize>,
+        similar: Vec<String>,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            id,
+            path,
+            span,
+            similar,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self).with_label(
+            Label::primary(self.path.clone(), self.span.clone()).with_message(self.label_message()),
+        ));
+        self
+    }
+}
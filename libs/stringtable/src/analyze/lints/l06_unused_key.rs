@@ -0,0 +1,158 @@
+use std::{ops::Range, sync::Arc};
+
+use hemtt_common::config::LintConfig;
+use hemtt_workspace::{
+    lint::{AnyLintRunner, Lint, LintRunner},
+    reporting::{Code, Codes, Diagnostic, Label, Severity},
+    WorkspacePath,
+};
+
+use crate::analyze::{keys::KeyUsage, LintData};
+
+crate::analyze::lint!(LintL06UnusedKey);
+
+impl Lint<LintData> for LintL06UnusedKey {
+    fn ident(&self) -> &'static str {
+        "unused_key"
+    }
+
+    fn sort(&self) -> u32 {
+        60
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for keys that are defined but never used"
+    }
+
+    fn documentation(&self) -> &'static str {
+        r#"Keys defined in the stringtables of the project are checked against the keys used by its configs and scripts.
+
+### Configuration
+
+- **ignore**: Keys to ignore, a key ending with `*` ignores every key starting with it
+
+```toml
+[lints.stringtables.unused_key]
+options.ignore = [
+    "STR_ABE_Main_Dynamic_*",
+]
+```
+
+### Explanation
+
+Keys built at runtime, such as `localize format ["STR_ABE_Main_%1", _name]`, can not be found, add them to `ignore`."#
+    }
+
+    fn default_config(&self) -> LintConfig {
+        LintConfig::warning()
+    }
+
+    fn runners(&self) -> Vec<Box<dyn AnyLintRunner<LintData>>> {
+        vec![Box::new(Runner)]
+    }
+}
+
+pub struct Runner;
+impl LintRunner<LintData> for Runner {
+    type Target = KeyUsage;
+    fn run(
+        &self,
+        _project: Option<&hemtt_common::config::ProjectConfig>,
+        config: &LintConfig,
+        _processed: Option<&hemtt_workspace::reporting::Processed>,
+        target: &KeyUsage,
+        _data: &LintData,
+    ) -> Codes {
+        if target.is_partial() {
+            return Vec::new();
+        }
+        let ignore = if let Some(toml::Value::Array(ignore)) = config.option("ignore") {
+            ignore
+                .iter()
+                .filter_map(|i| i.as_str().map(str::to_lowercase))
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+        let ignored = |key: &str| {
+            let key = key.to_lowercase();
+            ignore.iter().any(|i| {
+                i.strip_suffix('*')
+                    .map_or_else(|| *i == key, |prefix| key.starts_with(prefix))
+            })
+        };
+        target
+            .defined()
+            .iter()
+            .filter(|d| !ignored(d.key()) && !target.is_used(d.key()))
+            .map(|d| {
+                Arc::new(CodeStringtableUnusedKey::new(
+                    d.key().to_string(),
+                    d.file().clone(),
+                    d.span(),
+                    config.severity(),
+                )) as Arc<dyn Code>
+            })
+            .collect()
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct CodeStringtableUnusedKey {
+    id: String,
+    path: WorkspacePath,
+    span: Range<usize>,
+    severity: Severity,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl Code for CodeStringtableUnusedKey {
+    fn ident(&self) -> &'static str {
+        "L-L06"
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn message(&self) -> String {
+        format!("Key `{}` is never used", self.id)
+    }
+
+    fn label_message(&self) -> String {
+        "unused key".to_string()
+    }
+
+    fn note(&self) -> Option<String> {
+        Some("no config or script of the project uses this key".to_string())
+    }
+
+    fn help(&self) -> Option<String> {
+        Some("remove the key, or add it to `ignore` if it is built at runtime".to_string())
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        self.diagnostic.clone()
+    }
+}
+
+impl CodeStringtableUnusedKey {
+    #[must_use]
+    pub fn new(id: String, path: WorkspacePath, span: Range<usize>, severity: Severity) -> Self {
+        Self {
+            id,
+            path,
+            span,
+            severity,
+            diagnostic: None,
+        }
+        .generate_processed()
+    }
+
+    fn generate_processed(mut self) -> Self {
+        self.diagnostic = Some(Diagnostic::from_code(&self).with_label(
+            Label::primary(self.path.clone(), self.span.clone()).with_message(self.label_message()),
+        ));
+        self
+    }
+}
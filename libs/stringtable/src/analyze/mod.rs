@@ -1,7 +1,11 @@
 use hemtt_common::config::ProjectConfig;
 use hemtt_workspace::{lint::LintManager, lint_manager, reporting::Codes};
+use keys::KeyUsage;
 use lints::l01_sorted::StringtableData;
 
+pub mod keys;
+mod scan;
+
 pub mod lints {
     automod::dir!(pub "src/analyze/lints");
 }
@@ -40,3 +44,20 @@ pub fn lint_all(addons: &Vec<StringtableData>, project: Option<&ProjectConfig>)
     }
     manager.run(&LintData {}, project, None, addons)
 }
+
+#[must_use]
+/// Check the keys used by the project against the keys defined by its stringtables
+pub fn lint_usage(usage: &KeyUsage, project: Option<&ProjectConfig>) -> Codes {
+    let mut manager = LintManager::new(project.map_or_else(Default::default, |project| {
+        project.lints().stringtables().clone()
+    }));
+    if let Err(e) = manager.extend(
+        STRINGTABLE_LINTS
+            .iter()
+            .map(|l| (**l).clone())
+            .collect::<Vec<_>>(),
+    ) {
+        return e;
+    }
+    manager.run(&LintData {}, project, None, usage)
+}
@@ -0,0 +1,73 @@
+use std::ops::Range;
+
+use quick_xml::{events::Event, Reader};
+
+/// A `Key` as it is written in the stringtable
+///
+/// Serde drops the positions and any unknown elements,
+/// so lints that need to point at the source scan the raw XML
+pub struct ScannedKey {
+    pub id: String,
+    /// The opening tag of the key
+    pub span: Range<usize>,
+    /// The name of each entry, and the span of its opening tag
+    pub entries: Vec<(String, Range<usize>)>,
+}
+
+/// Find every `Key` in a stringtable
+///
+/// Stops at the first XML error, parsing has already reported it
+pub fn scan(source: &str) -> Vec<ScannedKey> {
+    let mut reader = Reader::from_str(source);
+    let mut keys = Vec::new();
+    let mut current: Option<ScannedKey> = None;
+    let mut depth = 0;
+    loop {
+        let start = usize::try_from(reader.buffer_position()).expect("position fits in usize");
+        let event = match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(event) => event,
+        };
+        let end = usize::try_from(reader.buffer_position()).expect("position fits in usize");
+        let (tag, empty) = match event {
+            Event::Start(tag) => (tag, false),
+            Event::Empty(tag) => (tag, true),
+            Event::End(_) => {
+                if depth > 0 {
+                    depth -= 1;
+                } else if let Some(key) = current.take() {
+                    keys.push(key);
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        let name = String::from_utf8_lossy(tag.name().as_ref()).to_string();
+        if let Some(key) = &mut current {
+            if depth == 0 {
+                key.entries.push((name, start..end));
+            }
+            if !empty {
+                depth += 1;
+            }
+        } else if name == "Key" {
+            let id = tag
+                .try_get_attribute("ID")
+                .ok()
+                .flatten()
+                .and_then(|id| id.unescape_value().ok().map(|id| id.to_string()))
+                .unwrap_or_default();
+            let key = ScannedKey {
+                id,
+                span: start..end,
+                entries: Vec::new(),
+            };
+            if empty {
+                keys.push(key);
+            } else {
+                current = Some(key);
+            }
+        }
+    }
+    keys
+}
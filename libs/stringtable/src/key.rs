@@ -12,6 +12,27 @@ where
     }
 }
 
+macro_rules! languages {
+    ($($lang:ident),*) => {
+        /// The entry for a language, by its name in any case
+        ///
+        /// Returns `None` if the language is not supported
+        fn entry(&self, language: &str) -> Option<&Option<String>> {
+            match language.to_lowercase().as_str() {
+                $(stringify!($lang) => Some(&self.$lang),)*
+                _ => None,
+            }
+        }
+
+        fn entry_mut(&mut self, language: &str) -> Option<&mut Option<String>> {
+            match language.to_lowercase().as_str() {
+                $(stringify!($lang) => Some(&mut self.$lang),)*
+                _ => None,
+            }
+        }
+    };
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Key {
@@ -98,11 +119,56 @@ pub struct Key {
 }
 
 impl Key {
+    languages!(
+        original,
+        english,
+        czech,
+        french,
+        spanish,
+        italian,
+        polish,
+        portuguese,
+        russian,
+        german,
+        korean,
+        japanese,
+        chinese,
+        chinesesimp,
+        turkish,
+        swedish,
+        slovak,
+        serbocroatian,
+        norwegian,
+        icelandic,
+        hungarian,
+        greek,
+        finnish,
+        dutch,
+        ukrainian,
+        danish
+    );
+
     #[must_use]
     pub fn id(&self) -> &str {
         &self.id
     }
 
+    #[must_use]
+    /// The entry for `Original` or a language, by its name in any case
+    pub fn get(&self, language: &str) -> Option<&str> {
+        self.entry(language).and_then(Option::as_deref)
+    }
+
+    /// Set or remove the entry for `Original` or a language, by its name in any case
+    ///
+    /// Returns `false` if the language is not supported
+    pub fn set(&mut self, language: &str, value: Option<String>) -> bool {
+        self.entry_mut(language).is_some_and(|entry| {
+            *entry = value;
+            true
+        })
+    }
+
     #[must_use]
     pub fn original(&self) -> Option<&str> {
         self.original.as_deref()
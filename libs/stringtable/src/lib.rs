@@ -15,7 +15,7 @@ pub use totals::Totals;
 use tracing::error;
 
 /// Languages in className format
-static ALL_LANGUAGES: [&str; 25] = [
+pub static ALL_LANGUAGES: [&str; 25] = [
     "English",
     "Czech",
     "French",
@@ -65,6 +65,10 @@ impl Project {
         &self.packages
     }
 
+    pub fn packages_mut(&mut self) -> &mut [Package] {
+        &mut self.packages
+    }
+
     pub fn sort(&mut self) {
         self.packages.sort_by(|a, b| a.name().cmp(b.name()));
         for package in &mut self.packages {
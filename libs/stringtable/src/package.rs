@@ -30,6 +30,14 @@ impl Package {
         &self.containers
     }
 
+    pub fn keys_mut(&mut self) -> &mut [Key] {
+        &mut self.keys
+    }
+
+    pub fn containers_mut(&mut self) -> &mut [Self] {
+        &mut self.containers
+    }
+
     pub fn sort(&mut self) {
         self.keys.sort_by(|a, b| a.id().cmp(b.id()));
         self.containers.sort_by(|a, b| a.name().cmp(b.name()));
@@ -0,0 +1,28 @@
+#![allow(clippy::unwrap_used)]
+
+use std::io::BufReader;
+
+use hemtt_stringtable::Project;
+
+#[test]
+fn edit_keeps_comments() {
+    let mut stringtable = Project::from_reader(BufReader::new(
+        std::fs::File::open("tests/sort/comments.xml").unwrap(),
+    ))
+    .unwrap();
+
+    let key = &mut stringtable.packages_mut()[0].keys_mut()[1];
+    assert_eq!(key.id(), "HasComment");
+    assert_eq!(key.get("english"), Some("This is a string with a comment"));
+    assert!(key.set("French", Some("Ceci est une chaîne".to_string())));
+    assert!(key.set("GERMAN", None));
+    assert!(!key.set("Klingon", Some("tlhIngan".to_string())));
+    assert_eq!(key.get("French"), Some("Ceci est une chaîne"));
+    assert_eq!(key.get("German"), None);
+    assert_eq!(key.get("Klingon"), None);
+
+    let mut out = String::new();
+    stringtable.to_writer(&mut out).unwrap();
+
+    insta::assert_snapshot!(out);
+}
@@ -2,8 +2,10 @@
 
 use std::io::BufReader;
 
+use hemtt_common::config::ProjectConfig;
+use hemtt_preprocessor::Processor;
 use hemtt_stringtable::{
-    analyze::{lint_all, lint_one},
+    analyze::{keys::KeyUsage, lint_all, lint_one, lint_usage},
     Project,
 };
 use hemtt_workspace::{
@@ -25,6 +27,38 @@ macro_rules! lint {
 }
 
 lint!(l01_sorted);
+lint!(l02_duplicate_key);
+lint!(l03_no_original);
+lint!(l04_invalid_language);
+
+#[test]
+fn usage_l05_l06_keys() {
+    let folder = std::path::PathBuf::from(ROOT).join("l05_l06_keys");
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&folder, LayerType::Source)
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let config = ProjectConfig::from_file(&folder.join("project.toml")).unwrap();
+    let source = workspace.join("stringtable.xml").unwrap();
+    let existing = source.read_to_string().expect("vfs issue");
+    let stringtable = Project::from_reader(BufReader::new(existing.as_bytes())).unwrap();
+
+    let mut usage = KeyUsage::new();
+    usage.define(&[(stringtable, source, existing)]);
+    for file in ["config.cpp", "script.sqf"] {
+        let processed = Processor::run(&workspace.join(file).unwrap()).unwrap();
+        usage.reference(&processed);
+    }
+
+    let workspace_files = WorkspaceFiles::new();
+    let output = lint_usage(&usage, Some(&config))
+        .iter()
+        .map(|e| e.diagnostic().unwrap().to_string(&workspace_files))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace('\r', "");
+    insta::assert_snapshot!(output);
+}
 
 fn lint(file: &str) -> String {
     let folder = std::path::PathBuf::from(ROOT);
@@ -40,10 +74,10 @@ fn lint(file: &str) -> String {
 
     let mut codes: Codes = Vec::new();
     codes.extend(lint_one(
-        &(stringtable.clone(), workspace.clone(), existing.clone()),
+        &(stringtable.clone(), source.clone(), existing.clone()),
         None,
     ));
-    codes.extend(lint_all(&vec![(stringtable, workspace, existing)], None));
+    codes.extend(lint_all(&vec![(stringtable, source, existing)], None));
 
     codes
         .iter()
@@ -0,0 +1,11 @@
+#define LSTRING(var) QUOTE(DOUBLES($STR_ABE_Main,var))
+#define QUOTE(var) #var
+#define DOUBLES(a,b) a##_##b
+
+class CfgVehicles {
+    class Car {
+        displayName = "$STR_ABE_Main_Title";
+        description = LSTRING(Missing);
+        author = "$STR_A3_Author";
+    };
+};
@@ -5,7 +5,7 @@ use std::sync::{Arc, RwLock};
 use std::{fs::DirEntry, str::FromStr};
 
 use hemtt_common::config::AddonConfig;
-use hemtt_common::prefix::{Prefix, FILES};
+use hemtt_common::prefix::{self, Prefix};
 use hemtt_common::version::Version;
 use tracing::{trace, warn};
 
@@ -21,6 +21,8 @@ pub enum Error {
     LocationInvalid(String),
     #[error("Optional addon not found: {0}")]
     OptionalNotFound(String),
+    #[error("Addon not found: {0}")]
+    NotFound(String),
     #[error("Addon prefix not found: {0}")]
     PrefixMissing(String),
 }
@@ -54,26 +56,18 @@ impl Addon {
                 }
             },
             prefix: {
-                let mut prefix = None;
-                let mut files = FILES
-                    .iter()
-                    .map(std::string::ToString::to_string)
+                let mut files = std::fs::read_dir(&path)?
+                    .filter_map(std::result::Result::ok)
+                    .filter_map(|entry| {
+                        let file = entry.path();
+                        prefix::precedence(file.file_name()?.to_str()?).map(|p| (p, file))
+                    })
                     .collect::<Vec<_>>();
-                files.append(
-                    &mut FILES
-                        .iter()
-                        .map(|f| f.to_uppercase())
-                        .collect::<Vec<String>>(),
-                );
-                'search: for file in &files {
-                    let path = path.join(file);
-                    if path.exists() {
-                        let content = std::fs::read_to_string(path)?;
-                        prefix = Some(Prefix::new(&content)?);
-                        break 'search;
-                    }
-                }
-                prefix.ok_or_else(|| Error::PrefixMissing(name.clone()))?
+                files.sort();
+                let Some((_, file)) = files.first() else {
+                    return Err(Error::PrefixMissing(name).into());
+                };
+                Prefix::new(&std::fs::read_to_string(file)?)?
             },
             location,
             name,
@@ -234,10 +228,117 @@ impl Display for Location {
 }
 
 type RequiredVersion = (Version, WorkspacePath, Range<usize>);
+/// A class in `CfgPatches`, and the patches in its `requiredAddons[]`
+pub type Patch = (String, Vec<String>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A function declared by `CfgFunctions`, or compiled by a `PREP` macro
+pub struct DeclaredFunction {
+    name: String,
+    source: Option<String>,
+    file: WorkspacePath,
+    span: Range<usize>,
+}
+
+impl DeclaredFunction {
+    #[must_use]
+    pub const fn new(
+        name: String,
+        source: Option<String>,
+        file: WorkspacePath,
+        span: Range<usize>,
+    ) -> Self {
+        Self {
+            name,
+            source,
+            file,
+            span,
+        }
+    }
+
+    #[must_use]
+    /// The name of the function, such as `abe_main_fnc_init`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    /// The path of the script, as written in the declaration
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    #[must_use]
+    /// The file the function is declared in
+    pub const fn file(&self) -> &WorkspacePath {
+        &self.file
+    }
+
+    #[must_use]
+    /// The span of the declaration in [`Self::file`]
+    pub const fn span(&self) -> &Range<usize> {
+        &self.span
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A sound shader used by a sound set in `CfgSoundSets`
+pub struct SoundShaderReference {
+    shader: String,
+    sound_set: String,
+    file: WorkspacePath,
+    span: Range<usize>,
+}
+
+impl SoundShaderReference {
+    #[must_use]
+    pub const fn new(
+        shader: String,
+        sound_set: String,
+        file: WorkspacePath,
+        span: Range<usize>,
+    ) -> Self {
+        Self {
+            shader,
+            sound_set,
+            file,
+            span,
+        }
+    }
+
+    #[must_use]
+    /// The name of the sound shader
+    pub fn shader(&self) -> &str {
+        &self.shader
+    }
+
+    #[must_use]
+    /// The name of the sound set using the shader
+    pub fn sound_set(&self) -> &str {
+        &self.sound_set
+    }
+
+    #[must_use]
+    /// The file the shader is used in
+    pub const fn file(&self) -> &WorkspacePath {
+        &self.file
+    }
+
+    #[must_use]
+    /// The span of the shader name in [`Self::file`]
+    pub const fn span(&self) -> &Range<usize> {
+        &self.span
+    }
+}
 
 #[derive(Debug, Clone, Default)]
 pub struct BuildData {
     required_version: Arc<RwLock<Option<RequiredVersion>>>,
+    config_functions: Arc<RwLock<Vec<DeclaredFunction>>>,
+    config_references: Arc<RwLock<Vec<String>>>,
+    patches: Arc<RwLock<Vec<Patch>>>,
+    sound_shaders: Arc<RwLock<Vec<String>>>,
+    sound_shader_references: Arc<RwLock<Vec<SoundShaderReference>>>,
 }
 
 impl BuildData {
@@ -245,6 +346,11 @@ impl BuildData {
     pub fn new() -> Self {
         Self {
             required_version: Arc::new(RwLock::new(None)),
+            config_functions: Arc::new(RwLock::new(Vec::new())),
+            config_references: Arc::new(RwLock::new(Vec::new())),
+            patches: Arc::new(RwLock::new(Vec::new())),
+            sound_shaders: Arc::new(RwLock::new(Vec::new())),
+            sound_shader_references: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -273,6 +379,104 @@ impl BuildData {
             .write()
             .expect("the required version lock is poisoned") = Some((version, file, line));
     }
+
+    #[must_use]
+    /// Fetches the functions declared in `CfgFunctions` of the addon's `config.cpp`
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned
+    pub fn config_functions(&self) -> Vec<DeclaredFunction> {
+        self.config_functions
+            .read()
+            .expect("the config functions lock is poisoned")
+            .clone()
+    }
+
+    #[must_use]
+    /// Fetches the function names used in the strings of the addon's `config.cpp`
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned
+    pub fn config_references(&self) -> Vec<String> {
+        self.config_references
+            .read()
+            .expect("the config references lock is poisoned")
+            .clone()
+    }
+
+    /// Sets the functions declared and used by the addon's `config.cpp`
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned
+    pub fn set_config_functions(&self, functions: Vec<DeclaredFunction>, references: Vec<String>) {
+        *self
+            .config_functions
+            .write()
+            .expect("the config functions lock is poisoned") = functions;
+        *self
+            .config_references
+            .write()
+            .expect("the config references lock is poisoned") = references;
+    }
+
+    #[must_use]
+    /// Fetches the classes in `CfgPatches` of the addon's `config.cpp`
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned
+    pub fn patches(&self) -> Vec<Patch> {
+        self.patches
+            .read()
+            .expect("the patches lock is poisoned")
+            .clone()
+    }
+
+    /// Sets the classes in `CfgPatches` of the addon's `config.cpp`
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned
+    pub fn set_patches(&self, patches: Vec<Patch>) {
+        *self.patches.write().expect("the patches lock is poisoned") = patches;
+    }
+
+    #[must_use]
+    /// Fetches the classes in `CfgSoundShaders` of the addon's `config.cpp`
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned
+    pub fn sound_shaders(&self) -> Vec<String> {
+        self.sound_shaders
+            .read()
+            .expect("the sound shaders lock is poisoned")
+            .clone()
+    }
+
+    #[must_use]
+    /// Fetches the sound shaders used in `CfgSoundSets` of the addon's `config.cpp`
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned
+    pub fn sound_shader_references(&self) -> Vec<SoundShaderReference> {
+        self.sound_shader_references
+            .read()
+            .expect("the sound shader references lock is poisoned")
+            .clone()
+    }
+
+    /// Sets the sound shaders declared and used by the addon's `config.cpp`
+    ///
+    /// # Panics
+    /// Panics if the lock is poisoned
+    pub fn set_sound_shaders(&self, shaders: Vec<String>, references: Vec<SoundShaderReference>) {
+        *self
+            .sound_shaders
+            .write()
+            .expect("the sound shaders lock is poisoned") = shaders;
+        *self
+            .sound_shader_references
+            .write()
+            .expect("the sound shader references lock is poisoned") = references;
+    }
 }
 
 mod test_helper {
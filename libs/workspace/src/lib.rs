@@ -4,7 +4,7 @@ use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use hemtt_common::{
     config::{PDriveOption, ProjectConfig},
-    prefix::{Prefix, FILES},
+    prefix::{self, Prefix},
 };
 use pdrive::PDrive;
 use tracing::trace;
@@ -35,9 +35,11 @@ pub struct Workspace {
     pub(crate) layers: Vec<(VfsPath, LayerType)>,
     pub(crate) project: Option<ProjectConfig>,
     pub(crate) pointers: HashMap<String, VfsPath>,
+    pub(crate) mounts: Vec<(String, VfsPath)>,
     pub(crate) addons: Vec<VfsPath>,
     pub(crate) missions: Vec<VfsPath>,
     pub(crate) pdrive: Option<PDrive>,
+    pub(crate) defines: Vec<String>,
 }
 
 impl Workspace {
@@ -59,6 +61,33 @@ impl Workspace {
         self.pdrive.as_ref()
     }
 
+    #[must_use]
+    /// Returns the macros defined for every preprocessed file, as `NAME` or `NAME=VALUE`
+    pub fn defines(&self) -> &[String] {
+        &self.defines
+    }
+
+    /// Remove every file written to the memory layer, so a rebuild only sees the files on disk
+    ///
+    /// # Errors
+    /// [`Error::Vfs`] if a file could not be removed
+    pub fn clear_memory(&self) -> Result<(), Error> {
+        for (layer, _) in self
+            .layers
+            .iter()
+            .filter(|(_, layer_type)| *layer_type == LayerType::Memory)
+        {
+            for entry in layer.read_dir()? {
+                if entry.is_dir()? {
+                    entry.remove_dir_all()?;
+                } else {
+                    entry.remove_file()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Create a new workspace from a vfs path
     ///
     /// # Errors
@@ -66,15 +95,19 @@ impl Workspace {
     pub fn create(
         vfs: VfsPath,
         layers: Vec<(VfsPath, LayerType)>,
+        mounts: Vec<(String, VfsPath)>,
         project: Option<ProjectConfig>,
         discovery: bool,
         pdrive: &PDriveOption,
+        defines: Vec<String>,
     ) -> Result<WorkspacePath, Error> {
         let mut workspace = Self {
             vfs,
             layers,
             project,
+            defines,
             pointers: HashMap::new(),
+            mounts,
             addons: Vec::new(),
             missions: Vec::new(),
             pdrive: if pdrive == &PDriveOption::Require {
@@ -118,7 +151,15 @@ impl Workspace {
                         self.missions.push(entry);
                     }
                     _ => {
-                        if FILES.contains(&entry.filename().to_lowercase().as_str()) {
+                        if let Some(precedence) = prefix::precedence(&entry.filename()) {
+                            // only the highest precedence prefix file in a folder is used
+                            if entry.parent().read_dir()?.any(|other| {
+                                prefix::precedence(&other.filename())
+                                    .is_some_and(|p| p < precedence)
+                            }) {
+                                trace!("Ignoring lower precedence prefix: {:?}", entry);
+                                continue;
+                            }
                             trace!("Prefix: {:?}", entry);
                             let prefix = Prefix::new(&entry.read_to_string()?)?;
                             let prefix_str = format!(
@@ -147,6 +188,8 @@ pub enum LayerType {
     Source,
     Include,
     Build,
+    /// Files written during a build, only kept in memory
+    Memory,
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -154,6 +197,8 @@ pub enum LayerType {
 /// A workspace builder
 pub struct WorkspaceBuilder {
     layers: Vec<(VfsPath, LayerType)>,
+    mounts: Vec<(String, VfsPath)>,
+    defines: Vec<String>,
 }
 
 impl WorkspaceBuilder {
@@ -167,10 +212,34 @@ impl WorkspaceBuilder {
         self
     }
 
+    #[must_use]
+    /// Mount a physical folder at a prefix, such as `\x\cba`,
+    /// for absolute includes that are not found in the workspace
+    pub fn mount(mut self, prefix: &str, path: &PathBuf) -> Self {
+        let prefix = prefix.replace('\\', "/").trim_matches('/').to_lowercase();
+        self.mounts.push((
+            if prefix.is_empty() {
+                prefix
+            } else {
+                format!("/{prefix}")
+            },
+            AltrootFS::new(PhysicalFS::new(path).into()).into(),
+        ));
+        self
+    }
+
+    #[must_use]
+    /// Define macros for every preprocessed file, as `NAME` or `NAME=VALUE`
+    pub fn defines(mut self, defines: &[String]) -> Self {
+        self.defines.extend_from_slice(defines);
+        self
+    }
+
     #[must_use]
     /// Add a memory layer to the virtual filesystem
     pub fn memory(mut self) -> Self {
-        self.layers.push((MemoryFS::new().into(), LayerType::Build));
+        self.layers
+            .push((MemoryFS::new().into(), LayerType::Memory));
         self
     }
 
@@ -186,12 +255,17 @@ impl WorkspaceBuilder {
     ) -> Result<WorkspacePath, Error> {
         let mut layers = self.layers.clone();
         layers.reverse();
+        let mut mounts = self.mounts;
+        // the most specific prefix is checked first
+        mounts.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
         Workspace::create(
             OverlayFS::new(&layers.into_iter().map(|(l, _)| l).collect::<Vec<_>>()).into(),
             self.layers,
+            mounts,
             project,
             discovery,
             pdrive,
+            self.defines,
         )
     }
 }
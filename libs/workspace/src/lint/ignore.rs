@@ -0,0 +1,104 @@
+//! Ignore codes with a `// hemtt-ignore` comment, or a `#pragma hemtt suppress` of their lint
+//!
+//! ```cpp
+//! // hemtt-ignore L-C01
+//! value = 1;
+//! value = 2; // hemtt-ignore L-C01, L-C02
+//!
+//! #pragma hemtt suppress invalid_value file
+//! ```
+
+use std::collections::HashMap;
+
+use codespan_reporting::diagnostic::LabelStyle;
+
+use crate::{
+    reporting::{Code, Processed},
+    WorkspacePath,
+};
+
+const DIRECTIVE: &str = "hemtt-ignore";
+
+#[derive(Default)]
+/// Reads the comments of each file once
+pub struct Ignores {
+    files: HashMap<WorkspacePath, Option<String>>,
+}
+
+impl Ignores {
+    /// Is the code ignored by a comment on its line, or on the line before it
+    pub fn ignored(&mut self, code: &dyn Code) -> bool {
+        let Some((_, index, content)) = self.locate(code) else {
+            return false;
+        };
+        let mut lines = content.lines().skip(index.saturating_sub(1));
+        let previous = if index == 0 { None } else { lines.next() };
+        let current = lines.next();
+        let ident = code.ident();
+        previous
+            .filter(|line| line.trim_start().starts_with("//"))
+            .into_iter()
+            .chain(current)
+            .any(|line| idents(line).any(|ignored| ignored == ident))
+    }
+
+    /// Is the lint of the code suppressed with `#pragma hemtt suppress` at its line
+    pub fn suppressed(&mut self, code: &dyn Code, lint: &str, processed: &Processed) -> bool {
+        if processed.suppressed_lints().is_empty() {
+            return false;
+        }
+        let Some((file, index, _)) = self.locate(code) else {
+            return false;
+        };
+        processed.is_lint_suppressed(lint, &file, index + 1)
+    }
+
+    /// The file of the primary label of a code, the index of its line, and the content of the file
+    fn locate(&mut self, code: &dyn Code) -> Option<(WorkspacePath, usize, &str)> {
+        let diagnostic = code.diagnostic()?;
+        let label = diagnostic
+            .labels
+            .into_iter()
+            .find(|l| l.style == LabelStyle::Primary)?;
+        let content = self
+            .files
+            .entry(label.file.clone())
+            .or_insert_with(|| label.file.read_to_string().ok())
+            .as_deref()?;
+        let before = content.get(..label.span.start)?;
+        Some((label.file, before.matches('\n').count(), content))
+    }
+}
+
+/// The codes listed in a `// hemtt-ignore` comment on a line
+fn idents(line: &str) -> impl Iterator<Item = &str> {
+    line.match_indices("//")
+        .find_map(|(i, _)| {
+            line[i + 2..]
+                .trim_start()
+                .strip_prefix(DIRECTIVE)
+                .filter(|rest| rest.starts_with(char::is_whitespace))
+        })
+        .into_iter()
+        .flat_map(|rest| rest.split(|c: char| c.is_whitespace() || c == ','))
+        .filter(|ident| !ident.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::idents;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            idents("// hemtt-ignore L-C01").collect::<Vec<_>>(),
+            vec!["L-C01"]
+        );
+        assert_eq!(
+            idents("value = 1; //hemtt-ignore L-C01, L-S02").collect::<Vec<_>>(),
+            vec!["L-C01", "L-S02"]
+        );
+        assert_eq!(idents("// hemtt-ignored L-C01").count(), 0);
+        assert_eq!(idents("value = 1;").count(), 0);
+    }
+}
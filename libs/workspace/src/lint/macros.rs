@@ -16,6 +16,10 @@ macro_rules! lint_manager {
                     static LINT_ADD: std::sync::LazyLock<
                         std::sync::Arc<Box<dyn hemtt_workspace::lint::Lint<super::super::LintData>>>,
                     > = std::sync::LazyLock::new(|| std::sync::Arc::new(Box::new($name)));
+                    #[linkme::distributed_slice(hemtt_workspace::lint::LINT_NAMES)]
+                    static LINT_NAME: fn() -> &'static str = || {
+                        hemtt_workspace::lint::Lint::<super::super::LintData>::ident(&$name)
+                    };
                 };
             }
             pub(crate) use lint;
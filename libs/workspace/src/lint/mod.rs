@@ -1,3 +1,4 @@
+mod ignore;
 pub mod macros;
 
 use std::{collections::HashMap, sync::Arc};
@@ -7,6 +8,24 @@ use hemtt_common::config::{LintConfig, LintConfigOverride, ProjectConfig};
 
 use crate::reporting::{Code, Codes, Diagnostic, Processed};
 
+#[linkme::distributed_slice]
+/// The name of every lint in the crates that are linked in, added by `lint!`
+pub static LINT_NAMES: [fn() -> &'static str];
+
+#[must_use]
+/// Is there a lint with this name, used by `#pragma hemtt suppress`
+pub fn is_lint(name: &str) -> bool {
+    LINT_NAMES.iter().any(|ident| ident() == name)
+}
+
+#[must_use]
+/// The names of every lint, sorted
+pub fn lint_names() -> Vec<&'static str> {
+    let mut names = LINT_NAMES.iter().map(|ident| ident()).collect::<Vec<_>>();
+    names.sort_unstable();
+    names
+}
+
 pub trait Lint<D>: Sync + Send {
     fn ident(&self) -> &'static str;
     fn sort(&self) -> u32 {
@@ -21,6 +40,10 @@ pub trait Lint<D>: Sync + Send {
     fn minimum_severity(&self) -> Severity {
         self.default_config().severity()
     }
+    /// Problems with the options of the lint, reported as invalid lint config
+    fn check_config(&self, _config: &LintConfig) -> Vec<String> {
+        vec![]
+    }
     fn runners(&self) -> Vec<Box<dyn AnyLintRunner<D>>>;
 }
 
@@ -139,6 +162,8 @@ impl<D> LintManager<D> {
         let lints: Lints<D> = vec![lint];
         self.check(&lints)?;
         self.lints.extend(lints);
+        // Lints are registered in link order, which is not stable between builds
+        self.lints.sort_by_key(|lint| lint.sort());
         Ok(())
     }
 
@@ -149,6 +174,8 @@ impl<D> LintManager<D> {
     pub fn extend(&mut self, lints: Lints<D>) -> Result<(), Codes> {
         self.check(&lints)?;
         self.lints.extend(lints);
+        // Lints are registered in link order, which is not stable between builds
+        self.lints.sort_by_key(|lint| lint.sort());
         Ok(())
     }
 
@@ -194,6 +221,11 @@ impl<D> LintManager<D> {
                         message: format!("Lint `{}` cannot be disabled", lint.ident()),
                     }));
                 }
+                for problem in lint.check_config(&config) {
+                    errors.push(Arc::new(InvalidLintConfig {
+                        message: format!("Lint `{}` {problem}", lint.ident()),
+                    }));
+                }
             }
         }
         if errors.is_empty() {
@@ -203,6 +235,11 @@ impl<D> LintManager<D> {
         }
     }
 
+    /// Run the lints against a target
+    ///
+    /// Codes are removed if they are ignored with a `// hemtt-ignore` comment,
+    /// or their lint is suppressed with `#pragma hemtt suppress`,
+    /// unless they come from a lint that cannot be disabled
     pub fn run(
         &self,
         data: &D,
@@ -210,6 +247,7 @@ impl<D> LintManager<D> {
         processed: Option<&Processed>,
         target: &dyn std::any::Any,
     ) -> Codes {
+        let mut ignores = ignore::Ignores::default();
         self.lints
             .iter()
             .flat_map(|lint| {
@@ -221,10 +259,12 @@ impl<D> LintManager<D> {
                 if !config.enabled() {
                     return vec![];
                 }
+                let critical = lint.minimum_severity() == Severity::Error;
                 lint.runners()
                     .iter()
                     .flat_map(|runner| runner.run(project, &config, processed, target, data))
-                    .collect::<Codes>()
+                    .map(|code| (code, critical, Some(lint.ident().to_string())))
+                    .collect::<Vec<_>>()
             })
             .chain(self.groups.iter().flat_map(|(lints, runner)| {
                 let mut configs = HashMap::new();
@@ -241,8 +281,34 @@ impl<D> LintManager<D> {
                 if configs.is_empty() {
                     return vec![];
                 }
-                runner.run(project, configs, processed, target, data)
+                let critical = lints
+                    .iter()
+                    .any(|lint| lint.minimum_severity() == Severity::Error);
+                runner
+                    .run(project, configs, processed, target, data)
+                    .into_iter()
+                    .map(|code| {
+                        // The lint of a code in a group is the anchor of its documentation
+                        let lint = code
+                            .link()
+                            .and_then(|link| link.rsplit_once('#'))
+                            .map(|(_, ident)| ident.to_string())
+                            .filter(|ident| lints.iter().any(|lint| lint.ident() == ident));
+                        (code, critical, lint)
+                    })
+                    .collect()
             }))
+            .filter(|(code, critical, lint)| {
+                *critical
+                    || !(ignores.ignored(code.as_ref())
+                        || lint
+                            .as_deref()
+                            .zip(processed)
+                            .is_some_and(|(lint, processed)| {
+                                ignores.suppressed(code.as_ref(), lint, processed)
+                            }))
+            })
+            .map(|(code, _, _)| code)
             .collect()
     }
 }
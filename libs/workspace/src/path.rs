@@ -36,7 +36,7 @@ impl WorkspacePath {
     }
 
     #[must_use]
-    /// Is the file from an include path
+    /// Is the file from an include path or a mounted folder
     pub fn is_include(&self) -> bool {
         self.data
             .workspace
@@ -48,6 +48,82 @@ impl WorkspacePath {
                     .and_then(|p| p.exists())
                     .unwrap_or(false)
             })
+            || self
+                .data
+                .workspace
+                .mounts
+                .iter()
+                .any(|(_, root)| root.root() == self.data.path.root())
+    }
+
+    #[must_use]
+    /// The layer the file was found in, to find it again with [`WorkspacePath::join_layer`]
+    ///
+    /// One of `source`, `include`, `mount:<prefix>` for a mounted folder, or `pdrive`
+    pub fn layer(&self) -> String {
+        let root = self.data.path.root();
+        if let Some((prefix, _)) = self
+            .data
+            .workspace
+            .mounts
+            .iter()
+            .find(|(_, mount)| mount.root() == root)
+        {
+            return format!("mount:{prefix}");
+        }
+        if root != self.data.workspace.vfs.root() {
+            return "pdrive".to_string();
+        }
+        if self.is_include() {
+            "include".to_string()
+        } else {
+            "source".to_string()
+        }
+    }
+
+    /// Join a path to the root of a layer returned by [`WorkspacePath::layer`]
+    ///
+    /// [`None`] if the layer is not part of the workspace
+    ///
+    /// # Errors
+    /// [`Error::Vfs`] if the path could not be joined
+    pub fn join_layer(&self, layer: &str, path: &str) -> Result<Option<Self>, Error> {
+        let root = if let Some(prefix) = layer.strip_prefix("mount:") {
+            let Some((_, mount)) = self
+                .data
+                .workspace
+                .mounts
+                .iter()
+                .find(|(mount, _)| mount == prefix)
+            else {
+                return Ok(None);
+            };
+            mount.root()
+        } else if layer == "pdrive" {
+            let Some(path) = self
+                .data
+                .workspace
+                .pdrive
+                .as_ref()
+                .and_then(|pdrive| pdrive.path_to(path))
+            else {
+                return Ok(None);
+            };
+            return Ok(Some(Self {
+                data: Arc::new(WorkspacePathData {
+                    path,
+                    workspace: self.data.workspace.clone(),
+                }),
+            }));
+        } else {
+            self.data.workspace.vfs.root()
+        };
+        Ok(Some(Self {
+            data: Arc::new(WorkspacePathData {
+                path: root.join(path.trim_start_matches('/'))?,
+                workspace: self.data.workspace.clone(),
+            }),
+        }))
     }
 
     /// join a path to the workspace path
@@ -193,9 +269,11 @@ impl WorkspacePath {
     ///
     /// Checks in order:
     /// - A3 P drive, if allowed and path starts with `/a3/`
-    /// - Relative to the current path, or absolute if the path starts with `/`
+    /// - Absolute, if the path starts with `/`
     /// - In the scanned pointers (prefix files)
-    /// - In the include path
+    /// - In the mounted folders, if the path starts with `/`
+    /// - Relative to the current path
+    /// - In the include folders, if the path is not absolute
     ///
     /// # Errors
     /// [`Error::Vfs`] if the path could not be located
@@ -303,10 +381,30 @@ impl WorkspacePath {
                     },
                 }));
             }
+            for (base, root) in &self.data.workspace.mounts {
+                if !path_lower.starts_with(&format!("{base}/")) {
+                    continue;
+                }
+                let Some(relative) = path.get(base.len() + 1..) else {
+                    continue;
+                };
+                let ret_path = root.join(relative)?;
+                if ret_path.exists()? {
+                    return Ok(Some(LocateResult {
+                        case_mismatch: None,
+                        path: Self {
+                            data: Arc::new(WorkspacePathData {
+                                path: ret_path,
+                                workspace: self.data.workspace.clone(),
+                            }),
+                        },
+                    }));
+                }
+            }
         }
         let ret_path = self.data.path.parent().join(&path)?;
         if ret_path.exists()? {
-            Ok(Some(LocateResult {
+            return Ok(Some(LocateResult {
                 case_mismatch: if is_wrong_case(&ret_path, &path) {
                     Some(ret_path.as_str().to_string())
                 } else {
@@ -318,10 +416,30 @@ impl WorkspacePath {
                         workspace: self.data.workspace.clone(),
                     }),
                 },
-            }))
-        } else {
-            Ok(None)
+            }));
+        }
+        if !path.starts_with('/') {
+            for (layer, layer_type) in &self.data.workspace.layers {
+                if layer_type != &LayerType::Include || !layer.join(&path)?.exists()? {
+                    continue;
+                }
+                let ret_path = self.data.workspace.vfs.join(&path)?;
+                return Ok(Some(LocateResult {
+                    case_mismatch: if is_wrong_case(&ret_path, &path) {
+                        Some(ret_path.as_str().to_string())
+                    } else {
+                        None
+                    },
+                    path: Self {
+                        data: Arc::new(WorkspacePathData {
+                            path: ret_path,
+                            workspace: self.data.workspace.clone(),
+                        }),
+                    },
+                }));
+            }
         }
+        Ok(None)
     }
 
     #[must_use]
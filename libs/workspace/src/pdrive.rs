@@ -2,6 +2,7 @@ use std::{
     collections::HashMap,
     env::temp_dir,
     fs::File,
+    io::Write,
     path::{Path, PathBuf},
 };
 
@@ -130,13 +131,14 @@ impl PDriveOnDemand {
             if dest_path.exists().expect("path exists") {
                 return Some(dest_path);
             }
-            let mut pbo = ReadablePbo::from(File::open(p).ok()?).ok()?;
-            let mut file = pbo.file(&source).ok()??;
+            let pbo = ReadablePbo::mmap(&File::open(p).ok()?).ok()?;
+            let file = pbo.file_bytes(&source).ok()??;
             let _ = dest_path.parent().create_dir_all();
-            if let Err(e) = std::io::copy(
-                &mut file,
-                &mut dest_path.create_file().expect("file can be created"),
-            ) {
+            if let Err(e) = dest_path
+                .create_file()
+                .expect("file can be created")
+                .write_all(file)
+            {
                 error!("Failed to extract file from PBO: {}", e);
                 return None;
             }
@@ -0,0 +1,70 @@
+use codespan_reporting::diagnostic::Severity;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// A diagnostic for machine-readable output
+pub struct Json {
+    /// The ident of the code
+    pub ident: String,
+    /// The severity, `error`, `warning`, `note`, or `help`
+    pub severity: &'static str,
+    /// The message of the diagnostic
+    pub message: String,
+    /// The file of the primary label
+    pub file: Option<String>,
+    /// The location of the primary label in the file
+    pub span: Option<Span>,
+    /// The message of the primary label
+    pub label: Option<String>,
+    /// Notes about the diagnostic
+    pub notes: Vec<String>,
+    /// Help on how to fix the diagnostic
+    pub help: Vec<String>,
+    /// Suggested replacements
+    pub suggestions: Vec<String>,
+    /// Link to the documentation of the code
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+/// A range in a file
+pub struct Span {
+    /// The start of the range
+    pub start: Position,
+    /// The end of the range
+    pub end: Position,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+/// A position in a file
+pub struct Position {
+    /// The byte offset from the start of the file
+    pub offset: usize,
+    /// The 1-based line
+    pub line: usize,
+    /// The 1-based column
+    pub column: usize,
+}
+
+pub const fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Bug | Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => "note",
+        Severity::Help => "help",
+    }
+}
+
+/// Remove terminal colours, some codes use them in their messages
+pub fn plain(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            chars.by_ref().find(char::is_ascii_alphabetic);
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -9,11 +9,13 @@ use codespan_reporting::{
 use crate::WorkspacePath;
 
 use self::annotation::Annotation;
+use self::json::{Json, Position, Span};
 pub use self::label::Label;
 
 use super::{Code, WorkspaceFiles};
 
 mod annotation;
+mod json;
 mod label;
 
 #[derive(Debug, Clone)]
@@ -304,6 +306,45 @@ impl Diagnostic {
             .collect()
     }
 
+    #[must_use]
+    /// Convert the diagnostic to machine-readable JSON
+    ///
+    /// The location is taken from the first primary label, if there is one
+    pub fn to_json(&self, files: &WorkspaceFiles) -> Json {
+        let primary = self.labels.iter().find(|l| l.style == LabelStyle::Primary);
+        let position = |file: &WorkspacePath, offset: usize| {
+            let line_index = files.line_index(file, offset).ok()?;
+            Some(Position {
+                offset,
+                line: files.line_number(file, line_index).ok()?,
+                column: files.column_number(file, line_index, offset).ok()?,
+            })
+        };
+        Json {
+            ident: self.code.clone(),
+            severity: json::severity(self.severity),
+            message: json::plain(&self.message),
+            file: primary.map(|l| l.file.as_str().to_string()),
+            span: primary.and_then(|l| {
+                Some(Span {
+                    start: position(&l.file, l.span.start)?,
+                    end: position(&l.file, l.span.end)?,
+                })
+            }),
+            label: primary.and_then(|l| l.message.as_deref().map(json::plain)),
+            notes: self.notes.iter().map(|n| json::plain(n)).collect(),
+            help: self.help.iter().map(|h| json::plain(h)).collect(),
+            suggestions: self.suggestions.iter().map(|s| json::plain(s)).collect(),
+            link: self.link.as_ref().map(|link| {
+                if link.starts_with("http") {
+                    link.clone()
+                } else {
+                    format!("https://hemtt.dev{link}")
+                }
+            }),
+        }
+    }
+
     #[cfg(feature = "lsp")]
     pub fn to_lsp(
         &self,
@@ -0,0 +1,74 @@
+use std::ops::Range;
+
+use crate::WorkspacePath;
+
+use super::Processed;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A machine-applicable fix, replacing a span of an original source file
+pub struct Fix {
+    file: WorkspacePath,
+    span: Range<usize>,
+    original: String,
+    replacement: String,
+}
+
+impl Fix {
+    #[must_use]
+    /// Create a fix for a span of a processed file
+    ///
+    /// The replacement is created from the original source of the span.
+    /// Returns `None` if the span was created by a macro, or spans multiple files,
+    /// as the source can not be changed safely.
+    pub fn from_processed(
+        processed: &Processed,
+        span: Range<usize>,
+        replacement: impl FnOnce(&str) -> Option<String>,
+    ) -> Option<Self> {
+        if span.is_empty() {
+            return None;
+        }
+        let map_start = processed.mapping(span.start)?;
+        let map_end = processed.mapping(span.end - 1)?;
+        if map_start.was_macro() || map_end.was_macro() || map_start.source() != map_end.source() {
+            return None;
+        }
+        let (file, source) = processed.source(map_start.source())?;
+        let span = map_start.original_start()..map_end.original_end();
+        let original = source.get(span.clone())?;
+        let replacement = replacement(original)?;
+        if replacement == original {
+            return None;
+        }
+        Some(Self {
+            file: file.clone(),
+            span,
+            original: original.to_string(),
+            replacement,
+        })
+    }
+
+    #[must_use]
+    /// The file to change
+    pub const fn file(&self) -> &WorkspacePath {
+        &self.file
+    }
+
+    #[must_use]
+    /// The byte span in the original file
+    pub const fn span(&self) -> &Range<usize> {
+        &self.span
+    }
+
+    #[must_use]
+    /// The text expected at the span, a fix is not applied if the file has changed
+    pub fn original(&self) -> &str {
+        &self.original
+    }
+
+    #[must_use]
+    /// The text to replace the span with
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+}
@@ -4,6 +4,7 @@ use std::fmt::Debug;
 
 pub mod diagnostic;
 mod files;
+mod fix;
 mod output;
 mod processed;
 mod symbol;
@@ -13,8 +14,9 @@ mod whitespace;
 pub use codespan_reporting::diagnostic::Severity;
 pub use diagnostic::{Diagnostic, Label};
 pub use files::{WorkspaceFile, WorkspaceFiles};
+pub use fix::Fix;
 pub use output::Output;
-pub use processed::{Mapping, Processed, Sources};
+pub use processed::{Include, Mapping, Probe, Processed, Sources, SuppressedLint};
 pub use symbol::Symbol;
 pub use token::Token;
 pub use whitespace::Whitespace;
@@ -68,6 +70,10 @@ pub trait Code: Send + Sync {
     fn suggestion(&self) -> Option<String> {
         None
     }
+    /// Machine-applicable fix, if any, applied by `hemtt check --fix`
+    fn fix(&self) -> Option<Fix> {
+        None
+    }
 
     /// A diagnostic for the LSP / terminal
     fn diagnostic(&self) -> Option<Diagnostic> {
@@ -1,7 +1,7 @@
 use std::{
     collections::HashMap,
     ops::{Range, RangeFrom},
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 use tracing::warn;
 
@@ -20,6 +20,12 @@ pub struct Processed {
     sources: Sources,
     output: String,
 
+    /// files included with `#include`, in the order they were included
+    included_files: Vec<WorkspacePath>,
+
+    /// each `#include`, with the file it is in
+    includes: Vec<Include>,
+
     /// character offset for each line
     line_offsets: HashMap<WorkspacePath, HashMap<usize, usize>>,
 
@@ -43,6 +49,15 @@ pub struct Processed {
 
     /// The preprocessor was able to check the file, but it should not be rapified
     no_rapify: bool,
+
+    /// lints suppressed with `#pragma hemtt suppress`
+    suppressed_lints: Vec<SuppressedLint>,
+
+    /// The file was flagged with `#pragma hemtt flag_as_entry`
+    entry: bool,
+
+    /// files that were looked for, found or not
+    probes: Mutex<Vec<Probe>>,
 }
 
 fn append_token(
@@ -184,8 +199,12 @@ impl Processed {
         #[cfg(feature = "lsp")] usage: HashMap<Position, Vec<Position>>,
         warnings: Codes,
         no_rapify: bool,
+        included_files: Vec<WorkspacePath>,
+        includes: Vec<Include>,
     ) -> Result<Self, Error> {
         let mut processed = Self {
+            included_files,
+            includes,
             macros,
             #[cfg(feature = "lsp")]
             usage,
@@ -198,6 +217,55 @@ impl Processed {
         Ok(processed)
     }
 
+    #[must_use]
+    /// Set the lints suppressed with `#pragma hemtt suppress`
+    pub fn with_suppressed_lints(mut self, suppressed_lints: Vec<SuppressedLint>) -> Self {
+        self.suppressed_lints = suppressed_lints;
+        self
+    }
+
+    #[must_use]
+    /// Set the files that were looked for, such as with `__has_include`
+    pub fn with_probes(self, probes: Vec<Probe>) -> Self {
+        Self {
+            probes: Mutex::new(probes),
+            ..self
+        }
+    }
+
+    #[must_use]
+    /// Set if the file was flagged with `#pragma hemtt flag_as_entry`
+    pub const fn with_entry(mut self, entry: bool) -> Self {
+        self.entry = entry;
+        self
+    }
+
+    #[must_use]
+    /// The files that were looked for, found or not
+    ///
+    /// The output and lints depend on them, but they are not in [`Processed::included_files`] unless they were also included
+    ///
+    /// # Panics
+    /// If the probes are poisoned
+    pub fn probes(&self) -> Vec<Probe> {
+        self.probes.lock().expect("probes poisoned").clone()
+    }
+
+    /// Locate a file from another file, and record it as a probe
+    ///
+    /// Used by lints that check if files exist, so their result can be checked again
+    ///
+    /// # Panics
+    /// If the probes are poisoned
+    pub fn locate(&self, from: &WorkspacePath, path: &str) -> Option<WorkspacePath> {
+        let found = from.locate(path).ok().flatten().map(|found| found.path);
+        self.probes
+            .lock()
+            .expect("probes poisoned")
+            .push(Probe::new(from.clone(), path.to_string(), found.is_some()));
+        found
+    }
+
     #[must_use]
     /// Get the output suitable for further processing
     /// Ignores certain tokens
@@ -217,6 +285,21 @@ impl Processed {
         self.sources.clone()
     }
 
+    #[must_use]
+    /// Get the files included with `#include`, in the order they were included
+    ///
+    /// Unlike [`Self::sources`], this includes files that did not add to the output,
+    /// such as files that only define macros
+    pub fn included_files(&self) -> &[WorkspacePath] {
+        &self.included_files
+    }
+
+    #[must_use]
+    /// Get each `#include` in the order they were processed, forming the include graph
+    pub fn includes(&self) -> &[Include] {
+        &self.includes
+    }
+
     #[must_use]
     /// Get a source by index
     ///
@@ -293,6 +376,28 @@ impl Processed {
         self.no_rapify
     }
 
+    #[must_use]
+    /// Get the lints suppressed with `#pragma hemtt suppress`
+    pub fn suppressed_lints(&self) -> &[SuppressedLint] {
+        &self.suppressed_lints
+    }
+
+    #[must_use]
+    /// Is the lint suppressed at a line of a file
+    pub fn is_lint_suppressed(&self, ident: &str, file: &WorkspacePath, line: usize) -> bool {
+        self.suppressed_lints
+            .iter()
+            .any(|suppressed| suppressed.lint() == ident && suppressed.applies(file, line))
+    }
+
+    #[must_use]
+    /// Was the file flagged with `#pragma hemtt flag_as_entry`
+    ///
+    /// An entry is used from outside of the project, such as a function called by other mods
+    pub const fn is_entry(&self) -> bool {
+        self.entry
+    }
+
     #[must_use]
     /// Return a string with the source from the span
     pub fn extract(&self, span: Range<usize>) -> Arc<str> {
@@ -384,3 +489,136 @@ impl Mapping {
         self.was_macro
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A file included with `#include`
+pub struct Include {
+    parent: WorkspacePath,
+    file: WorkspacePath,
+    position: Position,
+    used: bool,
+    suppressed: bool,
+}
+
+impl Include {
+    #[must_use]
+    /// Create a new include
+    pub const fn new(
+        parent: WorkspacePath,
+        file: WorkspacePath,
+        position: Position,
+        used: bool,
+        suppressed: bool,
+    ) -> Self {
+        Self {
+            parent,
+            file,
+            position,
+            used,
+            suppressed,
+        }
+    }
+
+    #[must_use]
+    /// The file containing the `#include`
+    pub const fn parent(&self) -> &WorkspacePath {
+        &self.parent
+    }
+
+    #[must_use]
+    /// The file that was included
+    pub const fn file(&self) -> &WorkspacePath {
+        &self.file
+    }
+
+    #[must_use]
+    /// The position of the path in the `#include`
+    pub const fn position(&self) -> &Position {
+        &self.position
+    }
+
+    #[must_use]
+    /// Did the file, or a file it includes, contribute tokens or defines that were used
+    pub const fn used(&self) -> bool {
+        self.used
+    }
+
+    #[must_use]
+    /// Was `pw5_include_unused` suppressed for the include
+    pub const fn suppressed(&self) -> bool {
+        self.suppressed
+    }
+
+    #[must_use]
+    /// Is the include reported as unused in `file`, it must be in `file`, unused, and not suppressed
+    pub fn unused_in(&self, file: &WorkspacePath) -> bool {
+        !self.used && !self.suppressed && &self.parent == file
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A file that was looked for, such as with `__has_include`
+pub struct Probe {
+    from: WorkspacePath,
+    path: String,
+    found: bool,
+}
+
+impl Probe {
+    #[must_use]
+    /// Create a new probe
+    pub const fn new(from: WorkspacePath, path: String, found: bool) -> Self {
+        Self { from, path, found }
+    }
+
+    #[must_use]
+    /// The file the path was located from
+    pub const fn from(&self) -> &WorkspacePath {
+        &self.from
+    }
+
+    #[must_use]
+    /// The path that was looked for
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    /// Was the file found
+    pub const fn found(&self) -> bool {
+        self.found
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A lint suppressed with `#pragma hemtt suppress`
+pub struct SuppressedLint {
+    lint: String,
+    file: Option<WorkspacePath>,
+    lines: Range<usize>,
+}
+
+impl SuppressedLint {
+    #[must_use]
+    /// Create a new suppressed lint
+    ///
+    /// Without a file, the lint is suppressed in every file of the config
+    pub const fn new(lint: String, file: Option<WorkspacePath>, lines: Range<usize>) -> Self {
+        Self { lint, file, lines }
+    }
+
+    #[must_use]
+    /// The ident of the suppressed lint
+    pub fn lint(&self) -> &str {
+        &self.lint
+    }
+
+    #[must_use]
+    /// Does the suppression apply to a line of a file
+    pub fn applies(&self, file: &WorkspacePath, line: usize) -> bool {
+        self.file
+            .as_ref()
+            .is_none_or(|suppressed| suppressed == file)
+            && self.lines.contains(&line)
+    }
+}
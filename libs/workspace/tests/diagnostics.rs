@@ -38,3 +38,35 @@ fn python() {
     }
     assert_eq!(diagnostic, expected);
 }
+
+#[test]
+fn json() {
+    let folder = std::path::PathBuf::from(ROOT);
+    let workspace = hemtt_workspace::Workspace::builder()
+        .physical(&folder, LayerType::Source)
+        .finish(None, false, &hemtt_common::config::PDriveOption::Disallow)
+        .unwrap();
+    let json = Diagnostic::new("T1".to_string(), "using python 2".to_string())
+        .set_severity(Severity::Warning)
+        .with_label(Label::secondary(
+            workspace.join("example.py").unwrap(),
+            6..19,
+        ))
+        .with_label(
+            Label::primary(workspace.join("example.py").unwrap(), 0..5)
+                .with_message("using outdated `print`".to_string()),
+        )
+        .with_help("`print` is replaced by `print()`".to_string())
+        .to_json(&WorkspaceFiles::new());
+    assert_eq!(json.ident, "T1");
+    assert_eq!(json.severity, "warning");
+    assert_eq!(json.file.as_deref(), Some("/example.py"));
+    let span = json.span.unwrap();
+    assert_eq!(
+        (span.start.line, span.start.column, span.end.column),
+        (1, 1, 6)
+    );
+    assert_eq!(json.label.as_deref(), Some("using outdated `print`"));
+    assert_eq!(json.help, vec!["`print` is replaced by `print()`"]);
+    assert!(json.link.is_none());
+}
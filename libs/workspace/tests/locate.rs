@@ -0,0 +1,83 @@
+#![allow(clippy::unwrap_used)]
+
+use std::{io::Write, path::PathBuf};
+
+use hemtt_common::config::PDriveOption;
+use hemtt_workspace::{LayerType, Workspace};
+
+#[test]
+fn include_paths() {
+    let workspace = Workspace::builder()
+        .physical(&PathBuf::from("tests/locate/project"), LayerType::Source)
+        .physical(&PathBuf::from("tests/locate/common"), LayerType::Include)
+        .finish(None, false, &PDriveOption::Disallow)
+        .unwrap();
+    let config = workspace.join("addons/main/config.cpp").unwrap();
+    let found = config.locate("compat\\shared.hpp").unwrap().unwrap();
+    assert_eq!(found.path.as_str(), "/compat/shared.hpp");
+    assert!(found.path.is_include());
+    assert_eq!(found.path.layer(), "include");
+    assert_eq!(config.layer(), "source");
+    assert!(config.locate("compat\\missing.hpp").unwrap().is_none());
+    assert!(config.locate("/compat/missing.hpp").unwrap().is_none());
+}
+
+#[test]
+fn mounts() {
+    let workspace = Workspace::builder()
+        .physical(&PathBuf::from("tests/locate/project"), LayerType::Source)
+        .mount("\\x\\cba\\", &PathBuf::from("tests/locate/cba"))
+        .mount("\\", &PathBuf::from("tests/locate/common"))
+        .finish(None, false, &PDriveOption::Disallow)
+        .unwrap();
+    let config = workspace.join("addons/main/config.cpp").unwrap();
+    let found = config
+        .locate("\\x\\cba\\addons\\main\\script_macros.hpp")
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.path.as_str(), "/addons/main/script_macros.hpp");
+    assert!(found.path.is_include());
+    // the mounted file is found again from its layer, not from the project
+    assert_eq!(found.path.layer(), "mount:/x/cba");
+    let joined = workspace
+        .join_layer(&found.path.layer(), found.path.as_str())
+        .unwrap()
+        .unwrap();
+    assert_eq!(joined, found.path);
+    assert!(!workspace
+        .join_layer("source", found.path.as_str())
+        .unwrap()
+        .unwrap()
+        .exists()
+        .unwrap());
+    let found = config.locate("\\compat\\shared.hpp").unwrap().unwrap();
+    assert_eq!(found.path.as_str(), "/compat/shared.hpp");
+    assert!(config
+        .locate("\\x\\cba\\addons\\main\\missing.hpp")
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn clear_memory() {
+    let workspace = Workspace::builder()
+        .physical(&PathBuf::from("tests/locate/project"), LayerType::Source)
+        .memory()
+        .finish(None, false, &PDriveOption::Disallow)
+        .unwrap();
+    let generated = workspace.join("addons/main/generated.hpp").unwrap();
+    generated
+        .create_file()
+        .unwrap()
+        .write_all(b"#define GENERATED")
+        .unwrap();
+    assert!(generated.exists().unwrap());
+    workspace.workspace().clear_memory().unwrap();
+    assert!(!generated.exists().unwrap());
+    // the files on disk are kept
+    assert!(workspace
+        .join("addons/main/config.cpp")
+        .unwrap()
+        .exists()
+        .unwrap());
+}
@@ -0,0 +1 @@
+#define CBA 1